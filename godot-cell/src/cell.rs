@@ -69,7 +69,66 @@ impl<T> GdCell<T> {
     }
 }
 
-/// Internals of [`GdCell`].
+/// A [`GdCell`] that stores its value inline, without a separate heap allocation.
+///
+/// The cell hands out the same guards as `GdCell`, but since its self-referential pointers are only set up on the first borrow, the cell
+/// must stay at a fixed address from then on. This is meant for cells that are embedded in a larger structure, which is moved to its
+/// final location before being used.
+pub struct GdCellInline<T>(GdCellInner<T>);
+
+impl<T> GdCellInline<T> {
+    /// Creates a new cell storing `value`.
+    ///
+    /// # Safety
+    /// The cell must not be moved after [`borrow()`](Self::borrow) or [`borrow_mut()`](Self::borrow_mut) has been called for the first
+    /// time, even after all guards are dropped.
+    pub unsafe fn new(value: T) -> Self {
+        Self(GdCellInner::new_unpinned(value))
+    }
+
+    /// Returns a new shared reference to the contents of the cell.
+    ///
+    /// Fails if an accessible mutable reference exists.
+    pub fn borrow(&self) -> Result<RefGuard<'_, T>, Box<dyn Error>> {
+        self.inner().borrow()
+    }
+
+    /// Returns a new mutable reference to the contents of the cell.
+    ///
+    /// Fails if an accessible mutable reference exists, or a shared reference exists.
+    pub fn borrow_mut(&self) -> Result<MutGuard<'_, T>, Box<dyn Error>> {
+        self.inner().borrow_mut()
+    }
+
+    /// Make the current mutable borrow inaccessible, thus freeing the value up to be reborrowed again.
+    ///
+    /// See [`GdCell::make_inaccessible()`].
+    pub fn make_inaccessible<'cell, 'val>(
+        &'cell self,
+        original_ref: &'val mut T,
+    ) -> Result<InaccessibleGuard<'val, T>, Box<dyn Error>>
+    where
+        'cell: 'val,
+    {
+        self.inner().make_inaccessible(original_ref)
+    }
+
+    /// Returns `true` if there are any mutable or shared references, regardless of whether the mutable
+    /// references are accessible or not.
+    ///
+    /// See [`GdCell::is_currently_bound()`].
+    pub fn is_currently_bound(&self) -> bool {
+        self.inner().is_currently_bound()
+    }
+
+    fn inner(&self) -> Pin<&GdCellInner<T>> {
+        // SAFETY: the contract of `new()` guarantees that the cell is not moved once its pointers have been initialized, which happens
+        // in the first borrow through this pinned reference.
+        unsafe { Pin::new_unchecked(&self.0) }
+    }
+}
+
+/// Internals of [`GdCell`] and [`GdCellInline`].
 ///
 /// This cell must be pinned to be usable, as it stores self-referential pointers. The [`GdCell`] type abstracts this detail away from
 /// the public type.
@@ -88,23 +147,29 @@ pub(crate) struct GdCellInner<T> {
 impl<T> GdCellInner<T> {
     /// Creates a new cell storing `value`.
     pub fn new(value: T) -> Pin<Box<Self>> {
-        let cell = Box::pin(Self {
-            state: Mutex::new(CellState::new()),
-            value: UnsafeCell::new(value),
-            _pin: PhantomPinned,
-        });
+        let cell = Box::pin(Self::new_unpinned(value));
 
         cell.state.lock().unwrap().initialize_ptr(&cell.value);
 
         cell
     }
 
+    /// Creates a new cell storing `value`, whose pointer is initialized lazily on the first borrow.
+    fn new_unpinned(value: T) -> Self {
+        Self {
+            state: Mutex::new(CellState::new()),
+            value: UnsafeCell::new(value),
+            _pin: PhantomPinned,
+        }
+    }
+
     /// Returns a new shared reference to the contents of the cell.
     ///
     /// Fails if an accessible mutable reference exists.
     pub fn borrow(self: Pin<&Self>) -> Result<RefGuard<'_, T>, Box<dyn Error>> {
         let mut state = self.state.lock().unwrap();
         state.borrow_state.increment_shared()?;
+        state.initialize_ptr_lazily(&self.value);
 
         // SAFETY: `increment_shared` succeeded, therefore there cannot currently be any accessible mutable
         // references.
@@ -117,6 +182,7 @@ impl<T> GdCellInner<T> {
     pub fn borrow_mut(self: Pin<&Self>) -> Result<MutGuard<'_, T>, Box<dyn Error>> {
         let mut state = self.state.lock().unwrap();
         state.borrow_state.increment_mut()?;
+        state.initialize_ptr_lazily(&self.value);
         let count = state.borrow_state.mut_count();
         let value = state.get_ptr();
 
@@ -222,6 +288,13 @@ impl<T> CellState<T> {
         }
     }
 
+    /// Initialize the pointer if this has not happened yet, for cells that are not pinned on construction.
+    fn initialize_ptr_lazily(&mut self, value: &UnsafeCell<T>) {
+        if self.ptr.is_null() {
+            self.initialize_ptr(value);
+        }
+    }
+
     /// Returns the current pointer. Panics if uninitialized.
     pub(crate) fn get_ptr(&self) -> NonNull<T> {
         NonNull::new(self.ptr).unwrap()
@@ -351,4 +424,41 @@ mod test {
         drop(guard1);
         drop(guard2);
     }
+
+    #[test]
+    fn inline_prevent_mut_shared() {
+        const VAL: i32 = 777;
+        let cell = unsafe { GdCellInline::new(VAL) };
+        let guard1 = cell.borrow_mut().unwrap();
+        let guard2 = cell.borrow();
+
+        assert_eq!(*guard1, VAL);
+        assert!(guard2.is_err());
+        assert!(cell.is_currently_bound());
+        drop(guard1);
+
+        assert!(!cell.is_currently_bound());
+    }
+
+    #[test]
+    fn inline_moved_before_borrow() {
+        const VAL: i32 = 4242;
+        let cell = unsafe { GdCellInline::new(VAL) };
+
+        // Moving is allowed as long as nothing was borrowed yet.
+        let boxed = Box::new(cell);
+
+        let mut guard1 = boxed.borrow_mut().unwrap();
+        let mut1 = &mut *guard1;
+        *mut1 = VAL + 1;
+
+        let inaccessible_guard = boxed.make_inaccessible(mut1).unwrap();
+        let guard2 = boxed.borrow().unwrap();
+        assert_eq!(*guard2, VAL + 1);
+        drop(guard2);
+        drop(inaccessible_guard);
+        drop(guard1);
+
+        assert_eq!(*boxed.borrow().unwrap(), VAL + 1);
+    }
 }
@@ -37,7 +37,7 @@ mod cell;
 mod guards;
 
 pub mod panicking {
-    pub use crate::cell::GdCell;
+    pub use crate::cell::{GdCell, GdCellInline};
     pub use crate::guards::{InaccessibleGuard, MutGuard, RefGuard};
 }
 
@@ -14,7 +14,7 @@ use std::fmt;
 use crate::context::Context;
 use crate::conv;
 use crate::models::domain::{GodotTy, ModName, RustTy, TyName};
-use crate::special_cases::is_builtin_type_scalar;
+use crate::special_cases::{is_builtin_type_scalar, is_enum_bitfield};
 use crate::util::ident;
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -190,6 +190,17 @@ fn to_rust_type_uncached(full_ty: &GodotTy, ctx: &mut Context) -> RustTy {
     }
 
     if let Some(qualified_enum) = ty.strip_prefix("enum::") {
+        // Enums that are generated as bitfields (see `is_enum_bitfield()`).
+        if is_enum_bitfield_override(qualified_enum) {
+            return to_rust_type_uncached(
+                &GodotTy {
+                    ty: format!("bitfield::{qualified_enum}"),
+                    meta: None,
+                },
+                ctx,
+            );
+        }
+
         return if let Some((class, enum_)) = qualified_enum.split_once('.') {
             // Class-local enum
             let module = ModName::from_godot(class);
@@ -238,6 +249,15 @@ fn to_rust_type_uncached(full_ty: &GodotTy, ctx: &mut Context) -> RustTy {
     }
 }
 
+fn is_enum_bitfield_override(qualified_enum: &str) -> bool {
+    let (class_name, enum_name) = match qualified_enum.split_once('.') {
+        Some((class, enum_)) => (Some(TyName::from_godot(class)), enum_),
+        None => (None, qualified_enum),
+    };
+
+    is_enum_bitfield(class_name.as_ref(), enum_name) == Some(true)
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Godot -> Rust expressions
 
@@ -59,41 +59,127 @@ pub fn make_core_central_code(api: &ExtensionApi, ctx: &mut Context) -> TokenStr
         ..
     } = make_variant_enums(api, ctx);
 
+    // Objects are decoded separately in VariantDispatch::from_variant(), as the variant may refer to a freed instance.
+    let (variant_ty_nonobject_pascal, (variant_ty_nonobject_shout, variant_ty_nonobject_rust)): (
+        Vec<_>,
+        (Vec<_>, Vec<_>),
+    ) = variant_ty_enumerators_pascal
+        .iter()
+        .zip(
+            variant_ty_enumerators_shout
+                .iter()
+                .zip(&variant_ty_enumerators_rust),
+        )
+        .filter(|(_, (shout, _))| *shout != "OBJECT")
+        .unzip();
+
     let (global_enum_defs, global_reexported_enum_defs) = make_global_enums(api);
     let variant_type_traits = make_variant_type_enum(api, false).0;
 
-    // TODO impl Clone, Debug, PartialEq, PartialOrd, Hash for VariantDispatch
+    // TODO impl PartialOrd, Hash for VariantDispatch
     // TODO could use try_to().unwrap_unchecked(), since type is already verified. Also directly overload from_variant().
     // But this requires that all the variant types support this.
     quote! {
         use crate::builtin::*;
         use crate::classes::Object;
+        use crate::meta::ToGodot;
         use crate::obj::Gd;
 
         // Remaining trait impls for sys::VariantType (traits only defined in godot-core).
         #variant_type_traits
 
-        #[allow(dead_code)]
+        /// Content of a [`Variant`], decoded into the corresponding Rust type.
+        ///
+        /// Allows to exhaustively `match` on the value stored in a variant, instead of comparing [`Variant::get_type()`] and converting
+        /// with [`Variant::to()`] in each branch. Obtained through [`Variant::dispatch()`].
+        ///
+        /// A variant holding a null object is represented as [`Nil`][Self::Nil], consistent with [`Variant::get_type()`]. A variant that
+        /// still refers to an object which has since been freed is represented as [`FreedObject`][Self::FreedObject].
+        ///
+        /// New variant types may be added in future Godot versions, so matches need a wildcard arm.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use godot::builtin::{Variant, VariantDispatch};
+        ///
+        /// fn describe(value: &Variant) -> String {
+        ///     match value.dispatch() {
+        ///         VariantDispatch::Nil => "nothing".to_string(),
+        ///         VariantDispatch::Int(i) => format!("integer {i}"),
+        ///         VariantDispatch::String(s) => format!("string {s}"),
+        ///         VariantDispatch::Object(obj) => format!("object of class {}", obj.get_class()),
+        ///         other => format!("{other:?}"),
+        ///     }
+        /// }
+        /// ```
+        #[derive(Clone, PartialEq)]
+        #[non_exhaustive]
         pub enum VariantDispatch {
             Nil,
             #(
                 #variant_ty_enumerators_pascal(#variant_ty_enumerators_rust),
             )*
+
+            /// The variant has type `OBJECT`, but the object it refers to has been freed.
+            FreedObject,
         }
 
         impl VariantDispatch {
+            /// Decodes the value stored in `variant`.
+            ///
+            /// Equivalent to [`Variant::dispatch()`].
             pub fn from_variant(variant: &Variant) -> Self {
                 match variant.get_type() {
                     VariantType::NIL => Self::Nil,
+                    VariantType::OBJECT => match variant.try_to::<Gd<Object>>() {
+                        Ok(object) => Self::Object(object),
+                        Err(_) => Self::FreedObject,
+                    },
                     #(
-                        VariantType::#variant_ty_enumerators_shout
-                            => Self::#variant_ty_enumerators_pascal(variant.to::<#variant_ty_enumerators_rust>()),
+                        VariantType::#variant_ty_nonobject_shout
+                            => Self::#variant_ty_nonobject_pascal(variant.to::<#variant_ty_nonobject_rust>()),
                     )*
 
                     // Panic can be removed as soon as VariantType is a proper, non-exhaustive enum.
                     _ => panic!("Variant type not supported: {:?}", variant.get_type()),
                 }
             }
+
+            /// Returns the type of the stored value.
+            pub fn variant_type(&self) -> VariantType {
+                match self {
+                    Self::Nil => VariantType::NIL,
+                    #(
+                        Self::#variant_ty_enumerators_pascal(_) => VariantType::#variant_ty_enumerators_shout,
+                    )*
+                    Self::FreedObject => VariantType::OBJECT,
+                }
+            }
+
+            /// Converts the value back into a [`Variant`].
+            ///
+            /// A [`FreedObject`][Self::FreedObject] becomes `NIL`, as the object no longer exists.
+            pub fn into_variant(self) -> Variant {
+                match self {
+                    Self::Nil => Variant::nil(),
+                    #(
+                        Self::#variant_ty_enumerators_pascal(v) => v.to_variant(),
+                    )*
+                    Self::FreedObject => Variant::nil(),
+                }
+            }
+        }
+
+        impl From<&Variant> for VariantDispatch {
+            fn from(variant: &Variant) -> Self {
+                Self::from_variant(variant)
+            }
+        }
+
+        impl From<VariantDispatch> for Variant {
+            fn from(dispatch: VariantDispatch) -> Self {
+                dispatch.into_variant()
+            }
         }
 
         impl std::fmt::Debug for VariantDispatch {
@@ -103,6 +189,7 @@ pub fn make_core_central_code(api: &ExtensionApi, ctx: &mut Context) -> TokenStr
                     #(
                         Self::#variant_ty_enumerators_pascal(v) => write!(f, "{v:?}"),
                     )*
+                    Self::FreedObject => write!(f, "<Freed Object>"),
                 }
             }
         }
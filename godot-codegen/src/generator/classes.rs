@@ -224,6 +224,7 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
                 type Memory = crate::obj::bounds::#assoc_memory;
                 type DynMemory = crate::obj::bounds::#assoc_dyn_memory;
                 type Declarer = crate::obj::bounds::DeclEngine;
+                type Layout = crate::obj::bounds::LayoutBoxed;
             }
 
             #(
@@ -310,9 +311,28 @@ fn make_class_module_file(classes_and_modules: Vec<GeneratedClassModule>) -> Tok
         }
     });
 
+    let class_name_arms = classes_and_modules.iter().map(|m| {
+        let class_name = &m.class_name;
+        let godot_str = &class_name.godot_ty;
+
+        quote! {
+            #godot_str => <#class_name as crate::obj::GodotClass>::class_name(),
+        }
+    });
+
     quote! {
         #( #class_decls )*
 
+        /// Maps a Godot class name to the `ClassName` of the generated Rust type, if that class is part of the generated API.
+        pub(crate) fn engine_class_name(godot_name: &str) -> Option<crate::meta::ClassName> {
+            let class_name = match godot_name {
+                #( #class_name_arms )*
+                _ => return None,
+            };
+
+            Some(class_name)
+        }
+
         /// Notification enums for all classes.
         pub mod notify {
             #( #notify_decls )*
@@ -342,8 +362,13 @@ fn make_constructor_and_default(
         // not strict Rust mutability, it makes the API much more usable).
         // As long as the user has multiple Gd smart pointers to the same singletons, only the internal raw pointers are aliased.
         // See also Deref/DerefMut impl for Gd.
+        let rust_class_name = class.name().rust_ty.to_string();
+        let api_level = class.api_level.api_level_variant();
+
         constructor = quote! {
             pub fn singleton() -> Gd<Self> {
+                sys::debug_check_class_api_level(sys::ClassApiLevel::#api_level, #rust_class_name, "singleton");
+
                 unsafe {
                     let __class_name = #godot_class_stringname;
                     let __object_ptr = sys::interface_fn!(global_get_singleton)(__class_name.string_sys());
@@ -487,8 +512,14 @@ fn make_class_method_definition(
         quote! { fptr_by_index(#table_index) }
     };
 
+    let api_level = class.api_level.api_level_variant();
+    let level_check = quote! {
+        sys::debug_check_class_api_level(sys::ClassApiLevel::#api_level, #rust_class_name, #rust_method_name);
+    };
+
     let object_ptr = &receiver.ffi_arg;
     let ptrcall_invocation = quote! {
+        #level_check
         let method_bind = sys::#get_method_table().#fptr_access;
 
         <CallSig as PtrcallSignatureTuple>::out_class_ptrcall(
@@ -502,6 +533,7 @@ fn make_class_method_definition(
     };
 
     let varcall_invocation = quote! {
+        #level_check
         let method_bind = sys::#get_method_table().#fptr_access;
 
         <CallSig as VarcallSignatureTuple>::out_class_varcall(
@@ -258,7 +258,8 @@ fn make_enum_engine_trait_impl(enum_: &Enum) -> TokenStream {
 
 /// Creates implementations for bitwise operators for the given enum.
 ///
-/// Currently this is just [`BitOr`](std::ops::BitOr) for bitfields but that could be expanded in the future.
+/// Currently this is [`BitOr`](std::ops::BitOr) and [`BitAnd`](std::ops::BitAnd) (plus assignment variants) for bitfields, but that could
+/// be expanded in the future.
 fn make_enum_bitwise_operators(enum_: &Enum) -> TokenStream {
     let name = &enum_.name;
 
@@ -271,6 +272,26 @@ fn make_enum_bitwise_operators(enum_: &Enum) -> TokenStream {
                     Self { ord: self.ord | rhs.ord }
                 }
             }
+
+            impl std::ops::BitOrAssign for #name {
+                fn bitor_assign(&mut self, rhs: Self) {
+                    *self = *self | rhs;
+                }
+            }
+
+            impl std::ops::BitAnd for #name {
+                type Output = Self;
+
+                fn bitand(self, rhs: Self) -> Self::Output {
+                    Self { ord: self.ord & rhs.ord }
+                }
+            }
+
+            impl std::ops::BitAndAssign for #name {
+                fn bitand_assign(&mut self, rhs: Self) {
+                    *self = *self & rhs;
+                }
+            }
         }
     } else {
         TokenStream::new()
@@ -7,7 +7,8 @@
 
 use crate::generator::functions_common;
 use crate::generator::functions_common::{FnCode, FnReceiver};
-use crate::models::domain::{ExtensionApi, Function, UtilityFunction};
+use crate::models::domain::{ExtensionApi, Function, RustTy, UtilityFunction};
+use crate::special_cases;
 use crate::{util, SubmitFn};
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
@@ -24,12 +25,33 @@ pub(crate) fn generate_utilities_file(
         .iter()
         .map(make_utility_function_definition);
 
+    let typed_fn_defs = api
+        .utility_functions
+        .iter()
+        .filter_map(make_typed_utility_function_definition);
+
     let imports = util::make_imports();
 
     let tokens = quote! {
         #imports
 
         #(#utility_fn_defs)*
+
+        /// Generic versions of utility functions that accept and return `Variant`.
+        ///
+        /// Functions like `clamp()` or `max()` work with integers, floats and vectors in GDScript, so Godot declares them with `Variant`
+        /// parameters. The functions in this module take and return one Rust type `T` instead, and convert from and to `Variant` internally.
+        /// Variadic functions take their additional arguments as a slice `rest: &[T]`.
+        ///
+        /// Coverage is partial: only functions whose parameters and return value all share one type are included, i.e. `abs()`,
+        /// `ceil()`, `clamp()`, `floor()`, `max()`, `min()`, `round()`, `sign()`, `snapped()` and `wrap()`. Others, such as `lerp()`
+        /// (whose weight is always a float), are only available in their `Variant` form in the parent module. Utility functions that
+        /// Godot already declares with concrete types, like `wrapf()` or `smoothstep()`, need no counterpart.
+        pub mod typed {
+            use crate::meta::{FromGodot, ToGodot};
+
+            #(#typed_fn_defs)*
+        }
     };
 
     submit_fn(gen_path.join("utilities.rs"), tokens);
@@ -78,3 +100,65 @@ pub(crate) fn make_utility_function_definition(function: &UtilityFunction) -> To
     // Utility functions have no builders.
     definition.into_functions_only()
 }
+
+/// Generates the generic counterpart of a `Variant`-based utility function, see [`special_cases::is_utility_function_generic`].
+fn make_typed_utility_function_definition(function: &UtilityFunction) -> Option<TokenStream> {
+    let function_name_str = function.name();
+    if !special_cases::is_utility_function_generic(function_name_str) {
+        return None;
+    }
+
+    let is_variant = |ty: &RustTy| matches!(ty, RustTy::BuiltinIdent(ident) if ident == "Variant");
+
+    // Skip if a future Godot version changes the signature.
+    let has_variant_signature = function
+        .params()
+        .iter()
+        .all(|param| is_variant(&param.type_))
+        && function
+            .return_value()
+            .type_
+            .as_ref()
+            .is_some_and(is_variant);
+
+    if !has_variant_signature {
+        return None;
+    }
+
+    let fn_name = make_utility_function_ptr_name(function_name_str);
+    let param_names = function
+        .params()
+        .iter()
+        .map(|param| &param.name)
+        .collect::<Vec<_>>();
+
+    let doc = format!(
+        "Generic version of [`{function_name_str}()`][super::{fn_name}], converting from and to `T`."
+    );
+
+    let definition = if function.is_vararg() {
+        quote! {
+            #[doc = #doc]
+            ///
+            /// # Panics
+            /// If the result cannot be converted back to `T`.
+            pub fn #fn_name<T: ToGodot + FromGodot>(#( #param_names: T, )* rest: &[T]) -> T {
+                let rest = rest.iter().map(ToGodot::to_variant).collect::<Vec<_>>();
+
+                super::#fn_name(#( #param_names.to_variant(), )* &rest).to::<T>()
+            }
+        }
+    } else {
+        quote! {
+            #[doc = #doc]
+            ///
+            /// # Panics
+            /// If the result cannot be converted back to `T`.
+            pub fn #fn_name<T: ToGodot + FromGodot>(#( #param_names: T ),*) -> T {
+                super::#fn_name(#( #param_names.to_variant() ),*).to::<T>()
+            }
+        }
+    };
+
+    Some(definition)
+}
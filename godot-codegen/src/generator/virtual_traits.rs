@@ -114,7 +114,8 @@ fn make_special_virtual_methods(notification_enum_name: &Ident) -> TokenStream {
         ///
         /// See also in Godot docs:
         /// * [`Object::_get_property_list`](https://docs.godotengine.org/en/latest/classes/class_object.html#class-object-private-method-get-property-list)
-        #[cfg(since_api = "4.3")]
+        ///
+        /// Before Godot 4.3, classes without `#[class(tool)]` report no additional properties while running in the editor.
         fn get_property_list(&mut self) -> Vec<crate::meta::PropertyInfo> {
             unimplemented!()
         }
@@ -734,6 +734,15 @@ impl ClassCodegenLevel {
         [Self::Servers, Self::Scene, Self::Editor]
     }
 
+    /// Variant of `sys::ClassApiLevel` that loads this level's method table.
+    pub fn api_level_variant(self) -> Ident {
+        match self {
+            Self::Servers => ident("Server"),
+            Self::Scene => ident("Scene"),
+            Self::Editor => ident("Editor"),
+        }
+    }
+
     pub fn table_global_getter(self) -> Ident {
         format_ident!("class_{}_api", self.lower())
     }
@@ -15,7 +15,8 @@ use crate::models::domain::{
 use crate::models::json::{
     JsonBuiltinClass, JsonBuiltinMethod, JsonBuiltinSizes, JsonClass, JsonClassConstant,
     JsonClassMethod, JsonConstructor, JsonEnum, JsonEnumConstant, JsonExtensionApi, JsonHeader,
-    JsonMethodReturn, JsonNativeStructure, JsonOperator, JsonSingleton, JsonUtilityFunction,
+    JsonMethodArg, JsonMethodReturn, JsonNativeStructure, JsonOperator, JsonSingleton,
+    JsonUtilityFunction,
 };
 use crate::util::{get_api_level, ident, option_as_slice};
 use crate::{conv, special_cases};
@@ -459,7 +460,7 @@ impl ClassMethod {
             common: FunctionCommon {
                 name: rust_method_name.to_string(),
                 godot_name: godot_method_name,
-                parameters: FnParam::new_range(&method.arguments, ctx),
+                parameters: Self::make_params(method, class_name, ctx),
                 return_value: FnReturn::new(&method.return_value, ctx),
                 is_vararg: method.is_vararg,
                 is_private,
@@ -470,6 +471,34 @@ impl ClassMethod {
         })
     }
 
+    fn make_params(
+        method: &JsonClassMethod,
+        class_name: &TyName,
+        ctx: &mut Context,
+    ) -> Vec<FnParam> {
+        option_as_slice(&method.arguments)
+            .iter()
+            .map(|arg| {
+                // Some parameters are declared as int in the JSON, but documented as enums.
+                match special_cases::get_class_method_param_enum_replacement(
+                    class_name,
+                    &method.name,
+                    &arg.name,
+                ) {
+                    Some(enum_ty) => {
+                        let replaced = JsonMethodArg {
+                            type_: enum_ty.to_string(),
+                            meta: None,
+                            ..arg.clone()
+                        };
+                        FnParam::new(&replaced, ctx)
+                    }
+                    None => FnParam::new(arg, ctx),
+                }
+            })
+            .collect()
+    }
+
     fn make_virtual_method_name(godot_method_name: &str) -> &str {
         // Remove leading underscore from virtual method names.
         let method_name = godot_method_name
@@ -525,7 +554,8 @@ impl UtilityFunction {
 impl Enum {
     pub fn from_json(json_enum: &JsonEnum, surrounding_class: Option<&TyName>) -> Self {
         let godot_name = &json_enum.name;
-        let is_bitfield = json_enum.is_bitfield;
+        let is_bitfield = special_cases::is_enum_bitfield(surrounding_class, godot_name)
+            .unwrap_or(json_enum.is_bitfield);
         let is_private = special_cases::is_enum_private(surrounding_class, godot_name);
         let is_exhaustive = special_cases::is_enum_exhaustive(surrounding_class, godot_name);
 
@@ -123,6 +123,11 @@ const SELECTED_CLASSES: &[&str] = &[
     "AnimatedSprite2D",
     "Area2D",
     "ArrayMesh",
+    "AudioStream",
+    "AudioStreamGenerator",
+    "AudioStreamGeneratorPlayback",
+    "AudioStreamPlayback",
+    "AudioStreamPlaybackResampled",
     "AudioStreamPlayer",
     "BaseButton",
     "BoxMesh",
@@ -131,11 +136,19 @@ const SELECTED_CLASSES: &[&str] = &[
     "Camera3D",
     "CanvasItem",
     "CanvasLayer",
+    "CircleShape2D",
     "ClassDB",
     "CollisionObject2D",
+    "CollisionObject3D",
     "CollisionShape2D",
+    "Container",
     "Control",
+    "EditorImportPlugin",
+    "EditorInspectorPlugin",
+    "EditorInterface",
     "EditorPlugin",
+    "EditorProperty",
+    "EditorSettings",
     "Engine",
     "FileAccess",
     "GDScript",
@@ -145,10 +158,30 @@ const SELECTED_CLASSES: &[&str] = &[
     "Input",
     "InputEvent",
     "InputEventAction",
+    "InputEventFromWindow",
+    "InputEventGesture",
+    "InputEventJoypadButton",
+    "InputEventJoypadMotion",
+    "InputEventKey",
+    "InputEventMagnifyGesture",
+    "InputEventMIDI",
+    "InputEventMouse",
+    "InputEventMouseButton",
+    "InputEventMouseMotion",
+    "InputEventPanGesture",
+    "InputEventScreenDrag",
+    "InputEventScreenTouch",
+    "InputEventShortcut",
+    "InputEventWithModifiers",
     "Label",
     "MainLoop",
     "Marker2D",
+    "Material",
     "Mesh",
+    "NavigationMesh",
+    "NavigationPolygon",
+    "NavigationServer2D",
+    "NavigationServer3D",
     "Node",
     "Node2D",
     "Node3D",
@@ -158,7 +191,14 @@ const SELECTED_CLASSES: &[&str] = &[
     "PackedScene",
     "PathFollow2D",
     "PhysicsBody2D",
+    "PhysicsDirectSpaceState2D",
+    "PhysicsDirectSpaceState3D",
+    "PhysicsRayQueryParameters2D",
+    "PhysicsRayQueryParameters3D",
+    "PhysicsShapeQueryParameters2D",
+    "PhysicsShapeQueryParameters3D",
     "PrimitiveMesh",
+    "ProjectSettings",
     "RefCounted",
     "RenderingServer",
     "Resource",
@@ -166,11 +206,17 @@ const SELECTED_CLASSES: &[&str] = &[
     "ResourceLoader",
     "ResourceSaver",
     "RigidBody2D",
+    "SceneReplicationConfig",
+    "SceneState",
     "SceneTree",
     "SceneTreeTimer",
     "Script",
     "ScriptExtension",
     "ScriptLanguage",
+    "Shader",
+    "ShaderMaterial",
+    "Shape2D",
+    "Shape3D",
     "Sprite2D",
     "SpriteFrames",
     "TextServer",
@@ -182,4 +228,6 @@ const SELECTED_CLASSES: &[&str] = &[
     "Timer",
     "Viewport",
     "Window",
+    "World2D",
+    "World3D",
 ];
@@ -298,6 +298,55 @@ pub fn is_utility_function_deleted(function: &JsonUtilityFunction, ctx: &mut Con
     hardcoded ||*/ codegen_special_cases::is_utility_function_excluded(function, ctx)
 }
 
+/// Whether a utility function operates on numbers and vectors alike, and thus takes and returns `Variant`.
+///
+/// For these, a generic counterpart is generated in `godot::global::typed`, which converts from and to a single Rust type `T`.
+/// Functions like `lerp()` are not listed, since not all of their parameters share the same type. Keep the list in the docs of the
+/// generated `typed` module in sync.
+#[rustfmt::skip]
+pub fn is_utility_function_generic(godot_function_name: &str) -> bool {
+    matches!(godot_function_name,
+        | "abs"
+        | "ceil"
+        | "clamp"
+        | "floor"
+        | "max"
+        | "min"
+        | "round"
+        | "sign"
+        | "snapped"
+        | "wrap"
+    )
+}
+
+/// For certain class methods, replaces an `int` parameter with an enum or bitfield type.
+///
+/// Godot's API JSON declares some parameters as `int`, although the class reference documents them as taking values of an enum. This
+/// is mostly the case for flags, which are OR-ed together in GDScript. Returns the replacement type in JSON notation, e.g.
+/// `"bitfield::Object.ConnectFlags"`.
+///
+/// This list is curated and not complete: it covers the `int` parameters known to take enum values, and grows as more are found. Most
+/// enum and bitfield parameters are already typed in the API JSON and need no entry here.
+///
+/// Changing a parameter type is a breaking change for generated APIs (e.g. `connect_ex().flags()` takes `ConnectFlags` instead of
+/// `u32`), so all callers in godot-rust itself must be migrated along with a new entry.
+#[rustfmt::skip]
+pub fn get_class_method_param_enum_replacement(class_name: &TyName, godot_method_name: &str, param_name: &str) -> Option<&'static str> {
+    let replacement = match (class_name.godot_ty.as_str(), godot_method_name, param_name) {
+        | ("Object", "connect", "flags") => "bitfield::Object.ConnectFlags",
+
+        | ("Node", "duplicate", "flags") => "bitfield::Node.DuplicateFlags",
+
+        | ("SceneTree", "call_group_flags", "flags")
+        | ("SceneTree", "notify_group_flags", "call_flags")
+        | ("SceneTree", "set_group_flags", "call_flags") => "bitfield::SceneTree.GroupCallFlags",
+
+        _ => return None,
+    };
+
+    Some(replacement)
+}
+
 pub fn maybe_rename_class_method<'m>(class_name: &TyName, godot_method_name: &'m str) -> &'m str {
     match (class_name.godot_ty.as_str(), godot_method_name) {
         // GDScript, GDScriptNativeClass, possibly more in the future
@@ -334,6 +383,12 @@ pub fn get_interface_extra_docs(trait_name: &str) -> Option<&'static str> {
         "IScriptExtension" => {
             Some("Use this in combination with the [`obj::script` module][crate::obj::script].")
         }
+        "IRigidBody2D" => {
+            Some("In `integrate_forces()`, [`PhysicsDirectBodyState2D::contacts()`][crate::classes::PhysicsDirectBodyState2D::contacts] collects all contacts into a `Vec`.")
+        }
+        "IRigidBody3D" => {
+            Some("In `integrate_forces()`, [`PhysicsDirectBodyState3D::contacts()`][crate::classes::PhysicsDirectBodyState3D::contacts] collects all contacts into a `Vec`.")
+        }
 
         _ => None,
     }
@@ -407,3 +462,23 @@ pub fn is_enum_exhaustive(class_name: Option<&TyName>, enum_name: &str) -> bool
         => true, _ => false
     }
 }
+
+/// Overrides whether an enum is generated as a bitfield.
+///
+/// A few enums are declared as regular enums in Godot, but their values are meant to be combined with `|`.
+/// Returns `None` to keep the declaration from the API JSON.
+///
+/// Like all non-exhaustive engine enums, bitfields are generated as newtype structs with associated constants rather than Rust `enum`s.
+/// Their field is private, so they behave like `#[non_exhaustive]` types: Godot adding flags in later versions does not break users.
+#[rustfmt::skip]
+pub fn is_enum_bitfield(class_name: Option<&TyName>, enum_name: &str) -> Option<bool> {
+    let class_name = class_name.map(|ty| ty.godot_ty.as_str());
+
+    match (class_name, enum_name) {
+        | (Some("Object"), "ConnectFlags")
+        | (Some("Node"), "DuplicateFlags")
+        | (Some("SceneTree"), "GroupCallFlags")
+
+        => Some(true), _ => None
+    }
+}
@@ -17,4 +17,20 @@ fn main() {
     println!("cargo:rerun-if-changed=build.rs");
 
     godot_bindings::emit_godot_version_cfg();
+    emit_rustc_version();
+}
+
+/// Makes the compiler version available as `GODOT_RUST_RUSTC_VERSION`, for the layout hash of `#[class(shared)]` classes.
+fn emit_rustc_version() {
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+
+    let version = std::process::Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    println!("cargo:rustc-env=GODOT_RUST_RUSTC_VERSION={version}");
 }
@@ -461,6 +461,32 @@ impl Basis {
         self.rows[0].is_finite() && self.rows[1].is_finite() && self.rows[2].is_finite()
     }
 
+    /// Transforms all `vectors` in place, equivalent to `*vector = self * *vector` for each element.
+    ///
+    /// With the `fast-math` feature (in single precision), vectors are transformed four at a time using SIMD instructions.
+    pub fn xform_vectors(&self, vectors: &mut [Vector3]) {
+        #[cfg(all(feature = "fast-math", not(feature = "double-precision")))]
+        crate::builtin::math::xform(self, Vector3::ZERO, vectors);
+
+        #[cfg(not(all(feature = "fast-math", not(feature = "double-precision"))))]
+        for vector in vectors {
+            *vector = *self * *vector;
+        }
+    }
+
+    /// Multiplies `self` with all `bases` in place, equivalent to `*basis = self * *basis` for each element.
+    ///
+    /// With the `fast-math` feature (in single precision), the products are computed using glam's SIMD matrix representation.
+    pub fn mul_all(&self, bases: &mut [Basis]) {
+        #[cfg(all(feature = "fast-math", not(feature = "double-precision")))]
+        crate::builtin::math::mul_bases(self, bases);
+
+        #[cfg(not(all(feature = "fast-math", not(feature = "double-precision"))))]
+        for basis in bases {
+            *basis = *self * *basis;
+        }
+    }
+
     /// Returns the first column of the matrix,
     ///
     /// _Godot equivalent: `Basis.x`_, see [`Basis`] for why it's changed
@@ -841,6 +867,36 @@ mod test {
         );
     }
 
+    #[test]
+    fn batch_operations() {
+        let basis = Basis::from_euler(EulerOrder::XYZ, Vector3::new(0.5, -1.0, 2.0));
+
+        let vectors = [
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(-4.0, 0.5, 0.0),
+            Vector3::ZERO,
+            Vector3::new(0.0, -7.0, 2.5),
+            Vector3::ONE,
+            Vector3::new(9.0, 8.0, -7.0),
+        ];
+        let mut batch = vectors;
+        basis.xform_vectors(&mut batch);
+        for (original, transformed) in vectors.iter().zip(batch.iter()) {
+            assert_eq_approx!(*transformed, basis * *original);
+        }
+
+        let bases = [
+            Basis::IDENTITY,
+            Basis::from_diagonal(2.0, -1.0, 0.5),
+            Basis::from_axis_angle(Vector3::RIGHT, FRAC_PI_2),
+        ];
+        let mut batch = bases;
+        basis.mul_all(&mut batch);
+        for (original, multiplied) in bases.iter().zip(batch.iter()) {
+            assert_eq_approx!(*multiplied, basis * *original);
+        }
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
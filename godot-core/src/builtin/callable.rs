@@ -7,11 +7,13 @@
 
 use godot_ffi as sys;
 
-use crate::builtin::{inner, StringName, Variant, VariantArray};
+use crate::builtin::{inner, Dictionary, StringName, Variant, VariantArray, VariantType};
 use crate::classes::Object;
+use crate::global::MethodFlags;
+use crate::meta::error::CallableError;
 use crate::meta::{GodotType, ToGodot};
 use crate::obj::bounds::DynMemory;
-use crate::obj::Bounds;
+use crate::obj::{Bounds, EngineBitfield, EngineEnum};
 use crate::obj::{Gd, GodotClass, InstanceId};
 use std::{fmt, ptr};
 use sys::{ffi_methods, GodotFfi};
@@ -56,6 +58,37 @@ impl Callable {
         }
     }
 
+    /// Create a callable for the method `object::method_name`, verifying that the method exists and accepts `arg_count` arguments.
+    ///
+    /// Unlike [`from_object_method()`][Self::from_object_method], a misspelled method name or wrong arity is reported immediately,
+    /// instead of when the callable is invoked (e.g. when a connected signal is emitted). Script methods are taken into account.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # fn connect(node: Gd<Node>) {
+    /// let callable = Callable::from_object_method_checked(&node, "on_timeout", 0)
+    ///     .unwrap_or_else(|err| panic!("cannot connect: {err}"));
+    /// # }
+    /// ```
+    pub fn from_object_method_checked<T, S>(
+        object: &Gd<T>,
+        method_name: S,
+        arg_count: usize,
+    ) -> Result<Self, CallableError>
+    where
+        T: GodotClass,
+        S: Into<StringName>,
+    {
+        let method = method_name.into();
+        let object_ref = object.raw.as_object();
+
+        let signature = MethodSignature::find(object_ref, &method)?;
+        signature.check_arg_count(arg_count)?;
+
+        Ok(Self::from_object_method(object, method))
+    }
+
     #[cfg(since_api = "4.2")]
     fn default_callable_custom_info() -> sys::GDExtensionCallableCustomInfo {
         sys::GDExtensionCallableCustomInfo {
@@ -112,6 +145,53 @@ impl Callable {
         Self::from_custom_info(info)
     }
 
+    /// Create a callable from a Rust function or closure that is not thread-safe.
+    ///
+    /// Like [`from_fn()`][Self::from_fn], but `rust_function` does not need to be `Send + Sync`, so it can capture e.g. `Rc` or `Gd`.
+    /// In return, the callable must only be invoked on the thread that created it; calls from other threads fail with an error. The same
+    /// applies to recursive calls, i.e. invoking the callable from within `rust_function`. If Godot destroys the callable on another
+    /// thread, the closure is leaked instead of dropped.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    ///
+    /// let count = Rc::new(Cell::new(0));
+    /// let count_in_fn = count.clone();
+    /// let callable = Callable::from_local_fn("increment", move |_args: &[&Variant]| {
+    ///     count_in_fn.set(count_in_fn.get() + 1);
+    ///     Ok(Variant::nil())
+    /// });
+    /// ```
+    #[cfg(since_api = "4.2")]
+    pub fn from_local_fn<F, S>(name: S, rust_function: F) -> Self
+    where
+        F: 'static + FnMut(&[&Variant]) -> Result<Variant, ()>,
+        S: Into<crate::builtin::GString>,
+    {
+        let userdata = CallableUserdata {
+            inner: FnWrapper {
+                rust_function: LocalFn {
+                    function: std::cell::RefCell::new(rust_function),
+                    thread: std::thread::current().id(),
+                },
+                name: name.into(),
+            },
+        };
+
+        let info = sys::GDExtensionCallableCustomInfo {
+            callable_userdata: Box::into_raw(Box::new(userdata)) as *mut std::ffi::c_void,
+            call_func: Some(rust_callable_call_local_fn::<F>),
+            free_func: Some(rust_callable_destroy_local_fn::<F>),
+            to_string_func: Some(rust_callable_to_string_named::<LocalFn<F>>),
+            ..Self::default_callable_custom_info()
+        };
+
+        Self::from_custom_info(info)
+    }
+
     /// Create a highly configurable callable from Rust.
     ///
     /// See [`RustCallable`] for requirements on the type.
@@ -180,6 +260,46 @@ impl Callable {
         self.as_inner().bindv(arguments)
     }
 
+    /// Returns a copy of this callable with `args` bound, after checking them against the target method's signature.
+    ///
+    /// Like GDScript's `bind()`, bound arguments are appended after the arguments passed at call time. For callables referring to an
+    /// object method, the number and types of `args` are verified against the method's trailing parameters, so mismatches are reported here
+    /// rather than on invocation. Parameters of type `Variant` accept any value, `float` accepts `int`, and `String`/`StringName`
+    /// are interchangeable.
+    ///
+    /// Custom callables (lambdas, closures, already bound callables) don't expose a signature; their arguments are bound unchecked.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # fn connect(node: Gd<Node>) {
+    /// // Method declared as `fn on_hit(&mut self, damage: i32, source: GString)`.
+    /// let callable = node.callable("on_hit").bind_typed((10, GString::from("trap"))).expect("signature matches");
+    /// # }
+    /// ```
+    pub fn bind_typed<A: CallableArgs>(&self, args: A) -> Result<Self, CallableError> {
+        let args = args.to_variant_array();
+
+        if !self.is_custom() {
+            if let (Some(object), Some(method)) = (self.object(), self.method_name()) {
+                let signature = MethodSignature::find(&object, &method)?;
+                signature.check_bound_args(&args)?;
+            }
+        }
+
+        Ok(self.bindv(args))
+    }
+
+    /// Returns a copy of this callable that ignores the last `count` arguments passed at call time.
+    ///
+    /// Useful to connect a signal to a method with fewer parameters than the signal.
+    ///
+    /// _Godot equivalent: `unbind`_
+    pub fn unbind(&self, count: usize) -> Self {
+        let count = i64::try_from(count).expect("unbind count fits in i64");
+        self.as_inner().unbind(count)
+    }
+
     /// Returns the name of the method represented by this callable. If the callable is a lambda function,
     /// returns the function's name.
     ///
@@ -330,6 +450,169 @@ impl fmt::Display for Callable {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Typed argument binding
+
+/// Tuple of arguments that can be bound to a [`Callable`] with [`Callable::bind_typed()`].
+///
+/// Implemented for tuples of up to 8 elements, each implementing [`ToGodot`].
+pub trait CallableArgs {
+    /// Converts the arguments to a variant array, in order.
+    fn to_variant_array(&self) -> VariantArray;
+}
+
+macro_rules! impl_callable_args_for_tuple {
+    ($($Arg:ident: $index:tt),*) => {
+        impl<$($Arg: ToGodot),*> CallableArgs for ($($Arg,)*) {
+            fn to_variant_array(&self) -> VariantArray {
+                #[allow(unused_mut)]
+                let mut array = VariantArray::new();
+                $( array.push(self.$index.to_variant()); )*
+                array
+            }
+        }
+    };
+}
+
+impl_callable_args_for_tuple!();
+impl_callable_args_for_tuple!(A0: 0);
+impl_callable_args_for_tuple!(A0: 0, A1: 1);
+impl_callable_args_for_tuple!(A0: 0, A1: 1, A2: 2);
+impl_callable_args_for_tuple!(A0: 0, A1: 1, A2: 2, A3: 3);
+impl_callable_args_for_tuple!(A0: 0, A1: 1, A2: 2, A3: 3, A4: 4);
+impl_callable_args_for_tuple!(A0: 0, A1: 1, A2: 2, A3: 3, A4: 4, A5: 5);
+impl_callable_args_for_tuple!(A0: 0, A1: 1, A2: 2, A3: 3, A4: 4, A5: 5, A6: 6);
+impl_callable_args_for_tuple!(A0: 0, A1: 1, A2: 2, A3: 3, A4: 4, A5: 5, A6: 6, A7: 7);
+
+/// Parameter information of an object method, as reported by `Object::get_method_list()`.
+struct MethodSignature {
+    method: String,
+    param_types: Vec<VariantType>,
+    default_count: usize,
+    is_vararg: bool,
+}
+
+impl MethodSignature {
+    fn find(object: &Object, method: &StringName) -> Result<Self, CallableError> {
+        let method_str = method.to_string();
+
+        let info = object.get_method_list().iter_shared().find(|info| {
+            info.get("name")
+                .is_some_and(|name| name.stringify().to_string() == method_str)
+        });
+
+        let Some(info) = info else {
+            return Err(CallableError::MethodNotFound {
+                class: object.get_class().to_string(),
+                method: method_str,
+            });
+        };
+
+        let param_types = array_field(&info, "args")
+            .iter_shared()
+            .map(|arg| {
+                let ty = arg
+                    .try_to::<Dictionary>()
+                    .ok()
+                    .and_then(|arg| arg.get("type"))
+                    .and_then(|ty| ty.try_to::<i32>().ok())
+                    .unwrap_or(0);
+
+                VariantType::try_from_ord(ty).unwrap_or(VariantType::NIL)
+            })
+            .collect();
+
+        let flags = info
+            .get("flags")
+            .and_then(|flags| flags.try_to::<u64>().ok())
+            .unwrap_or(0);
+
+        Ok(Self {
+            method: method_str,
+            param_types,
+            default_count: array_field(&info, "default_args").len(),
+            is_vararg: flags & MethodFlags::VARARG.ord() != 0,
+        })
+    }
+
+    fn max_args(&self) -> usize {
+        self.param_types.len()
+    }
+
+    fn min_args(&self) -> usize {
+        self.param_types.len().saturating_sub(self.default_count)
+    }
+
+    fn check_arg_count(&self, arg_count: usize) -> Result<(), CallableError> {
+        let (min, max) = (self.min_args(), self.max_args());
+
+        if arg_count < min || (arg_count > max && !self.is_vararg) {
+            return Err(CallableError::ArgumentCount {
+                method: self.method.clone(),
+                expected: (min, max),
+                actual: arg_count,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Checks arguments that are bound to the last parameters.
+    fn check_bound_args(&self, args: &VariantArray) -> Result<(), CallableError> {
+        let bound_count = args.len();
+        if bound_count > self.max_args() {
+            if self.is_vararg {
+                return Ok(());
+            }
+
+            return Err(CallableError::ArgumentCount {
+                method: self.method.clone(),
+                expected: (self.min_args(), self.max_args()),
+                actual: bound_count,
+            });
+        }
+
+        let first_index = self.max_args() - bound_count;
+        for (offset, arg) in args.iter_shared().enumerate() {
+            let index = first_index + offset;
+            let expected = self.param_types[index];
+            let actual = arg.get_type();
+
+            if !is_arg_type_compatible(expected, actual) {
+                return Err(CallableError::ArgumentType {
+                    method: self.method.clone(),
+                    index,
+                    expected,
+                    actual,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn array_field(info: &Dictionary, key: &str) -> VariantArray {
+    info.get(key)
+        .and_then(|value| value.try_to::<VariantArray>().ok())
+        .unwrap_or_default()
+}
+
+/// Whether an argument of type `actual` can be passed to a parameter of type `expected`, mirroring Godot's implicit conversions.
+fn is_arg_type_compatible(expected: VariantType, actual: VariantType) -> bool {
+    use VariantType as T;
+
+    expected == actual
+        || expected == T::NIL // parameter of type Variant
+        || matches!(
+            (expected, actual),
+            (T::FLOAT, T::INT)
+                | (T::STRING, T::STRING_NAME)
+                | (T::STRING_NAME, T::STRING)
+                | (T::OBJECT, T::NIL)
+        )
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Callbacks for custom implementations
 
@@ -363,6 +646,18 @@ mod custom_callable {
         pub(crate) name: GString,
     }
 
+    /// Closure of [`Callable::from_local_fn()`], bound to the thread that created it.
+    pub(crate) struct LocalFn<F> {
+        pub(crate) function: std::cell::RefCell<F>,
+        pub(crate) thread: std::thread::ThreadId,
+    }
+
+    impl<F> LocalFn<F> {
+        fn is_own_thread(&self) -> bool {
+            std::thread::current().id() == self.thread
+        }
+    }
+
     /// Represents a custom callable object defined in Rust.
     ///
     /// This trait has a single method, `invoke`, which is called upon invocation.
@@ -411,6 +706,52 @@ mod custom_callable {
         crate::meta::varcall_return_checked(result, r_return, r_error);
     }
 
+    pub unsafe extern "C" fn rust_callable_call_local_fn<F>(
+        callable_userdata: *mut std::ffi::c_void,
+        p_args: *const sys::GDExtensionConstVariantPtr,
+        p_argument_count: sys::GDExtensionInt,
+        r_return: sys::GDExtensionVariantPtr,
+        r_error: *mut sys::GDExtensionCallError,
+    ) where
+        F: FnMut(&[&Variant]) -> Result<Variant, ()>,
+    {
+        let arg_refs: &[&Variant] = Variant::borrow_ref_slice(p_args, p_argument_count as usize);
+
+        // Only shared access: another thread may call concurrently, and must not touch the closure.
+        let w = &(*(callable_userdata as *const CallableUserdata<FnWrapper<LocalFn<F>>>)).inner;
+        let local = &w.rust_function;
+
+        let result = if !local.is_own_thread() {
+            crate::global::godot_error!(
+                "callable `{}` created with Callable::from_local_fn() called from another thread",
+                w.name
+            );
+            Err(())
+        } else if let Ok(mut function) = local.function.try_borrow_mut() {
+            function(arg_refs)
+        } else {
+            crate::global::godot_error!(
+                "callable `{}` created with Callable::from_local_fn() called recursively",
+                w.name
+            );
+            Err(())
+        };
+
+        crate::meta::varcall_return_checked(result, r_return, r_error);
+    }
+
+    pub unsafe extern "C" fn rust_callable_destroy_local_fn<F>(
+        callable_userdata: *mut std::ffi::c_void,
+    ) {
+        let rust_ptr = callable_userdata as *mut CallableUserdata<FnWrapper<LocalFn<F>>>;
+        let boxed = Box::from_raw(rust_ptr);
+
+        // The closure is not `Send`, so it must not be dropped on another thread.
+        if !boxed.inner.rust_function.is_own_thread() {
+            std::mem::forget(boxed);
+        }
+    }
+
     pub unsafe extern "C" fn rust_callable_destroy<T>(callable_userdata: *mut std::ffi::c_void) {
         let rust_ptr = callable_userdata as *mut CallableUserdata<T>;
         let _drop = Box::from_raw(rust_ptr);
@@ -12,6 +12,7 @@ use crate::builtin::*;
 use crate::meta::error::{ConvertError, FromGodotError, FromVariantError};
 use crate::meta::{
     ArrayElement, ArrayTypeInfo, FromGodot, GodotConvert, GodotFfiVariant, GodotType, ToGodot,
+    VecRepresentation,
 };
 use crate::obj::EngineEnum;
 use crate::registry::property::{
@@ -661,6 +662,18 @@ impl<T: ArrayElement> Array<T> {
         ptr
     }
 
+    /// Returns a reference to the element at the given index, without cloning it.
+    ///
+    /// The array must not be modified (also not through other references to the same array) while the returned reference is alive.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub(crate) fn variant_ref(&self, index: usize) -> &Variant {
+        // SAFETY: ptr() returns a valid pointer to an element, which lives as long as the array is not modified.
+        unsafe { Variant::borrow_var_sys(self.ptr(index)) }
+    }
+
     /// Returns a pointer to the element at the given index, or null if out of bounds.
     fn ptr_or_null(&self, index: usize) -> sys::GDExtensionConstVariantPtr {
         // SAFETY: array_operator_index_const returns null for invalid indexes.
@@ -740,7 +753,7 @@ impl<T: ArrayElement> Array<T> {
     }
 
     /// Returns the runtime type info of this array.
-    fn type_info(&self) -> ArrayTypeInfo {
+    pub(crate) fn type_info(&self) -> ArrayTypeInfo {
         let variant_type = VariantType::from_sys(
             self.as_inner().get_typed_builtin() as sys::GDExtensionVariantType
         );
@@ -753,18 +766,35 @@ impl<T: ArrayElement> Array<T> {
     }
 
     /// Checks that the inner array has the correct type set on it for storing elements of type `T`.
+    ///
+    /// With the `conversion-paths` feature, the error is annotated with the index of the first element that cannot be converted to `T`.
     fn with_checked_type(self) -> Result<Self, ConvertError> {
         let self_ty = self.type_info();
         let target_ty = ArrayTypeInfo::of::<T>();
 
         if self_ty == target_ty {
-            Ok(self)
+            return Ok(self);
+        }
+
+        let first_incompatible = if cfg!(feature = "conversion-paths") {
+            // Only read from, so the actual element type does not matter.
+            let elements = VariantArray::from_variant_unchecked_type(&self.to_variant())?;
+            elements
+                .iter_shared()
+                .position(|element| T::try_from_variant(&element).is_err())
         } else {
-            Err(FromGodotError::BadArrayType {
-                expected: target_ty,
-                actual: self_ty,
-            }
-            .into_error(self))
+            None
+        };
+
+        let err = FromGodotError::BadArrayType {
+            expected: target_ty,
+            actual: self_ty,
+        }
+        .into_error(self);
+
+        match first_incompatible {
+            Some(index) => Err(err.at_index(index)),
+            None => Err(err),
         }
     }
 
@@ -794,6 +824,68 @@ impl<T: ArrayElement> Array<T> {
     }
 }
 
+impl VariantArray {
+    /// Creates an empty array with the given runtime element type, e.g. read from a text format.
+    ///
+    /// # Safety
+    ///
+    /// The array violates the invariant of `VariantArray`, as it only accepts elements of the given type. It must only be filled with
+    /// matching elements (the engine rejects others) and then be converted to `Variant`, without being handed out as `VariantArray`.
+    pub(crate) unsafe fn new_with_runtime_type(
+        element_type: VariantType,
+        class_name: &StringName,
+    ) -> Self {
+        let mut array = Self::new();
+        let script = Variant::nil();
+
+        // SAFETY: The array is a newly created empty untyped array.
+        unsafe {
+            interface_fn!(array_set_typed)(
+                array.sys_mut(),
+                element_type.sys(),
+                class_name.string_sys(),
+                script.var_sys(),
+            );
+        }
+
+        array
+    }
+
+    /// Converts a variant holding any array (typed or not) into a `VariantArray` referring to the same storage.
+    ///
+    /// Returns `None` if the variant does not hold an array.
+    ///
+    /// # Safety
+    ///
+    /// The runtime type of the array is not checked. The result must only be used for reading, since writes may violate the array's
+    /// element type (see [`as_inner_mut()`](Self::as_inner_mut)).
+    pub(crate) unsafe fn from_variant_unchecked(variant: &Variant) -> Option<Self> {
+        if variant.get_type() != VariantType::ARRAY {
+            return None;
+        }
+
+        let array = unsafe {
+            sys::new_with_uninit_or_init::<Self>(|self_ptr| {
+                let array_from_variant = sys::builtin_fn!(array_from_variant);
+                array_from_variant(self_ptr, sys::SysPtr::force_mut(variant.var_sys()));
+            })
+        };
+
+        Some(array)
+    }
+
+    /// Converts each element to `U`, collecting the results in a `Vec`.
+    ///
+    /// Fails on the first element that cannot be converted. The error is annotated with its index (see [`ConvertError::at_index()`]),
+    /// so that failures in nested structures can be located.
+    pub fn try_to_vec<U: FromGodot>(&self) -> Result<Vec<U>, ConvertError> {
+        self.iter_shared()
+            .enumerate()
+            .map(|(index, element)| element.try_to::<U>().map_err(|err| err.at_index(index)))
+            .collect()
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Traits
 
@@ -824,7 +916,9 @@ unsafe impl<T: ArrayElement> GodotFfi for Array<T> {
 }
 
 // Only implement for untyped arrays; typed arrays cannot be nested in Godot.
-impl ArrayElement for VariantArray {}
+impl ArrayElement for VariantArray {
+    type __VecVia = Array<Self>;
+}
 
 impl<T: ArrayElement> GodotConvert for Array<T> {
     type Via = Self;
@@ -971,6 +1065,8 @@ impl<T: ArrayElement> Default for Array<T> {
 impl<T: ArrayElement> Drop for Array<T> {
     #[inline]
     fn drop(&mut self) {
+        sys::alloc_stats::on_destruct::<Self>();
+
         unsafe {
             let array_destroy = sys::builtin_fn!(array_destroy);
             array_destroy(self.sys_mut());
@@ -997,6 +1093,20 @@ impl<T: ArrayElement> GodotType for Array<T> {
     fn godot_type_name() -> String {
         "Array".to_string()
     }
+
+    /// Typed arrays are declared as `Array[T]` in method and signal signatures, so that Godot checks element types of arguments.
+    #[cfg(since_api = "4.2")]
+    fn property_info(property_name: &str) -> crate::meta::PropertyInfo {
+        crate::meta::PropertyInfo {
+            variant_type: VariantType::ARRAY,
+            class_name: crate::meta::ClassName::none(),
+            property_name: StringName::from(property_name),
+            hint: crate::global::PropertyHint::NONE,
+            hint_string: GString::new(),
+            usage: crate::global::PropertyUsageFlags::DEFAULT,
+        }
+        .with_hint_info(<Self as Var>::property_hint())
+    }
 }
 
 impl<T: ArrayElement> GodotFfiVariant for Array<T> {
@@ -1010,6 +1120,16 @@ impl<T: ArrayElement> GodotFfiVariant for Array<T> {
     }
 
     fn ffi_from_variant(variant: &Variant) -> Result<Self, ConvertError> {
+        Self::from_variant_unchecked_type(variant)?.with_checked_type()
+    }
+}
+
+impl<T: ArrayElement> Array<T> {
+    /// Converts a variant holding an array of any element type, without checking that type against `T`.
+    ///
+    /// The result violates the safety invariant of `Array` unless followed by [`with_checked_type()`][Self::with_checked_type], or
+    /// `T` is `Variant` and the array is only read from.
+    fn from_variant_unchecked_type(variant: &Variant) -> Result<Self, ConvertError> {
         if variant.get_type() != Self::variant_type() {
             return Err(FromVariantError::BadType {
                 expected: Self::variant_type(),
@@ -1025,7 +1145,7 @@ impl<T: ArrayElement> GodotFfiVariant for Array<T> {
             })
         };
 
-        array.with_checked_type()
+        Ok(array)
     }
 }
 
@@ -1103,6 +1223,132 @@ impl<T: ArrayElement + FromGodot> From<&Array<T>> for Vec<T> {
     }
 }
 
+/// Converts this array to a fixed-size Rust array.
+///
+/// Fails if the length of the array is not `N`, or if an element cannot be converted.
+impl<T: ArrayElement + FromGodot, const N: usize> TryFrom<&Array<T>> for [T; N] {
+    type Error = ConvertError;
+
+    fn try_from(array: &Array<T>) -> Result<Self, Self::Error> {
+        if array.len() != N {
+            return Err(FromGodotError::BadArrayLength {
+                expected: N,
+                actual: array.len(),
+            }
+            .into_error(array.clone()));
+        }
+
+        let vec = array.try_to_vec::<T>()?;
+        Ok(vec_into_array(vec))
+    }
+}
+
+/// Converts a `Vec` whose length has already been checked to be `N`.
+pub(crate) fn vec_into_array<T, const N: usize>(vec: Vec<T>) -> [T; N] {
+    match vec.try_into() {
+        Ok(array) => array,
+        Err(vec) => unreachable!("length {} checked to be {N}", vec.len()),
+    }
+}
+
+impl Array<GString> {
+    /// Converts this array to a vector of Rust strings.
+    pub fn to_vec_string(&self) -> Vec<String> {
+        self.iter_shared().map(String::from).collect()
+    }
+
+    /// Appends all strings of `iter`, allocating the array storage at once for iterators with known length.
+    fn extend_str<S: AsRef<str>>(&mut self, iter: impl IntoIterator<Item = S>) {
+        let mut iter = iter.into_iter();
+        let start = self.len();
+        let (lower_bound, _) = iter.size_hint();
+
+        if lower_bound > 0 {
+            // SAFETY: The new elements are all overwritten with strings below, or removed again.
+            unsafe { self.as_inner_mut() }.resize(to_i64(start + lower_bound));
+
+            // SAFETY: `self` has at least `start + lower_bound` elements, which are all valid `Variant`s. The array is not accessed
+            // otherwise while the slice exists.
+            let elements = unsafe { Variant::borrow_slice_mut(self.ptr_mut(start), lower_bound) };
+
+            let mut written = 0;
+            for (slot, s) in elements.iter_mut().zip(&mut iter) {
+                *slot = GString::from(s.as_ref()).to_variant();
+                written += 1;
+            }
+
+            // `size_hint()` is not trusted to be correct.
+            self.shrink(start + written);
+        }
+
+        for s in iter {
+            self.push(GString::from(s.as_ref()));
+        }
+    }
+}
+
+/// Creates an `Array<GString>` from Rust string slices.
+impl<'a> FromIterator<&'a str> for Array<GString> {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut array = Self::new();
+        array.extend_str(iter);
+        array
+    }
+}
+
+/// Creates an `Array<GString>` from Rust strings.
+impl FromIterator<String> for Array<GString> {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        let mut array = Self::new();
+        array.extend_str(iter);
+        array
+    }
+}
+
+/// Extends an `Array<GString>` with Rust string slices.
+impl<'a> Extend<&'a str> for Array<GString> {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        self.extend_str(iter);
+    }
+}
+
+/// Extends an `Array<GString>` with Rust strings.
+impl Extend<String> for Array<GString> {
+    fn extend<I: IntoIterator<Item = String>>(&mut self, iter: I) {
+        self.extend_str(iter);
+    }
+}
+
+/// Creates an `Array<GString>` from the given string slices.
+impl From<&[&str]> for Array<GString> {
+    fn from(slice: &[&str]) -> Self {
+        slice.iter().copied().collect()
+    }
+}
+
+/// Creates an `Array<GString>` from the given strings.
+impl From<&[String]> for Array<GString> {
+    fn from(slice: &[String]) -> Self {
+        let mut array = Self::new();
+        array.extend_str(slice);
+        array
+    }
+}
+
+/// Creates an `Array<GString>` from the given strings.
+impl From<Vec<String>> for Array<GString> {
+    fn from(vec: Vec<String>) -> Self {
+        Self::from(vec.as_slice())
+    }
+}
+
+/// Converts the `Array<GString>` to a vector of Rust strings.
+impl From<&Array<GString>> for Vec<String> {
+    fn from(array: &Array<GString>) -> Self {
+        array.to_vec_string()
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 /// An iterator over typed elements of an [`Array`].
@@ -1301,3 +1547,109 @@ mod serialize {
         }
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Vec<T> as Godot array
+
+/// `Vec<T>` is passed to Godot as a typed `Array<T>`, e.g. in `#[func]` and `#[signal]` parameters.
+///
+/// The exception is `Vec<u8>`, which becomes a `PackedByteArray` -- the type Godot uses for byte buffers.
+impl<T: ArrayElement> GodotConvert for Vec<T> {
+    type Via = T::__VecVia;
+}
+
+impl<T: ArrayElement> ToGodot for Vec<T> {
+    fn to_godot(&self) -> Self::Via {
+        T::__VecVia::from_elements(self.as_slice())
+    }
+}
+
+impl<T: ArrayElement> FromGodot for Vec<T> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        via.try_into_elements()
+    }
+
+    /// Converts each element individually.
+    ///
+    /// Unlike `Array<T>`, this accepts arrays whose runtime type differs from `T`, as long as all elements are convertible -- such as
+    /// untyped arrays created in GDScript, or `Array[Node3D]` for `Vec<Gd<Node>>`. This is what Godot itself does when passing arrays
+    /// to typed parameters of GDScript functions and signal handlers. `Vec<u8>` additionally accepts `PackedByteArray`.
+    fn try_from_variant(variant: &Variant) -> Result<Self, ConvertError> {
+        T::__VecVia::try_elements_from_variant(variant)
+    }
+}
+
+/// `Vec<T>` properties are registered like `Array<T>` (or `PackedByteArray` for `Vec<u8>`), and converted on each access from Godot.
+impl<T: ArrayElement> Var for Vec<T> {
+    fn get_property(&self) -> Self::Via {
+        self.to_godot()
+    }
+
+    fn set_property(&mut self, value: Self::Via) {
+        *self = FromGodot::from_godot(value);
+    }
+
+    fn property_hint() -> PropertyHintInfo {
+        <T::__VecVia as Var>::property_hint()
+    }
+}
+
+impl<T: ArrayElement> Export for Vec<T>
+where
+    T::__VecVia: Export,
+{
+    fn default_export_info() -> PropertyHintInfo {
+        <T::__VecVia as Export>::default_export_info()
+    }
+}
+
+impl<T: ArrayElement> VecRepresentation<T> for Array<T> {
+    fn from_elements(elements: &[T]) -> Self {
+        Array::from(elements)
+    }
+
+    fn try_into_elements(self) -> Result<Vec<T>, ConvertError> {
+        // SAFETY: See `From<&Array<T>> for Vec<T>`.
+        let elements = unsafe { Variant::borrow_slice(self.ptr(0), self.len()) };
+
+        elements
+            .iter()
+            .enumerate()
+            .map(|(index, element)| T::try_from_variant(element).map_err(|err| err.at_index(index)))
+            .collect()
+    }
+
+    fn try_elements_from_variant(variant: &Variant) -> Result<Vec<T>, ConvertError> {
+        // Only read from, so the actual element type does not matter.
+        let array = VariantArray::from_variant_unchecked_type(variant)?;
+
+        array
+            .iter_shared()
+            .enumerate()
+            .map(|(index, element)| {
+                T::try_from_variant(&element).map_err(|err| err.at_index(index))
+            })
+            .collect()
+    }
+}
+
+impl VecRepresentation<u8> for PackedByteArray {
+    fn from_elements(elements: &[u8]) -> Self {
+        PackedByteArray::from(elements)
+    }
+
+    fn try_into_elements(self) -> Result<Vec<u8>, ConvertError> {
+        Ok(self.to_vec())
+    }
+
+    fn try_elements_from_variant(variant: &Variant) -> Result<Vec<u8>, ConvertError> {
+        if variant.get_type() == VariantType::PACKED_BYTE_ARRAY {
+            return variant
+                .try_to::<PackedByteArray>()
+                .map(|bytes| bytes.to_vec());
+        }
+
+        // Also accept `Array[int]` and untyped arrays, as long as all elements fit into a byte.
+        <Array<u8> as VecRepresentation<u8>>::try_elements_from_variant(variant)
+    }
+}
@@ -8,6 +8,7 @@
 use godot_ffi as sys;
 
 use crate::builtin::{inner, Variant, VariantArray};
+use crate::meta::error::ConvertError;
 use crate::meta::{FromGodot, ToGodot};
 use crate::registry::property::{
     builtin_type_string, Export, PropertyHintInfo, TypeStringHint, Var,
@@ -136,6 +137,18 @@ impl Dictionary {
         self.as_inner().get(key.to_variant(), Variant::nil())
     }
 
+    /// Returns the value for the given key, converted to `V`.
+    ///
+    /// Absent keys are treated like `NIL` values. On failure, the error is annotated with the key (see [`ConvertError::at_key()`]),
+    /// so that failures in nested structures can be located.
+    pub fn try_get_as<K: ToGodot, V: FromGodot>(&self, key: K) -> Result<V, ConvertError> {
+        let key = key.to_variant();
+
+        self.get_or_nil(key.clone())
+            .try_to::<V>()
+            .map_err(|err| err.at_key(key))
+    }
+
     /// Returns `true` if the dictionary contains the given key.
     ///
     /// _Godot equivalent: `has`_
@@ -227,6 +240,47 @@ impl Dictionary {
         old_value
     }
 
+    /// Gets the entry for the given key, for in-place manipulation.
+    ///
+    /// Works like [`HashMap::entry()`][std::collections::HashMap::entry]. This avoids repeated lookups when a value should only be
+    /// inserted if absent, or modified if present.
+    ///
+    /// Since `Array` and `Dictionary` values are reference-counted, nested containers can be modified through the returned value without
+    /// writing them back.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let mut dict = Dictionary::new();
+    ///
+    /// // Count occurrences.
+    /// for word in ["apple", "pear", "apple"] {
+    ///     dict.entry(word)
+    ///         .and_modify(|count| *count = (count.to::<i64>() + 1).to_variant())
+    ///         .or_insert(1);
+    /// }
+    /// assert_eq!(dict.at("apple"), 2.to_variant());
+    ///
+    /// // Append to nested array, inserting it if necessary.
+    /// let mut list: VariantArray = dict.entry("list").or_insert_with(VariantArray::new).to();
+    /// list.push(1.to_variant());
+    /// assert_eq!(dict.at("list"), varray![1].to_variant());
+    /// ```
+    pub fn entry<K: ToGodot>(&mut self, key: K) -> Entry<'_> {
+        let key = key.to_variant();
+
+        if self.contains_key(key.clone()) {
+            let value = self.get_or_nil(key.clone());
+            Entry::Occupied(OccupiedEntry {
+                dict: self,
+                key,
+                value,
+            })
+        } else {
+            Entry::Vacant(VacantEntry { dict: self, key })
+        }
+    }
+
     /// Returns a 32-bit integer hash value representing the dictionary and its contents.
     #[must_use]
     pub fn hash(&self) -> u32 {
@@ -316,6 +370,21 @@ impl Dictionary {
         inner::InnerDictionary::from_outer(self)
     }
 
+    /// Returns a reference to the value at `key` without cloning it, or `None` if the key is absent.
+    ///
+    /// The dictionary must not be modified (also not through other references to the same dictionary) while the returned reference is alive.
+    pub(crate) fn get_ref(&self, key: &Variant) -> Option<&Variant> {
+        if !self.as_inner().has(key.clone()) {
+            return None;
+        }
+
+        // SAFETY: the key exists, so the pointer refers to the stored value, which lives as long as the dictionary is not modified.
+        unsafe {
+            let ptr = interface_fn!(dictionary_operator_index_const)(self.sys(), key.var_sys());
+            Some(Variant::borrow_var_sys(sys::SysPtr::as_const(ptr)))
+        }
+    }
+
     /// Get the pointer corresponding to the given key in the dictionary.
     ///
     /// If there exists no value at the given key, a `NIL` variant will be inserted for that key.
@@ -713,6 +782,152 @@ impl<'a, K: FromGodot> Iterator for TypedKeys<'a, K> {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Entry API
+
+/// A view into a single key of a [`Dictionary`], which may be present or absent.
+///
+/// Returned by [`Dictionary::entry()`].
+pub enum Entry<'a> {
+    /// The key is present in the dictionary.
+    Occupied(OccupiedEntry<'a>),
+
+    /// The key is absent from the dictionary.
+    Vacant(VacantEntry<'a>),
+}
+
+impl<'a> Entry<'a> {
+    /// Returns the key of this entry.
+    pub fn key(&self) -> &Variant {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Inserts `default` if the key is absent. Returns the (possibly new) value.
+    pub fn or_insert<V: ToGodot>(self, default: V) -> Variant {
+        match self {
+            Entry::Occupied(entry) => entry.get(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Inserts the result of `default()` if the key is absent. Returns the (possibly new) value.
+    ///
+    /// `default` is only invoked if the key is absent.
+    pub fn or_insert_with<V, F>(self, default: F) -> Variant
+    where
+        V: ToGodot,
+        F: FnOnce() -> V,
+    {
+        match self {
+            Entry::Occupied(entry) => entry.get(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Inserts `NIL` if the key is absent. Returns the (possibly new) value.
+    pub fn or_nil(self) -> Variant {
+        self.or_insert(Variant::nil())
+    }
+
+    /// Modifies the value if the key is present; does nothing otherwise.
+    ///
+    /// See [`OccupiedEntry::modify()`] for how the value is updated.
+    ///
+    /// Returns the entry, so that it can be chained with [`or_insert()`][Self::or_insert] and similar methods.
+    pub fn and_modify<F>(self, modify: F) -> Self
+    where
+        F: FnOnce(&mut Variant),
+    {
+        match self {
+            Entry::Occupied(mut entry) => {
+                entry.modify(modify);
+                Entry::Occupied(entry)
+            }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+
+/// An entry in a [`Dictionary`], for a key that is present.
+///
+/// Part of [`Entry`]. The value is looked up once, when the entry is created, and kept in sync by the entry's own methods. Changes made
+/// through another reference to the same dictionary (dictionaries are shared on clone) are not reflected.
+pub struct OccupiedEntry<'a> {
+    dict: &'a mut Dictionary,
+    key: Variant,
+    value: Variant,
+}
+
+impl<'a> OccupiedEntry<'a> {
+    /// Returns the key of this entry.
+    pub fn key(&self) -> &Variant {
+        &self.key
+    }
+
+    /// Returns the value, without another lookup.
+    pub fn get(&self) -> Variant {
+        self.value.clone()
+    }
+
+    /// Replaces the value, returning the previous one.
+    pub fn insert<V: ToGodot>(&mut self, value: V) -> Variant {
+        let value = value.to_variant();
+        self.dict.set(self.key.clone(), value.clone());
+        std::mem::replace(&mut self.value, value)
+    }
+
+    /// Modifies the value through a closure.
+    ///
+    /// This is not done in-place: the entry's copy of the value is passed to `modify`, and then written back under the same key (one
+    /// more lookup). Variants with reference semantics (objects, arrays, dictionaries) still refer to the same instance, but any changes
+    /// the closure makes to the dictionary's entry for this key are overwritten.
+    pub fn modify<F>(&mut self, modify: F)
+    where
+        F: FnOnce(&mut Variant),
+    {
+        // Godot offers no stable pointer into the dictionary's storage, and the closure may modify the dictionary through another (shared)
+        // reference. Working on a copy avoids dangling pointers.
+        modify(&mut self.value);
+        self.dict.set(self.key.clone(), self.value.clone());
+    }
+
+    /// Removes the entry from the dictionary, returning its value.
+    pub fn remove(self) -> Variant {
+        self.dict.as_inner().erase(self.key);
+        self.value
+    }
+}
+
+/// An entry in a [`Dictionary`], for a key that is absent.
+///
+/// Part of [`Entry`].
+pub struct VacantEntry<'a> {
+    dict: &'a mut Dictionary,
+    key: Variant,
+}
+
+impl<'a> VacantEntry<'a> {
+    /// Returns the key of this entry.
+    pub fn key(&self) -> &Variant {
+        &self.key
+    }
+
+    /// Returns the key of this entry, consuming it.
+    pub fn into_key(self) -> Variant {
+        self.key
+    }
+
+    /// Inserts a value for this entry's key, and returns it.
+    pub fn insert<V: ToGodot>(self, value: V) -> Variant {
+        let value = value.to_variant();
+        self.dict.set(self.key, value.clone());
+        value
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helper functions
 
@@ -731,6 +946,9 @@ fn u8_to_bool(u: u8) -> bool {
 /// Any value can be used as a key, but to use an expression you need to surround it
 /// in `()` or `{}`.
 ///
+/// Entries of other dictionaries can be spread into the literal with `..expr`, similar to Rust's struct update syntax. Later entries
+/// overwrite earlier ones with the same key. Values can themselves be `dict!` literals, to build nested dictionaries.
+///
 /// # Example
 /// ```no_run
 /// use godot::builtin::{dict, Variant};
@@ -742,6 +960,14 @@ fn u8_to_bool(u: u8) -> bool {
 ///     key: true,
 ///     (1 + 2): "final",
 /// };
+///
+/// // Nested literals and spreading.
+/// let defaults = dict! { "volume": 0.8, "fullscreen": false };
+/// let config = dict! {
+///     "audio": dict! { "music": true },
+///     ..defaults,
+///     "fullscreen": true,
+/// };
 /// ```
 ///
 /// # See also
@@ -749,6 +975,7 @@ fn u8_to_bool(u: u8) -> bool {
 /// For arrays, similar macros [`array!`][macro@crate::builtin::array] and [`varray!`][macro@crate::builtin::varray] exist.
 #[macro_export]
 macro_rules! dict {
+    // Fast path for plain entries: no recursion, so arbitrarily large literals don't hit the macro recursion limit.
     ($($key:tt: $value:expr),* $(,)?) => {
         {
             let mut d = $crate::builtin::Dictionary::new();
@@ -761,4 +988,30 @@ macro_rules! dict {
             d
         }
     };
+
+    // Entries mixed with `..spread` expressions.
+    ($($tokens:tt)*) => {
+        {
+            let mut d = $crate::builtin::Dictionary::new();
+            $crate::__dict_entries!(d; $($tokens)*);
+            d
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __dict_entries {
+    ($d:ident;) => {};
+
+    ($d:ident; .. $other:expr $(, $($rest:tt)*)?) => {
+        $d.extend_dictionary(::std::clone::Clone::clone(&$other), true);
+        $( $crate::__dict_entries!($d; $($rest)*); )?
+    };
+
+    ($d:ident; $key:tt: $value:expr $(, $($rest:tt)*)?) => {
+        #[allow(unused_parens)]
+        $d.set($key, $value);
+        $( $crate::__dict_entries!($d; $($rest)*); )?
+    };
 }
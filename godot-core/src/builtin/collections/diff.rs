@@ -0,0 +1,424 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::{Array, Dictionary, Variant, VariantArray, VariantType};
+use crate::meta::ArrayElement;
+
+/// Nesting depth after which containers are compared with `==` instead of recursively, to guard against self-referential containers.
+const MAX_DEPTH: usize = 100;
+
+impl<T: ArrayElement> Array<T> {
+    /// Compares two arrays recursively, including nested arrays and dictionaries.
+    ///
+    /// Unlike `==`, two `NaN` floats are considered equal, so that an array is always deep-equal to its own copy. Only contents are
+    /// compared, not the runtime element type of nested arrays.
+    pub fn deep_eq(&self, other: &Self) -> bool {
+        arrays_deep_eq(self, other, 0)
+    }
+
+    /// Returns a copy of the array, in which nested arrays and dictionaries are copied as well.
+    ///
+    /// Same as [`duplicate_deep()`][Self::duplicate_deep]. Objects are not duplicated; the copy refers to the same instances.
+    pub fn deep_clone(&self) -> Self {
+        self.duplicate_deep()
+    }
+
+    /// Computes the changes that turn `self` into `new`.
+    ///
+    /// Elements are compared by index: if `new` is longer, the extra elements are reported as added; if it is shorter, the missing
+    /// ones as removed. Nested arrays and dictionaries are diffed recursively, so a change deep inside is reported with its full path.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::{dict, varray};
+    ///
+    /// let old = varray![1, dict! { "hp": 10 }];
+    /// let new = varray![1, dict! { "hp": 7 }, "extra"];
+    ///
+    /// let changes = old.diff(&new);
+    /// let paths: Vec<String> = changes.iter().map(|c| c.path().to_string()).collect();
+    /// assert_eq!(paths, ["[1].hp", "[2]"]);
+    /// ```
+    pub fn diff(&self, new: &Self) -> ChangeSet {
+        let mut differ = Differ::default();
+        differ.arrays(self, new, 0);
+        differ.finish()
+    }
+}
+
+impl Dictionary {
+    /// Compares two dictionaries recursively, including nested arrays and dictionaries.
+    ///
+    /// Key order does not matter. Like [`Array::deep_eq()`], two `NaN` floats are considered equal.
+    pub fn deep_eq(&self, other: &Self) -> bool {
+        dicts_deep_eq(self, other, 0)
+    }
+
+    /// Returns a copy of the dictionary, in which nested arrays and dictionaries are copied as well.
+    ///
+    /// Same as [`duplicate_deep()`][Self::duplicate_deep]. Objects are not duplicated; the copy refers to the same instances.
+    pub fn deep_clone(&self) -> Self {
+        self.duplicate_deep()
+    }
+
+    /// Computes the changes that turn `self` into `new`.
+    ///
+    /// Keys only present in `new` are reported as added, keys only present in `self` as removed. Values of common keys are diffed
+    /// recursively if both are arrays or both are dictionaries, and reported as modified otherwise.
+    pub fn diff(&self, new: &Self) -> ChangeSet {
+        let mut differ = Differ::default();
+        differ.dicts(self, new, 0);
+        differ.finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Change set
+
+/// Set of changes between two arrays or dictionaries, obtained from [`Array::diff()`] or [`Dictionary::diff()`].
+///
+/// Changes are ordered by their position in the old container (for arrays) or by the old container's key order (for dictionaries), with
+/// additions following the elements they are appended to.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ChangeSet {
+    changes: Vec<Change>,
+}
+
+impl ChangeSet {
+    /// Returns `true` if both containers were deep-equal.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Number of changes.
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Iterates over all changes.
+    pub fn iter(&self) -> std::slice::Iter<'_, Change> {
+        self.changes.iter()
+    }
+
+    /// Returns the changes as a slice.
+    pub fn as_slice(&self) -> &[Change] {
+        &self.changes
+    }
+
+    /// Returns the changes as a `Vec`, consuming `self`.
+    pub fn into_vec(self) -> Vec<Change> {
+        self.changes
+    }
+}
+
+impl IntoIterator for ChangeSet {
+    type Item = Change;
+    type IntoIter = std::vec::IntoIter<Change>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.changes.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ChangeSet {
+    type Item = &'a Change;
+    type IntoIter = std::slice::Iter<'a, Change>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.changes.iter()
+    }
+}
+
+/// Lists one change per line.
+impl fmt::Display for ChangeSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in self.changes.iter() {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Single difference between two containers, located by a [`DiffPath`].
+#[derive(Clone, PartialEq, Debug)]
+pub enum Change {
+    /// An element or entry is only present in the new container.
+    Added { path: DiffPath, value: Variant },
+
+    /// An element or entry is only present in the old container.
+    Removed { path: DiffPath, value: Variant },
+
+    /// A value is present in both containers, but differs.
+    Modified {
+        path: DiffPath,
+        old: Variant,
+        new: Variant,
+    },
+}
+
+impl Change {
+    /// Location of the change, relative to the diffed container.
+    pub fn path(&self) -> &DiffPath {
+        match self {
+            Change::Added { path, .. }
+            | Change::Removed { path, .. }
+            | Change::Modified { path, .. } => path,
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Added { path, value } => write!(f, "+ {path}: {value}"),
+            Change::Removed { path, value } => write!(f, "- {path}: {value}"),
+            Change::Modified { path, old, new } => write!(f, "~ {path}: {old} -> {new}"),
+        }
+    }
+}
+
+/// Location inside nested arrays and dictionaries.
+///
+/// Displayed in a GDScript-like notation, e.g. `[3].config.max_hp` or `[0]["key with spaces"]`.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct DiffPath {
+    segments: Vec<PathSegment>,
+}
+
+impl DiffPath {
+    /// Segments from the outermost to the innermost container.
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+}
+
+impl fmt::Display for DiffPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.segments.iter().enumerate() {
+            match segment {
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+                PathSegment::Key(key) => match identifier_key(key) {
+                    Some(name) if i == 0 => write!(f, "{name}")?,
+                    Some(name) => write!(f, ".{name}")?,
+                    None if is_string(key) => write!(f, "[\"{key}\"]")?,
+                    None => write!(f, "[{key}]")?,
+                },
+            }
+        }
+        Ok(())
+    }
+}
+
+/// One step in a [`DiffPath`].
+#[derive(Clone, PartialEq, Debug)]
+pub enum PathSegment {
+    /// Index into an array.
+    Index(usize),
+
+    /// Key into a dictionary.
+    Key(Variant),
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+/// Nested container held by a variant.
+enum Container {
+    Array(VariantArray),
+    Dictionary(Dictionary),
+}
+
+impl Container {
+    fn of(variant: &Variant) -> Option<Self> {
+        match variant.get_type() {
+            // SAFETY: the array is only read.
+            VariantType::ARRAY => {
+                unsafe { VariantArray::from_variant_unchecked(variant) }.map(Container::Array)
+            }
+            VariantType::DICTIONARY => Some(Container::Dictionary(variant.to())),
+            _ => None,
+        }
+    }
+}
+
+fn arrays_deep_eq<T: ArrayElement, U: ArrayElement>(
+    a: &Array<T>,
+    b: &Array<U>,
+    depth: usize,
+) -> bool {
+    a.len() == b.len()
+        && (0..a.len()).all(|i| variants_deep_eq(a.variant_ref(i), b.variant_ref(i), depth + 1))
+}
+
+fn dicts_deep_eq(a: &Dictionary, b: &Dictionary, depth: usize) -> bool {
+    a.len() == b.len()
+        && a.keys_shared().all(|key| {
+            let a_value = a.get_ref(&key).expect("key is present");
+            b.get_ref(&key)
+                .is_some_and(|b_value| variants_deep_eq(a_value, b_value, depth + 1))
+        })
+}
+
+fn variants_deep_eq(a: &Variant, b: &Variant, depth: usize) -> bool {
+    if depth > MAX_DEPTH {
+        return a == b;
+    }
+
+    match (Container::of(a), Container::of(b)) {
+        (Some(Container::Array(a)), Some(Container::Array(b))) => arrays_deep_eq(&a, &b, depth),
+        (Some(Container::Dictionary(a)), Some(Container::Dictionary(b))) => {
+            dicts_deep_eq(&a, &b, depth)
+        }
+        (None, None) => leaves_eq(a, b),
+        _ => false,
+    }
+}
+
+/// Like `==`, but reflexive for `NaN`.
+fn leaves_eq(a: &Variant, b: &Variant) -> bool {
+    a == b || (is_nan(a) && is_nan(b))
+}
+
+fn is_nan(variant: &Variant) -> bool {
+    variant.get_type() == VariantType::FLOAT && variant.to::<f64>().is_nan()
+}
+
+fn is_string(key: &Variant) -> bool {
+    let ty = key.get_type();
+    ty == VariantType::STRING || ty == VariantType::STRING_NAME
+}
+
+/// Returns the key as a string if it can be displayed as `.name`.
+fn identifier_key(key: &Variant) -> Option<String> {
+    if !is_string(key) {
+        return None;
+    }
+
+    let name = key.to_string();
+    let mut chars = name.chars();
+    let starts_valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_');
+
+    if starts_valid && chars.all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        Some(name)
+    } else {
+        None
+    }
+}
+
+/// Accumulates changes while walking both containers. The current path is shared and only cloned when a change is recorded.
+#[derive(Default)]
+struct Differ {
+    path: Vec<PathSegment>,
+    changes: Vec<Change>,
+}
+
+impl Differ {
+    fn finish(self) -> ChangeSet {
+        ChangeSet {
+            changes: self.changes,
+        }
+    }
+
+    fn arrays<T: ArrayElement, U: ArrayElement>(
+        &mut self,
+        old: &Array<T>,
+        new: &Array<U>,
+        depth: usize,
+    ) {
+        let common_len = old.len().min(new.len());
+
+        for i in 0..common_len {
+            self.path.push(PathSegment::Index(i));
+            self.variants(old.variant_ref(i), new.variant_ref(i), depth + 1);
+            self.path.pop();
+        }
+
+        for i in common_len..old.len() {
+            self.path.push(PathSegment::Index(i));
+            self.record_removed(old.variant_ref(i));
+            self.path.pop();
+        }
+
+        for i in common_len..new.len() {
+            self.path.push(PathSegment::Index(i));
+            self.record_added(new.variant_ref(i));
+            self.path.pop();
+        }
+    }
+
+    fn dicts(&mut self, old: &Dictionary, new: &Dictionary, depth: usize) {
+        for key in old.keys_shared() {
+            let old_value = old.get_ref(&key).expect("key is present");
+            let new_value = new.get_ref(&key);
+
+            self.path.push(PathSegment::Key(key));
+            match new_value {
+                Some(new_value) => self.variants(old_value, new_value, depth + 1),
+                None => self.record_removed(old_value),
+            }
+            self.path.pop();
+        }
+
+        for key in new.keys_shared() {
+            if old.get_ref(&key).is_some() {
+                continue;
+            }
+
+            let new_value = new.get_ref(&key).expect("key is present");
+
+            self.path.push(PathSegment::Key(key));
+            self.record_added(new_value);
+            self.path.pop();
+        }
+    }
+
+    fn variants(&mut self, old: &Variant, new: &Variant, depth: usize) {
+        if depth <= MAX_DEPTH {
+            match (Container::of(old), Container::of(new)) {
+                (Some(Container::Array(old)), Some(Container::Array(new))) => {
+                    return self.arrays(&old, &new, depth)
+                }
+                (Some(Container::Dictionary(old)), Some(Container::Dictionary(new))) => {
+                    return self.dicts(&old, &new, depth)
+                }
+                _ => {}
+            }
+        }
+
+        if !leaves_eq(old, new) {
+            self.changes.push(Change::Modified {
+                path: self.current_path(),
+                old: old.clone(),
+                new: new.clone(),
+            });
+        }
+    }
+
+    fn record_added(&mut self, value: &Variant) {
+        self.changes.push(Change::Added {
+            path: self.current_path(),
+            value: value.clone(),
+        });
+    }
+
+    fn record_removed(&mut self, value: &Variant) {
+        self.changes.push(Change::Removed {
+            path: self.current_path(),
+            value: value.clone(),
+        });
+    }
+
+    fn current_path(&self) -> DiffPath {
+        DiffPath {
+            segments: self.path.clone(),
+        }
+    }
+}
@@ -7,15 +7,28 @@
 
 mod array;
 mod dictionary;
+mod diff;
+#[cfg(feature = "ndarray")]
+mod ndarray_interop;
 mod packed_array;
+#[cfg(feature = "rayon")]
+mod rayon_interop;
 
 // Re-export in godot::builtin.
 pub(crate) mod containers {
     pub use super::array::{Array, VariantArray};
     pub use super::dictionary::Dictionary;
+    pub use super::dictionary::Entry as DictEntry;
+    pub use super::dictionary::OccupiedEntry as DictOccupiedEntry;
+    pub use super::dictionary::VacantEntry as DictVacantEntry;
     pub use super::packed_array::*;
 }
 
+// Re-export in godot::builtin::diff.
+pub(crate) mod diffs {
+    pub use super::diff::{Change, ChangeSet, DiffPath, PathSegment};
+}
+
 // Re-export in godot::builtin::iter.
 pub(crate) mod iterators {
     pub use super::array::Iter as ArrayIter;
@@ -0,0 +1,142 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Conversions between numeric packed arrays and [`ndarray`] arrays.
+
+use ndarray::{
+    Array1, ArrayBase, ArrayView, ArrayView1, ArrayView2, ArrayViewMut, ArrayViewMut1,
+    ArrayViewMut2, Data, Dimension, Ix2, ShapeError, StrideShape,
+};
+
+use crate::builtin::{
+    real, PackedByteArray, PackedFloat32Array, PackedFloat64Array, PackedInt32Array,
+    PackedInt64Array, PackedVector2Array, Vector2,
+};
+
+macro_rules! impl_ndarray_interop {
+    ($PackedArray:ident, $Element:ty) => {
+        /// Conversions from and to [`ndarray`] arrays (requires feature `ndarray`).
+        impl $PackedArray {
+            /// Returns a one-dimensional `ndarray` view of the elements, without copying.
+            pub fn as_ndarray(&self) -> ArrayView1<'_, $Element> {
+                ArrayView1::from(self.as_slice())
+            }
+
+            /// Returns a mutable one-dimensional `ndarray` view of the elements, without copying (except for copy-on-write,
+            /// see [`as_mut_slice()`][Self::as_mut_slice]).
+            pub fn as_ndarray_mut(&mut self) -> ArrayViewMut1<'_, $Element> {
+                ArrayViewMut1::from(self.as_mut_slice())
+            }
+
+            /// Interprets the elements as an `ndarray` view of the given `shape`, without copying.
+            ///
+            /// The shape is row-major (C order) unless specified otherwise, e.g. `(height, width)` for a single-channel image or
+            /// `(frames, 2)` for stereo audio. Fails if the number of elements doesn't match the shape.
+            pub fn as_ndarray_shaped<D, Sh>(
+                &self,
+                shape: Sh,
+            ) -> Result<ArrayView<'_, $Element, D>, ShapeError>
+            where
+                D: Dimension,
+                Sh: Into<StrideShape<D>>,
+            {
+                ArrayView::from_shape(shape, self.as_slice())
+            }
+
+            /// Mutable version of [`as_ndarray_shaped()`][Self::as_ndarray_shaped].
+            pub fn as_ndarray_shaped_mut<D, Sh>(
+                &mut self,
+                shape: Sh,
+            ) -> Result<ArrayViewMut<'_, $Element, D>, ShapeError>
+            where
+                D: Dimension,
+                Sh: Into<StrideShape<D>>,
+            {
+                ArrayViewMut::from_shape(shape, self.as_mut_slice())
+            }
+
+            /// Copies the elements into an owned one-dimensional `ndarray`.
+            pub fn to_ndarray(&self) -> Array1<$Element> {
+                Array1::from(self.to_vec())
+            }
+        }
+
+        #[doc = concat!("Copies all elements of an `ndarray` into a `", stringify!($PackedArray), "`, in logical (row-major) order.")]
+        ///
+        /// Arrays in standard layout are copied in one go; others are traversed element by element.
+        impl<S, D> From<&ArrayBase<S, D>> for $PackedArray
+        where
+            S: Data<Elem = $Element>,
+            D: Dimension,
+        {
+            fn from(array: &ArrayBase<S, D>) -> Self {
+                match array.as_slice() {
+                    Some(slice) => Self::from(slice),
+                    None => array.iter().copied().collect(),
+                }
+            }
+        }
+    };
+}
+
+impl_ndarray_interop!(PackedByteArray, u8);
+impl_ndarray_interop!(PackedInt32Array, i32);
+impl_ndarray_interop!(PackedInt64Array, i64);
+impl_ndarray_interop!(PackedFloat32Array, f32);
+impl_ndarray_interop!(PackedFloat64Array, f64);
+
+/// Conversions from and to [`ndarray`] arrays (requires feature `ndarray`).
+///
+/// This is mostly useful for stereo audio, e.g. the frames passed to `AudioStreamGeneratorPlayback::push_buffer()`.
+impl PackedVector2Array {
+    /// Returns a `(len, 2)` view of the vector components, without copying. Column 0 holds `x`, column 1 holds `y`.
+    pub fn as_ndarray(&self) -> ArrayView2<'_, real> {
+        let slice = self.as_slice();
+
+        // SAFETY: Vector2 is #[repr(C)] and consists of exactly two `real` fields, so the slice is a contiguous sequence of reals.
+        let flat =
+            unsafe { std::slice::from_raw_parts(slice.as_ptr().cast::<real>(), slice.len() * 2) };
+
+        ArrayView2::from_shape((slice.len(), 2), flat).expect("shape matches length")
+    }
+
+    /// Mutable version of [`as_ndarray()`][Self::as_ndarray].
+    pub fn as_ndarray_mut(&mut self) -> ArrayViewMut2<'_, real> {
+        let slice = self.as_mut_slice();
+        let len = slice.len();
+
+        // SAFETY: see as_ndarray(); the exclusive borrow of the slice is moved into the view.
+        let flat =
+            unsafe { std::slice::from_raw_parts_mut(slice.as_mut_ptr().cast::<real>(), len * 2) };
+
+        ArrayViewMut2::from_shape((len, 2), flat).expect("shape matches length")
+    }
+}
+
+/// Copies the rows of a `(n, 2)` array into a `PackedVector2Array`.
+///
+/// # Panics
+/// If the array does not have exactly two columns.
+impl<S> From<&ArrayBase<S, Ix2>> for PackedVector2Array
+where
+    S: Data<Elem = real>,
+{
+    fn from(array: &ArrayBase<S, Ix2>) -> Self {
+        assert_eq!(
+            array.ncols(),
+            2,
+            "PackedVector2Array requires an array of shape (n, 2), got {:?}",
+            array.shape()
+        );
+
+        array
+            .rows()
+            .into_iter()
+            .map(|row| Vector2::new(row[0], row[1]))
+            .collect()
+    }
+}
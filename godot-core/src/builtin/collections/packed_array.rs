@@ -8,6 +8,7 @@
 use godot_ffi as sys;
 
 use crate::builtin::*;
+use crate::meta::error::{ConvertError, FromGodotError};
 use crate::meta::ToGodot;
 use std::{fmt, ops};
 use sys::types::*;
@@ -433,6 +434,23 @@ macro_rules! impl_packed_array {
             }
         }
 
+        #[doc = concat!("Converts the `", stringify!($PackedArray), "` to a fixed-size Rust array, failing if its length is not `N`.")]
+        impl<const N: usize> TryFrom<&$PackedArray> for [$Element; N] {
+            type Error = ConvertError;
+
+            fn try_from(array: &$PackedArray) -> Result<Self, Self::Error> {
+                if array.len() != N {
+                    return Err(FromGodotError::BadArrayLength {
+                        expected: N,
+                        actual: array.len(),
+                    }
+                    .into_error(array.clone()));
+                }
+
+                Ok(super::array::vec_into_array(array.to_vec()))
+            }
+        }
+
         #[doc = concat!("Creates a `", stringify!($PackedArray), "` from the given slice.")]
         impl From<&[$Element]> for $PackedArray {
             fn from(slice: &[$Element]) -> Self {
@@ -766,3 +784,101 @@ impl_packed_array!(
         PartialEq => packed_color_array_operator_equal;
     },
 );
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// String conversions
+
+impl PackedStringArray {
+    /// Converts this array to a vector of Rust strings.
+    pub fn to_vec_string(&self) -> Vec<String> {
+        self.as_slice().iter().map(String::from).collect()
+    }
+
+    /// Appends all strings of `iter`, allocating the array storage at once for iterators with known length.
+    fn extend_str<S: AsRef<str>>(&mut self, iter: impl IntoIterator<Item = S>) {
+        let mut iter = iter.into_iter();
+        let start = self.len();
+        let (lower_bound, _) = iter.size_hint();
+
+        if lower_bound > 0 {
+            self.resize(start + lower_bound);
+
+            let mut written = 0;
+            for (slot, s) in self.as_mut_slice()[start..].iter_mut().zip(&mut iter) {
+                *slot = GString::from(s.as_ref());
+                written += 1;
+            }
+
+            // `size_hint()` is not trusted to be correct.
+            if written < lower_bound {
+                self.resize(start + written);
+            }
+        }
+
+        for s in iter {
+            self.push(GString::from(s.as_ref()));
+        }
+    }
+}
+
+/// Creates a `PackedStringArray` from Rust string slices.
+impl<'a> FromIterator<&'a str> for PackedStringArray {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut array = Self::new();
+        array.extend_str(iter);
+        array
+    }
+}
+
+/// Creates a `PackedStringArray` from Rust strings.
+impl FromIterator<String> for PackedStringArray {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        let mut array = Self::new();
+        array.extend_str(iter);
+        array
+    }
+}
+
+/// Extends a `PackedStringArray` with Rust string slices.
+impl<'a> Extend<&'a str> for PackedStringArray {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        self.extend_str(iter);
+    }
+}
+
+/// Extends a `PackedStringArray` with Rust strings.
+impl Extend<String> for PackedStringArray {
+    fn extend<I: IntoIterator<Item = String>>(&mut self, iter: I) {
+        self.extend_str(iter);
+    }
+}
+
+/// Creates a `PackedStringArray` from the given string slices.
+impl From<&[&str]> for PackedStringArray {
+    fn from(slice: &[&str]) -> Self {
+        slice.iter().copied().collect()
+    }
+}
+
+/// Creates a `PackedStringArray` from the given strings.
+impl From<&[String]> for PackedStringArray {
+    fn from(slice: &[String]) -> Self {
+        let mut array = Self::new();
+        array.extend_str(slice);
+        array
+    }
+}
+
+/// Creates a `PackedStringArray` from the given strings.
+impl From<Vec<String>> for PackedStringArray {
+    fn from(vec: Vec<String>) -> Self {
+        Self::from(vec.as_slice())
+    }
+}
+
+/// Converts the `PackedStringArray` to a vector of Rust strings.
+impl From<&PackedStringArray> for Vec<String> {
+    fn from(array: &PackedStringArray) -> Self {
+        array.to_vec_string()
+    }
+}
@@ -0,0 +1,94 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Parallel iteration over arrays and packed arrays with [`rayon`].
+
+use rayon::iter::{
+    FromParallelIterator, IntoParallelIterator, IntoParallelRefIterator,
+    IntoParallelRefMutIterator, ParallelIterator,
+};
+
+use crate::builtin::{
+    Array, Color, PackedByteArray, PackedColorArray, PackedFloat32Array, PackedFloat64Array,
+    PackedInt32Array, PackedInt64Array, PackedVector2Array, PackedVector3Array, Vector2, Vector3,
+};
+use crate::meta::{ArrayElement, FromGodot, ToGodot};
+
+#[cfg(since_api = "4.3")]
+use crate::builtin::{PackedVector4Array, Vector4};
+
+macro_rules! impl_rayon_interop {
+    ($PackedArray:ident, $Element:ty) => {
+        /// Parallel iteration with [`rayon`] (requires feature `rayon`).
+        impl $PackedArray {
+            /// Returns a parallel iterator over the elements, without copying.
+            ///
+            /// The elements are read directly from the array's storage. This is safe because the array is borrowed for the lifetime
+            /// of the iterator, and copy-on-write ensures that other handles to the same storage never modify it in place.
+            pub fn par_iter(&self) -> rayon::slice::Iter<'_, $Element> {
+                self.as_slice().par_iter()
+            }
+
+            /// Returns a parallel iterator over mutable references to the elements, without copying (except for copy-on-write,
+            /// see [`as_mut_slice()`][Self::as_mut_slice]).
+            pub fn par_iter_mut(&mut self) -> rayon::slice::IterMut<'_, $Element> {
+                self.as_mut_slice().par_iter_mut()
+            }
+        }
+
+        #[doc = concat!("Collects a parallel iterator into a `", stringify!($PackedArray), "`, preserving the order of elements.")]
+        impl FromParallelIterator<$Element> for $PackedArray {
+            fn from_par_iter<I>(par_iter: I) -> Self
+            where
+                I: IntoParallelIterator<Item = $Element>,
+            {
+                let elements: Vec<$Element> = par_iter.into_par_iter().collect();
+                Self::from(elements.as_slice())
+            }
+        }
+    };
+}
+
+impl_rayon_interop!(PackedByteArray, u8);
+impl_rayon_interop!(PackedInt32Array, i32);
+impl_rayon_interop!(PackedInt64Array, i64);
+impl_rayon_interop!(PackedFloat32Array, f32);
+impl_rayon_interop!(PackedFloat64Array, f64);
+impl_rayon_interop!(PackedVector2Array, Vector2);
+impl_rayon_interop!(PackedVector3Array, Vector3);
+#[cfg(since_api = "4.3")]
+impl_rayon_interop!(PackedVector4Array, Vector4);
+impl_rayon_interop!(PackedColorArray, Color);
+
+/// Parallel iteration with [`rayon`] (requires feature `rayon`).
+impl<T: ArrayElement + FromGodot + Send> Array<T> {
+    /// Returns a parallel iterator over a snapshot of the elements.
+    ///
+    /// `Array` stores its elements as variants, which must not be accessed from other threads. The elements are thus converted to `T`
+    /// on the calling thread and moved into a contiguous buffer first, which the parallel iterator then owns. Modifications of the array
+    /// after this call are not observed by the iterator.
+    ///
+    /// Only element types that are `Send` can be iterated in parallel. This excludes objects (`Gd<T>`) and other types that are bound
+    /// to the main thread.
+    pub fn par_iter(&self) -> rayon::vec::IntoIter<T> {
+        let elements: Vec<T> = self.iter_shared().collect();
+        elements.into_par_iter()
+    }
+}
+
+/// Collects a parallel iterator into an `Array`, preserving the order of elements.
+///
+/// The elements are gathered in a contiguous buffer and converted to variants on the calling thread.
+impl<T: ArrayElement + ToGodot + Send> FromParallelIterator<T> for Array<T> {
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = T>,
+    {
+        let elements: Vec<T> = par_iter.into_par_iter().collect();
+        Self::from(elements.as_slice())
+    }
+}
@@ -0,0 +1,332 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use super::math::{ApproxEq, FloatExt};
+use super::Color;
+
+/// OKLab color representation: perceptual lightness `l` and the two opponent axes `a` (green-red) and `b` (blue-yellow).
+///
+/// [OKLab](https://bottosson.github.io/posts/oklab/) is a perceptually uniform color space: the same numeric distance corresponds to
+/// roughly the same visible difference everywhere. This makes it suitable for interpolating colors and generating gradients, where
+/// interpolation in sRGB tends to produce muddy or too dark intermediate colors.
+///
+/// `l` is in range `0.0..=1.0`; `a` and `b` are roughly in `-0.4..=0.4` for colors within the sRGB gamut.
+///
+/// Like [`ColorHsv`][super::ColorHsv], `ColorOklab` *is not* a [`GodotType`](crate::meta::GodotType); convert it back to [`Color`]
+/// with [`to_rgb()`][Self::to_rgb] before passing it to Godot. The conversions are implemented in Rust and don't call into Godot.
+///
+/// ```
+/// use godot::builtin::Color;
+///
+/// let color = Color::from_rgb(0.2, 0.6, 0.9);
+/// let lab = color.to_oklab();
+/// assert!(lab.l > 0.0 && lab.l < 1.0);
+///
+/// // Roundtrip is lossless up to floating-point precision.
+/// let back = lab.to_rgb();
+/// assert!((back.r - color.r).abs() < 1e-4);
+/// assert!((back.b - color.b).abs() < 1e-4);
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColorOklab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+    pub alpha: f32,
+}
+
+impl ColorOklab {
+    /// Construct from lightness and the `a`/`b` axes, with alpha `1.0`.
+    pub const fn from_lab(l: f32, a: f32, b: f32) -> Self {
+        Self {
+            l,
+            a,
+            b,
+            alpha: 1.0,
+        }
+    }
+
+    /// Construct from lightness, the `a`/`b` axes and alpha.
+    pub const fn from_laba(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        Self { l, a, b, alpha }
+    }
+
+    /// Converts to [`Color`] (sRGB). Channels of colors outside the sRGB gamut are clamped to `0.0..=1.0`.
+    pub fn to_rgb(self) -> Color {
+        let (r, g, b) = oklab_to_linear_srgb(self.l, self.a, self.b);
+
+        Color::from_rgba(
+            linear_to_srgb(r).clamp(0.0, 1.0),
+            linear_to_srgb(g).clamp(0.0, 1.0),
+            linear_to_srgb(b).clamp(0.0, 1.0),
+            self.alpha,
+        )
+    }
+
+    /// Converts to the polar form, [`ColorOklch`].
+    pub fn to_oklch(self) -> ColorOklch {
+        let c = (self.a * self.a + self.b * self.b).sqrt();
+        let h = if c.is_zero_approx() {
+            0.0
+        } else {
+            wrap_turns(self.b.atan2(self.a) / std::f32::consts::TAU)
+        };
+
+        ColorOklch {
+            l: self.l,
+            c,
+            h,
+            alpha: self.alpha,
+        }
+    }
+
+    /// Linear interpolation between `self` and `to`, component-wise.
+    #[must_use]
+    pub fn lerp(self, to: Self, weight: f32) -> Self {
+        Self {
+            l: self.l.lerp(to.l, weight),
+            a: self.a.lerp(to.a, weight),
+            b: self.b.lerp(to.b, weight),
+            alpha: self.alpha.lerp(to.alpha, weight),
+        }
+    }
+
+    /// Euclidean distance in OKLab (ignoring alpha), a measure for how different two colors look.
+    ///
+    /// Differences below about `0.02` are hard to notice for most people.
+    pub fn distance_to(self, other: Self) -> f32 {
+        let dl = self.l - other.l;
+        let da = self.a - other.a;
+        let db = self.b - other.b;
+
+        (dl * dl + da * da + db * db).sqrt()
+    }
+}
+
+impl ApproxEq for ColorOklab {
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.l.approx_eq(&other.l)
+            && self.a.approx_eq(&other.a)
+            && self.b.approx_eq(&other.b)
+            && self.alpha.approx_eq(&other.alpha)
+    }
+}
+
+/// OKLCH color representation: the polar form of [`ColorOklab`], with lightness `l`, chroma `c` and hue `h`.
+///
+/// Hue is expressed in turns (`0.0..1.0`) like in [`ColorHsv`][super::ColorHsv], not in degrees. Chroma is `0.0` for grays and roughly
+/// up to `0.37` for saturated colors within the sRGB gamut.
+///
+/// OKLCH is convenient to derive colors with the same perceived lightness, e.g. for palettes: change `h` and keep `l` and `c`.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColorOklch {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+    pub alpha: f32,
+}
+
+impl ColorOklch {
+    /// Construct from lightness, chroma and hue (in turns), with alpha `1.0`.
+    pub const fn from_lch(l: f32, c: f32, h: f32) -> Self {
+        Self {
+            l,
+            c,
+            h,
+            alpha: 1.0,
+        }
+    }
+
+    /// Construct from lightness, chroma, hue (in turns) and alpha.
+    pub const fn from_lcha(l: f32, c: f32, h: f32, alpha: f32) -> Self {
+        Self { l, c, h, alpha }
+    }
+
+    /// Converts to [`Color`] (sRGB). Channels of colors outside the sRGB gamut are clamped to `0.0..=1.0`.
+    pub fn to_rgb(self) -> Color {
+        self.to_oklab().to_rgb()
+    }
+
+    /// Converts to the cartesian form, [`ColorOklab`].
+    pub fn to_oklab(self) -> ColorOklab {
+        let (sin, cos) = (self.h * std::f32::consts::TAU).sin_cos();
+
+        ColorOklab {
+            l: self.l,
+            a: self.c * cos,
+            b: self.c * sin,
+            alpha: self.alpha,
+        }
+    }
+
+    /// Interpolation between `self` and `to`, taking the shorter way around the hue circle.
+    #[must_use]
+    pub fn lerp(self, to: Self, weight: f32) -> Self {
+        // Shortest signed hue difference, in range -0.5..0.5.
+        let dh = wrap_turns(to.h - self.h + 0.5) - 0.5;
+
+        Self {
+            l: self.l.lerp(to.l, weight),
+            c: self.c.lerp(to.c, weight),
+            h: wrap_turns(self.h + dh * weight),
+            alpha: self.alpha.lerp(to.alpha, weight),
+        }
+    }
+}
+
+impl ApproxEq for ColorOklch {
+    /// Hue values are wrapped before approximate comparison.
+    fn approx_eq(&self, other: &Self) -> bool {
+        let dh = wrap_turns(self.h - other.h + 0.5) - 0.5;
+
+        self.l.approx_eq(&other.l)
+            && self.c.approx_eq(&other.c)
+            && dh.is_zero_approx()
+            && self.alpha.approx_eq(&other.alpha)
+    }
+}
+
+/// OKLab conversions, perceptual interpolation and contrast.
+impl Color {
+    /// Converts to [OKLab](ColorOklab), interpreting `self` as sRGB.
+    pub fn to_oklab(self) -> ColorOklab {
+        let (l, a, b) = linear_srgb_to_oklab(
+            srgb_to_linear(self.r),
+            srgb_to_linear(self.g),
+            srgb_to_linear(self.b),
+        );
+
+        ColorOklab {
+            l,
+            a,
+            b,
+            alpha: self.a,
+        }
+    }
+
+    /// Converts to [OKLCH](ColorOklch), interpreting `self` as sRGB.
+    pub fn to_oklch(self) -> ColorOklch {
+        self.to_oklab().to_oklch()
+    }
+
+    /// Interpolates between `self` and `to` in OKLab space, giving perceptually even steps.
+    ///
+    /// Unlike [`lerp()`][Self::lerp], which interpolates sRGB channels, the intermediate colors don't become muddy or too dark.
+    #[must_use]
+    pub fn lerp_oklab(self, to: Color, weight: f32) -> Self {
+        self.to_oklab().lerp(to.to_oklab(), weight).to_rgb()
+    }
+
+    /// Interpolates between `self` and `to` in OKLCH space, taking the shorter way around the hue circle.
+    ///
+    /// Keeps intermediate colors saturated, at the cost of passing through other hues (e.g. red to blue via purple).
+    #[must_use]
+    pub fn lerp_oklch(self, to: Color, weight: f32) -> Self {
+        self.to_oklch().lerp(to.to_oklch(), weight).to_rgb()
+    }
+
+    /// Returns `steps` colors, evenly spaced in OKLab from `self` to `to` (both included).
+    ///
+    /// Returns an empty vector for `steps == 0`, and `[self]` for `steps == 1`.
+    pub fn gradient_oklab(self, to: Color, steps: usize) -> Vec<Color> {
+        match steps {
+            0 => Vec::new(),
+            1 => vec![self],
+            _ => {
+                let (from, to) = (self.to_oklab(), to.to_oklab());
+                let last = (steps - 1) as f32;
+
+                (0..steps)
+                    .map(|i| from.lerp(to, i as f32 / last).to_rgb())
+                    .collect()
+            }
+        }
+    }
+
+    /// Relative luminance as defined by [WCAG 2](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance), in range `0.0..=1.0`.
+    ///
+    /// Unlike [`luminance()`][Self::luminance], this linearizes the sRGB channels first. Alpha is ignored.
+    pub fn relative_luminance(self) -> f32 {
+        0.2126 * srgb_to_linear(self.r)
+            + 0.7152 * srgb_to_linear(self.g)
+            + 0.0722 * srgb_to_linear(self.b)
+    }
+
+    /// WCAG contrast ratio between `self` and `other`, in range `1.0..=21.0`. The order of the two colors doesn't matter.
+    ///
+    /// WCAG requires at least `4.5` for normal text and `3.0` for large text (level AA). Alpha is ignored.
+    ///
+    /// ```
+    /// use godot::builtin::Color;
+    ///
+    /// let ratio = Color::BLACK.contrast_ratio(Color::WHITE);
+    /// assert!((ratio - 21.0).abs() < 0.01);
+    /// ```
+    pub fn contrast_ratio(self, other: Color) -> f32 {
+        let l1 = self.relative_luminance();
+        let l2 = other.relative_luminance();
+        let (lighter, darker) = if l1 >= l2 { (l1, l2) } else { (l2, l1) };
+
+        (lighter + 0.05) / (darker + 0.05)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Conversion math, see https://bottosson.github.io/posts/oklab/
+
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+#[allow(clippy::excessive_precision)]
+fn linear_srgb_to_oklab(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    let l = 0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b;
+    let m = 0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b;
+    let s = 0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b;
+
+    let (l, m, s) = (l.cbrt(), m.cbrt(), s.cbrt());
+
+    (
+        0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+    )
+}
+
+#[allow(clippy::excessive_precision)]
+fn oklab_to_linear_srgb(l: f32, a: f32, b: f32) -> (f32, f32, f32) {
+    let l_ = l + 0.3963377774 * a + 0.2158037573 * b;
+    let m_ = l - 0.1055613458 * a - 0.0638541728 * b;
+    let s_ = l - 0.0894841775 * a - 1.2914855480 * b;
+
+    let (l, m, s) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+
+    (
+        4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+        -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+        -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+    )
+}
+
+/// Wraps a value measured in turns to `0.0..1.0`.
+fn wrap_turns(turns: f32) -> f32 {
+    turns.rem_euclid(1.0)
+}
@@ -0,0 +1,136 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+/// Declares a set of named [`Color`][crate::builtin::Color] constants from HTML color codes, validated at compile time.
+///
+/// Generates a unit struct with one associated constant per color, plus `ENTRIES`, a slice of all `(name, color)` pairs in declaration
+/// order. Colors are written as string literals in the same format as [`Color::from_html()`][crate::builtin::Color::from_html]:
+/// `"#RGB"`, `"#RGBA"`, `"#RRGGBB"` or `"#RRGGBBAA"`, where the `#` is optional.
+///
+/// Unlike `Color::from_html()`, no Godot call is involved, so the constants can be used anywhere, including `const` contexts.
+/// Malformed codes are reported as compile errors.
+///
+/// # Example
+/// ```
+/// use godot::builtin::{palette, Color};
+///
+/// palette! {
+///     /// Colors of the in-game UI.
+///     pub struct UiPalette {
+///         PRIMARY = "#3366ff",
+///         /// Used for destructive actions.
+///         DANGER = "#e53935",
+///         OVERLAY = "#00000080",
+///     }
+/// }
+///
+/// assert_eq!(UiPalette::PRIMARY, Color::from_rgba(0x33 as f32 / 255.0, 0x66 as f32 / 255.0, 1.0, 1.0));
+/// assert_eq!(UiPalette::OVERLAY.a8(), 0x80);
+/// assert_eq!(UiPalette::ENTRIES[1].0, "DANGER");
+/// ```
+///
+/// Invalid colors fail to compile:
+/// ```compile_fail
+/// use godot::builtin::palette;
+///
+/// palette! {
+///     struct Broken {
+///         TYPO = "#12345g",
+///     }
+/// }
+/// # let _ = Broken::TYPO;
+/// ```
+#[macro_export]
+macro_rules! palette {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $Name:ident {
+            $(
+                $(#[$const_attr:meta])*
+                $Const:ident = $html:literal
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+        $vis struct $Name;
+
+        impl $Name {
+            $(
+                $(#[$const_attr])*
+                #[doc = concat!("\n\n_HTML color: `", $html, "`_")]
+                pub const $Const: $crate::builtin::Color = {
+                    const RGBA: u32 = $crate::builtin::__parse_html_color($html);
+
+                    $crate::builtin::Color::from_rgba(
+                        ((RGBA >> 24) & 0xff) as f32 / 255.0,
+                        ((RGBA >> 16) & 0xff) as f32 / 255.0,
+                        ((RGBA >> 8) & 0xff) as f32 / 255.0,
+                        (RGBA & 0xff) as f32 / 255.0,
+                    )
+                };
+            )*
+
+            /// All colors in this palette, with their names, in declaration order.
+            pub const ENTRIES: &'static [(&'static str, $crate::builtin::Color)] = &[
+                $( (stringify!($Const), Self::$Const), )*
+            ];
+        }
+    };
+}
+
+/// Parses an HTML color code into `0xRRGGBBAA` at compile time. Panics (i.e. fails compilation in const context) on invalid input.
+#[doc(hidden)]
+pub const fn __parse_html_color(html: &str) -> u32 {
+    let bytes = html.as_bytes();
+    let start = if !bytes.is_empty() && bytes[0] == b'#' {
+        1
+    } else {
+        0
+    };
+    let len = bytes.len() - start;
+
+    // Short forms have one digit per channel, which is repeated (e.g. "f80" == "ff8800").
+    let (digits_per_channel, has_alpha) = match len {
+        3 => (1, false),
+        4 => (1, true),
+        6 => (2, false),
+        8 => (2, true),
+        _ => panic!("HTML color must have 3, 4, 6 or 8 hex digits, optionally prefixed with '#'"),
+    };
+
+    let channel_count = if has_alpha { 4 } else { 3 };
+    let mut rgba: u32 = 0;
+    let mut channel = 0;
+    while channel < channel_count {
+        let pos = start + channel * digits_per_channel;
+        let value = if digits_per_channel == 1 {
+            let digit = hex_digit(bytes[pos]);
+            digit * 16 + digit
+        } else {
+            hex_digit(bytes[pos]) * 16 + hex_digit(bytes[pos + 1])
+        };
+
+        rgba = (rgba << 8) | value;
+        channel += 1;
+    }
+
+    if has_alpha {
+        rgba
+    } else {
+        (rgba << 8) | 0xff
+    }
+}
+
+const fn hex_digit(byte: u8) -> u32 {
+    match byte {
+        b'0'..=b'9' => (byte - b'0') as u32,
+        b'a'..=b'f' => (byte - b'a' + 10) as u32,
+        b'A'..=b'F' => (byte - b'A' + 10) as u32,
+        _ => panic!("HTML color contains a character that is not a hex digit"),
+    }
+}
@@ -41,6 +41,8 @@ macro_rules! impl_builtin_traits_inner {
         impl Drop for $Type {
             #[inline]
             fn drop(&mut self) {
+                ::godot_ffi::alloc_stats::on_destruct::<Self>();
+
                 unsafe {
                     let destructor = ::godot_ffi::builtin_fn!($gd_method @1);
                     destructor(self.sys_mut());
@@ -0,0 +1,134 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Batch operations, used by `Transform3D`, `Basis` and `Quaternion` with the `fast-math` feature in single precision.
+//!
+//! Only [`xform()`] is vectorized across elements: vectors are processed four at a time in structure-of-arrays layout, where each `Vec4`
+//! holds the same component of four vectors. [`mul_bases()`] and [`slerp()`] process one element at a time, but compute each element
+//! with glam's `Mat3A` and `Quat` in Rust, rather than calling into Godot. glam maps these types to SSE2, NEON or WASM SIMD registers
+//! where available, and falls back to scalar code elsewhere.
+//!
+//! The module is always compiled in single precision, so that its tests also run without `fast-math`.
+
+use glam::{Mat3A, Vec4};
+
+use crate::builtin::math::{GlamConv, GlamType};
+use crate::builtin::{Basis, Quaternion, Vector3};
+
+/// Computes `*vector = basis * *vector + origin` for each element.
+pub(crate) fn xform(basis: &Basis, origin: Vector3, vectors: &mut [Vector3]) {
+    let rows = basis
+        .rows
+        .map(|row| [Vec4::splat(row.x), Vec4::splat(row.y), Vec4::splat(row.z)]);
+    let offset = [
+        Vec4::splat(origin.x),
+        Vec4::splat(origin.y),
+        Vec4::splat(origin.z),
+    ];
+
+    let mut chunks = vectors.chunks_exact_mut(4);
+    for chunk in &mut chunks {
+        let x = Vec4::new(chunk[0].x, chunk[1].x, chunk[2].x, chunk[3].x);
+        let y = Vec4::new(chunk[0].y, chunk[1].y, chunk[2].y, chunk[3].y);
+        let z = Vec4::new(chunk[0].z, chunk[1].z, chunk[2].z, chunk[3].z);
+
+        let [out_x, out_y, out_z] =
+            [0, 1, 2].map(|i| rows[i][0] * x + rows[i][1] * y + rows[i][2] * z + offset[i]);
+
+        for (lane, vector) in chunk.iter_mut().enumerate() {
+            *vector = Vector3::new(out_x[lane], out_y[lane], out_z[lane]);
+        }
+    }
+
+    for vector in chunks.into_remainder() {
+        *vector = *basis * *vector + origin;
+    }
+}
+
+/// Computes `*basis = lhs * *basis` for each element.
+pub(crate) fn mul_bases(lhs: &Basis, bases: &mut [Basis]) {
+    let lhs = Mat3A::from_front(lhs);
+
+    for basis in bases {
+        *basis = (lhs * Mat3A::from_front(basis)).to_front();
+    }
+}
+
+/// Computes `*from = from.slerp(to, weight)` for each pair of elements.
+///
+/// Like Godot, this interpolates along the shortest path. Inputs must be normalized.
+pub(crate) fn slerp(from: &mut [Quaternion], to: &[Quaternion], weight: f32) {
+    for (from, to) in from.iter_mut().zip(to) {
+        *from = from.to_glam().slerp(to.to_glam(), weight).to_front();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assert_eq_approx;
+    use crate::builtin::real_consts::FRAC_PI_2;
+    use crate::builtin::EulerOrder;
+
+    fn sample_vectors(count: usize) -> Vec<Vector3> {
+        (0..count)
+            .map(|i| {
+                let i = i as f32;
+                Vector3::new(i - 3.0, 2.0 * i, -0.5 * i + 1.0)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn xform_matches_scalar() {
+        let basis = Basis::from_euler(EulerOrder::XYZ, Vector3::new(0.3, -1.2, 2.0))
+            .scaled(Vector3::new(1.5, 0.5, 2.0));
+        let origin = Vector3::new(4.0, -5.0, 6.0);
+
+        // Covers full chunks and a remainder.
+        let original = sample_vectors(11);
+        let mut batch = original.clone();
+        xform(&basis, origin, &mut batch);
+
+        for (original, transformed) in original.iter().zip(batch.iter()) {
+            assert_eq_approx!(*transformed, basis * *original + origin);
+        }
+    }
+
+    #[test]
+    fn mul_bases_matches_scalar() {
+        let lhs = Basis::from_axis_angle(Vector3::UP, FRAC_PI_2);
+        let original = [
+            Basis::IDENTITY,
+            Basis::from_diagonal(2.0, 3.0, 4.0),
+            Basis::from_euler(EulerOrder::YXZ, Vector3::new(1.0, 0.5, -0.25)),
+        ];
+
+        let mut batch = original;
+        mul_bases(&lhs, &mut batch);
+
+        for (original, multiplied) in original.iter().zip(batch.iter()) {
+            assert_eq_approx!(*multiplied, lhs * *original);
+        }
+    }
+
+    #[test]
+    fn slerp_shortest_path() {
+        let from = Quaternion::from_axis_angle(Vector3::UP, 0.0);
+        let to = Quaternion::from_axis_angle(Vector3::UP, FRAC_PI_2);
+
+        let mut batch = [from, from, from];
+        slerp(&mut batch, &[to, -to, to], 0.5);
+
+        let halfway = Quaternion::from_axis_angle(Vector3::UP, FRAC_PI_2 / 2.0);
+        assert_eq_approx!(batch[0], halfway);
+        assert_eq_approx!(batch[1], halfway);
+        assert_eq_approx!(batch[2], halfway);
+    }
+}
@@ -0,0 +1,824 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Deterministic fixed-point arithmetic.
+//!
+//! Floating-point results can differ between platforms, compilers and instruction sets (e.g. through fused multiply-add or different
+//! implementations of `sin()`). Lockstep networking, where each peer simulates the game and only inputs are exchanged, requires bit-identical
+//! results everywhere. [`Fix64`] and the vector types in this module only use integer operations, and are therefore deterministic.
+//!
+//! Convert to floats only for presentation, e.g. when setting a node's position from the simulation state.
+//!
+//! All types implement [`ToGodot`] and [`FromGodot`] without loss of precision, so they can be sent as RPC arguments or stored in
+//! `Variant`s. `Fix64` is represented as `int`, vectors as `PackedInt64Array`.
+//!
+//! # Example
+//! ```no_run
+//! use godot::builtin::math::fixed::{Fix64, FixedVector2};
+//! use godot::builtin::Vector2;
+//!
+//! let speed = Fix64::from_ratio(3, 2);
+//! let direction = FixedVector2::new(Fix64::ONE, Fix64::ONE).normalized();
+//!
+//! let mut position = FixedVector2::ZERO;
+//! position += direction * speed;
+//!
+//! let visible: Vector2 = position.to_vector2();
+//! let back = FixedVector2::from(visible);
+//! ```
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+use crate::builtin::{real, PackedInt64Array, RealConv, Vector2, Vector2i, Vector3, Vector3i};
+use crate::meta::error::{ConvertError, FromGodotError};
+use crate::meta::{FromGodot, GodotConvert, ToGodot};
+
+const FRAC_BITS: u32 = 32;
+const FRAC_MASK: i64 = (1 << FRAC_BITS) - 1;
+const SCALE: f64 = (1u64 << FRAC_BITS) as f64;
+
+/// Signed fixed-point number with 32 integer and 32 fractional bits.
+///
+/// The range is about ±2.1 billion, with a resolution of about 2.3e-10. All operations are deterministic across platforms.
+///
+/// Arithmetic operators behave like those of the underlying `i64`: they panic on overflow in debug builds and wrap in release builds.
+/// Use the `checked_*` methods where overflow is possible. Division by zero panics.
+#[derive(Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[repr(transparent)]
+pub struct Fix64(i64);
+
+impl Fix64 {
+    /// Zero.
+    pub const ZERO: Self = Self(0);
+
+    /// One.
+    pub const ONE: Self = Self(1 << FRAC_BITS);
+
+    /// One half.
+    pub const HALF: Self = Self(1 << (FRAC_BITS - 1));
+
+    /// Smallest positive value, `2^-32`.
+    pub const EPSILON: Self = Self(1);
+
+    /// Largest representable value.
+    pub const MAX: Self = Self(i64::MAX);
+
+    /// Smallest (most negative) representable value.
+    pub const MIN: Self = Self(i64::MIN);
+
+    /// Archimedes' constant π.
+    pub const PI: Self = Self(13_493_037_705);
+
+    /// The full circle constant τ = 2π.
+    pub const TAU: Self = Self(26_986_075_409);
+
+    /// π/2.
+    pub const FRAC_PI_2: Self = Self(6_746_518_852);
+
+    /// π/4.
+    pub const FRAC_PI_4: Self = Self(3_373_259_426);
+
+    /// Creates a value from its raw representation, i.e. the value multiplied by 2^32.
+    pub const fn from_bits(bits: i64) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the raw representation, i.e. the value multiplied by 2^32.
+    pub const fn to_bits(self) -> i64 {
+        self.0
+    }
+
+    /// Creates a value from an integer. This is exact.
+    pub const fn from_i32(value: i32) -> Self {
+        Self((value as i64) << FRAC_BITS)
+    }
+
+    /// Creates the value `numerator / denominator`, rounded towards zero.
+    ///
+    /// # Panics
+    /// If `denominator` is zero.
+    pub const fn from_ratio(numerator: i32, denominator: i32) -> Self {
+        Self(((numerator as i64) << FRAC_BITS) / denominator as i64)
+    }
+
+    /// Converts from a float, rounding to the nearest representable value. Saturates outside the range; NaN becomes zero.
+    ///
+    /// The conversion itself is deterministic, but the input must be as well -- don't convert results of float calculations
+    /// that are then fed back into the simulation.
+    pub fn from_f64(value: f64) -> Self {
+        Self((value * SCALE).round() as i64)
+    }
+
+    /// Converts from a float, see [`from_f64()`][Self::from_f64].
+    pub fn from_f32(value: f32) -> Self {
+        Self::from_f64(value as f64)
+    }
+
+    /// Converts from a [`real`], see [`from_f64()`][Self::from_f64].
+    pub fn from_real(value: real) -> Self {
+        Self::from_f64(value.as_f64())
+    }
+
+    /// Converts to the nearest `f64`.
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / SCALE
+    }
+
+    /// Converts to the nearest `f32`.
+    pub fn to_f32(self) -> f32 {
+        self.to_f64() as f32
+    }
+
+    /// Converts to the nearest [`real`].
+    pub fn to_real(self) -> real {
+        real::from_f64(self.to_f64())
+    }
+
+    /// Returns the integer part, rounded towards negative infinity.
+    pub const fn to_i64(self) -> i64 {
+        self.0 >> FRAC_BITS
+    }
+
+    /// Rounds towards negative infinity.
+    pub const fn floor(self) -> Self {
+        Self(self.0 & !FRAC_MASK)
+    }
+
+    /// Rounds towards positive infinity.
+    pub const fn ceil(self) -> Self {
+        Self(self.0 + FRAC_MASK).floor()
+    }
+
+    /// Rounds to the nearest integer, with halfway cases rounded towards positive infinity.
+    pub const fn round(self) -> Self {
+        Self(self.0 + Self::HALF.0).floor()
+    }
+
+    /// Returns the fractional part, always in `0..1` (also for negative values).
+    pub const fn fract(self) -> Self {
+        Self(self.0 & FRAC_MASK)
+    }
+
+    /// Absolute value.
+    pub const fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+
+    /// Returns `-1`, `0` or `1` depending on the sign.
+    pub const fn signum(self) -> Self {
+        Self::from_i32(self.0.signum() as i32)
+    }
+
+    /// Whether the value is negative.
+    pub const fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    /// Addition, or `None` on overflow.
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_add(rhs.0) {
+            Some(bits) => Some(Self(bits)),
+            None => None,
+        }
+    }
+
+    /// Subtraction, or `None` on overflow.
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_sub(rhs.0) {
+            Some(bits) => Some(Self(bits)),
+            None => None,
+        }
+    }
+
+    /// Multiplication, or `None` on overflow.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let wide = (self.0 as i128 * rhs.0 as i128) >> FRAC_BITS;
+        i64::try_from(wide).ok().map(Self)
+    }
+
+    /// Division, or `None` on overflow or division by zero.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.0 == 0 {
+            return None;
+        }
+
+        let wide = ((self.0 as i128) << FRAC_BITS) / rhs.0 as i128;
+        i64::try_from(wide).ok().map(Self)
+    }
+
+    /// Linear interpolation from `self` to `to` by `weight`.
+    pub fn lerp(self, to: Self, weight: Self) -> Self {
+        self + (to - self) * weight
+    }
+
+    /// Square root, rounded down.
+    ///
+    /// # Panics
+    /// If `self` is negative.
+    pub fn sqrt(self) -> Self {
+        assert!(
+            !self.is_negative(),
+            "Fix64::sqrt() of negative value {self}"
+        );
+
+        // sqrt(bits / 2^32) * 2^32 = sqrt(bits * 2^32).
+        Self(isqrt((self.0 as u128) << FRAC_BITS) as i64)
+    }
+
+    /// Sine, with `self` in radians. The absolute error is below 1e-8.
+    pub fn sin(self) -> Self {
+        // Reduce to [-π/2, π/2], where the Taylor series converges quickly.
+        let mut x = Self(self.0.rem_euclid(Self::TAU.0));
+        if x > Self::PI {
+            x -= Self::TAU;
+        }
+        if x > Self::FRAC_PI_2 {
+            x = Self::PI - x;
+        } else if x < -Self::FRAC_PI_2 {
+            x = -Self::PI - x;
+        }
+
+        // sin(x) = x * (1 - x²/(2*3) * (1 - x²/(4*5) * (1 - ...))).
+        let x2 = x * x;
+        let mut acc = Self::ONE;
+        for divisor in [210, 156, 110, 72, 42, 20, 6] {
+            acc = Self::ONE - Self((x2 * acc).0 / divisor);
+        }
+
+        x * acc
+    }
+
+    /// Cosine, with `self` in radians. The absolute error is below 1e-8.
+    pub fn cos(self) -> Self {
+        Self(self.0.rem_euclid(Self::TAU.0) + Self::FRAC_PI_2.0).sin()
+    }
+
+    /// Sine and cosine at once.
+    pub fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
+    /// Tangent, with `self` in radians.
+    ///
+    /// # Panics
+    /// If the cosine of `self` is zero.
+    pub fn tan(self) -> Self {
+        self.sin() / self.cos()
+    }
+
+    /// Arc tangent, returning radians in `[-π/2, π/2]`.
+    pub fn atan(self) -> Self {
+        self.atan2(Self::ONE)
+    }
+
+    /// Four-quadrant arc tangent of `self / x`, returning radians in `[-π, π]`. Returns zero if both values are zero.
+    pub fn atan2(self, x: Self) -> Self {
+        let y = self;
+        if y == Self::ZERO && x == Self::ZERO {
+            return Self::ZERO;
+        }
+
+        let (abs_y, abs_x) = (y.abs(), x.abs());
+        let mut angle = if abs_y <= abs_x {
+            atan_unit(abs_y / abs_x)
+        } else {
+            Self::FRAC_PI_2 - atan_unit(abs_x / abs_y)
+        };
+
+        if x.is_negative() {
+            angle = Self::PI - angle;
+        }
+        if y.is_negative() {
+            angle = -angle;
+        }
+        angle
+    }
+
+    /// Arc sine, returning radians in `[-π/2, π/2]`.
+    ///
+    /// # Panics
+    /// If `self` is outside `[-1, 1]`.
+    pub fn asin(self) -> Self {
+        self.atan2((Self::ONE - self * self).sqrt())
+    }
+
+    /// Arc cosine, returning radians in `[0, π]`.
+    ///
+    /// # Panics
+    /// If `self` is outside `[-1, 1]`.
+    pub fn acos(self) -> Self {
+        (Self::ONE - self * self).sqrt().atan2(self)
+    }
+}
+
+impl From<i32> for Fix64 {
+    fn from(value: i32) -> Self {
+        Self::from_i32(value)
+    }
+}
+
+impl fmt::Debug for Fix64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Fix64({})", self.to_f64())
+    }
+}
+
+impl fmt::Display for Fix64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f64(), f)
+    }
+}
+
+impl Add for Fix64 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Fix64 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Mul for Fix64 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        from_wide((self.0 as i128 * rhs.0 as i128) >> FRAC_BITS)
+    }
+}
+
+impl Div for Fix64 {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        assert!(rhs.0 != 0, "Fix64: division by zero");
+        from_wide(((self.0 as i128) << FRAC_BITS) / rhs.0 as i128)
+    }
+}
+
+impl Rem for Fix64 {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        Self(self.0 % rhs.0)
+    }
+}
+
+impl Mul<i32> for Fix64 {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self {
+        Self(self.0 * rhs as i64)
+    }
+}
+
+impl Div<i32> for Fix64 {
+    type Output = Self;
+
+    fn div(self, rhs: i32) -> Self {
+        Self(self.0 / rhs as i64)
+    }
+}
+
+impl Neg for Fix64 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+macro_rules! impl_assign_ops {
+    ($Type:ty; $( $Trait:ident :: $method:ident => $op:tt $Rhs:ty ),* $(,)?) => {
+        $(
+            impl $Trait<$Rhs> for $Type {
+                fn $method(&mut self, rhs: $Rhs) {
+                    *self = *self $op rhs;
+                }
+            }
+        )*
+    };
+}
+
+impl_assign_ops!(Fix64;
+    AddAssign::add_assign => + Fix64,
+    SubAssign::sub_assign => - Fix64,
+    MulAssign::mul_assign => * Fix64,
+    DivAssign::div_assign => / Fix64,
+    MulAssign::mul_assign => * i32,
+    DivAssign::div_assign => / i32,
+);
+
+impl std::iter::Sum for Fix64 {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl GodotConvert for Fix64 {
+    type Via = i64;
+}
+
+impl ToGodot for Fix64 {
+    fn to_godot(&self) -> Self::Via {
+        self.0
+    }
+}
+
+impl FromGodot for Fix64 {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Ok(Self(via))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Vectors
+
+/// 2D vector with [`Fix64`] components; the deterministic counterpart of [`Vector2`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct FixedVector2 {
+    pub x: Fix64,
+    pub y: Fix64,
+}
+
+impl FixedVector2 {
+    /// Zero vector.
+    pub const ZERO: Self = Self::new(Fix64::ZERO, Fix64::ZERO);
+
+    /// Vector with all components set to one.
+    pub const ONE: Self = Self::new(Fix64::ONE, Fix64::ONE);
+
+    /// Creates a vector from its components.
+    pub const fn new(x: Fix64, y: Fix64) -> Self {
+        Self { x, y }
+    }
+
+    /// Creates a unit vector pointing in direction `angle` (radians), measured from the positive X axis.
+    pub fn from_angle(angle: Fix64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(cos, sin)
+    }
+
+    /// Converts to the nearest [`Vector2`].
+    pub fn to_vector2(self) -> Vector2 {
+        Vector2::new(self.x.to_real(), self.y.to_real())
+    }
+
+    /// Dot product.
+    pub fn dot(self, other: Self) -> Fix64 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// 2D cross product, i.e. the Z component of the 3D cross product.
+    pub fn cross(self, other: Self) -> Fix64 {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Angle from the positive X axis, in radians.
+    pub fn angle(self) -> Fix64 {
+        self.y.atan2(self.x)
+    }
+
+    /// Vector rotated by `angle` radians.
+    pub fn rotated(self, angle: Fix64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+}
+
+/// 3D vector with [`Fix64`] components; the deterministic counterpart of [`Vector3`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct FixedVector3 {
+    pub x: Fix64,
+    pub y: Fix64,
+    pub z: Fix64,
+}
+
+impl FixedVector3 {
+    /// Zero vector.
+    pub const ZERO: Self = Self::new(Fix64::ZERO, Fix64::ZERO, Fix64::ZERO);
+
+    /// Vector with all components set to one.
+    pub const ONE: Self = Self::new(Fix64::ONE, Fix64::ONE, Fix64::ONE);
+
+    /// Creates a vector from its components.
+    pub const fn new(x: Fix64, y: Fix64, z: Fix64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Converts to the nearest [`Vector3`].
+    pub fn to_vector3(self) -> Vector3 {
+        Vector3::new(self.x.to_real(), self.y.to_real(), self.z.to_real())
+    }
+
+    /// Dot product.
+    pub fn dot(self, other: Self) -> Fix64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Cross product.
+    pub fn cross(self, other: Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+}
+
+macro_rules! impl_fixed_vector {
+    ($Vector:ident, $FloatVector:ident, $IntVector:ident, $len:literal; $($comp:ident),+) => {
+        impl $Vector {
+            /// Squared length. Cheaper than [`length()`][Self::length], and sufficient to compare lengths.
+            pub fn length_squared(self) -> Fix64 {
+                self.dot(self)
+            }
+
+            /// Length (magnitude).
+            pub fn length(self) -> Fix64 {
+                self.length_squared().sqrt()
+            }
+
+            /// Vector scaled to length one, or zero if `self` is zero.
+            pub fn normalized(self) -> Self {
+                let length = self.length();
+                if length == Fix64::ZERO {
+                    Self::ZERO
+                } else {
+                    self / length
+                }
+            }
+
+            /// Distance to `to`.
+            pub fn distance_to(self, to: Self) -> Fix64 {
+                (to - self).length()
+            }
+
+            /// Squared distance to `to`.
+            pub fn distance_squared_to(self, to: Self) -> Fix64 {
+                (to - self).length_squared()
+            }
+
+            /// Linear interpolation from `self` to `to` by `weight`.
+            pub fn lerp(self, to: Self, weight: Fix64) -> Self {
+                self + (to - self) * weight
+            }
+
+            /// Component-wise absolute value.
+            pub fn abs(self) -> Self {
+                Self { $( $comp: self.$comp.abs() ),+ }
+            }
+        }
+
+        impl Add for $Vector {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self { $( $comp: self.$comp + rhs.$comp ),+ }
+            }
+        }
+
+        impl Sub for $Vector {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self { $( $comp: self.$comp - rhs.$comp ),+ }
+            }
+        }
+
+        impl Mul<Fix64> for $Vector {
+            type Output = Self;
+
+            fn mul(self, rhs: Fix64) -> Self {
+                Self { $( $comp: self.$comp * rhs ),+ }
+            }
+        }
+
+        impl Div<Fix64> for $Vector {
+            type Output = Self;
+
+            fn div(self, rhs: Fix64) -> Self {
+                Self { $( $comp: self.$comp / rhs ),+ }
+            }
+        }
+
+        impl Neg for $Vector {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $( $comp: -self.$comp ),+ }
+            }
+        }
+
+        impl_assign_ops!($Vector;
+            AddAssign::add_assign => + $Vector,
+            SubAssign::sub_assign => - $Vector,
+            MulAssign::mul_assign => * Fix64,
+            DivAssign::div_assign => / Fix64,
+        );
+
+        impl From<$IntVector> for $Vector {
+            /// Exact conversion from integer coordinates.
+            fn from(v: $IntVector) -> Self {
+                Self { $( $comp: Fix64::from_i32(v.$comp) ),+ }
+            }
+        }
+
+        impl From<$FloatVector> for $Vector {
+            /// Rounds each component to the nearest representable value.
+            fn from(v: $FloatVector) -> Self {
+                Self { $( $comp: Fix64::from_real(v.$comp) ),+ }
+            }
+        }
+
+        impl From<$Vector> for $FloatVector {
+            fn from(v: $Vector) -> Self {
+                $FloatVector { $( $comp: v.$comp.to_real() ),+ }
+            }
+        }
+
+        impl GodotConvert for $Vector {
+            type Via = PackedInt64Array;
+        }
+
+        impl ToGodot for $Vector {
+            fn to_godot(&self) -> Self::Via {
+                PackedInt64Array::from(&[ $( self.$comp.to_bits() ),+ ])
+            }
+        }
+
+        impl FromGodot for $Vector {
+            fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+                let Ok([ $( $comp ),+ ]) = <[i64; $len]>::try_from(via.as_slice()) else {
+                    return Err(FromGodotError::BadArrayLength {
+                        expected: $len,
+                        actual: via.len(),
+                    }
+                    .into_error(via));
+                };
+
+                Ok(Self { $( $comp: Fix64::from_bits($comp) ),+ })
+            }
+        }
+    };
+}
+
+impl_fixed_vector!(FixedVector2, Vector2, Vector2i, 2; x, y);
+impl_fixed_vector!(FixedVector3, Vector3, Vector3i, 3; x, y, z);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+fn from_wide(wide: i128) -> Fix64 {
+    debug_assert!(
+        i64::try_from(wide).is_ok(),
+        "Fix64: arithmetic overflow ({wide} out of range)"
+    );
+
+    Fix64(wide as i64)
+}
+
+/// Integer square root, rounded down.
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+
+    // Newton's method, starting from a power of two above the root.
+    let mut x = 1u128 << ((128 - n.leading_zeros()) / 2 + 1);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// Arc tangent for `x` in `[0, 1]`.
+fn atan_unit(x: Fix64) -> Fix64 {
+    // atan(x) = 2 * atan(x / (1 + sqrt(1 + x²))) brings the argument below tan(π/8) ≈ 0.414.
+    let t = x / (Fix64::ONE + (Fix64::ONE + x * x).sqrt());
+
+    // atan(t) = t * (1 - t² * (1/3 - t² * (1/5 - ...))).
+    let t2 = t * t;
+    let mut acc = Fix64::ZERO;
+    for k in (1..=11).rev() {
+        acc = Fix64(Fix64::ONE.0 / (2 * k + 1)) - t2 * acc;
+    }
+
+    t * (Fix64::ONE - t2 * acc) * 2
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_close(actual: Fix64, expected: f64) {
+        let diff = (actual.to_f64() - expected).abs();
+        assert!(diff < 1e-8, "{actual} != {expected} (diff {diff})");
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = Fix64::from_ratio(3, 2);
+        let b = Fix64::from_i32(-4);
+
+        assert_eq!(a + b, Fix64::from_ratio(-5, 2));
+        assert_eq!(a - b, Fix64::from_ratio(11, 2));
+        assert_eq!(a * b, Fix64::from_i32(-6));
+        assert_eq!(Fix64::from_i32(3) / b, Fix64::from_ratio(-3, 4));
+        assert_eq!(a * 2, Fix64::from_i32(3));
+        assert_eq!(-a, Fix64::from_f64(-1.5));
+
+        assert_eq!(Fix64::MAX.checked_add(Fix64::EPSILON), None);
+        assert_eq!(Fix64::MAX.checked_mul(Fix64::from_i32(2)), None);
+        assert_eq!(Fix64::ONE.checked_div(Fix64::ZERO), None);
+    }
+
+    #[test]
+    fn rounding() {
+        let v = Fix64::from_f64(-1.25);
+
+        assert_eq!(v.floor(), Fix64::from_i32(-2));
+        assert_eq!(v.ceil(), Fix64::from_i32(-1));
+        assert_eq!(v.round(), Fix64::from_i32(-1));
+        assert_eq!(v.fract(), Fix64::from_f64(0.75));
+        assert_eq!(v.to_i64(), -2);
+        assert_eq!(Fix64::from_f64(2.5).round(), Fix64::from_i32(3));
+    }
+
+    #[test]
+    fn sqrt() {
+        assert_eq!(Fix64::from_i32(16).sqrt(), Fix64::from_i32(4));
+        assert_eq!(Fix64::ZERO.sqrt(), Fix64::ZERO);
+        assert_close(Fix64::from_i32(2).sqrt(), std::f64::consts::SQRT_2);
+        assert_close(Fix64::from_f64(0.01).sqrt(), 0.1);
+        assert_close(Fix64::MAX.sqrt(), (i64::MAX as f64 / SCALE).sqrt());
+    }
+
+    #[test]
+    fn trigonometry() {
+        for i in -40..=40 {
+            let x = i as f64 * 0.25;
+            let fx = Fix64::from_f64(x);
+
+            assert_close(fx.sin(), x.sin());
+            assert_close(fx.cos(), x.cos());
+        }
+
+        for i in -20..=20 {
+            let y = i as f64 * 0.37;
+            for x in [-3.0, -0.5, 0.0, 0.5, 3.0] {
+                assert_close(Fix64::from_f64(y).atan2(Fix64::from_f64(x)), y.atan2(x));
+            }
+        }
+
+        assert_eq!(Fix64::ZERO.atan2(Fix64::ZERO), Fix64::ZERO);
+        assert_close(Fix64::HALF.asin(), 0.5f64.asin());
+        assert_close(Fix64::HALF.acos(), 0.5f64.acos());
+    }
+
+    #[test]
+    fn vectors() {
+        let v = FixedVector2::from(Vector2::new(3.0, 4.0));
+        assert_eq!(v.length(), Fix64::from_i32(5));
+        assert_eq!(v.to_vector2(), Vector2::new(3.0, 4.0));
+        assert_eq!(FixedVector2::from(Vector2i::new(3, 4)), v);
+
+        let n = v.normalized();
+        assert_eq!(n.x, Fix64::from_ratio(3, 5));
+        assert_eq!(FixedVector2::ZERO.normalized(), FixedVector2::ZERO);
+
+        let rotated = FixedVector2::new(Fix64::ONE, Fix64::ZERO).rotated(Fix64::FRAC_PI_2);
+        assert_close(rotated.x, 0.0);
+        assert_close(rotated.y, 1.0);
+
+        let x = FixedVector3::new(Fix64::ONE, Fix64::ZERO, Fix64::ZERO);
+        let y = FixedVector3::new(Fix64::ZERO, Fix64::ONE, Fix64::ZERO);
+        assert_eq!(
+            x.cross(y),
+            FixedVector3::new(Fix64::ZERO, Fix64::ZERO, Fix64::ONE)
+        );
+        assert_eq!(Vector3::from(x + y), Vector3::new(1.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn godot_convert() {
+        let value = Fix64::from_f64(-12.345);
+        assert_eq!(Fix64::from_godot(value.to_godot()), value);
+    }
+}
@@ -6,9 +6,14 @@
  */
 
 mod approx_eq;
+#[cfg(not(feature = "double-precision"))]
+#[cfg_attr(not(feature = "fast-math"), allow(dead_code))]
+mod batch;
 mod float;
 mod glam_helpers;
 
+pub mod fixed;
+
 pub use crate::{assert_eq_approx, assert_ne_approx};
 pub use approx_eq::ApproxEq;
 pub use float::FloatExt;
@@ -16,6 +21,9 @@ pub use float::FloatExt;
 // Internal glam re-exports
 pub(crate) use glam_helpers::*;
 
+#[cfg(all(feature = "fast-math", not(feature = "double-precision")))]
+pub(crate) use batch::*;
+
 #[cfg(test)]
 mod test {
     use super::*;
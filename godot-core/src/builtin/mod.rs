@@ -34,13 +34,12 @@
 //!   overloading would become impossible](https://github.com/kvark/mint/issues/75).
 
 // Re-export macros.
-pub use crate::{array, dict, real, reals, varray};
+pub use crate::{array, dict, palette, real, reals, sname, varray};
 
 // Re-export generated enums.
 pub use crate::gen::central::global_reexported_enums::{Corner, EulerOrder, Side, VariantOperator};
+pub use crate::gen::central::VariantDispatch;
 pub use crate::sys::VariantType;
-// Not yet public.
-pub(crate) use crate::gen::central::VariantDispatch;
 
 #[doc(hidden)]
 pub mod __prelude_reexport {
@@ -52,12 +51,13 @@ pub mod __prelude_reexport {
     pub use collections::containers::*;
     pub use color::*;
     pub use color_hsv::*;
+    pub use color_oklab::*;
     pub use plane::*;
     pub use projection::*;
     pub use quaternion::*;
     pub use real_inner::*;
     pub use rect2::*;
-    pub use rect2i::*;
+    pub use rect2i::Rect2i;
     pub use rid::*;
     pub use signal::*;
     pub use string::{GString, NodePath, StringName};
@@ -67,22 +67,36 @@ pub mod __prelude_reexport {
     pub use vectors::*;
 
     pub use super::{EulerOrder, Side, VariantOperator, VariantType};
-    pub use crate::{array, dict, real, reals, varray};
+    pub use crate::{array, dict, real, reals, sname, varray};
 }
 
 pub use __prelude_reexport::*;
 
+#[doc(hidden)]
+pub use color_palette::__parse_html_color;
+
 /// Math-related functions and traits like [`ApproxEq`][math::ApproxEq].
 pub mod math;
 
-/// Iterator types for arrays and dictionaries.
+/// Structural comparison of arrays and dictionaries, see [`Array::diff()`] and [`Dictionary::diff()`].
+///
+/// Nested arrays and dictionaries are compared element by element. Elements are accessed by reference, so only values that end up in a
+/// [`ChangeSet`][diff::ChangeSet] are cloned.
+pub mod diff {
+    pub use super::collections::diffs::*;
+}
+
+/// Iterator types for arrays, dictionaries and grid cells.
 pub mod iter {
     pub use super::collections::iterators::*;
+    pub use super::rect2i::Cells as Rect2iCells;
 }
 
 /// Specialized types related to Godot's various string implementations.
 pub mod strings {
-    pub use super::string::TransientStringNameOrd;
+    #[cfg(since_api = "4.1")]
+    pub use super::string::StringNameStr;
+    pub use super::string::{GStr, TransientStringNameOrd};
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -99,6 +113,8 @@ mod collections;
 mod color;
 mod color_constants; // After color, so that constants are listed after methods in docs (alphabetic ensures that).
 mod color_hsv;
+mod color_oklab;
+mod color_palette;
 mod plane;
 mod projection;
 mod quaternion;
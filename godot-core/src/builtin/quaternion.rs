@@ -155,6 +155,38 @@ impl Quaternion {
         self.as_inner().slerp(to, weight.as_f64())
     }
 
+    /// Interpolates each element of `quaternions` towards the element of `to` at the same index, in place.
+    ///
+    /// Equivalent to `*q = q.slerp(to[i], weight)` for each element. With the `fast-math` feature (in single precision), this is computed
+    /// in Rust using glam's SIMD quaternion representation, instead of calling into Godot for each element. Results may then differ from
+    /// [`slerp()`][Self::slerp] within floating-point tolerance.
+    ///
+    /// # Panics
+    /// If `quaternions` and `to` have different lengths, or if any quaternion is not normalized.
+    pub fn slerp_all(quaternions: &mut [Self], to: &[Self], weight: real) {
+        assert_eq!(
+            quaternions.len(),
+            to.len(),
+            "slerp_all() requires slices of equal length"
+        );
+
+        #[cfg(all(feature = "fast-math", not(feature = "double-precision")))]
+        {
+            let normalized_inputs = quaternions
+                .iter()
+                .chain(to)
+                .all(|quat| quat.is_normalized());
+            assert!(normalized_inputs, "Slerp requires normalized quaternions");
+
+            crate::builtin::math::slerp(quaternions, to, weight);
+        }
+
+        #[cfg(not(all(feature = "fast-math", not(feature = "double-precision"))))]
+        for (quat, to) in quaternions.iter_mut().zip(to) {
+            *quat = quat.slerp(*to, weight);
+        }
+    }
+
     /// # Panics
     /// If either quaternion is not normalized.
     pub fn slerpni(self, to: Self, weight: real) -> Self {
@@ -241,6 +241,45 @@ impl Rect2i {
         Self::from_corners(new_pos, new_end)
     }
 
+    /// Returns the intersection of all given rectangles, or `None` if they have no area in common or `rects` is empty.
+    ///
+    /// Useful to find the region visible to multiple cameras, or the cells covered by several areas of effect.
+    #[inline]
+    pub fn intersect_many(rects: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut rects = rects.into_iter();
+        let first = rects.next()?;
+
+        rects.try_fold(first, |acc, rect| acc.intersection(rect))
+    }
+
+    /// Returns an iterator over all integer positions inside the `Rect2i`, row by row.
+    ///
+    /// Like [`contains_point()`][Self::contains_point], the right and bottom edges are exclusive. An empty `Rect2i` yields
+    /// no cells.
+    ///
+    /// # Example
+    /// ```
+    /// use godot::builtin::{Rect2i, Vector2i};
+    ///
+    /// let rect = Rect2i::from_components(1, 2, 2, 2);
+    /// let cells: Vec<Vector2i> = rect.iter_cells().collect();
+    ///
+    /// assert_eq!(cells, [
+    ///     Vector2i::new(1, 2), Vector2i::new(2, 2),
+    ///     Vector2i::new(1, 3), Vector2i::new(2, 3),
+    /// ]);
+    /// ```
+    #[inline]
+    pub fn iter_cells(self) -> Cells {
+        self.assert_nonnegative();
+
+        Cells {
+            rect: self,
+            next: 0,
+            len: self.size.x as usize * self.size.y as usize,
+        }
+    }
+
     /// Returns `true` if either of the coordinates of this `Rect2i`s `size` vector is negative.
     #[inline]
     pub const fn is_negative(self) -> bool {
@@ -290,6 +329,43 @@ impl std::fmt::Display for Rect2i {
     }
 }
 
+/// Iterator over the cells of a [`Rect2i`], see [`Rect2i::iter_cells()`].
+///
+/// Exported as `godot::builtin::iter::Rect2iCells`.
+#[derive(Clone, Debug)]
+pub struct Cells {
+    rect: Rect2i,
+    next: usize,
+    len: usize,
+}
+
+impl Iterator for Cells {
+    type Item = Vector2i;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.len {
+            return None;
+        }
+
+        let width = self.rect.size.x as usize;
+        let offset = Vector2i::new((self.next % width) as i32, (self.next / width) as i32);
+        self.next += 1;
+
+        Some(self.rect.position + offset)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Cells {}
+
+impl std::iter::FusedIterator for Cells {}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -601,4 +677,44 @@ mod test {
 
         crate::builtin::test_utils::roundtrip(&rect, expected_json);
     }
+
+    #[test]
+    fn iter_cells() {
+        let rect = Rect2i::from_components(-1, 5, 3, 2);
+        let cells = rect.iter_cells();
+        assert_eq!(cells.len(), 6);
+
+        let cells = cells.collect::<Vec<_>>();
+        assert_eq!(cells.len(), 6);
+        assert_eq!(cells[0], Vector2i::new(-1, 5));
+        assert_eq!(cells[2], Vector2i::new(1, 5));
+        assert_eq!(cells[3], Vector2i::new(-1, 6));
+        assert!(cells.iter().all(|&cell| rect.contains_point(cell)));
+
+        assert_eq!(Rect2i::from_components(3, 3, 0, 5).iter_cells().count(), 0);
+        assert_eq!(Rect2i::default().iter_cells().next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn iter_cells_negative_panics() {
+        let _ = Rect2i::from_components(0, 0, -1, 2).iter_cells();
+    }
+
+    #[test]
+    fn intersect_many() {
+        let a = Rect2i::from_components(0, 0, 10, 10);
+        let b = Rect2i::from_components(2, 3, 10, 10);
+        let c = Rect2i::from_components(-5, 5, 9, 9);
+
+        assert_eq!(
+            Rect2i::intersect_many([a, b, c]),
+            Some(Rect2i::from_components(2, 5, 2, 5))
+        );
+        assert_eq!(Rect2i::intersect_many([a]), Some(a));
+        assert_eq!(Rect2i::intersect_many([]), None);
+
+        let far = Rect2i::from_components(100, 100, 1, 1);
+        assert_eq!(Rect2i::intersect_many([a, b, far]), None);
+    }
 }
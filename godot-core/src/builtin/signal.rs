@@ -10,7 +10,7 @@ use std::ptr;
 
 use godot_ffi as sys;
 
-use crate::builtin::{inner, Array, Callable, Dictionary, StringName, Variant};
+use crate::builtin::{inner, Array, Callable, CallableArgs, Dictionary, StringName, Variant};
 use crate::classes::Object;
 use crate::global::Error;
 use crate::meta::{FromGodot, GodotType, ToGodot};
@@ -94,6 +94,38 @@ impl Signal {
         object.emit_signal(self.name(), varargs);
     }
 
+    /// Emits this signal with arguments given as a tuple of Rust values.
+    ///
+    /// Each argument is converted with [`ToGodot`]; e.g. a `Vec<Gd<T>>` is passed as typed array, which handlers can receive
+    /// as `Vec<Gd<T>>` or `Array<Gd<T>>` parameter again.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # fn emit(area: Gd<Node>, bodies: Vec<Gd<Node>>) {
+    /// // Signal declared as `#[signal] fn bodies_hit(bodies: Vec<Gd<Node>>, damage: i32);`.
+    /// Signal::from_object_signal(&area, "bodies_hit").emit_typed((bodies, 10));
+    /// # }
+    /// ```
+    pub fn emit_typed<A: CallableArgs>(&self, args: A) {
+        let args = args.to_variant_array().iter_shared().collect::<Vec<_>>();
+        self.emit(&args);
+    }
+
+    /// Returns a handle to this signal that can be sent to other threads and emitted from there.
+    ///
+    /// Emissions through the handle are deferred to the main thread, see [`ThreadsafeSignal::emit_deferred()`]. Must be called on the main
+    /// thread.
+    ///
+    /// # Panics
+    /// If this signal has no object.
+    ///
+    /// [`ThreadsafeSignal::emit_deferred()`]: crate::task::ThreadsafeSignal::emit_deferred
+    #[cfg(since_api = "4.2")]
+    pub fn to_threadsafe(&self) -> crate::task::ThreadsafeSignal {
+        crate::task::ThreadsafeSignal::new(self)
+    }
+
     /// Returns an [`Array`] of connections for this signal.
     ///
     /// Each connection is represented as a Dictionary that contains three entries:
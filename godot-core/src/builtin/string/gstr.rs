@@ -0,0 +1,300 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::{self, Write};
+#[cfg(since_api = "4.1")]
+use std::marker::PhantomData;
+#[cfg(since_api = "4.1")]
+use std::ops::Deref;
+use std::ops::Index;
+use std::slice::SliceIndex;
+
+use super::GString;
+#[cfg(since_api = "4.1")]
+use super::StringName;
+
+/// Borrowed view into the characters of a Godot string, analogous to `&str` for `String`.
+///
+/// Godot stores strings as UTF-32. Converting a [`GString`] to a Rust `String` re-encodes every character as UTF-8 and allocates.
+/// `&GStr` instead borrows the characters directly, so that parsing and comparisons do not pay this cost. Obtain it through
+/// [`GString::as_gstr()`], [`StringName::as_gstr()`] or from an existing `&[char]` slice.
+///
+/// Indices and lengths are measured in characters (Unicode scalar values), not bytes.
+///
+/// # Example
+/// ```no_run
+/// use godot::builtin::strings::GStr;
+/// use godot::builtin::GString;
+///
+/// let line = GString::from("key = value");
+/// let view: &GStr = line.as_gstr();
+///
+/// let mut parts = view.split('=');
+/// let key = parts.next().unwrap().trim();
+/// assert_eq!(key, "key");
+/// assert!(view.ends_with("value"));
+/// ```
+#[repr(transparent)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct GStr {
+    chars: [char],
+}
+
+impl GStr {
+    /// Creates a string view from a slice of characters.
+    pub fn from_chars(chars: &[char]) -> &GStr {
+        // SAFETY: GStr is a repr(transparent) wrapper around [char].
+        unsafe { &*(chars as *const [char] as *const GStr) }
+    }
+
+    /// The characters of this string.
+    pub fn chars(&self) -> &[char] {
+        &self.chars
+    }
+
+    /// Number of characters (not bytes).
+    pub fn len(&self) -> usize {
+        self.chars.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chars.is_empty()
+    }
+
+    /// Returns a sub-view for the character range `range`, or `None` if it is out of bounds.
+    pub fn get<R>(&self, range: R) -> Option<&GStr>
+    where
+        R: SliceIndex<[char], Output = [char]>,
+    {
+        self.chars.get(range).map(GStr::from_chars)
+    }
+
+    /// Returns whether the string starts with `prefix`.
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        let mut chars = self.chars.iter().copied();
+
+        prefix
+            .chars()
+            .all(|expected| chars.next() == Some(expected))
+    }
+
+    /// Returns whether the string ends with `suffix`.
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        let mut chars = self.chars.iter().rev().copied();
+
+        suffix
+            .chars()
+            .rev()
+            .all(|expected| chars.next() == Some(expected))
+    }
+
+    /// Returns whether `pattern` occurs in the string.
+    pub fn contains(&self, pattern: &str) -> bool {
+        self.find(pattern).is_some()
+    }
+
+    /// Returns the character index of the first occurrence of `pattern`, or `None` if not found.
+    pub fn find(&self, pattern: &str) -> Option<usize> {
+        let pattern_len = pattern.chars().count();
+        if pattern_len == 0 {
+            return Some(0);
+        }
+
+        self.chars
+            .windows(pattern_len)
+            .position(|window| window.iter().copied().eq(pattern.chars()))
+    }
+
+    /// Returns the character index of the first occurrence of `c`, or `None` if not found.
+    pub fn find_char(&self, c: char) -> Option<usize> {
+        self.chars.iter().position(|&ch| ch == c)
+    }
+
+    /// Splits the string at each occurrence of `separator`.
+    pub fn split(&self, separator: char) -> impl Iterator<Item = &GStr> + '_ {
+        self.chars
+            .split(move |&c| c == separator)
+            .map(GStr::from_chars)
+    }
+
+    /// Splits the string at the first occurrence of `separator`, returning the parts before and after it.
+    pub fn split_once(&self, separator: char) -> Option<(&GStr, &GStr)> {
+        let index = self.find_char(separator)?;
+
+        Some((
+            GStr::from_chars(&self.chars[..index]),
+            GStr::from_chars(&self.chars[index + 1..]),
+        ))
+    }
+
+    /// Returns a view with leading and trailing whitespace removed.
+    pub fn trim(&self) -> &GStr {
+        self.trim_start().trim_end()
+    }
+
+    /// Returns a view with leading whitespace removed.
+    pub fn trim_start(&self) -> &GStr {
+        let start = self
+            .chars
+            .iter()
+            .position(|c| !c.is_whitespace())
+            .unwrap_or(self.chars.len());
+
+        GStr::from_chars(&self.chars[start..])
+    }
+
+    /// Returns a view with trailing whitespace removed.
+    pub fn trim_end(&self) -> &GStr {
+        let end = self
+            .chars
+            .iter()
+            .rposition(|c| !c.is_whitespace())
+            .map_or(0, |i| i + 1);
+
+        GStr::from_chars(&self.chars[..end])
+    }
+
+    /// Parses the string into another type, e.g. a number.
+    ///
+    /// This converts the view to a temporary Rust `String`, since `FromStr` operates on `&str`.
+    pub fn parse<F: std::str::FromStr>(&self) -> Result<F, F::Err> {
+        self.to_string().parse()
+    }
+
+    /// Creates a new, owned Godot string from this view.
+    pub fn to_gstring(&self) -> GString {
+        GString::from(self)
+    }
+}
+
+impl<R> Index<R> for GStr
+where
+    R: SliceIndex<[char], Output = [char]>,
+{
+    type Output = GStr;
+
+    fn index(&self, range: R) -> &GStr {
+        GStr::from_chars(&self.chars[range])
+    }
+}
+
+impl fmt::Display for GStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chars.iter().try_for_each(|&c| f.write_char(c))
+    }
+}
+
+impl fmt::Debug for GStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        for c in self.chars.iter() {
+            for escaped in c.escape_debug() {
+                f.write_char(escaped)?;
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+impl PartialEq<str> for GStr {
+    fn eq(&self, other: &str) -> bool {
+        self.chars.iter().copied().eq(other.chars())
+    }
+}
+
+impl PartialEq<&str> for GStr {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl PartialEq<GStr> for str {
+    fn eq(&self, other: &GStr) -> bool {
+        *other == *self
+    }
+}
+
+#[cfg(since_api = "4.1")]
+impl PartialEq<GString> for GStr {
+    fn eq(&self, other: &GString) -> bool {
+        self.chars == *other.chars()
+    }
+}
+
+impl From<&GStr> for GString {
+    fn from(s: &GStr) -> Self {
+        let utf8: String = s.chars.iter().collect();
+        GString::from(utf8)
+    }
+}
+
+impl From<&GStr> for String {
+    fn from(s: &GStr) -> Self {
+        s.chars.iter().collect()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Borrowed view into the characters of a [`StringName`], dereferencing to [`GStr`].
+///
+/// Obtained through [`StringName::as_gstr()`]. Godot does not expose the characters of a `StringName` directly, so the view holds the
+/// name's string representation. For names created from Rust strings, Godot stores this representation inside the name and only its
+/// reference count is incremented; the characters are not copied. Names created from static Latin-1 C strings (e.g. by
+/// [`sname!`][crate::builtin::sname]) are converted to UTF-32 once per view.
+#[cfg(since_api = "4.1")]
+pub struct StringNameStr<'a> {
+    string: GString,
+    _name: PhantomData<&'a StringName>,
+}
+
+#[cfg(since_api = "4.1")]
+impl<'a> StringNameStr<'a> {
+    pub(super) fn new(name: &'a StringName) -> Self {
+        Self {
+            string: GString::from(name),
+            _name: PhantomData,
+        }
+    }
+}
+
+#[cfg(since_api = "4.1")]
+impl Deref for StringNameStr<'_> {
+    type Target = GStr;
+
+    fn deref(&self) -> &GStr {
+        self.string.as_gstr()
+    }
+}
+
+#[cfg(since_api = "4.1")]
+impl fmt::Display for StringNameStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+#[cfg(since_api = "4.1")]
+impl fmt::Debug for StringNameStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(since_api = "4.1")]
+impl PartialEq<str> for StringNameStr<'_> {
+    fn eq(&self, other: &str) -> bool {
+        **self == *other
+    }
+}
+
+#[cfg(since_api = "4.1")]
+impl PartialEq<&str> for StringNameStr<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        **self == **other
+    }
+}
@@ -14,7 +14,7 @@ use sys::{ffi_methods, interface_fn, GodotFfi};
 use crate::builtin::inner;
 
 use super::string_chars::validate_unicode_scalar_sequence;
-use super::{NodePath, StringName};
+use super::{GStr, NodePath, StringName};
 
 /// Godot's reference counted string type.
 ///
@@ -144,6 +144,14 @@ impl GString {
         }
     }
 
+    /// Returns a borrowed view of the string's characters.
+    ///
+    /// Unlike [`to_string()`][ToString::to_string], this does not re-encode or allocate. See [`GStr`][crate::builtin::strings::GStr] for available operations.
+    #[cfg(since_api = "4.1")]
+    pub fn as_gstr(&self) -> &GStr {
+        GStr::from_chars(self.chars())
+    }
+
     ffi_methods! {
         type sys::GDExtensionStringPtr = *mut Self;
 
@@ -7,6 +7,7 @@
 
 //! Godot-types that are Strings.
 
+mod gstr;
 mod gstring;
 mod macros;
 mod node_path;
@@ -16,6 +17,9 @@ mod string_name;
 use crate::meta::error::ConvertError;
 use crate::meta::{FromGodot, GodotConvert, ToGodot};
 
+pub use gstr::GStr;
+#[cfg(since_api = "4.1")]
+pub use gstr::StringNameStr;
 pub use gstring::*;
 pub use node_path::NodePath;
 pub use string_name::{StringName, TransientStringNameOrd};
@@ -89,6 +89,15 @@ impl StringName {
             .expect("Godot hashes are uint32_t")
     }
 
+    /// Returns a borrowed view of the name's characters.
+    ///
+    /// Unlike [`to_string()`][ToString::to_string], this does not re-encode to UTF-8. See [`StringNameStr`][crate::builtin::strings::StringNameStr]
+    /// for the cost of obtaining the view, and [`GStr`][crate::builtin::strings::GStr] for available operations.
+    #[cfg(since_api = "4.1")]
+    pub fn as_gstr(&self) -> super::StringNameStr<'_> {
+        super::StringNameStr::new(self)
+    }
+
     /// O(1), non-lexicographic, non-stable ordering relation.
     ///
     /// The result of the comparison is **not** lexicographic and **not** stable across multiple runs of your application.
@@ -330,6 +339,80 @@ impl From<&'static std::ffi::CStr> for StringName {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Caching
+
+/// Returns a `&'static StringName` for a string literal, constructed only once.
+///
+/// Constructing a `StringName` requires hashing the string and looking it up in Godot's global name table. In hot code paths, e.g. when
+/// calling methods, emitting signals or querying input actions by name every frame, this cost can add up. This macro lazily creates the
+/// `StringName` on first use and caches it in a `static`, so subsequent evaluations only perform an atomic load. Initialization is
+/// thread-safe.
+///
+/// Since Godot 4.2, ASCII literals are passed to Godot as static Latin-1 strings, which avoids converting and copying the characters.
+/// Whether a literal qualifies is decided at compile time; other literals are converted from UTF-8 as usual.
+///
+/// The cached `StringName` is never destroyed. Each macro invocation site has its own cache, so prefer a helper function or constant
+/// if the same name is used in many places.
+///
+/// # Example
+/// ```no_run
+/// use godot::builtin::{sname, StringName};
+/// use godot::classes::{Input, Node};
+/// use godot::obj::Gd;
+///
+/// fn update(mut node: Gd<Node>) {
+///     if Input::singleton().is_action_pressed(sname!("jump").clone()) {
+///         let method: &'static StringName = sname!("update_hud");
+///         node.call(method.clone(), &[]);
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! sname {
+    ($string:literal) => {{
+        const LATIN1: ::std::option::Option<&'static ::std::ffi::CStr> =
+            $crate::builtin::StringName::__latin1_literal(::std::concat!($string, "\0"));
+
+        static CACHED: ::std::sync::OnceLock<$crate::builtin::StringName> =
+            ::std::sync::OnceLock::new();
+
+        CACHED.get_or_init(|| $crate::builtin::StringName::__from_literal($string, LATIN1))
+    }};
+}
+
+impl StringName {
+    /// Returns the literal as C string, if it can be passed to Godot as Latin-1 without conversion. Used by [`sname!`].
+    #[doc(hidden)]
+    pub const fn __latin1_literal(nul_terminated: &'static str) -> Option<&'static std::ffi::CStr> {
+        let bytes = nul_terminated.as_bytes();
+
+        // Non-ASCII characters are encoded differently in UTF-8 and Latin-1.
+        if !bytes.is_ascii() {
+            return None;
+        }
+
+        // Fails for interior nul bytes.
+        match std::ffi::CStr::from_bytes_with_nul(bytes) {
+            Ok(c_str) => Some(c_str),
+            Err(_) => None,
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn __from_literal(string: &str, latin1: Option<&'static std::ffi::CStr>) -> Self {
+        #[cfg(since_api = "4.2")]
+        if let Some(c_str) = latin1 {
+            return Self::from(c_str);
+        }
+
+        #[cfg(before_api = "4.2")]
+        let _ = latin1;
+
+        Self::from(string)
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Ordering
 
@@ -263,6 +263,39 @@ impl Transform3D {
             origin: self.origin + (self.basis * offset),
         }
     }
+
+    /// Transforms all `points` in place, equivalent to `*point = self * *point` for each element.
+    ///
+    /// Use it for vertex buffers, e.g. on the result of `PackedVector3Array::as_mut_slice()`. With the `fast-math` feature (in single
+    /// precision), points are transformed four at a time using SIMD instructions.
+    pub fn xform_points(&self, points: &mut [Vector3]) {
+        #[cfg(all(feature = "fast-math", not(feature = "double-precision")))]
+        crate::builtin::math::xform(&self.basis, self.origin, points);
+
+        #[cfg(not(all(feature = "fast-math", not(feature = "double-precision"))))]
+        for point in points {
+            *point = *self * *point;
+        }
+    }
+
+    /// Transforms all `vectors` in place, ignoring the translation (origin) of the transform.
+    ///
+    /// Equivalent to `*vector = self.basis * *vector` for each element. Suited for directions and normals in orthonormal transforms.
+    /// Vectorized like [`xform_points()`][Self::xform_points].
+    pub fn xform_vectors(&self, vectors: &mut [Vector3]) {
+        self.basis.xform_vectors(vectors);
+    }
+
+    /// Composes `self` with all `transforms` in place, equivalent to `*transform = self * *transform` for each element.
+    ///
+    /// In single precision, this uses glam's SIMD representation of affine transforms.
+    pub fn compose_all(&self, transforms: &mut [Transform3D]) {
+        let affine = self.to_glam();
+
+        for transform in transforms {
+            *transform = (affine * transform.to_glam()).to_front();
+        }
+    }
 }
 
 impl Display for Transform3D {
@@ -399,6 +432,7 @@ crate::meta::impl_godot_as_self!(Transform3D);
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::assert_eq_approx;
 
     // Tests translated from Godot.
 
@@ -493,6 +527,32 @@ mod test {
         );
     }
 
+    #[test]
+    fn xform_batch() {
+        let points = [
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, -2.0, 3.5),
+            Vector3::new(-4.0, 5.0, 6.0),
+        ];
+
+        let mut batch = points;
+        DUMMY_TRANSFORM.xform_points(&mut batch);
+        for (original, transformed) in points.iter().zip(batch.iter()) {
+            assert_eq_approx!(*transformed, DUMMY_TRANSFORM * *original);
+        }
+
+        let mut batch = points;
+        DUMMY_TRANSFORM.xform_vectors(&mut batch);
+        for (original, transformed) in points.iter().zip(batch.iter()) {
+            assert_eq_approx!(*transformed, DUMMY_TRANSFORM.basis * *original);
+        }
+
+        let mut transforms = [Transform3D::IDENTITY, DUMMY_TRANSFORM];
+        DUMMY_TRANSFORM.compose_all(&mut transforms);
+        assert_eq_approx!(transforms[0], DUMMY_TRANSFORM);
+        assert_eq_approx!(transforms[1], DUMMY_TRANSFORM * DUMMY_TRANSFORM);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
@@ -84,7 +84,9 @@ macro_rules! impl_ffi_variant {
             impl_ffi_variant!(@godot_type_name $T $(, $godot_type_name)?);
         }
 
-        impl ArrayElement for $T {}
+        impl ArrayElement for $T {
+            type __VecVia = Array<$T>;
+        }
     };
 
     (@godot_type_name $T:ty) => {
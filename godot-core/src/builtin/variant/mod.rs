@@ -13,6 +13,11 @@ use std::{fmt, ptr};
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
 mod impls;
+mod ordering;
+mod text;
+
+pub use ordering::VariantOrd;
+pub use text::VariantText;
 
 /// Godot variant type, able to store a variety of different types.
 ///
@@ -57,6 +62,13 @@ impl Variant {
         T::try_from_variant(self)
     }
 
+    /// Decodes the stored value, allowing to `match` on its type.
+    ///
+    /// See [`VariantDispatch`] for an example.
+    pub fn dispatch(&self) -> VariantDispatch {
+        VariantDispatch::from_variant(self)
+    }
+
     /// Checks whether the variant is empty (`null` value in GDScript).
     ///
     /// See also [`Self::get_type`].
@@ -404,7 +416,9 @@ impl Variant {
     }
 }
 
-impl ArrayElement for Variant {}
+impl ArrayElement for Variant {
+    type __VecVia = crate::builtin::VariantArray;
+}
 
 // SAFETY:
 // `from_opaque` properly initializes a dereferenced pointer to an `OpaqueVariant`.
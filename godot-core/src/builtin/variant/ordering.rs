@@ -0,0 +1,268 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use crate::builtin::{GString, Variant, VariantOperator, VariantType};
+use crate::meta::ToGodot;
+use crate::obj::EngineEnum;
+
+impl Variant {
+    /// Compares two variants using a total order, for sorting and ordered collections.
+    ///
+    /// Values are ordered by a single key: first their [`VariantType`], then their contents. Within one type, the order is consistent with
+    /// GDScript's `<` and `==` operators wherever those are defined:
+    /// - `int` and `float` share a type, and compare by exact mathematical value. `NaN` is greater than all other numbers and equal to itself.
+    /// - `String` and `StringName` share a type, and compare by their characters.
+    /// - Vectors, `bool` and `Rid` compare like in GDScript.
+    /// - Arrays compare element-wise using this order, then by length.
+    ///
+    /// Values of other types (e.g. dictionaries) are ordered by their [`hash()`][Self::hash], and then by their string representation.
+    /// This is deterministic within one run, but not meaningful; don't rely on the relative order of e.g. two dictionaries.
+    ///
+    /// Since GDScript compares values of different types as unequal, GDScript's `<` does not carry over to those. Vectors containing `NaN`
+    /// components are not ordered consistently either.
+    ///
+    /// See also [`VariantOrd`], to use variants as keys in a `BTreeMap`.
+    pub fn total_cmp(&self, other: &Variant) -> Ordering {
+        type_rank(self)
+            .cmp(&type_rank(other))
+            .then_with(|| compare_same_rank(self, other))
+    }
+
+    /// Hash that is consistent with [`total_cmp()`][Self::total_cmp]: values which compare equal (like `1` and `1.0`) have the same hash.
+    fn normalized_hash(&self) -> i64 {
+        match self.get_type() {
+            VariantType::FLOAT => {
+                let value = self.to::<f64>();
+
+                if value.fract() == 0.0 && value >= i64::MIN as f64 && value < i64::MAX as f64 {
+                    return Variant::hash(&(value as i64).to_variant());
+                }
+            }
+            VariantType::STRING_NAME => {
+                return Variant::hash(&self.to::<GString>().to_variant());
+            }
+            VariantType::ARRAY => {
+                // FNV-1a over element hashes, so that typed and untyped arrays with equal elements hash alike.
+                return array_elements(self).fold(
+                    0xcbf2_9ce4_8422_2325_u64 as i64,
+                    |hash, element| {
+                        (hash ^ element.normalized_hash()).wrapping_mul(0x0100_0000_01b3)
+                    },
+                );
+            }
+            _ => {}
+        }
+
+        Variant::hash(self)
+    }
+}
+
+/// Uses Godot's `hash()`, which includes the contents of arrays and dictionaries.
+///
+/// Integral floats are hashed like the equivalent `int`, since GDScript considers `1 == 1.0`.
+impl Hash for Variant {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_i64(self.normalized_hash());
+    }
+}
+
+/// Follows GDScript's comparison operators. Returns `None` if the values cannot be compared, e.g. because of different types.
+impl PartialOrd for Variant {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        // Equality first: some types support `==` but not `<`.
+        if self == other {
+            Some(Ordering::Equal)
+        } else if evaluate_bool(self, other, VariantOperator::LESS)? {
+            Some(Ordering::Less)
+        } else if evaluate_bool(other, self, VariantOperator::LESS)? {
+            Some(Ordering::Greater)
+        } else {
+            // Neither less, greater nor equal, e.g. NaN.
+            None
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Wrapper that gives [`Variant`] a total order and equality, so it can be used as a key in `BTreeMap`, `BTreeSet` or `HashMap`.
+///
+/// Ordering follows [`Variant::total_cmp()`]. Two values are equal if they compare equal in GDScript, or if both are `NaN`.
+///
+/// # Example
+/// ```no_run
+/// use std::collections::BTreeMap;
+/// use godot::builtin::{Variant, VariantOrd};
+/// use godot::meta::ToGodot;
+///
+/// let mut map = BTreeMap::new();
+/// map.insert(VariantOrd(3.to_variant()), "three");
+/// map.insert(VariantOrd(1.5.to_variant()), "one and a half");
+///
+/// let keys: Vec<Variant> = map.into_keys().map(|key| key.0).collect();
+/// assert_eq!(keys, [1.5.to_variant(), 3.to_variant()]);
+/// ```
+#[derive(Clone, Default)]
+pub struct VariantOrd(pub Variant);
+
+impl VariantOrd {
+    /// Returns the wrapped variant.
+    pub fn into_inner(self) -> Variant {
+        self.0
+    }
+}
+
+impl PartialEq for VariantOrd {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for VariantOrd {}
+
+impl PartialOrd for VariantOrd {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for VariantOrd {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Hash for VariantOrd {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(&self.0, state);
+    }
+}
+
+impl fmt::Debug for VariantOrd {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Variant> for VariantOrd {
+    fn from(variant: Variant) -> Self {
+        Self(variant)
+    }
+}
+
+impl From<VariantOrd> for Variant {
+    fn from(key: VariantOrd) -> Self {
+        key.0
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+fn evaluate_bool(lhs: &Variant, rhs: &Variant, op: VariantOperator) -> Option<bool> {
+    lhs.evaluate(rhs, op).map(|result| result.booleanize())
+}
+
+fn is_number(variant: &Variant) -> bool {
+    let ty = variant.get_type();
+    ty == VariantType::INT || ty == VariantType::FLOAT
+}
+
+/// Rank of a type in [`Variant::total_cmp()`]. Types whose values can be equal in GDScript share a rank.
+fn type_rank(variant: &Variant) -> i32 {
+    match variant.get_type() {
+        VariantType::FLOAT => VariantType::INT.ord(),
+        VariantType::STRING_NAME => VariantType::STRING.ord(),
+        ty => ty.ord(),
+    }
+}
+
+/// Compares two values of the same [`type_rank()`].
+fn compare_same_rank(lhs: &Variant, rhs: &Variant) -> Ordering {
+    use VariantType as T;
+
+    match lhs.get_type() {
+        T::NIL => Ordering::Equal,
+        T::INT | T::FLOAT => compare_numbers(lhs, rhs),
+        T::STRING | T::STRING_NAME => lhs.to::<GString>().cmp(&rhs.to::<GString>()),
+        T::ARRAY => {
+            let mut lhs_elements = array_elements(lhs);
+            let mut rhs_elements = array_elements(rhs);
+
+            loop {
+                match (lhs_elements.next(), rhs_elements.next()) {
+                    (Some(l), Some(r)) => {
+                        let ordering = l.total_cmp(&r);
+                        if ordering != Ordering::Equal {
+                            return ordering;
+                        }
+                    }
+                    // Shorter array is less, if it is a prefix of the longer one.
+                    (l, r) => return l.is_some().cmp(&r.is_some()),
+                }
+            }
+        }
+
+        // Types for which GDScript's `<` is a total order.
+        T::BOOL
+        | T::VECTOR2
+        | T::VECTOR2I
+        | T::VECTOR3
+        | T::VECTOR3I
+        | T::VECTOR4
+        | T::VECTOR4I
+        | T::RID => lhs.partial_cmp(rhs).unwrap_or(Ordering::Equal),
+
+        _ => Variant::hash(lhs)
+            .cmp(&Variant::hash(rhs))
+            .then_with(|| lhs.stringify().cmp(&rhs.stringify())),
+    }
+}
+
+/// Compares `int`/`float` values by exact value; `NaN` is greater than all other numbers.
+fn compare_numbers(lhs: &Variant, rhs: &Variant) -> Ordering {
+    match (lhs.get_type(), rhs.get_type()) {
+        (VariantType::INT, VariantType::INT) => lhs.to::<i64>().cmp(&rhs.to::<i64>()),
+        (VariantType::INT, _) => compare_int_float(lhs.to::<i64>(), rhs.to::<f64>()),
+        (_, VariantType::INT) => compare_int_float(rhs.to::<i64>(), lhs.to::<f64>()).reverse(),
+        _ => {
+            let (l, r) = (lhs.to::<f64>(), rhs.to::<f64>());
+            l.partial_cmp(&r)
+                .unwrap_or_else(|| l.is_nan().cmp(&r.is_nan()))
+        }
+    }
+}
+
+/// Compares without converting `int` to `float`, which would lose precision above 2^53.
+fn compare_int_float(int: i64, float: f64) -> Ordering {
+    // 2^63 is exactly representable; everything in [-2^63, 2^63) truncates to a valid i64.
+    const BOUND: f64 = 9_223_372_036_854_775_808.0;
+
+    if float.is_nan() || float >= BOUND {
+        return Ordering::Less;
+    }
+    if float < -BOUND {
+        return Ordering::Greater;
+    }
+
+    let truncated = float.trunc();
+    int.cmp(&(truncated as i64)).then_with(|| {
+        // Same integral part: the float is larger if it has a positive fraction, smaller if negative.
+        truncated.partial_cmp(&float).expect("float is not NaN")
+    })
+}
+
+/// Iterates over the elements of a variant holding a (possibly typed) array.
+fn array_elements(array: &Variant) -> impl Iterator<Item = Variant> + '_ {
+    // Goes through the variant, since typed arrays cannot be converted to VariantArray.
+    let len = array.call("size", &[]).to::<i64>();
+    (0..len).map(move |index| array.call("get", &[index.to_variant()]))
+}
@@ -0,0 +1,1302 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Text format of GDScript's `var_to_str()` and `str_to_var()`, implemented in Rust.
+
+use std::fmt;
+use std::fmt::Write as _;
+
+use crate::builtin::{
+    real, Aabb, Basis, Color, Dictionary, GString, NodePath, PackedByteArray, PackedColorArray,
+    PackedFloat32Array, PackedFloat64Array, PackedInt32Array, PackedInt64Array, PackedStringArray,
+    PackedVector2Array, PackedVector3Array, Plane, Projection, Quaternion, RealConv, Rect2, Rect2i,
+    StringName, Transform2D, Transform3D, Variant, VariantArray, VariantType, Vector2, Vector2i,
+    Vector3, Vector3i, Vector4, Vector4i,
+};
+use crate::meta::error::ConvertError;
+use crate::meta::ToGodot;
+
+#[cfg(since_api = "4.3")]
+use crate::builtin::PackedVector4Array;
+
+impl Variant {
+    /// Serializes the variant in the text format of GDScript's `var_to_str()`.
+    ///
+    /// This is meant for diffable save files and configuration. Floats are written with 6 significant digits, like in the engine.
+    ///
+    /// # Errors
+    /// If the variant contains an object, callable, signal or RID. The engine writes those in ways that cannot be read back reliably.
+    pub fn to_text(&self) -> Result<String, ConvertError> {
+        VariantText::from_variant(self).map(|text| text.to_string())
+    }
+
+    /// Deserializes a variant from the text format of GDScript's `var_to_str()`, the counterpart to [`to_text()`][Self::to_text].
+    ///
+    /// # Errors
+    /// If `text` is not valid, or contains types which [`VariantText`] does not support.
+    pub fn from_text(text: &str) -> Result<Variant, ConvertError> {
+        VariantText::parse(text).map(|text| text.to_variant())
+    }
+}
+
+/// Value in the text format of GDScript's `var_to_str()` and `str_to_var()`, independent of the engine.
+///
+/// Unlike [`Variant`], this type does not need a running Godot instance: it can be parsed, inspected and written in build scripts or
+/// unit tests. [`from_variant()`][Self::from_variant] and [`to_variant()`][Self::to_variant] convert from/to actual variants.
+///
+/// `Display` writes the text format, and [`parse()`][Self::parse] reads it.
+///
+/// # Example
+/// ```
+/// use godot::builtin::{Vector2, VariantText};
+///
+/// let value = VariantText::Dictionary(vec![
+///     (VariantText::String("pos".into()), VariantText::Vector2(Vector2::new(1.5, -2.0))),
+/// ]);
+///
+/// let text = value.to_string();
+/// assert_eq!(text, "{\n\"pos\": Vector2(1.5, -2)\n}");
+/// assert_eq!(VariantText::parse(&text).unwrap(), value);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub enum VariantText {
+    Nil,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(String),
+    StringName(String),
+    NodePath(String),
+    Vector2(Vector2),
+    Vector2i(Vector2i),
+    Rect2(Rect2),
+    Rect2i(Rect2i),
+    Vector3(Vector3),
+    Vector3i(Vector3i),
+    Transform2D(Transform2D),
+    Vector4(Vector4),
+    Vector4i(Vector4i),
+    Plane(Plane),
+    Quaternion(Quaternion),
+    Aabb(Aabb),
+    Basis(Basis),
+    Transform3D(Transform3D),
+    Projection(Projection),
+    Color(Color),
+
+    /// Array, optionally typed. `element_type` is the Godot name of the element type (e.g. `int`, `Vector2`), or a class name.
+    Array {
+        element_type: Option<String>,
+        elements: Vec<VariantText>,
+    },
+
+    /// Dictionary entries, written in this order.
+    Dictionary(Vec<(VariantText, VariantText)>),
+
+    PackedByteArray(Vec<u8>),
+    PackedInt32Array(Vec<i32>),
+    PackedInt64Array(Vec<i64>),
+    PackedFloat32Array(Vec<f32>),
+    PackedFloat64Array(Vec<f64>),
+    PackedStringArray(Vec<String>),
+    PackedVector2Array(Vec<Vector2>),
+    PackedVector3Array(Vec<Vector3>),
+    PackedColorArray(Vec<Color>),
+    #[cfg(since_api = "4.3")]
+    PackedVector4Array(Vec<Vector4>),
+}
+
+impl VariantText {
+    /// Parses a single value in the format of GDScript's `str_to_var()`.
+    pub fn parse(text: &str) -> Result<Self, ConvertError> {
+        let mut parser = Parser::new(text);
+        let value = parser.parse_value()?;
+
+        parser.skip_whitespace();
+        if parser.peek().is_some() {
+            return Err(parser.error("unexpected trailing characters"));
+        }
+
+        Ok(value)
+    }
+
+    /// Converts a variant to its text representation.
+    ///
+    /// Dictionary keys are sorted with [`Variant::total_cmp()`], like the engine does for types that GDScript can compare.
+    ///
+    /// # Errors
+    /// If the variant (or one of its elements) holds an object, callable, signal or RID.
+    pub fn from_variant(variant: &Variant) -> Result<Self, ConvertError> {
+        let text = match variant.get_type() {
+            VariantType::NIL => Self::Nil,
+            VariantType::BOOL => Self::Bool(variant.to()),
+            VariantType::INT => Self::Int(variant.to()),
+            VariantType::FLOAT => Self::Float(variant.to()),
+            VariantType::STRING => Self::String(variant.to::<GString>().to_string()),
+            VariantType::STRING_NAME => Self::StringName(variant.to::<StringName>().to_string()),
+            VariantType::NODE_PATH => Self::NodePath(variant.to::<NodePath>().to_string()),
+            VariantType::VECTOR2 => Self::Vector2(variant.to()),
+            VariantType::VECTOR2I => Self::Vector2i(variant.to()),
+            VariantType::RECT2 => Self::Rect2(variant.to()),
+            VariantType::RECT2I => Self::Rect2i(variant.to()),
+            VariantType::VECTOR3 => Self::Vector3(variant.to()),
+            VariantType::VECTOR3I => Self::Vector3i(variant.to()),
+            VariantType::TRANSFORM2D => Self::Transform2D(variant.to()),
+            VariantType::VECTOR4 => Self::Vector4(variant.to()),
+            VariantType::VECTOR4I => Self::Vector4i(variant.to()),
+            VariantType::PLANE => Self::Plane(variant.to()),
+            VariantType::QUATERNION => Self::Quaternion(variant.to()),
+            VariantType::AABB => Self::Aabb(variant.to()),
+            VariantType::BASIS => Self::Basis(variant.to()),
+            VariantType::TRANSFORM3D => Self::Transform3D(variant.to()),
+            VariantType::PROJECTION => Self::Projection(variant.to()),
+            VariantType::COLOR => Self::Color(variant.to()),
+            VariantType::ARRAY => {
+                // SAFETY: the array is only read.
+                let array = unsafe { VariantArray::from_variant_unchecked(variant) }
+                    .expect("variant of type ARRAY");
+
+                let type_info = array.type_info();
+                let element_type = if !type_info.is_typed() {
+                    None
+                } else if type_info.variant_type() == VariantType::OBJECT {
+                    Some(type_info.class_name().to_string())
+                } else {
+                    Some(type_name(type_info.variant_type()).to_string())
+                };
+
+                let elements = array
+                    .iter_shared()
+                    .map(|element| Self::from_variant(&element))
+                    .collect::<Result<_, _>>()?;
+
+                Self::Array {
+                    element_type,
+                    elements,
+                }
+            }
+            VariantType::DICTIONARY => {
+                let mut entries = variant.to::<Dictionary>().iter_shared().collect::<Vec<_>>();
+                entries.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+                let entries = entries
+                    .into_iter()
+                    .map(|(key, value)| {
+                        Ok((Self::from_variant(&key)?, Self::from_variant(&value)?))
+                    })
+                    .collect::<Result<_, ConvertError>>()?;
+
+                Self::Dictionary(entries)
+            }
+            VariantType::PACKED_BYTE_ARRAY => {
+                Self::PackedByteArray(variant.to::<PackedByteArray>().to_vec())
+            }
+            VariantType::PACKED_INT32_ARRAY => {
+                Self::PackedInt32Array(variant.to::<PackedInt32Array>().to_vec())
+            }
+            VariantType::PACKED_INT64_ARRAY => {
+                Self::PackedInt64Array(variant.to::<PackedInt64Array>().to_vec())
+            }
+            VariantType::PACKED_FLOAT32_ARRAY => {
+                Self::PackedFloat32Array(variant.to::<PackedFloat32Array>().to_vec())
+            }
+            VariantType::PACKED_FLOAT64_ARRAY => {
+                Self::PackedFloat64Array(variant.to::<PackedFloat64Array>().to_vec())
+            }
+            VariantType::PACKED_STRING_ARRAY => {
+                Self::PackedStringArray(variant.to::<PackedStringArray>().to_vec_string())
+            }
+            VariantType::PACKED_VECTOR2_ARRAY => {
+                Self::PackedVector2Array(variant.to::<PackedVector2Array>().to_vec())
+            }
+            VariantType::PACKED_VECTOR3_ARRAY => {
+                Self::PackedVector3Array(variant.to::<PackedVector3Array>().to_vec())
+            }
+            VariantType::PACKED_COLOR_ARRAY => {
+                Self::PackedColorArray(variant.to::<PackedColorArray>().to_vec())
+            }
+            #[cfg(since_api = "4.3")]
+            VariantType::PACKED_VECTOR4_ARRAY => {
+                Self::PackedVector4Array(variant.to::<PackedVector4Array>().to_vec())
+            }
+            other => {
+                return Err(ConvertError::new(format!(
+                    "variant of type {other:?} cannot be represented as text"
+                )))
+            }
+        };
+
+        Ok(text)
+    }
+
+    /// Converts to an actual variant.
+    ///
+    /// Typed arrays keep their element type.
+    pub fn to_variant(&self) -> Variant {
+        match self {
+            Self::Nil => Variant::nil(),
+            Self::Bool(value) => value.to_variant(),
+            Self::Int(value) => value.to_variant(),
+            Self::Float(value) => value.to_variant(),
+            Self::String(value) => GString::from(value).to_variant(),
+            Self::StringName(value) => StringName::from(value).to_variant(),
+            Self::NodePath(value) => NodePath::from(value).to_variant(),
+            Self::Vector2(value) => value.to_variant(),
+            Self::Vector2i(value) => value.to_variant(),
+            Self::Rect2(value) => value.to_variant(),
+            Self::Rect2i(value) => value.to_variant(),
+            Self::Vector3(value) => value.to_variant(),
+            Self::Vector3i(value) => value.to_variant(),
+            Self::Transform2D(value) => value.to_variant(),
+            Self::Vector4(value) => value.to_variant(),
+            Self::Vector4i(value) => value.to_variant(),
+            Self::Plane(value) => value.to_variant(),
+            Self::Quaternion(value) => value.to_variant(),
+            Self::Aabb(value) => value.to_variant(),
+            Self::Basis(value) => value.to_variant(),
+            Self::Transform3D(value) => value.to_variant(),
+            Self::Projection(value) => value.to_variant(),
+            Self::Color(value) => value.to_variant(),
+            Self::Array {
+                element_type,
+                elements,
+            } => {
+                let mut array = match element_type.as_deref() {
+                    None => VariantArray::new(),
+                    Some(name) => {
+                        let (variant_type, class_name) = match type_from_name(name) {
+                            Some(variant_type) => (variant_type, StringName::default()),
+                            None => (VariantType::OBJECT, StringName::from(name)),
+                        };
+
+                        // SAFETY: the array is only filled with the parsed elements, which the engine checks against the element type.
+                        unsafe { VariantArray::new_with_runtime_type(variant_type, &class_name) }
+                    }
+                };
+
+                for element in elements {
+                    array.push(element.to_variant());
+                }
+
+                array.to_variant()
+            }
+            Self::Dictionary(entries) => entries
+                .iter()
+                .map(|(key, value)| (key.to_variant(), value.to_variant()))
+                .collect::<Dictionary>()
+                .to_variant(),
+            Self::PackedByteArray(values) => PackedByteArray::from(values.as_slice()).to_variant(),
+            Self::PackedInt32Array(values) => {
+                PackedInt32Array::from(values.as_slice()).to_variant()
+            }
+            Self::PackedInt64Array(values) => {
+                PackedInt64Array::from(values.as_slice()).to_variant()
+            }
+            Self::PackedFloat32Array(values) => {
+                PackedFloat32Array::from(values.as_slice()).to_variant()
+            }
+            Self::PackedFloat64Array(values) => {
+                PackedFloat64Array::from(values.as_slice()).to_variant()
+            }
+            Self::PackedStringArray(values) => {
+                PackedStringArray::from(values.as_slice()).to_variant()
+            }
+            Self::PackedVector2Array(values) => {
+                PackedVector2Array::from(values.as_slice()).to_variant()
+            }
+            Self::PackedVector3Array(values) => {
+                PackedVector3Array::from(values.as_slice()).to_variant()
+            }
+            Self::PackedColorArray(values) => {
+                PackedColorArray::from(values.as_slice()).to_variant()
+            }
+            #[cfg(since_api = "4.3")]
+            Self::PackedVector4Array(values) => {
+                PackedVector4Array::from(values.as_slice()).to_variant()
+            }
+        }
+    }
+}
+
+impl fmt::Display for VariantText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Nil => f.write_str("null"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Int(value) => write!(f, "{value}"),
+            Self::Float(value) => f.write_str(&format_float(*value)),
+            Self::String(value) => write_string(f, value),
+            Self::StringName(value) => {
+                f.write_char('&')?;
+                write_escaped_string(f, value)
+            }
+            Self::NodePath(value) => {
+                f.write_str("NodePath(")?;
+                write_escaped_string(f, value)?;
+                f.write_char(')')
+            }
+            Self::Vector2(v) => write_reals(f, "Vector2", &[v.x, v.y]),
+            Self::Vector2i(v) => write_ints(f, "Vector2i", &[v.x, v.y]),
+            Self::Rect2(r) => write_reals(
+                f,
+                "Rect2",
+                &[r.position.x, r.position.y, r.size.x, r.size.y],
+            ),
+            Self::Rect2i(r) => write_ints(
+                f,
+                "Rect2i",
+                &[r.position.x, r.position.y, r.size.x, r.size.y],
+            ),
+            Self::Vector3(v) => write_reals(f, "Vector3", &[v.x, v.y, v.z]),
+            Self::Vector3i(v) => write_ints(f, "Vector3i", &[v.x, v.y, v.z]),
+            Self::Transform2D(t) => write_reals(
+                f,
+                "Transform2D",
+                &[t.a.x, t.a.y, t.b.x, t.b.y, t.origin.x, t.origin.y],
+            ),
+            Self::Vector4(v) => write_reals(f, "Vector4", &[v.x, v.y, v.z, v.w]),
+            Self::Vector4i(v) => write_ints(f, "Vector4i", &[v.x, v.y, v.z, v.w]),
+            Self::Plane(p) => write_reals(f, "Plane", &[p.normal.x, p.normal.y, p.normal.z, p.d]),
+            Self::Quaternion(q) => write_reals(f, "Quaternion", &[q.x, q.y, q.z, q.w]),
+            Self::Aabb(b) => write_reals(
+                f,
+                "AABB",
+                &[
+                    b.position.x,
+                    b.position.y,
+                    b.position.z,
+                    b.size.x,
+                    b.size.y,
+                    b.size.z,
+                ],
+            ),
+            Self::Basis(b) => write_reals(f, "Basis", &basis_reals(b)),
+            Self::Transform3D(t) => {
+                let mut reals = basis_reals(&t.basis).to_vec();
+                reals.extend([t.origin.x, t.origin.y, t.origin.z]);
+                write_reals(f, "Transform3D", &reals)
+            }
+            Self::Projection(p) => {
+                let reals = p
+                    .cols
+                    .iter()
+                    .flat_map(|c| [c.x, c.y, c.z, c.w])
+                    .collect::<Vec<_>>();
+                write_reals(f, "Projection", &reals)
+            }
+            Self::Color(c) => write_floats(f, "Color", &[c.r, c.g, c.b, c.a].map(f64::from)),
+            Self::Array {
+                element_type,
+                elements,
+            } => {
+                if let Some(element_type) = element_type {
+                    write!(f, "Array[{element_type}](")?;
+                }
+
+                f.write_char('[')?;
+                for (i, element) in elements.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    element.fmt(f)?;
+                }
+                f.write_char(']')?;
+
+                if element_type.is_some() {
+                    f.write_char(')')?;
+                }
+                Ok(())
+            }
+            Self::Dictionary(entries) => {
+                if entries.is_empty() {
+                    return f.write_str("{}");
+                }
+
+                f.write_str("{\n")?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",\n")?;
+                    }
+                    write!(f, "{key}: {value}")?;
+                }
+                f.write_str("\n}")
+            }
+            Self::PackedByteArray(values) => write_ints(f, "PackedByteArray", values),
+            Self::PackedInt32Array(values) => write_ints(f, "PackedInt32Array", values),
+            Self::PackedInt64Array(values) => write_ints(f, "PackedInt64Array", values),
+            Self::PackedFloat32Array(values) => {
+                let values = values.iter().map(|&v| f64::from(v)).collect::<Vec<_>>();
+                write_floats(f, "PackedFloat32Array", &values)
+            }
+            Self::PackedFloat64Array(values) => write_floats(f, "PackedFloat64Array", values),
+            Self::PackedStringArray(values) => {
+                f.write_str("PackedStringArray(")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write_string(f, value)?;
+                }
+                f.write_char(')')
+            }
+            Self::PackedVector2Array(values) => {
+                let reals = values.iter().flat_map(|v| [v.x, v.y]).collect::<Vec<_>>();
+                write_reals(f, "PackedVector2Array", &reals)
+            }
+            Self::PackedVector3Array(values) => {
+                let reals = values
+                    .iter()
+                    .flat_map(|v| [v.x, v.y, v.z])
+                    .collect::<Vec<_>>();
+                write_reals(f, "PackedVector3Array", &reals)
+            }
+            Self::PackedColorArray(values) => {
+                let floats = values
+                    .iter()
+                    .flat_map(|c| [c.r, c.g, c.b, c.a].map(f64::from))
+                    .collect::<Vec<_>>();
+                write_floats(f, "PackedColorArray", &floats)
+            }
+            #[cfg(since_api = "4.3")]
+            Self::PackedVector4Array(values) => {
+                let reals = values
+                    .iter()
+                    .flat_map(|v| [v.x, v.y, v.z, v.w])
+                    .collect::<Vec<_>>();
+                write_reals(f, "PackedVector4Array", &reals)
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Writing
+
+fn basis_reals(basis: &Basis) -> [real; 9] {
+    let [x, y, z] = basis.rows;
+    [x.x, x.y, x.z, y.x, y.y, y.z, z.x, z.y, z.z]
+}
+
+/// Writes a string literal. Like the engine, only backslashes and quotes are escaped; newlines are kept.
+fn write_string(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in value.chars() {
+        match c {
+            '\\' => f.write_str("\\\\")?,
+            '"' => f.write_str("\\\"")?,
+            _ => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+/// Writes a string literal with all control characters escaped, which the engine uses for `StringName` and `NodePath`.
+fn write_escaped_string(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in value.chars() {
+        match c {
+            '\\' => f.write_str("\\\\")?,
+            '\u{7}' => f.write_str("\\a")?,
+            '\u{8}' => f.write_str("\\b")?,
+            '\u{c}' => f.write_str("\\f")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            '\u{b}' => f.write_str("\\v")?,
+            '\'' => f.write_str("\\'")?,
+            '?' => f.write_str("\\?")?,
+            '"' => f.write_str("\\\"")?,
+            _ => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+fn write_ints<T: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    values: &[T],
+) -> fmt::Result {
+    write!(f, "{name}(")?;
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        write!(f, "{value}")?;
+    }
+    f.write_char(')')
+}
+
+fn write_reals(f: &mut fmt::Formatter<'_>, name: &str, values: &[real]) -> fmt::Result {
+    let values = values.iter().map(|&v| v.as_f64()).collect::<Vec<_>>();
+    write_floats(f, name, &values)
+}
+
+fn write_floats(f: &mut fmt::Formatter<'_>, name: &str, values: &[f64]) -> fmt::Result {
+    write!(f, "{name}(")?;
+    for (i, &value) in values.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        f.write_str(&format_component(value))?;
+    }
+    f.write_char(')')
+}
+
+/// Formats a standalone `float`, which always has a decimal point or exponent, so it's read back as float.
+fn format_float(value: f64) -> String {
+    let mut text = format_component(value);
+    if value.is_finite() && !text.contains(['.', 'e']) {
+        text.push_str(".0");
+    }
+    text
+}
+
+/// Formats a float inside a compound type, e.g. `1` or `0.5` in `Vector2(1, 0.5)`.
+fn format_component(value: f64) -> String {
+    if value == 0.0 {
+        // Also for -0.0, to avoid spurious diffs.
+        "0".to_string()
+    } else if value.is_nan() {
+        "nan".to_string()
+    } else if value.is_infinite() {
+        let text = if value > 0.0 { "inf" } else { "inf_neg" };
+        text.to_string()
+    } else {
+        format_general(value)
+    }
+}
+
+/// Equivalent of C's `printf("%g")`: 6 significant digits, scientific notation for very small or large numbers, no trailing zeros.
+fn format_general(value: f64) -> String {
+    const PRECISION: i32 = 6;
+
+    let scientific = format!("{:.*e}", (PRECISION - 1) as usize, value);
+    let (mantissa, exponent) = scientific.split_once('e').expect("scientific notation");
+    let exponent: i32 = exponent.parse().expect("integer exponent");
+
+    if (-4..PRECISION).contains(&exponent) {
+        let decimals = (PRECISION - 1 - exponent) as usize;
+        trim_zeros(format!("{value:.decimals$}"))
+    } else {
+        let sign = if exponent < 0 { '-' } else { '+' };
+        format!(
+            "{}e{sign}{:02}",
+            trim_zeros(mantissa.to_string()),
+            exponent.abs()
+        )
+    }
+}
+
+fn trim_zeros(mut number: String) -> String {
+    if number.contains('.') {
+        let trimmed = number.trim_end_matches('0').trim_end_matches('.').len();
+        number.truncate(trimmed);
+    }
+    number
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Parsing
+
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { text, pos: 0 }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn error(&self, message: &str) -> ConvertError {
+        let line = self.text[..self.pos].matches('\n').count() + 1;
+        ConvertError::new(format!("invalid variant text at line {line}: {message}"))
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ConvertError> {
+        self.skip_whitespace();
+        if self.bump() == Some(expected) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{expected}`")))
+        }
+    }
+
+    /// Consumes `c` if it's the next non-whitespace character.
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(c) {
+            self.bump();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<VariantText, ConvertError> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some('"') => self.parse_string().map(VariantText::String),
+            Some('&') => {
+                self.bump();
+                self.parse_string().map(VariantText::StringName)
+            }
+            Some('^') => {
+                self.bump();
+                self.parse_string().map(VariantText::NodePath)
+            }
+            Some('[') => Ok(VariantText::Array {
+                element_type: None,
+                elements: self.parse_array()?,
+            }),
+            Some('{') => self.parse_dictionary(),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.parse_number(),
+            Some(c) if c.is_alphabetic() || c == '_' => {
+                let ident = self.parse_ident();
+                self.parse_ident_value(&ident)
+            }
+            Some(_) => Err(self.error("unexpected character")),
+            None => Err(self.error("unexpected end of text")),
+        }
+    }
+
+    fn parse_ident(&mut self) -> String {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_alphanumeric() || c == '_') {
+            self.bump();
+        }
+        self.text[start..self.pos].to_string()
+    }
+
+    fn parse_ident_value(&mut self, ident: &str) -> Result<VariantText, ConvertError> {
+        let value = match ident {
+            "null" => VariantText::Nil,
+            "true" => VariantText::Bool(true),
+            "false" => VariantText::Bool(false),
+            "inf" => VariantText::Float(f64::INFINITY),
+            "inf_neg" => VariantText::Float(f64::NEG_INFINITY),
+            "nan" => VariantText::Float(f64::NAN),
+            "Array" => {
+                let element_type = if self.eat('[') {
+                    self.skip_whitespace();
+                    let element_type = self.parse_ident();
+                    self.expect(']')?;
+                    Some(element_type)
+                } else {
+                    None
+                };
+
+                self.expect('(')?;
+                self.skip_whitespace();
+                if self.peek() != Some('[') {
+                    return Err(self.error("expected `[`"));
+                }
+                let elements = self.parse_array()?;
+                self.expect(')')?;
+
+                VariantText::Array {
+                    element_type,
+                    elements,
+                }
+            }
+            _ => {
+                self.expect('(')?;
+                let args = self.parse_args()?;
+                self.make_constructed(ident, args)?
+            }
+        };
+
+        Ok(value)
+    }
+
+    fn parse_args(&mut self) -> Result<Vec<VariantText>, ConvertError> {
+        let mut args = Vec::new();
+        if self.eat(')') {
+            return Ok(args);
+        }
+
+        loop {
+            args.push(self.parse_value()?);
+            if self.eat(')') {
+                return Ok(args);
+            }
+            self.expect(',')?;
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<Vec<VariantText>, ConvertError> {
+        self.expect('[')?;
+
+        let mut elements = Vec::new();
+        loop {
+            if self.eat(']') {
+                return Ok(elements);
+            }
+            elements.push(self.parse_value()?);
+
+            if !self.eat(',') {
+                self.expect(']')?;
+                return Ok(elements);
+            }
+        }
+    }
+
+    fn parse_dictionary(&mut self) -> Result<VariantText, ConvertError> {
+        self.expect('{')?;
+
+        let mut entries = Vec::new();
+        loop {
+            if self.eat('}') {
+                return Ok(VariantText::Dictionary(entries));
+            }
+
+            let key = self.parse_value()?;
+            self.expect(':')?;
+            let value = self.parse_value()?;
+            entries.push((key, value));
+
+            if !self.eat(',') {
+                self.expect('}')?;
+                return Ok(VariantText::Dictionary(entries));
+            }
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<VariantText, ConvertError> {
+        let start = self.pos;
+        let mut is_float = false;
+
+        if self.peek() == Some('-') {
+            self.bump();
+        }
+
+        while let Some(c) = self.peek() {
+            match c {
+                '0'..='9' => {}
+                '.' => is_float = true,
+                'e' | 'E' => {
+                    is_float = true;
+                    self.bump();
+                    if matches!(self.peek(), Some('+' | '-')) {
+                        self.bump();
+                    }
+                    continue;
+                }
+                _ => break,
+            }
+            self.bump();
+        }
+
+        let number = &self.text[start..self.pos];
+        if number == "-" && self.text[self.pos..].starts_with("inf") {
+            // Accept `-inf` too, which GDScript prints for negative infinity.
+            self.parse_ident();
+            return Ok(VariantText::Float(f64::NEG_INFINITY));
+        }
+
+        let value = if is_float {
+            number.parse().map(VariantText::Float).ok()
+        } else {
+            number.parse().map(VariantText::Int).ok()
+        };
+
+        value.ok_or_else(|| self.error(&format!("invalid number `{number}`")))
+    }
+
+    fn parse_string(&mut self) -> Result<String, ConvertError> {
+        if self.bump() != Some('"') {
+            return Err(self.error("expected `\"`"));
+        }
+
+        let mut string = String::new();
+        loop {
+            let c = self
+                .bump()
+                .ok_or_else(|| self.error("unterminated string"))?;
+            match c {
+                '"' => return Ok(string),
+                '\\' => {
+                    let escaped = self
+                        .bump()
+                        .ok_or_else(|| self.error("unterminated string"))?;
+                    let unescaped = match escaped {
+                        'b' => '\u{8}',
+                        't' => '\t',
+                        'n' => '\n',
+                        'f' => '\u{c}',
+                        'r' => '\r',
+                        'u' => self.parse_unicode_escape(4)?,
+                        'U' => self.parse_unicode_escape(6)?,
+                        other => other,
+                    };
+                    string.push(unescaped);
+                }
+                _ => string.push(c),
+            }
+        }
+    }
+
+    fn parse_unicode_escape(&mut self, digits: usize) -> Result<char, ConvertError> {
+        let end = self.pos + digits;
+        let code = self
+            .text
+            .get(self.pos..end)
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+            .and_then(char::from_u32)
+            .ok_or_else(|| self.error("invalid unicode escape"))?;
+
+        self.pos = end;
+        Ok(code)
+    }
+
+    fn make_constructed(
+        &self,
+        name: &str,
+        args: Vec<VariantText>,
+    ) -> Result<VariantText, ConvertError> {
+        let value = match name {
+            "NodePath" | "StringName" | "String" => {
+                let [VariantText::String(string)] = args.as_slice() else {
+                    return Err(self.error(&format!("{name}() expects a single string")));
+                };
+
+                match name {
+                    "NodePath" => VariantText::NodePath(string.clone()),
+                    "StringName" => VariantText::StringName(string.clone()),
+                    _ => VariantText::String(string.clone()),
+                }
+            }
+            "PackedStringArray" => VariantText::PackedStringArray(
+                args.into_iter()
+                    .map(|arg| match arg {
+                        VariantText::String(string) => Ok(string),
+                        _ => Err(self.error("PackedStringArray() expects strings")),
+                    })
+                    .collect::<Result<_, _>>()?,
+            ),
+            "PackedByteArray" => VariantText::PackedByteArray(self.ints(name, &args)?),
+            "PackedInt32Array" => VariantText::PackedInt32Array(self.ints(name, &args)?),
+            "PackedInt64Array" => VariantText::PackedInt64Array(self.ints(name, &args)?),
+            "PackedFloat32Array" => VariantText::PackedFloat32Array(
+                self.floats(name, &args)?
+                    .into_iter()
+                    .map(|v| v as f32)
+                    .collect(),
+            ),
+            "PackedFloat64Array" => VariantText::PackedFloat64Array(self.floats(name, &args)?),
+            "PackedVector2Array" => VariantText::PackedVector2Array(
+                self.real_chunks::<2>(name, &args)?
+                    .into_iter()
+                    .map(|[x, y]| Vector2::new(x, y))
+                    .collect(),
+            ),
+            "PackedVector3Array" => VariantText::PackedVector3Array(
+                self.real_chunks::<3>(name, &args)?
+                    .into_iter()
+                    .map(|[x, y, z]| Vector3::new(x, y, z))
+                    .collect(),
+            ),
+            "PackedColorArray" => VariantText::PackedColorArray(
+                self.real_chunks::<4>(name, &args)?
+                    .into_iter()
+                    .map(|[r, g, b, a]| make_color([r, g, b, a]))
+                    .collect(),
+            ),
+            #[cfg(since_api = "4.3")]
+            "PackedVector4Array" => VariantText::PackedVector4Array(
+                self.real_chunks::<4>(name, &args)?
+                    .into_iter()
+                    .map(|[x, y, z, w]| Vector4::new(x, y, z, w))
+                    .collect(),
+            ),
+            "Vector2" => {
+                let [x, y] = self.reals(name, &args)?;
+                VariantText::Vector2(Vector2::new(x, y))
+            }
+            "Vector2i" => {
+                let [x, y] = self.fixed_ints(name, &args)?;
+                VariantText::Vector2i(Vector2i::new(x, y))
+            }
+            "Rect2" => {
+                let [x, y, w, h] = self.reals(name, &args)?;
+                VariantText::Rect2(Rect2::new(Vector2::new(x, y), Vector2::new(w, h)))
+            }
+            "Rect2i" => {
+                let [x, y, w, h] = self.fixed_ints(name, &args)?;
+                VariantText::Rect2i(Rect2i::new(Vector2i::new(x, y), Vector2i::new(w, h)))
+            }
+            "Vector3" => {
+                let [x, y, z] = self.reals(name, &args)?;
+                VariantText::Vector3(Vector3::new(x, y, z))
+            }
+            "Vector3i" => {
+                let [x, y, z] = self.fixed_ints(name, &args)?;
+                VariantText::Vector3i(Vector3i::new(x, y, z))
+            }
+            "Transform2D" => {
+                let [ax, ay, bx, by, ox, oy] = self.reals(name, &args)?;
+                VariantText::Transform2D(Transform2D {
+                    a: Vector2::new(ax, ay),
+                    b: Vector2::new(bx, by),
+                    origin: Vector2::new(ox, oy),
+                })
+            }
+            "Vector4" => {
+                let [x, y, z, w] = self.reals(name, &args)?;
+                VariantText::Vector4(Vector4::new(x, y, z, w))
+            }
+            "Vector4i" => {
+                let [x, y, z, w] = self.fixed_ints(name, &args)?;
+                VariantText::Vector4i(Vector4i::new(x, y, z, w))
+            }
+            "Plane" => {
+                let [x, y, z, d] = self.reals(name, &args)?;
+                // Not Plane::new(), which requires a normalized normal.
+                VariantText::Plane(Plane {
+                    normal: Vector3::new(x, y, z),
+                    d,
+                })
+            }
+            "Quaternion" => {
+                let [x, y, z, w] = self.reals(name, &args)?;
+                VariantText::Quaternion(Quaternion { x, y, z, w })
+            }
+            "AABB" => {
+                let [x, y, z, w, h, d] = self.reals(name, &args)?;
+                VariantText::Aabb(Aabb::new(Vector3::new(x, y, z), Vector3::new(w, h, d)))
+            }
+            "Basis" => VariantText::Basis(make_basis(self.reals(name, &args)?)),
+            "Transform3D" => {
+                let [rows @ .., ox, oy, oz] = self.reals::<12>(name, &args)?;
+
+                VariantText::Transform3D(Transform3D {
+                    basis: make_basis(rows),
+                    origin: Vector3::new(ox, oy, oz),
+                })
+            }
+            "Projection" => {
+                let cols = self.real_chunks::<4>(name, &args)?;
+                let cols: [[real; 4]; 4] = cols
+                    .try_into()
+                    .map_err(|_| self.error("Projection() expects 16 numbers"))?;
+
+                VariantText::Projection(Projection::new(
+                    cols.map(|[x, y, z, w]| Vector4::new(x, y, z, w)),
+                ))
+            }
+            "Color" => VariantText::Color(make_color(self.reals(name, &args)?)),
+            _ => return Err(self.error(&format!("unsupported type `{name}`"))),
+        };
+
+        Ok(value)
+    }
+
+    fn floats(&self, name: &str, args: &[VariantText]) -> Result<Vec<f64>, ConvertError> {
+        args.iter()
+            .map(|arg| match *arg {
+                VariantText::Int(value) => Ok(value as f64),
+                VariantText::Float(value) => Ok(value),
+                _ => Err(self.error(&format!("{name}() expects numbers"))),
+            })
+            .collect()
+    }
+
+    fn ints<T: TryFrom<i64>>(
+        &self,
+        name: &str,
+        args: &[VariantText],
+    ) -> Result<Vec<T>, ConvertError> {
+        args.iter()
+            .map(|arg| match *arg {
+                VariantText::Int(value) => T::try_from(value).ok(),
+                _ => None,
+            })
+            .map(|value| {
+                value.ok_or_else(|| self.error(&format!("{name}() expects integers in range")))
+            })
+            .collect()
+    }
+
+    fn reals<const N: usize>(
+        &self,
+        name: &str,
+        args: &[VariantText],
+    ) -> Result<[real; N], ConvertError> {
+        let floats = self.floats(name, args)?;
+        let floats: [f64; N] = floats
+            .try_into()
+            .map_err(|_| self.error(&format!("{name}() expects {N} numbers")))?;
+
+        Ok(floats.map(real::from_f64))
+    }
+
+    fn fixed_ints<const N: usize>(
+        &self,
+        name: &str,
+        args: &[VariantText],
+    ) -> Result<[i32; N], ConvertError> {
+        self.ints::<i32>(name, args)?
+            .try_into()
+            .map_err(|_| self.error(&format!("{name}() expects {N} integers")))
+    }
+
+    fn real_chunks<const N: usize>(
+        &self,
+        name: &str,
+        args: &[VariantText],
+    ) -> Result<Vec<[real; N]>, ConvertError> {
+        let floats = self.floats(name, args)?;
+        if floats.len() % N != 0 {
+            return Err(self.error(&format!("{name}() expects a multiple of {N} numbers")));
+        }
+
+        Ok(floats
+            .chunks_exact(N)
+            .map(|chunk| std::array::from_fn(|i| real::from_f64(chunk[i])))
+            .collect())
+    }
+}
+
+fn make_basis([a, b, c, d, e, f, g, h, i]: [real; 9]) -> Basis {
+    Basis {
+        rows: [
+            Vector3::new(a, b, c),
+            Vector3::new(d, e, f),
+            Vector3::new(g, h, i),
+        ],
+    }
+}
+
+fn make_color([r, g, b, a]: [real; 4]) -> Color {
+    Color::from_rgba(r.as_f32(), g.as_f32(), b.as_f32(), a.as_f32())
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Type names
+
+/// Godot names of built-in types, as used in typed arrays.
+const TYPE_NAMES: &[(VariantType, &str)] = &[
+    (VariantType::BOOL, "bool"),
+    (VariantType::INT, "int"),
+    (VariantType::FLOAT, "float"),
+    (VariantType::STRING, "String"),
+    (VariantType::VECTOR2, "Vector2"),
+    (VariantType::VECTOR2I, "Vector2i"),
+    (VariantType::RECT2, "Rect2"),
+    (VariantType::RECT2I, "Rect2i"),
+    (VariantType::VECTOR3, "Vector3"),
+    (VariantType::VECTOR3I, "Vector3i"),
+    (VariantType::TRANSFORM2D, "Transform2D"),
+    (VariantType::VECTOR4, "Vector4"),
+    (VariantType::VECTOR4I, "Vector4i"),
+    (VariantType::PLANE, "Plane"),
+    (VariantType::QUATERNION, "Quaternion"),
+    (VariantType::AABB, "AABB"),
+    (VariantType::BASIS, "Basis"),
+    (VariantType::TRANSFORM3D, "Transform3D"),
+    (VariantType::PROJECTION, "Projection"),
+    (VariantType::COLOR, "Color"),
+    (VariantType::STRING_NAME, "StringName"),
+    (VariantType::NODE_PATH, "NodePath"),
+    (VariantType::RID, "RID"),
+    (VariantType::CALLABLE, "Callable"),
+    (VariantType::SIGNAL, "Signal"),
+    (VariantType::DICTIONARY, "Dictionary"),
+    (VariantType::ARRAY, "Array"),
+    (VariantType::PACKED_BYTE_ARRAY, "PackedByteArray"),
+    (VariantType::PACKED_INT32_ARRAY, "PackedInt32Array"),
+    (VariantType::PACKED_INT64_ARRAY, "PackedInt64Array"),
+    (VariantType::PACKED_FLOAT32_ARRAY, "PackedFloat32Array"),
+    (VariantType::PACKED_FLOAT64_ARRAY, "PackedFloat64Array"),
+    (VariantType::PACKED_STRING_ARRAY, "PackedStringArray"),
+    (VariantType::PACKED_VECTOR2_ARRAY, "PackedVector2Array"),
+    (VariantType::PACKED_VECTOR3_ARRAY, "PackedVector3Array"),
+    (VariantType::PACKED_COLOR_ARRAY, "PackedColorArray"),
+    #[cfg(since_api = "4.3")]
+    (VariantType::PACKED_VECTOR4_ARRAY, "PackedVector4Array"),
+];
+
+fn type_name(variant_type: VariantType) -> &'static str {
+    TYPE_NAMES
+        .iter()
+        .find(|(ty, _)| *ty == variant_type)
+        .map_or("Variant", |(_, name)| name)
+}
+
+fn type_from_name(name: &str) -> Option<VariantType> {
+    TYPE_NAMES
+        .iter()
+        .find(|(_, n)| *n == name)
+        .map(|(ty, _)| *ty)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_floats_like_printf() {
+        assert_eq!(format_float(0.0), "0.0");
+        assert_eq!(format_float(-0.0), "0.0");
+        assert_eq!(format_float(1.0), "1.0");
+        assert_eq!(format_float(-2.5), "-2.5");
+        assert_eq!(format_float(0.1), "0.1");
+        assert_eq!(format_float(1.0 / 3.0), "0.333333");
+        assert_eq!(format_float(123456.0), "123456.0");
+        assert_eq!(format_float(1234567.0), "1.23457e+06");
+        assert_eq!(format_float(999999.5), "1e+06");
+        assert_eq!(format_float(0.0001), "0.0001");
+        assert_eq!(format_float(0.00001234), "1.234e-05");
+        assert_eq!(format_float(f64::INFINITY), "inf");
+        assert_eq!(format_float(f64::NEG_INFINITY), "inf_neg");
+        assert_eq!(format_float(f64::NAN), "nan");
+
+        assert_eq!(format_component(3.0), "3");
+        assert_eq!(format_component(0.707106781), "0.707107");
+    }
+
+    #[test]
+    fn write_compound() {
+        let value = VariantText::Array {
+            element_type: None,
+            elements: vec![
+                VariantText::Int(1),
+                VariantText::Float(2.0),
+                VariantText::String("say \"hi\"\n".into()),
+                VariantText::StringName("name".into()),
+                VariantText::NodePath("../Node".into()),
+                VariantText::Vector3i(Vector3i::new(1, -2, 3)),
+                VariantText::Color(Color::from_rgba(1.0, 0.5, 0.0, 1.0)),
+            ],
+        };
+
+        assert_eq!(
+            value.to_string(),
+            "[1, 2.0, \"say \\\"hi\\\"\n\", &\"name\", NodePath(\"../Node\"), Vector3i(1, -2, 3), Color(1, 0.5, 0, 1)]"
+        );
+
+        let typed = VariantText::Array {
+            element_type: Some("int".into()),
+            elements: vec![VariantText::Int(4)],
+        };
+        assert_eq!(typed.to_string(), "Array[int]([4])");
+
+        let dict = VariantText::Dictionary(vec![
+            (VariantText::String("a".into()), VariantText::Nil),
+            (VariantText::Int(2), VariantText::Dictionary(vec![])),
+        ]);
+        assert_eq!(dict.to_string(), "{\n\"a\": null,\n2: {}\n}");
+
+        let packed = VariantText::PackedVector2Array(vec![Vector2::new(1.0, 2.0)]);
+        assert_eq!(packed.to_string(), "PackedVector2Array(1, 2)");
+        assert_eq!(
+            VariantText::PackedByteArray(vec![]).to_string(),
+            "PackedByteArray()"
+        );
+    }
+
+    #[test]
+    fn parse_roundtrip() {
+        let texts = [
+            "null",
+            "true",
+            "-42",
+            "0.5",
+            "inf_neg",
+            "\"multi\nline \\\\ \\\"quoted\\\"\"",
+            "&\"name\"",
+            "NodePath(\"a/b:c\")",
+            "Vector2(1, -0.25)",
+            "Rect2i(0, 1, 2, 3)",
+            "Transform2D(1, 0, 0, 1, 5, 6)",
+            "Transform3D(1, 0, 0, 0, 1, 0, 0, 0, 1, 7, 8, 9)",
+            "Projection(1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1)",
+            "AABB(0, 0, 0, 1, 2, 3)",
+            "Plane(0, 1, 0, 2)",
+            "[1, [2, \"three\"], {}]",
+            "Array[Vector2]([Vector2(1, 2)])",
+            "{\n\"key\": [1, 2],\n&\"other\": {\n\"nested\": true\n}\n}",
+            "PackedInt32Array(1, 2, 3)",
+            "PackedStringArray(\"a\", \"b\")",
+            "PackedColorArray(1, 0, 0, 1, 0, 1, 0, 0.5)",
+        ];
+
+        for text in texts {
+            let value = VariantText::parse(text).unwrap_or_else(|err| panic!("{text}: {err}"));
+            assert_eq!(value.to_string(), text);
+        }
+    }
+
+    #[test]
+    fn parse_lenient() {
+        assert_eq!(
+            VariantText::parse("  [ 1 ,2, ]  ").unwrap(),
+            VariantText::Array {
+                element_type: None,
+                elements: vec![VariantText::Int(1), VariantText::Int(2)],
+            }
+        );
+        assert_eq!(
+            VariantText::parse("\"\\u00e9\\t\"").unwrap(),
+            VariantText::String("é\t".into())
+        );
+        assert_eq!(
+            VariantText::parse("^\"path\"").unwrap(),
+            VariantText::NodePath("path".into())
+        );
+        assert_eq!(
+            VariantText::parse("1e3").unwrap(),
+            VariantText::Float(1000.0)
+        );
+        assert_eq!(
+            VariantText::parse("-inf").unwrap(),
+            VariantText::Float(f64::NEG_INFINITY)
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let invalid = [
+            "",
+            "[1, 2",
+            "{\"a\" 1}",
+            "Vector2(1)",
+            "Vector2i(1.5, 2)",
+            "PackedByteArray(256)",
+            "Object(Node)",
+            "\"unterminated",
+            "1 2",
+        ];
+
+        for text in invalid {
+            assert!(VariantText::parse(text).is_err(), "should fail: {text:?}");
+        }
+
+        let err = VariantText::parse("[\n1,\n?]").unwrap_err();
+        assert!(err.to_string().contains("line 3"), "{err}");
+    }
+}
@@ -53,6 +53,48 @@ impl Vector2i {
         }
     }
 
+    /// Returns the 4 orthogonally adjacent cells, clockwise starting with the right one.
+    ///
+    /// The order matches Godot's `TileMap.get_surrounding_cells()` for square tiles: right, bottom, left, top (with Y pointing down).
+    #[inline]
+    pub fn neighbors4(self) -> [Self; 4] {
+        [Self::RIGHT, Self::DOWN, Self::LEFT, Self::UP].map(|offset| self + offset)
+    }
+
+    /// Returns the 8 orthogonally and diagonally adjacent cells, clockwise starting with the right one.
+    #[inline]
+    pub fn neighbors8(self) -> [Self; 8] {
+        [
+            Self::new(1, 0),
+            Self::new(1, 1),
+            Self::new(0, 1),
+            Self::new(-1, 1),
+            Self::new(-1, 0),
+            Self::new(-1, -1),
+            Self::new(0, -1),
+            Self::new(1, -1),
+        ]
+        .map(|offset| self + offset)
+    }
+
+    /// Returns the Manhattan distance (also called taxicab distance) to `to`: the sum of the absolute coordinate differences.
+    ///
+    /// This is the number of steps between two cells, when only moving orthogonally.
+    #[inline]
+    pub fn manhattan_distance_to(self, to: Self) -> i32 {
+        let diff = (to - self).abs();
+        diff.x + diff.y
+    }
+
+    /// Returns the Chebyshev distance to `to`: the maximum of the absolute coordinate differences.
+    ///
+    /// This is the number of steps between two cells, when diagonal moves are allowed (e.g. a king in chess).
+    #[inline]
+    pub fn chebyshev_distance_to(self, to: Self) -> i32 {
+        let diff = (to - self).abs();
+        diff.x.max(diff.y)
+    }
+
     /// Converts `self` to the corresponding [`real`] `glam` type.
     #[doc(hidden)]
     #[inline]
@@ -134,4 +176,37 @@ mod test {
         assert_eq!(Vector2i::new(15, 15).max_axis(), None);
         assert_eq!(Vector2i::new(15, 15).min_axis(), None);
     }
+
+    #[test]
+    fn neighbors() {
+        let cell = Vector2i::new(3, -2);
+
+        assert_eq!(
+            cell.neighbors4(),
+            [
+                Vector2i::new(4, -2),
+                Vector2i::new(3, -1),
+                Vector2i::new(2, -2),
+                Vector2i::new(3, -3),
+            ]
+        );
+
+        let neighbors8 = cell.neighbors8();
+        assert!(cell.neighbors4().iter().all(|n| neighbors8.contains(n)));
+        assert!(neighbors8
+            .iter()
+            .all(|n| n.chebyshev_distance_to(cell) == 1 && *n != cell));
+    }
+
+    #[test]
+    fn grid_distances() {
+        let a = Vector2i::new(1, 2);
+        let b = Vector2i::new(-3, 7);
+
+        assert_eq!(a.manhattan_distance_to(b), 9);
+        assert_eq!(b.manhattan_distance_to(a), 9);
+        assert_eq!(a.chebyshev_distance_to(b), 5);
+        assert_eq!(a.manhattan_distance_to(a), 0);
+        assert_eq!(a.chebyshev_distance_to(a), 0);
+    }
 }
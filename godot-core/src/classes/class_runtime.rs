@@ -20,7 +20,12 @@ pub(crate) fn debug_string<T: GodotClass>(
 ) -> std::fmt::Result {
     if let Some(id) = obj.instance_id_or_none() {
         let class: GString = obj.raw.as_object().get_class();
-        write!(f, "{ty} {{ id: {id}, class: {class} }}")
+
+        let mut s = f.debug_struct(ty);
+        s.field("id", &format_args!("{id}"))
+            .field("class", &format_args!("{class}"));
+        T::__godot_fmt_debug_instance(obj, &mut s);
+        s.finish()
     } else {
         write!(f, "{ty} {{ freed obj }}")
     }
@@ -0,0 +1,128 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::classes::{
+    InputEvent, InputEventAction, InputEventJoypadButton, InputEventJoypadMotion, InputEventKey,
+    InputEventMagnifyGesture, InputEventMidi, InputEventMouseButton, InputEventMouseMotion,
+    InputEventPanGesture, InputEventScreenDrag, InputEventScreenTouch, InputEventShortcut,
+};
+use crate::obj::Gd;
+
+/// Concrete type of an [`InputEvent`], for exhaustive matching.
+///
+/// Obtained via [`InputEventKind::from_event()`] or [`Gd::<InputEvent>::dispatch()`][Gd::dispatch]. Replaces chains of
+/// [`try_cast()`][Gd::try_cast] in input callbacks:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::classes::InputEventKind;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl INode for Player {
+///     fn input(&mut self, event: Gd<InputEvent>) {
+///         match event.dispatch() {
+///             InputEventKind::Key(key) if key.is_pressed() => {
+///                 godot_print!("Key pressed: {:?}", key.get_keycode());
+///             }
+///             InputEventKind::MouseButton(button) => {
+///                 godot_print!("Mouse button: {:?}", button.get_button_index());
+///             }
+///             _ => {}
+///         }
+///     }
+/// }
+/// ```
+///
+/// Events of classes not listed here (e.g. custom `InputEvent` subclasses) end up in [`Other`][Self::Other].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum InputEventKind {
+    Key(Gd<InputEventKey>),
+    MouseButton(Gd<InputEventMouseButton>),
+    MouseMotion(Gd<InputEventMouseMotion>),
+    JoypadButton(Gd<InputEventJoypadButton>),
+    JoypadMotion(Gd<InputEventJoypadMotion>),
+    ScreenTouch(Gd<InputEventScreenTouch>),
+    ScreenDrag(Gd<InputEventScreenDrag>),
+    MagnifyGesture(Gd<InputEventMagnifyGesture>),
+    PanGesture(Gd<InputEventPanGesture>),
+    Action(Gd<InputEventAction>),
+    Midi(Gd<InputEventMidi>),
+    Shortcut(Gd<InputEventShortcut>),
+
+    /// Any other event class.
+    Other(Gd<InputEvent>),
+}
+
+impl InputEventKind {
+    /// Determines the concrete class of `event`.
+    pub fn from_event(event: Gd<InputEvent>) -> Self {
+        // Each try_cast() hands back the original pointer on failure, which is then passed to the next attempt.
+        macro_rules! try_variant {
+            ($event:ident, $Variant:ident) => {
+                match $event.try_cast() {
+                    Ok(concrete) => return Self::$Variant(concrete),
+                    Err(event) => event,
+                }
+            };
+        }
+
+        // Ordered roughly by frequency, to keep the typical number of casts low.
+        let event = try_variant!(event, MouseMotion);
+        let event = try_variant!(event, Key);
+        let event = try_variant!(event, MouseButton);
+        let event = try_variant!(event, JoypadMotion);
+        let event = try_variant!(event, JoypadButton);
+        let event = try_variant!(event, ScreenDrag);
+        let event = try_variant!(event, ScreenTouch);
+        let event = try_variant!(event, MagnifyGesture);
+        let event = try_variant!(event, PanGesture);
+        let event = try_variant!(event, Action);
+        let event = try_variant!(event, Midi);
+        let event = try_variant!(event, Shortcut);
+
+        Self::Other(event)
+    }
+
+    /// Converts back to the general `InputEvent` pointer.
+    pub fn into_event(self) -> Gd<InputEvent> {
+        match self {
+            Self::Key(event) => event.upcast(),
+            Self::MouseButton(event) => event.upcast(),
+            Self::MouseMotion(event) => event.upcast(),
+            Self::JoypadButton(event) => event.upcast(),
+            Self::JoypadMotion(event) => event.upcast(),
+            Self::ScreenTouch(event) => event.upcast(),
+            Self::ScreenDrag(event) => event.upcast(),
+            Self::MagnifyGesture(event) => event.upcast(),
+            Self::PanGesture(event) => event.upcast(),
+            Self::Action(event) => event.upcast(),
+            Self::Midi(event) => event.upcast(),
+            Self::Shortcut(event) => event.upcast(),
+            Self::Other(event) => event,
+        }
+    }
+}
+
+impl From<Gd<InputEvent>> for InputEventKind {
+    fn from(event: Gd<InputEvent>) -> Self {
+        Self::from_event(event)
+    }
+}
+
+impl Gd<InputEvent> {
+    /// Matches the event against its concrete class. See [`InputEventKind`].
+    pub fn dispatch(self) -> InputEventKind {
+        InputEventKind::from_event(self)
+    }
+}
@@ -5,9 +5,14 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::builtin::NodePath;
-use crate::classes::{Node, PackedScene};
-use crate::obj::{Gd, Inherits};
+use std::borrow::Borrow;
+use std::collections::HashSet;
+
+use crate::builtin::{Callable, NodePath, StringName, Variant};
+use crate::classes::{Control, EditorProperty, Node, Object, PackedScene, Resource};
+use crate::meta::error::{NodeLookupError, ResolveNodesError};
+use crate::meta::{FromGodot, ToGodot};
+use crate::obj::{Gd, Inherits, InstanceId};
 
 /// Manual extensions for the `Node` class.
 impl Node {
@@ -44,6 +49,254 @@ impl Node {
         self.get_node_or_null(path)
             .and_then(|node| node.try_cast::<T>().ok())
     }
+
+    /// Retrieves the nodes at all `paths`, each of which must have type `T` or inherited.
+    ///
+    /// This is meant for exported `Vec<NodePath>` or `Array<NodePath>` fields, which are typically resolved once in `ready()`. Rather
+    /// than stopping at the first problem, all paths are looked up, and the error lists every path that is empty, missing or of the
+    /// wrong type.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # use godot::classes::{Area2D, INode};
+    /// #[derive(GodotClass)]
+    /// #[class(init, base=Node)]
+    /// struct TriggerGroup {
+    ///     #[export(node_path = (Area2D))]
+    ///     trigger_paths: Vec<NodePath>,
+    ///     triggers: Vec<Gd<Area2D>>,
+    ///     base: Base<Node>,
+    /// }
+    ///
+    /// #[godot_api]
+    /// impl INode for TriggerGroup {
+    ///     fn ready(&mut self) {
+    ///         match self.base().resolve_nodes_as::<Area2D>(&self.trigger_paths) {
+    ///             Ok(triggers) => self.triggers = triggers,
+    ///             Err(err) => godot_error!("TriggerGroup: {err}"),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn resolve_nodes_as<T>(
+        &self,
+        paths: impl IntoIterator<Item = impl Borrow<NodePath>>,
+    ) -> Result<Vec<Gd<T>>, ResolveNodesError>
+    where
+        T: Inherits<Node>,
+    {
+        let mut nodes = Vec::new();
+        let mut failures = Vec::new();
+        let mut total = 0;
+
+        for (index, path) in paths.into_iter().enumerate() {
+            total += 1;
+
+            match self.lookup_node_as::<T>(path.borrow()) {
+                Ok(node) => nodes.push(node),
+                Err(err) => failures.push((index, err)),
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(nodes)
+        } else {
+            Err(ResolveNodesError::new(total, failures))
+        }
+    }
+
+    fn lookup_node_as<T>(&self, path: &NodePath) -> Result<Gd<T>, NodeLookupError>
+    where
+        T: Inherits<Node>,
+    {
+        if path.is_empty() {
+            return Err(NodeLookupError::Empty);
+        }
+
+        let Some(node) = self.get_node_or_null(path.clone()) else {
+            return Err(NodeLookupError::NotFound { path: path.clone() });
+        };
+
+        node.try_cast::<T>()
+            .map_err(|node| NodeLookupError::WrongType {
+                path: path.clone(),
+                expected: T::class_name().to_string(),
+                actual: node.get_class().to_string(),
+            })
+    }
+
+    /// Returns all descendants of type `T` or inherited, in depth-first pre-order.
+    ///
+    /// Unlike `find_children()`, this does not filter by owner, and the type check also covers Rust classes. Internal children are skipped.
+    pub fn find_children_of_type<T>(&self) -> Vec<Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        let mut found = Vec::new();
+        let mut stack = children_reversed(self);
+
+        while let Some(node) = stack.pop() {
+            stack.extend(children_reversed(&node));
+
+            if let Ok(node) = node.try_cast::<T>() {
+                found.push(node);
+            }
+        }
+
+        found
+    }
+
+    /// Returns the closest ancestor of type `T` or inherited, or `None` if there is no such ancestor.
+    pub fn ancestor_of_type<T>(&self) -> Option<Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        let mut current = self.get_parent();
+
+        while let Some(node) = current {
+            match node.try_cast::<T>() {
+                Ok(ancestor) => return Some(ancestor),
+                Err(node) => current = node.get_parent(),
+            }
+        }
+
+        None
+    }
+
+    /// Returns all nodes matching a path pattern, relative to this node.
+    ///
+    /// The pattern consists of `/`-separated segments, like a `NodePath`. Each segment is matched against node names, where:
+    /// - `*` matches any sequence of characters, and `?` a single character.
+    /// - `**` matches any number of nested levels (including zero).
+    /// - `.` and `..` refer to the current and parent node, respectively.
+    ///
+    /// Nodes are returned in tree order, each at most once. The tree is only explored where the pattern can still match.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # fn hitboxes(level: Gd<Node>) {
+    /// // Every `Hitbox` directly below an enemy, e.g. `Enemies/Orc/Hitbox`.
+    /// let hitboxes = level.query("Enemies/*/Hitbox");
+    ///
+    /// // All nodes whose name ends in `Spawn`, anywhere below `Spawns`.
+    /// let spawns = level.query("Spawns/**/*Spawn");
+    /// # }
+    /// ```
+    pub fn query(&self, pattern: &str) -> Vec<Gd<Node>> {
+        let segments = pattern
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<_>>();
+
+        let mut query = NodeQuery {
+            segments: &segments,
+            visited: HashSet::new(),
+            found: Vec::new(),
+        };
+
+        // SAFETY: `self` is a live node, being accessed through a `Gd<Node>` or derived.
+        let this = unsafe { Gd::<Node>::from_obj_sys(self.__object_ptr()) };
+
+        query.visit(this, 0);
+        query.found
+    }
+
+    /// Like [`query()`][Self::query], but only returns nodes of type `T` or inherited.
+    pub fn query_as<T>(&self, pattern: &str) -> Vec<Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        self.query(pattern)
+            .into_iter()
+            .filter_map(|node| node.try_cast::<T>().ok())
+            .collect()
+    }
+}
+
+/// Children in reverse order, so that popping them off a stack yields them in tree order.
+fn children_reversed(node: &Node) -> Vec<Gd<Node>> {
+    let count = node.get_child_count();
+
+    (0..count)
+        .rev()
+        .filter_map(|index| node.get_child(index))
+        .collect()
+}
+
+struct NodeQuery<'a> {
+    segments: &'a [&'a str],
+    /// Pairs of (node, segment index) already explored; `**` can reach the same state along different routes.
+    visited: HashSet<(InstanceId, usize)>,
+    found: Vec<Gd<Node>>,
+}
+
+impl NodeQuery<'_> {
+    fn visit(&mut self, node: Gd<Node>, index: usize) {
+        if !self.visited.insert((node.instance_id(), index)) {
+            return;
+        }
+
+        let Some(&segment) = self.segments.get(index) else {
+            self.found.push(node);
+            return;
+        };
+
+        match segment {
+            "." => self.visit(node, index + 1),
+            ".." => {
+                if let Some(parent) = node.get_parent() {
+                    self.visit(parent, index + 1);
+                }
+            }
+            "**" => {
+                self.visit(node.clone(), index + 1);
+                for child in children_reversed(&node).into_iter().rev() {
+                    self.visit(child, index);
+                }
+            }
+            _ => {
+                for child in children_reversed(&node).into_iter().rev() {
+                    if glob_match(segment, &child.get_name().to_string()) {
+                        self.visit(child, index + 1);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Matches `text` against a pattern with `*` (any sequence) and `?` (any single character) wildcards.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let text = text.chars().collect::<Vec<_>>();
+
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` in the pattern, and the text position it is currently matched up to.
+    let mut backtrack = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    // Let the last `*` consume one more character.
+                    p = star + 1;
+                    t = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -72,3 +325,88 @@ impl PackedScene {
         self.instantiate().and_then(|gd| gd.try_cast::<T>().ok())
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `EditorProperty` class.
+///
+/// These simplify writing custom property editors, which are returned from `IEditorInspectorPlugin::parse_property()` via
+/// `EditorInspectorPlugin::add_property_editor()`. A typical editor embeds its controls with [`add_editor_control()`][Self::add_editor_control],
+/// reports user changes with [`set_edited_value()`][Self::set_edited_value], and refreshes its controls from
+/// [`get_edited_value()`][Self::get_edited_value] inside `IEditorProperty::update_property()`, which Godot calls whenever the
+/// property changes (including undo/redo). If the displayed value also depends on other state, [`refresh_on_signal()`][Self::refresh_on_signal]
+/// and [`refresh_on_resource_changed()`][Self::refresh_on_resource_changed] trigger the same refresh automatically.
+impl EditorProperty {
+    /// Returns the current value of the edited property, or `NIL` if no object is being edited.
+    pub fn get_edited_value(&self) -> Variant {
+        match self.get_edited_object() {
+            Some(object) => object.get(self.get_edited_property()),
+            None => Variant::nil(),
+        }
+    }
+
+    /// Returns the current value of the edited property, converted to `T`.
+    ///
+    /// Returns `None` if no object is being edited, or if the value cannot be converted to `T`.
+    pub fn try_get_edited_value_as<T: FromGodot>(&self) -> Option<T> {
+        self.get_edited_object()?;
+        self.get_edited_value().try_to::<T>().ok()
+    }
+
+    /// Submits a new value for the edited property.
+    ///
+    /// The inspector applies the value to the edited object and records the change in the undo/redo history. Do not set the property
+    /// on the object directly, as that bypasses undo/redo.
+    pub fn set_edited_value<T: ToGodot>(&mut self, value: T) {
+        let property = self.get_edited_property();
+        self.emit_changed(property, value.to_variant());
+    }
+
+    /// Adds `control` as a child of this property editor, and registers it for focus handling.
+    ///
+    /// Registering for focus ensures that the property is selected in the inspector when the control is clicked.
+    pub fn add_editor_control<T>(&mut self, control: Gd<T>)
+    where
+        T: Inherits<Control>,
+    {
+        let control = control.upcast::<Control>();
+
+        self.add_child(control.clone().upcast());
+        self.add_focusable(control);
+    }
+
+    /// Refreshes this editor whenever `signal` is emitted on `source`.
+    ///
+    /// The refresh goes through `update_property()`, so it ends up in `IEditorProperty::update_property()` like any other refresh.
+    /// Connecting the same signal multiple times has no additional effect. Godot removes the connection when either object is freed.
+    pub fn refresh_on_signal<T>(&self, source: &Gd<T>, signal: impl Into<StringName>)
+    where
+        T: Inherits<Object>,
+    {
+        // SAFETY: `self` is a live object, being accessed through a `Gd<EditorProperty>` or derived.
+        let this = unsafe { Gd::<EditorProperty>::from_obj_sys(self.__object_ptr()) };
+
+        let callable = Callable::from_object_method(&this, "update_property");
+        let signal = signal.into();
+        let mut source = source.clone().upcast::<Object>();
+
+        if !source.is_connected(signal.clone(), callable.clone()) {
+            source.connect(signal, callable);
+        }
+    }
+
+    /// Refreshes this editor whenever the edited value is a resource that emits `changed`.
+    ///
+    /// This covers sub-resources that are modified outside of this editor, e.g. from another inspector. Call it from
+    /// `IEditorProperty::update_property()` so that a newly assigned resource is tracked as well. Returns `false` if the edited value
+    /// is not a resource.
+    pub fn refresh_on_resource_changed(&self) -> bool {
+        match self.try_get_edited_value_as::<Gd<Resource>>() {
+            Some(resource) => {
+                self.refresh_on_signal(&resource, "changed");
+                true
+            }
+            None => false,
+        }
+    }
+}
@@ -17,10 +17,12 @@
 //! * [`notify`]: all notification enums, used when working with the virtual callback to handle lifecycle notifications.
 
 mod class_runtime;
+mod input_event_kind;
 mod manual_extensions;
 
 // Re-exports all generated classes, interface traits and sidecar modules.
 pub use crate::gen::classes::*;
+pub use input_event_kind::InputEventKind;
 
 /// Support for Godot _native structures_.
 ///
@@ -20,6 +20,15 @@ pub const fn base_attribute() {}
         More information on https://github.com/godot-rust/gdext/pull/702."]
 pub const fn feature_custom_godot() {}
 
+// Not a deprecation, but the same mechanism is the only way to emit a warning from a derive macro. The warning depends on a Cargo
+// feature of this crate, which the proc-macro cannot see.
+#[cfg(feature = "experimental-threads")]
+#[deprecated = "#[class(inline_storage)] has no effect with the `experimental-threads` feature."]
+pub const fn inline_storage_with_threads() {}
+
+#[cfg(not(feature = "experimental-threads"))]
+pub const fn inline_storage_with_threads() {}
+
 #[macro_export]
 macro_rules! emit_deprecated_warning {
     ($warning_fn:ident) => {
@@ -0,0 +1,193 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Runtime diagnostics, to find allocation-heavy code paths.
+//!
+//! Requires the `alloc-stats` Cargo feature. When enabled, every construction and destruction of a builtin that owns engine-side memory
+//! is counted: `GString`, `StringName`, `NodePath`, `Array`, `Dictionary` and all packed arrays. Value types like `Vector2` are not tracked.
+//!
+//! Counters are global and thread-safe. They only cover values owned by Rust. When a value is moved into the engine (e.g. returned from a
+//! `#[func]`), Rust takes over the previous value of the return slot and destroys it instead, so every construction has a matching
+//! destruction.
+//!
+//! There is no built-in singleton; to inspect the statistics from GDScript, expose [`AllocStats::to_dictionary()`] through a `#[func]`.
+//!
+//! # Example
+//! ```no_run
+//! use godot::diagnostics::AllocStats;
+//!
+//! let before = AllocStats::capture();
+//! // ... code that runs once per frame ...
+//! let delta = AllocStats::capture().since(&before);
+//!
+//! godot::global::godot_print!("Builtins constructed this frame: {}", delta.total_constructed());
+//! ```
+
+use std::fmt;
+
+use crate::builtin::{Dictionary, VariantType};
+use crate::meta::ToGodot;
+use godot_ffi as sys;
+
+/// Allocation counters for a single builtin type.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct BuiltinStats {
+    /// The builtin type.
+    pub variant_type: VariantType,
+
+    /// Number of values constructed.
+    pub constructed: u64,
+
+    /// Number of values destroyed.
+    pub destructed: u64,
+
+    /// Number of values currently alive on the Rust side.
+    pub live: i64,
+
+    /// Highest number of simultaneously alive values since startup, or since the last [`reset_peaks()`].
+    pub peak: i64,
+}
+
+/// Snapshot of allocation counters for all tracked builtin types.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct AllocStats {
+    entries: Vec<BuiltinStats>,
+}
+
+impl AllocStats {
+    /// Reads the current counters.
+    pub fn capture() -> Self {
+        let entries = tracked_types()
+            .filter_map(|ty| {
+                let raw = sys::alloc_stats::snapshot(ty)?;
+
+                Some(BuiltinStats {
+                    variant_type: ty,
+                    constructed: raw.constructed,
+                    destructed: raw.destructed,
+                    live: raw.live,
+                    peak: raw.peak,
+                })
+            })
+            .collect();
+
+        Self { entries }
+    }
+
+    /// Returns the difference between `self` and an `earlier` snapshot.
+    ///
+    /// Constructions, destructions and live counts are subtracted. The peak is taken from `self`, since high-water marks cannot be
+    /// attributed to an interval.
+    pub fn since(&self, earlier: &AllocStats) -> AllocStats {
+        let entries = self
+            .entries
+            .iter()
+            .map(|now| {
+                let Some(before) = earlier.get(now.variant_type) else {
+                    return *now;
+                };
+
+                BuiltinStats {
+                    variant_type: now.variant_type,
+                    constructed: now.constructed.saturating_sub(before.constructed),
+                    destructed: now.destructed.saturating_sub(before.destructed),
+                    live: now.live - before.live,
+                    peak: now.peak,
+                }
+            })
+            .collect();
+
+        AllocStats { entries }
+    }
+
+    /// Returns the counters for `variant_type`, or `None` if the type is not tracked.
+    pub fn get(&self, variant_type: VariantType) -> Option<&BuiltinStats> {
+        self.entries
+            .iter()
+            .find(|entry| entry.variant_type == variant_type)
+    }
+
+    /// Counters for all tracked types, ordered by variant type.
+    pub fn entries(&self) -> &[BuiltinStats] {
+        &self.entries
+    }
+
+    /// Total number of constructions across all tracked types.
+    pub fn total_constructed(&self) -> u64 {
+        self.entries.iter().map(|entry| entry.constructed).sum()
+    }
+
+    /// Total number of values currently alive across all tracked types.
+    pub fn total_live(&self) -> i64 {
+        self.entries.iter().map(|entry| entry.live).sum()
+    }
+
+    /// Converts the statistics to a dictionary, e.g. to display them from GDScript.
+    ///
+    /// Keys are the variant type names (such as `"STRING"`); values are dictionaries with keys `constructed`, `destructed`, `live` and `peak`.
+    pub fn to_dictionary(&self) -> Dictionary {
+        let mut result = Dictionary::new();
+
+        for entry in &self.entries {
+            let mut counters = Dictionary::new();
+            counters.set("constructed", entry.constructed as i64);
+            counters.set("destructed", entry.destructed as i64);
+            counters.set("live", entry.live);
+            counters.set("peak", entry.peak);
+
+            result.set(type_name(entry.variant_type), counters.to_variant());
+        }
+
+        result
+    }
+}
+
+impl fmt::Display for AllocStats {
+    /// Formats the statistics as a table, omitting types that have never been constructed.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<20} {:>12} {:>12} {:>10} {:>10}",
+            "type", "constructed", "destructed", "live", "peak"
+        )?;
+
+        for entry in self.entries.iter().filter(|entry| entry.constructed > 0) {
+            writeln!(
+                f,
+                "{:<20} {:>12} {:>12} {:>10} {:>10}",
+                type_name(entry.variant_type),
+                entry.constructed,
+                entry.destructed,
+                entry.live,
+                entry.peak
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Resets the high-water marks of all types to their current live count.
+///
+/// Useful to measure peaks per level or per frame.
+pub fn reset_peaks() {
+    sys::alloc_stats::reset_peaks();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+fn tracked_types() -> impl Iterator<Item = VariantType> {
+    (0..VariantType::MAX.sys())
+        .map(VariantType::from_sys)
+        .filter(|ty| sys::alloc_stats::is_tracked(*ty))
+}
+
+fn type_name(ty: VariantType) -> String {
+    // Debug output is the enumerator name, e.g. PACKED_BYTE_ARRAY.
+    format!("{ty:?}")
+}
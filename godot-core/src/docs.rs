@@ -0,0 +1,264 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Programmatic access to the documentation of classes registered by this extension.
+//!
+//! With the `register-docs` feature, the `///` doc comments of Rust classes and their `#[var]`/`#[export]` properties, `#[func]` methods,
+//! `#[signal]`s and `#[constant]`s are collected during class registration. This module exposes them as a structured model, which can
+//! be used to generate an external documentation site or in-game help screens.
+//!
+//! The docs are not registered with Godot itself, so they don't appear in the editor's built-in help (`EditorHelp`).
+//!
+//! ```no_run
+//! use godot::docs;
+//!
+//! for class in docs::registered_classes() {
+//!     println!("# {} (extends {})", class.name, class.base);
+//!     println!("{}", class.brief);
+//!
+//!     for method in &class.methods {
+//!         println!("* `{}()`: {}", method.name, method.brief());
+//!     }
+//! }
+//! ```
+//!
+//! Docs are available once the classes are registered, i.e. after the corresponding [`InitLevel`][crate::init::InitLevel] has been
+//! loaded (usually `Scene`).
+
+use godot_ffi as sys;
+use std::collections::HashMap;
+use sys::Global;
+
+use crate::meta::ClassName;
+use crate::obj::GodotClass;
+
+static CLASS_DOCS: Global<HashMap<ClassName, ClassDocs>> = Global::default();
+
+/// Documentation of a Rust class.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct ClassDocs {
+    /// Name of the class, as registered in Godot.
+    pub name: String,
+
+    /// Name of the direct base class.
+    pub base: String,
+
+    /// First paragraph of the class doc comment.
+    pub brief: String,
+
+    /// Remaining paragraphs of the class doc comment, after [`brief`][Self::brief].
+    pub description: String,
+
+    /// `#[var]` and `#[export]` properties, in declaration order.
+    pub properties: Vec<MemberDocs>,
+
+    /// `#[func]` methods, in declaration order.
+    pub methods: Vec<MemberDocs>,
+
+    /// `#[signal]`s, in declaration order.
+    pub signals: Vec<MemberDocs>,
+
+    /// `#[constant]`s, in declaration order.
+    pub constants: Vec<MemberDocs>,
+}
+
+impl ClassDocs {
+    /// Returns the brief and description, separated by an empty line (or only one of them, if the other is empty).
+    pub fn full_description(&self) -> String {
+        join_paragraphs(&self.brief, &self.description)
+    }
+}
+
+/// Documentation of a single class member (property, method, signal or constant).
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct MemberDocs {
+    /// Name of the member, as registered in Godot.
+    pub name: String,
+
+    /// Doc comment of the member. Empty if undocumented.
+    pub description: String,
+}
+
+impl MemberDocs {
+    /// Returns the first paragraph of the description.
+    pub fn brief(&self) -> &str {
+        split_brief(&self.description).0
+    }
+}
+
+/// Returns the docs of all classes registered by this extension, sorted by class name.
+///
+/// Classes are included even without any doc comments.
+pub fn registered_classes() -> Vec<ClassDocs> {
+    let docs = CLASS_DOCS.lock();
+
+    let mut classes: Vec<ClassDocs> = docs.values().cloned().collect();
+    classes.sort_by(|a, b| a.name.cmp(&b.name));
+    classes
+}
+
+/// Returns the docs of class `T`, or `None` if `T` has not been registered (yet).
+pub fn class_docs<T: GodotClass>() -> Option<ClassDocs> {
+    CLASS_DOCS.lock().get(&T::class_name()).cloned()
+}
+
+/// Returns the docs of the class named `class_name`, or `None` if no such class has been registered by this extension.
+pub fn class_docs_by_name(class_name: &str) -> Option<ClassDocs> {
+    let docs = CLASS_DOCS.lock();
+
+    docs.values()
+        .find(|class| class.name == class_name)
+        .cloned()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Registration (used by proc-macros)
+
+type RawMember<'a> = (&'a str, &'a [&'a str]);
+
+#[doc(hidden)]
+pub fn __register_class_docs<T: GodotClass>(class_lines: &[&str], properties: &[RawMember]) {
+    #[cfg(feature = "register-profiling")]
+    let _timer = DocsTimer::start();
+
+    let text = doc_text(class_lines);
+    let (brief, description) = split_brief(&text);
+
+    let mut docs = CLASS_DOCS.lock();
+    let class = entry::<T>(&mut docs);
+
+    class.brief = brief.to_string();
+    class.description = description.to_string();
+    class.properties = to_members(properties);
+}
+
+#[doc(hidden)]
+pub fn __register_member_docs<T: GodotClass>(
+    methods: &[RawMember],
+    signals: &[RawMember],
+    constants: &[RawMember],
+) {
+    #[cfg(feature = "register-profiling")]
+    let _timer = DocsTimer::start();
+
+    let mut docs = CLASS_DOCS.lock();
+    let class = entry::<T>(&mut docs);
+
+    // Called once per `#[godot_api]` block, so members of secondary blocks are merged. Same names replace previous state.
+    merge_members(&mut class.methods, methods);
+    merge_members(&mut class.signals, signals);
+    merge_members(&mut class.constants, constants);
+}
+
+/// Adds the time until drop to the docs registration time of the startup profile.
+#[cfg(feature = "register-profiling")]
+struct DocsTimer(std::time::Instant);
+
+#[cfg(feature = "register-profiling")]
+impl DocsTimer {
+    fn start() -> Self {
+        Self(std::time::Instant::now())
+    }
+}
+
+#[cfg(feature = "register-profiling")]
+impl Drop for DocsTimer {
+    fn drop(&mut self) {
+        crate::init::profiling::record_docs(self.0.elapsed());
+    }
+}
+
+fn entry<T: GodotClass>(docs: &mut HashMap<ClassName, ClassDocs>) -> &mut ClassDocs {
+    docs.entry(T::class_name()).or_insert_with(|| ClassDocs {
+        name: T::class_name().to_string(),
+        base: <T::Base as GodotClass>::class_name().to_string(),
+        ..Default::default()
+    })
+}
+
+fn merge_members(members: &mut Vec<MemberDocs>, raw: &[RawMember]) {
+    for member in to_members(raw) {
+        match members
+            .iter_mut()
+            .find(|existing| existing.name == member.name)
+        {
+            Some(existing) => *existing = member,
+            None => members.push(member),
+        }
+    }
+}
+
+fn to_members(raw: &[RawMember]) -> Vec<MemberDocs> {
+    raw.iter()
+        .map(|(name, lines)| MemberDocs {
+            name: name.to_string(),
+            description: doc_text(lines),
+        })
+        .collect()
+}
+
+/// Joins the lines of `///` comments, which have one leading space each.
+fn doc_text(lines: &[&str]) -> String {
+    let lines: Vec<&str> = lines
+        .iter()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .collect();
+
+    lines.join("\n").trim().to_string()
+}
+
+/// Splits text into the first paragraph and the rest.
+fn split_brief(text: &str) -> (&str, &str) {
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            return (text[..offset].trim_end(), text[offset..].trim());
+        }
+        offset += line.len();
+    }
+
+    (text.trim_end(), "")
+}
+
+fn join_paragraphs(first: &str, second: &str) -> String {
+    match (first.is_empty(), second.is_empty()) {
+        (_, true) => first.to_string(),
+        (true, false) => second.to_string(),
+        (false, false) => format!("{first}\n\n{second}"),
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn doc_text_strips_comment_space() {
+        let text = doc_text(&[" Brief line.", "", " Details,", "   indented."]);
+
+        assert_eq!(text, "Brief line.\n\nDetails,\n  indented.");
+    }
+
+    #[test]
+    fn split_brief_paragraphs() {
+        assert_eq!(
+            split_brief("One\nline.\n\nRest\n\nmore"),
+            ("One\nline.", "Rest\n\nmore")
+        );
+        assert_eq!(split_brief("Only brief."), ("Only brief.", ""));
+        assert_eq!(split_brief(""), ("", ""));
+    }
+
+    #[test]
+    fn join_paragraphs_skips_empty() {
+        assert_eq!(join_paragraphs("a", "b"), "a\n\nb");
+        assert_eq!(join_paragraphs("a", ""), "a");
+        assert_eq!(join_paragraphs("", "b"), "b");
+    }
+}
@@ -9,6 +9,9 @@
 //!
 //! See also [Godot docs for `@GlobalScope`](https://docs.godotengine.org/en/stable/classes/class_@globalscope.html#methods).
 //!
+//! Some utility functions taking `Variant` arguments, such as `clamp()` or `max()`, have generic counterparts in the [`typed`] module.
+//! Coverage is partial; see the module for which functions are included.
+//!
 //! # Builtin-related enums
 //!
 //! The library ships several additional enums in places where GDScript would use magic numbers. These are co-located with
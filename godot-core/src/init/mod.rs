@@ -16,14 +16,23 @@ use crate::out;
 
 pub use sys::GdextBuild;
 
+pub use crate::registry::class::register_lazy_classes;
+
+#[cfg(feature = "register-profiling")]
+pub mod profiling;
+
 #[doc(hidden)]
 // TODO consider body safe despite unsafe function, and explicitly mark unsafe {} locations
 pub unsafe fn __gdext_load_library<E: ExtensionLibrary>(
     interface_or_get_proc_address: sys::InitCompat,
     library: sys::GDExtensionClassLibraryPtr,
     init: *mut sys::GDExtensionInitialization,
+    default_metadata: LibraryMetadata,
 ) -> sys::GDExtensionBool {
     let init_code = || {
+        #[cfg(feature = "register-profiling")]
+        let begin = std::time::Instant::now();
+
         // Make sure the first thing we do is check whether hot reloading should be enabled or not. This is to ensure that if we do anything to
         // cause TLS-destructors to run then we have a setting already for how to deal with them. Otherwise this could cause the default
         // behavior to kick in and disable hot reloading.
@@ -34,6 +43,11 @@ pub unsafe fn __gdext_load_library<E: ExtensionLibrary>(
             Some(false) => sys::linux_reload_workaround::disable_hot_reload(),
         }
 
+        // Must happen before classes are registered, as it affects their names.
+        if let Some(prefix) = E::class_name_prefix() {
+            crate::meta::set_class_name_prefix(prefix);
+        }
+
         let tool_only_in_editor = match E::editor_run_behavior() {
             EditorRunBehavior::ToolClassesOnly => true,
             EditorRunBehavior::AllClasses => false,
@@ -43,9 +57,19 @@ pub unsafe fn __gdext_load_library<E: ExtensionLibrary>(
 
         sys::initialize(interface_or_get_proc_address, library, config);
 
-        // Currently no way to express failure; could be exposed to E if necessary.
+        *METADATA.lock() = Some(E::metadata().unwrap_or(default_metadata));
+
+        // Only failure that can be expressed is an incompatible engine version; could be exposed further to E if necessary.
         // No early exit, unclear if Godot still requires output parameters to be set.
-        let success = true;
+        let version = engine_version();
+        let min_version = E::min_compat_version();
+        let success = is_version_compatible(min_version, version);
+        if let (false, Some(min_version)) = (success, min_version) {
+            crate::global::godot_error!(
+                "{} requires Godot {min_version} or later, but runs on Godot {version}; library is not loaded.",
+                library_metadata()
+            );
+        }
 
         let godot_init_params = sys::GDExtensionInitialization {
             minimum_initialization_level: E::min_level().to_sys(),
@@ -56,6 +80,12 @@ pub unsafe fn __gdext_load_library<E: ExtensionLibrary>(
 
         *init = godot_init_params;
 
+        #[cfg(feature = "register-profiling")]
+        {
+            profiling::reset();
+            profiling::record_library_load(begin.elapsed());
+        }
+
         success as u8
     };
 
@@ -65,8 +95,15 @@ pub unsafe fn __gdext_load_library<E: ExtensionLibrary>(
     is_success.unwrap_or(0)
 }
 
+/// Whether the running engine `version` satisfies the library's [`ExtensionLibrary::min_compat_version()`].
+fn is_version_compatible(min_version: Option<EngineVersion>, version: EngineVersion) -> bool {
+    min_version.map_or(true, |min| version >= min)
+}
+
 static LEVEL_SERVERS_CORE_LOADED: AtomicBool = AtomicBool::new(false);
 
+static METADATA: sys::Global<Option<LibraryMetadata>> = sys::Global::default();
+
 unsafe extern "C" fn ffi_initialize_layer<E: ExtensionLibrary>(
     _userdata: *mut std::ffi::c_void,
     init_level: sys::GDExtensionInitializationLevel,
@@ -90,8 +127,24 @@ unsafe extern "C" fn ffi_initialize_layer<E: ExtensionLibrary>(
             LEVEL_SERVERS_CORE_LOADED.store(true, Relaxed);
         }
 
+        #[cfg(feature = "register-profiling")]
+        let begin = std::time::Instant::now();
+
         gdext_on_level_init(level);
         E::on_level_init(level);
+
+        #[cfg(since_api = "4.2")]
+        if level == InitLevel::Scene {
+            call_when_main_loop_started(E::on_main_loop_started);
+        }
+
+        // Hot reload only happens in the editor, so the editor level is the last one to be initialized again.
+        if level == InitLevel::Editor && crate::registry::extensions::take_reload_mark() {
+            E::on_editor_reload_end();
+        }
+
+        #[cfg(feature = "register-profiling")]
+        profiling::record_level(level, begin.elapsed());
     }
 
     // Swallow panics. TODO consider crashing if gdext init fails.
@@ -114,6 +167,12 @@ unsafe extern "C" fn ffi_deinitialize_layer<E: ExtensionLibrary>(
             LEVEL_SERVERS_CORE_LOADED.store(false, Relaxed);
         }
 
+        // The editor level is the first one to be unloaded.
+        if level == InitLevel::Editor && is_hot_reload_unload() {
+            crate::registry::extensions::set_reload_mark();
+            E::on_editor_reload_begin();
+        }
+
         E::on_level_deinit(level);
         gdext_on_level_deinit(level);
     });
@@ -134,6 +193,7 @@ fn gdext_on_level_init(level: InitLevel) {
             InitLevel::Scene => {
                 sys::load_class_method_table(sys::ClassApiLevel::Scene);
                 ensure_godot_features_compatible();
+                crate::registry::extensions::claim_library(&library_metadata());
             }
             InitLevel::Editor => {
                 sys::load_class_method_table(sys::ClassApiLevel::Editor);
@@ -147,6 +207,10 @@ fn gdext_on_level_init(level: InitLevel) {
 fn gdext_on_level_deinit(level: InitLevel) {
     crate::registry::class::unregister_classes(level);
 
+    if level == InitLevel::Scene {
+        crate::registry::extensions::release_library();
+    }
+
     if level == InitLevel::Core {
         // If lowest level is unloaded, call global deinitialization.
         // No business logic by itself, but ensures consistency if re-initialization (hot-reload on Linux) occurs.
@@ -220,6 +284,19 @@ pub unsafe trait ExtensionLibrary {
         // Nothing by default.
     }
 
+    /// Prefix prepended to the Godot names of all classes registered by this extension.
+    ///
+    /// Godot has a single global namespace for classes, so extensions (and the project's own scripts with `class_name`) can clash.
+    /// Returning e.g. `Some("Acme")` registers a Rust struct `Player` as `AcmePlayer` in Godot. `#[class(rename)]` is applied first,
+    /// so `#[class(rename = Hero)]` becomes `AcmeHero`.
+    ///
+    /// Classes with an explicit `#[class(namespace = "...")]` use that instead; use `namespace = ""` to opt out of the prefix.
+    ///
+    /// The prefix must consist of ASCII letters, digits and underscores. It is read once when the library is loaded.
+    fn class_name_prefix() -> Option<&'static str> {
+        None
+    }
+
     /// Whether to enable hot reloading of this library. Return `None` to use the default behavior.
     ///
     /// Enabling this will ensure that the library can be hot reloaded. If this is disabled then hot reloading may still work, but there is no
@@ -233,6 +310,79 @@ pub unsafe trait ExtensionLibrary {
     fn override_hot_reload() -> Option<bool> {
         None
     }
+
+    /// Name and version of this library, see [`LibraryMetadata`].
+    ///
+    /// Return `None` to use the package name and version from the `Cargo.toml` of the crate containing the `#[gdextension]` impl.
+    fn metadata() -> Option<LibraryMetadata> {
+        None
+    }
+
+    /// Oldest Godot version that this library can run on.
+    ///
+    /// If the running engine is older, an error is printed and the library is not loaded. Unlike `compatibility_minimum` in the
+    /// `.gdextension` file, this can also require a patch version, e.g. for an engine bugfix that the extension relies on.
+    fn min_compat_version() -> Option<EngineVersion> {
+        None
+    }
+
+    /// Custom logic once the main loop is running, before the first frame is processed.
+    ///
+    /// At this point, autoloads and the main scene are available, which is not yet the case in [`Self::on_level_init()`].
+    /// After a hot reload, this is called again in the next frame. Requires Godot 4.2 or later; not called on older versions.
+    fn on_main_loop_started() {
+        // Nothing by default.
+    }
+
+    /// Custom logic before the editor hot-reloads this library.
+    ///
+    /// Runs in the old instance of the library, before [`Self::on_level_deinit()`], while all classes are still registered. Useful
+    /// to persist state (e.g. in editor settings or on disk) that should survive the reload.
+    fn on_editor_reload_begin() {
+        // Nothing by default.
+    }
+
+    /// Custom logic after the editor has hot-reloaded this library.
+    ///
+    /// Runs in the new instance of the library, after [`Self::on_level_init()`] has been called for all levels.
+    fn on_editor_reload_end() {
+        // Nothing by default.
+    }
+}
+
+/// Name and version of a Rust extension library.
+///
+/// Returned by [`ExtensionLibrary::metadata()`]. The metadata of all loaded Rust extensions is shared in the process, so that libraries
+/// can check for each other, see [`rust_libraries()`](crate::registry::extensions::rust_libraries).
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct LibraryMetadata {
+    /// Name of the library, e.g. the crate name.
+    pub name: &'static str,
+
+    /// Version of the library, preferably in semver format.
+    pub version: &'static str,
+}
+
+impl LibraryMetadata {
+    pub const fn new(name: &'static str, version: &'static str) -> Self {
+        Self { name, version }
+    }
+}
+
+impl std::fmt::Display for LibraryMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.name, self.version)
+    }
+}
+
+/// Returns the metadata of this library, as declared by [`ExtensionLibrary::metadata()`].
+///
+/// # Panics
+/// If called before the library is initialized.
+pub fn library_metadata() -> LibraryMetadata {
+    METADATA
+        .lock()
+        .expect("library_metadata() called before library initialization")
 }
 
 /// Determines if and how an extension's code is run in the editor.
@@ -263,6 +413,199 @@ pub enum EditorRunBehavior {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// Determines what happens when Rust code invoked by Godot panics.
+///
+/// This applies to `#[func]` methods, virtual methods (such as `ready()` or `process()`) and other callbacks from the engine.
+/// The policy can be set globally through [`set_panic_policy()`], typically inside [`ExtensionLibrary::on_level_init()`].
+/// Individual methods can override the global policy with `#[func(on_panic = ...)]`.
+///
+/// Regardless of the policy, panics never unwind into the engine.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+#[non_exhaustive]
+pub enum PanicPolicy {
+    /// Prints the panic message to the Godot console, and returns a default value to the caller.
+    ///
+    /// When a `#[func]` is called dynamically (e.g. from GDScript), a call error is reported to the caller. This is the default.
+    #[default]
+    LogAndDefault,
+
+    /// Like [`LogAndDefault`][Self::LogAndDefault], but reports the panic as a script error including the Rust backtrace.
+    ///
+    /// Script errors are listed in the editor's debugger panel, with the call site that invoked the Rust code. Capturing a backtrace
+    /// is expensive, so this policy is mostly useful during development.
+    ScriptError,
+
+    /// Prints the panic message, then aborts the whole process.
+    ///
+    /// Useful for headless applications and CI, where a panic should not go unnoticed.
+    Abort,
+}
+
+impl PanicPolicy {
+    pub(crate) fn to_ord(self) -> u8 {
+        match self {
+            Self::LogAndDefault => 0,
+            Self::ScriptError => 1,
+            Self::Abort => 2,
+        }
+    }
+
+    pub(crate) fn from_ord(ord: u8) -> Self {
+        match ord {
+            0 => Self::LogAndDefault,
+            1 => Self::ScriptError,
+            2 => Self::Abort,
+            _ => unreachable!("invalid panic policy ordinal {ord}"),
+        }
+    }
+}
+
+/// Sets the global [`PanicPolicy`], returning the previous one.
+///
+/// Methods annotated with `#[func(on_panic = ...)]` are not affected.
+///
+/// # Example
+/// ```no_run
+/// use godot::init::{set_panic_policy, PanicPolicy};
+/// use godot::prelude::*;
+///
+/// struct MyExtension;
+///
+/// #[gdextension]
+/// unsafe impl ExtensionLibrary for MyExtension {
+///     fn on_level_init(level: InitLevel) {
+///         if level == InitLevel::Scene {
+///             set_panic_policy(PanicPolicy::ScriptError);
+///         }
+///     }
+/// }
+/// ```
+pub fn set_panic_policy(policy: PanicPolicy) -> PanicPolicy {
+    crate::private::set_global_panic_policy(policy)
+}
+
+/// Returns the global [`PanicPolicy`].
+pub fn panic_policy() -> PanicPolicy {
+    crate::private::global_panic_policy()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Version of the Godot engine, as `major.minor.patch`.
+///
+/// Returned by [`engine_version()`]. Versions are ordered, so they can be compared directly:
+/// ```no_run
+/// use godot::init::{engine_version, EngineVersion};
+///
+/// if engine_version() >= EngineVersion::new(4, 3, 0) {
+///     // ...
+/// }
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct EngineVersion {
+    pub major: u8,
+    pub minor: u8,
+    pub patch: u8,
+}
+
+impl EngineVersion {
+    pub const fn new(major: u8, minor: u8, patch: u8) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Version of the Godot API against which gdext was compiled (set by `api-*` features).
+    pub const fn compiled() -> Self {
+        let (major, minor, patch) = GdextBuild::godot_static_version_triple();
+        Self::new(major, minor, patch)
+    }
+}
+
+impl std::fmt::Display for EngineVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Returns the version of the Godot engine that loaded the extension.
+///
+/// This can be newer than [`EngineVersion::compiled()`], allowing a single binary to make use of features of newer Godot versions.
+/// To branch on the version, the [`if_godot!`][crate::if_godot] macro is often more concise.
+///
+/// # Panics
+/// If called before the library is initialized.
+pub fn engine_version() -> EngineVersion {
+    assert!(
+        sys::is_initialized(),
+        "engine_version() called before library initialization"
+    );
+
+    let (major, minor, patch) = GdextBuild::godot_runtime_version_triple();
+    EngineVersion::new(major, minor, patch)
+}
+
+/// Returns whether the running Godot engine provides the GDExtension interface function `interface_fn`.
+///
+/// Function names are the ones from `gdextension_interface.h`, without `GDExtensionInterface` prefix, e.g.
+/// `"classdb_register_extension_class3"`. This allows checking for individual capabilities rather than version numbers, for example
+/// when interacting with the raw [`sys`] interface. Always returns `false` when running on Godot 4.0, which does not support looking up
+/// functions by name.
+///
+/// # Panics
+/// If called before the library is initialized.
+pub fn engine_supports(interface_fn: &str) -> bool {
+    assert!(
+        sys::is_initialized(),
+        "engine_supports() called before library initialization"
+    );
+
+    // SAFETY: library is initialized.
+    unsafe { sys::has_interface_fn(interface_fn) }
+}
+
+/// Branches on the version of the running Godot engine.
+///
+/// Runtime counterpart to `#[cfg(since_api = "4.x")]` and `#[cfg(before_api = "4.x")]`. While the attributes select code depending on the
+/// Godot version against which gdext is _compiled_, this macro checks the version of the engine that _runs_ the extension. It is thus
+/// possible to ship one binary that targets multiple Godot 4.x minor versions, and enable functionality only where supported.
+///
+/// Supported comparisons are `>=` and `<`, followed by a `major.minor` version. The macro is an expression, returning the value of the
+/// selected block.
+///
+/// # Example
+/// ```no_run
+/// use godot::init::if_godot;
+///
+/// let label = if_godot!(>= 4.3 {
+///     "typed dictionaries available"
+/// } else {
+///     "untyped dictionaries only"
+/// });
+///
+/// if_godot!(< 4.2 {
+///     godot::global::godot_warn!("some features are disabled");
+/// });
+/// ```
+///
+/// # Panics
+/// If the version is not of the form `4.x`, or if called before the library is initialized.
+#[macro_export]
+macro_rules! if_godot {
+    (>= $version:literal $then:block $(else $otherwise:block)?) => {
+        if $crate::init::GdextBuild::since_api(stringify!($version)) $then $(else $otherwise)?
+    };
+    (< $version:literal $then:block $(else $otherwise:block)?) => {
+        if $crate::init::GdextBuild::before_api(stringify!($version)) $then $(else $otherwise)?
+    };
+}
+
+pub use crate::if_godot;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 /// Stage of the Godot initialization process.
 ///
 /// Godot's initialization and deinitialization processes are split into multiple stages, like a stack. At each level,
@@ -313,6 +656,51 @@ impl InitLevel {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// Whether the library is unloaded while the engine keeps running, i.e. hot reload in the editor (as opposed to shutdown).
+fn is_hot_reload_unload() -> bool {
+    // On shutdown, Godot deletes the main loop before unloading extensions.
+    let engine = crate::classes::Engine::singleton();
+    engine.is_editor_hint() && engine.get_main_loop().is_some()
+}
+
+/// Invokes `callback` on the first idle frame in which a main loop is present.
+#[cfg(since_api = "4.2")]
+fn call_when_main_loop_started(callback: fn()) {
+    use crate::builtin::{Callable, Variant};
+    use crate::classes::object::ConnectFlags;
+    use crate::meta::ToGodot;
+
+    // The main loop may not exist yet, so defer on the Engine singleton. Signal name is per-library, as other extensions may do the same.
+    let mut engine = crate::classes::Engine::singleton();
+    // SAFETY: library is initialized.
+    let library = unsafe { sys::get_library() };
+    let signal = format!("__godot_rust_main_loop_started_{:x}", library as usize);
+
+    if !engine.has_signal(signal.as_str().into()) {
+        engine.add_user_signal(signal.as_str().into());
+    }
+
+    let callable = Callable::from_fn("on_main_loop_started", move |_args: &[&Variant]| {
+        // Deferred calls may also be flushed during engine startup, before the main loop is set.
+        if crate::classes::Engine::singleton()
+            .get_main_loop()
+            .is_some()
+        {
+            callback();
+        } else {
+            call_when_main_loop_started(callback);
+        }
+        Ok(Variant::nil())
+    });
+
+    engine
+        .connect_ex(signal.as_str().into(), callable)
+        .flags(ConnectFlags::ONE_SHOT)
+        .done();
+
+    engine.call_deferred("emit_signal".into(), &[signal.to_variant()]);
+}
+
 fn ensure_godot_features_compatible() {
     // The reason why we don't simply call Os::has_feature() here is that we might move the high-level engine classes out of godot-core
     // later, and godot-core would only depend on godot-sys. This makes future migrations easier. We still have access to builtins though.
@@ -359,3 +747,29 @@ fn ensure_godot_features_compatible() {
         );
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::{is_version_compatible, EngineVersion};
+
+    #[test]
+    fn version_compatible_without_minimum() {
+        assert!(is_version_compatible(None, EngineVersion::new(4, 0, 0)));
+    }
+
+    #[test]
+    fn version_compatible_with_minimum() {
+        let min = Some(EngineVersion::new(4, 2, 1));
+
+        assert!(is_version_compatible(min, EngineVersion::new(4, 2, 1)));
+        assert!(is_version_compatible(min, EngineVersion::new(4, 3, 0)));
+        assert!(is_version_compatible(min, EngineVersion::new(5, 0, 0)));
+
+        assert!(!is_version_compatible(min, EngineVersion::new(4, 2, 0)));
+        assert!(!is_version_compatible(min, EngineVersion::new(4, 1, 9)));
+        assert!(!is_version_compatible(min, EngineVersion::new(3, 9, 9)));
+    }
+}
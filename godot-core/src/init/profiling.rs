@@ -0,0 +1,157 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Startup time measurements for the extension.
+//!
+//! Requires the `register-profiling` Cargo feature. When enabled, the following durations are recorded:
+//! - Loading the library, i.e. the GDExtension entry point.
+//! - Each initialization level, including the `ExtensionLibrary::on_level_init()` callback.
+//! - Registration of each class, including its methods, properties and signals.
+//! - Registration of docs (with `register-docs`). This time is also contained in the per-class timings.
+//!
+//! Classes declared with `#[class(lazy)]` are recorded when they are actually registered.
+//!
+//! ```no_run
+//! use godot::init::profiling;
+//! use godot::prelude::*;
+//!
+//! struct MyExtension;
+//!
+//! #[gdextension]
+//! unsafe impl ExtensionLibrary for MyExtension {
+//!     fn on_level_init(level: InitLevel) {
+//!         if level == InitLevel::Scene {
+//!             let profile = profiling::startup_profile();
+//!             godot_print!("{profile}");
+//!
+//!             for class in profile.slowest_classes(5) {
+//!                 godot_print!("{} took {:?}", class.class_name, class.duration);
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! Note that the callback of a level runs before its measurement ends, so a profile queried there does not contain that level's total yet.
+
+use std::fmt;
+use std::time::Duration;
+
+use godot_ffi as sys;
+use sys::Global;
+
+use crate::init::InitLevel;
+use crate::meta::ClassName;
+
+static PROFILE: Global<StartupProfile> = Global::default();
+
+/// Registration time of one class.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClassTiming {
+    /// Name of the class in Godot.
+    pub class_name: String,
+
+    /// Level at which the class was registered.
+    pub init_level: InitLevel,
+
+    /// Time spent registering the class with Godot.
+    pub duration: Duration,
+}
+
+/// Timings collected during startup, see [module docs](self).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StartupProfile {
+    /// Time spent in the GDExtension entry point.
+    pub library_load: Duration,
+
+    /// Time spent in each initialization level, in order of initialization.
+    pub levels: Vec<(InitLevel, Duration)>,
+
+    /// Registration time of each class, in order of registration.
+    pub classes: Vec<ClassTiming>,
+
+    /// Time spent registering docs (part of the class timings).
+    pub docs: Duration,
+}
+
+impl StartupProfile {
+    /// Total time of library load and all initialization levels measured so far.
+    pub fn total(&self) -> Duration {
+        self.library_load
+            + self
+                .levels
+                .iter()
+                .map(|(_, duration)| *duration)
+                .sum::<Duration>()
+    }
+
+    /// Sum of all class registration times.
+    pub fn class_registration(&self) -> Duration {
+        self.classes.iter().map(|class| class.duration).sum()
+    }
+
+    /// Returns up to `count` classes with the longest registration time, slowest first.
+    pub fn slowest_classes(&self, count: usize) -> Vec<&ClassTiming> {
+        let mut classes = self.classes.iter().collect::<Vec<_>>();
+        classes.sort_by(|a, b| b.duration.cmp(&a.duration));
+        classes.truncate(count);
+        classes
+    }
+}
+
+impl fmt::Display for StartupProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Extension startup: {:?} total", self.total())?;
+        writeln!(f, "  library load:   {:?}", self.library_load)?;
+
+        for (level, duration) in &self.levels {
+            writeln!(f, "  level {level:?}: {duration:?}")?;
+        }
+
+        write!(
+            f,
+            "  classes:        {:?} for {} classes (docs: {:?})",
+            self.class_registration(),
+            self.classes.len(),
+            self.docs
+        )
+    }
+}
+
+/// Returns a snapshot of all timings recorded so far.
+pub fn startup_profile() -> StartupProfile {
+    PROFILE.lock().clone()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Crate-internal recording
+
+pub(crate) fn record_library_load(duration: Duration) {
+    PROFILE.lock().library_load = duration;
+}
+
+pub(crate) fn record_level(init_level: InitLevel, duration: Duration) {
+    PROFILE.lock().levels.push((init_level, duration));
+}
+
+pub(crate) fn record_class(class_name: ClassName, init_level: InitLevel, duration: Duration) {
+    PROFILE.lock().classes.push(ClassTiming {
+        class_name: class_name.to_string(),
+        init_level,
+        duration,
+    });
+}
+
+#[cfg_attr(not(feature = "register-docs"), allow(dead_code))]
+pub(crate) fn record_docs(duration: Duration) {
+    PROFILE.lock().docs += duration;
+}
+
+/// Resets all timings, e.g. before re-initialization during hot reload.
+pub(crate) fn reset() {
+    *PROFILE.lock() = StartupProfile::default();
+}
@@ -15,11 +15,17 @@
 pub mod builder;
 pub mod builtin;
 pub mod classes;
+#[cfg(feature = "alloc-stats")]
+pub mod diagnostics;
+#[cfg(feature = "register-docs")]
+pub mod docs;
 pub mod global;
 pub mod init;
 pub mod meta;
 pub mod obj;
 pub mod registry;
+pub mod sys_ext;
+pub mod task;
 pub mod tools;
 
 mod storage;
@@ -0,0 +1,263 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Runtime detection of lossy numeric conversions between Rust and Godot.
+//!
+//! Available with the `conversion-audit` Cargo feature. Godot stores all integers as `i64` and all floats as `f64`; narrower Rust types
+//! are converted at the boundary. Some of these conversions are silent by design:
+//! - `f64` -> `f32` rounds to the nearest representable value, and values beyond the `f32` range become infinite or zero.
+//! - `u64` -> `i64` wraps around for values above `i64::MAX` in ptrcalls.
+//! - NaN and infinite floats (including vector and matrix components) are passed on to the engine, where they spread through physics
+//!   and rendering state.
+//!
+//! With the audit enabled, each of these cases is reported according to the current [`AuditMode`]. Overflowing integer conversions
+//! such as `i64` -> `i32` already fail with a [`ConvertError`][crate::meta::error::ConvertError] and are not affected.
+//!
+//! ```no_run
+//! use godot::meta::audit::{self, AuditMode};
+//!
+//! // E.g. in a debug build, during `ExtensionLibrary::on_level_init()`:
+//! audit::set_audit_mode(AuditMode::Panic);
+//! ```
+//!
+//! Outbound checks cover arguments of engine calls as well as values returned from `#[func]`s, both through ptrcall and varcall.
+//! They add a type inspection to each of these values, so the feature is meant for debugging, not for release builds.
+
+use std::any::Any;
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::builtin::{
+    Aabb, Basis, Plane, Projection, Quaternion, Rect2, Transform2D, Transform3D, Variant,
+    VariantType, Vector2, Vector3, Vector4,
+};
+use crate::global::godot_warn;
+use crate::meta::CallContext;
+
+/// How lossy conversions are reported.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum AuditMode {
+    /// Conversions are not checked.
+    Ignore,
+
+    /// Each lossy conversion prints a warning (default).
+    #[default]
+    Log,
+
+    /// Each lossy conversion panics, pointing to the offending call in the backtrace.
+    Panic,
+}
+
+static AUDIT_MODE: AtomicU8 = AtomicU8::new(AuditMode::Log as u8);
+
+/// Changes how lossy conversions are reported, for all threads.
+pub fn set_audit_mode(mode: AuditMode) {
+    AUDIT_MODE.store(mode as u8, Ordering::Relaxed);
+}
+
+/// Returns the current [`AuditMode`].
+pub fn audit_mode() -> AuditMode {
+    match AUDIT_MODE.load(Ordering::Relaxed) {
+        0 => AuditMode::Ignore,
+        1 => AuditMode::Log,
+        _ => AuditMode::Panic,
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Crate-internal checks
+
+/// Checks a `f64` coming from Godot, before it is narrowed to `f32`.
+pub(crate) fn check_f64_to_f32(value: f64) {
+    if audit_mode() == AuditMode::Ignore {
+        return;
+    }
+
+    if let Some(loss) = f64_to_f32_loss(value) {
+        report(format_args!(
+            "lossy conversion f64 -> f32: {value:e} {loss}, became {narrowed:e}",
+            narrowed = value as f32
+        ));
+    }
+}
+
+/// Describes how narrowing `value` to `f32` loses information, or returns `None` if it is exact.
+fn f64_to_f32_loss(value: f64) -> Option<&'static str> {
+    let narrowed = value as f32;
+
+    if !value.is_finite() || f64::from(narrowed) == value {
+        // NaN and infinity are preserved; they are reported when passed to Godot.
+        None
+    } else if narrowed.is_infinite() || (narrowed == 0.0 && value != 0.0) {
+        Some("is outside the f32 range")
+    } else {
+        Some("is not representable as f32")
+    }
+}
+
+/// Checks a `u64` before it is passed to Godot as `i64`.
+pub(crate) fn check_u64_to_i64(value: u64) {
+    if i64::try_from(value).is_err() {
+        report(format_args!(
+            "lossy conversion u64 -> i64: {value} exceeds i64::MAX, became {wrapped}",
+            wrapped = value as i64
+        ));
+    }
+}
+
+/// Checks an argument or return value passed from Rust to Godot for non-finite floats.
+pub(crate) fn check_outbound<T: Any>(value: &T, call_ctx: &CallContext) {
+    if audit_mode() != AuditMode::Ignore && is_non_finite(value) {
+        report(format_args!(
+            "non-finite value passed to Godot in `{call_ctx}`: {ty} contains NaN or infinity",
+            ty = std::any::type_name::<T>()
+        ));
+    }
+}
+
+fn is_non_finite(value: &dyn Any) -> bool {
+    // E.g. returned from a Callable, or passed to a varargs method.
+    if let Some(variant) = value.downcast_ref::<Variant>() {
+        return is_non_finite_variant(variant);
+    }
+
+    macro_rules! check {
+        ($($T:ty),* $(,)?) => {
+            $(
+                if let Some(value) = value.downcast_ref::<$T>() {
+                    return !value.is_finite();
+                }
+            )*
+        };
+    }
+
+    check!(
+        f64,
+        f32,
+        Vector2,
+        Vector3,
+        Vector4,
+        Quaternion,
+        Basis,
+        Transform2D,
+        Transform3D,
+        Rect2,
+        Aabb,
+        Plane,
+    );
+
+    if let Some(projection) = value.downcast_ref::<Projection>() {
+        return !projection.cols.iter().all(|col| col.is_finite());
+    }
+
+    false
+}
+
+fn is_non_finite_variant(variant: &Variant) -> bool {
+    macro_rules! check {
+        ($($T:ty),* $(,)?) => {
+            $(
+                if let Ok(value) = variant.try_to::<$T>() {
+                    return is_non_finite(&value);
+                }
+            )*
+        };
+    }
+
+    match variant.get_type() {
+        VariantType::FLOAT => check!(f64),
+        VariantType::VECTOR2 => check!(Vector2),
+        VariantType::VECTOR3 => check!(Vector3),
+        VariantType::VECTOR4 => check!(Vector4),
+        VariantType::QUATERNION => check!(Quaternion),
+        VariantType::BASIS => check!(Basis),
+        VariantType::TRANSFORM2D => check!(Transform2D),
+        VariantType::TRANSFORM3D => check!(Transform3D),
+        VariantType::RECT2 => check!(Rect2),
+        VariantType::AABB => check!(Aabb),
+        VariantType::PLANE => check!(Plane),
+        VariantType::PROJECTION => check!(Projection),
+        _ => {}
+    }
+
+    false
+}
+
+fn report(message: fmt::Arguments) {
+    match audit_mode() {
+        AuditMode::Ignore => {}
+        AuditMode::Log => godot_warn!("{message}"),
+        AuditMode::Panic => panic!("{message}"),
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builtin::real;
+
+    #[test]
+    fn f64_to_f32_exact() {
+        for value in [
+            0.0,
+            -0.0,
+            1.0,
+            0.5,
+            -1024.25,
+            f32::MAX as f64,
+            f32::MIN_POSITIVE as f64,
+        ] {
+            assert_eq!(f64_to_f32_loss(value), None, "{value}");
+        }
+
+        // Non-finite values are not narrowing losses.
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert_eq!(f64_to_f32_loss(value), None, "{value}");
+        }
+    }
+
+    #[test]
+    fn f64_to_f32_out_of_range() {
+        for value in [1e39, -1e39, 1e-50, -1e-50] {
+            assert_eq!(
+                f64_to_f32_loss(value),
+                Some("is outside the f32 range"),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn f64_to_f32_precision() {
+        for value in [0.1, 16_777_217.0, std::f64::consts::PI] {
+            assert_eq!(
+                f64_to_f32_loss(value),
+                Some("is not representable as f32"),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn non_finite_values() {
+        assert!(is_non_finite(&f64::NAN));
+        assert!(is_non_finite(&f32::INFINITY));
+        assert!(is_non_finite(&Vector2::new(1.0, real::NAN)));
+        assert!(is_non_finite(&Vector3::new(real::INFINITY, 0.0, 0.0)));
+
+        assert!(!is_non_finite(&1.5f64));
+        assert!(!is_non_finite(&Vector3::new(1.0, 2.0, 3.0)));
+        assert!(!is_non_finite(&Transform3D::IDENTITY));
+
+        // Types without floats are never reported.
+        assert!(!is_non_finite(&42i64));
+        assert!(!is_non_finite(&"text"));
+    }
+}
@@ -0,0 +1,127 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Typed queries about the class hierarchy known to Godot at runtime.
+//!
+//! Thin layer over [`ClassDb`], which works for engine classes, classes of any GDExtension, and Rust classes alike. Members are
+//! returned as the structs from [`register::reflection`](crate::registry::reflection), instead of `Dictionary` objects.
+//!
+//! ```no_run
+//! use godot::classes::{Node, Node3D};
+//! use godot::meta::class_db;
+//!
+//! assert!(class_db::is_subclass_of::<Node3D, Node>());
+//! assert!(class_db::class_exists("MeshInstance3D"));
+//!
+//! for property in class_db::properties("Camera3D", false) {
+//!     godot::global::godot_print!("{}: {:?}", property.name, property.variant_type);
+//! }
+//! ```
+//!
+//! Names of classes are passed as `&str` and always refer to the name in Godot, which can differ from the Rust type name (e.g.
+//! Rust `ClassDb` is `ClassDB` in Godot).
+
+use crate::builtin::StringName;
+use crate::classes::ClassDb;
+use crate::meta::ClassName;
+use crate::obj::GodotClass;
+use crate::registry::class;
+use crate::registry::reflection::{
+    self, ConstantMetadata, MethodMetadata, ParamMetadata, SignalMetadata,
+};
+
+/// Whether `Derived` is `Base` or inherits from it, according to Godot.
+///
+/// Unlike the compile-time [`Inherits`](crate::obj::Inherits) bound, this also works in generic code without bounds, and for
+/// classes registered by other extensions.
+pub fn is_subclass_of<Derived, Base>() -> bool
+where
+    Derived: GodotClass,
+    Base: GodotClass,
+{
+    class_inherits(Derived::class_name().as_str(), Base::class_name().as_str())
+}
+
+/// Whether the class `class` is `base` or inherits from it. Returns `false` if either class does not exist.
+pub fn class_inherits(class: &str, base: &str) -> bool {
+    class_exists(class) && ClassDb::singleton().is_parent_class(class.into(), base.into())
+}
+
+/// Whether a class named `class` is currently registered in Godot.
+pub fn class_exists(class: &str) -> bool {
+    ClassDb::singleton().class_exists(class.into())
+}
+
+/// Returns the direct base class, or `None` for `Object` and classes that do not exist.
+pub fn parent_class(class: &str) -> Option<String> {
+    if !class_exists(class) {
+        return None;
+    }
+
+    let parent = ClassDb::singleton().get_parent_class(class.into());
+    Some(parent.to_string()).filter(|parent| !parent.is_empty())
+}
+
+/// Returns all classes that inherit from `class`, directly or indirectly, sorted by name.
+pub fn subclasses_of(class: &str) -> Vec<String> {
+    let mut subclasses = ClassDb::singleton()
+        .get_inheriters_from_class(class.into())
+        .as_slice()
+        .iter()
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>();
+
+    subclasses.sort();
+    subclasses
+}
+
+/// Whether the class can be instantiated, i.e. exists and is not abstract.
+pub fn can_instantiate(class: &str) -> bool {
+    class_exists(class) && ClassDb::singleton().can_instantiate(class.into())
+}
+
+/// Returns the methods of a class. With `inherited`, methods of all base classes are included.
+///
+/// Returns an empty list if the class does not exist.
+pub fn methods(class: &str, inherited: bool) -> Vec<MethodMetadata> {
+    reflection::read_methods(&StringName::from(class), inherited)
+}
+
+/// Returns the properties of a class, including groups and subgroups. With `inherited`, properties of all base classes are included.
+///
+/// Returns an empty list if the class does not exist.
+pub fn properties(class: &str, inherited: bool) -> Vec<ParamMetadata> {
+    reflection::read_properties(&StringName::from(class), inherited)
+}
+
+/// Returns the signals of a class. With `inherited`, signals of all base classes are included.
+///
+/// Returns an empty list if the class does not exist.
+pub fn signals(class: &str, inherited: bool) -> Vec<SignalMetadata> {
+    reflection::read_signals(&StringName::from(class), inherited)
+}
+
+/// Returns the integer constants of a class, including enum and bitfield constants. With `inherited`, constants of all base classes
+/// are included.
+///
+/// Returns an empty list if the class does not exist.
+pub fn constants(class: &str, inherited: bool) -> Vec<ConstantMetadata> {
+    reflection::read_constants(&StringName::from(class), inherited)
+}
+
+/// Maps a Godot class name to the [`ClassName`] of the corresponding Rust type, if there is one.
+///
+/// This is the case for engine classes that are part of the generated API, and for classes registered by this extension.
+/// Classes of other extensions or scripts return `None`.
+pub fn rust_class_name(class: &str) -> Option<ClassName> {
+    crate::gen::classes::engine_class_name(class).or_else(|| {
+        class::loaded_classes()
+            .into_iter()
+            .map(|(class_name, _)| class_name)
+            .find(|class_name| class_name.as_str() == class)
+    })
+}
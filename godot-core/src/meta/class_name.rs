@@ -10,6 +10,7 @@ use std::collections::HashMap;
 use std::ffi::CStr;
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::sync::OnceLock;
 
 use crate::builtin::*;
 use sys::Global;
@@ -18,6 +19,9 @@ use sys::Global;
 // but we don't know how many classes).
 static CACHED_STRING_NAMES: Global<HashMap<ClassName, Box<StringName>>> = Global::default();
 
+/// Extension-wide prefix, see [`ExtensionLibrary::class_name_prefix()`](crate::init::ExtensionLibrary::class_name_prefix).
+static CLASS_NAME_PREFIX: OnceLock<&'static str> = OnceLock::new();
+
 /// Name of a class registered with Godot.
 ///
 /// Holds the Godot name, not the Rust name (they sometimes differ, e.g. Godot `CSGMesh3D` vs Rust `CsgMesh3D`).
@@ -27,6 +31,11 @@ static CACHED_STRING_NAMES: Global<HashMap<ClassName, Box<StringName>>> = Global
 #[derive(Copy, Clone, Debug)]
 pub struct ClassName {
     c_str: &'static CStr,
+
+    /// If present, the extension-wide prefix is prepended. The prefix is only known once the library is loaded, which is after
+    /// class plugins have been collected; so it must be applied lazily. The per-class cell holds the leaked prefixed name, so that
+    /// `as_str()` can return `&'static str` without locking.
+    prefixed: Option<&'static OnceLock<&'static str>>,
     // Could use small-array optimization for common string lengths.
     // Possible optimization: could store pre-computed hash. Would need a custom S parameter for HashMap<K, V, S>, see
     // https://doc.rust-lang.org/std/hash/trait.BuildHasher.html. (The default hasher recomputes the hash repeatedly).
@@ -46,7 +55,24 @@ impl ClassName {
         assert!(bytes.is_ascii(), "string must be ASCII"); // only half of u8 range
         let c_str = CStr::from_bytes_with_nul(bytes).expect("string must be null-terminated");
 
-        Self { c_str }
+        Self {
+            c_str,
+            prefixed: None,
+        }
+    }
+
+    /// Like [`from_ascii_cstr()`][Self::from_ascii_cstr], but applies the extension-wide class name prefix.
+    ///
+    /// Used for user classes without an explicit `#[class(namespace)]`. `prefixed` is a per-class cache for the prefixed name.
+    #[doc(hidden)]
+    pub fn prefixable_from_ascii_cstr(
+        bytes: &'static [u8],
+        prefixed: &'static OnceLock<&'static str>,
+    ) -> Self {
+        Self {
+            prefixed: Some(prefixed),
+            ..Self::from_ascii_cstr(bytes)
+        }
     }
 
     #[doc(hidden)]
@@ -66,7 +92,15 @@ impl ClassName {
     /// Returns the class name as a string slice with static storage duration.
     pub fn as_str(&self) -> &'static str {
         // unwrap() safe, checked in constructor
-        self.c_str.to_str().unwrap()
+        let name = self.c_str.to_str().unwrap();
+
+        match (self.prefixed, CLASS_NAME_PREFIX.get()) {
+            (Some(prefixed), Some(prefix)) => prefixed.get_or_init(|| {
+                let full_name = format!("{prefix}{name}");
+                Box::leak(full_name.into_boxed_str())
+            }),
+            _ => name,
+        }
     }
 
     /// Converts the class name to a `GString`.
@@ -98,13 +132,13 @@ impl ClassName {
     }
 
     fn load_string_name(&self) -> StringName {
-        StringName::from(self.c_str.to_str().unwrap())
+        StringName::from(self.as_str())
     }
 }
 
 impl PartialEq for ClassName {
     fn eq(&self, other: &Self) -> bool {
-        self.c_str == other.c_str
+        self.c_str == other.c_str && self.prefixed.is_some() == other.prefixed.is_some()
     }
 }
 
@@ -121,3 +155,19 @@ impl fmt::Display for ClassName {
         self.as_str().fmt(f)
     }
 }
+
+/// Sets the prefix for user classes, once when the library is loaded.
+pub(crate) fn set_class_name_prefix(prefix: &'static str) {
+    let is_valid = prefix
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !prefix.starts_with(|c: char| c.is_ascii_digit());
+
+    assert!(
+        is_valid,
+        "class name prefix must consist of ASCII letters, digits and `_`, and not start with a digit; got `{prefix}`"
+    );
+
+    // On hot reload, the library is reloaded from scratch, so the prefix can only be set once per process image.
+    let _ = CLASS_NAME_PREFIX.set(prefix);
+}
@@ -0,0 +1,80 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::meta::ClassName;
+use crate::obj::InstanceId;
+
+/// Error when a user instance cannot be bound, because it's already bound in a conflicting way.
+///
+/// Returned by [`Gd::try_bind()`][crate::obj::Gd::try_bind], [`Gd::try_with()`][crate::obj::Gd::try_with] and their `_mut` counterparts.
+/// The panicking versions (e.g. [`Gd::bind()`][crate::obj::Gd::bind]) fail in the same situations.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct BindError {
+    class_name: ClassName,
+    instance_id: Option<InstanceId>,
+    exclusive: bool,
+}
+
+impl BindError {
+    pub(crate) fn new(
+        class_name: ClassName,
+        instance_id: Option<InstanceId>,
+        exclusive: bool,
+    ) -> Self {
+        Self {
+            class_name,
+            instance_id,
+            exclusive,
+        }
+    }
+
+    /// Class of the object that could not be bound.
+    pub fn class_name(&self) -> ClassName {
+        self.class_name
+    }
+
+    /// ID of the object, or `None` if the object is dead.
+    pub fn instance_id(&self) -> Option<InstanceId> {
+        self.instance_id
+    }
+
+    /// Whether an exclusive (`bind_mut`) borrow was requested, as opposed to a shared (`bind`) one.
+    pub fn is_exclusive(&self) -> bool {
+        self.exclusive
+    }
+}
+
+impl fmt::Display for BindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(id) = self.instance_id else {
+            return write!(
+                f,
+                "cannot bind instance of `{}`: object is dead",
+                self.class_name
+            );
+        };
+
+        if self.exclusive {
+            write!(
+                f,
+                "cannot bind instance {id} of `{}` mutably: it is already bound (shared or mutably)",
+                self.class_name
+            )
+        } else {
+            write!(
+                f,
+                "cannot bind instance {id} of `{}`: it is already bound mutably",
+                self.class_name
+            )
+        }
+    }
+}
+
+impl Error for BindError {}
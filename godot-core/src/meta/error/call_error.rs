@@ -106,18 +106,23 @@ impl CallError {
     // ------------------------------------------------------------------------------------------------------------------------------------------
     // Constructors returning Result<(), Self>; possible failure
 
-    /// Checks whether number of arguments matches the number of parameters.
+    /// Checks whether number of arguments matches the number of parameters, of which the last `default_count` are optional.
     pub(crate) fn check_arg_count(
         call_ctx: &CallContext,
         arg_count: usize,
+        default_count: usize,
         param_count: usize,
     ) -> Result<(), Self> {
-        // This will need to be adjusted once optional parameters are supported in #[func].
-        if arg_count == param_count {
+        let required_count = param_count.saturating_sub(default_count);
+        if (required_count..=param_count).contains(&arg_count) {
             return Ok(());
         }
 
-        let call_error = Self::failed_param_count(call_ctx, arg_count, param_count);
+        let call_error = if default_count == 0 {
+            Self::failed_param_count(call_ctx, arg_count, param_count)
+        } else {
+            Self::failed_optional_param_count(call_ctx, arg_count, required_count, param_count)
+        };
 
         Err(call_error)
     }
@@ -233,6 +238,23 @@ impl CallError {
         )
     }
 
+    fn failed_optional_param_count(
+        call_ctx: &CallContext,
+        arg_count: usize,
+        required_count: usize,
+        param_count: usize,
+    ) -> CallError {
+        let arg_plural = plural(arg_count);
+
+        Self::new(
+            call_ctx,
+            format!(
+                "function has {param_count} parameters ({required_count} required), but received {arg_count} argument{arg_plural}"
+            ),
+            None,
+        )
+    }
+
     fn failed_varcall_inner(
         call_ctx: &CallContext,
         call_expr: String,
@@ -0,0 +1,81 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::VariantType;
+
+/// Error when creating or binding a [`Callable`][crate::builtin::Callable] whose target method doesn't fit.
+///
+/// Returned by [`Callable::from_object_method_checked()`][crate::builtin::Callable::from_object_method_checked] and
+/// [`Callable::bind_typed()`][crate::builtin::Callable::bind_typed].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum CallableError {
+    /// The object has no method with this name.
+    MethodNotFound { class: String, method: String },
+
+    /// The method cannot be called with the given number of arguments.
+    ArgumentCount {
+        method: String,
+        /// Number of arguments the method accepts, as `min..=max`. Differs if some parameters have default values.
+        expected: (usize, usize),
+        actual: usize,
+    },
+
+    /// A bound argument does not have the type declared by the method.
+    ArgumentType {
+        method: String,
+        /// Index of the parameter in the method signature.
+        index: usize,
+        expected: VariantType,
+        actual: VariantType,
+    },
+}
+
+impl CallableError {
+    /// Name of the method that was checked.
+    pub fn method_name(&self) -> &str {
+        match self {
+            Self::MethodNotFound { method, .. }
+            | Self::ArgumentCount { method, .. }
+            | Self::ArgumentType { method, .. } => method,
+        }
+    }
+}
+
+impl fmt::Display for CallableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MethodNotFound { class, method } => {
+                write!(f, "method `{method}` not found on class `{class}`")
+            }
+            Self::ArgumentCount {
+                method,
+                expected: (min, max),
+                actual,
+            } => {
+                if min == max {
+                    write!(f, "method `{method}` takes {min} argument(s), but {actual} were given")
+                } else {
+                    write!(f, "method `{method}` takes {min} to {max} arguments, but {actual} were given")
+                }
+            }
+            Self::ArgumentType {
+                method,
+                index,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "method `{method}` expects parameter {index} of type {expected:?}, but bound argument has type {actual:?}"
+            ),
+        }
+    }
+}
+
+impl Error for CallableError {}
@@ -18,10 +18,23 @@ type Cause = Box<dyn Error + Send + Sync>;
 /// Represents errors that can occur when converting values from Godot.
 ///
 /// To create user-defined errors, you can use [`ConvertError::default()`] or [`ConvertError::new("message")`][Self::new].
+///
+/// # Conversion paths
+/// When a conversion fails inside a nested structure, such as an array of dictionaries, the error can be annotated with the location of
+/// the failing value, using [`at_index()`][Self::at_index] and [`at_key()`][Self::at_key]. The resulting message then reads like
+/// `at [3].config.max_hp: expected type INT, got STRING: "10"`.
+///
+/// Built-in conversions annotate failing elements automatically: `Vec<T>` and `[T; N]` record the index of the element that failed to
+/// convert, typed `Array<T>` the first element that does not match `T`, and [`Dictionary::try_get_as()`][crate::builtin::Dictionary::try_get_as]
+/// the key.
+///
+/// Since recording paths has a small cost on every failed conversion, it is only enabled with the `conversion-paths` Cargo feature.
+/// Without it, the annotation methods have no effect.
 #[derive(Debug)]
 pub struct ConvertError {
     kind: ErrorKind,
     value: Option<Variant>,
+    path: ConvertPath,
 }
 
 impl ConvertError {
@@ -43,6 +56,7 @@ impl ConvertError {
         Self {
             kind,
             value: Some(value.to_variant()),
+            path: ConvertPath::default(),
         }
     }
 
@@ -66,9 +80,34 @@ impl ConvertError {
         Self {
             kind: ErrorKind::Custom(Some(error.into())),
             value: Some(value.to_variant()),
+            path: ConvertPath::default(),
         }
     }
 
+    /// Annotates the error with the index of the array element, whose conversion failed.
+    ///
+    /// Call this when propagating an error out of a nested conversion. Annotations are added from the innermost value outward, so the
+    /// outermost container is annotated last. Has no effect without the `conversion-paths` feature.
+    pub fn at_index(mut self, index: usize) -> Self {
+        self.path.push(|| PathSegment::Index(index));
+        self
+    }
+
+    /// Annotates the error with the dictionary key or field name, whose value failed to convert.
+    ///
+    /// See [`at_index()`][Self::at_index] for details.
+    pub fn at_key(mut self, key: impl fmt::Display) -> Self {
+        self.path.push(|| PathSegment::Key(key.to_string()));
+        self
+    }
+
+    /// Returns the location of the failing value inside nested structures, e.g. `[3].config.max_hp`.
+    ///
+    /// Returns `None` if the error has not been annotated, or if the `conversion-paths` feature is disabled.
+    pub fn path(&self) -> Option<String> {
+        self.path.to_path_string()
+    }
+
     /// Returns the rust-error that caused this error, if one exists.
     pub fn cause(&self) -> Option<&(dyn Error + Send + Sync + 'static)> {
         match &self.kind {
@@ -91,6 +130,10 @@ impl ConvertError {
 
 impl fmt::Display for ConvertError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(path) = self.path() {
+            write!(f, "at {path}: ")?;
+        }
+
         write!(f, "{}", self.kind)?;
 
         if let Some(value) = &self.value {
@@ -115,6 +158,7 @@ impl Default for ConvertError {
         Self {
             kind: ErrorKind::Custom(None),
             value: None,
+            path: ConvertPath::default(),
         }
     }
 }
@@ -123,17 +167,23 @@ impl Default for ConvertError {
 #[derive(Debug)]
 pub(crate) struct ErasedConvertError {
     kind: ErrorKind,
+    path: Option<String>,
 }
 
 impl From<ConvertError> for ErasedConvertError {
     fn from(v: ConvertError) -> Self {
+        let path = v.path();
         let ConvertError { kind, .. } = v;
-        Self { kind }
+        Self { kind, path }
     }
 }
 
 impl fmt::Display for ErasedConvertError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(path) = &self.path {
+            write!(f, "at {path}: ")?;
+        }
+
         write!(f, "{}", self.kind)
     }
 }
@@ -147,6 +197,57 @@ impl Error for ErasedConvertError {
     }
 }
 
+/// Location of a failed conversion inside nested structures. Only recorded with the `conversion-paths` feature.
+#[derive(Default, Debug)]
+struct ConvertPath {
+    /// Segments from innermost to outermost.
+    #[cfg(feature = "conversion-paths")]
+    segments: Vec<PathSegment>,
+}
+
+#[cfg_attr(not(feature = "conversion-paths"), allow(dead_code))]
+#[derive(Debug)]
+enum PathSegment {
+    Index(usize),
+    Key(String),
+}
+
+impl ConvertPath {
+    #[cfg(feature = "conversion-paths")]
+    fn push(&mut self, make_segment: impl FnOnce() -> PathSegment) {
+        self.segments.push(make_segment());
+    }
+
+    #[cfg(not(feature = "conversion-paths"))]
+    fn push(&mut self, _make_segment: impl FnOnce() -> PathSegment) {}
+
+    #[cfg(feature = "conversion-paths")]
+    fn to_path_string(&self) -> Option<String> {
+        use std::fmt::Write as _;
+
+        if self.segments.is_empty() {
+            return None;
+        }
+
+        let mut path = String::new();
+        for segment in self.segments.iter().rev() {
+            match segment {
+                PathSegment::Index(index) => write!(path, "[{index}]"),
+                PathSegment::Key(key) if path.is_empty() => write!(path, "{key}"),
+                PathSegment::Key(key) => write!(path, ".{key}"),
+            }
+            .expect("writing to String cannot fail");
+        }
+
+        Some(path)
+    }
+
+    #[cfg(not(feature = "conversion-paths"))]
+    fn to_path_string(&self) -> Option<String> {
+        None
+    }
+}
+
 #[derive(Debug)]
 pub(crate) enum ErrorKind {
     FromGodot(FromGodotError),
@@ -173,6 +274,11 @@ pub(crate) enum FromGodotError {
         expected: ArrayTypeInfo,
         actual: ArrayTypeInfo,
     },
+    /// Conversion to a fixed-size array `[T; N]`.
+    BadArrayLength {
+        expected: usize,
+        actual: usize,
+    },
     /// InvalidEnum is also used by bitfields.
     InvalidEnum,
     ZeroInstanceId,
@@ -221,6 +327,12 @@ impl fmt::Display for FromGodotError {
                     actual.class_name()
                 )
             }
+            Self::BadArrayLength { expected, actual } => {
+                write!(
+                    f,
+                    "expected array of length {expected}, got length {actual}"
+                )
+            }
             Self::InvalidEnum => write!(f, "invalid engine enum value"),
             Self::ZeroInstanceId => write!(f, "`InstanceId` cannot be 0"),
         }
@@ -0,0 +1,64 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::meta::error::ConvertError;
+use crate::obj::InstanceId;
+
+/// Error when looking up an object by its [`InstanceId`], see [`Gd::try_from_instance_id()`][crate::obj::Gd::try_from_instance_id].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum InstanceIdError {
+    /// No live object has this ID. Either it has been freed, or the ID never belonged to an object.
+    Dead { id: InstanceId },
+
+    /// The object is alive, but its class does not inherit the requested one.
+    WrongType {
+        id: InstanceId,
+        expected: String,
+        actual: String,
+    },
+}
+
+impl InstanceIdError {
+    /// The instance ID that was looked up.
+    pub fn instance_id(&self) -> InstanceId {
+        match self {
+            Self::Dead { id } | Self::WrongType { id, .. } => *id,
+        }
+    }
+
+    /// Returns `true` if no object with this ID is alive.
+    pub fn is_dead(&self) -> bool {
+        matches!(self, Self::Dead { .. })
+    }
+}
+
+impl fmt::Display for InstanceIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Dead { id } => write!(f, "instance ID {id} does not refer to a live object"),
+            Self::WrongType {
+                id,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "instance ID {id} refers to an object of class `{actual}`, which does not inherit `{expected}`"
+            ),
+        }
+    }
+}
+
+impl Error for InstanceIdError {}
+
+impl From<InstanceIdError> for ConvertError {
+    fn from(error: InstanceIdError) -> Self {
+        ConvertError::with_error(error)
+    }
+}
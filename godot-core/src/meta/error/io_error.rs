@@ -70,6 +70,16 @@ impl IoError {
         }
     }
 
+    pub(crate) fn loading_scene_root(expected: String, actual: String, path: String) -> Self {
+        Self {
+            data: ErrorData::Load(LoaderError {
+                kind: LoaderErrorKind::SceneRoot { actual },
+                class: expected,
+                path,
+            }),
+        }
+    }
+
     pub(crate) fn check_unique_open_file_access(
         file_access: Gd<FileAccess>,
     ) -> Result<Gd<FileAccess>, Self> {
@@ -118,6 +128,7 @@ struct LoaderError {
 enum LoaderErrorKind {
     Load,
     Cast,
+    SceneRoot { actual: String },
 }
 
 impl Error for LoaderError {}
@@ -136,6 +147,10 @@ impl fmt::Display for LoaderError {
                 f,
                 "can't cast loaded resource to class: '{class}' from path: '{path}'"
             ),
+            LoaderErrorKind::SceneRoot { actual } => write!(
+                f,
+                "scene root has class: '{actual}', but expected class: '{class}' (or derived) from path: '{path}'"
+            ),
         }
     }
 }
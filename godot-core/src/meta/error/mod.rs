@@ -7,10 +7,20 @@
 
 //! Errors in the gdext library.
 
+mod bind_error;
 mod call_error;
+mod callable_error;
 mod convert_error;
+mod instance_id_error;
 mod io_error;
+mod node_lookup_error;
+mod script_error;
 
+pub use bind_error::*;
 pub use call_error::*;
+pub use callable_error::*;
 pub use convert_error::*;
+pub use instance_id_error::*;
 pub use io_error::*;
+pub use node_lookup_error::*;
+pub use script_error::*;
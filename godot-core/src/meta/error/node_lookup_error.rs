@@ -0,0 +1,91 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::NodePath;
+
+/// Error when looking up a single node by path, see [`Node::resolve_nodes_as()`][crate::classes::Node::resolve_nodes_as].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum NodeLookupError {
+    /// The path is empty, e.g. an element of an exported array that was never assigned in the inspector.
+    Empty,
+
+    /// There is no node at the path.
+    NotFound { path: NodePath },
+
+    /// The node exists, but its class does not inherit the requested one.
+    WrongType {
+        path: NodePath,
+        expected: String,
+        actual: String,
+    },
+}
+
+impl fmt::Display for NodeLookupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "node path is empty"),
+            Self::NotFound { path } => write!(f, "no node found at path `{path}`"),
+            Self::WrongType {
+                path,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "node at path `{path}` has class `{actual}`, which does not inherit `{expected}`"
+            ),
+        }
+    }
+}
+
+impl Error for NodeLookupError {}
+
+/// Error when resolving multiple node paths at once, containing every path that failed.
+///
+/// Returned by [`Node::resolve_nodes_as()`][crate::classes::Node::resolve_nodes_as].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ResolveNodesError {
+    total: usize,
+    failures: Vec<(usize, NodeLookupError)>,
+}
+
+impl ResolveNodesError {
+    pub(crate) fn new(total: usize, failures: Vec<(usize, NodeLookupError)>) -> Self {
+        Self { total, failures }
+    }
+
+    /// The failed lookups, as pairs of (index in the input, error). Ordered by index.
+    pub fn failures(&self) -> &[(usize, NodeLookupError)] {
+        &self.failures
+    }
+
+    /// Number of paths that were looked up, including the successful ones.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+}
+
+impl fmt::Display for ResolveNodesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} of {} node paths could not be resolved:",
+            self.failures.len(),
+            self.total
+        )?;
+
+        for (index, error) in &self.failures {
+            write!(f, "\n  [{index}] {error}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ResolveNodesError {}
@@ -0,0 +1,64 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::meta::error::{CallError, ConvertError};
+
+/// Error when accessing a script attached to an object through a [`ScriptHandle`][crate::obj::ScriptHandle].
+#[derive(Debug)]
+pub enum ScriptError {
+    /// The script declares no method with this name.
+    MethodNotFound { script: String, method: String },
+
+    /// The script declares no property with this name.
+    PropertyNotFound { script: String, property: String },
+
+    /// The method exists, but calling it failed (e.g. wrong number or types of arguments).
+    Call(CallError),
+
+    /// The returned value or property could not be converted to the requested Rust type.
+    Convert(ConvertError),
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MethodNotFound { script, method } => {
+                write!(f, "method `{method}` not found in script {script}")
+            }
+            Self::PropertyNotFound { script, property } => {
+                write!(f, "property `{property}` not found in script {script}")
+            }
+            Self::Call(err) => write!(f, "script call failed: {err}"),
+            Self::Convert(err) => write!(f, "script value has unexpected type: {err}"),
+        }
+    }
+}
+
+impl Error for ScriptError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Call(err) => Some(err),
+            Self::Convert(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<CallError> for ScriptError {
+    fn from(err: CallError) -> Self {
+        Self::Call(err)
+    }
+}
+
+impl From<ConvertError> for ScriptError {
+    fn from(err: ConvertError) -> Self {
+        Self::Convert(err)
+    }
+}
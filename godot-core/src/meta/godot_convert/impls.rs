@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::builtin::Variant;
+use crate::builtin::{Array, PackedByteArray, Variant};
 use crate::meta::error::{ConvertError, FromFfiError, FromVariantError};
 use crate::meta::{
     ArrayElement, ClassName, FromGodot, GodotConvert, GodotNullableFfi, GodotType, PropertyInfo,
@@ -143,11 +143,33 @@ where
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Fixed-size arrays
+
+// Passed to Godot as typed `Array<T>`. The length is part of the Rust type, so #[func] signatures document their arity, and mismatching
+// arrays coming from GDScript are rejected during conversion. Packed arrays convert via `TryFrom<&PackedArray>` instead.
+
+impl<T: ArrayElement, const N: usize> GodotConvert for [T; N] {
+    type Via = Array<T>;
+}
+
+impl<T: ArrayElement + ToGodot, const N: usize> ToGodot for [T; N] {
+    fn to_godot(&self) -> Self::Via {
+        Array::from(self)
+    }
+}
+
+impl<T: ArrayElement + FromGodot, const N: usize> FromGodot for [T; N] {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Self::try_from(&via)
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Scalars
 
 macro_rules! impl_godot_scalar {
-    ($T:ty as $Via:ty, $err:path, $param_metadata:expr) => {
+    ($T:ty as $Via:ty, $err:path, $param_metadata:expr $(; vec = $VecVia:ty)?) => {
         impl GodotType for $T {
             type Ffi = $Via;
 
@@ -170,7 +192,7 @@ macro_rules! impl_godot_scalar {
             impl_godot_scalar!(@shared_fns; $Via, $param_metadata);
         }
 
-        impl_godot_scalar!(@shared_traits; $T);
+        impl_godot_scalar!(@shared_traits; $T $(, $VecVia)?);
     };
 
     ($T:ty as $Via:ty, $param_metadata:expr; lossy) => {
@@ -186,6 +208,9 @@ macro_rules! impl_godot_scalar {
             }
 
             fn try_from_ffi(ffi: Self::Ffi) -> Result<Self, ConvertError> {
+                #[cfg(feature = "conversion-audit")]
+                crate::meta::audit::check_f64_to_f32(ffi);
+
                 Ok(ffi as $T)
             }
 
@@ -205,8 +230,18 @@ macro_rules! impl_godot_scalar {
         }
     };
 
-    (@shared_traits; $T:ty) => {
-        impl ArrayElement for $T {}
+    (@vec_via; $T:ty) => {
+        Array<$T>
+    };
+
+    (@vec_via; $T:ty, $VecVia:ty) => {
+        $VecVia
+    };
+
+    (@shared_traits; $T:ty $(, $VecVia:ty)?) => {
+        impl ArrayElement for $T {
+            type __VecVia = impl_godot_scalar!(@vec_via; $T $(, $VecVia)?);
+        }
 
         impl GodotConvert for $T {
             type Via = $T;
@@ -241,7 +276,8 @@ impl_godot_scalar!(
 impl_godot_scalar!(
     u8 as i64,
     FromFfiError::U8,
-    sys::GDEXTENSION_METHOD_ARGUMENT_METADATA_INT_IS_UINT8
+    sys::GDEXTENSION_METHOD_ARGUMENT_METADATA_INT_IS_UINT8;
+    vec = PackedByteArray
 );
 impl_godot_scalar!(
     i16 as i64,
@@ -276,11 +312,14 @@ impl GodotType for u64 {
     type Ffi = i64;
 
     fn to_ffi(&self) -> Self::Ffi {
+        #[cfg(feature = "conversion-audit")]
+        crate::meta::audit::check_u64_to_i64(*self);
+
         *self as i64
     }
 
     fn into_ffi(self) -> Self::Ffi {
-        self as i64
+        self.to_ffi()
     }
 
     fn try_from_ffi(ffi: Self::Ffi) -> Result<Self, ConvertError> {
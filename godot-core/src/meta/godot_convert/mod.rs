@@ -10,7 +10,7 @@ mod impls;
 use crate::builtin::Variant;
 use crate::meta::error::ConvertError;
 use crate::meta::traits::GodotFfiVariant;
-use crate::meta::GodotType;
+use crate::meta::{CallContext, GodotType};
 
 /// Indicates that a type can be passed to/from Godot, either directly or through an intermediate "via" type.
 ///
@@ -91,8 +91,17 @@ pub trait FromGodot: Sized + GodotConvert {
     }
 }
 
-pub(crate) fn into_ffi<T: ToGodot>(value: T) -> <T::Via as GodotType>::Ffi {
-    value.into_godot().into_ffi()
+/// Converts a value passed from Rust to Godot; with the `conversion-audit` feature, non-finite floats are reported.
+pub(crate) fn into_ffi<T: ToGodot>(
+    value: T,
+    _call_ctx: &CallContext,
+) -> <T::Via as GodotType>::Ffi {
+    let via = value.into_godot();
+
+    #[cfg(feature = "conversion-audit")]
+    crate::meta::audit::check_outbound(&via, _call_ctx);
+
+    via.into_ffi()
 }
 
 pub(crate) fn try_from_ffi<T: FromGodot>(
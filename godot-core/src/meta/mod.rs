@@ -14,11 +14,17 @@ mod sealed;
 mod signature;
 mod traits;
 
+pub mod class_db;
 pub mod error;
+
+#[cfg(feature = "conversion-audit")]
+pub mod audit;
 pub use class_name::ClassName;
+
+pub(crate) use class_name::set_class_name_prefix;
 pub use godot_convert::{FromGodot, GodotConvert, ToGodot};
 use sys::conv::u32_to_usize;
-pub use traits::{ArrayElement, GodotType};
+pub use traits::{ArrayElement, GodotType, VecRepresentation};
 
 pub(crate) use crate::impl_godot_as_self;
 pub(crate) use array_type_info::ArrayTypeInfo;
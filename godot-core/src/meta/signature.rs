@@ -39,6 +39,7 @@ pub trait VarcallSignatureTuple: PtrcallSignatureTuple {
         arg_count: i64,
         ret: sys::GDExtensionVariantPtr,
         err: *mut sys::GDExtensionCallError,
+        default_args: fn() -> Vec<Variant>,
         func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
     ) -> Result<(), CallError>;
 
@@ -184,10 +185,23 @@ macro_rules! impl_varcall_signature_for_tuple {
                 arg_count: i64,
                 ret: sys::GDExtensionVariantPtr,
                 err: *mut sys::GDExtensionCallError,
+                default_args: fn() -> Vec<Variant>,
                 func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
             ) -> Result<(), CallError> {
                 //$crate::out!("in_varcall: {call_ctx}");
-                CallError::check_arg_count(call_ctx, arg_count as usize, $PARAM_COUNT)?;
+
+                // Default values are only evaluated if the caller omitted arguments.
+                let defaults;
+                let args_with_defaults;
+                let args_ptr = if arg_count as usize == $PARAM_COUNT {
+                    args_ptr
+                } else {
+                    defaults = default_args();
+                    CallError::check_arg_count(call_ctx, arg_count as usize, defaults.len(), $PARAM_COUNT)?;
+
+                    args_with_defaults = unsafe { fill_default_args(args_ptr, arg_count as usize, &defaults, $PARAM_COUNT) };
+                    args_with_defaults.as_ptr()
+                };
 
                 #[cfg(feature = "trace")]
                 trace::push(true, false, &call_ctx);
@@ -197,6 +211,10 @@ macro_rules! impl_varcall_signature_for_tuple {
                 )*) ;
 
                 let rust_result = func(instance_ptr, args);
+
+                #[cfg(feature = "conversion-audit")]
+                crate::meta::audit::check_outbound(&rust_result.to_godot(), call_ctx);
+
                 varcall_return::<$R>(rust_result, ret, err);
                 Ok(())
             }
@@ -220,11 +238,14 @@ macro_rules! impl_varcall_signature_for_tuple {
                     crate::classes::ensure_object_alive(instance_id, object_ptr, &call_ctx);
                 }
 
+                #[cfg(all(debug_assertions, feature = "experimental-threads", since_api = "4.2"))]
+                crate::task::debug_check_scene_access(maybe_instance_id, &call_ctx);
+
                 let class_fn = sys::interface_fn!(object_method_bind_call);
 
                 let explicit_args = [
                     $(
-                        GodotFfiVariant::ffi_to_variant(&into_ffi($pn)),
+                        GodotFfiVariant::ffi_to_variant(&into_ffi($pn, &call_ctx)),
                     )*
                 ];
 
@@ -269,7 +290,7 @@ macro_rules! impl_varcall_signature_for_tuple {
                 let object_call_script_method = sys::interface_fn!(object_call_script_method);
                 let explicit_args = [
                     $(
-                        GodotFfiVariant::ffi_to_variant(&into_ffi($pn)),
+                        GodotFfiVariant::ffi_to_variant(&into_ffi($pn, &call_ctx)),
                     )*
                 ];
 
@@ -304,7 +325,7 @@ macro_rules! impl_varcall_signature_for_tuple {
 
                 let explicit_args: [Variant; $PARAM_COUNT] = [
                     $(
-                        GodotFfiVariant::ffi_to_variant(&into_ffi($pn)),
+                        GodotFfiVariant::ffi_to_variant(&into_ffi($pn, &call_ctx)),
                     )*
                 ];
 
@@ -386,12 +407,15 @@ macro_rules! impl_ptrcall_signature_for_tuple {
                     crate::classes::ensure_object_alive(instance_id, object_ptr, &call_ctx);
                 }
 
+                #[cfg(all(debug_assertions, feature = "experimental-threads", since_api = "4.2"))]
+                crate::task::debug_check_scene_access(maybe_instance_id, &call_ctx);
+
                 let class_fn = sys::interface_fn!(object_method_bind_ptrcall);
 
                 #[allow(clippy::let_unit_value)]
                 let marshalled_args = (
                     $(
-                        into_ffi($pn),
+                        into_ffi($pn, &call_ctx),
                     )*
                 );
 
@@ -422,7 +446,7 @@ macro_rules! impl_ptrcall_signature_for_tuple {
                 #[allow(clippy::let_unit_value)]
                 let marshalled_args = (
                     $(
-                        into_ffi($pn),
+                        into_ffi($pn, &call_ctx),
                     )*
                 );
 
@@ -450,7 +474,7 @@ macro_rules! impl_ptrcall_signature_for_tuple {
                 #[allow(clippy::let_unit_value)]
                 let marshalled_args = (
                     $(
-                        into_ffi($pn),
+                        into_ffi($pn, &call_ctx),
                     )*
                 );
 
@@ -469,6 +493,30 @@ macro_rules! impl_ptrcall_signature_for_tuple {
     };
 }
 
+/// Returns pointers to the `arg_count` arguments of `args_ptr`, followed by the defaults of the omitted trailing parameters.
+///
+/// # Safety
+/// - It must be safe to dereference the pointers at `args_ptr.offset(0..arg_count)`.
+/// - `arg_count` must lie in `param_count - default_args.len() ..= param_count`.
+unsafe fn fill_default_args(
+    args_ptr: *const sys::GDExtensionConstVariantPtr,
+    arg_count: usize,
+    default_args: &[Variant],
+    param_count: usize,
+) -> Vec<sys::GDExtensionConstVariantPtr> {
+    let omitted_count = param_count - arg_count;
+    let omitted_defaults = &default_args[default_args.len() - omitted_count..];
+
+    let mut args = Vec::with_capacity(param_count);
+    args.extend((0..arg_count).map(|i| unsafe { *args_ptr.add(i) }));
+    args.extend(
+        omitted_defaults
+            .iter()
+            .map(|arg| arg.var_sys().cast_const()),
+    );
+    args
+}
+
 /// Convert the `N`th argument of `args_ptr` into a value of type `P`.
 ///
 /// # Safety
@@ -544,10 +592,10 @@ unsafe fn ptrcall_arg<P: FromGodot, const N: isize>(
 unsafe fn ptrcall_return<R: ToGodot>(
     ret_val: R,
     ret: sys::GDExtensionTypePtr,
-    _call_ctx: &CallContext,
+    call_ctx: &CallContext,
     call_type: sys::PtrcallType,
 ) {
-    let val = into_ffi(ret_val);
+    let val = into_ffi(ret_val, call_ctx);
     val.move_return_ptr(ret, call_type);
 }
 
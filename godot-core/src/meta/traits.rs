@@ -12,6 +12,7 @@ use crate::global::{PropertyHint, PropertyUsageFlags};
 use crate::meta::error::ConvertError;
 use crate::meta::{sealed, ClassName, FromGodot, GodotConvert, PropertyInfo, ToGodot};
 use crate::registry::method::MethodParamOrReturnInfo;
+use crate::registry::property::Var;
 
 // Re-export sys traits in this module, so all are in one place.
 pub use sys::{GodotFfi, GodotNullableFfi};
@@ -120,4 +121,19 @@ pub trait GodotType:
     label = "does not implement `Var`",
     note = "see also: https://godot-rust.github.io/docs/gdext/master/godot/builtin/meta/trait.ArrayElement.html"
 )]
-pub trait ArrayElement: GodotType {}
+pub trait ArrayElement: GodotType {
+    /// Godot type that `Vec<Self>` converts to: `Array<Self>`, except for `u8` which maps to `PackedByteArray`.
+    #[doc(hidden)]
+    type __VecVia: VecRepresentation<Self>;
+}
+
+/// Godot collection that represents a `Vec<T>`, see [`ArrayElement::__VecVia`].
+#[doc(hidden)]
+pub trait VecRepresentation<T>: GodotType + Var {
+    fn from_elements(elements: &[T]) -> Self;
+
+    fn try_into_elements(self) -> Result<Vec<T>, ConvertError>;
+
+    /// Converts a variant to a `Vec<T>`, accepting all collections whose elements are convertible to `T`.
+    fn try_elements_from_variant(variant: &Variant) -> Result<Vec<T>, ConvertError>;
+}
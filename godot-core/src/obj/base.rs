@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::obj::{Gd, GodotClass};
+use crate::obj::{Gd, GodotClass, InstanceId};
 use crate::{classes, sys};
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 use std::mem::ManuallyDrop;
@@ -97,6 +97,10 @@ impl<T: GodotClass> Base<T> {
         (*self.obj).clone()
     }
 
+    pub(crate) fn instance_id(&self) -> InstanceId {
+        self.obj.instance_id()
+    }
+
     // Currently only used in outbound virtual calls (for scripts); search for: base_field(self).obj_sys().
     #[doc(hidden)]
     pub fn obj_sys(&self) -> sys::GDExtensionObjectPtr {
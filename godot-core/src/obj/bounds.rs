@@ -47,6 +47,14 @@
 //!
 // Note that depending on if you want to exclude `Object`, you should use `DynMemory` instead of `Memory`.
 
+use std::error::Error;
+
+#[cfg(not(feature = "experimental-threads"))]
+use godot_cell::panicking::{GdCell, GdCellInline, InaccessibleGuard, MutGuard, RefGuard};
+
+#[cfg(feature = "experimental-threads")]
+use godot_cell::blocking::{GdCell, InaccessibleGuard, MutGuard, RefGuard};
+
 use crate::obj::cap::GodotDefault;
 use crate::obj::{Bounds, Gd, GodotClass, RawGd};
 use crate::registry::callbacks;
@@ -58,7 +66,7 @@ use private::Sealed;
 // Sealed trait
 
 pub(super) mod private {
-    use super::{Declarer, DynMemory, Memory};
+    use super::{Declarer, DynMemory, Layout, Memory};
 
     // Bounds trait declared here for code locality; re-exported in crate::obj.
 
@@ -97,6 +105,10 @@ pub(super) mod private {
         /// Whether this class is a core Godot class provided by the engine, or declared by the user as a Rust struct.
         // TODO what about GDScript user classes?
         type Declarer: Declarer;
+
+        /// Whether the user instance is stored in its own allocation, or inline with the instance storage.
+        #[doc(hidden)]
+        type Layout: Layout;
     }
 
     /// Implements [`Bounds`] for a user-defined class.
@@ -130,6 +142,7 @@ pub(super) mod private {
                 type Memory = <<$UserClass as $crate::obj::GodotClass>::Base as $crate::obj::Bounds>::Memory;
                 type DynMemory = <<$UserClass as $crate::obj::GodotClass>::Base as $crate::obj::Bounds>::DynMemory;
                 type Declarer = $crate::obj::bounds::DeclUser;
+                type Layout = $crate::obj::bounds::LayoutBoxed;
             }
         };
     }
@@ -408,3 +421,114 @@ impl Declarer for DeclUser {
         }
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Layout bounds
+
+/// Specifies how the user instance of a class is stored.
+///
+/// Only relevant for user classes; engine classes have no user instance.
+#[doc(hidden)]
+pub trait Layout: Sealed {
+    #[doc(hidden)]
+    type Cell<T>: UserCell<T>;
+}
+
+/// User instance in a separate heap allocation, referenced by the instance storage. This is the default.
+#[doc(hidden)]
+pub enum LayoutBoxed {}
+impl Sealed for LayoutBoxed {}
+impl Layout for LayoutBoxed {
+    type Cell<T> = GdCell<T>;
+}
+
+/// User instance stored directly inside the instance storage, selected with `#[class(inline_storage)]`.
+///
+/// Saves one allocation and pointer indirection per object, at the cost of a larger instance storage. Meant for small classes.
+///
+/// With the `experimental-threads` feature, the thread-aware cell has no inline variant, so this behaves like [`LayoutBoxed`].
+#[doc(hidden)]
+pub enum LayoutInline {}
+impl Sealed for LayoutInline {}
+impl Layout for LayoutInline {
+    #[cfg(not(feature = "experimental-threads"))]
+    type Cell<T> = GdCellInline<T>;
+
+    #[cfg(feature = "experimental-threads")]
+    type Cell<T> = GdCell<T>;
+}
+
+/// Cell holding the user instance, abstracting over [`Layout`] choices.
+#[doc(hidden)]
+pub trait UserCell<T> {
+    /// # Safety
+    /// The cell must not be moved after its first borrow.
+    #[doc(hidden)]
+    unsafe fn new(value: T) -> Self;
+
+    #[doc(hidden)]
+    fn borrow(&self) -> Result<RefGuard<'_, T>, Box<dyn Error>>;
+
+    #[doc(hidden)]
+    fn borrow_mut(&self) -> Result<MutGuard<'_, T>, Box<dyn Error>>;
+
+    #[doc(hidden)]
+    fn make_inaccessible<'cell: 'val, 'val>(
+        &'cell self,
+        original_ref: &'val mut T,
+    ) -> Result<InaccessibleGuard<'val, T>, Box<dyn Error>>;
+
+    #[doc(hidden)]
+    fn is_currently_bound(&self) -> bool;
+}
+
+impl<T> UserCell<T> for GdCell<T> {
+    unsafe fn new(value: T) -> Self {
+        GdCell::new(value)
+    }
+
+    fn borrow(&self) -> Result<RefGuard<'_, T>, Box<dyn Error>> {
+        GdCell::borrow(self)
+    }
+
+    fn borrow_mut(&self) -> Result<MutGuard<'_, T>, Box<dyn Error>> {
+        GdCell::borrow_mut(self)
+    }
+
+    fn make_inaccessible<'cell: 'val, 'val>(
+        &'cell self,
+        original_ref: &'val mut T,
+    ) -> Result<InaccessibleGuard<'val, T>, Box<dyn Error>> {
+        GdCell::make_inaccessible(self, original_ref)
+    }
+
+    fn is_currently_bound(&self) -> bool {
+        GdCell::is_currently_bound(self)
+    }
+}
+
+#[cfg(not(feature = "experimental-threads"))]
+impl<T> UserCell<T> for GdCellInline<T> {
+    unsafe fn new(value: T) -> Self {
+        GdCellInline::new(value)
+    }
+
+    fn borrow(&self) -> Result<RefGuard<'_, T>, Box<dyn Error>> {
+        GdCellInline::borrow(self)
+    }
+
+    fn borrow_mut(&self) -> Result<MutGuard<'_, T>, Box<dyn Error>> {
+        GdCellInline::borrow_mut(self)
+    }
+
+    fn make_inaccessible<'cell: 'val, 'val>(
+        &'cell self,
+        original_ref: &'val mut T,
+    ) -> Result<InaccessibleGuard<'val, T>, Box<dyn Error>> {
+        GdCellInline::make_inaccessible(self, original_ref)
+    }
+
+    fn is_currently_bound(&self) -> bool {
+        GdCellInline::is_currently_bound(self)
+    }
+}
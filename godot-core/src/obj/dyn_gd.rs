@@ -0,0 +1,533 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+
+use crate::builtin::GString;
+use crate::classes::{ClassDb, Node, Object, Resource};
+use crate::global::{godot_warn, PropertyHint};
+use crate::meta::error::ConvertError;
+use crate::meta::{ClassName, FromGodot, GodotConvert, PropertyHintInfo, ToGodot};
+use crate::obj::{bounds, Bounds, Gd, GdMut, GdRef, GodotClass, Inherits, NoBase};
+use crate::registry::plugin::{DynTraitImpl, PluginItem};
+use crate::registry::property::{Export, Var};
+use crate::sys::Global;
+
+/// Implemented for user classes that implement the Rust trait `D` (a `dyn Trait` type), to access them as trait objects.
+///
+/// Do not implement this manually; use [`#[godot_dyn]`](../register/attr.godot_dyn.html) on the `impl Trait for Class` block instead,
+/// which also registers the class, so that [`DynGd`] can be created from objects whose concrete class is only known at runtime.
+pub trait AsDyn<D: ?Sized>: GodotClass {
+    fn dyn_upcast(&self) -> &D;
+    fn dyn_upcast_mut(&mut self) -> &mut D;
+}
+
+/// Smart pointer to an object of class `T` (or derived), whose Rust instance implements the trait object `D`.
+///
+/// This allows working with objects through a Rust trait, without knowing their concrete class. A typical use case is an exported
+/// field, which the inspector shows as a picker for all classes implementing the trait:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::obj::DynGd;
+///
+/// trait Behavior {
+///     fn next_action(&mut self) -> GString;
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Resource)]
+/// struct Aggressive {}
+///
+/// #[godot_dyn]
+/// impl Behavior for Aggressive {
+///     fn next_action(&mut self) -> GString {
+///         "attack".into()
+///     }
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Enemy {
+///     // Inspector only offers resource classes which implement `Behavior`.
+///     #[export]
+///     brain: Option<DynGd<Resource, dyn Behavior>>,
+/// }
+///
+/// fn think(enemy: &mut Enemy) {
+///     if let Some(brain) = enemy.brain.as_mut() {
+///         let action = brain.dyn_bind_mut().next_action();
+///     }
+/// }
+/// ```
+///
+/// `DynGd` dereferences to [`Gd<T>`], so engine methods of `T` are directly available. Use [`dyn_bind()`][Self::dyn_bind] and
+/// [`dyn_bind_mut()`][Self::dyn_bind_mut] to access the trait; the same borrow rules as for [`Gd::bind()`] apply.
+///
+/// # Conversions
+/// - Statically, from a class implementing the trait: [`Gd::into_dyn()`].
+/// - Dynamically, checking the class at runtime: [`DynGd::try_from_gd()`], or `FromGodot` (e.g. for `#[func]` parameters or variants).
+///
+/// When the inspector or GDScript assigns an object that doesn't implement the trait to an exported `DynGd` field, the assignment is
+/// rejected with a warning and the field keeps its previous value.
+pub struct DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    obj: Gd<T>,
+    binder: Box<dyn DynBinder<D>>,
+}
+
+impl<T, D> DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    /// Checks whether the class of `obj`, or one of its base classes, implements the trait `D` (registered with `#[godot_dyn]`) and
+    /// wraps it.
+    ///
+    /// On failure, returns an error holding `obj`.
+    pub fn try_from_gd(obj: Gd<T>) -> Result<Self, ConvertError> {
+        let runtime_class = obj.upcast_ref::<Object>().get_class();
+
+        // Walk up the inheritance chain, e.g. for scripts or engine-derived classes extending an implementing class. The registry lock
+        // is only held while comparing names; the binder is created after releasing it.
+        let mut class = runtime_class.clone();
+        let glue = loop {
+            let class_str = class.to_string();
+            if class_str.is_empty() {
+                break None;
+            }
+
+            let found = with_dyn_impls::<D, _>(|impls| {
+                impls
+                    .iter()
+                    .find(|(class_name, _)| class_name.as_str() == class_str)
+                    .map(|(_, glue)| glue.clone())
+            });
+            if found.is_some() {
+                break found;
+            }
+
+            class = ClassDb::singleton().get_parent_class(class);
+        };
+
+        let binder = glue.map(|glue| glue.make_binder(obj.clone().upcast()));
+
+        match binder {
+            Some(erased) => {
+                let binder = erased
+                    .downcast::<Box<dyn DynBinder<D>>>()
+                    .expect("DynTraitImpl registered with mismatching trait");
+
+                Ok(Self {
+                    obj,
+                    binder: *binder,
+                })
+            }
+            None => Err(ConvertError::with_error_value(
+                NotImplementedError {
+                    class: runtime_class.to_string(),
+                    trait_name: std::any::type_name::<D>(),
+                },
+                obj,
+            )),
+        }
+    }
+
+    /// Shared access to the trait object. Panics (like [`Gd::bind()`]) if the instance is currently bound mutably.
+    pub fn dyn_bind(&self) -> DynGdRef<'_, D> {
+        self.binder.dyn_bind()
+    }
+
+    /// Exclusive access to the trait object. Panics (like [`Gd::bind_mut()`]) if the instance is currently bound.
+    pub fn dyn_bind_mut(&mut self) -> DynGdMut<'_, D> {
+        self.binder.dyn_bind_mut()
+    }
+
+    /// Upcasts the object to a base class `Base`, keeping the trait.
+    pub fn upcast<Base>(self) -> DynGd<Base, D>
+    where
+        Base: GodotClass,
+        T: Inherits<Base>,
+    {
+        DynGd {
+            obj: self.obj.upcast(),
+            binder: self.binder,
+        }
+    }
+
+    /// Returns the underlying object pointer, dropping the trait information.
+    pub fn into_gd(self) -> Gd<T> {
+        self.obj
+    }
+
+    /// Names of all classes which implement `D` (through `#[godot_dyn]`) and inherit from `T`.
+    pub fn implementor_class_names() -> Vec<ClassName> {
+        let base = T::class_name();
+
+        with_dyn_impls::<D, _>(|impls| {
+            impls
+                .iter()
+                .filter(|(_, glue)| glue.inherits(base))
+                .map(|(class_name, _)| *class_name)
+                .collect()
+        })
+    }
+}
+
+impl<T> Gd<T>
+where
+    T: GodotClass + Bounds<Declarer = bounds::DeclUser>,
+{
+    /// Converts into a [`DynGd`], to access the object through the trait object `D`.
+    ///
+    /// Unlike [`DynGd::try_from_gd()`], this is checked at compile time and does not require `#[godot_dyn]` registration.
+    pub fn into_dyn<D>(self) -> DynGd<T, D>
+    where
+        T: AsDyn<D>,
+        D: ?Sized + 'static,
+    {
+        let binder = Box::new(ConcreteBinder { obj: self.clone() });
+        DynGd { obj: self, binder }
+    }
+}
+
+impl<T, D> Deref for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    type Target = Gd<T>;
+
+    fn deref(&self) -> &Gd<T> {
+        &self.obj
+    }
+}
+
+impl<T, D> DerefMut for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn deref_mut(&mut self) -> &mut Gd<T> {
+        &mut self.obj
+    }
+}
+
+impl<T, D> Clone for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            obj: self.obj.clone(),
+            binder: self.binder.clone_boxed(),
+        }
+    }
+}
+
+impl<T, D> PartialEq for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.obj == other.obj
+    }
+}
+
+impl<T, D> fmt::Debug for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::classes::debug_string(&self.obj, f, "DynGd")
+    }
+}
+
+impl<T, D> fmt::Display for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.obj, f)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Conversions and export
+
+impl<T, D> GodotConvert for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    type Via = Gd<T>;
+}
+
+impl<T, D> ToGodot for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn to_godot(&self) -> Self::Via {
+        self.obj.to_godot()
+    }
+
+    fn into_godot(self) -> Self::Via {
+        self.obj.into_godot()
+    }
+}
+
+impl<T, D> FromGodot for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Self::try_from_gd(via)
+    }
+}
+
+impl<T, D> Var for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn get_property(&self) -> Self::Via {
+        self.to_godot()
+    }
+
+    fn set_property(&mut self, value: Self::Via) {
+        // The editor can still assign arbitrary objects of class T, e.g. via drag & drop. Keep the old value in that case.
+        match Self::try_from_gd(value) {
+            Ok(value) => *self = value,
+            Err(err) => godot_warn!("rejected assignment to DynGd property: {err}"),
+        }
+    }
+
+    fn __new_from_property(value: Self::Via) -> Option<Self> {
+        // Same for `Option<DynGd>` properties that are currently `None`.
+        Self::try_from_gd(value)
+            .map_err(|err| godot_warn!("rejected assignment to DynGd property: {err}"))
+            .ok()
+    }
+}
+
+impl<T, D> Export for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn default_export_info() -> PropertyHintInfo {
+        let hint = if T::inherits::<Resource>() {
+            PropertyHint::RESOURCE_TYPE
+        } else if T::inherits::<Node>() {
+            PropertyHint::NODE_TYPE
+        } else {
+            PropertyHint::NONE
+        };
+
+        // Restrict the inspector's picker to implementing classes. Without any, fall back to T, so that the picker is not unconstrained.
+        let implementors = Self::implementor_class_names();
+        let hint_string = if implementors.is_empty() || hint == PropertyHint::NONE {
+            T::class_name().to_gstring()
+        } else {
+            let names: Vec<&str> = implementors.iter().map(ClassName::as_str).collect();
+            GString::from(names.join(","))
+        };
+
+        PropertyHintInfo { hint, hint_string }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Guards
+
+/// Shared borrow of a [`DynGd`]'s trait object, obtained through [`DynGd::dyn_bind()`].
+pub struct DynGdRef<'a, D: ?Sized> {
+    guard: Box<dyn Deref<Target = D> + 'a>,
+}
+
+impl<D: ?Sized> Deref for DynGdRef<'_, D> {
+    type Target = D;
+
+    fn deref(&self) -> &D {
+        &self.guard
+    }
+}
+
+/// Exclusive borrow of a [`DynGd`]'s trait object, obtained through [`DynGd::dyn_bind_mut()`].
+pub struct DynGdMut<'a, D: ?Sized> {
+    guard: Box<dyn DerefMut<Target = D> + 'a>,
+}
+
+impl<D: ?Sized> Deref for DynGdMut<'_, D> {
+    type Target = D;
+
+    fn deref(&self) -> &D {
+        &self.guard
+    }
+}
+
+impl<D: ?Sized> DerefMut for DynGdMut<'_, D> {
+    fn deref_mut(&mut self) -> &mut D {
+        &mut self.guard
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+/// Type-erased access to the concrete class behind a `DynGd`.
+pub(crate) trait DynBinder<D: ?Sized> {
+    fn dyn_bind(&self) -> DynGdRef<'_, D>;
+    fn dyn_bind_mut(&mut self) -> DynGdMut<'_, D>;
+    fn clone_boxed(&self) -> Box<dyn DynBinder<D>>;
+}
+
+struct ConcreteBinder<C: GodotClass> {
+    obj: Gd<C>,
+}
+
+impl<C, D> DynBinder<D> for ConcreteBinder<C>
+where
+    C: AsDyn<D> + Bounds<Declarer = bounds::DeclUser>,
+    D: ?Sized + 'static,
+{
+    fn dyn_bind(&self) -> DynGdRef<'_, D> {
+        DynGdRef {
+            guard: Box::new(UpcastGuard::<_, D>::new(self.obj.bind())),
+        }
+    }
+
+    fn dyn_bind_mut(&mut self) -> DynGdMut<'_, D> {
+        DynGdMut {
+            guard: Box::new(UpcastGuard::<_, D>::new(self.obj.bind_mut())),
+        }
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DynBinder<D>> {
+        Box::new(ConcreteBinder {
+            obj: self.obj.clone(),
+        })
+    }
+}
+
+/// Wraps a `GdRef<C>` or `GdMut<C>`, dereferencing to the trait object `D` instead of `C`.
+struct UpcastGuard<G, D: ?Sized> {
+    guard: G,
+    _trait: PhantomData<fn() -> *const D>,
+}
+
+impl<G, D: ?Sized> UpcastGuard<G, D> {
+    fn new(guard: G) -> Self {
+        Self {
+            guard,
+            _trait: PhantomData,
+        }
+    }
+}
+
+impl<'a, C: AsDyn<D>, D: ?Sized> Deref for UpcastGuard<GdRef<'a, C>, D> {
+    type Target = D;
+
+    fn deref(&self) -> &D {
+        self.guard.dyn_upcast()
+    }
+}
+
+impl<'a, C: AsDyn<D>, D: ?Sized> Deref for UpcastGuard<GdMut<'a, C>, D> {
+    type Target = D;
+
+    fn deref(&self) -> &D {
+        self.guard.dyn_upcast()
+    }
+}
+
+impl<'a, C: AsDyn<D>, D: ?Sized> DerefMut for UpcastGuard<GdMut<'a, C>, D> {
+    fn deref_mut(&mut self) -> &mut D {
+        self.guard.dyn_upcast_mut()
+    }
+}
+
+/// Creates the type-erased binder for `#[godot_dyn]` registrations; the result is a `Box<Box<dyn DynBinder<D>>>`.
+pub(crate) fn make_erased_binder<C, D>(obj: Gd<Object>) -> Box<dyn Any>
+where
+    C: AsDyn<D> + Bounds<Declarer = bounds::DeclUser>,
+    D: ?Sized + 'static,
+{
+    let binder: Box<dyn DynBinder<D>> = Box::new(ConcreteBinder {
+        obj: obj.cast::<C>(),
+    });
+    Box::new(binder)
+}
+
+/// Whether class `C` is or inherits from the class named `base`. Runtime counterpart to [`GodotClass::inherits()`].
+pub(crate) fn class_inherits<C: GodotClass>(base: ClassName) -> bool {
+    if C::class_name() == base {
+        true
+    } else if C::Base::class_name() == <NoBase>::class_name() {
+        false
+    } else {
+        class_inherits::<C::Base>(base)
+    }
+}
+
+/// All `#[godot_dyn]` registrations, grouped by trait. Built on first use; plugins are complete once the library is loaded.
+static DYN_IMPLS: Global<Option<HashMap<TypeId, Vec<(ClassName, DynTraitImpl)>>>> =
+    Global::default();
+
+fn with_dyn_impls<D, R>(f: impl FnOnce(&[(ClassName, DynTraitImpl)]) -> R) -> R
+where
+    D: ?Sized + 'static,
+{
+    let mut guard = DYN_IMPLS.lock();
+    let map = guard.get_or_insert_with(|| {
+        let mut map = HashMap::<TypeId, Vec<_>>::new();
+
+        crate::private::iterate_plugins(|plugin| {
+            if let PluginItem::DynTraitImpl(glue) = &plugin.item {
+                map.entry(glue.dyn_trait_typeid())
+                    .or_default()
+                    .push((plugin.class_name, glue.clone()));
+            }
+        });
+
+        map
+    });
+
+    let impls = map.get(&TypeId::of::<D>()).map_or(&[][..], Vec::as_slice);
+    f(impls)
+}
+
+#[derive(Debug)]
+struct NotImplementedError {
+    class: String,
+    trait_name: &'static str,
+}
+
+impl fmt::Display for NotImplementedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "class `{}` does not implement trait `{}` (missing #[godot_dyn] impl?)",
+            self.class, self.trait_name
+        )
+    }
+}
+
+impl std::error::Error for NotImplementedError {}
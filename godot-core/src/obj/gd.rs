@@ -12,13 +12,14 @@ use godot_ffi as sys;
 
 use sys::{static_assert_eq_size_align, VariantType};
 
-use crate::builtin::{Callable, NodePath, StringName, Variant};
+use crate::builtin::{Array, Callable, NodePath, StringName, Variant};
 use crate::global::PropertyHint;
-use crate::meta::error::{ConvertError, FromFfiError};
+use crate::meta::error::{BindError, ConvertError, FromFfiError, InstanceIdError};
 use crate::meta::{ArrayElement, CallContext, FromGodot, GodotConvert, GodotType, ToGodot};
 use crate::obj::raw::RawGd;
 use crate::obj::{
     bounds, cap, Bounds, EngineEnum, GdDerefTarget, GdMut, GdRef, GodotClass, Inherits, InstanceId,
+    WeakGd,
 };
 use crate::private::callbacks;
 use crate::registry::property::{Export, PropertyHintInfo, TypeStringHint, Var};
@@ -33,6 +34,13 @@ use crate::{classes, out};
 ///
 /// `Gd<T>` never holds null objects. If you need nullability, use `Option<Gd<T>>`.
 ///
+/// # Layout
+///
+/// The object pointer inside `Gd<T>` is never null, which lets the compiler use the null value to represent `None`. As a result,
+/// `Option<Gd<T>>` has the same size and alignment as `Gd<T>`, for every `T`. This is guaranteed and verified at compile time, so you can
+/// rely on it, e.g. for densely packed storage of optional object references. The size of `Gd<T>` itself is not part of that guarantee;
+/// it contains cached type information and differs between Debug and Release builds.
+///
 /// # Memory management
 ///
 /// This smart pointer behaves differently depending on `T`'s associated types, see [`GodotClass`] for their documentation.
@@ -104,6 +112,18 @@ static_assert_eq_size_align!(
     "Godot FFI: pointer type `Object*` should have size advertised in JSON extension file"
 );
 
+// Niche optimization: `None` is represented by a null object pointer, which never occurs in `Gd<T>`. The layout does not depend on `T`.
+static_assert_eq_size_align!(
+    Option<Gd<classes::Object>>,
+    Gd<classes::Object>,
+    "Option<Gd<T>> should have the same layout as Gd<T>"
+);
+static_assert_eq_size_align!(
+    Option<Gd<classes::RefCounted>>,
+    Gd<classes::RefCounted>,
+    "Option<Gd<T>> should have the same layout as Gd<T>"
+);
+
 /// _The methods in this impl block are only available for user-declared `T`, that is,
 /// structs with `#[derive(GodotClass)]` but not Godot classes like `Node` or `RefCounted`._ <br><br>
 impl<T> Gd<T>
@@ -181,22 +201,96 @@ where
     pub fn bind_mut(&mut self) -> GdMut<T> {
         self.raw.bind_mut()
     }
+
+    /// Like [`bind()`][Self::bind], but returns an error instead of panicking if the instance is already bound mutably.
+    pub fn try_bind(&self) -> Result<GdRef<T>, BindError> {
+        if !self.is_instance_valid() {
+            return Err(self.bind_error(false));
+        }
+
+        self.raw.try_bind().ok_or_else(|| self.bind_error(false))
+    }
+
+    /// Like [`bind_mut()`][Self::bind_mut], but returns an error instead of panicking if the instance is already bound.
+    pub fn try_bind_mut(&mut self) -> Result<GdMut<T>, BindError> {
+        let error = self.bind_error(true);
+        if !self.is_instance_valid() {
+            return Err(error);
+        }
+
+        self.raw.try_bind_mut().ok_or(error)
+    }
+
+    /// Binds the instance for the duration of `f`, passing it a shared reference.
+    ///
+    /// Shorthand for `f(&*gd.bind())`, which makes it impossible to accidentally keep the guard alive for too long:
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # #[derive(GodotClass)] #[class(init)] struct Player { health: i32, base: Base<Node> }
+    /// # let player: Gd<Player> = todo!();
+    /// let health = player.with(|p| p.health);
+    /// ```
+    ///
+    /// # Panics
+    /// In the same situations as [`bind()`][Self::bind]. Use [`try_with()`][Self::try_with] to handle these cases.
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.bind())
+    }
+
+    /// Binds the instance exclusively for the duration of `f`, passing it a mutable reference.
+    ///
+    /// # Panics
+    /// In the same situations as [`bind_mut()`][Self::bind_mut]. Use [`try_with_mut()`][Self::try_with_mut] to handle these cases.
+    pub fn with_mut<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> R {
+        f(&mut self.bind_mut())
+    }
+
+    /// Like [`with()`][Self::with], but returns an error instead of panicking if the instance cannot be bound.
+    pub fn try_with<R>(&self, f: impl FnOnce(&T) -> R) -> Result<R, BindError> {
+        self.try_bind().map(|guard| f(&guard))
+    }
+
+    /// Like [`with_mut()`][Self::with_mut], but returns an error instead of panicking if the instance cannot be bound.
+    pub fn try_with_mut<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> Result<R, BindError> {
+        self.try_bind_mut().map(|mut guard| f(&mut guard))
+    }
+
+    fn bind_error(&self, exclusive: bool) -> BindError {
+        let id = if self.is_instance_valid() {
+            self.instance_id_or_none()
+        } else {
+            None
+        };
+
+        BindError::new(T::class_name(), id, exclusive)
+    }
 }
 
 /// _The methods in this impl block are available for any `T`._ <br><br>
 impl<T: GodotClass> Gd<T> {
     /// Looks up the given instance ID and returns the associated object, if possible.
     ///
-    /// If no such instance ID is registered, or if the dynamic type of the object behind that instance ID
-    /// is not compatible with `T`, then `None` is returned.
-    pub fn try_from_instance_id(instance_id: InstanceId) -> Result<Self, ConvertError> {
+    /// Fails if no such instance ID is registered, or if the dynamic type of the object behind that instance ID is not compatible
+    /// with `T`. The [`InstanceIdError`] tells the two cases apart. This never panics, so it is suitable for IDs that may be stale,
+    /// e.g. in ECS-style architectures that store IDs instead of `Gd` pointers.
+    ///
+    /// # Migration
+    /// Previous versions returned [`ConvertError`]. `InstanceIdError` converts into it, so `?` keeps working in functions returning
+    /// `ConvertError`. Code that names the error type explicitly can call `.map_err(ConvertError::from)` to keep the old type.
+    pub fn try_from_instance_id(instance_id: InstanceId) -> Result<Self, InstanceIdError> {
         let ptr = classes::object_ptr_from_id(instance_id);
 
         // SAFETY: assumes that the returned GDExtensionObjectPtr is convertible to Object* (i.e. C++ upcast doesn't modify the pointer)
-        let untyped = unsafe { Gd::<classes::Object>::from_obj_sys_or_none(ptr)? };
+        let untyped = unsafe { Gd::<classes::Object>::from_obj_sys_or_none(ptr) }
+            .map_err(|_| InstanceIdError::Dead { id: instance_id })?;
+
         untyped
             .owned_cast::<T>()
-            .map_err(|obj| FromFfiError::WrongObjectType.into_error(obj))
+            .map_err(|obj| InstanceIdError::WrongType {
+                id: instance_id,
+                expected: T::class_name().to_string(),
+                actual: obj.get_class().to_string(),
+            })
     }
 
     /// ⚠️ Looks up the given instance ID and returns the associated object.
@@ -267,6 +361,13 @@ impl<T: GodotClass> Gd<T> {
         self.raw.is_instance_valid()
     }
 
+    /// Creates a weak reference to this object, which does not keep it alive.
+    ///
+    /// See [`WeakGd`] for details. If this object is already dead, the returned weak reference is empty.
+    pub fn downgrade(&self) -> WeakGd<T> {
+        WeakGd::from_gd(self)
+    }
+
     /// **Upcast:** convert into a smart pointer to a base class. Always succeeds.
     ///
     /// Moves out of this value. If you want to create _another_ smart pointer instance,
@@ -428,6 +529,60 @@ impl<T: GodotClass> Gd<T> {
         Callable::from_object_method(self, method_name)
     }
 
+    /// Returns whether the script attached to this object defines a method named `method_name`.
+    ///
+    /// Methods registered by Rust or declared by engine classes are not considered. This can be used to check whether a `#[func(virtual)]`
+    /// method has been overridden by a script.
+    #[cfg(since_api = "4.3")]
+    pub fn has_script_method(&self, method_name: impl Into<StringName>) -> bool {
+        self.raw.check_rtti("has_script_method");
+        let method_name = method_name.into();
+
+        // SAFETY: object is alive (checked above), method name is valid for the duration of the call.
+        unsafe {
+            crate::private::has_virtual_script_method(self.obj_sys(), method_name.string_sys())
+        }
+    }
+
+    /// Calls a method that an attached script may override, falling back to `default` otherwise.
+    ///
+    /// If the script defines `method_name`, it is called with `args` and its return value is converted to `R`. If there is no script or it
+    /// does not define the method, `default` is invoked instead, typically providing the Rust implementation.
+    ///
+    /// This is the dynamic counterpart to `#[func(virtual)]`. It is useful for optional hooks that are not declared in Rust, such as
+    /// callbacks a mod can implement:
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// fn on_spawned(mut enemy: Gd<Node>, position: Vector2) -> bool {
+    ///     enemy.call_script_virtual("_on_spawned", &[position.to_variant()], || true)
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// If the script's return value cannot be converted to `R`.
+    #[cfg(since_api = "4.3")]
+    pub fn call_script_virtual<R: FromGodot>(
+        &mut self,
+        method_name: impl Into<StringName>,
+        args: &[Variant],
+        default: impl FnOnce() -> R,
+    ) -> R
+    where
+        T: Inherits<classes::Object>,
+    {
+        let method_name = method_name.into();
+        if !self.has_script_method(method_name.clone()) {
+            return default();
+        }
+
+        let mut object = self.clone().upcast::<classes::Object>();
+        let result = object.call(method_name.clone(), args);
+
+        result.try_to::<R>().unwrap_or_else(|err| {
+            panic!("call_script_virtual(): script method `{method_name}` returned incompatible value: {err}")
+        })
+    }
+
     pub(crate) unsafe fn from_obj_sys_or_none(
         ptr: sys::GDExtensionObjectPtr,
     ) -> Result<Self, ConvertError> {
@@ -702,8 +857,13 @@ impl<T: GodotClass> GodotType for Gd<T> {
     }
 }
 
-impl<T: GodotClass> ArrayElement for Gd<T> {}
-impl<T: GodotClass> ArrayElement for Option<Gd<T>> {}
+impl<T: GodotClass> ArrayElement for Gd<T> {
+    type __VecVia = Array<Self>;
+}
+
+impl<T: GodotClass> ArrayElement for Option<Gd<T>> {
+    type __VecVia = Array<Self>;
+}
 
 impl<T> Default for Gd<T>
 where
@@ -0,0 +1,149 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+/// Field holding a dependency that tests can substitute, e.g. with a mock object.
+///
+/// Classes often depend on engine objects that are impractical in tests: a `Gd<HttpRequest>` performing real network requests, a
+/// node that must sit in a specific scene, or a clock. Storing such a dependency in an `Injectable<T>` allows test code to provide a
+/// replacement _before_ the class creates the real one. `T` is typically a `Gd<T>`, a [`DynGd<T, D>`][crate::obj::DynGd] or a
+/// `Box<dyn Trait>`, so that the replacement can be of a different type.
+///
+/// Production code initializes the field with [`init_with()`][Self::init_with], usually in `ready()`. This creates the real
+/// dependency, unless one has been injected before. Afterward, the value is accessed through `Deref`/`DerefMut`.
+///
+/// Annotating the field with `#[inject]` generates a method `inject_<field>(&mut self, value: T)` on the class, so that tests can
+/// swap the dependency without access to private fields. These methods are only generated in debug builds, so release code cannot
+/// come to depend on them. `Injectable<T>` itself is just an `Option<T>`, there is no further bookkeeping.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::HttpRequest;
+/// use godot::obj::Injectable;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Leaderboard {
+///     #[inject]
+///     http: Injectable<Gd<HttpRequest>>,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl INode for Leaderboard {
+///     fn ready(&mut self) {
+///         // Only creates a real HttpRequest if no substitute has been injected.
+///         let http = self.http.init_with(HttpRequest::new_alloc).clone();
+///         self.base_mut().add_child(http);
+///     }
+/// }
+///
+/// // In test code, before adding the node to the tree:
+/// let mut fake_http = HttpRequest::new_alloc();
+/// fake_http.set_timeout(0.1); // Fail fast instead of waiting for the real server.
+///
+/// let mut leaderboard = Leaderboard::new_alloc();
+/// leaderboard.bind_mut().inject_http(fake_http.clone());
+/// assert_eq!(*leaderboard.bind().http, fake_http);
+/// ```
+pub struct Injectable<T> {
+    value: Option<T>,
+}
+
+impl<T> Injectable<T> {
+    /// Creates a field that is already initialized with `value`.
+    ///
+    /// The value can still be replaced with [`inject()`][Self::inject].
+    pub fn new(value: T) -> Self {
+        Self { value: Some(value) }
+    }
+
+    /// Creates an uninitialized field, to be initialized by [`init_with()`][Self::init_with] or [`inject()`][Self::inject].
+    ///
+    /// This is also the [`Default`].
+    pub fn uninit() -> Self {
+        Self { value: None }
+    }
+
+    /// Initializes the field with the result of `init_fn`, unless it already holds a value, e.g. an injected one.
+    ///
+    /// Returns the value of the field in either case.
+    pub fn init_with(&mut self, init_fn: impl FnOnce() -> T) -> &mut T {
+        self.value.get_or_insert_with(init_fn)
+    }
+
+    /// Replaces the value of the field with `value`, returning the previous one, if any.
+    ///
+    /// Meant for test code, which usually calls this through the method generated by `#[inject]`.
+    pub fn inject(&mut self, value: T) -> Option<T> {
+        self.value.replace(value)
+    }
+
+    /// Whether the field holds a value, either from initialization or injection.
+    pub fn is_initialized(&self) -> bool {
+        self.value.is_some()
+    }
+
+    /// Returns the value, or `None` if the field is uninitialized.
+    pub fn get(&self) -> Option<&T> {
+        self.value.as_ref()
+    }
+
+    /// Returns the value mutably, or `None` if the field is uninitialized.
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        self.value.as_mut()
+    }
+
+    /// Removes the value from the field, leaving it uninitialized.
+    pub fn take(&mut self) -> Option<T> {
+        self.value.take()
+    }
+}
+
+impl<T> Default for Injectable<T> {
+    fn default() -> Self {
+        Self::uninit()
+    }
+}
+
+impl<T> Deref for Injectable<T> {
+    type Target = T;
+
+    /// Returns the value.
+    ///
+    /// # Panics
+    /// If the field has neither been initialized nor injected.
+    fn deref(&self) -> &Self::Target {
+        self.value
+            .as_ref()
+            .expect("Injectable<T> accessed before init_with() or inject()")
+    }
+}
+
+impl<T> DerefMut for Injectable<T> {
+    /// Returns the value mutably.
+    ///
+    /// # Panics
+    /// If the field has neither been initialized nor injected.
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.value
+            .as_mut()
+            .expect("Injectable<T> accessed before init_with() or inject()")
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Injectable<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            Some(value) => f.debug_tuple("Injectable").field(value).finish(),
+            None => f.write_str("Injectable(uninit)"),
+        }
+    }
+}
@@ -7,6 +7,7 @@
 
 use crate::meta::error::{ConvertError, FromGodotError};
 use crate::meta::{FromGodot, GodotConvert, ToGodot};
+use crate::registry::property::{Export, PropertyHintInfo, TypeStringHint, Var};
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 use std::num::NonZeroU64;
 
@@ -51,6 +52,15 @@ impl InstanceId {
         self.to_u64() as i64
     }
 
+    /// Returns whether a live object with this ID exists.
+    ///
+    /// Equivalent to GDScript's `is_instance_id_valid()`, but queries the object database directly instead of going through a utility
+    /// function call. To obtain the object, use [`Gd::try_from_instance_id()`][crate::obj::Gd::try_from_instance_id].
+    #[doc(alias = "is_instance_id_valid")]
+    pub fn is_valid(self) -> bool {
+        !crate::classes::object_ptr_from_id(self).is_null()
+    }
+
     /// Returns if the obj being referred-to is inheriting `RefCounted`.
     ///
     /// This is a very fast operation and involves no engine round-trip, as the information is encoded in the ID itself.
@@ -93,3 +103,30 @@ impl FromGodot for InstanceId {
         Self::try_from_i64(via).ok_or_else(|| FromGodotError::ZeroInstanceId.into_error(via))
     }
 }
+
+// Stored as `int` in properties, like in GDScript.
+impl Var for InstanceId {
+    fn get_property(&self) -> Self::Via {
+        self.to_i64()
+    }
+
+    fn set_property(&mut self, value: Self::Via) {
+        // Zero cannot be represented; keep the previous ID.
+        match Self::try_from_i64(value) {
+            Some(id) => *self = id,
+            None => crate::godot_error!("cannot assign 0 to InstanceId property"),
+        }
+    }
+}
+
+impl Export for InstanceId {
+    fn default_export_info() -> PropertyHintInfo {
+        <i64 as Export>::default_export_info()
+    }
+}
+
+impl TypeStringHint for InstanceId {
+    fn type_string() -> String {
+        <i64 as TypeStringHint>::type_string()
+    }
+}
@@ -12,22 +12,36 @@
 //! * [`Gd`], a smart pointer that manages instances of Godot classes.
 
 mod base;
+pub(crate) mod dyn_gd;
 mod gd;
 mod guards;
+mod injectable;
 mod instance_id;
 mod onready;
+mod prop;
 mod raw;
+mod scoped_bind;
+mod script_handle;
+mod state_machine;
 mod traits;
+mod weak_gd;
 
 pub(crate) mod rtti;
 
 pub use base::*;
+pub use dyn_gd::{AsDyn, DynGd, DynGdMut, DynGdRef};
 pub use gd::*;
 pub use guards::{BaseMut, BaseRef, GdMut, GdRef};
+pub use injectable::*;
 pub use instance_id::*;
 pub use onready::*;
+pub use prop::*;
 pub use raw::*;
+pub use scoped_bind::*;
+pub use script_handle::*;
+pub use state_machine::*;
 pub use traits::*;
+pub use weak_gd::*;
 
 pub mod bounds;
 pub mod script;
@@ -36,3 +50,5 @@ pub use bounds::private::Bounds;
 // Do not re-export rtti here.
 
 type GdDerefTarget<T> = <<T as Bounds>::Declarer as bounds::Declarer>::DerefTarget<T>;
+
+pub use crate::bind_scope;
@@ -191,6 +191,15 @@ impl<T> std::ops::DerefMut for OnReady<T> {
     }
 }
 
+impl<T: std::fmt::Debug> std::fmt::Debug for OnReady<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.state {
+            InitState::Initialized { value } => f.debug_tuple("OnReady").field(value).finish(),
+            _ => f.write_str("OnReady(<uninit>)"),
+        }
+    }
+}
+
 impl<T: GodotConvert> GodotConvert for OnReady<T> {
     type Via = T::Via;
 }
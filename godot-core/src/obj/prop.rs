@@ -0,0 +1,436 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::rc::{Rc, Weak};
+
+use crate::builtin::{StringName, Variant};
+use crate::classes::Object;
+use crate::meta::error::ConvertError;
+use crate::meta::{ClassName, FromGodot, GodotConvert, PropertyInfo, ToGodot};
+use crate::obj::{Base, Gd, GodotClass, InstanceId};
+use crate::registry::property::{Export, PropertyHintInfo, TypeStringHint, Var};
+
+/// Observable property, which notifies observers and emits a signal whenever its value changes.
+///
+/// `Prop<T>` is meant to be used as a field in a Rust class. It provides data binding in the style of UI frameworks: instead of writing
+/// custom setters that update dependent state, you can react to changes with [`on_changed()`][Self::on_changed], or keep other
+/// properties in sync with [`bind_to()`][Self::bind_to] and [`bind_two_way()`][Self::bind_two_way].
+///
+/// Observers are only notified if the new value differs from the old one (according to `PartialEq`). This also guarantees that cyclic
+/// bindings terminate.
+///
+/// # Changed signal
+/// For every `Prop<T>` field named `field`, `#[derive(GodotClass)]` registers a signal `field_changed(value: T)`. If the class uses the
+/// generated constructor (`#[class(init)]`) and has a `Base<T>` field, the property is automatically attached to the object, so that
+/// the signal is emitted on every change. With a custom `init()`, call [`attach()`][Self::attach] yourself.
+///
+/// The signal is emitted immediately, while the object is typically still mutably bound (e.g. inside a `&mut self` method or the
+/// property setter). Handlers that access the object, such as `#[func]`s of the same class, should therefore be connected with
+/// [`ConnectFlags::DEFERRED`][crate::classes::object::ConnectFlags::DEFERRED], to avoid a double-borrow panic.
+///
+/// # Properties
+/// `Prop<T>` can be used in `#[var]` and `#[export]` fields, if `T` supports them. Assigning the property from GDScript or the editor
+/// goes through [`set()`][Self::set] and thus notifies observers.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::obj::Prop;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     // Registers signal `health_changed(value: int)`.
+///     #[var]
+///     #[init(default = Prop::new(100))]
+///     health: Prop<i32>,
+///
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl INode for Player {
+///     fn ready(&mut self) {
+///         self.health.on_changed(|health| godot_print!("Health is now {health}"));
+///     }
+/// }
+///
+/// // Keeps a health bar in sync with the player's health.
+/// fn update_health_bar(player: &mut Gd<Player>, bar_value: &Prop<i32>) {
+///     player.bind().health.bind_to(bar_value);
+/// }
+/// ```
+///
+/// # Thread safety
+/// `Prop<T>` shares its state between bindings using reference counting, and is neither `Send` nor `Sync`.
+pub struct Prop<T> {
+    state: Rc<PropState<T>>,
+}
+
+impl<T: PartialEq + Clone + 'static> Prop<T> {
+    /// Creates a new property with the given initial value.
+    pub fn new(value: T) -> Self {
+        Self {
+            state: Rc::new(PropState {
+                value: RefCell::new(value),
+                observers: RefCell::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// Returns a copy of the current value.
+    pub fn get(&self) -> T {
+        self.state.value.borrow().clone()
+    }
+
+    /// Calls `f` with a reference to the current value, without cloning it.
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.state.value.borrow())
+    }
+
+    /// Sets a new value, notifying observers and bound properties if it differs from the current one.
+    ///
+    /// Returns whether the value has changed.
+    pub fn set(&mut self, value: T) -> bool {
+        self.state.update(value)
+    }
+
+    /// Sets a new value without notifying anyone.
+    ///
+    /// Bound properties are not updated and thus run out of sync, until the next [`set()`][Self::set].
+    pub fn set_silently(&mut self, value: T) {
+        *self.state.value.borrow_mut() = value;
+    }
+
+    /// Modifies the value in place, notifying observers if it has changed.
+    ///
+    /// Returns whether the value has changed.
+    pub fn modify(&mut self, f: impl FnOnce(&mut T)) -> bool {
+        let mut value = self.get();
+        f(&mut value);
+
+        self.set(value)
+    }
+
+    /// Registers a callback that is invoked with the new value whenever it changes.
+    ///
+    /// The observer stays registered until [`PropLink::disconnect()`] is called or the property is dropped; discarding the returned link
+    /// does not disconnect it.
+    pub fn on_changed(&self, mut observer: impl FnMut(&T) + 'static) -> PropLink {
+        let id = self.state.add_observer(Box::new(move |value| {
+            observer(value);
+            true
+        }));
+
+        PropLink::new(vec![(self.state_weak(), id)])
+    }
+
+    /// One-way binding: whenever this property changes, `target` is set to the same value.
+    ///
+    /// `target` is immediately updated to the current value.
+    pub fn bind_to(&self, target: &Prop<T>) -> PropLink {
+        self.bind_to_with(target, T::clone)
+    }
+
+    /// One-way binding with conversion: whenever this property changes, `target` is set to `map(value)`.
+    ///
+    /// This allows binding properties of different types, e.g. a numeric value to a label text. `target` is immediately updated.
+    /// Once `target` is dropped, the binding is removed on the next change.
+    pub fn bind_to_with<U, F>(&self, target: &Prop<U>, map: F) -> PropLink
+    where
+        U: PartialEq + Clone + 'static,
+        F: Fn(&T) -> U + 'static,
+    {
+        target.state.update(self.with(&map));
+
+        let weak_target = Rc::downgrade(&target.state);
+        let id = self.state.add_observer(Box::new(move |value| {
+            let Some(target) = weak_target.upgrade() else {
+                return false;
+            };
+
+            target.update(map(value));
+            true
+        }));
+
+        PropLink::new(vec![(self.state_weak(), id)])
+    }
+
+    /// Two-way binding: whenever one of the properties changes, the other one is set to the same value.
+    ///
+    /// `other` is immediately updated to the value of `self`.
+    pub fn bind_two_way(&self, other: &Prop<T>) -> PropLink {
+        let forward = self.bind_to(other);
+        let backward = other.bind_to(self);
+
+        PropLink::new(
+            forward
+                .entries
+                .into_iter()
+                .chain(backward.entries)
+                .collect(),
+        )
+    }
+
+    /// Attaches the property to an object, emitting the signal `signal_name` on it with the new value whenever the value changes.
+    ///
+    /// Only needed in custom `init()` functions; see [changed signal](#changed-signal). The object is referenced by its instance ID and
+    /// is not kept alive; once it is freed, the signal emission is removed on the next change. It can also be stopped earlier by
+    /// disconnecting the returned link.
+    pub fn attach<B>(&mut self, owner: &Base<B>, signal_name: impl Into<StringName>) -> PropLink
+    where
+        B: GodotClass,
+        T: ToGodot,
+    {
+        let owner_id = owner.instance_id();
+        let signal_name = signal_name.into();
+
+        let id = self.state.add_observer(Box::new(move |value: &T| {
+            emit_on_owner(owner_id, &signal_name, &[value.to_variant()])
+        }));
+
+        PropLink::new(vec![(self.state_weak(), id)])
+    }
+
+    /// Returns how many observers and bindings are currently registered.
+    pub fn observer_count(&self) -> usize {
+        self.state.observers.borrow().len()
+    }
+
+    fn state_weak(&self) -> Weak<dyn ObserverList> {
+        let weak: Weak<PropState<T>> = Rc::downgrade(&self.state);
+        weak
+    }
+
+    #[doc(hidden)]
+    pub fn __register_changed_signal(class_name: ClassName, signal_name: &str)
+    where
+        T: Var,
+    {
+        register_signal(
+            class_name,
+            signal_name,
+            &[PropertyInfo::new_var::<T>("value")],
+        );
+    }
+}
+
+impl<T: PartialEq + Clone + Default + 'static> Default for Prop<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Prop<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Prop")
+            .field("value", &*self.state.value.borrow())
+            .field("observers", &self.state.observers.borrow().len())
+            .finish()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Prop<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.state.value.borrow().fmt(f)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Bindings
+
+/// Handle to an observer or binding registered on one or more [`Prop`]s.
+///
+/// Links are not disconnected when dropped, like signal connections in Godot. Keep the handle around if you need to
+/// [`disconnect()`][Self::disconnect] later.
+#[derive(Debug)]
+pub struct PropLink {
+    entries: Vec<(Weak<dyn ObserverList>, ObserverId)>,
+}
+
+impl PropLink {
+    fn new(entries: Vec<(Weak<dyn ObserverList>, ObserverId)>) -> Self {
+        Self { entries }
+    }
+
+    /// Removes the observer or binding. Properties that have been dropped in the meantime are skipped.
+    pub fn disconnect(self) {
+        for (state, id) in self.entries {
+            if let Some(state) = state.upgrade() {
+                state.remove_observer(id);
+            }
+        }
+    }
+
+    /// Returns whether at least one of the linked properties is still alive.
+    pub fn is_connected(&self) -> bool {
+        self.entries
+            .iter()
+            .any(|(state, _)| state.strong_count() > 0)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Conversions and properties
+
+impl<T: GodotConvert> GodotConvert for Prop<T> {
+    type Via = T::Via;
+}
+
+impl<T: ToGodot> ToGodot for Prop<T> {
+    fn to_godot(&self) -> Self::Via {
+        self.state.value.borrow().to_godot()
+    }
+}
+
+impl<T: FromGodot + PartialEq + Clone + 'static> FromGodot for Prop<T> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        T::try_from_godot(via).map(Self::new)
+    }
+}
+
+impl<T: Var + PartialEq + Clone + 'static> Var for Prop<T> {
+    fn get_property(&self) -> Self::Via {
+        self.state.value.borrow().get_property()
+    }
+
+    fn set_property(&mut self, value: Self::Via) {
+        // Apply T's own setter logic on a copy, so that observers are notified through set().
+        let mut new_value = self.get();
+        new_value.set_property(value);
+
+        self.set(new_value);
+    }
+
+    fn property_hint() -> PropertyHintInfo {
+        T::property_hint()
+    }
+}
+
+impl<T: Export + PartialEq + Clone + 'static> Export for Prop<T> {
+    fn default_export_info() -> PropertyHintInfo {
+        T::default_export_info()
+    }
+}
+
+impl<T: TypeStringHint> TypeStringHint for Prop<T> {
+    fn type_string() -> String {
+        T::type_string()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+type ObserverId = u64;
+
+/// Returns whether it wants to be notified of further changes; otherwise it is removed.
+type Observer<T> = Rc<RefCell<Box<dyn FnMut(&T) -> bool>>>;
+
+thread_local! {
+    static NEXT_OBSERVER_ID: Cell<ObserverId> = const { Cell::new(0) };
+}
+
+struct PropState<T> {
+    value: RefCell<T>,
+    observers: RefCell<Vec<(ObserverId, Observer<T>)>>,
+}
+
+impl<T: PartialEq + Clone> PropState<T> {
+    fn update(&self, value: T) -> bool {
+        {
+            let mut current = self.value.borrow_mut();
+            if *current == value {
+                return false;
+            }
+            *current = value;
+        }
+
+        // Iterate over a snapshot, so observers may add or remove observers while being notified.
+        let observers = self.observers.borrow().clone();
+        let value = self.value.borrow().clone();
+
+        for (id, observer) in observers {
+            // An observer that is already running is part of a binding cycle which didn't settle on an equal value; skip it
+            // to avoid infinite recursion.
+            let Ok(mut observer) = observer.try_borrow_mut() else {
+                continue;
+            };
+
+            if !observer(&value) {
+                self.remove_observer(id);
+            }
+        }
+
+        true
+    }
+
+    fn add_observer(&self, observer: Box<dyn FnMut(&T) -> bool>) -> ObserverId {
+        let id = NEXT_OBSERVER_ID.with(|next| {
+            let id = next.get();
+            next.set(id + 1);
+            id
+        });
+
+        self.observers
+            .borrow_mut()
+            .push((id, Rc::new(RefCell::new(observer))));
+
+        id
+    }
+}
+
+/// Type-erased access to the observers of a property, so that links can span properties of different types.
+trait ObserverList {
+    fn remove_observer(&self, id: ObserverId);
+}
+
+impl<T> ObserverList for PropState<T> {
+    fn remove_observer(&self, id: ObserverId) {
+        self.observers.borrow_mut().retain(|(i, _)| *i != id);
+    }
+}
+
+/// Registers a signal of a Rust class, used for the changed signals of [`Prop`] and [`StateMachine`][crate::obj::StateMachine] fields.
+pub(super) fn register_signal(
+    class_name: ClassName,
+    signal_name: &str,
+    parameters: &[PropertyInfo],
+) {
+    // The infos own the strings that the sys structs point to, so they must outlive the registration call.
+    let parameters_sys: Vec<_> = parameters.iter().map(PropertyInfo::property_sys).collect();
+    let signal_name = StringName::from(signal_name);
+
+    // SAFETY: all pointers are valid for the duration of the call.
+    unsafe {
+        sys::interface_fn!(classdb_register_extension_class_signal)(
+            sys::get_library(),
+            class_name.string_sys(),
+            signal_name.string_sys(),
+            parameters_sys.as_ptr(),
+            parameters_sys.len() as sys::GDExtensionInt,
+        );
+    }
+}
+
+/// Emits `signal_name` on the owner. Returns `false` if the owner has been freed.
+pub(super) fn emit_on_owner(
+    owner_id: InstanceId,
+    signal_name: &StringName,
+    args: &[Variant],
+) -> bool {
+    // The owner may have been freed while the property is still around, e.g. when moved out of the object.
+    let Ok(mut owner) = Gd::<Object>::try_from_instance_id(owner_id) else {
+        return false;
+    };
+
+    owner.emit_signal(signal_name.clone(), args);
+    true
+}
@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use std::ptr;
+use std::ptr::{self, NonNull};
 
 use godot_ffi as sys;
 use sys::{interface_fn, GodotFfi, GodotNullableFfi, PtrcallType};
@@ -19,7 +19,7 @@ use crate::obj::bounds::DynMemory as _;
 use crate::obj::rtti::ObjectRtti;
 use crate::obj::{bounds, Bounds, GdDerefTarget, GdMut, GdRef, GodotClass, InstanceId};
 use crate::storage::{InstanceStorage, Storage};
-use crate::{classes, global, out};
+use crate::{classes, out};
 
 /// Low-level bindings for object pointers in Godot.
 ///
@@ -28,16 +28,29 @@ use crate::{classes, global, out};
 #[repr(C)]
 #[doc(hidden)]
 pub struct RawGd<T: GodotClass> {
-    pub(super) obj: *mut T,
+    // Dangling if the object is null, which is determined by `cached_rtti`. Never null, so that `Option<Gd<T>>` can use the niche.
+    // FFI code must use `obj_sys()`, which maps the null state back to a null pointer.
+    obj: NonNull<T>,
     // Must not be changed after initialization.
     cached_rtti: Option<ObjectRtti>,
 }
 
+#[cfg(since_api = "4.1")]
+/// Null object pointer, whose address is passed to Godot for null arguments (the `obj` field is dangling in that case).
+struct NullObjectPtr(sys::GDExtensionObjectPtr);
+
+// SAFETY: the pointer is never dereferenced nor modified.
+#[cfg(since_api = "4.1")]
+unsafe impl Sync for NullObjectPtr {}
+
+#[cfg(since_api = "4.1")]
+static NULL_OBJECT_PTR: NullObjectPtr = NullObjectPtr(ptr::null_mut());
+
 impl<T: GodotClass> RawGd<T> {
     /// Create a new object representing a null in Godot.
     pub(super) fn null() -> Self {
         Self {
-            obj: ptr::null_mut(),
+            obj: NonNull::dangling(),
             cached_rtti: None,
         }
     }
@@ -49,22 +62,20 @@ impl<T: GodotClass> RawGd<T> {
     ///
     /// `obj` must be a valid object pointer or a null pointer.
     pub(super) unsafe fn from_obj_sys_weak(obj: sys::GDExtensionObjectPtr) -> Self {
-        let rtti = if obj.is_null() {
-            None
-        } else {
-            let raw_id = unsafe { interface_fn!(object_get_instance_id)(obj) };
+        let Some(non_null) = NonNull::new(obj.cast::<T>()) else {
+            return Self::null();
+        };
 
-            let instance_id = InstanceId::try_from_u64(raw_id)
-                .expect("constructed RawGd weak pointer with instance ID 0");
+        let raw_id = unsafe { interface_fn!(object_get_instance_id)(obj) };
 
-            // TODO(bromeon): this should query dynamic type of object, which can be different from T (upcast, FromGodot, etc).
-            // See comment in ObjectRtti.
-            Some(ObjectRtti::of::<T>(instance_id))
-        };
+        let instance_id = InstanceId::try_from_u64(raw_id)
+            .expect("constructed RawGd weak pointer with instance ID 0");
 
         Self {
-            obj: obj.cast::<T>(),
-            cached_rtti: rtti,
+            obj: non_null,
+            // TODO(bromeon): this should query dynamic type of object, which can be different from T (upcast, FromGodot, etc).
+            // See comment in ObjectRtti.
+            cached_rtti: Some(ObjectRtti::of::<T>(instance_id)),
         }
     }
 
@@ -95,7 +106,7 @@ impl<T: GodotClass> RawGd<T> {
     /// This does not check if the object is dead, for that use
     /// [`instance_id_or_none()`](Self::instance_id_or_none).
     pub(crate) fn is_null(&self) -> bool {
-        self.obj.is_null() || self.cached_rtti.is_none()
+        self.cached_rtti.is_none()
     }
 
     pub(crate) fn instance_id_unchecked(&self) -> Option<InstanceId> {
@@ -105,7 +116,7 @@ impl<T: GodotClass> RawGd<T> {
     pub(crate) fn is_instance_valid(&self) -> bool {
         self.cached_rtti
             .as_ref()
-            .map(|rtti| global::is_instance_id_valid(rtti.instance_id().to_i64()))
+            .map(|rtti| rtti.instance_id().is_valid())
             .unwrap_or(false)
     }
 
@@ -242,7 +253,7 @@ impl<T: GodotClass> RawGd<T> {
         //
         // #[repr(C)]
         // pub struct RawGd<T: GodotClass> {
-        //     obj: *mut T,
+        //     obj: NonNull<T>,
         //     cached_rtti: Option<ObjectRtti>,
         // }
         //
@@ -348,14 +359,18 @@ impl<T: GodotClass> RawGd<T> {
     }
 
     pub(super) fn obj_sys(&self) -> sys::GDExtensionObjectPtr {
-        self.obj as sys::GDExtensionObjectPtr
+        if self.is_null() {
+            ptr::null_mut()
+        } else {
+            self.obj.as_ptr() as sys::GDExtensionObjectPtr
+        }
     }
 
     pub(super) fn script_sys(&self) -> sys::GDExtensionScriptLanguagePtr
     where
         T: super::Inherits<crate::classes::ScriptLanguage>,
     {
-        self.obj.cast()
+        self.obj_sys().cast()
     }
 }
 
@@ -372,6 +387,11 @@ where
         GdRef::from_guard(self.storage().unwrap().get())
     }
 
+    /// Like [`bind()`][Self::bind], but returns `None` instead of panicking if the instance is already bound mutably.
+    pub(crate) fn try_bind(&self) -> Option<GdRef<T>> {
+        self.storage()?.try_get().map(GdRef::from_guard)
+    }
+
     /// Hands out a guard for an exclusive borrow, through which the user instance can be read and written.
     ///
     /// See [`crate::obj::Gd::bind_mut()`] for a more in depth explanation.
@@ -380,6 +400,11 @@ where
         GdMut::from_guard(self.storage().unwrap().get_mut())
     }
 
+    /// Like [`bind_mut()`][Self::bind_mut], but returns `None` instead of panicking if the instance is already bound.
+    pub(crate) fn try_bind_mut(&mut self) -> Option<GdMut<T>> {
+        self.storage()?.try_get_mut().map(GdMut::from_guard)
+    }
+
     /// Storage object associated with the extension instance.
     ///
     /// Returns `None` if self is null.
@@ -424,7 +449,7 @@ where
         }
 
         let callbacks = crate::storage::nop_instance_callbacks();
-        let token = sys::get_library() as *mut std::ffi::c_void;
+        let token = crate::registry::extensions::binding_token::<T>();
         let binding = interface_fn!(object_get_instance_binding)(self.obj_sys(), token, &callbacks);
 
         debug_assert!(
@@ -467,11 +492,11 @@ where
     }
 
     fn sys(&self) -> sys::GDExtensionConstTypePtr {
-        self.obj.cast()
+        self.obj_sys().cast()
     }
 
     fn sys_mut(&mut self) -> sys::GDExtensionTypePtr {
-        self.obj.cast()
+        self.obj_sys().cast()
     }
 
     // For more context around `ref_get_object` and `ref_set_object`, see:
@@ -502,7 +527,7 @@ where
         if T::DynMemory::pass_as_ref(call_type) {
             interface_fn!(ref_set_object)(ptr as sys::GDExtensionRefPtr, self.obj_sys())
         } else {
-            ptr::write(ptr as *mut _, self.obj)
+            ptr::write(ptr as *mut sys::GDExtensionObjectPtr, self.obj_sys())
         }
         // We've passed ownership to caller.
         std::mem::forget(self);
@@ -526,7 +551,11 @@ where
 
         #[cfg(since_api = "4.1")]
         {
-            ptr::addr_of!(self.obj) as sys::GDExtensionConstTypePtr
+            if self.is_null() {
+                ptr::addr_of!(NULL_OBJECT_PTR.0) as sys::GDExtensionConstTypePtr
+            } else {
+                ptr::addr_of!(self.obj) as sys::GDExtensionConstTypePtr
+            }
         }
     }
 }
@@ -626,7 +655,7 @@ impl<T: GodotClass> Clone for RawGd<T> {
         }
 
         if !self.is_null() {
-            unsafe { Self::from_obj_sys(self.obj as sys::GDExtensionObjectPtr) }
+            unsafe { Self::from_obj_sys(self.obj_sys()) }
         } else {
             Self::null()
         }
@@ -0,0 +1,148 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::obj::{bounds, Bounds, Gd, GdMut, GdRef, GodotClass, InstanceId};
+
+/// Binds several [`Gd`] pointers at once, for the duration of a block.
+///
+/// Each binding is either `ref name = gd` (shared, like [`Gd::bind()`]) or `mut name = gd` (exclusive, like [`Gd::bind_mut()`]).
+/// Inside the block, `name` refers to the bound user instance. All guards are released at the end of the block, whose value is returned.
+///
+/// `gd` can be a `Gd<T>` place or a reference to one. For `mut` bindings, this is either a `mut` variable or a `&mut Gd<T>`, which is
+/// reborrowed.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::obj::bind_scope;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Fighter {
+///     health: i32,
+///     damage: i32,
+/// }
+///
+/// fn attack(attacker: &Gd<Fighter>, defender: &mut Gd<Fighter>) -> bool {
+///     bind_scope!(ref attacker = attacker, mut defender = defender => {
+///         defender.health -= attacker.damage;
+///         defender.health <= 0
+///     })
+/// }
+///
+/// let attacker = Fighter::new_alloc();
+/// let mut defender = Fighter::new_alloc();
+/// let defeated = attack(&attacker, &mut defender);
+///
+/// // Owned pointers work as well.
+/// let health = bind_scope!(mut defender = defender => { defender.health });
+/// # attacker.free();
+/// # defender.free();
+/// ```
+///
+/// # Panics
+/// If an object cannot be bound, with a message naming the binding. If two bindings refer to the same object and at least one of them is
+/// `mut` (e.g. a fighter attacking itself), this is detected before anything is bound, and the message names both bindings.
+#[macro_export]
+macro_rules! bind_scope {
+    (@munch [$($acc:tt)*] $kind:tt $name:ident = $gd:expr, $($rest:tt)+) => {
+        $crate::bind_scope!(@munch [$($acc)* ($kind $name $gd)] $($rest)+)
+    };
+    (@munch [$($acc:tt)*] $kind:tt $name:ident = $gd:expr => $body:block) => {
+        $crate::bind_scope!(@expand [$($acc)* ($kind $name $gd)] $body)
+    };
+
+    (@expand [$(($kind:tt $name:ident $gd:expr))+] $body:block) => {{
+        $( let $name = $crate::bind_scope!(@ref $kind $gd); )+
+
+        $crate::obj::__bind_scope_check(&[
+            $( (stringify!($name), $crate::obj::__bind_scope_id(&*$name), $crate::bind_scope!(@is_mut $kind)) ),+
+        ]);
+
+        $( $crate::bind_scope!(@bind $kind $name); )+
+        $body
+    }};
+
+    (@ref ref $gd:expr) => { &$gd };
+    // Method call to reborrow `&mut Gd<T>` expressions, or to borrow `Gd<T>` places mutably.
+    (@ref mut $gd:expr) => {{
+        use $crate::obj::__BindScopeMut as _;
+        ($gd).__bind_scope_mut()
+    }};
+
+    (@is_mut ref) => { false };
+    (@is_mut mut) => { true };
+
+    (@bind ref $name:ident) => {
+        let $name = $crate::obj::__bind_scope_shared(stringify!($name), $name);
+    };
+    (@bind mut $name:ident) => {
+        #[allow(unused_mut)]
+        let mut $name = $crate::obj::__bind_scope_exclusive(stringify!($name), $name);
+    };
+
+    // Entry point, must come last to not match the internal rules. Collects bindings as `(kind name expr)` triples.
+    ($($bindings:tt)+) => {
+        $crate::bind_scope!(@munch [] $($bindings)+)
+    };
+}
+
+/// Mutable access to a `Gd<T>` for `bind_scope!`; used with method call syntax, which auto-borrows places and reborrows `&mut Gd<T>`.
+#[doc(hidden)]
+pub trait __BindScopeMut<T: GodotClass> {
+    fn __bind_scope_mut(&mut self) -> &mut Gd<T>;
+}
+
+impl<T: GodotClass> __BindScopeMut<T> for Gd<T> {
+    fn __bind_scope_mut(&mut self) -> &mut Gd<T> {
+        self
+    }
+}
+
+#[doc(hidden)]
+pub fn __bind_scope_id<T: GodotClass>(gd: &Gd<T>) -> Option<InstanceId> {
+    if gd.is_instance_valid() {
+        gd.instance_id_or_none()
+    } else {
+        None
+    }
+}
+
+#[doc(hidden)]
+pub fn __bind_scope_check(bindings: &[(&str, Option<InstanceId>, bool)]) {
+    for (i, (name, id, is_mut)) in bindings.iter().enumerate() {
+        let Some(id) = id else {
+            continue;
+        };
+
+        for (other_name, other_id, other_is_mut) in &bindings[..i] {
+            if other_id.as_ref() == Some(id) && (*is_mut || *other_is_mut) {
+                panic!(
+                    "bind_scope!: `{other_name}` and `{name}` refer to the same object (instance {id}), \
+                    which cannot be bound mutably while bound elsewhere"
+                );
+            }
+        }
+    }
+}
+
+#[doc(hidden)]
+pub fn __bind_scope_shared<'a, T>(name: &str, gd: &'a Gd<T>) -> GdRef<'a, T>
+where
+    T: GodotClass + Bounds<Declarer = bounds::DeclUser>,
+{
+    gd.try_bind()
+        .unwrap_or_else(|err| panic!("bind_scope!: cannot bind `{name}`: {err}"))
+}
+
+#[doc(hidden)]
+pub fn __bind_scope_exclusive<'a, T>(name: &str, gd: &'a mut Gd<T>) -> GdMut<'a, T>
+where
+    T: GodotClass + Bounds<Declarer = bounds::DeclUser>,
+{
+    gd.try_bind_mut()
+        .unwrap_or_else(|err| panic!("bind_scope!: cannot bind `{name}`: {err}"))
+}
@@ -0,0 +1,239 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::{Array, Dictionary, GString, StringName, Variant};
+use crate::classes::{Object, Script};
+use crate::global::PropertyUsageFlags;
+use crate::meta::error::ScriptError;
+use crate::meta::{FromGodot, ToGodot};
+use crate::obj::{EngineBitfield, Gd, Inherits};
+
+/// Script attached to an object, with typed access to the script's methods and properties.
+///
+/// Obtained through [`Gd::attach_script()`] or [`Gd::script_handle()`]. The handle keeps both the object and the script alive (as far as
+/// reference counting goes), but does not prevent the object from being freed or its script from being replaced.
+///
+/// Unlike [`Object::call()`], which returns `NIL` and prints an error on failure, all accessors check that the script declares the
+/// method or property and return a [`ScriptError`] otherwise.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::classes::GDScript;
+///
+/// let mut script = GDScript::new_gd();
+/// script.set_source_code("extends RefCounted\nfunc add(a: int, b: int) -> int:\n\treturn a + b".into());
+/// script.reload();
+///
+/// let mut obj = RefCounted::new_gd();
+/// let mut handle = obj.attach_script(script);
+///
+/// let sum: i64 = handle.call("add", &[1.to_variant(), 2.to_variant()]).unwrap();
+/// assert_eq!(sum, 3);
+/// ```
+pub struct ScriptHandle<S: Inherits<Script> = Script> {
+    object: Gd<Object>,
+    script: Gd<S>,
+}
+
+impl<S: Inherits<Script>> ScriptHandle<S> {
+    /// The attached script.
+    pub fn script(&self) -> &Gd<S> {
+        &self.script
+    }
+
+    /// The object to which the script is attached.
+    pub fn object(&self) -> &Gd<Object> {
+        &self.object
+    }
+
+    /// Returns `true` if the script (or one of its base scripts) declares a method named `method`.
+    pub fn has_method(&self, method: impl Into<StringName>) -> bool {
+        let method = method.into();
+        self.method_names().contains(&method)
+    }
+
+    /// Names of all methods declared by the script, including those of base scripts.
+    ///
+    /// Methods of the object's engine or Rust class are not included.
+    pub fn method_names(&self) -> Vec<StringName> {
+        let methods = self.base_script().get_script_method_list();
+        names_of(methods, |_| true)
+    }
+
+    /// Returns `true` if the script (or one of its base scripts) declares a property named `property`.
+    pub fn has_property(&self, property: impl Into<StringName>) -> bool {
+        let property = property.into();
+        self.property_names().contains(&property)
+    }
+
+    /// Names of all properties declared by the script, including those of base scripts.
+    ///
+    /// Editor categories and groups are skipped.
+    pub fn property_names(&self) -> Vec<StringName> {
+        let non_properties = PropertyUsageFlags::CATEGORY.ord()
+            | PropertyUsageFlags::GROUP.ord()
+            | PropertyUsageFlags::SUBGROUP.ord();
+
+        let properties = self.base_script().get_script_property_list();
+        names_of(properties, |dict| {
+            let usage = dict.get("usage").map_or(0, |usage| usage.to::<i64>());
+            (usage as u64) & non_properties == 0
+        })
+    }
+
+    /// Calls the script method `method` with `args`, converting its return value to `R`.
+    ///
+    /// Use `R = Variant` to accept any return value, or `R = ()` for methods without one.
+    pub fn call<R: FromGodot>(
+        &mut self,
+        method: impl Into<StringName>,
+        args: &[Variant],
+    ) -> Result<R, ScriptError> {
+        let method = method.into();
+        if !self.has_method(method.clone()) {
+            return Err(ScriptError::MethodNotFound {
+                script: self.script_description(),
+                method: method.to_string(),
+            });
+        }
+
+        let result = self.object.try_call(method, args)?;
+        let value = result.try_to::<R>()?;
+        Ok(value)
+    }
+
+    /// Reads the script property `property`, converting it to `V`.
+    pub fn get<V: FromGodot>(&self, property: impl Into<StringName>) -> Result<V, ScriptError> {
+        let property = self.check_property(property.into())?;
+
+        let value = self.object.get(property).try_to::<V>()?;
+        Ok(value)
+    }
+
+    /// Assigns `value` to the script property `property`.
+    pub fn set<V: ToGodot>(
+        &mut self,
+        property: impl Into<StringName>,
+        value: V,
+    ) -> Result<(), ScriptError> {
+        let property = self.check_property(property.into())?;
+
+        self.object.set(property, value.to_variant());
+        Ok(())
+    }
+
+    fn check_property(&self, property: StringName) -> Result<StringName, ScriptError> {
+        if self.has_property(property.clone()) {
+            Ok(property)
+        } else {
+            Err(ScriptError::PropertyNotFound {
+                script: self.script_description(),
+                property: property.to_string(),
+            })
+        }
+    }
+
+    fn base_script(&self) -> Gd<Script> {
+        self.script.clone().upcast()
+    }
+
+    /// Resource path if available (scripts created in code have none), otherwise the script's class.
+    fn script_description(&self) -> String {
+        let path = self.base_script().get_path();
+        if path.is_empty() {
+            format!("<{}>", self.base_script().get_class())
+        } else {
+            format!("'{path}'")
+        }
+    }
+}
+
+impl<S: Inherits<Script>> Clone for ScriptHandle<S> {
+    fn clone(&self) -> Self {
+        Self {
+            object: self.object.clone(),
+            script: self.script.clone(),
+        }
+    }
+}
+
+impl<S: Inherits<Script>> fmt::Debug for ScriptHandle<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScriptHandle")
+            .field("object", &self.object)
+            .field("script", &self.script)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Script access on Gd
+
+impl<T: Inherits<Object>> Gd<T> {
+    /// Attaches `script` to this object, replacing any existing script, and returns a handle to interact with it.
+    ///
+    /// Like `Object.set_script()` in GDScript, this re-creates the script instance; script variables start with their default values.
+    /// If the script's base type is incompatible with the object's class, Godot reports an error and the script is not attached.
+    pub fn attach_script<S: Inherits<Script>>(&mut self, script: Gd<S>) -> ScriptHandle<S> {
+        self.upcast_mut::<Object>().set_script(script.to_variant());
+
+        ScriptHandle {
+            object: self.clone().upcast(),
+            script,
+        }
+    }
+
+    /// Returns a handle to the script attached to this object, if there is one and it is of type `S`.
+    ///
+    /// Use `S = Script` to accept any kind of script.
+    pub fn script_handle<S: Inherits<Script>>(&self) -> Option<ScriptHandle<S>> {
+        let script = self
+            .upcast_ref::<Object>()
+            .get_script()
+            .try_to::<Option<Gd<Script>>>()
+            .ok()
+            .flatten()?;
+
+        let script = script.try_cast::<S>().ok()?;
+
+        Some(ScriptHandle {
+            object: self.clone().upcast(),
+            script,
+        })
+    }
+
+    /// Removes the script from this object and returns it, or `None` if no script was attached.
+    pub fn detach_script(&mut self) -> Option<Gd<Script>> {
+        let object = self.upcast_mut::<Object>();
+        let previous = object
+            .get_script()
+            .try_to::<Option<Gd<Script>>>()
+            .ok()
+            .flatten();
+
+        if previous.is_some() {
+            object.set_script(Variant::nil());
+        }
+
+        previous
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+fn names_of(list: Array<Dictionary>, filter: impl Fn(&Dictionary) -> bool) -> Vec<StringName> {
+    list.iter_shared()
+        .filter(|dict| filter(dict))
+        .filter_map(|dict| dict.get("name"))
+        .filter_map(|name| name.try_to::<GString>().ok())
+        .map(|name| StringName::from(&name))
+        .collect()
+}
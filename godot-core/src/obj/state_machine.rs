@@ -0,0 +1,408 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+use std::fmt;
+
+use crate::builtin::{GString, StringName};
+use crate::global::{godot_warn, PropertyHint, PropertyUsageFlags};
+use crate::meta::error::ConvertError;
+use crate::meta::{ClassName, FromGodot, GodotConvert, PropertyInfo, ToGodot};
+use crate::obj::prop::{emit_on_owner, register_signal};
+use crate::obj::{bounds, Base, Bounds, Gd, GodotClass, InstanceId};
+use crate::registry::property::{Export, PropertyHintInfo, Var};
+
+/// Enum whose variants are the states of a [`StateMachine`].
+///
+/// Usually derived with `#[derive(StateEnum)]`, or implicitly with `#[derive(GodotClass)]` on an enum (see [`StateClass`]). Variants may
+/// carry data; the derive then only constructs unit variants by name.
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(StateEnum)]
+/// enum Movement {
+///     Idle,
+///     Walking { speed: f32 },
+///     Jumping(f64),
+/// }
+///
+/// assert_eq!(Movement::VARIANT_NAMES, ["Idle", "Walking", "Jumping"]);
+/// assert_eq!(Movement::Walking { speed: 2.0 }.variant_name(), "Walking");
+/// assert!(Movement::from_variant_name("Idle").is_some());
+/// assert!(Movement::from_variant_name("Walking").is_none());
+/// ```
+pub trait StateEnum: Sized + 'static {
+    /// Names of all variants, in declaration order.
+    const VARIANT_NAMES: &'static [&'static str];
+
+    /// Index of the current variant in [`VARIANT_NAMES`][Self::VARIANT_NAMES].
+    fn variant_index(&self) -> usize;
+
+    /// Constructs the variant with the given name, if it does not carry any data.
+    fn from_variant_name(name: &str) -> Option<Self>;
+
+    /// Name of the current variant.
+    fn variant_name(&self) -> &'static str {
+        Self::VARIANT_NAMES[self.variant_index()]
+    }
+}
+
+/// Rust enum registered as a Godot class, whose variants are the states of the object.
+///
+/// Implemented by `#[derive(GodotClass)]` on enums, which also implements [`StateEnum`]. Each object of the class holds one variant,
+/// including its data. The derive registers:
+/// - An exported property `state` holding the name of the current variant, as an enum in the inspector. Assigning the name of a unit
+///   variant (e.g. from GDScript or the editor) transitions into that state.
+/// - A signal `state_changed(from: StringName, to: StringName)` with the variant names, emitted on every transition through
+///   [`transition()`][Self::transition] or [`step()`][Self::step].
+///
+/// With `#[class(init)]`, objects start in the first variant, which must then be a unit variant. Since enums cannot hold a `Base<T>`
+/// field, per-frame logic is written in `#[func(gd_self)]` virtual methods, which receive the object as `Gd<Self>`.
+/// [`step()`][Self::step] then dispatches on the current state with a `match`.
+///
+/// Note that assigning to `*self` directly (e.g. in a `&mut self` method) changes the state silently, without emitting the signal.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// enum Door {
+///     Closed,
+///     Opening { progress: f64 },
+///     Open,
+/// }
+///
+/// #[godot_api]
+/// impl INode for Door {
+///     #[func(gd_self)]
+///     fn process(mut this: Gd<Self>, delta: f64) {
+///         Self::step(&mut this, |state| match state {
+///             Door::Opening { progress } if *progress >= 1.0 => Some(Door::Open),
+///             Door::Opening { progress } => {
+///                 *progress += delta;
+///                 None
+///             }
+///             Door::Closed | Door::Open => None,
+///         });
+///     }
+/// }
+///
+/// fn open(door: &mut Gd<Door>) {
+///     // Emits `state_changed("Closed", "Opening")`.
+///     Door::transition(door, Door::Opening { progress: 0.0 });
+/// }
+/// ```
+pub trait StateClass: StateEnum + GodotClass + Bounds<Declarer = bounds::DeclUser> {
+    /// Moves the object into the state `next` and returns the previous state.
+    ///
+    /// The signal `state_changed` is only emitted if the variant differs. It is emitted once the object is no longer bound, so that
+    /// handlers can access it.
+    ///
+    /// # Panics
+    /// If the object is currently bound, e.g. inside a `&mut self` method.
+    fn transition(this: &mut Gd<Self>, next: Self) -> Self {
+        let previous = std::mem::replace(&mut *this.bind_mut(), next);
+        let current = this.bind().variant_name();
+
+        if previous.variant_name() != current {
+            emit_transition(
+                this.instance_id(),
+                crate::sname!("state_changed"),
+                previous.variant_name(),
+                current,
+            );
+        }
+
+        previous
+    }
+
+    /// Runs one update of the object: `f` is invoked with the current state, and the object transitions into the returned state, if any.
+    ///
+    /// Returns whether a transition took place. Meant for `match`-based dispatch in `process()` and similar callbacks.
+    ///
+    /// # Panics
+    /// If the object is currently bound, e.g. inside a `&mut self` method.
+    fn step(this: &mut Gd<Self>, f: impl FnOnce(&mut Self) -> Option<Self>) -> bool {
+        // Bind guard is released before the transition.
+        let next = f(&mut *this.bind_mut());
+
+        match next {
+            Some(next) => {
+                Self::transition(this, next);
+                true
+            }
+            None => false,
+        }
+    }
+
+    #[doc(hidden)]
+    fn __set_state_by_name(mut this: Gd<Self>, name: StringName) {
+        if let Some(next) = unit_variant_by_name::<Self>(&name) {
+            Self::transition(&mut this, next);
+        }
+    }
+
+    #[doc(hidden)]
+    fn __register_state_class(getter_name: &str, setter_name: &str) {
+        let class_name = Self::class_name();
+        register_transition_signal(class_name, "state_changed");
+
+        let property_info = PropertyInfo {
+            usage: PropertyUsageFlags::DEFAULT,
+            ..PropertyInfo::new_var::<StringName>("state").with_hint_info(state_hint::<Self>())
+        };
+
+        let property_info_sys = property_info.property_sys();
+        let getter_name = StringName::from(getter_name);
+        let setter_name = StringName::from(setter_name);
+
+        // SAFETY: all pointers are valid for the duration of the call.
+        unsafe {
+            sys::interface_fn!(classdb_register_extension_class_property)(
+                sys::get_library(),
+                class_name.string_sys(),
+                std::ptr::addr_of!(property_info_sys),
+                setter_name.string_sys(),
+                getter_name.string_sys(),
+            );
+        }
+    }
+}
+
+/// State machine driven by a Rust enum, meant to be used as a field in a Rust class.
+///
+/// This is an alternative to [`StateClass`] for classes that hold more than their state, e.g. a `Base<T>` field or other data.
+///
+/// The current state is an enum value implementing [`StateEnum`]; its variants can carry state-specific data. Per-frame logic is
+/// typically written as a `match` inside [`step()`][Self::step], which returns the next state (if any).
+///
+/// # Property and signal
+/// For every `StateMachine<S>` field named `field`, `#[derive(GodotClass)]`:
+/// - Registers a signal `field_changed(from: StringName, to: StringName)` with the variant names, emitted on every transition.
+/// - Registers a property `field` holding the name of the current variant, shown read-only in the inspector, unless the field has its
+///   own `#[var]` or `#[export]` attribute. Assigning the name of a unit variant (e.g. from GDScript) transitions into that state.
+///
+/// Like with [`Prop`][crate::obj::Prop], the state machine is attached automatically when the class uses the generated constructor and
+/// has a `Base<T>` field; otherwise call [`attach()`][Self::attach] yourself. The signal is emitted immediately, like for [`StateClass`].
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(StateEnum)]
+/// enum Movement {
+///     Idle,
+///     Walking { speed: f32 },
+///     Falling { time: f64 },
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node2D)]
+/// struct Player {
+///     // Registers property `state` and signal `state_changed(from, to)`.
+///     #[init(default = StateMachine::new(Movement::Idle))]
+///     state: StateMachine<Movement>,
+///
+///     base: Base<Node2D>,
+/// }
+///
+/// #[godot_api]
+/// impl INode2D for Player {
+///     fn process(&mut self, delta: f64) {
+///         let on_floor = self.base().get_position().y >= 0.0;
+///
+///         self.state.step(|state| match state {
+///             Movement::Idle => None,
+///             Movement::Walking { .. } if !on_floor => Some(Movement::Falling { time: 0.0 }),
+///             Movement::Walking { speed } => {
+///                 *speed = speed.min(300.0);
+///                 None
+///             }
+///             Movement::Falling { .. } if on_floor => Some(Movement::Idle),
+///             Movement::Falling { time } => {
+///                 *time += delta;
+///                 None
+///             }
+///         });
+///     }
+/// }
+/// ```
+pub struct StateMachine<S> {
+    state: S,
+    owner: Option<(InstanceId, StringName)>,
+}
+
+impl<S: StateEnum> StateMachine<S> {
+    /// Creates a state machine in the `initial` state.
+    pub fn new(initial: S) -> Self {
+        Self {
+            state: initial,
+            owner: None,
+        }
+    }
+
+    /// Returns the current state.
+    pub fn get(&self) -> &S {
+        &self.state
+    }
+
+    /// Returns the current state mutably, e.g. to update its data. Reassigning it does not count as a transition.
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.state
+    }
+
+    /// Name of the current variant.
+    pub fn variant_name(&self) -> &'static str {
+        self.state.variant_name()
+    }
+
+    /// Moves into the state `next` and returns the previous state.
+    ///
+    /// The changed signal is only emitted if the variant differs; replacing a state with the same variant and new data is silent.
+    pub fn transition(&mut self, next: S) -> S {
+        let previous = std::mem::replace(&mut self.state, next);
+
+        if previous.variant_index() != self.state.variant_index() {
+            self.notify(previous.variant_name());
+        }
+
+        previous
+    }
+
+    /// Runs one update of the state machine: `f` is invoked with the current state, and transitions into the returned state, if any.
+    ///
+    /// Returns whether a transition took place. This is the building block for `match`-based dispatch in `process()` and similar callbacks.
+    pub fn step(&mut self, f: impl FnOnce(&mut S) -> Option<S>) -> bool {
+        match f(&mut self.state) {
+            Some(next) => {
+                self.transition(next);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Attaches the state machine to an object, emitting the signal `signal_name` on it with the old and new variant names on every
+    /// transition.
+    ///
+    /// Only needed in custom `init()` functions; see [property and signal](#property-and-signal). The object is not kept alive.
+    pub fn attach<B: GodotClass>(&mut self, owner: &Base<B>, signal_name: impl Into<StringName>) {
+        self.owner = Some((owner.instance_id(), signal_name.into()));
+    }
+
+    fn notify(&self, from: &str) {
+        if let Some((owner_id, signal_name)) = &self.owner {
+            emit_transition(*owner_id, signal_name, from, self.variant_name());
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn __register_changed_signal(class_name: ClassName, signal_name: &str) {
+        register_transition_signal(class_name, signal_name);
+    }
+}
+
+impl<S: StateEnum + Default> Default for StateMachine<S> {
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for StateMachine<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StateMachine")
+            .field("state", &self.state)
+            .field("attached", &self.owner.is_some())
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Conversions and properties
+
+impl<S: StateEnum> GodotConvert for StateMachine<S> {
+    type Via = StringName;
+}
+
+impl<S: StateEnum> ToGodot for StateMachine<S> {
+    fn to_godot(&self) -> Self::Via {
+        StringName::from(self.variant_name())
+    }
+}
+
+impl<S: StateEnum> FromGodot for StateMachine<S> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        let name = via.to_string();
+
+        S::from_variant_name(&name)
+            .map(Self::new)
+            .ok_or_else(|| ConvertError::new(format!("no unit variant named `{name}`")))
+    }
+}
+
+impl<S: StateEnum> Var for StateMachine<S> {
+    fn get_property(&self) -> Self::Via {
+        self.to_godot()
+    }
+
+    fn set_property(&mut self, value: Self::Via) {
+        if let Some(next) = unit_variant_by_name::<S>(&value) {
+            self.transition(next);
+        }
+    }
+
+    fn property_hint() -> PropertyHintInfo {
+        state_hint::<S>()
+    }
+}
+
+impl<S: StateEnum> Export for StateMachine<S> {
+    fn default_export_info() -> PropertyHintInfo {
+        Self::property_hint()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation, shared by StateClass and StateMachine
+
+fn register_transition_signal(class_name: ClassName, signal_name: &str) {
+    register_signal(
+        class_name,
+        signal_name,
+        &[
+            PropertyInfo::new_var::<StringName>("from"),
+            PropertyInfo::new_var::<StringName>("to"),
+        ],
+    );
+}
+
+fn emit_transition(owner_id: InstanceId, signal_name: &StringName, from: &str, to: &str) {
+    let from = StringName::from(from);
+    let to = StringName::from(to);
+
+    emit_on_owner(owner_id, signal_name, &[from.to_variant(), to.to_variant()]);
+}
+
+/// Variants with data cannot be constructed from a name; warns and returns `None` for them, so the current state is kept.
+fn unit_variant_by_name<S: StateEnum>(name: &StringName) -> Option<S> {
+    let next = S::from_variant_name(&name.to_string());
+    if next.is_none() {
+        godot_warn!("rejected transition to state `{name}`: no unit variant with this name");
+    }
+
+    next
+}
+
+fn state_hint<S: StateEnum>() -> PropertyHintInfo {
+    PropertyHintInfo {
+        hint: PropertyHint::ENUM,
+        hint_string: GString::from(S::VARIANT_NAMES.join(",")),
+    }
+}
@@ -57,6 +57,16 @@ where
             Self::Base::inherits::<U>()
         }
     }
+
+    /// Adds the user instance to the `Debug` output of `Gd<Self>`. Overridden by `#[class(debug)]`.
+    #[doc(hidden)]
+    fn __godot_fmt_debug_instance(_obj: &Gd<Self>, _s: &mut std::fmt::DebugStruct<'_, '_>) {}
+
+    /// Library token of a `#[class(shared)]` class imported from another library, or 0 if not imported. `None` for all other classes.
+    #[doc(hidden)]
+    fn __imported_token() -> Option<&'static std::sync::atomic::AtomicUsize> {
+        None
+    }
 }
 
 /// Type representing the absence of a base class, at the root of the hierarchy.
@@ -80,6 +90,7 @@ unsafe impl Bounds for NoBase {
     type Memory = bounds::MemManual;
     type DynMemory = bounds::MemManual;
     type Declarer = bounds::DeclEngine;
+    type Layout = bounds::LayoutBoxed;
 }
 
 /// Non-strict inheritance relationship in the Godot class hierarchy.
@@ -149,6 +160,17 @@ pub trait UserClass: Bounds<Declarer = bounds::DeclUser> {
     fn __default_virtual_call(_method_name: &str) -> sys::GDExtensionClassCallVirtual {
         None
     }
+
+    /// Whether `#[export(required)]` fields or `#[class(warnings)]` contribute configuration warnings.
+    #[doc(hidden)]
+    const __HAS_CONFIG_WARNINGS: bool = false;
+
+    #[doc(hidden)]
+    fn __after_get_configuration_warnings(
+        &self,
+        _warnings: &mut crate::builtin::PackedStringArray,
+    ) {
+    }
 }
 
 /// Auto-implemented for all engine-provided enums.
@@ -541,6 +563,14 @@ pub mod cap {
         fn __godot_property_get_revert(&self, property: StringName) -> Option<Variant>;
     }
 
+    /// Auto-implemented for `#[derive(GodotClass)]` with a generated `init`, if the class has `#[export]` fields.
+    #[doc(hidden)]
+    pub trait GodotPropertyDefault: UserClass {
+        /// Values of all exported properties right after the generated `init`. Each field initializer is evaluated once per call.
+        #[doc(hidden)]
+        fn __godot_property_defaults() -> Vec<(StringName, Variant)>;
+    }
+
     /// Auto-implemented for `#[godot_api] impl MyClass` blocks
     pub trait ImplementsGodotApi: GodotClass {
         #[doc(hidden)]
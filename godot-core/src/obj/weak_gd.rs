@@ -0,0 +1,207 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::marker::PhantomData;
+
+use crate::meta::error::ConvertError;
+use crate::meta::{FromGodot, GodotConvert, ToGodot};
+use crate::obj::{Gd, GodotClass, InstanceId};
+use crate::registry::property::{Export, PropertyHintInfo, TypeStringHint, Var};
+
+/// Weak reference to a Godot object.
+///
+/// A `WeakGd<T>` does not keep the object alive and does not prevent it from being freed. To access the object, [`upgrade()`][Self::upgrade]
+/// the reference to a strong [`Gd<T>`], which fails if the object has been destroyed in the meantime.
+///
+/// This is useful to store back-references, e.g. from a child to its parent node, or between ref-counted objects which would otherwise
+/// form a reference cycle and leak.
+///
+/// Weak references are based on instance IDs and work for both manually-managed and ref-counted objects. This corresponds to Godot's
+/// `weakref()` function, which also stores the instance ID. Since Godot does not reuse instance IDs, a `WeakGd` can never accidentally
+/// point to a different object.
+///
+/// # Properties
+/// `WeakGd<T>` can be used in `#[var]` and `#[export]` fields. In Godot, the property appears as a nullable object of class `T`; it
+/// reads as `null` once the object has been destroyed.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::obj::WeakGd;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Turret {
+///     // Does not keep the target alive.
+///     #[export]
+///     target: WeakGd<Node3D>,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl INode for Turret {
+///     fn process(&mut self, _delta: f64) {
+///         if let Some(target) = self.target.upgrade() {
+///             godot_print!("Aiming at {}", target.get_position());
+///         }
+///     }
+/// }
+/// ```
+pub struct WeakGd<T: GodotClass> {
+    instance_id: Option<InstanceId>,
+
+    // Like Gd<T>: not Send/Sync.
+    _marker: PhantomData<*const T>,
+}
+
+impl<T: GodotClass> WeakGd<T> {
+    /// Creates an empty weak reference, which never upgrades.
+    pub fn new() -> Self {
+        Self {
+            instance_id: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a weak reference to the object pointed to by `gd`.
+    ///
+    /// If the object is already dead, the result is an empty weak reference. Equivalent to [`Gd::downgrade()`].
+    pub fn from_gd(gd: &Gd<T>) -> Self {
+        Self {
+            instance_id: gd.instance_id_or_none(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a weak reference to the object with the given instance ID, e.g. one stored in a save file or received over the network.
+    ///
+    /// Neither the existence nor the class of the object are checked here; [`upgrade()`][Self::upgrade] returns `None` if there is no
+    /// such object, or if it is not of class `T` (or derived from it).
+    pub fn from_instance_id(instance_id: InstanceId) -> Self {
+        Self {
+            instance_id: Some(instance_id),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Attempts to obtain a strong reference to the object.
+    ///
+    /// Returns `None` if the reference is empty, the object has been destroyed, or it is not of class `T`. For ref-counted objects, the
+    /// returned `Gd` keeps the object alive as long as it exists.
+    pub fn upgrade(&self) -> Option<Gd<T>> {
+        let instance_id = self.instance_id?;
+
+        Gd::try_from_instance_id(instance_id).ok()
+    }
+
+    /// Returns whether the referenced object is still alive.
+    ///
+    /// Prefer [`upgrade()`][Self::upgrade] if you want to access the object, to avoid a race between checking and accessing.
+    pub fn is_valid(&self) -> bool {
+        self.upgrade().is_some()
+    }
+
+    /// Returns the instance ID of the referenced object, or `None` if the reference is empty.
+    ///
+    /// The ID is returned even if the object has been destroyed.
+    pub fn instance_id(&self) -> Option<InstanceId> {
+        self.instance_id
+    }
+}
+
+impl<T: GodotClass> Default for WeakGd<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: GodotClass> Clone for WeakGd<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: GodotClass> Copy for WeakGd<T> {}
+
+impl<T: GodotClass> PartialEq for WeakGd<T> {
+    /// Returns whether both weak references point to the same object (or are both empty).
+    fn eq(&self, other: &Self) -> bool {
+        self.instance_id == other.instance_id
+    }
+}
+
+impl<T: GodotClass> Eq for WeakGd<T> {}
+
+impl<T: GodotClass> std::hash::Hash for WeakGd<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.instance_id.hash(state);
+    }
+}
+
+impl<T: GodotClass> Debug for WeakGd<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.instance_id {
+            Some(id) if self.is_valid() => {
+                write!(f, "WeakGd {{ id: {id}, class: {} }}", T::class_name())
+            }
+            Some(id) => write!(f, "WeakGd {{ id: {id}, dead }}"),
+            None => write!(f, "WeakGd {{ empty }}"),
+        }
+    }
+}
+
+impl<T: GodotClass> From<&Gd<T>> for WeakGd<T> {
+    fn from(gd: &Gd<T>) -> Self {
+        Self::from_gd(gd)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Conversions and properties
+
+impl<T: GodotClass> GodotConvert for WeakGd<T> {
+    type Via = Option<Gd<T>>;
+}
+
+impl<T: GodotClass> ToGodot for WeakGd<T> {
+    fn to_godot(&self) -> Self::Via {
+        self.upgrade()
+    }
+}
+
+impl<T: GodotClass> FromGodot for WeakGd<T> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Ok(via.map(|gd| gd.downgrade()).unwrap_or_default())
+    }
+}
+
+impl<T: GodotClass> Var for WeakGd<T> {
+    fn get_property(&self) -> Self::Via {
+        self.upgrade()
+    }
+
+    fn set_property(&mut self, value: Self::Via) {
+        *self = FromGodot::from_godot(value);
+    }
+
+    fn property_hint() -> PropertyHintInfo {
+        <Gd<T> as Var>::property_hint()
+    }
+}
+
+impl<T: GodotClass> Export for WeakGd<T> {
+    fn default_export_info() -> PropertyHintInfo {
+        <Gd<T> as Export>::default_export_info()
+    }
+}
+
+impl<T: GodotClass> TypeStringHint for WeakGd<T> {
+    fn type_string() -> String {
+        <Gd<T> as TypeStringHint>::type_string()
+    }
+}
@@ -7,15 +7,20 @@
 
 pub use crate::gen::classes::class_macros;
 pub use crate::obj::rtti::ObjectRtti;
+pub use crate::registry::call_stats::MethodCallCounter;
 pub use crate::registry::callbacks;
-pub use crate::registry::plugin::{ClassPlugin, ErasedRegisterFn, PluginItem};
+pub use crate::registry::extensions::{FieldLayout, SharedClass};
+pub use crate::registry::plugin::{
+    ClassPlugin, DynTraitImpl, ErasedRegisterFn, PersistImpl, PluginItem,
+};
 pub use crate::storage::{as_storage, Storage};
 pub use sys::out;
 
 #[cfg(feature = "trace")]
 pub use crate::meta::trace;
 
-use crate::global::godot_error;
+use crate::global::{godot_error, godot_script_error};
+use crate::init::PanicPolicy;
 use crate::meta::error::CallError;
 use crate::meta::CallContext;
 use crate::sys;
@@ -34,6 +39,9 @@ static CALL_ERRORS: Global<CallErrors> = Global::default();
 /// - 2: normal printing
 static ERROR_PRINT_LEVEL: atomic::AtomicU8 = atomic::AtomicU8::new(2);
 
+/// Ordinal of the global [`PanicPolicy`], see [`crate::init::set_panic_policy()`].
+static PANIC_POLICY: atomic::AtomicU8 = atomic::AtomicU8::new(0);
+
 sys::plugin_registry!(pub __GODOT_PLUGIN_REGISTRY: ClassPlugin);
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -118,6 +126,99 @@ pub fn auto_init<T>(l: &mut crate::obj::OnReady<T>) {
     l.init_auto();
 }
 
+/// Registration info of a `#[class(shared)]` class with the given fields (including the base field).
+pub fn shared_class<T: crate::obj::GodotClass>(fields: &[FieldLayout]) -> SharedClass {
+    SharedClass {
+        layout_hash: crate::registry::extensions::layout_hash::<T>(fields),
+        imported_token: T::__imported_token().expect("#[class(shared)] provides a token cache"),
+    }
+}
+
+/// Adds a configuration warning for an `#[export(required)]` property, if it is not assigned.
+pub fn push_required_warning<T: crate::registry::property::ExportRequired>(
+    warnings: &mut crate::builtin::PackedStringArray,
+    property: &str,
+    value: &T,
+) {
+    if !value.is_assigned() {
+        warnings.push(format!("Property \"{property}\" is required, but not assigned.").into());
+    }
+}
+
+/// Invokes a parameterless virtual method of a user class through its registered callback, like Godot does.
+///
+/// Integration tests use this for virtuals that the engine only calls in the editor, such as `_get_configuration_warnings`.
+#[cfg(feature = "trace")]
+pub fn call_virtual<T, R>(
+    object: &crate::obj::Gd<T>,
+    callback: sys::GDExtensionClassCallVirtual,
+) -> R
+where
+    T: crate::obj::GodotClass,
+    R: crate::meta::GodotType,
+{
+    let callback = callback.expect("class does not register this virtual method");
+    let storage = object
+        .raw
+        .storage()
+        .expect("object is a live user instance");
+    let instance =
+        storage as *const crate::storage::InstanceStorage<T> as sys::GDExtensionClassInstancePtr;
+
+    // SAFETY: `instance` is the instance pointer of a live object of class `T`, and the method takes no arguments. Like Godot, pass an
+    // initialized return value.
+    let ffi = unsafe {
+        <R::Ffi as sys::GodotFfi>::new_with_init(|ret| callback(instance, std::ptr::null(), ret))
+    };
+
+    R::try_from_ffi(ffi).expect("virtual method returns a valid value")
+}
+
+/// Whether a `#[class(lazy)]` class has been deferred at startup, regardless of whether it has been registered since.
+#[cfg(feature = "trace")]
+pub fn was_class_deferred(class_name: &str) -> bool {
+    crate::registry::class::was_class_deferred(class_name)
+}
+
+/// Names of the classes registered at `init_level`, in registration order.
+#[cfg(feature = "trace")]
+pub fn registration_order(init_level: crate::init::InitLevel) -> Vec<String> {
+    crate::registry::class::loaded_classes_in_order(init_level)
+        .into_iter()
+        .map(|class_name| class_name.to_string())
+        .collect()
+}
+
+/// Whether a class of this extension is registered as abstract base class, which the editor does not offer for instantiation.
+#[cfg(feature = "trace")]
+pub fn is_class_virtual(class_name: &str) -> bool {
+    crate::registry::class::is_class_virtual(class_name)
+}
+
+/// Adds the user instance to `Gd<T>`'s `Debug` output, for `#[class(debug)]`.
+///
+/// Does not panic if the instance is bound mutably. Nested objects (e.g. a `Gd` field of the instance) are printed without their instance,
+/// to avoid infinite recursion on reference cycles.
+pub fn fmt_debug_instance<T>(obj: &crate::obj::Gd<T>, s: &mut std::fmt::DebugStruct<'_, '_>)
+where
+    T: std::fmt::Debug + crate::obj::Bounds<Declarer = crate::obj::bounds::DeclUser>,
+{
+    thread_local! {
+        static IS_FORMATTING: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+    }
+
+    if IS_FORMATTING.with(|flag| flag.replace(true)) {
+        return;
+    }
+
+    match obj.raw.try_bind() {
+        Some(instance) => s.field("instance", &*instance),
+        None => s.field("instance", &format_args!("<bound>")),
+    };
+
+    IS_FORMATTING.with(|flag| flag.set(false));
+}
+
 #[cfg(since_api = "4.3")]
 pub unsafe fn has_virtual_script_method(
     object_ptr: sys::GDExtensionObjectPtr,
@@ -171,7 +272,8 @@ pub fn is_class_runtime(is_tool: bool) -> bool {
 struct GodotPanicInfo {
     line: u32,
     file: String,
-    //backtrace: Backtrace, // for future use
+    /// Only captured for [`PanicPolicy::ScriptError`], as it is expensive.
+    backtrace: Option<std::backtrace::Backtrace>,
 }
 
 pub fn extract_panic_message(err: Box<dyn std::any::Any + Send>) -> String {
@@ -206,6 +308,15 @@ pub(crate) fn has_error_print_level(level: u8) -> bool {
     ERROR_PRINT_LEVEL.load(atomic::Ordering::Relaxed) >= level
 }
 
+pub(crate) fn set_global_panic_policy(policy: PanicPolicy) -> PanicPolicy {
+    let prev = PANIC_POLICY.swap(policy.to_ord(), atomic::Ordering::Relaxed);
+    PanicPolicy::from_ord(prev)
+}
+
+pub(crate) fn global_panic_policy() -> PanicPolicy {
+    PanicPolicy::from_ord(PANIC_POLICY.load(atomic::Ordering::Relaxed))
+}
+
 /// Executes `code`. If a panic is thrown, it is caught and an error message is printed to Godot.
 ///
 /// Returns `Err(message)` if a panic occurred, and `Ok(result)` with the result of `code` otherwise.
@@ -215,18 +326,35 @@ where
     F: FnOnce() -> R + std::panic::UnwindSafe,
     S: std::fmt::Display,
 {
-    handle_panic_with_print(error_context, code, has_error_print_level(1))
+    handle_panic_with_policy(error_context, None, code)
+}
+
+/// Like [`handle_panic`], but with a per-method policy that overrides the global [`PanicPolicy`].
+pub fn handle_panic_with_policy<E, F, R, S>(
+    error_context: E,
+    policy: Option<PanicPolicy>,
+    code: F,
+) -> Result<R, String>
+where
+    E: FnOnce() -> S,
+    F: FnOnce() -> R + std::panic::UnwindSafe,
+    S: std::fmt::Display,
+{
+    let policy = policy.unwrap_or_else(global_panic_policy);
+    handle_panic_with_print(error_context, code, has_error_print_level(1), policy)
 }
 
 pub fn handle_varcall_panic<F, R>(
     call_ctx: &CallContext,
     out_err: &mut sys::GDExtensionCallError,
+    policy: Option<PanicPolicy>,
     code: F,
 ) where
     F: FnOnce() -> Result<R, CallError> + std::panic::UnwindSafe,
 {
+    let policy = policy.unwrap_or_else(global_panic_policy);
     let outcome: Result<Result<R, CallError>, String> =
-        handle_panic_with_print(|| call_ctx, code, false);
+        handle_panic_with_print(|| call_ctx, code, false, policy);
 
     let call_error = match outcome {
         // All good.
@@ -252,13 +380,19 @@ pub fn handle_varcall_panic<F, R>(
     //sys::interface_fn!(variant_new_nil)(sys::AsUninit::as_uninit(ret));
 }
 
-fn handle_panic_with_print<E, F, R, S>(error_context: E, code: F, print: bool) -> Result<R, String>
+fn handle_panic_with_print<E, F, R, S>(
+    error_context: E,
+    code: F,
+    print: bool,
+    policy: PanicPolicy,
+) -> Result<R, String>
 where
     E: FnOnce() -> S,
     F: FnOnce() -> R + std::panic::UnwindSafe,
     S: std::fmt::Display,
 {
     let info: Arc<Mutex<Option<GodotPanicInfo>>> = Arc::new(Mutex::new(None));
+    let capture_backtrace = policy == PanicPolicy::ScriptError;
 
     // Back up previous hook, set new one
     let prev_hook = std::panic::take_hook();
@@ -269,7 +403,7 @@ where
                 *info.lock().unwrap() = Some(GodotPanicInfo {
                     file: location.file().to_string(),
                     line: location.line(),
-                    //backtrace: Backtrace::capture(),
+                    backtrace: capture_backtrace.then(std::backtrace::Backtrace::force_capture),
                 });
             } else {
                 eprintln!("panic occurred, but can't get location information");
@@ -291,21 +425,47 @@ where
             let guard = info.lock().unwrap();
             let info = guard.as_ref().expect("no panic info available");
 
-            if print {
-                godot_error!(
-                    "Rust function panicked at {}:{}.\n  Context: {}",
-                    info.file,
-                    info.line,
-                    error_context()
-                );
-                //eprintln!("Backtrace:\n{}", info.backtrace);
-            }
-
             let msg = extract_panic_message(err);
             let msg = format_panic_message(msg);
 
-            if print {
-                godot_error!("{msg}");
+            match policy {
+                PanicPolicy::LogAndDefault => {
+                    if print {
+                        godot_error!(
+                            "Rust function panicked at {}:{}.\n  Context: {}",
+                            info.file,
+                            info.line,
+                            error_context()
+                        );
+                        godot_error!("{msg}");
+                    }
+                }
+                PanicPolicy::ScriptError => {
+                    // Script errors are reported even for varcalls (where `print` is false), since they carry the backtrace.
+                    if has_error_print_level(1) {
+                        let backtrace = info
+                            .backtrace
+                            .as_ref()
+                            .map(|bt| bt.to_string())
+                            .unwrap_or_default();
+
+                        godot_script_error!(
+                            "Rust function panicked at {}:{}.\n  Context: {}\n{msg}\nBacktrace:\n{backtrace}",
+                            info.file,
+                            info.line,
+                            error_context()
+                        );
+                    }
+                }
+                PanicPolicy::Abort => {
+                    let context = error_context();
+                    godot_error!(
+                        "Rust function panicked at {}:{}; aborting (panic policy).\n  Context: {context}\n{msg}",
+                        info.file,
+                        info.line,
+                    );
+                    std::process::abort();
+                }
             }
 
             Err(msg)
@@ -0,0 +1,203 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Statistics about how Godot invokes `#[func]` methods: through _ptrcall_ or _varcall_.
+//!
+//! Every `#[func]` is registered with two entry points, and the caller decides which one is used:
+//! - **ptrcall** passes each argument as a pointer to its native representation. No `Variant` is created, and the return value is
+//!   written in place. This is the path taken by callers that know the exact signature, e.g. other GDExtensions calling through
+//!   `object_method_bind_ptrcall`, or GDScript code in which the receiver and all arguments are statically typed.
+//! - **varcall** passes all arguments as `Variant`s, which need to be converted one by one. This is the fallback for dynamic calls:
+//!   untyped GDScript code, `Object.call()`, `Callable`s, signals and deferred calls.
+//!
+//! Since the choice is made on the calling side, a method that is meant to be fast can silently end up on the slow path, e.g. when a
+//! variable in GDScript loses its static type. In debug builds, godot-rust counts the calls through each path, so this can be verified:
+//!
+//! ```no_run
+//! use godot::register::call_stats;
+//!
+//! fn check_hot_paths() {
+//!     for stats in call_stats::all_call_stats() {
+//!         if stats.varcalls > 0 {
+//!             godot::global::godot_print!(
+//!                 "{}::{}: {} of {} calls through varcall",
+//!                 stats.class_name,
+//!                 stats.method_name,
+//!                 stats.varcalls,
+//!                 stats.total()
+//!             );
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! Individual methods can additionally be annotated with `#[func(expect_ptrcall)]`. The first varcall of such a method then prints an
+//! error to the Godot console, pointing to the caller that should be typed.
+//!
+//! In release builds, no statistics are collected: [`all_call_stats()`] returns an empty list, and [`method_call_stats()`] returns `None`.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+use crate::meta::{CallContext, ClassName};
+use crate::sys::Global;
+
+/// Registered counters, keyed by their address.
+static REGISTERED_COUNTERS: Global<HashMap<usize, RegisteredCounter>> = Global::default();
+
+/// Number of calls to one `#[func]`, split by calling convention.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MethodCallStats {
+    /// Name of the class, as known to Godot.
+    pub class_name: String,
+
+    /// Name of the method, as known to Godot (i.e. after `#[func(rename)]`).
+    pub method_name: String,
+
+    /// Number of calls through ptrcall.
+    pub ptrcalls: u64,
+
+    /// Number of calls through varcall.
+    pub varcalls: u64,
+}
+
+impl MethodCallStats {
+    /// Total number of calls.
+    pub fn total(&self) -> u64 {
+        self.ptrcalls + self.varcalls
+    }
+
+    /// Whether the method was called at least once, and never through varcall.
+    pub fn is_ptrcall_only(&self) -> bool {
+        self.ptrcalls > 0 && self.varcalls == 0
+    }
+}
+
+/// Returns the call statistics of all `#[func]` methods registered by this extension.
+///
+/// Methods with the most varcalls come first; methods with the same count are sorted by class and method name.
+pub fn all_call_stats() -> Vec<MethodCallStats> {
+    let mut stats = REGISTERED_COUNTERS
+        .lock()
+        .values()
+        .map(RegisteredCounter::stats)
+        .collect::<Vec<_>>();
+
+    stats.sort_by(|a, b| {
+        b.varcalls
+            .cmp(&a.varcalls)
+            .then_with(|| a.class_name.cmp(&b.class_name))
+            .then_with(|| a.method_name.cmp(&b.method_name))
+    });
+    stats
+}
+
+/// Returns the call statistics of a single method, or `None` if this extension registered no such `#[func]`.
+pub fn method_call_stats(class_name: &str, method_name: &str) -> Option<MethodCallStats> {
+    REGISTERED_COUNTERS
+        .lock()
+        .values()
+        .find(|registered| {
+            registered.method_name == method_name && registered.class_name.to_string() == class_name
+        })
+        .map(RegisteredCounter::stats)
+}
+
+/// Resets the statistics of all methods to zero, e.g. to measure a specific part of the game.
+pub fn reset_call_stats() {
+    for registered in REGISTERED_COUNTERS.lock().values() {
+        registered.counter.reset();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+struct RegisteredCounter {
+    class_name: ClassName,
+    method_name: &'static str,
+    counter: &'static MethodCallCounter,
+}
+
+impl RegisteredCounter {
+    fn stats(&self) -> MethodCallStats {
+        MethodCallStats {
+            class_name: self.class_name.to_string(),
+            method_name: self.method_name.to_string(),
+            ptrcalls: self.counter.ptrcalls.load(Ordering::Relaxed),
+            varcalls: self.counter.varcalls.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Per-method counter, stored in a `static` generated by `#[godot_api]`.
+#[doc(hidden)]
+pub struct MethodCallCounter {
+    ptrcalls: AtomicU64,
+    varcalls: AtomicU64,
+    expect_ptrcall: bool,
+    has_reported_varcall: AtomicBool,
+}
+
+impl MethodCallCounter {
+    pub const fn new(expect_ptrcall: bool) -> Self {
+        Self {
+            ptrcalls: AtomicU64::new(0),
+            varcalls: AtomicU64::new(0),
+            expect_ptrcall,
+            has_reported_varcall: AtomicBool::new(false),
+        }
+    }
+
+    /// Makes the counter visible to [`all_call_stats()`]. Registering the same counter again has no effect.
+    ///
+    /// Does nothing in release builds, where calls are not counted.
+    pub fn register(&'static self, class_name: ClassName, method_name: &'static str) {
+        if !cfg!(debug_assertions) {
+            return;
+        }
+
+        REGISTERED_COUNTERS
+            .lock()
+            .entry(std::ptr::from_ref(self) as usize)
+            .or_insert(RegisteredCounter {
+                class_name,
+                method_name,
+                counter: self,
+            });
+    }
+
+    #[inline]
+    pub fn record_ptrcall(&self) {
+        #[cfg(debug_assertions)]
+        self.ptrcalls.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn record_varcall(&self, call_ctx: &CallContext) {
+        #[cfg(debug_assertions)]
+        {
+            self.varcalls.fetch_add(1, Ordering::Relaxed);
+
+            if self.expect_ptrcall && !self.has_reported_varcall.swap(true, Ordering::Relaxed) {
+                crate::global::godot_error!(
+                    "{call_ctx}: method is marked #[func(expect_ptrcall)], but was called through varcall.\n  \
+                    Make sure the caller knows the object's class and argument types statically (e.g. typed GDScript variables).\n  \
+                    Further varcalls of this method will not be reported."
+                );
+            }
+        }
+
+        #[cfg(not(debug_assertions))]
+        let _ = call_ctx;
+    }
+
+    fn reset(&self) {
+        self.ptrcalls.store(0, Ordering::Relaxed);
+        self.varcalls.store(0, Ordering::Relaxed);
+    }
+}
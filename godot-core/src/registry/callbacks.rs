@@ -12,10 +12,13 @@
 
 use crate::builder::ClassBuilder;
 use crate::builtin::{StringName, Variant};
+use crate::meta::ClassName;
 use crate::obj::{cap, Base, GodotClass, UserClass};
 use crate::storage::{as_storage, InstanceStorage, Storage, StorageRefCounted};
 use godot_ffi as sys;
 use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
 use sys::conv::u32_to_usize;
 use sys::interface_fn;
 
@@ -38,6 +41,9 @@ where
     T: GodotClass,
     F: FnOnce(Base<T::Base>) -> T,
 {
+    // Classes declared with #[class(lazy)] are registered on first instantiation.
+    crate::registry::class::ensure_class_registered(T::class_name());
+
     let base_class_name = T::Base::class_name();
 
     let base_ptr = unsafe { interface_fn!(classdb_construct_object)(base_class_name.string_sys()) };
@@ -68,7 +74,8 @@ where
     let base = unsafe { Base::from_sys(base_ptr) };
     let user_instance = make_user_instance(unsafe { Base::from_base(&base) });
 
-    let instance = InstanceStorage::<T>::construct(user_instance, base);
+    // SAFETY: the storage is moved to the heap right away, before anything can borrow the user instance.
+    let instance = unsafe { InstanceStorage::<T>::construct(user_instance, base) };
     let instance_ptr = instance.into_raw();
     let instance_ptr = instance_ptr as sys::GDExtensionClassInstancePtr;
 
@@ -77,7 +84,7 @@ where
         interface_fn!(object_set_instance)(base_ptr, class_name.string_sys(), instance_ptr);
         interface_fn!(object_set_instance_binding)(
             base_ptr,
-            sys::get_library() as *mut std::ffi::c_void,
+            crate::registry::extensions::binding_token::<T>(),
             instance_ptr as *mut std::ffi::c_void,
             &binding_data_callbacks,
         );
@@ -220,27 +227,76 @@ pub unsafe extern "C" fn get_property_list<T: cap::GodotGetPropertyList>(
         .map(|prop| prop.into_owned_property_sys())
         .collect();
 
+    let len: u32 = property_list_sys
+        .len()
+        .try_into()
+        .expect("property list cannot be longer than `u32::MAX`");
+
     // SAFETY: Godot ensures that `count` is initialized and valid to write into.
     unsafe {
-        *count = property_list_sys
-            .len()
-            .try_into()
-            .expect("property list cannot be longer than `u32::MAX`");
+        *count = len;
     }
 
-    Box::leak(property_list_sys).as_mut_ptr()
+    // Empty lists own no memory. Their dangling pointers would also all be the same, so they are not tracked for freeing.
+    if len == 0 {
+        return std::ptr::null();
+    }
+
+    let list = Box::leak(property_list_sys).as_mut_ptr();
+
+    #[cfg(before_api = "4.3")]
+    property_list_lengths::insert(list, len);
+
+    list
 }
 
 /// # Safety
 ///
 /// - Must only be called by Godot as a callback for `free_property_list` for a rust-defined class of type `T`.
 /// - Must only be passed to Godot as a callback when [`get_property_list`] is the corresponding `get_property_list` callback.
+#[cfg(since_api = "4.3")]
 #[deny(unsafe_op_in_unsafe_fn)]
 pub unsafe extern "C" fn free_property_list<T: cap::GodotGetPropertyList>(
     _instance: sys::GDExtensionClassInstancePtr,
     list: *const sys::GDExtensionPropertyInfo,
     count: u32,
 ) {
+    // SAFETY: forwarded from the caller.
+    unsafe { free_property_list_with_count(list, count) }
+}
+
+/// # Safety
+///
+/// - Must only be called by Godot as a callback for `free_property_list` for a rust-defined class of type `T`.
+/// - Must only be passed to Godot as a callback when [`get_property_list`] is the corresponding `get_property_list` callback.
+#[cfg(before_api = "4.3")]
+#[deny(unsafe_op_in_unsafe_fn)]
+pub unsafe extern "C" fn free_property_list<T: cap::GodotGetPropertyList>(
+    _instance: sys::GDExtensionClassInstancePtr,
+    list: *const sys::GDExtensionPropertyInfo,
+) {
+    // Empty lists are handed out as null and not tracked, see `get_property_list`.
+    if list.is_null() {
+        return;
+    }
+
+    // Before Godot 4.3, the length is not passed back to us, so it is looked up from the time the list was created.
+    let count = property_list_lengths::remove(list);
+
+    // SAFETY: forwarded from the caller; `count` is the length that get_property_list() returned together with `list`.
+    unsafe { free_property_list_with_count(list, count) }
+}
+
+/// # Safety
+///
+/// `list` and `count` must have been returned by [`get_property_list`], and the list must not be freed more than once.
+#[deny(unsafe_op_in_unsafe_fn)]
+unsafe fn free_property_list_with_count(list: *const sys::GDExtensionPropertyInfo, count: u32) {
+    // Empty lists are handed out as null, see `get_property_list`.
+    if list.is_null() {
+        return;
+    }
+
     let list = list as *mut sys::GDExtensionPropertyInfo;
 
     // SAFETY: `list` comes from `get_property_list` above, and `count` also comes from the same function.
@@ -264,6 +320,28 @@ pub unsafe extern "C" fn free_property_list<T: cap::GodotGetPropertyList>(
     }
 }
 
+/// Lengths of property lists handed out to Godot, which are needed to free them again.
+#[cfg(before_api = "4.3")]
+mod property_list_lengths {
+    use godot_ffi as sys;
+    use std::collections::HashMap;
+    use sys::Global;
+
+    // Keyed by the list address; pointers are not Send.
+    static LENGTHS: Global<HashMap<usize, u32>> = Global::default();
+
+    pub(super) fn insert(list: *const sys::GDExtensionPropertyInfo, len: u32) {
+        LENGTHS.lock().insert(list as usize, len);
+    }
+
+    pub(super) fn remove(list: *const sys::GDExtensionPropertyInfo) -> u32 {
+        LENGTHS
+            .lock()
+            .remove(&(list as usize))
+            .expect("free_property_list called for a list not returned by get_property_list")
+    }
+}
+
 /// # Safety
 ///
 /// * `instance` must be a valid `T` instance pointer for the duration of this function call.
@@ -317,6 +395,45 @@ pub unsafe extern "C" fn property_get_revert<T: cap::GodotPropertyGetRevert>(
 
     sys::conv::SYS_TRUE
 }
+
+/// # Safety
+///
+/// - Must only be called by Godot as a callback for `property_can_revert` for a rust-defined class of type `T`.
+#[deny(unsafe_op_in_unsafe_fn)]
+pub unsafe extern "C" fn default_property_can_revert<T: cap::GodotPropertyDefault>(
+    _instance: sys::GDExtensionClassInstancePtr,
+    property_name: sys::GDExtensionConstStringNamePtr,
+) -> sys::GDExtensionBool {
+    // SAFETY: Godot provides us with a valid `StringName` pointer for the duration of this call.
+    let property = unsafe { StringName::borrow_string_sys(property_name) };
+    let revert = default_property_value::<T>(property);
+
+    sys::conv::bool_to_sys(revert.is_some())
+}
+
+/// # Safety
+///
+/// - Must only be called by Godot as a callback for `property_get_revert` for a rust-defined class of type `T`.
+#[deny(unsafe_op_in_unsafe_fn)]
+pub unsafe extern "C" fn default_property_get_revert<T: cap::GodotPropertyDefault>(
+    _instance: sys::GDExtensionClassInstancePtr,
+    property_name: sys::GDExtensionConstStringNamePtr,
+    ret: sys::GDExtensionVariantPtr,
+) -> sys::GDExtensionBool {
+    // SAFETY: Godot provides us with a valid `StringName` pointer for the duration of this call.
+    let property = unsafe { StringName::borrow_string_sys(property_name) };
+    let Some(revert) = default_property_value::<T>(property) else {
+        return sys::conv::SYS_FALSE;
+    };
+
+    // SAFETY: Godot provides us with a valid `Variant` pointer.
+    unsafe {
+        revert.move_into_var_ptr(ret);
+    }
+
+    sys::conv::SYS_TRUE
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Safe, higher-level methods
 
@@ -354,3 +471,41 @@ pub fn register_user_methods_constants<T: cap::ImplementsGodotApi>(_class_builde
     T::__register_methods();
     T::__register_constants();
 }
+
+/// Value of `property` right after the generated `init`, or `None` if `T` has no such `#[export]` field.
+///
+/// The field initializers (`#[init(default = ...)]` or `Default::default()`) are evaluated without constructing an instance, once per
+/// class on the first query. The values are cached until the class is unregistered.
+fn default_property_value<T: cap::GodotPropertyDefault>(property: &StringName) -> Option<Variant> {
+    #[cfg(before_api = "4.3")]
+    if crate::private::is_class_inactive(T::__config().is_tool) {
+        return None;
+    }
+
+    let class_name = T::class_name();
+    PROPERTY_DEFAULTS.with(|cache| {
+        if let Some(values) = cache.borrow().get(&class_name) {
+            return values.get(property).cloned();
+        }
+
+        // Not holding the borrow: initializers may query reverts of other classes.
+        let values: HashMap<StringName, Variant> =
+            T::__godot_property_defaults().into_iter().collect();
+        let value = values.get(property).cloned();
+        cache.borrow_mut().insert(class_name, values);
+
+        value
+    })
+}
+
+/// Drops the cached revert values of a class, before it is unregistered.
+pub(crate) fn clear_default_property_values(class_name: ClassName) {
+    PROPERTY_DEFAULTS.with(|cache| {
+        cache.borrow_mut().remove(&class_name);
+    });
+}
+
+thread_local! {
+    static PROPERTY_DEFAULTS: RefCell<HashMap<ClassName, HashMap<StringName, Variant>>> =
+        RefCell::default();
+}
@@ -7,13 +7,15 @@
 
 use std::collections::HashMap;
 use std::ptr;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use crate::init::InitLevel;
 use crate::meta::ClassName;
 use crate::obj::{cap, GodotClass};
 use crate::private::{ClassPlugin, PluginItem};
-use crate::registry::callbacks;
+use crate::registry::extensions::SharedClass;
 use crate::registry::plugin::ErasedRegisterFn;
+use crate::registry::{callbacks, extensions};
 use crate::{godot_error, sys};
 use sys::{interface_fn, out, Global, GlobalGuard, GlobalLockError};
 
@@ -34,6 +36,10 @@ pub struct LoadedClass {
     name: ClassName,
     #[cfg_attr(before_api = "4.1", allow(dead_code))]
     is_editor_plugin: bool,
+
+    /// Whether the class was registered as abstract base class, i.e. with `#[class(abstract)]`.
+    #[cfg(feature = "trace")]
+    is_virtual: bool,
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -44,10 +50,14 @@ struct ClassRegistrationInfo {
     parent_class_name: Option<ClassName>,
     // Following functions are stored separately, since their order matters.
     register_methods_constants_fn: Option<ErasedRegisterFn>,
+    /// From `#[godot_api(secondary)]` blocks, in unspecified order.
+    secondary_register_fns: Vec<ErasedRegisterFn>,
     register_properties_fn: Option<ErasedRegisterFn>,
     user_register_fn: Option<ErasedRegisterFn>,
     default_virtual_fn: sys::GDExtensionClassGetVirtual, // Option (set if there is at least one OnReady field)
     user_virtual_fn: sys::GDExtensionClassGetVirtual, // Option (set if there is a `#[godot_api] impl I*`)
+    default_property_can_revert_fn: sys::GDExtensionClassPropertyCanRevert, // Option (set if the class has exported fields and a generated init)
+    default_property_get_revert_fn: sys::GDExtensionClassPropertyGetRevert,
 
     /// Godot low-level class creation parameters.
     #[cfg(before_api = "4.2")]
@@ -61,6 +71,15 @@ struct ClassRegistrationInfo {
     init_level: InitLevel,
     is_editor_plugin: bool,
 
+    /// Whether `#[class(lazy)]` was used.
+    is_lazy: bool,
+
+    /// Layout hash and token cache, if `#[class(shared)]` was used.
+    shared_class: Option<SharedClass>,
+
+    /// Classes from `#[class(requires = [...])]`, which are registered before this one.
+    required_classes: Vec<ClassName>,
+
     /// Used to ensure that each component is only filled once.
     component_already_filled: [bool; 3],
 }
@@ -71,6 +90,14 @@ impl ClassRegistrationInfo {
 
         // Note: when changing this match, make sure the array has sufficient size.
         let index = match item {
+            // A class can implement any number of traits.
+            PluginItem::DynTraitImpl(_) => return,
+            // Persistence glue is independent of registration.
+            PluginItem::Persist(_) => return,
+            // Any number of secondary `#[godot_api]` blocks can be merged into the primary one.
+            PluginItem::InherentImpl {
+                is_secondary: true, ..
+            } => return,
             PluginItem::Struct { .. } => 0,
             PluginItem::InherentImpl { .. } => 1,
             PluginItem::ITraitImpl { .. } => 2,
@@ -131,15 +158,21 @@ pub fn register_class<
         class_name: T::class_name(),
         parent_class_name: Some(T::Base::class_name()),
         register_methods_constants_fn: None,
+        secondary_register_fns: Vec::new(),
         register_properties_fn: None,
         user_register_fn: Some(ErasedRegisterFn {
             raw: callbacks::register_class_by_builder::<T>,
         }),
         user_virtual_fn: None,
         default_virtual_fn: None,
+        default_property_can_revert_fn: None,
+        default_property_get_revert_fn: None,
         godot_params,
         init_level: T::INIT_LEVEL,
         is_editor_plugin: false,
+        is_lazy: false,
+        shared_class: None,
+        required_classes: Vec::new(),
         component_already_filled: Default::default(), // [false; N]
     });
 }
@@ -170,37 +203,404 @@ pub fn auto_register_classes(init_level: InitLevel) {
         fill_class_info(elem.item.clone(), class_info);
     });
 
+    // Lazy classes are deferred until first use. In the editor, all classes must be known up-front to appear in dialogs and scenes.
+    // Core-level classes are always registered eagerly, since the editor state cannot be queried yet.
+    let defer_lazy =
+        init_level != InitLevel::Core && !crate::classes::Engine::singleton().is_editor_hint();
+
     let mut loaded_classes_by_level = global_loaded_classes();
-    for info in map.into_values() {
-        let class_name = info.class_name;
-        out!("Register class:   {class_name} at level `{init_level:?}`");
-        let loaded_class = LoadedClass {
-            name: class_name,
-            is_editor_plugin: info.is_editor_plugin,
-        };
-        loaded_classes_by_level
-            .entry(init_level)
-            .or_default()
-            .push(loaded_class);
+    for info in order_by_requirements(map) {
+        if info.is_lazy && defer_lazy {
+            out!(
+                "Defer class:      {} at level `{init_level:?}`",
+                info.class_name
+            );
+            defer_class(init_level, info);
+            continue;
+        }
 
-        register_class_raw(info);
-        out!("Class {class_name} loaded");
+        register_loaded_class(init_level, info, &mut loaded_classes_by_level);
     }
 
     out!("All classes for level `{init_level:?}` auto-registered.");
 }
 
+/// Registers a class that has been collected from plugins, and remembers it for unregistration.
+fn register_loaded_class(
+    init_level: InitLevel,
+    info: ClassRegistrationInfo,
+    loaded_classes_by_level: &mut HashMap<InitLevel, Vec<LoadedClass>>,
+) {
+    let class_name = info.class_name;
+
+    // Required classes that are lazy (possibly from an earlier init level) must not be deferred any longer.
+    for &required in info.required_classes.iter() {
+        register_pending_class(required, loaded_classes_by_level);
+    }
+
+    // Another Rust extension may have registered a class with the same name; Godot would silently use only one of them.
+    if let Err(other_library) = extensions::claim_class(init_level, class_name) {
+        // Classes marked #[class(shared)] are instead used through the other library's registration.
+        if let Some(shared) = &info.shared_class {
+            match extensions::import_shared_class(init_level, class_name, shared) {
+                Ok(()) => out!("Import class:     {class_name} from {other_library}"),
+                Err(reason) => godot_error!(
+                    "shared class `{class_name}` cannot be imported from another Rust extension: {reason}.\n  \
+                    registered by:  {other_library}\n  \
+                    skipped in:     {own_library}\n\
+                    Both libraries must be built from the same class definition, godot-rust version and compiler.",
+                    own_library = extensions::library_path(),
+                ),
+            }
+            return;
+        }
+
+        godot_error!(
+            "class `{class_name}` is already registered by another Rust extension; skipping registration.\n  \
+            registered by:  {other_library}\n  \
+            skipped in:     {own_library}\n\
+            Consider #[class(rename = ...)] or ExtensionLibrary::class_name_prefix() in one of the libraries.",
+            own_library = extensions::library_path(),
+        );
+        return;
+    }
+
+    if let Some(shared) = &info.shared_class {
+        extensions::share_class(init_level, class_name, shared.layout_hash);
+    }
+
+    out!("Register class:   {class_name} at level `{init_level:?}`");
+    let loaded_class = LoadedClass {
+        name: class_name,
+        is_editor_plugin: info.is_editor_plugin,
+        #[cfg(feature = "trace")]
+        is_virtual: info.godot_params.is_virtual != 0,
+    };
+    loaded_classes_by_level
+        .entry(init_level)
+        .or_default()
+        .push(loaded_class);
+
+    #[cfg(feature = "register-profiling")]
+    let begin = std::time::Instant::now();
+
+    register_class_raw(info);
+
+    #[cfg(feature = "register-profiling")]
+    crate::init::profiling::record_class(class_name, init_level, begin.elapsed());
+
+    out!("Class {class_name} loaded");
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Lazy registration
+
+/// Class declared with `#[class(lazy)]`, whose registration is deferred until first use.
+struct PendingClass {
+    init_level: InitLevel,
+    info: ClassRegistrationInfo,
+}
+
+// SAFETY: the raw pointers in the creation info are only function pointers and null userdata. Registration happens on the main thread;
+// the mutex only protects against accidental concurrent access.
+unsafe impl Send for PendingClass {}
+
+static PENDING_CLASSES: Global<HashMap<ClassName, PendingClass>> = Global::default();
+
+/// Fast path for [`ensure_class_registered()`], avoiding the lock when no class is deferred. Kept in sync with `PENDING_CLASSES`.
+static HAS_PENDING_CLASSES: AtomicBool = AtomicBool::new(false);
+
+/// Classes that have been deferred at startup, whether or not they are registered by now. Lets tests check deferral independently of
+/// their order.
+#[cfg(feature = "trace")]
+static DEFERRED_CLASSES: Global<Vec<ClassName>> = Global::default();
+
+fn defer_class(init_level: InitLevel, info: ClassRegistrationInfo) {
+    let class_name = info.class_name;
+
+    PENDING_CLASSES
+        .lock()
+        .insert(class_name, PendingClass { init_level, info });
+    HAS_PENDING_CLASSES.store(true, Ordering::Release);
+
+    #[cfg(feature = "trace")]
+    DEFERRED_CLASSES.lock().push(class_name);
+}
+
+/// Removes the pending class `class_name`, if any.
+fn take_pending_class(class_name: ClassName) -> Option<PendingClass> {
+    let mut pending_classes = PENDING_CLASSES.lock();
+    let pending = pending_classes.remove(&class_name);
+    update_has_pending_classes(&pending_classes);
+
+    pending
+}
+
+fn update_has_pending_classes(pending_classes: &HashMap<ClassName, PendingClass>) {
+    HAS_PENDING_CLASSES.store(!pending_classes.is_empty(), Ordering::Release);
+}
+
+/// Whether class `class_name` was declared with `#[class(lazy)]` and deferred at startup.
+#[cfg(feature = "trace")]
+pub(crate) fn was_class_deferred(class_name: &str) -> bool {
+    DEFERRED_CLASSES
+        .lock()
+        .iter()
+        .any(|deferred| deferred.as_str() == class_name)
+}
+
+/// Registers class `class_name` now, if it was declared with `#[class(lazy)]` and has not been registered yet.
+///
+/// Called whenever Rust instantiates a class. Classes that are instantiated by Godot (e.g. in scenes or from GDScript) have to be
+/// registered before, see [`register_lazy_classes()`].
+///
+/// # Panics
+/// If the class is still deferred and this is not called on the main thread, since Godot only accepts registrations there.
+pub(crate) fn ensure_class_registered(class_name: ClassName) {
+    if !HAS_PENDING_CLASSES.load(Ordering::Acquire) {
+        return;
+    }
+
+    if !sys::is_main_thread() {
+        assert!(
+            !PENDING_CLASSES.lock().contains_key(&class_name),
+            "class `{class_name}` is declared with #[class(lazy)] and can only be registered on the main thread; \
+            call register_lazy_classes() before using it from other threads"
+        );
+        return;
+    }
+
+    register_pending_class(class_name, &mut global_loaded_classes());
+}
+
+/// Registers class `class_name` if it is currently deferred.
+fn register_pending_class(
+    class_name: ClassName,
+    loaded_classes_by_level: &mut HashMap<InitLevel, Vec<LoadedClass>>,
+) {
+    if !HAS_PENDING_CLASSES.load(Ordering::Acquire) {
+        return;
+    }
+
+    // Release the lock before registering, as registration runs user code.
+    let pending = take_pending_class(class_name);
+    if let Some(PendingClass { init_level, info }) = pending {
+        register_loaded_class(init_level, info, loaded_classes_by_level);
+    }
+}
+
+/// Registers all classes declared with `#[class(lazy)]` which have not been registered yet.
+///
+/// Lazy classes are registered automatically when they are first instantiated from Rust. Call this function before Godot itself needs
+/// such classes, e.g. before loading a scene that contains them, or before GDScript code refers to them.
+pub fn register_lazy_classes() {
+    let pending = {
+        let mut pending_classes = PENDING_CLASSES.lock();
+        let pending = std::mem::take(&mut *pending_classes);
+        update_has_pending_classes(&pending_classes);
+        pending
+    };
+
+    let mut init_levels = HashMap::new();
+    let infos = pending
+        .into_iter()
+        .map(|(class_name, PendingClass { init_level, info })| {
+            init_levels.insert(class_name, init_level);
+            (class_name, info)
+        })
+        .collect();
+
+    let mut loaded_classes_by_level = global_loaded_classes();
+    for info in order_by_requirements(infos) {
+        let init_level = init_levels[&info.class_name];
+        register_loaded_class(init_level, info, &mut loaded_classes_by_level);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Registration order
+
+/// Brings classes into registration order, see [`sort_by_requirements()`].
+///
+/// Classes in a requirement cycle, and classes requiring them, cannot be ordered; they are reported as an error and not registered.
+fn order_by_requirements(
+    mut map: HashMap<ClassName, ClassRegistrationInfo>,
+) -> Vec<ClassRegistrationInfo> {
+    let classes = map
+        .values()
+        .map(|info| {
+            let required = info
+                .required_classes
+                .iter()
+                .map(ClassName::as_str)
+                .collect();
+            (info.class_name.as_str(), required)
+        })
+        .collect::<Vec<_>>();
+
+    let (order, cycle) = sort_by_requirements(classes);
+
+    let mut by_name = map
+        .drain()
+        .map(|(class_name, info)| (class_name.as_str(), info))
+        .collect::<HashMap<_, _>>();
+
+    let ordered = order
+        .into_iter()
+        .map(|name| by_name.remove(name).expect("class ordered twice"))
+        .collect();
+
+    if let Some(cycle) = cycle {
+        let mut skipped = by_name.into_keys().collect::<Vec<_>>();
+        skipped.sort_unstable();
+
+        godot_error!(
+            "#[class(requires)] forms a cycle: {}.\n  \
+            The following classes are not registered: {}.",
+            cycle.join(" -> "),
+            skipped.join(", ")
+        );
+    }
+
+    ordered
+}
+
+/// Orders classes such that each comes after the classes it requires; otherwise alphabetically.
+///
+/// Requirements that are not part of `classes` are ignored, as they belong to other init levels (or are engine classes). This makes
+/// registration independent of the order in which the linker collects plugins.
+///
+/// If there is a cycle, the classes in it and those (transitively) requiring them are left out of the order, and one cycle is returned as
+/// the second element (first class repeated at the end).
+fn sort_by_requirements<K>(mut classes: Vec<(K, Vec<K>)>) -> (Vec<K>, Option<Vec<K>>)
+where
+    K: Copy + Ord + std::hash::Hash,
+{
+    use std::collections::{BTreeSet, HashSet};
+
+    classes.sort_unstable_by_key(|(name, _)| *name);
+    let known = classes
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<HashSet<_>>();
+
+    // Number of unregistered requirements per class, and reverse edges.
+    let mut pending_count = HashMap::new();
+    let mut dependents = HashMap::<K, Vec<K>>::new();
+    for (name, required) in classes.iter() {
+        let required = required
+            .iter()
+            .filter(|req| known.contains(*req))
+            .collect::<HashSet<_>>();
+
+        pending_count.insert(*name, required.len());
+        for req in required {
+            dependents.entry(*req).or_default().push(*name);
+        }
+    }
+
+    // Kahn's algorithm; the BTreeSet yields the alphabetically smallest class among those that are ready.
+    let mut ready = pending_count
+        .iter()
+        .filter(|(_, count)| **count == 0)
+        .map(|(name, _)| *name)
+        .collect::<BTreeSet<_>>();
+
+    let mut order = Vec::with_capacity(classes.len());
+    while let Some(name) = ready.pop_first() {
+        order.push(name);
+
+        for dependent in dependents.get(&name).into_iter().flatten() {
+            let count = pending_count.get_mut(dependent).unwrap();
+            *count -= 1;
+            if *count == 0 {
+                ready.insert(*dependent);
+            }
+        }
+    }
+
+    if order.len() == classes.len() {
+        return (order, None);
+    }
+
+    // Every remaining class has a remaining requirement; following them must eventually revisit a class.
+    let remaining = |name: &K| pending_count[name] > 0;
+    let requirements = classes.iter().cloned().collect::<HashMap<_, _>>();
+
+    let start = classes
+        .iter()
+        .map(|(name, _)| *name)
+        .find(remaining)
+        .unwrap();
+    let mut path = vec![start];
+    let cycle = loop {
+        let current = *path.last().unwrap();
+        let next = requirements[&current]
+            .iter()
+            .copied()
+            .filter(|req| known.contains(req) && remaining(req))
+            .min()
+            .unwrap();
+
+        if let Some(pos) = path.iter().position(|name| *name == next) {
+            let mut cycle = path.split_off(pos);
+            cycle.push(next);
+            break cycle;
+        }
+        path.push(next);
+    };
+
+    (order, Some(cycle))
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 pub fn unregister_classes(init_level: InitLevel) {
+    // Lazy classes which were never used need no unregistration.
+    {
+        let mut pending_classes = PENDING_CLASSES.lock();
+        pending_classes.retain(|_, pending| pending.init_level != init_level);
+        update_has_pending_classes(&pending_classes);
+    }
+
     let mut loaded_classes_by_level = global_loaded_classes();
     let loaded_classes_current_level = loaded_classes_by_level
         .remove(&init_level)
         .unwrap_or_default();
     out!("Unregistering classes of level {init_level:?}...");
-    for class_name in loaded_classes_current_level.into_iter().rev() {
-        unregister_class_raw(class_name);
+    extensions::release_imported_classes(init_level);
+    for loaded_class in loaded_classes_current_level.into_iter().rev() {
+        extensions::release_class(init_level, loaded_class.name);
+        unregister_class_raw(loaded_class);
     }
 }
 
+/// Names of the classes registered by this extension at `init_level`, in registration order.
+#[cfg(feature = "trace")]
+pub(crate) fn loaded_classes_in_order(init_level: InitLevel) -> Vec<ClassName> {
+    global_loaded_classes()
+        .get(&init_level)
+        .map(|classes| classes.iter().map(|class| class.name).collect())
+        .unwrap_or_default()
+}
+
+/// Whether class `class_name` is currently registered as abstract base class ("virtual" in Godot).
+#[cfg(feature = "trace")]
+pub(crate) fn is_class_virtual(class_name: &str) -> bool {
+    global_loaded_classes()
+        .values()
+        .flatten()
+        .any(|class| class.is_virtual && class.name.as_str() == class_name)
+}
+
+/// Names and init levels of all classes currently registered by this extension.
+pub(crate) fn loaded_classes() -> Vec<(ClassName, InitLevel)> {
+    global_loaded_classes()
+        .iter()
+        .flat_map(|(init_level, classes)| classes.iter().map(|class| (class.name, *init_level)))
+        .collect()
+}
+
 fn global_loaded_classes() -> GlobalGuard<'static, HashMap<InitLevel, Vec<LoadedClass>>> {
     match LOADED_CLASSES.try_lock() {
         Ok(it) => it,
@@ -228,15 +628,26 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             register_properties_fn,
             free_fn,
             default_get_virtual_fn,
+            default_property_can_revert_fn,
+            default_property_get_revert_fn,
             is_tool,
             is_editor_plugin,
             is_hidden,
             is_instantiable,
+            is_lazy,
+            is_abstract,
+            shared_class_fn,
+            required_classes,
         } => {
             c.parent_class_name = Some(base_class_name);
             c.default_virtual_fn = default_get_virtual_fn;
+            c.default_property_can_revert_fn = default_property_can_revert_fn;
+            c.default_property_get_revert_fn = default_property_get_revert_fn;
             c.register_properties_fn = Some(register_properties_fn);
             c.is_editor_plugin = is_editor_plugin;
+            c.is_lazy = is_lazy;
+            c.shared_class = shared_class_fn.map(|shared_class| shared_class());
+            c.required_classes = required_classes;
 
             // Classes marked #[class(no_init)] are translated to "abstract" in Godot. This disables their default constructor.
             // "Abstract" is a misnomer -- it's not an abstract base class, but rather a "utility/static class" (although it can have instance
@@ -247,6 +658,10 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             //
             // See also: https://github.com/godotengine/godot/pull/58972
             c.godot_params.is_abstract = sys::conv::bool_to_sys(!is_instantiable);
+
+            // #[class(abstract)] maps to Godot's "virtual": the class cannot be instantiated by name, but scripts can inherit it. Unlike
+            // with `no_init`, the constructor is kept, since instances of derived scripts are created through it.
+            c.godot_params.is_virtual = sys::conv::bool_to_sys(is_abstract);
             c.godot_params.free_instance_func = Some(free_fn);
 
             fill_into(
@@ -282,8 +697,13 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
 
         PluginItem::InherentImpl {
             register_methods_constants_fn,
+            is_secondary,
         } => {
-            c.register_methods_constants_fn = Some(register_methods_constants_fn);
+            if is_secondary {
+                c.secondary_register_fns.push(register_methods_constants_fn);
+            } else {
+                c.register_methods_constants_fn = Some(register_methods_constants_fn);
+            }
         }
 
         PluginItem::ITraitImpl {
@@ -325,6 +745,10 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             c.godot_params.property_get_revert_func = user_property_get_revert_fn;
             c.user_virtual_fn = Some(get_virtual_fn);
         }
+
+        PluginItem::DynTraitImpl(_) | PluginItem::Persist(_) => {
+            // Not needed for registration; looked up by DynGd and SaveGame at runtime.
+        }
     }
     // out!("|   reg (after):     {c:?}");
     // out!();
@@ -355,6 +779,12 @@ fn register_class_raw(mut info: ClassRegistrationInfo) {
         info.godot_params.get_virtual_func = info.user_virtual_fn.or(info.default_virtual_fn);
     }
 
+    // Same for reverting properties: a user-defined `property_get_revert` takes precedence over the field initializers.
+    if info.godot_params.property_get_revert_func.is_none() {
+        info.godot_params.property_can_revert_func = info.default_property_can_revert_fn;
+        info.godot_params.property_get_revert_func = info.default_property_get_revert_fn;
+    }
+
     // The explicit () type notifies us if Godot API ever adds a return type.
     let registration_failed = unsafe {
         // Try to register class...
@@ -410,6 +840,10 @@ fn register_class_raw(mut info: ClassRegistrationInfo) {
         (register_fn.raw)(&mut class_builder);
     }
 
+    for register_fn in &info.secondary_register_fns {
+        (register_fn.raw)(&mut class_builder);
+    }
+
     if let Some(register_fn) = info.register_properties_fn {
         (register_fn.raw)(&mut class_builder);
     }
@@ -427,6 +861,7 @@ fn register_class_raw(mut info: ClassRegistrationInfo) {
 fn unregister_class_raw(class: LoadedClass) {
     let class_name = class.name;
     out!("Unregister class: {class_name}");
+    callbacks::clear_default_property_values(class_name);
 
     // If class is an editor plugin, unregister that first.
     #[cfg(since_api = "4.1")]
@@ -459,13 +894,19 @@ fn default_registration_info(class_name: ClassName) -> ClassRegistrationInfo {
         class_name,
         parent_class_name: None,
         register_methods_constants_fn: None,
+        secondary_register_fns: Vec::new(),
         register_properties_fn: None,
         user_register_fn: None,
         default_virtual_fn: None,
         user_virtual_fn: None,
+        default_property_can_revert_fn: None,
+        default_property_get_revert_fn: None,
         godot_params: default_creation_info(),
         init_level: InitLevel::Scene,
         is_editor_plugin: false,
+        is_lazy: false,
+        shared_class: None,
+        required_classes: Vec::new(),
         component_already_filled: Default::default(), // [false; N]
     }
 }
@@ -549,3 +990,50 @@ fn default_creation_info() -> sys::GDExtensionClassCreationInfo3 {
         class_userdata: ptr::null_mut(),
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::sort_by_requirements;
+
+    #[test]
+    fn sort_alphabetical_without_requirements() {
+        let classes = vec![("Zebra", vec![]), ("Ant", vec![]), ("Moose", vec![])];
+
+        let (order, cycle) = sort_by_requirements(classes);
+        assert_eq!(order, ["Ant", "Moose", "Zebra"]);
+        assert_eq!(cycle, None);
+    }
+
+    #[test]
+    fn sort_requirements_first() {
+        let classes = vec![
+            ("Level", vec!["Tile", "Enemy"]),
+            ("Enemy", vec!["Weapon"]),
+            ("Tile", vec![]),
+            ("Weapon", vec!["Node"]), // Not in list (e.g. engine class) -> ignored.
+            ("Arena", vec![]),
+        ];
+
+        let (order, cycle) = sort_by_requirements(classes);
+        assert_eq!(order, ["Arena", "Tile", "Weapon", "Enemy", "Level"]);
+        assert_eq!(cycle, None);
+    }
+
+    #[test]
+    fn sort_reports_cycle() {
+        let classes = vec![
+            ("A", vec!["B"]),
+            ("B", vec!["C"]),
+            ("C", vec!["A"]),
+            ("D", vec!["C"]),
+            ("E", vec![]),
+        ];
+
+        // D requires a class of the cycle, so it is left out as well.
+        let (order, cycle) = sort_by_requirements(classes);
+        assert_eq!(order, ["E"]);
+        assert_eq!(cycle, Some(vec!["A", "B", "C", "A"]));
+    }
+}
@@ -60,6 +60,21 @@ pub enum ConstantKind {
 }
 
 impl ConstantKind {
+    /// Creates an enum or bitfield constant from a type annotated with `#[godot_enum]`.
+    pub fn from_enum<E: ExportEnum>() -> Self {
+        let name = StringName::from(E::ENUM_NAME);
+        let enumerators = E::enumerators();
+
+        if E::IS_BITFIELD {
+            ConstantKind::Bitfield {
+                name,
+                flags: enumerators,
+            }
+        } else {
+            ConstantKind::Enum { name, enumerators }
+        }
+    }
+
     fn register(&self, class_name: ClassName) {
         match self {
             ConstantKind::Integer(integer) => {
@@ -94,3 +109,17 @@ impl ExportConstant {
         self.kind.register(self.class_name)
     }
 }
+
+/// Rust enum which can be registered as a class-scoped enum or bitfield in Godot.
+///
+/// Implemented by the `#[godot_enum]` attribute; registered by listing the enum in `#[godot_api(enums = [...])]`.
+pub trait ExportEnum {
+    /// Name of the enum, as visible to GDScript (e.g. `MyClass.Direction`).
+    const ENUM_NAME: &'static str;
+
+    /// Whether the enum should be registered as a bitfield, i.e. its enumerators can be combined with `|`.
+    const IS_BITFIELD: bool;
+
+    /// All enumerators, in declaration order.
+    fn enumerators() -> Vec<IntegerConstant>;
+}
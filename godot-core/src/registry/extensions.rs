@@ -0,0 +1,527 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Coexistence of multiple Rust GDExtensions in the same Godot process.
+//!
+//! Every Rust extension has its own plugin registry, so two libraries are not aware of each other's classes. If both register a class
+//! with the same name, Godot ends up with only one of them -- typically leading to confusing errors far from the actual cause.
+//!
+//! To detect this, classes registered by godot-rust are additionally recorded in a process-wide table, which is shared between all
+//! Rust extensions (it is stored as metadata on the `Engine` singleton). During registration, a class whose name was already claimed
+//! by another library is skipped, and an error naming both libraries is printed. The same table can be queried through this module,
+//! e.g. to check whether a class from a companion extension is available before instantiating it with `ClassDb`.
+//!
+//! ```no_run
+//! use godot::register::extensions;
+//!
+//! match extensions::find_rust_class("InventoryItem") {
+//!     Some(class) if !class.is_own() => {
+//!         godot::global::godot_print!("InventoryItem provided by {}", class.library_path);
+//!     }
+//!     Some(_) => {}
+//!     None => godot::global::godot_warn!("inventory extension not loaded"),
+//! }
+//! ```
+//!
+//! Classes registered at [`InitLevel::Core`] are not recorded, since the `Engine` singleton is not yet accessible at that point.
+//! Before Godot 4.1, library paths are not available, so libraries cannot be told apart; classes are not recorded either, and duplicates
+//! are not detected.
+//!
+//! Similarly, each library publishes its [`LibraryMetadata`] once the `Scene` level is loaded, which can be queried with
+//! [`rust_libraries()`] -- for example, to check that a companion extension has a compatible version.
+//!
+//! # Shared classes
+//!
+//! A game may split its Rust code across multiple extensions, which use common classes from a shared crate. Normally, each library
+//! registers its own copy of such a class, and the second registration is rejected as described above. If the class is declared with
+//! `#[class(shared)]`, the second library instead _imports_ the class: it does not register anything with Godot, but operates on the
+//! instances of the first library. This makes `Gd<T>::bind()` and `bind_mut()` work across library boundaries, without going through
+//! `Variant` reflection.
+//!
+//! Importing is only sound if both libraries agree on the memory layout of the instances. To catch mismatches, each shared class is
+//! published with a hash of its type name, size and alignment, the name, type, offset and size of each field, as well as the Rust
+//! compiler version, godot-rust version and relevant Cargo features. If the hashes do not match, the class is not imported and an
+//! error is printed.
+//!
+//! Types enter the hash through their [`TypeId`], which differs between versions, feature sets and compiler versions of the crate
+//! declaring the type. Layouts of nested field types are therefore covered as well, as long as they are declared in crates managed
+//! by Cargo. What remains undetected are changes to a type's source without a version bump (e.g. an edited path dependency), as well
+//! as compiler flags that affect layouts, such as `-Z randomize-layout`. All libraries must therefore be built from the same class
+//! definition and toolchain (and use the same allocator, if instances own heap memory).
+//!
+//! Further requirements:
+//! - The library registering the class must be loaded first. Imports happen during registration, so [`is_imported()`] can be used
+//!   afterwards to check the outcome.
+//! - Shared classes should not be prefixed with [`ExtensionLibrary::class_name_prefix()`][crate::init::ExtensionLibrary::class_name_prefix],
+//!   e.g. by declaring a `#[class(namespace = ...)]`, so that names agree across libraries.
+//! - Shared classes cannot be registered at [`InitLevel::Core`].
+
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::builtin::{Dictionary, GString, PackedStringArray, StringName};
+use crate::classes::Engine;
+use crate::init::{InitLevel, LibraryMetadata};
+use crate::meta::{ClassName, ToGodot};
+use crate::sys;
+use sys::Global;
+
+/// Shared classes imported from other libraries. Their library tokens are stored in [`SharedClass::imported_token`].
+static IMPORTED_CLASSES: Global<HashMap<ClassName, ImportedClass>> = Global::default();
+
+/// Metadata key on the `Engine` singleton, under which the shared table is stored.
+///
+/// Part of the protocol between different godot-rust versions; do not change.
+const REGISTRY_META_KEY: &str = "__godot_rust_classes";
+
+/// Metadata key on the `Engine` singleton for the table of libraries: path -> `{ name, version, godot_rust_version }`.
+const LIBRARY_META_KEY: &str = "__godot_rust_libraries";
+
+/// Metadata key on the `Engine` singleton for the table of `#[class(shared)]` classes: class name -> `{ library_path, token, layout }`.
+const SHARED_META_KEY: &str = "__godot_rust_shared_classes";
+
+/// Metadata key on the `Engine` singleton for the paths of libraries that are currently hot-reloaded.
+const RELOAD_META_KEY: &str = "__godot_rust_reloading";
+
+/// Returned by [`library_path()`] before Godot 4.1.
+const UNKNOWN_PATH: &str = "<unknown>";
+
+/// Class registered by a Rust GDExtension -- either this one or another library loaded in the same process.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RustClass {
+    /// Name of the class, as known to Godot.
+    pub class_name: String,
+
+    /// Path to the dynamic library which registered the class.
+    ///
+    /// Before Godot 4.1, the path is not available and this is `"<unknown>"`.
+    pub library_path: String,
+}
+
+impl RustClass {
+    /// Whether the class was registered by the calling library.
+    pub fn is_own(&self) -> bool {
+        self.library_path == library_path()
+    }
+}
+
+/// Returns all classes currently registered by Rust extensions, including this one, sorted by class name.
+pub fn rust_classes() -> Vec<RustClass> {
+    let table = load_dictionary(REGISTRY_META_KEY);
+
+    let mut classes = table
+        .iter_shared()
+        .map(|(class_name, library_path)| RustClass {
+            class_name: class_name.to_string(),
+            library_path: library_path.to_string(),
+        })
+        .collect::<Vec<_>>();
+
+    classes.sort_by(|a, b| a.class_name.cmp(&b.class_name));
+    classes
+}
+
+/// Looks up a class registered by any Rust extension (including this one). Returns `None` for classes not registered by godot-rust.
+pub fn find_rust_class(class_name: &str) -> Option<RustClass> {
+    let library_path = load_dictionary(REGISTRY_META_KEY).get(class_name)?;
+
+    Some(RustClass {
+        class_name: class_name.to_string(),
+        library_path: library_path.to_string(),
+    })
+}
+
+/// Rust extension library loaded in the process -- either this one or another one.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RustLibrary {
+    /// Name of the library, see [`LibraryMetadata::name`].
+    pub name: String,
+
+    /// Version of the library, see [`LibraryMetadata::version`].
+    pub version: String,
+
+    /// Version of godot-rust which the library was compiled with.
+    pub godot_rust_version: String,
+
+    /// Path to the dynamic library, or `"<unknown>"` before Godot 4.1.
+    pub library_path: String,
+}
+
+impl RustLibrary {
+    /// Whether this is the calling library.
+    pub fn is_own(&self) -> bool {
+        self.library_path == library_path()
+    }
+}
+
+/// Returns all Rust extensions which have loaded the `Scene` level, including this one, sorted by name.
+pub fn rust_libraries() -> Vec<RustLibrary> {
+    let mut libraries = load_dictionary(LIBRARY_META_KEY)
+        .iter_shared()
+        .filter_map(|(library_path, info)| {
+            let info = info.try_to::<Dictionary>().ok()?;
+            let field = |key: &str| info.get(key).map(|value| value.stringify().to_string());
+
+            Some(RustLibrary {
+                name: field("name")?,
+                version: field("version")?,
+                godot_rust_version: field("godot_rust_version").unwrap_or_default(),
+                library_path: library_path.stringify().to_string(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    libraries.sort_by(|a, b| a.name.cmp(&b.name));
+    libraries
+}
+
+/// Looks up a loaded Rust extension by its [name](LibraryMetadata::name).
+pub fn find_rust_library(name: &str) -> Option<RustLibrary> {
+    rust_libraries()
+        .into_iter()
+        .find(|library| library.name == name)
+}
+
+/// Path to the dynamic library of the calling extension, or `"<unknown>"` before Godot 4.1.
+pub fn library_path() -> String {
+    #[cfg(since_api = "4.1")]
+    {
+        let path = unsafe {
+            GString::new_with_string_uninit(|ptr| {
+                crate::sys::interface_fn!(get_library_path)(crate::sys::get_library(), ptr)
+            })
+        };
+        path.to_string()
+    }
+
+    #[cfg(before_api = "4.1")]
+    {
+        String::from(UNKNOWN_PATH)
+    }
+}
+
+/// Whether `class_name` is a `#[class(shared)]` class of this library, which was imported from another library instead of being
+/// registered. See [module docs](self#shared-classes).
+pub fn is_imported(class_name: &str) -> bool {
+    IMPORTED_CLASSES
+        .lock()
+        .keys()
+        .any(|imported| imported.to_string() == class_name)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Crate-internal registration
+
+/// Records `class_name` as registered by this library.
+///
+/// Returns `Err` with the path of the other library, if the class is already claimed by a different one. Re-registration by the same
+/// library (e.g. hot reload) is fine. Without known library paths (before Godot 4.1), this always succeeds.
+pub(crate) fn claim_class(init_level: InitLevel, class_name: ClassName) -> Result<(), String> {
+    let own_path = library_path();
+
+    // Before Godot 4.1, all libraries report the same unknown path, so classes cannot be attributed to a library.
+    if init_level == InitLevel::Core || own_path == UNKNOWN_PATH {
+        return Ok(());
+    }
+
+    let key = class_name.to_string();
+    let mut table = load_dictionary(REGISTRY_META_KEY);
+
+    if let Some(existing) = table.get(key.as_str()) {
+        let existing = existing.to_string();
+        if existing != own_path {
+            return Err(existing);
+        }
+    }
+
+    table.set(key, own_path);
+    store_dictionary(REGISTRY_META_KEY, table);
+    Ok(())
+}
+
+/// Removes `class_name` from the shared table, if it was registered by this library.
+pub(crate) fn release_class(init_level: InitLevel, class_name: ClassName) {
+    if init_level == InitLevel::Core || library_path() == UNKNOWN_PATH {
+        return;
+    }
+
+    let key = class_name.to_string();
+    let mut table = load_dictionary(REGISTRY_META_KEY);
+
+    let is_own = table
+        .get(key.as_str())
+        .is_some_and(|path| path.to_string() == library_path());
+
+    if is_own {
+        table.remove(key.as_str());
+        store_dictionary(REGISTRY_META_KEY, table);
+
+        let mut shared = load_dictionary(SHARED_META_KEY);
+        if shared.remove(key).is_some() {
+            store_dictionary(SHARED_META_KEY, shared);
+        }
+    }
+}
+
+/// Publishes a `#[class(shared)]` class registered by this library, so that other libraries can import it.
+pub(crate) fn share_class(init_level: InitLevel, class_name: ClassName, layout_hash: u64) {
+    if init_level == InitLevel::Core {
+        return;
+    }
+
+    let mut entry = Dictionary::new();
+    entry.set("library_path", library_path());
+    // SAFETY: the library is initialized during class registration.
+    let token = unsafe { sys::get_library() };
+    entry.set("token", token as usize as i64);
+    entry.set("layout", layout_hash as i64);
+
+    let mut table = load_dictionary(SHARED_META_KEY);
+    table.set(class_name.to_string(), entry);
+    store_dictionary(SHARED_META_KEY, table);
+}
+
+/// Uses the other library's registration of a `#[class(shared)]` class, if the layouts match.
+///
+/// Returns `Err` with the reason, if the class cannot be imported.
+pub(crate) fn import_shared_class(
+    init_level: InitLevel,
+    class_name: ClassName,
+    shared: &SharedClass,
+) -> Result<(), String> {
+    let entry = load_dictionary(SHARED_META_KEY)
+        .get(class_name.to_string())
+        .and_then(|entry| entry.try_to::<Dictionary>().ok())
+        .ok_or("the other library did not declare it #[class(shared)]")?;
+
+    let field = |key: &str| entry.get(key).and_then(|value| value.try_to::<i64>().ok());
+
+    if field("layout") != Some(shared.layout_hash as i64) {
+        return Err("the instance layouts differ".to_string());
+    }
+
+    let token = field("token").ok_or("the shared class table is corrupted")?;
+
+    shared
+        .imported_token
+        .store(token as usize, Ordering::Release);
+
+    IMPORTED_CLASSES.lock().insert(
+        class_name,
+        ImportedClass {
+            init_level,
+            token: shared.imported_token,
+        },
+    );
+    Ok(())
+}
+
+/// Forgets the classes imported at `init_level`, so that a later re-initialization starts over. Counterpart to [`release_class()`].
+pub(crate) fn release_imported_classes(init_level: InitLevel) {
+    IMPORTED_CLASSES.lock().retain(|_, imported| {
+        if imported.init_level != init_level {
+            return true;
+        }
+
+        imported.token.store(0, Ordering::Release);
+        false
+    });
+}
+
+/// Library token under which instances of `T` store their Rust part.
+///
+/// This is the calling library, unless the class was imported from another one.
+pub(crate) fn binding_token<T: crate::obj::GodotClass>() -> *mut std::ffi::c_void {
+    // Only #[class(shared)] classes have a token cache; it is 0 unless the class is imported.
+    if let Some(imported_token) = T::__imported_token() {
+        let token = imported_token.load(Ordering::Acquire);
+        if token != 0 {
+            return token as *mut std::ffi::c_void;
+        }
+    }
+
+    // SAFETY: the library is initialized while classes are in use.
+    unsafe { sys::get_library() as *mut std::ffi::c_void }
+}
+
+/// Hash identifying the memory layout of `T` instances with the given `fields`, see [module docs](self#shared-classes).
+pub(crate) fn layout_hash<T: crate::obj::GodotClass>(fields: &[FieldLayout]) -> u64 {
+    use crate::storage::InstanceStorage;
+
+    let mut layout = format!(
+        "{rustc};{version};{type_name};{size};{align};{storage_size};{storage_align};{threads};{debug}",
+        rustc = env!("GODOT_RUST_RUSTC_VERSION"),
+        version = env!("CARGO_PKG_VERSION"),
+        type_name = std::any::type_name::<T>(),
+        size = std::mem::size_of::<T>(),
+        align = std::mem::align_of::<T>(),
+        storage_size = std::mem::size_of::<InstanceStorage<T>>(),
+        storage_align = std::mem::align_of::<InstanceStorage<T>>(),
+        threads = cfg!(feature = "experimental-threads"),
+        debug = cfg!(debug_assertions),
+    );
+
+    for field in fields {
+        let FieldLayout {
+            name,
+            type_name,
+            offset,
+            size,
+            align,
+            ..
+        } = field;
+
+        write!(layout, ";{name}:{type_name}@{offset}+{size}/{align}").unwrap();
+    }
+
+    let mut hasher = FnvHasher::default();
+    layout.hash(&mut hasher);
+    TypeId::of::<T>().hash(&mut hasher);
+    for field in fields {
+        field.type_id.hash(&mut hasher);
+    }
+
+    hasher.finish()
+}
+
+/// FNV-1a, which (unlike std's hashers) is guaranteed to be stable across builds.
+struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+/// Registration info of a `#[class(shared)]` class, created by [`crate::private::shared_class()`].
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct SharedClass {
+    /// Compared between libraries before importing the class.
+    pub(crate) layout_hash: u64,
+
+    /// Library token of the other library, if the class is imported; otherwise 0. Per-class `static` generated by the macro.
+    pub(crate) imported_token: &'static AtomicUsize,
+}
+
+/// Layout of one field of a `#[class(shared)]` class, generated by `#[derive(GodotClass)]`.
+#[doc(hidden)]
+pub struct FieldLayout {
+    pub name: &'static str,
+    pub type_name: &'static str,
+    pub type_id: TypeId,
+    pub offset: usize,
+    pub size: usize,
+    pub align: usize,
+}
+
+struct ImportedClass {
+    init_level: InitLevel,
+    token: &'static AtomicUsize,
+}
+
+/// Publishes the metadata of this library.
+pub(crate) fn claim_library(metadata: &LibraryMetadata) {
+    let mut info = Dictionary::new();
+    info.set("name", metadata.name);
+    info.set("version", metadata.version);
+    info.set("godot_rust_version", env!("CARGO_PKG_VERSION"));
+
+    let mut table = load_dictionary(LIBRARY_META_KEY);
+    table.set(library_path(), info);
+    store_dictionary(LIBRARY_META_KEY, table);
+}
+
+/// Removes the metadata of this library.
+pub(crate) fn release_library() {
+    let mut table = load_dictionary(LIBRARY_META_KEY);
+    table.remove(library_path());
+    store_dictionary(LIBRARY_META_KEY, table);
+}
+
+/// Remembers that this library is being hot-reloaded. Stored on the engine, since the library's own state does not survive the reload.
+pub(crate) fn set_reload_mark() {
+    let key = StringName::from(RELOAD_META_KEY);
+    let mut engine = Engine::singleton();
+
+    let mut paths = engine
+        .get_meta_ex(key.clone())
+        .default(PackedStringArray::new().to_variant())
+        .done()
+        .try_to::<PackedStringArray>()
+        .unwrap_or_default();
+
+    paths.push(library_path().into());
+    engine.set_meta(key, paths.to_variant());
+}
+
+/// Returns whether this library has just been hot-reloaded, and clears the mark.
+pub(crate) fn take_reload_mark() -> bool {
+    let key = StringName::from(RELOAD_META_KEY);
+    let mut engine = Engine::singleton();
+
+    if !engine.has_meta(key.clone()) {
+        return false;
+    }
+
+    let mut paths = engine
+        .get_meta(key.clone())
+        .try_to::<PackedStringArray>()
+        .unwrap_or_default();
+
+    let Some(index) = paths.find(&library_path().into(), None) else {
+        return false;
+    };
+
+    paths.remove(index);
+    if paths.is_empty() {
+        engine.remove_meta(key);
+    } else {
+        engine.set_meta(key, paths.to_variant());
+    }
+
+    true
+}
+
+fn load_dictionary(meta_key: &str) -> Dictionary {
+    let engine = Engine::singleton();
+    let key = StringName::from(meta_key);
+
+    if engine.has_meta(key.clone()) {
+        engine
+            .get_meta(key)
+            .try_to::<Dictionary>()
+            .unwrap_or_default()
+    } else {
+        Dictionary::new()
+    }
+}
+
+fn store_dictionary(meta_key: &str, table: Dictionary) {
+    let key = StringName::from(meta_key);
+
+    if table.is_empty() {
+        Engine::singleton().remove_meta(key);
+    } else {
+        Engine::singleton().set_meta(key, table.to_variant());
+    }
+}
@@ -0,0 +1,295 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Typed bitmasks, exported to Godot as `PROPERTY_HINT_FLAGS`.
+//!
+//! A flags type is a fieldless enum with one variant per bit, deriving [`GodotFlags`][trait@GodotFlags]. Sets of these flags are stored
+//! in [`FlagSet<F>`], which shows up in the inspector as a list of checkboxes with the variant names.
+//!
+//! ```no_run
+//! use godot::prelude::*;
+//!
+//! #[derive(GodotFlags, Copy, Clone, Debug)]
+//! #[repr(u32)]
+//! enum Layer {
+//!     Terrain = 1 << 0,
+//!     Player = 1 << 1,
+//!     Enemies = 1 << 2,
+//! }
+//!
+//! #[derive(GodotClass)]
+//! #[class(init, base=Node)]
+//! struct Sensor {
+//!     #[export]
+//!     detects: FlagSet<Layer>,
+//!
+//!     // Existing `u32` fields can keep their type and only borrow the names.
+//!     #[export(flags = Layer)]
+//!     collides_with: u32,
+//! }
+//!
+//! fn setup(sensor: &mut Sensor) {
+//!     sensor.detects = Layer::Player | Layer::Enemies;
+//!     sensor.detects.remove(Layer::Enemies);
+//!
+//!     assert!(sensor.detects.contains(Layer::Player));
+//!     assert_eq!(sensor.detects.bits(), 0b010);
+//! }
+//! ```
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::{BitAnd, BitOr, BitOrAssign, Sub};
+
+use crate::meta::error::ConvertError;
+use crate::meta::{FromGodot, GodotConvert, ToGodot};
+use crate::registry::property::{export_info_functions, Export, PropertyHintInfo, Var};
+
+/// Enum whose variants are the individual bits of a bitmask.
+///
+/// Usually derived with `#[derive(GodotFlags)]` on a fieldless `#[repr(u32)]` enum, whose discriminants are the (non-zero) bit values.
+/// Variants may also combine multiple bits. The derive also implements `|` on the enum, producing a [`FlagSet`].
+pub trait GodotFlags: Copy + 'static {
+    /// All flags and their names, in declaration order.
+    const FLAGS: &'static [(&'static str, Self)];
+
+    /// Bits of this flag within the mask.
+    fn bits(self) -> u32;
+
+    /// Name of this flag, or `None` if it is not listed in [`FLAGS`][Self::FLAGS].
+    fn name(self) -> Option<&'static str> {
+        Self::FLAGS
+            .iter()
+            .find(|(_, flag)| flag.bits() == self.bits())
+            .map(|(name, _)| *name)
+    }
+}
+
+/// Set of flags of type `F`, stored as a `u32` bitmask.
+///
+/// Bits that do not correspond to any flag (e.g. set from GDScript) are retained, so that round-trips through the engine are lossless.
+pub struct FlagSet<F> {
+    bits: u32,
+    _flags: PhantomData<F>,
+}
+
+impl<F: GodotFlags> FlagSet<F> {
+    /// Set without any flags.
+    pub const fn empty() -> Self {
+        Self::from_bits(0)
+    }
+
+    /// Set containing all flags listed in [`GodotFlags::FLAGS`].
+    pub fn all() -> Self {
+        F::FLAGS.iter().map(|(_, flag)| *flag).collect()
+    }
+
+    /// Creates a set from a raw bitmask, retaining bits which do not correspond to any flag.
+    pub const fn from_bits(bits: u32) -> Self {
+        Self {
+            bits,
+            _flags: PhantomData,
+        }
+    }
+
+    /// The raw bitmask.
+    pub const fn bits(self) -> u32 {
+        self.bits
+    }
+
+    /// Whether no bit is set.
+    pub const fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+
+    /// Whether all bits of `flag` are set.
+    pub fn contains(self, flag: F) -> bool {
+        self.bits & flag.bits() == flag.bits()
+    }
+
+    /// Sets all bits of `flag`.
+    pub fn insert(&mut self, flag: F) {
+        self.bits |= flag.bits();
+    }
+
+    /// Clears all bits of `flag`.
+    pub fn remove(&mut self, flag: F) {
+        self.bits &= !flag.bits();
+    }
+
+    /// Flips all bits of `flag`.
+    pub fn toggle(&mut self, flag: F) {
+        self.bits ^= flag.bits();
+    }
+
+    /// Inserts or removes `flag`, depending on `enabled`.
+    pub fn set(&mut self, flag: F, enabled: bool) {
+        if enabled {
+            self.insert(flag);
+        } else {
+            self.remove(flag);
+        }
+    }
+
+    /// Returns the listed flags contained in this set, in declaration order.
+    pub fn iter(self) -> impl Iterator<Item = F> {
+        F::FLAGS
+            .iter()
+            .map(|(_, flag)| *flag)
+            .filter(move |flag| self.contains(*flag))
+    }
+}
+
+// Manual impls, to not require these traits on `F`.
+
+impl<F> Clone for FlagSet<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F> Copy for FlagSet<F> {}
+
+impl<F> PartialEq for FlagSet<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits == other.bits
+    }
+}
+
+impl<F> Eq for FlagSet<F> {}
+
+impl<F> Hash for FlagSet<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bits.hash(state);
+    }
+}
+
+impl<F: GodotFlags> Default for FlagSet<F> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<F: GodotFlags> fmt::Debug for FlagSet<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut remaining = self.bits;
+        let mut names = Vec::new();
+
+        for (name, flag) in F::FLAGS {
+            if self.contains(*flag) {
+                names.push(*name);
+                remaining &= !flag.bits();
+            }
+        }
+
+        write!(f, "FlagSet(")?;
+        if names.is_empty() && remaining == 0 {
+            write!(f, "empty")?;
+        }
+        write!(f, "{}", names.join(" | "))?;
+        if remaining != 0 {
+            let separator = if names.is_empty() { "" } else { " | " };
+            write!(f, "{separator}{remaining:#x}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl<F: GodotFlags> From<F> for FlagSet<F> {
+    fn from(flag: F) -> Self {
+        Self::from_bits(flag.bits())
+    }
+}
+
+impl<F: GodotFlags> FromIterator<F> for FlagSet<F> {
+    fn from_iter<I: IntoIterator<Item = F>>(iter: I) -> Self {
+        let mut set = Self::empty();
+        for flag in iter {
+            set.insert(flag);
+        }
+        set
+    }
+}
+
+impl<F: GodotFlags> BitOr for FlagSet<F> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self::from_bits(self.bits | rhs.bits)
+    }
+}
+
+impl<F: GodotFlags> BitOr<F> for FlagSet<F> {
+    type Output = Self;
+
+    fn bitor(self, rhs: F) -> Self {
+        Self::from_bits(self.bits | rhs.bits())
+    }
+}
+
+impl<F: GodotFlags> BitOrAssign<F> for FlagSet<F> {
+    fn bitor_assign(&mut self, rhs: F) {
+        self.insert(rhs);
+    }
+}
+
+impl<F: GodotFlags> BitAnd for FlagSet<F> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self::from_bits(self.bits & rhs.bits)
+    }
+}
+
+impl<F: GodotFlags> Sub for FlagSet<F> {
+    type Output = Self;
+
+    /// Set difference: flags in `self`, but not in `rhs`.
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_bits(self.bits & !rhs.bits)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Conversions and properties
+
+impl<F: GodotFlags> GodotConvert for FlagSet<F> {
+    type Via = u32;
+}
+
+impl<F: GodotFlags> ToGodot for FlagSet<F> {
+    fn to_godot(&self) -> Self::Via {
+        self.bits
+    }
+}
+
+impl<F: GodotFlags> FromGodot for FlagSet<F> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Ok(Self::from_bits(via))
+    }
+}
+
+impl<F: GodotFlags> Var for FlagSet<F> {
+    fn get_property(&self) -> Self::Via {
+        self.bits
+    }
+
+    fn set_property(&mut self, value: Self::Via) {
+        self.bits = value;
+    }
+
+    fn property_hint() -> PropertyHintInfo {
+        export_info_functions::export_flags_of::<F>()
+    }
+}
+
+impl<F: GodotFlags> Export for FlagSet<F> {
+    fn default_export_info() -> PropertyHintInfo {
+        Self::property_hint()
+    }
+}
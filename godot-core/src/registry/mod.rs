@@ -8,9 +8,14 @@
 // Note: final re-exports from godot-core are in lib.rs, mod private_register.
 // These are public here for simplicity, but many are not imported by the main crate.
 
+pub mod call_stats;
 pub mod callbacks;
 pub mod class;
 pub mod constant;
+pub mod extensions;
+pub mod flags;
 pub mod method;
 pub mod plugin;
 pub mod property;
+pub mod reflection;
+pub mod replication;
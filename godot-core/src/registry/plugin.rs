@@ -5,12 +5,17 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use std::any::Any;
+use std::any::{Any, TypeId};
 use std::fmt;
 
+use crate::builtin::Dictionary;
+use crate::classes::Object;
 use crate::init::InitLevel;
 use crate::meta::ClassName;
+use crate::obj::{bounds, AsDyn, Bounds, Gd};
+use crate::registry::extensions::SharedClass;
 use crate::sys;
+use crate::tools::persist::{Persist, PersistError};
 
 // TODO(bromeon): some information coming from the proc-macro API is deferred through PluginItem, while others is directly
 // translated to code. Consider moving more code to the PluginItem, which allows for more dynamic registration and will
@@ -85,6 +90,23 @@ pub enum PluginItem {
             ) -> sys::GDExtensionClassCallVirtual,
         >,
 
+        /// Reverts `#[export]` properties to their field initializers in the generated `init`. Used if there is no `#[godot_api] impl`
+        /// block overriding `property_get_revert`.
+        default_property_can_revert_fn: Option<
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                p_name: sys::GDExtensionConstStringNamePtr,
+            ) -> sys::GDExtensionBool,
+        >,
+
+        default_property_get_revert_fn: Option<
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                p_name: sys::GDExtensionConstStringNamePtr,
+                r_ret: sys::GDExtensionVariantPtr,
+            ) -> sys::GDExtensionBool,
+        >,
+
         /// Whether `#[class(tool)]` was used.
         is_tool: bool,
 
@@ -96,6 +118,18 @@ pub enum PluginItem {
 
         /// Whether the class has a default constructor.
         is_instantiable: bool,
+
+        /// Whether `#[class(lazy)]` was used.
+        is_lazy: bool,
+
+        /// Whether `#[class(abstract)]` was used.
+        is_abstract: bool,
+
+        /// Set if `#[class(shared)]` was used. Returns the layout hash, which must match across libraries, and the token cache.
+        shared_class_fn: Option<fn() -> SharedClass>,
+
+        /// Classes listed in `#[class(requires = [...])]`, which are registered before this class.
+        required_classes: Vec<ClassName>,
     },
 
     /// Collected from `#[godot_api] impl MyClass`.
@@ -104,6 +138,10 @@ pub enum PluginItem {
         ///
         /// Always present since that's the entire point of this `impl` block.
         register_methods_constants_fn: ErasedRegisterFn,
+
+        /// Whether the block is declared with `#[godot_api(secondary)]`. A class can have any number of secondary blocks, but only one
+        /// primary one.
+        is_secondary: bool,
     },
 
     /// Collected from `#[godot_api] impl I... for MyClass`.
@@ -181,8 +219,7 @@ pub enum PluginItem {
             ) -> *const sys::GDExtensionPropertyInfo,
         >,
 
-        // We do not support using this in Godot < 4.3, however it's easier to leave this in and fail elsewhere when attempting to use
-        // this in Godot < 4.3.
+        // Before Godot 4.3, the list length is not passed back, so the callback looks it up from the time the list was created.
         #[cfg(before_api = "4.3")]
         user_free_property_list_fn: Option<
             unsafe extern "C" fn(
@@ -214,4 +251,73 @@ pub enum PluginItem {
             ) -> sys::GDExtensionBool,
         >,
     },
+
+    /// Collected from `#[godot_dyn] impl Trait for MyClass`. Unlike the other items, a class can have several of these.
+    DynTraitImpl(DynTraitImpl),
+
+    /// Collected from `#[class(persist)]`.
+    Persist(PersistImpl),
+}
+
+/// Runtime glue for a Rust trait implemented by a user class, used by [`DynGd`][crate::obj::DynGd].
+#[derive(Clone, Debug)]
+pub struct DynTraitImpl {
+    /// `TypeId` of the `dyn Trait` type.
+    dyn_trait_typeid: TypeId,
+
+    /// Checks if the implementing class inherits the given class.
+    class_inherits_fn: fn(ClassName) -> bool,
+
+    /// Creates a `Box<Box<dyn DynBinder<D>>>` from an object of the implementing class.
+    erased_binder_fn: fn(Gd<Object>) -> Box<dyn Any>,
+}
+
+impl DynTraitImpl {
+    pub fn new<C, D>() -> Self
+    where
+        C: AsDyn<D> + Bounds<Declarer = bounds::DeclUser>,
+        D: ?Sized + 'static,
+    {
+        Self {
+            dyn_trait_typeid: TypeId::of::<D>(),
+            class_inherits_fn: crate::obj::dyn_gd::class_inherits::<C>,
+            erased_binder_fn: crate::obj::dyn_gd::make_erased_binder::<C, D>,
+        }
+    }
+
+    pub(crate) fn dyn_trait_typeid(&self) -> TypeId {
+        self.dyn_trait_typeid
+    }
+
+    pub(crate) fn inherits(&self, base: ClassName) -> bool {
+        (self.class_inherits_fn)(base)
+    }
+
+    pub(crate) fn make_binder(&self, obj: Gd<Object>) -> Box<dyn Any> {
+        (self.erased_binder_fn)(obj)
+    }
+}
+
+/// Runtime glue for a user class with `#[class(persist)]`, used by [`SaveGame`][crate::tools::persist::SaveGame].
+#[derive(Clone, Debug)]
+pub struct PersistImpl {
+    save_fn: fn(Gd<Object>) -> Dictionary,
+    load_fn: fn(Gd<Object>, &Dictionary) -> Result<(), PersistError>,
+}
+
+impl PersistImpl {
+    pub fn new<C: Persist>() -> Self {
+        Self {
+            save_fn: crate::tools::persist::save_erased::<C>,
+            load_fn: crate::tools::persist::load_erased::<C>,
+        }
+    }
+
+    pub(crate) fn save(&self, obj: Gd<Object>) -> Dictionary {
+        (self.save_fn)(obj)
+    }
+
+    pub(crate) fn load(&self, obj: Gd<Object>, state: &Dictionary) -> Result<(), PersistError> {
+        (self.load_fn)(obj, state)
+    }
 }
@@ -9,9 +9,9 @@
 
 use godot_ffi as sys;
 
-use crate::builtin::GString;
+use crate::builtin::{GString, Variant};
 use crate::global::PropertyHint;
-use crate::meta::{FromGodot, GodotConvert, GodotType, ToGodot};
+use crate::meta::{FromGodot, GodotConvert, GodotType, PropertyInfo, ToGodot};
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Trait definitions
@@ -38,6 +38,18 @@ pub trait Var: GodotConvert {
     fn property_hint() -> PropertyHintInfo {
         PropertyHintInfo::with_hint_none("")
     }
+
+    /// Creates a value from a property assignment, when there is no previous value to call [`set_property()`][Self::set_property] on.
+    ///
+    /// Used by `Option<Self>` properties that are currently `None`. Returns `None` if the assignment is rejected, in which case the
+    /// property keeps its old value.
+    #[doc(hidden)]
+    fn __new_from_property(value: Self::Via) -> Option<Self>
+    where
+        Self: FromGodot + Sized,
+    {
+        Some(FromGodot::from_godot(value))
+    }
 }
 
 /// Trait implemented for types that can be used as `#[export]` fields.
@@ -66,6 +78,73 @@ pub trait TypeStringHint {
     fn type_string() -> String;
 }
 
+/// Plain Rust struct whose fields can be flattened into the property list of a class, using `#[export(flatten)]`.
+///
+/// This trait is usually derived with `#[derive(ExportGroup)]`. Each field of the struct must implement [`Export`]; hints can be
+/// customized with the same `#[export(...)]` keys as on class fields.
+///
+/// A class field `movement: MovementSettings` annotated with `#[export(flatten)]` then exposes the properties `movement/speed`,
+/// `movement/accel` etc., which the editor displays as a group. This avoids creating a `Resource` subclass for simple bundles of settings.
+///
+/// Flattened groups require Godot 4.2 or later.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(ExportGroup, Default)]
+/// struct MovementSettings {
+///     #[export(range = (0.0, 100.0))]
+///     speed: f32,
+///     accel: f32,
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     #[export(flatten)]
+///     movement: MovementSettings,
+/// }
+/// ```
+pub trait ExportGroup {
+    /// Property infos of all fields, in declaration order. Names are not prefixed with the field name of the class.
+    fn group_properties() -> Vec<PropertyInfo>;
+
+    /// Returns the value of the field at `index`, corresponding to the position in [`group_properties()`][Self::group_properties].
+    fn get_group_property(&self, index: usize) -> Variant;
+
+    /// Sets the value of the field at `index`, corresponding to the position in [`group_properties()`][Self::group_properties].
+    ///
+    /// If `value` cannot be converted to the field's type, an error is printed and the field keeps its previous value.
+    fn set_group_property(&mut self, index: usize, value: Variant);
+}
+
+/// Types of fields that can be marked `#[export(required)]`.
+///
+/// While a required property is not assigned, the node displays a configuration warning in the scene dock. This requires the class
+/// to be a `#[class(tool)]`, as no code runs in the editor otherwise.
+#[diagnostic::on_unimplemented(
+    message = "`#[export(required)]` needs a type that can be unassigned, such as `Option<T>`",
+    label = "type does not implement `ExportRequired`"
+)]
+pub trait ExportRequired {
+    /// Whether the property has a value, i.e. no "not assigned" warning is shown.
+    fn is_assigned(&self) -> bool;
+}
+
+/// Types of fields that can be marked `#[export(node_path = (...))]`: `NodePath`, and `Array<NodePath>` or `Vec<NodePath>`.
+///
+/// The inspector then only lets the user pick nodes of the listed classes (or derived ones) in the scene tree. For collections, the
+/// filter applies to each element.
+#[diagnostic::on_unimplemented(
+    message = "`#[export(node_path)]` needs a `NodePath`, `Array<NodePath>` or `Vec<NodePath>` field",
+    label = "type does not implement `ExportNodePath`"
+)]
+pub trait ExportNodePath: Export {
+    /// Export info for a node path (or collection of them), which can only refer to nodes of the given comma-separated classes.
+    #[doc(hidden)]
+    fn node_path_export_info(valid_types: &str) -> PropertyHintInfo;
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Blanket impls for Option<T>
 
@@ -89,8 +168,8 @@ where
             Some(value) => {
                 if let Some(current_value) = self {
                     current_value.set_property(value)
-                } else {
-                    *self = Some(FromGodot::from_godot(value))
+                } else if let Some(new_value) = T::__new_from_property(value) {
+                    *self = Some(new_value)
                 }
             }
             None => *self = None,
@@ -108,6 +187,64 @@ where
     }
 }
 
+impl<T> ExportRequired for Option<T> {
+    fn is_assigned(&self) -> bool {
+        self.is_some()
+    }
+}
+
+macro_rules! impl_export_required_for_strings {
+    ($($Ty:ty),*) => {
+        $(
+            impl ExportRequired for $Ty {
+                fn is_assigned(&self) -> bool {
+                    !self.is_empty()
+                }
+            }
+        )*
+    };
+}
+
+impl_export_required_for_strings!(
+    GString,
+    crate::builtin::StringName,
+    crate::builtin::NodePath
+);
+
+impl ExportNodePath for crate::builtin::NodePath {
+    fn node_path_export_info(valid_types: &str) -> PropertyHintInfo {
+        PropertyHintInfo {
+            hint: PropertyHint::NODE_PATH_VALID_TYPES,
+            hint_string: valid_types.into(),
+        }
+    }
+}
+
+impl ExportNodePath for crate::builtin::Array<crate::builtin::NodePath> {
+    fn node_path_export_info(valid_types: &str) -> PropertyHintInfo {
+        use crate::builtin::VariantType;
+        use crate::obj::EngineEnum as _;
+
+        // Element hint of typed arrays: "{element_type}/{element_hint}:{element_hint_string}".
+        let hint_string = format!(
+            "{}/{}:{valid_types}",
+            VariantType::NODE_PATH.ord(),
+            PropertyHint::NODE_PATH_VALID_TYPES.ord()
+        );
+
+        PropertyHintInfo {
+            hint: PropertyHint::TYPE_STRING,
+            hint_string: hint_string.into(),
+        }
+    }
+}
+
+impl ExportNodePath for Vec<crate::builtin::NodePath> {
+    fn node_path_export_info(valid_types: &str) -> PropertyHintInfo {
+        crate::builtin::Array::<crate::builtin::NodePath>::node_path_export_info(valid_types)
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Export machinery
 
@@ -144,10 +281,14 @@ impl PropertyHintInfo {
 /// Each function is named the same as the equivalent Godot annotation.  
 /// For instance, `@export_range` in Godot is `fn export_range` here.
 pub mod export_info_functions {
+    use godot_ffi as sys;
+
     use crate::builtin::GString;
     use crate::global::PropertyHint;
+    use crate::meta::ClassName;
+    use crate::registry::flags::GodotFlags;
 
-    use super::PropertyHintInfo;
+    use super::{ExportNodePath, PropertyHintInfo, TypeStringHint};
 
     /// Turn a list of variables into a comma separated string containing only the identifiers corresponding
     /// to a true boolean variable.
@@ -299,6 +440,16 @@ pub mod export_info_functions {
         }
     }
 
+    /// Equivalent to `@export_flags` in Godot, with names and bits taken from a [`GodotFlags`][crate::registry::flags::GodotFlags] type.
+    pub fn export_flags_of<F: GodotFlags>() -> PropertyHintInfo {
+        let bits = F::FLAGS
+            .iter()
+            .map(|(name, flag)| (*name, Some(flag.bits())))
+            .collect::<Vec<_>>();
+
+        export_flags(&bits)
+    }
+
     /// Equivalent to `@export_file` in Godot.
     ///
     /// Pass an empty string to have no filter.
@@ -333,6 +484,56 @@ pub mod export_info_functions {
         }
     }
 
+    /// Equivalent to `@export_node_path` in Godot, for `NodePath` fields and collections of them.
+    ///
+    /// Only nodes inheriting one of `class_names` can be picked in the inspector. Pass an empty slice to allow any node.
+    pub fn export_node_path<T: ExportNodePath>(class_names: &[ClassName]) -> PropertyHintInfo {
+        let valid_types = class_names
+            .iter()
+            .map(ClassName::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+
+        T::node_path_export_info(&valid_types)
+    }
+
+    /// Equivalent to `@export var dict: Dictionary[K, V]` in Godot.
+    ///
+    /// Typed dictionaries are only available since Godot 4.4. When compiled against an earlier API version, this falls back to the
+    /// hint of an untyped `Dictionary`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use godot::builtin::GString;
+    /// # use godot::classes::Texture2D;
+    /// # use godot::obj::Gd;
+    /// # use godot::register::property::export_info_functions::export_typed_dictionary;
+    /// export_typed_dictionary::<GString, Option<Gd<Texture2D>>>();
+    /// ```
+    pub fn export_typed_dictionary<K, V>() -> PropertyHintInfo
+    where
+        K: TypeStringHint,
+        V: TypeStringHint,
+    {
+        let hint_info: PropertyHintInfo;
+
+        // Godot encodes the element types of exported typed dictionaries as "key_type_string;value_type_string".
+        #[cfg(since_api = "4.4")]
+        {
+            hint_info = PropertyHintInfo {
+                hint: PropertyHint::DICTIONARY_TYPE,
+                hint_string: format!("{};{}", K::type_string(), V::type_string()).into(),
+            };
+        }
+        #[cfg(before_api = "4.4")]
+        {
+            hint_info = PropertyHintInfo::with_hint_none("Dictionary");
+        }
+
+        hint_info
+    }
+
     macro_rules! default_export_funcs {
         (
             $( $function_name:ident => $property_hint:ident, )*
@@ -440,6 +641,29 @@ mod export_impls {
 
     impl_property_by_godot_convert!(Color);
 
+    // Rust strings are exported like GString, e.g. for `#[export(file)]` paths.
+    impl Var for String {
+        fn get_property(&self) -> Self::Via {
+            self.to_godot()
+        }
+
+        fn set_property(&mut self, value: Self::Via) {
+            *self = value.to_string();
+        }
+    }
+
+    impl Export for String {
+        fn default_export_info() -> PropertyHintInfo {
+            <GString as Export>::default_export_info()
+        }
+    }
+
+    impl TypeStringHint for String {
+        fn type_string() -> String {
+            <GString as TypeStringHint>::type_string()
+        }
+    }
+
     // Arrays
     // We manually implement `Export`.
     impl_property_by_godot_convert!(PackedByteArray, no_export);
@@ -491,6 +715,49 @@ mod export_impls {
     // impl_property_by_godot_convert!(Signal);
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Flattened groups (used by proc-macros)
+
+/// Registers the fields of `G` as indexed properties `{field_name}/{sub_field}` of a class.
+///
+/// The getter and setter must be registered methods of the class, taking the property index as the first parameter.
+#[doc(hidden)]
+#[cfg(since_api = "4.2")]
+pub fn register_export_group<G: ExportGroup>(
+    class_name: crate::meta::ClassName,
+    field_name: &str,
+    getter_name: &str,
+    setter_name: &str,
+) {
+    use crate::builtin::StringName;
+
+    let getter_name = StringName::from(getter_name);
+    let setter_name = StringName::from(setter_name);
+
+    for (index, property) in G::group_properties().into_iter().enumerate() {
+        let property_name = format!("{field_name}/{}", property.property_name);
+        let property_info = PropertyInfo {
+            property_name: property_name.into(),
+            ..property
+        };
+
+        let property_info_sys = property_info.property_sys();
+        let index = sys::GDExtensionInt::try_from(index).expect("too many properties in group");
+
+        // SAFETY: all pointers are valid for the duration of the call.
+        unsafe {
+            sys::interface_fn!(classdb_register_extension_class_property_indexed)(
+                sys::get_library(),
+                class_name.string_sys(),
+                std::ptr::addr_of!(property_info_sys),
+                setter_name.string_sys(),
+                getter_name.string_sys(),
+                index,
+            );
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Crate-local utilities
 
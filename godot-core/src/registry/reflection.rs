@@ -0,0 +1,334 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Runtime access to the classes registered by this extension, and their methods, signals, properties and constants.
+//!
+//! The metadata is read back from Godot's `ClassDB` after registration, so it reflects exactly what GDScript and the editor see --
+//! including renamed symbols and members added by the builder API. Unlike the `ClassDB` methods, the results are plain Rust structs.
+//!
+//! This enables tools that are driven by the registered API, such as developer consoles, cheat commands or RPC routers.
+//!
+//! ```no_run
+//! use godot::prelude::*;
+//! use godot::register::reflection;
+//!
+//! fn print_commands() {
+//!     let Some(class) = reflection::find_class("Cheats") else {
+//!         return;
+//!     };
+//!
+//!     for method in class.methods.iter().filter(|m| !m.name.starts_with('_')) {
+//!         let params = method.params.iter().map(|p| p.name.as_str()).collect::<Vec<_>>();
+//!         godot_print!("{}({})", method.name, params.join(", "));
+//!     }
+//! }
+//!
+//! fn run_command(cheats: Gd<Object>, command: &str, args: &[Variant]) -> Option<Variant> {
+//!     let class = reflection::find_class("Cheats")?;
+//!     let method = class.method(command)?;
+//!
+//!     if !method.accepts_arg_count(args.len()) {
+//!         return None;
+//!     }
+//!
+//!     Some(cheats.clone().call(command.into(), args))
+//! }
+//! ```
+//!
+//! Only classes which are registered at the time of the call are listed; classes with `#[class(lazy)]` may not be registered yet.
+
+use crate::builtin::{Dictionary, StringName, Variant, VariantArray, VariantType};
+use crate::classes::ClassDb;
+use crate::global::{MethodFlags, PropertyHint, PropertyUsageFlags};
+use crate::init::InitLevel;
+use crate::obj::{EngineBitfield, EngineEnum};
+use crate::registry::class;
+
+/// A class registered by this extension.
+#[derive(Clone, Debug)]
+pub struct ClassMetadata {
+    /// Name of the class in Godot.
+    pub name: String,
+
+    /// Name of the direct base class, e.g. `"Node"`.
+    pub base_class: String,
+
+    /// Level at which the class was registered.
+    pub init_level: InitLevel,
+
+    /// Methods declared by this class (not inherited), including `#[func]`s and overridden virtual methods.
+    pub methods: Vec<MethodMetadata>,
+
+    /// Signals declared by this class.
+    pub signals: Vec<SignalMetadata>,
+
+    /// Properties declared by this class, including groups and subgroups.
+    pub properties: Vec<ParamMetadata>,
+
+    /// Integer constants declared by this class, including enum and bitfield constants.
+    pub constants: Vec<ConstantMetadata>,
+}
+
+impl ClassMetadata {
+    /// Looks up a method by name.
+    pub fn method(&self, name: &str) -> Option<&MethodMetadata> {
+        self.methods.iter().find(|method| method.name == name)
+    }
+
+    /// Looks up a signal by name.
+    pub fn signal(&self, name: &str) -> Option<&SignalMetadata> {
+        self.signals.iter().find(|signal| signal.name == name)
+    }
+
+    /// Looks up a constant by name.
+    pub fn constant(&self, name: &str) -> Option<&ConstantMetadata> {
+        self.constants.iter().find(|constant| constant.name == name)
+    }
+}
+
+/// Method of a registered class.
+#[derive(Clone, Debug)]
+pub struct MethodMetadata {
+    /// Name of the method in Godot.
+    pub name: String,
+
+    /// Parameters, in order.
+    pub params: Vec<ParamMetadata>,
+
+    /// Return type, or `None` if the method returns nothing.
+    pub return_type: Option<ParamMetadata>,
+
+    /// Default values for the last `default_args.len()` parameters.
+    pub default_args: Vec<Variant>,
+
+    /// Whether the method is static, i.e. has no `self` receiver.
+    pub is_static: bool,
+
+    /// Whether the method accepts a variable number of arguments.
+    pub is_vararg: bool,
+
+    /// Whether the method is declared `const` (takes `&self` rather than `&mut self`).
+    pub is_const: bool,
+}
+
+impl MethodMetadata {
+    /// Whether the method can be called with `count` arguments, taking default values and varargs into account.
+    pub fn accepts_arg_count(&self, count: usize) -> bool {
+        let max = self.params.len();
+        let min = max.saturating_sub(self.default_args.len());
+
+        count >= min && (count <= max || self.is_vararg)
+    }
+}
+
+/// Signal of a registered class.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignalMetadata {
+    /// Name of the signal in Godot.
+    pub name: String,
+
+    /// Parameters, in order.
+    pub params: Vec<ParamMetadata>,
+}
+
+/// Type information of a parameter, return value or property.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParamMetadata {
+    /// Name of the parameter or property. Empty for return values.
+    pub name: String,
+
+    /// Type of the value; [`VariantType::NIL`] stands for `Variant`.
+    pub variant_type: VariantType,
+
+    /// For objects, the name of the class. Empty otherwise.
+    pub class_name: String,
+
+    /// Hint, e.g. [`PropertyHint::ARRAY_TYPE`] for typed arrays.
+    pub hint: PropertyHint,
+
+    /// Extra information for the hint, e.g. the element type of typed arrays.
+    pub hint_string: String,
+
+    /// Usage flags, relevant for properties.
+    pub usage: PropertyUsageFlags,
+}
+
+/// Integer constant of a registered class.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConstantMetadata {
+    /// Name of the constant in Godot.
+    pub name: String,
+
+    /// Value of the constant.
+    pub value: i64,
+
+    /// Name of the enum or bitfield this constant belongs to, if any.
+    pub enum_name: Option<String>,
+}
+
+/// Returns all classes currently registered by this extension, sorted by name.
+///
+/// Other Rust extensions in the same process are not included; see [`extensions`](crate::registry::extensions) for those.
+pub fn own_classes() -> Vec<ClassMetadata> {
+    let mut classes = class::loaded_classes()
+        .into_iter()
+        .map(|(class_name, init_level)| read_class(class_name.to_string(), init_level))
+        .collect::<Vec<_>>();
+
+    classes.sort_by(|a, b| a.name.cmp(&b.name));
+    classes
+}
+
+/// Returns the class registered by this extension under the name `class_name`, or `None` if there is no such class.
+pub fn find_class(class_name: &str) -> Option<ClassMetadata> {
+    let (_, init_level) = class::loaded_classes()
+        .into_iter()
+        .find(|(name, _)| name.to_string() == class_name)?;
+
+    Some(read_class(class_name.to_string(), init_level))
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+fn read_class(name: String, init_level: InitLevel) -> ClassMetadata {
+    let class = StringName::from(&name);
+
+    ClassMetadata {
+        base_class: ClassDb::singleton()
+            .get_parent_class(class.clone())
+            .to_string(),
+        methods: read_methods(&class, false),
+        signals: read_signals(&class, false),
+        properties: read_properties(&class, false),
+        constants: read_constants(&class, false),
+        name,
+        init_level,
+    }
+}
+
+pub(crate) fn read_methods(class: &StringName, inherited: bool) -> Vec<MethodMetadata> {
+    ClassDb::singleton()
+        .class_get_method_list_ex(class.clone())
+        .no_inheritance(!inherited)
+        .done()
+        .iter_shared()
+        .map(|info| read_method(&info))
+        .collect()
+}
+
+pub(crate) fn read_signals(class: &StringName, inherited: bool) -> Vec<SignalMetadata> {
+    ClassDb::singleton()
+        .class_get_signal_list_ex(class.clone())
+        .no_inheritance(!inherited)
+        .done()
+        .iter_shared()
+        .map(|info| SignalMetadata {
+            name: string_field(&info, "name"),
+            params: read_params(&info),
+        })
+        .collect()
+}
+
+pub(crate) fn read_properties(class: &StringName, inherited: bool) -> Vec<ParamMetadata> {
+    ClassDb::singleton()
+        .class_get_property_list_ex(class.clone())
+        .no_inheritance(!inherited)
+        .done()
+        .iter_shared()
+        .map(|info| read_param(&info))
+        .collect()
+}
+
+pub(crate) fn read_constants(class: &StringName, inherited: bool) -> Vec<ConstantMetadata> {
+    let db = ClassDb::singleton();
+
+    db.class_get_integer_constant_list_ex(class.clone())
+        .no_inheritance(!inherited)
+        .done()
+        .as_slice()
+        .iter()
+        .map(|constant| {
+            let constant = StringName::from(constant);
+            let enum_name = db
+                .class_get_integer_constant_enum_ex(class.clone(), constant.clone())
+                .no_inheritance(!inherited)
+                .done();
+
+            ConstantMetadata {
+                name: constant.to_string(),
+                value: db.class_get_integer_constant(class.clone(), constant),
+                enum_name: Some(enum_name.to_string()).filter(|name| !name.is_empty()),
+            }
+        })
+        .collect()
+}
+
+fn read_method(info: &Dictionary) -> MethodMetadata {
+    let flags = info
+        .get("flags")
+        .and_then(|flags| flags.try_to::<u64>().ok())
+        .unwrap_or(0);
+
+    // Godot always reports a return value; `NIL` without the "nil is variant" usage means the method returns nothing.
+    let return_type = info
+        .get("return")
+        .and_then(|ret| ret.try_to::<Dictionary>().ok())
+        .map(|ret| read_param(&ret))
+        .filter(|ret| {
+            ret.variant_type != VariantType::NIL
+                || ret.usage.is_set(PropertyUsageFlags::NIL_IS_VARIANT)
+        });
+
+    MethodMetadata {
+        name: string_field(info, "name"),
+        params: read_params(info),
+        return_type,
+        default_args: array_field(info, "default_args").iter_shared().collect(),
+        is_static: flags & MethodFlags::STATIC.ord() != 0,
+        is_vararg: flags & MethodFlags::VARARG.ord() != 0,
+        is_const: flags & MethodFlags::CONST.ord() != 0,
+    }
+}
+
+fn read_params(info: &Dictionary) -> Vec<ParamMetadata> {
+    array_field(info, "args")
+        .iter_shared()
+        .filter_map(|arg| arg.try_to::<Dictionary>().ok())
+        .map(|arg| read_param(&arg))
+        .collect()
+}
+
+fn read_param(info: &Dictionary) -> ParamMetadata {
+    let int_field = |key: &str| {
+        info.get(key)
+            .and_then(|value| value.try_to::<i64>().ok())
+            .unwrap_or(0)
+    };
+
+    ParamMetadata {
+        name: string_field(info, "name"),
+        variant_type: VariantType::try_from_ord(int_field("type") as i32)
+            .unwrap_or(VariantType::NIL),
+        class_name: string_field(info, "class_name"),
+        hint: PropertyHint::try_from_ord(int_field("hint") as i32).unwrap_or(PropertyHint::NONE),
+        hint_string: string_field(info, "hint_string"),
+        usage: PropertyUsageFlags::from_ord(int_field("usage") as u64),
+    }
+}
+
+fn string_field(info: &Dictionary, key: &str) -> String {
+    info.get(key)
+        .map(|value| value.stringify().to_string())
+        .unwrap_or_default()
+}
+
+fn array_field(info: &Dictionary, key: &str) -> VariantArray {
+    info.get(key)
+        .and_then(|value| value.try_to::<VariantArray>().ok())
+        .unwrap_or_default()
+}
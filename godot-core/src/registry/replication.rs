@@ -0,0 +1,149 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Replication of properties over the network, via `MultiplayerSynchronizer`.
+//!
+//! Properties declared with `#[var(replicate = ...)]` are recorded during class registration. At runtime, a
+//! `SceneReplicationConfig` can be generated from this information and assigned to a `MultiplayerSynchronizer`, instead of
+//! configuring each property manually in the editor.
+//!
+//! ```no_run
+//! # use godot::prelude::*;
+//! use godot::classes::MultiplayerSynchronizer;
+//! use godot::register::replication;
+//!
+//! #[derive(GodotClass)]
+//! #[class(init, base=Node)]
+//! struct Player {
+//!     #[var(replicate = always)]
+//!     position: Vector2,
+//!
+//!     #[var(replicate = on_change)]
+//!     health: i32,
+//!
+//!     base: Base<Node>,
+//! }
+//!
+//! // Synchronizer whose `root_path` points to the player node:
+//! fn configure(synchronizer: &mut Gd<MultiplayerSynchronizer>) {
+//!     let config = replication::replication_config::<Player>();
+//!     synchronizer.set_replication_config(config);
+//! }
+//! ```
+
+use godot_ffi as sys;
+use std::collections::HashMap;
+use sys::Global;
+
+use crate::builtin::NodePath;
+use crate::classes::SceneReplicationConfig;
+use crate::meta::ClassName;
+use crate::obj::{Gd, GodotClass, NewGd};
+
+static REPLICATED_PROPERTIES: Global<HashMap<ClassName, Vec<ReplicatedProperty>>> =
+    Global::default();
+
+/// How a property is replicated by a `MultiplayerSynchronizer`.
+///
+/// Corresponds to the `replicate` key in `#[var(replicate = ...)]`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Replication {
+    /// Sent on spawn and synchronized every network frame (`replicate = always`).
+    Always,
+
+    /// Sent on spawn and synchronized whenever the value changes (`replicate = on_change`).
+    ///
+    /// Before Godot 4.2, this is equivalent to [`Always`][Self::Always].
+    OnChange,
+
+    /// Only sent when the node is spawned, never synchronized afterward (`replicate = spawn`).
+    SpawnOnly,
+}
+
+/// A property of a Rust class, which is replicated over the network.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ReplicatedProperty {
+    /// Name of the property, as registered in Godot.
+    pub name: &'static str,
+
+    /// Replication mode.
+    pub mode: Replication,
+}
+
+/// Returns all replicated properties of class `T`, in declaration order.
+pub fn replicated_properties<T: GodotClass>() -> Vec<ReplicatedProperty> {
+    let properties = REPLICATED_PROPERTIES.lock();
+
+    properties
+        .get(&T::class_name())
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Generates a `SceneReplicationConfig` for the replicated properties of `T`.
+///
+/// The synchronizer's `root_path` must point to the node of class `T`. To synchronize nodes further down the tree, or multiple classes
+/// with the same synchronizer, use [`add_replicated_properties()`].
+pub fn replication_config<T: GodotClass>() -> Gd<SceneReplicationConfig> {
+    let mut config = SceneReplicationConfig::new_gd();
+    add_replicated_properties::<T>(&mut config, ".");
+
+    config
+}
+
+/// Adds the replicated properties of `T` to an existing `SceneReplicationConfig`.
+///
+/// `node_path` is the path of the node of class `T`, relative to the synchronizer's `root_path`; use `"."` for the root itself.
+/// Properties already present in the config are reconfigured.
+pub fn add_replicated_properties<T: GodotClass>(
+    config: &mut Gd<SceneReplicationConfig>,
+    node_path: &str,
+) {
+    for property in replicated_properties::<T>() {
+        let path = NodePath::from(format!("{node_path}:{}", property.name));
+
+        if !config.has_property(path.clone()) {
+            config.add_property(path.clone());
+        }
+
+        config.property_set_spawn(path.clone(), true);
+        apply_sync_mode(config, path, property.mode);
+    }
+}
+
+#[cfg(since_api = "4.2")]
+fn apply_sync_mode(config: &mut Gd<SceneReplicationConfig>, path: NodePath, mode: Replication) {
+    use crate::classes::scene_replication_config::ReplicationMode;
+
+    let mode = match mode {
+        Replication::Always => ReplicationMode::ALWAYS,
+        Replication::OnChange => ReplicationMode::ON_CHANGE,
+        Replication::SpawnOnly => ReplicationMode::NEVER,
+    };
+
+    config.property_set_replication_mode(path, mode);
+}
+
+#[cfg(before_api = "4.2")]
+fn apply_sync_mode(config: &mut Gd<SceneReplicationConfig>, path: NodePath, mode: Replication) {
+    config.property_set_sync(path, mode != Replication::SpawnOnly);
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Registration (used by proc-macros)
+
+#[doc(hidden)]
+pub fn register_replicated_property(class_name: ClassName, name: &'static str, mode: Replication) {
+    let mut properties = REPLICATED_PROPERTIES.lock();
+    let class_properties = properties.entry(class_name).or_default();
+
+    // Classes may be registered multiple times, e.g. on hot reload.
+    match class_properties.iter_mut().find(|p| p.name == name) {
+        Some(existing) => existing.mode = mode,
+        None => class_properties.push(ReplicatedProperty { name, mode }),
+    }
+}
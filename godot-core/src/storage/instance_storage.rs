@@ -69,7 +69,12 @@ pub unsafe trait Storage {
     type Instance: GodotClass;
 
     /// Constructs a new storage for an instance binding referencing `user_instance`.
-    fn construct(
+    ///
+    /// # Safety
+    /// The storage must be moved to its final location with [`into_raw()`](Storage::into_raw()) before the user instance is borrowed,
+    /// and must not be moved afterward. With `#[class(inline_storage)]`, the user instance lives inside the storage, so borrows would
+    /// otherwise be invalidated by the move.
+    unsafe fn construct(
         user_instance: Self::Instance,
         base: Base<<Self::Instance as GodotClass>::Base>,
     ) -> Self;
@@ -86,12 +91,22 @@ pub unsafe trait Storage {
     /// they are violated.
     fn get(&self) -> RefGuard<'_, Self::Instance>;
 
+    /// Returns a shared reference to this storage's instance, or `None` if it is currently bound mutably.
+    ///
+    /// Unlike [`get()`](Storage::get()), this does not panic.
+    fn try_get(&self) -> Option<RefGuard<'_, Self::Instance>>;
+
     /// Returns a mutable/exclusive reference to this storage's instance.
     ///
     /// This will ensure Rust's rules surrounding references are upheld. Possibly panicking at runtime if
     /// they are violated.
     fn get_mut(&self) -> MutGuard<'_, Self::Instance>;
 
+    /// Returns a mutable/exclusive reference to this storage's instance, or `None` if it is currently bound.
+    ///
+    /// Unlike [`get_mut()`](Storage::get_mut()), this does not panic.
+    fn try_get_mut(&self) -> Option<MutGuard<'_, Self::Instance>>;
+
     /// Returns a guard that allows calling methods on `Gd<Base>` that take `&mut self`.
     ///
     /// This can use the provided `instance` to provide extra safety guarantees such as allowing reentrant
@@ -38,7 +38,7 @@ pub struct InstanceStorage<T: GodotClass> {
 unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
     type Instance = T;
 
-    fn construct(
+    unsafe fn construct(
         user_instance: Self::Instance,
         base: Base<<Self::Instance as GodotClass>::Base>,
     ) -> Self {
@@ -72,6 +72,14 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
         })
     }
 
+    fn try_get(&self) -> Option<RefGuard<'_, T>> {
+        self.user_instance.borrow().ok()
+    }
+
+    fn try_get_mut(&self) -> Option<MutGuard<'_, T>> {
+        self.user_instance.borrow_mut().ok()
+    }
+
     fn get_mut(&self) -> MutGuard<'_, T> {
         self.user_instance.borrow_mut().unwrap_or_else(|err| {
             panic!(
@@ -9,17 +9,21 @@ use std::any::type_name;
 use std::cell;
 
 #[cfg(not(feature = "experimental-threads"))]
-use godot_cell::panicking::{GdCell, InaccessibleGuard, MutGuard, RefGuard};
+use godot_cell::panicking::{InaccessibleGuard, MutGuard, RefGuard};
 
 #[cfg(feature = "experimental-threads")]
-use godot_cell::blocking::{GdCell, InaccessibleGuard, MutGuard, RefGuard};
+use godot_cell::blocking::{InaccessibleGuard, MutGuard, RefGuard};
 
-use crate::obj::{Base, GodotClass};
+use crate::obj::bounds::{Layout, UserCell};
+use crate::obj::{Base, Bounds, GodotClass};
 use crate::out;
 use crate::storage::{Lifecycle, Storage, StorageRefCounted};
 
+/// Cell type of the user instance, depending on whether the class uses `#[class(inline_storage)]`.
+type UserInstanceCell<T> = <<T as Bounds>::Layout as Layout>::Cell<T>;
+
 pub struct InstanceStorage<T: GodotClass> {
-    user_instance: GdCell<T>,
+    user_instance: UserInstanceCell<T>,
     pub(super) base: Base<T::Base>,
 
     // Declared after `user_instance`, is dropped last
@@ -38,13 +42,15 @@ pub struct InstanceStorage<T: GodotClass> {
 unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
     type Instance = T;
 
-    fn construct(
+    unsafe fn construct(
         user_instance: Self::Instance,
         base: Base<<Self::Instance as GodotClass>::Base>,
     ) -> Self {
         out!("    Storage::construct             <{}>", type_name::<T>());
         Self {
-            user_instance: GdCell::new(user_instance),
+            // SAFETY: per the contract of `construct()`, the storage is moved to its final heap location in `into_raw()` before the
+            // user instance is borrowed, and never moved again.
+            user_instance: unsafe { <UserInstanceCell<T> as UserCell<T>>::new(user_instance) },
             base,
             lifecycle: cell::Cell::new(Lifecycle::Alive),
             godot_ref_count: cell::Cell::new(1),
@@ -72,6 +78,14 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
         })
     }
 
+    fn try_get(&self) -> Option<RefGuard<'_, T>> {
+        self.user_instance.borrow().ok()
+    }
+
+    fn try_get_mut(&self) -> Option<MutGuard<'_, T>> {
+        self.user_instance.borrow_mut().ok()
+    }
+
     fn get_mut(&self) -> MutGuard<'_, T> {
         self.user_instance.borrow_mut().unwrap_or_else(|err| {
             panic!(
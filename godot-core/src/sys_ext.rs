@@ -0,0 +1,160 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Safe access to selected low-level GDExtension interface functions.
+//!
+//! The raw GDExtension C interface is available through `godot::sys`, but every function requires `unsafe` code and careful handling
+//! of pointers and string encodings. This module provides safe wrappers for functions that are commonly needed in power-user code,
+//! e.g. custom loggers or tooling, and that are not already covered by higher-level APIs in [`classes`][crate::classes] or
+//! [`global`][crate::global].
+//!
+//! All functions in this module must be called after the library has been initialized, i.e. not before
+//! [`ExtensionLibrary::on_level_init()`][crate::init::ExtensionLibrary::on_level_init] is invoked for the first time.
+
+use std::ffi::CString;
+
+use godot_ffi as sys;
+use sys::interface_fn;
+
+use crate::builtin::StringName;
+use crate::obj::{Gd, GodotClass, InstanceId};
+
+/// Location in source code, attached to messages printed through [`print_error_at()`] and related functions.
+///
+/// Godot displays this location in the debugger and makes it clickable, if it refers to a script in the project.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct SourceLocation<'a> {
+    /// Name of the function in which the message originates.
+    pub function: &'a str,
+
+    /// Path of the file, e.g. `res://player.gd` for scripts or a Rust source path.
+    pub file: &'a str,
+
+    /// 1-based line number.
+    pub line: u32,
+}
+
+impl SourceLocation<'static> {
+    /// Returns the location of the caller, using `#[track_caller]`.
+    ///
+    /// Rust has no stable way of obtaining the caller's function name; it is left empty.
+    #[track_caller]
+    pub fn caller() -> Self {
+        let location = std::panic::Location::caller();
+
+        Self {
+            function: "",
+            file: location.file(),
+            line: location.line(),
+        }
+    }
+}
+
+/// Prints an error to Godot's debugger and the OS terminal, attributed to a custom source location.
+///
+/// Unlike [`godot_error!`][crate::global::godot_error], which always reports the Rust call site, this allows forwarding errors from other
+/// sources, such as a scripting layer built on top of Rust. If `notify_editor` is true, the editor additionally shows a toast notification.
+pub fn print_error_at(message: &str, location: &SourceLocation, notify_editor: bool) {
+    print_with(MessageKind::Error, message, location, notify_editor)
+}
+
+/// Prints a warning to Godot's debugger and the OS terminal, attributed to a custom source location.
+///
+/// See [`print_error_at()`] for details.
+pub fn print_warning_at(message: &str, location: &SourceLocation, notify_editor: bool) {
+    print_with(MessageKind::Warning, message, location, notify_editor)
+}
+
+/// Prints a script error to Godot's debugger and the OS terminal, attributed to a custom source location.
+///
+/// See [`print_error_at()`] for details.
+pub fn print_script_error_at(message: &str, location: &SourceLocation, notify_editor: bool) {
+    print_with(MessageKind::ScriptError, message, location, notify_editor)
+}
+
+/// Queries the engine for the instance ID of `object`.
+///
+/// Returns `None` if the object has already been destroyed. In contrast to [`Gd::instance_id()`], this does not use the ID cached on the
+/// Rust side, but reads it from the engine object.
+pub fn object_instance_id<T: GodotClass>(object: &Gd<T>) -> Option<InstanceId> {
+    if !object.is_instance_valid() {
+        return None;
+    }
+
+    // SAFETY: object is alive, as checked above.
+    let raw_id = unsafe { interface_fn!(object_get_instance_id)(object.obj_sys()) };
+
+    InstanceId::try_from_i64(raw_id as i64)
+}
+
+/// Returns whether a class of name `class_name` is registered in Godot's `ClassDB`.
+///
+/// This includes engine classes as well as classes registered by any GDExtension, including this one (once registration has happened).
+pub fn class_exists(class_name: &StringName) -> bool {
+    // SAFETY: class_name is a valid StringName for the duration of the call.
+    let tag = unsafe { interface_fn!(classdb_get_class_tag)(class_name.string_sys()) };
+
+    !tag.is_null()
+}
+
+/// Loads XML class documentation into the editor's help system.
+///
+/// The XML must follow the format of Godot's `doc/classes/*.xml` files. Only has an effect when running inside the editor.
+#[cfg(since_api = "4.3")]
+pub fn load_editor_help_xml(xml: &str) {
+    let len = xml.len() as sys::GDExtensionInt;
+
+    // SAFETY: pointer and length describe a valid UTF-8 buffer for the duration of the call.
+    unsafe {
+        interface_fn!(editor_help_load_xml_from_utf8_chars_and_len)(
+            xml.as_ptr() as *const std::ffi::c_char,
+            len,
+        );
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+enum MessageKind {
+    Error,
+    Warning,
+    ScriptError,
+}
+
+fn print_with(kind: MessageKind, message: &str, location: &SourceLocation, notify_editor: bool) {
+    let message = to_c_string(message);
+    let function = to_c_string(location.function);
+    let file = to_c_string(location.file);
+    let line = i32::try_from(location.line).unwrap_or(i32::MAX);
+    let notify_editor = sys::conv::bool_to_sys(notify_editor);
+
+    // SAFETY: all strings are null-terminated and outlive the call.
+    unsafe {
+        let print_fn = match kind {
+            MessageKind::Error => interface_fn!(print_error),
+            MessageKind::Warning => interface_fn!(print_warning),
+            MessageKind::ScriptError => interface_fn!(print_script_error),
+        };
+
+        print_fn(
+            message.as_ptr(),
+            function.as_ptr(),
+            file.as_ptr(),
+            line,
+            notify_editor,
+        );
+    }
+}
+
+/// Converts to a C string; interior null bytes (which cannot be represented) are removed.
+fn to_c_string(s: &str) -> CString {
+    CString::new(s).unwrap_or_else(|_| {
+        let sanitized = s.replace('\0', "");
+        CString::new(sanitized).expect("null bytes removed")
+    })
+}
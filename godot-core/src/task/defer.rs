@@ -0,0 +1,257 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+
+use crate::builtin::{Callable, StringName, Variant};
+use crate::classes::object::ConnectFlags;
+use crate::classes::{Engine, Object, SceneTree};
+use crate::meta::ToGodot;
+use crate::obj::Gd;
+use crate::sys;
+
+/// Runs `f` at the end of the current frame, on the main thread.
+///
+/// Closures run in the order they were deferred, after all code that is currently executing (signal handlers, `process()` etc.) has
+/// finished. This is based on Godot's message queue, the same mechanism as `Object::call_deferred()`, so deferred closures and
+/// deferred method calls are interleaved in the order they were scheduled.
+///
+/// Closures deferred while the queue is being flushed run after the current batch, but still in the same frame. If a closure panics,
+/// the panic is reported and the remaining closures still run.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::task::defer;
+///
+/// fn on_enemy_died(mut enemy: Gd<Node>) {
+///     // Removing nodes while the physics engine iterates them is not allowed, so do it afterward.
+///     defer(move || enemy.queue_free());
+/// }
+/// ```
+///
+/// # Panics
+/// If called from another thread than the main thread, or if there is no main loop running.
+pub fn defer(f: impl FnOnce() + 'static) {
+    enqueue(QueueKind::Idle, None, Box::new(f));
+}
+
+/// Runs `f` at the start of the next physics tick, on the main thread.
+///
+/// Closures run in the order they were deferred, before the `physics_process()` methods of nodes are invoked.
+///
+/// # Panics
+/// If called from another thread than the main thread, or if the main loop is not a [`SceneTree`] (or derived class).
+pub fn defer_physics(f: impl FnOnce() + 'static) {
+    enqueue(QueueKind::Physics, None, Box::new(f));
+}
+
+/// Like [`defer()`], but only the most recent closure deferred with the same `key` is run.
+///
+/// If a closure with an equal key is already pending, it is replaced by `f`, which takes over its position in the queue. Returns `true`
+/// in that case. This is useful for expensive operations that are triggered many times per frame, but only need to run once, e.g.
+/// rebuilding a UI list after every change to its data.
+///
+/// Keys of different types never compare equal.
+///
+/// ```no_run
+/// use godot::task::defer_coalesced;
+///
+/// # fn rebuild_inventory_ui() {}
+/// for _ in 0..10 {
+///     // Only the last of these runs.
+///     defer_coalesced("inventory_ui", || rebuild_inventory_ui());
+/// }
+/// ```
+pub fn defer_coalesced<K>(key: K, f: impl FnOnce() + 'static) -> bool
+where
+    K: PartialEq + 'static,
+{
+    enqueue(QueueKind::Idle, Some(Box::new(key)), Box::new(f))
+}
+
+/// Like [`defer_physics()`], but only the most recent closure deferred with the same `key` is run.
+///
+/// See [`defer_coalesced()`] for details.
+pub fn defer_physics_coalesced<K>(key: K, f: impl FnOnce() + 'static) -> bool
+where
+    K: PartialEq + 'static,
+{
+    enqueue(QueueKind::Physics, Some(Box::new(key)), Box::new(f))
+}
+
+/// Number of closures waiting to be run by [`defer()`] and [`defer_coalesced()`].
+pub fn deferred_count() -> usize {
+    QUEUES.with(|queues| queues.borrow().idle.entries.len())
+}
+
+/// Number of closures waiting to be run by [`defer_physics()`] and [`defer_physics_coalesced()`].
+pub fn deferred_physics_count() -> usize {
+    QUEUES.with(|queues| queues.borrow().physics.entries.len())
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+const FLUSH_SIGNAL: &str = "__gdext_flush_deferred";
+
+type Task = Box<dyn FnOnce()>;
+
+thread_local! {
+    static QUEUES: RefCell<Queues> = RefCell::new(Queues::default());
+}
+
+/// Type-erased key for coalescing, comparable with keys of any type.
+trait CoalesceKey {
+    fn as_any(&self) -> &dyn Any;
+    fn eq_key(&self, other: &dyn CoalesceKey) -> bool;
+}
+
+impl<K: PartialEq + 'static> CoalesceKey for K {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_key(&self, other: &dyn CoalesceKey) -> bool {
+        other.as_any().downcast_ref::<K>() == Some(self)
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum QueueKind {
+    Idle,
+    Physics,
+}
+
+#[derive(Default)]
+struct Queue {
+    entries: VecDeque<(Option<Box<dyn CoalesceKey>>, Task)>,
+
+    /// Whether a flush has been scheduled, which hasn't run yet.
+    is_scheduled: bool,
+}
+
+#[derive(Default)]
+struct Queues {
+    idle: Queue,
+    physics: Queue,
+}
+
+impl Queues {
+    fn get_mut(&mut self, kind: QueueKind) -> &mut Queue {
+        match kind {
+            QueueKind::Idle => &mut self.idle,
+            QueueKind::Physics => &mut self.physics,
+        }
+    }
+}
+
+fn enqueue(kind: QueueKind, key: Option<Box<dyn CoalesceKey>>, task: Task) -> bool {
+    // The queues are thread-local and flushed by the main loop, so tasks deferred on other threads would never run.
+    assert!(
+        sys::is_main_thread(),
+        "deferred tasks must be scheduled on the main thread; use godot::task::MainThreadQueue from other threads"
+    );
+
+    let (coalesced, needs_schedule) = QUEUES.with(|queues| {
+        let mut queues = queues.borrow_mut();
+        let queue = queues.get_mut(kind);
+
+        let existing = key.as_ref().and_then(|key| {
+            queue.entries.iter_mut().find(|(other, _)| {
+                other
+                    .as_ref()
+                    .is_some_and(|other| other.eq_key(key.as_ref()))
+            })
+        });
+
+        let coalesced = if let Some((_, existing_task)) = existing {
+            *existing_task = task;
+            true
+        } else {
+            queue.entries.push_back((key, task));
+            false
+        };
+
+        let needs_schedule = !queue.is_scheduled;
+        queue.is_scheduled = true;
+
+        (coalesced, needs_schedule)
+    });
+
+    // Schedule outside the RefCell borrow, in case Godot calls back synchronously.
+    if needs_schedule {
+        match kind {
+            QueueKind::Idle => schedule_idle_flush(),
+            QueueKind::Physics => schedule_physics_flush(),
+        }
+    }
+
+    coalesced
+}
+
+fn schedule_idle_flush() {
+    // Godot's message queue can only call methods, not arbitrary callables. Defer an emit of a user signal on the main loop instead.
+    let mut main_loop = main_loop();
+    let signal = StringName::from(FLUSH_SIGNAL);
+
+    if !main_loop.has_signal(signal.clone()) {
+        main_loop.add_user_signal(FLUSH_SIGNAL.into());
+        main_loop.connect(signal.clone(), flush_callable(QueueKind::Idle));
+    }
+
+    main_loop.call_deferred("emit_signal".into(), &[signal.to_variant()]);
+}
+
+fn schedule_physics_flush() {
+    let mut tree = main_loop()
+        .try_cast::<SceneTree>()
+        .unwrap_or_else(|main_loop| {
+            panic!(
+                "defer_physics() requires the main loop to be a SceneTree, but it is {main_loop:?}"
+            )
+        });
+
+    tree.connect_ex("physics_frame".into(), flush_callable(QueueKind::Physics))
+        .flags(ConnectFlags::ONE_SHOT)
+        .done();
+}
+
+fn flush_callable(kind: QueueKind) -> Callable {
+    Callable::from_fn("flush_deferred", move |_args: &[&Variant]| {
+        flush(kind);
+        Ok(Variant::nil())
+    })
+}
+
+fn flush(kind: QueueKind) {
+    // Take the tasks out, so they can defer further tasks without double-borrowing the RefCell. Those are scheduled for a new flush.
+    let entries = QUEUES.with(|queues| {
+        let mut queues = queues.borrow_mut();
+        let queue = queues.get_mut(kind);
+        queue.is_scheduled = false;
+
+        std::mem::take(&mut queue.entries)
+    });
+
+    // A panicking task must not discard the remaining ones.
+    for (_key, task) in entries {
+        let _ = crate::private::handle_panic(
+            || "deferred task panicked",
+            std::panic::AssertUnwindSafe(task),
+        );
+    }
+}
+
+fn main_loop() -> Gd<Object> {
+    Engine::singleton()
+        .get_main_loop()
+        .expect("deferred tasks require a running main loop")
+        .upcast()
+}
@@ -0,0 +1,37 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Scheduling of work on the main thread, relative to Godot's frame loop.
+//!
+//! [`defer()`] runs a closure at the end of the current frame, like `Object::call_deferred()`, but without needing an object and a method
+//! name. [`defer_physics()`] runs it at the next physics tick. Both keep FIFO order and support coalescing, see [`defer_coalesced()`].
+//!
+//! With the `experimental-threads` feature, work can also be moved off the main thread, onto Godot's `WorkerThreadPool`:
+//! [`spawn_blocking()`] runs a closure and returns a [`JoinHandle`] for its result, and [`parallel_for()`] distributes calls over an
+//! index range.
+//!
+//! Other threads can hand work back to the main thread through a [`MainThreadQueue`], or emit signals via a [`ThreadsafeSignal`]. These
+//! are flushed once per frame, and bounded queues provide backpressure.
+
+#[cfg(since_api = "4.2")]
+mod defer;
+#[cfg(since_api = "4.2")]
+mod threadsafe;
+#[cfg(all(feature = "experimental-threads", since_api = "4.2"))]
+mod worker;
+
+#[cfg(since_api = "4.2")]
+pub use defer::*;
+#[cfg(since_api = "4.2")]
+pub use threadsafe::{MainThreadQueue, QueueFull, ThreadsafeSignal};
+#[cfg(all(feature = "experimental-threads", since_api = "4.2"))]
+pub use worker::{
+    is_worker_task, parallel_for, spawn_blocking, spawn_parallel_for, GroupJoinHandle, JoinHandle,
+};
+
+#[cfg(all(debug_assertions, feature = "experimental-threads", since_api = "4.2"))]
+pub(crate) use worker::debug_check_scene_access;
@@ -0,0 +1,397 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, OnceLock, Weak};
+use std::thread::{self, ThreadId};
+
+use crate::builtin::{CallableArgs, Signal, StringName, Variant};
+use crate::classes::{Object, Os};
+use crate::obj::{Gd, InstanceId};
+use crate::tools::add_process_callback;
+
+/// Queue through which any thread can schedule work on the main thread.
+///
+/// Tasks are run on the main thread at the start of the next frame, in the order they were posted, when the [`SceneTree`][crate::classes::SceneTree]
+/// emits `process_frame`. The queue itself is `Send + Sync` and cheap to clone; clones share the same tasks. A typical use is a
+/// producer/consumer setup, where worker threads compute results and hand them to the main thread as signal emissions.
+///
+/// A queue is either unbounded, or has a fixed capacity. Posting to a full bounded queue fails with [`QueueFull`], or blocks until the
+/// main thread has made room (`*_blocking` methods). This provides backpressure for producers that are faster than the main thread.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::task::MainThreadQueue;
+///
+/// fn start_loading(node: Gd<Node>) {
+///     let queue = MainThreadQueue::bounded(16);
+///     let chunk_loaded = Signal::from_object_signal(&node, "chunk_loaded").to_threadsafe();
+///
+///     std::thread::spawn(move || {
+///         for index in 0..100_i64 {
+///             // ... expensive work ...
+///             queue.emit_blocking(&chunk_loaded, (index,));
+///         }
+///     });
+/// }
+/// ```
+#[derive(Clone)]
+pub struct MainThreadQueue {
+    shared: Arc<Shared>,
+}
+
+impl MainThreadQueue {
+    /// Creates a queue without capacity limit. Must be called on the main thread.
+    ///
+    /// # Panics
+    /// If called from another thread, or if the main loop is not a `SceneTree` (or derived class).
+    pub fn unbounded() -> Self {
+        Self::new(None)
+    }
+
+    /// Creates a queue that holds at most `capacity` pending tasks. Must be called on the main thread.
+    ///
+    /// # Panics
+    /// If `capacity` is 0, if called from another thread, or if the main loop is not a `SceneTree` (or derived class).
+    pub fn bounded(capacity: usize) -> Self {
+        assert!(capacity > 0, "MainThreadQueue capacity must be at least 1");
+        Self::new(Some(capacity))
+    }
+
+    /// Schedules `f` to run on the main thread.
+    ///
+    /// Returns `Err(QueueFull)` without scheduling `f` if the queue is bounded and at capacity.
+    pub fn post(&self, f: impl FnOnce() + Send + 'static) -> Result<(), QueueFull> {
+        self.push(Box::new(f), false)
+    }
+
+    /// Schedules `f` to run on the main thread, waiting for the queue to have room if it is at capacity.
+    ///
+    /// # Panics
+    /// If called on the main thread while the queue is full, as the queue could never be flushed.
+    pub fn post_blocking(&self, f: impl FnOnce() + Send + 'static) {
+        self.push(Box::new(f), true)
+            .expect("blocking push never fails");
+    }
+
+    /// Schedules an emission of `signal` with `args` on the main thread.
+    ///
+    /// The arguments are converted to variants only on the main thread, so they just need to be `Send`. If the object has been freed
+    /// in the meantime, the emission is skipped.
+    ///
+    /// Returns `Err(QueueFull)` if the queue is bounded and at capacity.
+    pub fn emit<A>(&self, signal: &ThreadsafeSignal, args: A) -> Result<(), QueueFull>
+    where
+        A: CallableArgs + Send + 'static,
+    {
+        self.push(signal.emit_task(args), false)
+    }
+
+    /// Like [`emit()`][Self::emit], but waits for the queue to have room if it is at capacity.
+    ///
+    /// # Panics
+    /// If called on the main thread while the queue is full.
+    pub fn emit_blocking<A>(&self, signal: &ThreadsafeSignal, args: A)
+    where
+        A: CallableArgs + Send + 'static,
+    {
+        self.push(signal.emit_task(args), true)
+            .expect("blocking push never fails");
+    }
+
+    /// Schedules a call of `method` with `args` on the object with ID `object`, on the main thread.
+    ///
+    /// The return value of the method is discarded. If the object has been freed in the meantime, the call is skipped.
+    ///
+    /// Returns `Err(QueueFull)` if the queue is bounded and at capacity.
+    pub fn call<A>(
+        &self,
+        object: InstanceId,
+        method: impl Into<String>,
+        args: A,
+    ) -> Result<(), QueueFull>
+    where
+        A: CallableArgs + Send + 'static,
+    {
+        self.push(call_task(object, method.into(), args), false)
+    }
+
+    /// Like [`call()`][Self::call], but waits for the queue to have room if it is at capacity.
+    ///
+    /// # Panics
+    /// If called on the main thread while the queue is full.
+    pub fn call_blocking<A>(&self, object: InstanceId, method: impl Into<String>, args: A)
+    where
+        A: CallableArgs + Send + 'static,
+    {
+        self.push(call_task(object, method.into(), args), true)
+            .expect("blocking push never fails");
+    }
+
+    /// Number of tasks waiting to be run.
+    pub fn len(&self) -> usize {
+        self.shared.lock().len()
+    }
+
+    /// Whether no tasks are waiting to be run.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Maximum number of pending tasks, or `None` for unbounded queues.
+    pub fn capacity(&self) -> Option<usize> {
+        self.shared.capacity
+    }
+
+    fn new(capacity: Option<usize>) -> Self {
+        let shared = Arc::new(Shared {
+            tasks: Mutex::new(VecDeque::new()),
+            has_room: Condvar::new(),
+            capacity,
+        });
+
+        register_queue(Arc::downgrade(&shared));
+        Self { shared }
+    }
+
+    fn push(&self, task: Task, block: bool) -> Result<(), QueueFull> {
+        let shared = &self.shared;
+        let mut tasks = shared.lock();
+
+        if let Some(capacity) = shared.capacity {
+            while tasks.len() >= capacity {
+                if !block {
+                    return Err(QueueFull { capacity });
+                }
+
+                assert!(
+                    !is_main_thread(),
+                    "MainThreadQueue is full (capacity {capacity}); blocking on the main thread would never return"
+                );
+
+                tasks = shared
+                    .has_room
+                    .wait(tasks)
+                    .unwrap_or_else(|poisoned| poisoned.into_inner());
+            }
+        }
+
+        tasks.push_back(task);
+        Ok(())
+    }
+}
+
+impl fmt::Debug for MainThreadQueue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MainThreadQueue")
+            .field("len", &self.len())
+            .field("capacity", &self.shared.capacity)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Error returned when posting to a bounded [`MainThreadQueue`] that is at capacity.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct QueueFull {
+    capacity: usize,
+}
+
+impl QueueFull {
+    /// Capacity of the queue that was full.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl fmt::Display for QueueFull {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "main thread queue is full (capacity {})", self.capacity)
+    }
+}
+
+impl Error for QueueFull {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Thread-safe handle to a signal, which can be emitted from any thread.
+///
+/// Obtained from [`Signal::to_threadsafe()`] on the main thread. Unlike `Signal`, it only stores the instance ID of the object and the
+/// signal name, so it can be sent to other threads. Emissions are always deferred to the main thread.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ThreadsafeSignal {
+    object: InstanceId,
+    name: String,
+}
+
+impl ThreadsafeSignal {
+    pub(crate) fn new(signal: &Signal) -> Self {
+        let object = signal
+            .object_id()
+            .expect("Signal::to_threadsafe(): signal has no object");
+
+        // Make sure the default queue exists and is hooked up, while still on the main thread.
+        default_queue();
+
+        Self {
+            object,
+            name: signal.name().to_string(),
+        }
+    }
+
+    /// Emits the signal with `args` on the main thread, at the start of the next frame.
+    ///
+    /// Uses an unbounded queue shared by all `ThreadsafeSignal`s, so this never blocks. Use [`MainThreadQueue::emit()`] for a bounded
+    /// queue with backpressure.
+    pub fn emit_deferred<A>(&self, args: A)
+    where
+        A: CallableArgs + Send + 'static,
+    {
+        default_queue()
+            .push(self.emit_task(args), false)
+            .expect("default queue is unbounded");
+    }
+
+    /// ID of the object that owns the signal.
+    pub fn object_id(&self) -> InstanceId {
+        self.object
+    }
+
+    /// Name of the signal.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn emit_task<A>(&self, args: A) -> Task
+    where
+        A: CallableArgs + Send + 'static,
+    {
+        let object = self.object;
+        let name = self.name.clone();
+
+        Box::new(move || {
+            let Ok(mut object) = Gd::<Object>::try_from_instance_id(object) else {
+                return;
+            };
+
+            let args = args.to_variant_array().iter_shared().collect::<Vec<_>>();
+            object.emit_signal(StringName::from(name.as_str()), &args);
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+type Task = Box<dyn FnOnce() + Send>;
+
+struct Shared {
+    tasks: Mutex<VecDeque<Task>>,
+
+    /// Notified whenever the queue is flushed, to wake up blocked producers.
+    has_room: Condvar,
+
+    capacity: Option<usize>,
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, VecDeque<Task>> {
+        // Tasks are never run while the lock is held, so a poisoned mutex still holds a consistent queue.
+        self.tasks
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// All live queues, flushed once per frame.
+static QUEUES: Mutex<Vec<Weak<Shared>>> = Mutex::new(Vec::new());
+
+static DEFAULT_QUEUE: OnceLock<MainThreadQueue> = OnceLock::new();
+
+static MAIN_THREAD: OnceLock<ThreadId> = OnceLock::new();
+
+/// Whether `flush_all()` has been added as a process callback. Process-wide, so that the hook is installed exactly once.
+static IS_HOOKED: AtomicBool = AtomicBool::new(false);
+
+fn default_queue() -> &'static MainThreadQueue {
+    DEFAULT_QUEUE.get_or_init(MainThreadQueue::unbounded)
+}
+
+fn register_queue(queue: Weak<Shared>) {
+    let os = Os::singleton();
+    assert_eq!(
+        os.get_thread_caller_id(),
+        os.get_main_thread_id(),
+        "MainThreadQueue must be created on the main thread"
+    );
+
+    // Only set once the hook is installed, so that a failed attempt (e.g. no SceneTree yet) is retried by the next queue.
+    // No race, as this runs on the main thread.
+    if !IS_HOOKED.load(Ordering::Acquire) {
+        MAIN_THREAD.get_or_init(|| thread::current().id());
+
+        add_process_callback(|_delta| flush_all());
+        IS_HOOKED.store(true, Ordering::Release);
+    }
+
+    QUEUES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(queue);
+}
+
+fn is_main_thread() -> bool {
+    MAIN_THREAD.get() == Some(&thread::current().id())
+}
+
+fn flush_all() {
+    // Collect the live queues first, so tasks can create new queues without deadlocking.
+    let queues = {
+        let mut queues = QUEUES
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        queues.retain(|queue| queue.strong_count() > 0);
+        queues.iter().filter_map(Weak::upgrade).collect::<Vec<_>>()
+    };
+
+    for shared in queues {
+        // Take the tasks out, so producers can post new ones (scheduled for the next frame) while these run.
+        let tasks = std::mem::take(&mut *shared.lock());
+        shared.has_room.notify_all();
+
+        // A panicking task must not discard the remaining ones.
+        for task in tasks {
+            let _ = crate::private::handle_panic(
+                || "task of MainThreadQueue panicked",
+                std::panic::AssertUnwindSafe(task),
+            );
+        }
+    }
+}
+
+fn call_task<A>(object: InstanceId, method: String, args: A) -> Task
+where
+    A: CallableArgs + Send + 'static,
+{
+    Box::new(move || {
+        let Ok(mut object) = Gd::<Object>::try_from_instance_id(object) else {
+            return;
+        };
+
+        let args = args
+            .to_variant_array()
+            .iter_shared()
+            .collect::<Vec<Variant>>();
+        object.call(StringName::from(method.as_str()), &args);
+    })
+}
@@ -0,0 +1,311 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::any::Any;
+use std::cell::Cell;
+use std::fmt;
+use std::ops::Range;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+
+use crate::builtin::{Callable, Variant};
+use crate::classes::WorkerThreadPool;
+use crate::obj::Gd;
+
+type PanicPayload = Box<dyn Any + Send>;
+
+/// Runs `f` on Godot's [`WorkerThreadPool`], returning a handle to wait for its result.
+///
+/// Prefer this over `std::thread::spawn()` for CPU-heavy work (pathfinding, procedural generation, ...), so that your extension
+/// shares threads with the engine instead of competing with it.
+///
+/// The closure runs on a worker thread, so it must not access nodes inside the scene tree. In debug builds, such accesses panic.
+/// Compute the result in the closure, and apply it to the scene after [`JoinHandle::join()`] on the main thread.
+///
+/// # Example
+/// ```no_run
+/// use godot::task;
+///
+/// # fn generate_chunk(seed: u64) -> Vec<u8> { vec![] }
+/// let handle = task::spawn_blocking(|| generate_chunk(1234));
+///
+/// // ...later, e.g. in process():
+/// if handle.is_finished() {
+///     let chunk = handle.join();
+/// }
+/// ```
+pub fn spawn_blocking<F, R>(f: F) -> JoinHandle<R>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    let result = Arc::new(Mutex::new(None));
+    let task_result = result.clone();
+
+    // The callable is only invoked once, but has to be `FnMut` and `Sync`.
+    let f = Mutex::new(Some(f));
+    let callable = Callable::from_fn("spawn_blocking", move |_args: &[&Variant]| {
+        let f = f.lock().unwrap().take();
+        if let Some(f) = f {
+            let outcome = run_in_worker(f);
+            *task_result.lock().unwrap() = Some(outcome);
+        }
+        Ok(Variant::nil())
+    });
+
+    let task_id = pool().add_task(callable);
+
+    JoinHandle {
+        task_id,
+        result,
+        is_waited: false,
+    }
+}
+
+/// Calls `f` for each index in `range`, distributed among worker threads. Blocks until all calls have finished.
+///
+/// Useful for data-parallel work, e.g. processing each element of a large array. Calls may run concurrently and in any order.
+///
+/// Like in [`spawn_blocking()`], `f` must not access nodes inside the scene tree.
+///
+/// # Panics
+/// If any call of `f` panics, after all other calls have finished.
+///
+/// # Example
+/// ```no_run
+/// use std::sync::Arc;
+/// use std::sync::atomic::{AtomicU64, Ordering};
+/// use godot::task;
+///
+/// let sum = Arc::new(AtomicU64::new(0));
+/// let sum_ref = sum.clone();
+///
+/// task::parallel_for(0..1000, move |i| {
+///     sum_ref.fetch_add(i as u64, Ordering::Relaxed);
+/// });
+///
+/// assert_eq!(sum.load(Ordering::Relaxed), 499_500);
+/// ```
+pub fn parallel_for<F>(range: Range<usize>, f: F)
+where
+    F: Fn(usize) + Send + Sync + 'static,
+{
+    spawn_parallel_for(range, f).join();
+}
+
+/// Like [`parallel_for()`], but returns immediately with a handle to wait for the calls.
+pub fn spawn_parallel_for<F>(range: Range<usize>, f: F) -> GroupJoinHandle
+where
+    F: Fn(usize) + Send + Sync + 'static,
+{
+    let panic = Arc::new(Mutex::new(None));
+    let group_panic = panic.clone();
+
+    let start = range.start;
+    let count = range.len();
+    let elements =
+        i32::try_from(count).expect("parallel_for(): range must have at most i32::MAX elements");
+
+    let callable = Callable::from_fn("parallel_for", move |args: &[&Variant]| {
+        let index = start + args[0].to::<i64>() as usize;
+
+        if let Err(payload) = run_in_worker(|| f(index)) {
+            // Keep the first panic; the others are likely follow-up errors.
+            group_panic.lock().unwrap().get_or_insert(payload);
+        }
+        Ok(Variant::nil())
+    });
+
+    let group_id = pool().add_group_task(callable, elements);
+
+    GroupJoinHandle {
+        group_id,
+        panic,
+        is_waited: false,
+    }
+}
+
+/// Whether the current thread is running a task started through this module.
+pub fn is_worker_task() -> bool {
+    IN_WORKER_TASK.get()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Handles
+
+/// Handle to a task started with [`spawn_blocking()`].
+///
+/// Godot needs to be notified once a task's result is no longer needed. If the handle is dropped without calling [`join()`][Self::join],
+/// the drop blocks until the task has finished.
+pub struct JoinHandle<R> {
+    task_id: i64,
+    result: Arc<Mutex<Option<std::thread::Result<R>>>>,
+    is_waited: bool,
+}
+
+impl<R> JoinHandle<R> {
+    /// Whether the task has finished, i.e. [`join()`][Self::join] would not block.
+    pub fn is_finished(&self) -> bool {
+        self.is_waited || pool().is_task_completed(self.task_id)
+    }
+
+    /// Blocks until the task has finished, and returns its result on the calling thread.
+    ///
+    /// # Panics
+    /// If the task panicked; the panic is resumed with the original payload.
+    pub fn join(mut self) -> R {
+        self.wait();
+
+        let outcome = self
+            .result
+            .lock()
+            .unwrap()
+            .take()
+            .expect("task finished without result");
+
+        outcome.unwrap_or_else(|payload| panic::resume_unwind(payload))
+    }
+
+    /// Returns the result if the task has finished, or the handle itself otherwise. Never blocks.
+    pub fn try_join(self) -> Result<R, Self> {
+        if self.is_finished() {
+            Ok(self.join())
+        } else {
+            Err(self)
+        }
+    }
+
+    fn wait(&mut self) {
+        if !self.is_waited {
+            self.is_waited = true;
+
+            // Also cleans up the task on Godot's side. The returned error is only non-OK for invalid IDs or waiting on oneself.
+            pool().wait_for_task_completion(self.task_id);
+        }
+    }
+}
+
+impl<R> Drop for JoinHandle<R> {
+    fn drop(&mut self) {
+        self.wait();
+    }
+}
+
+impl<R> fmt::Debug for JoinHandle<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinHandle")
+            .field("task_id", &self.task_id)
+            .finish()
+    }
+}
+
+/// Handle to calls started with [`spawn_parallel_for()`].
+///
+/// Like [`JoinHandle`], dropping the handle blocks until all calls have finished.
+pub struct GroupJoinHandle {
+    group_id: i64,
+    panic: Arc<Mutex<Option<PanicPayload>>>,
+    is_waited: bool,
+}
+
+impl GroupJoinHandle {
+    /// Whether all calls have finished.
+    pub fn is_finished(&self) -> bool {
+        self.is_waited || pool().is_group_task_completed(self.group_id)
+    }
+
+    /// Number of calls that have finished so far.
+    pub fn finished_count(&self) -> usize {
+        pool().get_group_processed_element_count(self.group_id) as usize
+    }
+
+    /// Blocks until all calls have finished.
+    ///
+    /// # Panics
+    /// If any call panicked; the first panic is resumed with the original payload.
+    pub fn join(mut self) {
+        self.wait();
+
+        if let Some(payload) = self.panic.lock().unwrap().take() {
+            panic::resume_unwind(payload);
+        }
+    }
+
+    fn wait(&mut self) {
+        if !self.is_waited {
+            self.is_waited = true;
+            pool().wait_for_group_task_completion(self.group_id);
+        }
+    }
+}
+
+impl Drop for GroupJoinHandle {
+    fn drop(&mut self) {
+        self.wait();
+    }
+}
+
+impl fmt::Debug for GroupJoinHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GroupJoinHandle")
+            .field("group_id", &self.group_id)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+thread_local! {
+    static IN_WORKER_TASK: Cell<bool> = const { Cell::new(false) };
+
+    /// Set while the scene access check itself calls into Godot, to not check those calls again.
+    #[cfg(debug_assertions)]
+    static IS_CHECKING_ACCESS: Cell<bool> = const { Cell::new(false) };
+}
+
+fn pool() -> Gd<WorkerThreadPool> {
+    WorkerThreadPool::singleton()
+}
+
+/// Runs `f` with the worker flag set, catching panics so they can be resumed on the joining thread.
+fn run_in_worker<R>(f: impl FnOnce() -> R) -> std::thread::Result<R> {
+    // Worker threads are reused, and a task may itself wait for others, so restore the previous value.
+    let was_in_task = IN_WORKER_TASK.replace(true);
+    let outcome = panic::catch_unwind(AssertUnwindSafe(f));
+    IN_WORKER_TASK.set(was_in_task);
+
+    outcome
+}
+
+/// Panics if a worker task calls a method on a node inside the scene tree.
+///
+/// Invoked for every outbound engine call in debug builds; only does actual work on threads running a task.
+#[cfg(debug_assertions)]
+pub(crate) fn debug_check_scene_access(
+    maybe_instance_id: Option<crate::obj::InstanceId>,
+    call_ctx: &crate::meta::CallContext,
+) {
+    let Some(instance_id) = maybe_instance_id else {
+        return;
+    };
+
+    if !IN_WORKER_TASK.get() || IS_CHECKING_ACCESS.get() {
+        return;
+    }
+
+    IS_CHECKING_ACCESS.set(true);
+    let in_tree = Gd::<crate::classes::Node>::try_from_instance_id(instance_id)
+        .is_ok_and(|node| node.is_inside_tree());
+    IS_CHECKING_ACCESS.set(false);
+
+    assert!(
+        !in_tree,
+        "{call_ctx}: access to node inside the scene tree from a worker task; \
+        return the data from the task and apply it on the main thread instead"
+    );
+}
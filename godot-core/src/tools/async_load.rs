@@ -0,0 +1,416 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+use crate::builtin::{GString, Variant, VariantArray};
+use crate::classes::resource_loader::ThreadLoadStatus;
+use crate::classes::{Object, Resource, ResourceLoader};
+use crate::global::Error as GodotError;
+use crate::meta::error::IoError;
+use crate::meta::ToGodot;
+use crate::obj::{Gd, Inherits};
+
+/// Status of a background load started with [`ResourceLoader::load_async()`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum AsyncLoadStatus {
+    /// Loading is still ongoing; the value is the progress between 0.0 and 1.0.
+    InProgress(f32),
+
+    /// The resource has been loaded successfully.
+    Loaded,
+
+    /// Loading failed, or the loaded resource is not of the requested class.
+    Failed,
+}
+
+/// Handle to a resource that is loaded in the background, obtained from [`ResourceLoader::load_async()`].
+///
+/// Wraps Godot's `ResourceLoader.load_threaded_*()` functions, keeping track of the request state. The handle can be polled each frame
+/// with [`poll_status()`][Self::poll_status] and [`try_get()`][Self::try_get], or awaited as a [`Future`].
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::{PackedScene, ResourceLoader};
+/// use godot::tools::AsyncLoadStatus;
+///
+/// let mut level = ResourceLoader::load_async::<PackedScene>("res://levels/big.tscn").unwrap();
+///
+/// // Each frame:
+/// match level.poll_status() {
+///     AsyncLoadStatus::InProgress(progress) => godot::global::godot_print!("{:.0}%", progress * 100.0),
+///     AsyncLoadStatus::Loaded => {
+///         let scene = level.try_get().unwrap().unwrap();
+///     }
+///     AsyncLoadStatus::Failed => godot::global::godot_error!("failed to load level"),
+/// }
+/// ```
+///
+/// # Futures
+/// Godot does not notify when a threaded load completes. While the future is pending, it checks the status once per frame (using
+/// [`add_process_callback()`][crate::tools::add_process_callback]) and only wakes the task once loading has finished. Before Godot 4.2,
+/// the future re-schedules itself on every poll instead. On a thread-blocking executor, prefer [`wait()`][Self::wait].
+///
+/// # Dropping
+/// Godot offers no way to cancel a threaded load, and keeps the result around until it is fetched. When a pending handle is dropped,
+/// the request is therefore released as soon as loading finishes, discarding the resource. Before Godot 4.2, dropping the handle
+/// blocks until loading has finished.
+///
+/// # Thread safety
+/// Godot loads the resource on a separate thread. If the resource (or one of its sub-resources) is a Rust class, its constructor
+/// runs on that thread. This is only sound if the class does not access thread-unsafe state. Since gdext's object model is not yet
+/// thread-safe, this API is only available with the `experimental-threads` feature. Resources consisting only of engine classes can
+/// be loaded without concern.
+pub struct AsyncLoad<T: Inherits<Resource>> {
+    path: GString,
+    progress: VariantArray,
+    state: LoadState<T>,
+
+    /// Waker of the task awaiting this load; taken once loading has finished.
+    waker: Rc<RefCell<Option<Waker>>>,
+
+    /// Frame callback that wakes the awaiting task.
+    #[cfg(since_api = "4.2")]
+    watcher: Option<crate::tools::FrameCallbackHandle>,
+}
+
+enum LoadState<T: Inherits<Resource>> {
+    Pending,
+    Loaded(Gd<T>),
+    Failed,
+}
+
+impl<T: Inherits<Resource>> AsyncLoad<T> {
+    fn request(path: GString, use_sub_threads: bool) -> Result<Self, IoError> {
+        let err = call_loader(
+            "load_threaded_request",
+            &[
+                path.to_variant(),
+                T::class_name().to_gstring().to_variant(),
+                use_sub_threads.to_variant(),
+            ],
+        );
+
+        if err.try_to::<GodotError>().ok() != Some(GodotError::OK) {
+            return Err(IoError::loading(
+                T::class_name().to_string(),
+                path.to_string(),
+            ));
+        }
+
+        Ok(Self {
+            path,
+            progress: VariantArray::new(),
+            state: LoadState::Pending,
+            waker: Rc::new(RefCell::new(None)),
+            #[cfg(since_api = "4.2")]
+            watcher: None,
+        })
+    }
+
+    /// Path of the resource being loaded.
+    pub fn path(&self) -> &GString {
+        &self.path
+    }
+
+    /// Queries the current status of the background load.
+    ///
+    /// Once the status is [`Loaded`][AsyncLoadStatus::Loaded] or [`Failed`][AsyncLoadStatus::Failed], it does not change anymore.
+    pub fn poll_status(&mut self) -> AsyncLoadStatus {
+        match self.state {
+            LoadState::Pending => {}
+            LoadState::Loaded(_) => return AsyncLoadStatus::Loaded,
+            LoadState::Failed => return AsyncLoadStatus::Failed,
+        }
+
+        // The progress array is shared with Godot, which stores the current progress as its only element.
+        let status = call_loader(
+            "load_threaded_get_status",
+            &[self.path.to_variant(), self.progress.to_variant()],
+        );
+
+        let status = status.try_to::<ThreadLoadStatus>().ok();
+        if status == Some(ThreadLoadStatus::IN_PROGRESS) {
+            return AsyncLoadStatus::InProgress(self.read_progress());
+        }
+
+        if status == Some(ThreadLoadStatus::LOADED) {
+            self.fetch();
+        } else {
+            self.state = LoadState::Failed;
+        }
+
+        self.poll_status()
+    }
+
+    /// Returns the loading progress between 0.0 and 1.0.
+    pub fn progress(&mut self) -> f32 {
+        match self.poll_status() {
+            AsyncLoadStatus::InProgress(progress) => progress,
+            AsyncLoadStatus::Loaded | AsyncLoadStatus::Failed => 1.0,
+        }
+    }
+
+    /// Returns whether loading has finished, either successfully or not.
+    pub fn is_done(&mut self) -> bool {
+        !matches!(self.poll_status(), AsyncLoadStatus::InProgress(_))
+    }
+
+    /// Returns the loaded resource, or `None` if loading is still in progress.
+    ///
+    /// Can be called repeatedly; once loaded, the same object is returned each time.
+    pub fn try_get(&mut self) -> Option<Result<Gd<T>, IoError>> {
+        match self.poll_status() {
+            AsyncLoadStatus::InProgress(_) => None,
+            AsyncLoadStatus::Loaded | AsyncLoadStatus::Failed => Some(self.result()),
+        }
+    }
+
+    /// Blocks the current thread until loading has finished, and returns the resource.
+    pub fn wait(mut self) -> Result<Gd<T>, IoError> {
+        if let LoadState::Pending = self.state {
+            // load_threaded_get() blocks until the resource is available.
+            self.fetch();
+        }
+
+        self.result()
+    }
+
+    /// Invokes `callback` on the main thread once loading has finished.
+    ///
+    /// The status is checked once per frame, using [`add_process_callback()`][crate::tools::add_process_callback].
+    #[cfg(since_api = "4.2")]
+    pub fn on_finished<F>(mut self, callback: F)
+    where
+        T: 'static,
+        F: FnOnce(Result<Gd<T>, IoError>) + 'static,
+    {
+        use std::cell::Cell;
+
+        let handle = Rc::new(Cell::new(None));
+        let handle_in_callback = handle.clone();
+        let mut callback = Some(callback);
+
+        let registered = crate::tools::add_process_callback(move |_delta| {
+            let Some(result) = self.try_get() else {
+                return;
+            };
+
+            if let Some(handle) = handle_in_callback.take() {
+                crate::tools::remove_frame_callback(handle);
+            }
+            if let Some(callback) = callback.take() {
+                callback(result);
+            }
+        });
+
+        handle.set(Some(registered));
+    }
+
+    fn fetch(&mut self) {
+        let resource = call_loader("load_threaded_get", &[self.path.to_variant()]);
+
+        // Godot forgets about the request after load_threaded_get(), so the outcome is cached.
+        self.state = match resource.try_to::<Option<Gd<Resource>>>() {
+            Ok(Some(res)) => match res.try_cast::<T>() {
+                Ok(obj) => LoadState::Loaded(obj),
+                Err(_) => LoadState::Failed,
+            },
+            Ok(None) | Err(_) => LoadState::Failed,
+        };
+    }
+
+    fn result(&self) -> Result<Gd<T>, IoError> {
+        match &self.state {
+            LoadState::Loaded(obj) => Ok(obj.clone()),
+            LoadState::Pending | LoadState::Failed => Err(IoError::loading(
+                T::class_name().to_string(),
+                self.path.to_string(),
+            )),
+        }
+    }
+
+    fn read_progress(&self) -> f32 {
+        self.progress
+            .try_get(0)
+            .and_then(|v| v.try_to::<f32>().ok())
+            .unwrap_or(0.0)
+    }
+
+    /// Makes sure that `waker` is invoked once loading has finished.
+    #[cfg(since_api = "4.2")]
+    fn wake_when_done(&mut self, waker: &Waker) {
+        *self.waker.borrow_mut() = Some(waker.clone());
+
+        if self.watcher.is_some() {
+            return;
+        }
+
+        let path = self.path.clone();
+        let progress = VariantArray::new();
+        let waker = self.waker.clone();
+
+        let watcher = crate::tools::add_process_callback(move |_delta| {
+            // Only query Godot while a task is waiting.
+            if waker.borrow().is_none() || is_in_progress(&path, &progress) {
+                return;
+            }
+
+            if let Some(waker) = waker.borrow_mut().take() {
+                waker.wake();
+            }
+        });
+
+        self.watcher = Some(watcher);
+    }
+
+    #[cfg(before_api = "4.2")]
+    fn wake_when_done(&mut self, waker: &Waker) {
+        // No frame callbacks available; re-schedule until done.
+        waker.wake_by_ref();
+    }
+
+    fn stop_watching(&mut self) {
+        self.waker.borrow_mut().take();
+
+        #[cfg(since_api = "4.2")]
+        if let Some(watcher) = self.watcher.take() {
+            crate::tools::remove_frame_callback(watcher);
+        }
+    }
+}
+
+impl<T: Inherits<Resource>> Drop for AsyncLoad<T> {
+    fn drop(&mut self) {
+        self.stop_watching();
+
+        if !matches!(self.state, LoadState::Pending) {
+            return;
+        }
+
+        release_when_done(self.path.clone());
+    }
+}
+
+// The future does not rely on pinning; Gd<T> is a handle that may be moved freely.
+impl<T: Inherits<Resource>> Unpin for AsyncLoad<T> {}
+
+impl<T: Inherits<Resource>> Future for AsyncLoad<T> {
+    type Output = Result<Gd<T>, IoError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        match this.try_get() {
+            Some(result) => {
+                this.stop_watching();
+                Poll::Ready(result)
+            }
+            None => {
+                this.wake_when_done(cx.waker());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<T: Inherits<Resource>> fmt::Debug for AsyncLoad<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = match self.state {
+            LoadState::Pending => "pending",
+            LoadState::Loaded(_) => "loaded",
+            LoadState::Failed => "failed",
+        };
+
+        f.debug_struct("AsyncLoad")
+            .field("class", &T::class_name())
+            .field("path", &self.path)
+            .field("state", &state)
+            .finish()
+    }
+}
+
+/// Calls a method on the `ResourceLoader` singleton dynamically.
+///
+/// The threaded-loading methods are excluded from codegen, since they are not safe to use in general (see "Thread safety" on
+/// [`AsyncLoad`]). This type encapsulates them behind an API that documents the restrictions.
+fn call_loader(method: &str, args: &[Variant]) -> Variant {
+    ResourceLoader::singleton()
+        .upcast::<Object>()
+        .call(method.into(), args)
+}
+
+fn is_in_progress(path: &GString, progress: &VariantArray) -> bool {
+    let status = call_loader(
+        "load_threaded_get_status",
+        &[path.to_variant(), progress.to_variant()],
+    );
+
+    status.try_to::<ThreadLoadStatus>().ok() == Some(ThreadLoadStatus::IN_PROGRESS)
+}
+
+/// Fetches and discards the result of an abandoned request once it has finished, so that Godot releases it.
+#[cfg(since_api = "4.2")]
+fn release_when_done(path: GString) {
+    use std::cell::Cell;
+
+    let progress = VariantArray::new();
+    let handle = Rc::new(Cell::new(None));
+    let handle_in_callback = handle.clone();
+
+    let registered = crate::tools::add_process_callback(move |_delta| {
+        if is_in_progress(&path, &progress) {
+            return;
+        }
+
+        call_loader("load_threaded_get", &[path.to_variant()]);
+
+        if let Some(handle) = handle_in_callback.take() {
+            crate::tools::remove_frame_callback(handle);
+        }
+    });
+
+    handle.set(Some(registered));
+}
+
+/// Blocks until the abandoned request has finished, so that Godot releases it.
+#[cfg(before_api = "4.2")]
+fn release_when_done(path: GString) {
+    call_loader("load_threaded_get", &[path.to_variant()]);
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `ResourceLoader` class.
+impl ResourceLoader {
+    /// Starts loading the resource at `path` in a background thread.
+    ///
+    /// Returns a handle to track progress and obtain the resource of class `T`. Fails if Godot cannot start the request, e.g. because the
+    /// path does not exist. A failed cast to `T` is only detected once loading has finished.
+    ///
+    /// Only available with the `experimental-threads` feature, because Rust classes among the loaded resources are constructed on the
+    /// loading thread; see [thread safety](AsyncLoad#thread-safety).
+    pub fn load_async<T>(path: impl Into<GString>) -> Result<AsyncLoad<T>, IoError>
+    where
+        T: Inherits<Resource>,
+    {
+        AsyncLoad::request(path.into(), false)
+    }
+
+    /// Like [`load_async()`][Self::load_async], but allows Godot to use multiple threads for loading sub-resources.
+    pub fn load_async_with_sub_threads<T>(path: impl Into<GString>) -> Result<AsyncLoad<T>, IoError>
+    where
+        T: Inherits<Resource>,
+    {
+        AsyncLoad::request(path.into(), true)
+    }
+}
@@ -0,0 +1,338 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Generating audio from Rust, through [`AudioStreamGenerator`].
+//!
+//! An `AudioStreamGenerator` plays stereo frames that are pushed to its [`AudioStreamGeneratorPlayback`]. Pushing frames one by one
+//! with `push_frame()` costs one engine call per frame, which adds up to tens of thousands of calls per second. [`GeneratorWriter`]
+//! pushes whole slices through a reused packed array instead, and keeps track of buffer underruns.
+//!
+//! If frames are produced elsewhere (e.g. on a synthesizer thread, or in bursts), [`FrameRingBuffer`] can hold them until the
+//! generator has room.
+//!
+//! # Example
+//! ```no_run
+//! use godot::prelude::*;
+//! use godot::classes::AudioStreamPlayer;
+//! use godot::tools::audio::GeneratorWriter;
+//!
+//! #[derive(GodotClass)]
+//! #[class(init, base=AudioStreamPlayer)]
+//! struct SineSynth {
+//!     phase: f32,
+//!     writer: Option<GeneratorWriter>,
+//!     base: Base<AudioStreamPlayer>,
+//! }
+//!
+//! #[godot_api]
+//! impl IAudioStreamPlayer for SineSynth {
+//!     fn ready(&mut self) {
+//!         self.base_mut().play();
+//!         self.writer = GeneratorWriter::from_player(&self.base());
+//!     }
+//!
+//!     fn process(&mut self, _delta: f64) {
+//!         let Some(writer) = self.writer.as_mut() else { return };
+//!         let increment = 440.0 / writer.mix_rate();
+//!         let mut phase = self.phase;
+//!
+//!         writer.fill_available(|frames| {
+//!             for frame in frames {
+//!                 let sample = (phase * std::f32::consts::TAU).sin() * 0.3;
+//!                 *frame = Vector2::new(sample, sample);
+//!                 phase = (phase + increment).fract();
+//!             }
+//!         });
+//!
+//!         self.phase = phase;
+//!     }
+//! }
+//! ```
+
+use std::fmt;
+
+use crate::builtin::{PackedVector2Array, Vector2};
+use crate::classes::{AudioStreamGenerator, AudioStreamGeneratorPlayback, AudioStreamPlayer};
+use crate::obj::Gd;
+
+/// Pushes frames into an [`AudioStreamGeneratorPlayback`] in bulk.
+///
+/// Frames are copied into an internal packed array that is reused across calls, so that steady-state pushing does not allocate.
+pub struct GeneratorWriter {
+    playback: Gd<AudioStreamGeneratorPlayback>,
+    mix_rate: f32,
+    scratch: PackedVector2Array,
+    reported_skips: i32,
+}
+
+impl GeneratorWriter {
+    /// Wraps the playback of a generator stream. `mix_rate` is the sample rate of the stream, see [`mix_rate()`][Self::mix_rate].
+    pub fn new(playback: Gd<AudioStreamGeneratorPlayback>, mix_rate: f32) -> Self {
+        let reported_skips = playback.get_skips();
+
+        Self {
+            playback,
+            mix_rate,
+            scratch: PackedVector2Array::new(),
+            reported_skips,
+        }
+    }
+
+    /// Obtains the writer for a player whose stream is an [`AudioStreamGenerator`].
+    ///
+    /// Returns `None` if the player has a different stream, or is not playing (Godot only creates the playback once `play()` is called).
+    pub fn from_player(player: &Gd<AudioStreamPlayer>) -> Option<Self> {
+        let generator = player
+            .get_stream()?
+            .try_cast::<AudioStreamGenerator>()
+            .ok()?;
+        let playback = player
+            .get_stream_playback()?
+            .try_cast::<AudioStreamGeneratorPlayback>()
+            .ok()?;
+
+        Some(Self::new(playback, generator.get_mix_rate()))
+    }
+
+    /// The wrapped playback.
+    pub fn playback(&self) -> &Gd<AudioStreamGeneratorPlayback> {
+        &self.playback
+    }
+
+    /// Sample rate in Hz, i.e. how many frames are played per second.
+    pub fn mix_rate(&self) -> f32 {
+        self.mix_rate
+    }
+
+    /// Number of frames that can currently be pushed without overflowing the generator's buffer.
+    pub fn frames_available(&self) -> usize {
+        self.playback.get_frames_available().max(0) as usize
+    }
+
+    /// Pushes as many `frames` as fit into the buffer, in order. Returns how many have been pushed.
+    pub fn push_frames(&mut self, frames: &[Vector2]) -> usize {
+        let count = frames.len().min(self.frames_available());
+        if count == 0 {
+            return 0;
+        }
+
+        self.scratch.resize(count);
+        self.scratch
+            .as_mut_slice()
+            .copy_from_slice(&frames[..count]);
+        self.push_scratch();
+
+        count
+    }
+
+    /// Lets `fill` write directly into all currently available frames, then pushes them. Returns the number of frames.
+    ///
+    /// The slice passed to `fill` is zero-initialized only the first time it reaches a given size; otherwise it holds the previously
+    /// written frames. Overwrite all of them.
+    pub fn fill_available(&mut self, fill: impl FnOnce(&mut [Vector2])) -> usize {
+        let count = self.frames_available();
+        if count == 0 {
+            return 0;
+        }
+
+        self.scratch.resize(count);
+        fill(self.scratch.as_mut_slice());
+        self.push_scratch();
+
+        count
+    }
+
+    /// Moves as many frames as fit from `ring` into the generator. Returns how many have been moved.
+    pub fn push_from_ring(&mut self, ring: &mut FrameRingBuffer) -> usize {
+        let count = ring.len().min(self.frames_available());
+        if count == 0 {
+            return 0;
+        }
+
+        self.scratch.resize(count);
+        let popped = ring.pop_into(self.scratch.as_mut_slice());
+        debug_assert_eq!(popped, count);
+        self.push_scratch();
+
+        count
+    }
+
+    /// Number of buffer underruns (frames the generator had to skip because nothing was pushed in time) since the last call.
+    ///
+    /// A non-zero value indicates audible glitches; push more frames per call, or use a larger `buffer_length` on the stream.
+    pub fn take_underruns(&mut self) -> u32 {
+        let skips = self.playback.get_skips();
+        let new_skips = skips.saturating_sub(self.reported_skips).max(0);
+        self.reported_skips = skips;
+
+        new_skips as u32
+    }
+
+    /// Discards all frames in the generator's buffer that have not been played yet.
+    pub fn clear(&mut self) {
+        self.playback.clear_buffer();
+    }
+
+    fn push_scratch(&mut self) {
+        // The packed array is reference-counted; the clone is released after the call, so the next resize doesn't copy.
+        let pushed = self.playback.push_buffer(self.scratch.clone());
+        debug_assert!(pushed, "pushed more frames than available");
+    }
+}
+
+impl fmt::Debug for GeneratorWriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GeneratorWriter")
+            .field("playback", &self.playback)
+            .field("mix_rate", &self.mix_rate)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Fixed-capacity FIFO buffer of stereo frames.
+///
+/// Pushing into a full buffer stores only as many frames as fit, so producing code can tell how much was dropped. No allocations happen
+/// after construction.
+///
+/// The buffer itself is not synchronized. To share it between a producer thread and the main thread, wrap it in a `Mutex`.
+#[derive(Clone)]
+pub struct FrameRingBuffer {
+    frames: Box<[Vector2]>,
+    read: usize,
+    len: usize,
+}
+
+impl FrameRingBuffer {
+    /// Creates an empty buffer holding up to `capacity` frames.
+    ///
+    /// # Panics
+    /// If `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "FrameRingBuffer capacity must be positive");
+
+        Self {
+            frames: vec![Vector2::ZERO; capacity].into_boxed_slice(),
+            read: 0,
+            len: 0,
+        }
+    }
+
+    /// Creates a buffer that holds as many frames as the generator's own buffer (`mix_rate * buffer_length`).
+    ///
+    /// A fractional frame count is rounded up, so the buffer is never smaller than the generator's.
+    pub fn for_generator(generator: &Gd<AudioStreamGenerator>) -> Self {
+        // Godot stores both properties as 32-bit floats; narrowing the product drops the widening error (e.g. 800.0000119 for
+        // 8000 * 0.1), which would otherwise be rounded up to an extra frame.
+        let frames = (generator.get_mix_rate() * generator.get_buffer_length()) as f32;
+        Self::new((frames.ceil() as usize).max(1))
+    }
+
+    /// Maximum number of frames.
+    pub fn capacity(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Number of frames currently stored.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of frames that can be pushed before the buffer is full.
+    pub fn free_len(&self) -> usize {
+        self.capacity() - self.len
+    }
+
+    /// Appends as many `frames` as fit. Returns how many have been stored; the rest is dropped.
+    pub fn push(&mut self, frames: &[Vector2]) -> usize {
+        let count = frames.len().min(self.free_len());
+        let capacity = self.capacity();
+        let write = (self.read + self.len) % capacity;
+
+        // Stored in up to two contiguous parts: until the end of the storage, then from the start.
+        let first = count.min(capacity - write);
+        self.frames[write..write + first].copy_from_slice(&frames[..first]);
+        self.frames[..count - first].copy_from_slice(&frames[first..count]);
+
+        self.len += count;
+        count
+    }
+
+    /// Removes the oldest frames and writes them to `out`. Returns how many have been written.
+    pub fn pop_into(&mut self, out: &mut [Vector2]) -> usize {
+        let count = out.len().min(self.len);
+        let capacity = self.capacity();
+
+        let first = count.min(capacity - self.read);
+        out[..first].copy_from_slice(&self.frames[self.read..self.read + first]);
+        out[first..count].copy_from_slice(&self.frames[..count - first]);
+
+        self.read = (self.read + count) % capacity;
+        self.len -= count;
+        count
+    }
+
+    /// Removes all frames.
+    pub fn clear(&mut self) {
+        self.read = 0;
+        self.len = 0;
+    }
+}
+
+impl fmt::Debug for FrameRingBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrameRingBuffer")
+            .field("len", &self.len)
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frames(range: std::ops::Range<i32>) -> Vec<Vector2> {
+        range.map(|i| Vector2::new(i as f32, -i as f32)).collect()
+    }
+
+    #[test]
+    fn ring_push_pop_wraps_around() {
+        let mut ring = FrameRingBuffer::new(4);
+        assert_eq!(ring.push(&frames(0..3)), 3);
+
+        let mut out = [Vector2::ZERO; 2];
+        assert_eq!(ring.pop_into(&mut out), 2);
+        assert_eq!(out.to_vec(), frames(0..2));
+
+        // Wraps: one slot at the end, two at the start.
+        assert_eq!(ring.push(&frames(3..6)), 3);
+        assert_eq!(ring.len(), 4);
+        assert_eq!(ring.free_len(), 0);
+
+        let mut out = [Vector2::ZERO; 8];
+        assert_eq!(ring.pop_into(&mut out), 4);
+        assert_eq!(out[..4].to_vec(), frames(2..6));
+        assert!(ring.is_empty());
+    }
+
+    #[test]
+    fn ring_push_drops_overflow() {
+        let mut ring = FrameRingBuffer::new(3);
+        assert_eq!(ring.push(&frames(0..5)), 3);
+        assert_eq!(ring.push(&frames(5..6)), 0);
+
+        let mut out = [Vector2::ZERO; 3];
+        ring.pop_into(&mut out);
+        assert_eq!(out.to_vec(), frames(0..3));
+    }
+}
@@ -0,0 +1,111 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Rid, Vector2, Vector3};
+use crate::classes::{Object, PhysicsDirectBodyState2D, PhysicsDirectBodyState3D};
+use crate::obj::Gd;
+
+/// Generates the contact struct and `PhysicsDirectBodyState` helpers for one dimension.
+macro_rules! impl_body_state {
+    ($Contact:ident, $BodyState:ident, $Vector:ty, $dim:literal) => {
+        #[doc = concat!("One contact of a body, as reported by [`", stringify!($BodyState), "`].")]
+        ///
+        /// Positions and normals are in global coordinates. Using plain fields means the contact can be stored and passed around freely.
+        ///
+        /// A custom body state (a class inheriting `PhysicsDirectBodyState*Extension`) can keep a `Vec` of contacts and serve its
+        /// `get_contact_*` virtual functions by indexing into it. godot-rust provides no further helpers for custom physics servers.
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct $Contact {
+            /// Contact position on the body.
+            pub local_position: $Vector,
+
+            /// Contact normal, pointing away from the body.
+            pub local_normal: $Vector,
+
+            /// Index of the body's shape that is in contact.
+            pub local_shape: i32,
+
+            /// The other object, or `None` if it has no object (e.g. a body created directly through the physics server).
+            pub collider: Option<Gd<Object>>,
+
+            /// RID of the other object.
+            pub collider_rid: Rid,
+
+            /// Contact position on the other object.
+            pub collider_position: $Vector,
+
+            /// Index of the other object's shape that is in contact.
+            pub collider_shape: i32,
+
+            /// Velocity of the other object at the contact position.
+            pub collider_velocity_at_position: $Vector,
+
+            /// Impulse created by the contact.
+            pub impulse: $Vector,
+        }
+
+        #[doc = concat!("Manual extensions for the `", stringify!($BodyState), "` class.")]
+        ///
+        #[doc = concat!("These are meant for `IRigidBody", $dim, "::integrate_forces()`, which receives the body state every physics frame.")]
+        impl $BodyState {
+            /// Returns all contacts of the body.
+            ///
+            /// This is a convenience wrapper: Godot has no bulk API for contacts, so each of the 9 properties is still read with one engine call
+            /// per contact. What changes is that this happens once, and the result can then be iterated and indexed without further calls.
+            ///
+            /// Contacts are only reported if the body has `contact_monitor` enabled and `max_contacts_reported` greater than zero.
+            pub fn contacts(&self) -> Vec<$Contact> {
+                (0..self.get_contact_count())
+                    .map(|index| $Contact {
+                        local_position: self.get_contact_local_position(index),
+                        local_normal: self.get_contact_local_normal(index),
+                        local_shape: self.get_contact_local_shape(index),
+                        collider: self.get_contact_collider_object(index),
+                        collider_rid: self.get_contact_collider(index),
+                        collider_position: self.get_contact_collider_position(index),
+                        collider_shape: self.get_contact_collider_shape(index),
+                        collider_velocity_at_position: self
+                            .get_contact_collider_velocity_at_position(index),
+                        impulse: self.get_contact_impulse(index),
+                    })
+                    .collect()
+            }
+
+            /// Applies multiple impulses, each as pairs of `(impulse, position)`.
+            ///
+            /// Convenience wrapper that calls `apply_impulse()` once per impulse.
+            ///
+            /// Positions are offsets from the body origin in global coordinates, like in `apply_impulse()`.
+            pub fn apply_impulses(&mut self, impulses: &[($Vector, $Vector)]) {
+                for &(impulse, position) in impulses {
+                    self.apply_impulse_ex(impulse).position(position).done();
+                }
+            }
+
+            /// Applies multiple forces for the current physics frame, each as pairs of `(force, position)`.
+            ///
+            /// Convenience wrapper that calls `apply_force()` once per force.
+            ///
+            /// Positions are offsets from the body origin in global coordinates, like in `apply_force()`.
+            pub fn apply_forces(&mut self, forces: &[($Vector, $Vector)]) {
+                for &(force, position) in forces {
+                    self.apply_force_ex(force).position(position).done();
+                }
+            }
+
+            /// Sum of the impulses of all contacts, e.g. to estimate how hard the body was hit.
+            pub fn total_contact_impulse(&self) -> $Vector {
+                (0..self.get_contact_count())
+                    .map(|index| self.get_contact_impulse(index))
+                    .fold(<$Vector>::ZERO, |sum, impulse| sum + impulse)
+            }
+        }
+    };
+}
+
+impl_body_state!(Contact2D, PhysicsDirectBodyState2D, Vector2, "2D");
+impl_body_state!(Contact3D, PhysicsDirectBodyState3D, Vector3, "3D");
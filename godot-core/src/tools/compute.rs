@@ -0,0 +1,724 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Typed wrapper around compute pipelines of a [`RenderingDevice`].
+//!
+//! Running a compute shader through the raw `RenderingDevice` API involves many calls operating on untyped [`Rid`]s, all of which must be
+//! freed manually and in the right order. This module wraps them in RAII types:
+//!
+//! - [`ComputeDevice`] owns a `RenderingDevice`, usually a local one created for compute work.
+//! - [`ComputeShader`] holds a compiled compute shader together with its pipeline.
+//! - [`GpuBuffer<T>`] is a storage or uniform buffer with elements of type `T`, which is uploaded from and downloaded to slices.
+//! - [`UniformSet`] binds buffers to the `set` and `binding` slots declared in the shader.
+//!
+//! All resources are freed when dropped. They keep the device alive, so they can be dropped in any order.
+//!
+//! Buffer elements must implement [`BufferData`], i.e. be plain data without padding. Note that GLSL's `std430` layout aligns `vec3` to
+//! 16 bytes; use 4-component vectors or separate scalars in buffers.
+//!
+//! # Example
+//! ```no_run
+//! use godot::tools::compute::{ComputeDevice, ComputeError};
+//!
+//! fn double_all(values: &[f32]) -> Result<Vec<f32>, ComputeError> {
+//!     let device = ComputeDevice::local().expect("compute requires the Forward+ or Mobile renderer");
+//!     let shader = device.load_shader("res://shaders/double.glsl")?;
+//!
+//!     let buffer = device.storage_buffer(values);
+//!     let uniforms = shader.uniform_set(0).storage(0, &buffer).build();
+//!
+//!     device
+//!         .pass(&shader)
+//!         .uniform_set(&uniforms)
+//!         .dispatch(values.len().div_ceil(64) as u32, 1, 1);
+//!     device.submit_and_wait();
+//!
+//!     Ok(buffer.read())
+//! }
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::{size_of, size_of_val};
+use std::ops::Range;
+use std::rc::Rc;
+
+use crate::builtin::{Array, GString, PackedByteArray, Rid};
+use crate::classes::rendering_device::{ShaderStage, UniformType};
+use crate::classes::{
+    RdShaderFile, RdShaderSource, RdShaderSpirv, RdUniform, RenderingDevice, RenderingServer,
+};
+use crate::meta::error::IoError;
+use crate::obj::{Gd, NewGd};
+use crate::tools::try_load;
+
+/// Rendering device for compute work.
+///
+/// Cheap to clone; clones refer to the same device.
+#[derive(Clone)]
+pub struct ComputeDevice {
+    device: Rc<DeviceHandle>,
+}
+
+impl ComputeDevice {
+    /// Creates a new local rendering device, which runs independently of rendering and is synchronized explicitly.
+    ///
+    /// Returns `None` if the current renderer has no rendering device, e.g. with the Compatibility renderer or `--headless`.
+    pub fn local() -> Option<Self> {
+        let rd = RenderingServer::singleton().create_local_rendering_device()?;
+        Some(Self::from_handle(rd, true))
+    }
+
+    /// The main rendering device, shared with the renderer.
+    ///
+    /// Work dispatched on it runs as part of rendering the next frame; [`submit_and_wait()`][Self::submit_and_wait] is not available.
+    /// This is useful for shaders whose results are consumed by rendering, e.g. textures written by a compute shader.
+    pub fn main() -> Option<Self> {
+        let rd = RenderingServer::singleton().get_rendering_device()?;
+        Some(Self::from_handle(rd, false))
+    }
+
+    /// The underlying `RenderingDevice`, for operations not covered by this module.
+    pub fn rendering_device(&self) -> Gd<RenderingDevice> {
+        self.device.rd.clone()
+    }
+
+    /// Whether this is a local device created by [`local()`][Self::local].
+    pub fn is_local(&self) -> bool {
+        self.device.is_local
+    }
+
+    /// Loads a compute shader from a GLSL file imported by Godot (e.g. `res://shaders/boids.glsl` starting with `#[compute]`).
+    pub fn load_shader(&self, path: &str) -> Result<ComputeShader, ComputeError> {
+        let file = try_load::<RdShaderFile>(path).map_err(ComputeError::Load)?;
+
+        let base_error = file.get_base_error().to_string();
+        if !base_error.is_empty() {
+            return Err(ComputeError::Compile {
+                message: base_error,
+            });
+        }
+
+        let spirv = file.get_spirv().ok_or_else(|| ComputeError::Compile {
+            message: format!("shader file '{path}' contains no SPIR-V"),
+        })?;
+
+        self.create_shader(spirv, path)
+    }
+
+    /// Compiles a compute shader from GLSL source code.
+    ///
+    /// The source is the content of a compute shader file without the `#[compute]` line, i.e. starting with `#version`.
+    pub fn compile_shader(&self, glsl_source: &str) -> Result<ComputeShader, ComputeError> {
+        let mut source = RdShaderSource::new_gd();
+        source.set_stage_source(ShaderStage::COMPUTE, glsl_source.into());
+
+        let spirv = self
+            .rd()
+            .shader_compile_spirv_from_source(source)
+            .ok_or_else(|| ComputeError::Compile {
+                message: "shader compilation returned no SPIR-V".to_string(),
+            })?;
+
+        self.create_shader(spirv, "")
+    }
+
+    /// Creates a storage buffer initialized with `data`.
+    ///
+    /// Storage buffers can be read and written by shaders, and correspond to `buffer` blocks in GLSL.
+    pub fn storage_buffer<T: BufferData>(&self, data: &[T]) -> GpuBuffer<T> {
+        let bytes = PackedByteArray::from(as_bytes(data));
+        let rid = self
+            .rd()
+            .storage_buffer_create_ex(byte_len::<T>(data.len()))
+            .data(bytes)
+            .done();
+
+        GpuBuffer::new(self, rid, data.len(), BufferKind::Storage)
+    }
+
+    /// Creates a storage buffer with `len` zero-initialized elements.
+    pub fn storage_buffer_zeroed<T: BufferData>(&self, len: usize) -> GpuBuffer<T> {
+        let rid = self.rd().storage_buffer_create(byte_len::<T>(len));
+
+        GpuBuffer::new(self, rid, len, BufferKind::Storage)
+    }
+
+    /// Creates a uniform buffer initialized with `data`.
+    ///
+    /// Uniform buffers are read-only for shaders, and correspond to `uniform` blocks in GLSL. Their size should be a multiple of 16 bytes.
+    pub fn uniform_buffer<T: BufferData>(&self, data: &[T]) -> GpuBuffer<T> {
+        let bytes = PackedByteArray::from(as_bytes(data));
+        let rid = self
+            .rd()
+            .uniform_buffer_create_ex(byte_len::<T>(data.len()))
+            .data(bytes)
+            .done();
+
+        GpuBuffer::new(self, rid, data.len(), BufferKind::Uniform)
+    }
+
+    /// Starts recording a dispatch of `shader`.
+    pub fn pass<'a>(&'a self, shader: &'a ComputeShader) -> ComputePass<'a> {
+        ComputePass {
+            device: self,
+            shader,
+            uniform_sets: Vec::new(),
+            push_constants: Vec::new(),
+        }
+    }
+
+    /// Submits all dispatched work to the GPU and waits until it has finished.
+    ///
+    /// Afterward, the buffers contain the results and can be read.
+    ///
+    /// # Panics
+    /// If this is not a local device.
+    pub fn submit_and_wait(&self) {
+        self.submit();
+        self.wait();
+    }
+
+    /// Submits all dispatched work to the GPU, without waiting for it.
+    ///
+    /// Call [`wait()`][Self::wait] before reading results. In the meantime, the CPU can do other work.
+    ///
+    /// # Panics
+    /// If this is not a local device.
+    pub fn submit(&self) {
+        self.assert_local("submit()");
+        self.rd().submit();
+    }
+
+    /// Waits for work previously [submitted][Self::submit] to finish.
+    ///
+    /// # Panics
+    /// If this is not a local device.
+    pub fn wait(&self) {
+        self.assert_local("wait()");
+        self.rd().sync();
+    }
+
+    fn from_handle(rd: Gd<RenderingDevice>, is_local: bool) -> Self {
+        Self {
+            device: Rc::new(DeviceHandle { rd, is_local }),
+        }
+    }
+
+    fn rd(&self) -> Gd<RenderingDevice> {
+        self.device.rd.clone()
+    }
+
+    fn create_shader(
+        &self,
+        spirv: Gd<RdShaderSpirv>,
+        name: &str,
+    ) -> Result<ComputeShader, ComputeError> {
+        let compile_error = spirv
+            .get_stage_compile_error(ShaderStage::COMPUTE)
+            .to_string();
+        if !compile_error.is_empty() {
+            return Err(ComputeError::Compile {
+                message: compile_error,
+            });
+        }
+
+        let mut rd = self.rd();
+        let shader = rd
+            .shader_create_from_spirv_ex(spirv)
+            .name(GString::from(name))
+            .done();
+
+        if shader.is_invalid() {
+            return Err(ComputeError::Creation("shader"));
+        }
+
+        let pipeline = rd.compute_pipeline_create(shader);
+        if pipeline.is_invalid() {
+            rd.free_rid(shader);
+            return Err(ComputeError::Creation("compute pipeline"));
+        }
+
+        Ok(ComputeShader {
+            device: self.device.clone(),
+            shader,
+            pipeline,
+        })
+    }
+
+    fn assert_local(&self, method: &str) {
+        assert!(
+            self.device.is_local,
+            "ComputeDevice::{method} is only available for local devices; work on the main device is submitted by the renderer"
+        );
+    }
+}
+
+impl fmt::Debug for ComputeDevice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ComputeDevice")
+            .field("is_local", &self.device.is_local)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Compiled compute shader and its pipeline. Freed on drop.
+pub struct ComputeShader {
+    device: Rc<DeviceHandle>,
+    shader: Rid,
+    pipeline: Rid,
+}
+
+impl ComputeShader {
+    /// Starts building a uniform set for slot `set` of this shader, i.e. `layout(set = ...)` in GLSL.
+    pub fn uniform_set(&self, set: u32) -> UniformSetBuilder<'_> {
+        UniformSetBuilder {
+            shader: self,
+            set,
+            uniforms: Array::new(),
+        }
+    }
+
+    /// RID of the shader.
+    pub fn shader_rid(&self) -> Rid {
+        self.shader
+    }
+
+    /// RID of the compute pipeline.
+    pub fn pipeline_rid(&self) -> Rid {
+        self.pipeline
+    }
+}
+
+impl Drop for ComputeShader {
+    fn drop(&mut self) {
+        let mut rd = self.device.rd.clone();
+        rd.free_rid(self.pipeline);
+        rd.free_rid(self.shader);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Storage or uniform buffer on the GPU, holding `len()` elements of type `T`. Freed on drop.
+pub struct GpuBuffer<T: BufferData> {
+    device: Rc<DeviceHandle>,
+    rid: Rid,
+    len: usize,
+    kind: BufferKind,
+    _element: PhantomData<T>,
+}
+
+impl<T: BufferData> GpuBuffer<T> {
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the buffer has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Size in bytes.
+    pub fn size_bytes(&self) -> usize {
+        self.len * size_of::<T>()
+    }
+
+    /// RID of the buffer.
+    pub fn rid(&self) -> Rid {
+        self.rid
+    }
+
+    /// Overwrites elements starting at index `offset` with `data`.
+    ///
+    /// # Panics
+    /// If `offset + data.len()` exceeds the buffer length.
+    pub fn write(&self, offset: usize, data: &[T]) {
+        assert!(
+            offset + data.len() <= self.len,
+            "GpuBuffer::write(): range {offset}..{} out of bounds for length {}",
+            offset + data.len(),
+            self.len
+        );
+
+        self.device.rd.clone().buffer_update(
+            self.rid,
+            byte_len::<T>(offset),
+            byte_len::<T>(data.len()),
+            PackedByteArray::from(as_bytes(data)),
+        );
+    }
+
+    /// Downloads all elements.
+    ///
+    /// On a local device, call [`ComputeDevice::submit_and_wait()`] first, otherwise the results of dispatched work are not available.
+    pub fn read(&self) -> Vec<T> {
+        self.read_range(0..self.len)
+    }
+
+    /// Downloads the elements in `range`.
+    ///
+    /// # Panics
+    /// If `range` is out of bounds.
+    pub fn read_range(&self, range: Range<usize>) -> Vec<T> {
+        assert!(
+            range.start <= range.end && range.end <= self.len,
+            "GpuBuffer::read_range(): range {range:?} out of bounds for length {}",
+            self.len
+        );
+
+        if range.is_empty() {
+            return Vec::new();
+        }
+
+        let bytes = self
+            .device
+            .rd
+            .clone()
+            .buffer_get_data_ex(self.rid)
+            .offset_bytes(byte_len::<T>(range.start))
+            .size_bytes(byte_len::<T>(range.len()))
+            .done();
+
+        from_bytes(bytes.as_slice())
+    }
+}
+
+impl<T: BufferData> Drop for GpuBuffer<T> {
+    fn drop(&mut self) {
+        self.device.rd.clone().free_rid(self.rid);
+    }
+}
+
+impl<T: BufferData> fmt::Debug for GpuBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GpuBuffer")
+            .field("kind", &self.kind)
+            .field("len", &self.len)
+            .field("rid", &self.rid)
+            .finish()
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum BufferKind {
+    Storage,
+    Uniform,
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Buffers bound to the bindings of one uniform set slot. Freed on drop.
+pub struct UniformSet {
+    device: Rc<DeviceHandle>,
+    rid: Rid,
+    set: u32,
+}
+
+impl UniformSet {
+    /// RID of the uniform set.
+    pub fn rid(&self) -> Rid {
+        self.rid
+    }
+
+    /// Slot of the uniform set in the shader.
+    pub fn set_index(&self) -> u32 {
+        self.set
+    }
+}
+
+impl Drop for UniformSet {
+    fn drop(&mut self) {
+        // Godot frees uniform sets automatically when one of their buffers or the shader is freed.
+        let mut rd = self.device.rd.clone();
+        if rd.uniform_set_is_valid(self.rid) {
+            rd.free_rid(self.rid);
+        }
+    }
+}
+
+/// Builder for a [`UniformSet`], created by [`ComputeShader::uniform_set()`].
+#[must_use]
+pub struct UniformSetBuilder<'a> {
+    shader: &'a ComputeShader,
+    set: u32,
+    uniforms: Array<Gd<RdUniform>>,
+}
+
+impl<'a> UniformSetBuilder<'a> {
+    /// Binds a buffer to `binding`, as storage buffer.
+    ///
+    /// The buffer must have been created with [`ComputeDevice::storage_buffer()`] or [`storage_buffer_zeroed()`][ComputeDevice::storage_buffer_zeroed].
+    pub fn storage<T: BufferData>(self, binding: i32, buffer: &GpuBuffer<T>) -> Self {
+        debug_assert_eq!(buffer.kind, BufferKind::Storage, "expected storage buffer");
+        self.bind(UniformType::STORAGE_BUFFER, binding, buffer.rid)
+    }
+
+    /// Binds a buffer to `binding`, as uniform buffer.
+    ///
+    /// The buffer must have been created with [`ComputeDevice::uniform_buffer()`].
+    pub fn uniform<T: BufferData>(self, binding: i32, buffer: &GpuBuffer<T>) -> Self {
+        debug_assert_eq!(buffer.kind, BufferKind::Uniform, "expected uniform buffer");
+        self.bind(UniformType::UNIFORM_BUFFER, binding, buffer.rid)
+    }
+
+    /// Binds an arbitrary RID, e.g. a texture or sampler, with the given uniform type.
+    pub fn bind(mut self, uniform_type: UniformType, binding: i32, rid: Rid) -> Self {
+        let mut uniform = RdUniform::new_gd();
+        uniform.set_uniform_type(uniform_type);
+        uniform.set_binding(binding);
+        uniform.add_id(rid);
+
+        self.uniforms.push(uniform);
+        self
+    }
+
+    /// Creates the uniform set.
+    ///
+    /// # Panics
+    /// If Godot fails to create the uniform set, e.g. because the bindings do not match the shader.
+    pub fn build(self) -> UniformSet {
+        let device = self.shader.device.clone();
+        let rid = device
+            .rd
+            .clone()
+            .uniform_set_create(self.uniforms, self.shader.shader, self.set);
+
+        assert!(
+            rid.is_valid(),
+            "failed to create uniform set {}; do the bindings match the shader?",
+            self.set
+        );
+
+        UniformSet {
+            device,
+            rid,
+            set: self.set,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Single dispatch of a compute shader, created by [`ComputeDevice::pass()`].
+#[must_use = "a compute pass does nothing until dispatch() is called"]
+pub struct ComputePass<'a> {
+    device: &'a ComputeDevice,
+    shader: &'a ComputeShader,
+    uniform_sets: Vec<&'a UniformSet>,
+    push_constants: Vec<u8>,
+}
+
+impl<'a> ComputePass<'a> {
+    /// Binds a uniform set, at the slot it was created for.
+    pub fn uniform_set(mut self, uniform_set: &'a UniformSet) -> Self {
+        self.uniform_sets.push(uniform_set);
+        self
+    }
+
+    /// Sets the push constants, i.e. the `layout(push_constant)` block in GLSL.
+    ///
+    /// The data is padded with zeros to a multiple of 16 bytes, as required by Godot.
+    pub fn push_constants<T: BufferData>(mut self, data: &[T]) -> Self {
+        let mut bytes = as_bytes(data).to_vec();
+        bytes.resize(bytes.len().next_multiple_of(16), 0);
+
+        self.push_constants = bytes;
+        self
+    }
+
+    /// Dispatches the shader with the given number of work groups in each dimension.
+    ///
+    /// The number of invocations is the number of groups times the `local_size` declared in the shader.
+    pub fn dispatch(self, x_groups: u32, y_groups: u32, z_groups: u32) {
+        let mut rd = self.device.rd();
+
+        let list = rd.compute_list_begin();
+        rd.compute_list_bind_compute_pipeline(list, self.shader.pipeline);
+
+        for uniform_set in &self.uniform_sets {
+            rd.compute_list_bind_uniform_set(list, uniform_set.rid, uniform_set.set);
+        }
+
+        if !self.push_constants.is_empty() {
+            let size = self.push_constants.len() as u32;
+            rd.compute_list_set_push_constant(
+                list,
+                PackedByteArray::from(self.push_constants.as_slice()),
+                size,
+            );
+        }
+
+        rd.compute_list_dispatch(list, x_groups, y_groups, z_groups);
+        rd.compute_list_end();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Error while creating compute resources.
+#[derive(Debug)]
+pub enum ComputeError {
+    /// The shader file could not be loaded.
+    Load(IoError),
+
+    /// The shader failed to compile.
+    Compile {
+        /// Error message from the shader compiler.
+        message: String,
+    },
+
+    /// Godot returned an invalid RID for the given kind of resource.
+    Creation(&'static str),
+}
+
+impl fmt::Display for ComputeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Load(err) => write!(f, "failed to load compute shader: {err}"),
+            Self::Compile { message } => write!(f, "failed to compile compute shader: {message}"),
+            Self::Creation(what) => write!(f, "failed to create {what}"),
+        }
+    }
+}
+
+impl Error for ComputeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Load(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Plain data that can be copied byte-wise to and from GPU buffers.
+///
+/// Implemented for numeric scalars, arrays of them and 2- and 4-component vectors.
+///
+/// # Safety
+/// The type must have no padding bytes, and every bit pattern must be a valid value. Implementing it for `#[repr(C)]` structs made of
+/// `BufferData` fields without padding is sound.
+pub unsafe trait BufferData: Copy + 'static {}
+
+macro_rules! impl_buffer_data {
+    ($($Ty:ty),* $(,)?) => {
+        $(
+            // SAFETY: primitive numbers and #[repr(C)] vectors of them have no padding and no invalid bit patterns.
+            unsafe impl BufferData for $Ty {}
+        )*
+    };
+}
+
+impl_buffer_data!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+impl_buffer_data!(crate::builtin::Vector2i, crate::builtin::Vector4i);
+
+#[cfg(not(feature = "double-precision"))]
+impl_buffer_data!(
+    crate::builtin::Vector2,
+    crate::builtin::Vector4,
+    crate::builtin::Color
+);
+
+// SAFETY: arrays have no padding between elements.
+unsafe impl<T: BufferData, const N: usize> BufferData for [T; N] {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+/// Shared by all resources, so the device outlives them.
+struct DeviceHandle {
+    rd: Gd<RenderingDevice>,
+    is_local: bool,
+}
+
+impl Drop for DeviceHandle {
+    fn drop(&mut self) {
+        // The main device belongs to the renderer.
+        if self.is_local {
+            self.rd.clone().free();
+        }
+    }
+}
+
+impl<T: BufferData> GpuBuffer<T> {
+    fn new(device: &ComputeDevice, rid: Rid, len: usize, kind: BufferKind) -> Self {
+        assert!(
+            rid.is_valid(),
+            "failed to create GPU buffer of {len} elements"
+        );
+
+        Self {
+            device: device.device.clone(),
+            rid,
+            len,
+            kind,
+            _element: PhantomData,
+        }
+    }
+}
+
+fn byte_len<T>(len: usize) -> u32 {
+    u32::try_from(len * size_of::<T>()).expect("GPU buffer size exceeds u32::MAX bytes")
+}
+
+fn as_bytes<T: BufferData>(data: &[T]) -> &[u8] {
+    // SAFETY: BufferData guarantees no padding, so all bytes are initialized.
+    unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), size_of_val(data)) }
+}
+
+fn from_bytes<T: BufferData>(bytes: &[u8]) -> Vec<T> {
+    let len = bytes.len() / size_of::<T>();
+    let mut result = Vec::<T>::with_capacity(len);
+
+    // SAFETY: the destination has capacity for `len` elements, and BufferData guarantees that any bytes form valid values. Copying
+    // byte-wise avoids alignment requirements on the source.
+    unsafe {
+        std::ptr::copy_nonoverlapping(
+            bytes.as_ptr(),
+            result.as_mut_ptr().cast::<u8>(),
+            len * size_of::<T>(),
+        );
+        result.set_len(len);
+    }
+
+    result
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes_roundtrip() {
+        let values = [1.5_f32, -2.0, 3.25];
+        let bytes = as_bytes(&values);
+        assert_eq!(bytes.len(), 12);
+        assert_eq!(&bytes[..4], &1.5_f32.to_ne_bytes());
+
+        // Misaligned source.
+        let mut shifted = vec![0_u8];
+        shifted.extend_from_slice(bytes);
+        assert_eq!(from_bytes::<f32>(&shifted[1..]), values);
+
+        let arrays = [[1_u32, 2], [3, 4]];
+        assert_eq!(from_bytes::<[u32; 2]>(as_bytes(&arrays)), arrays);
+    }
+
+    #[test]
+    fn byte_len_scales() {
+        assert_eq!(byte_len::<f32>(16), 64);
+        assert_eq!(byte_len::<[f64; 4]>(2), 64);
+    }
+}
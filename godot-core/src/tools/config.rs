@@ -0,0 +1,480 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Loading configuration files in JSON, JSON5 or TOML format.
+//!
+//! Files are read through Godot's `FileAccess`, so `res://` and `user://` paths work, including in exported games where resources are
+//! packed into a `.pck` file. The format is derived from the file extension: `.json`, `.json5` or `.toml`.
+//!
+//! Contents can be loaded in two ways:
+//! - [`load()`] deserializes into any type implementing [`serde::Deserialize`]. Missing or mistyped entries are reported with their location.
+//! - [`load_dictionary()`] returns a [`Dictionary`], for dynamic access or to pass data on to GDScript.
+//!
+//! Errors carry the path of the file and, where available, line and column, so they can be shown to modders or designers as-is.
+//!
+//! ```no_run
+//! use godot::prelude::*;
+//! use godot::tools::config;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Balancing {
+//!     max_health: i32,
+//!     spawn_rate: f32,
+//!     enemies: Vec<String>,
+//! }
+//!
+//! fn load_balancing() -> Option<Balancing> {
+//!     match config::load::<Balancing>("res://config/balancing.toml") {
+//!         Ok(balancing) => Some(balancing),
+//!         Err(err) => {
+//!             // e.g. "res://config/balancing.toml:3:14: invalid type: string "fast", expected f32"
+//!             godot_error!("{err}");
+//!             None
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! In addition, [`to_json_value()`] and [`from_json_value()`] convert between variants -- including `Dictionary` and `Array` -- and
+//! [`serde_json::Value`].
+
+use std::error::Error;
+use std::fmt;
+use std::io::Read;
+
+use serde::de::DeserializeOwned;
+
+use crate::builtin::{
+    Dictionary, GString, PackedByteArray, PackedFloat32Array, PackedFloat64Array, PackedInt32Array,
+    PackedInt64Array, PackedStringArray, Variant, VariantArray, VariantType,
+};
+use crate::classes::file_access::ModeFlags;
+use crate::meta::error::ConvertError;
+use crate::meta::ToGodot;
+use crate::tools::GFile;
+
+/// File format of a configuration file.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ConfigFormat {
+    /// [JSON](https://www.json.org), extension `.json`.
+    Json,
+
+    /// [JSON5](https://json5.org), extension `.json5`. Allows comments, trailing commas and unquoted keys.
+    Json5,
+
+    /// [TOML](https://toml.io), extension `.toml`. Date and time values are loaded as strings.
+    Toml,
+}
+
+impl ConfigFormat {
+    /// Derives the format from the extension of `path` (case-insensitive), or returns `None` if the extension is not recognized.
+    pub fn from_path(path: &str) -> Option<Self> {
+        let (_, extension) = path.rsplit_once('.')?;
+
+        match extension.to_ascii_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            "json5" => Some(Self::Json5),
+            "toml" => Some(Self::Toml),
+            _ => None,
+        }
+    }
+}
+
+/// Loads the configuration file at `path` and deserializes it into `T`.
+///
+/// The format is determined by the file extension, see [`ConfigFormat::from_path()`].
+pub fn load<T: DeserializeOwned>(path: impl Into<GString>) -> Result<T, ConfigError> {
+    let (path, format, text) = read_file(path.into())?;
+    parse_with_path(&text, format, &path)
+}
+
+/// Loads the configuration file at `path` into a dictionary.
+///
+/// The top-level value of the file must be an object (JSON) or table (TOML). Integers are stored as `i64`, other numbers as `f64`.
+pub fn load_dictionary(path: impl Into<GString>) -> Result<Dictionary, ConfigError> {
+    let (path, format, text) = read_file(path.into())?;
+    parse_dictionary_with_path(&text, format, &path)
+}
+
+/// Deserializes `text` in the given format into `T`.
+///
+/// Like [`load()`], but for text which does not come from a file, e.g. downloaded data. In errors, the path is `<string>`.
+pub fn parse<T: DeserializeOwned>(text: &str, format: ConfigFormat) -> Result<T, ConfigError> {
+    parse_with_path(text, format, STRING_PATH)
+}
+
+/// Parses `text` in the given format into a dictionary.
+///
+/// Like [`load_dictionary()`], but for text which does not come from a file. In errors, the path is `<string>`.
+pub fn parse_dictionary(text: &str, format: ConfigFormat) -> Result<Dictionary, ConfigError> {
+    parse_dictionary_with_path(text, format, STRING_PATH)
+}
+
+/// Converts a JSON value into a variant.
+///
+/// Objects become [`Dictionary`], arrays become untyped [`VariantArray`]. Integers which fit into `i64` are stored as `INT`, all other
+/// numbers as `FLOAT`.
+pub fn from_json_value(value: &serde_json::Value) -> Variant {
+    use serde_json::Value;
+
+    match value {
+        Value::Null => Variant::nil(),
+        Value::Bool(b) => b.to_variant(),
+        Value::Number(number) => match number.as_i64() {
+            Some(int) => int.to_variant(),
+            None => number.as_f64().unwrap_or(f64::NAN).to_variant(),
+        },
+        Value::String(string) => string.to_variant(),
+        Value::Array(elements) => elements
+            .iter()
+            .map(from_json_value)
+            .collect::<VariantArray>()
+            .to_variant(),
+        Value::Object(entries) => entries
+            .iter()
+            .map(|(key, value)| (key.as_str(), from_json_value(value)))
+            .collect::<Dictionary>()
+            .to_variant(),
+    }
+}
+
+/// Converts a variant into a JSON value.
+///
+/// Supported are `nil`, `bool`, numbers, strings (including `StringName` and `NodePath`), arrays -- including packed arrays of numbers
+/// and strings -- and dictionaries with string keys. Other types, such as vectors or objects, have no JSON representation and result
+/// in an error, as do infinite and NaN floats.
+pub fn to_json_value(value: &Variant) -> Result<serde_json::Value, ConvertError> {
+    use serde_json::Value;
+
+    let json = match value.get_type() {
+        VariantType::NIL => Value::Null,
+        VariantType::BOOL => Value::Bool(value.to()),
+        VariantType::INT => Value::from(value.to::<i64>()),
+        VariantType::FLOAT => json_float(value.to::<f64>())?,
+        VariantType::STRING | VariantType::STRING_NAME | VariantType::NODE_PATH => {
+            Value::String(value.stringify().to_string())
+        }
+        VariantType::ARRAY => Value::Array(
+            value
+                .to::<VariantArray>()
+                .iter_shared()
+                .enumerate()
+                .map(|(i, element)| to_json_value(&element).map_err(|err| err.at_index(i)))
+                .collect::<Result<_, _>>()?,
+        ),
+        VariantType::DICTIONARY => {
+            let mut entries = serde_json::Map::new();
+            for (key, element) in value.to::<Dictionary>().iter_shared() {
+                if !matches!(
+                    key.get_type(),
+                    VariantType::STRING | VariantType::STRING_NAME
+                ) {
+                    return Err(ConvertError::new(format!(
+                        "JSON object keys must be strings, found {:?} key {key}",
+                        key.get_type()
+                    )));
+                }
+
+                let key = key.stringify().to_string();
+                let element = to_json_value(&element).map_err(|err| err.at_key(&key))?;
+                entries.insert(key, element);
+            }
+
+            Value::Object(entries)
+        }
+        VariantType::PACKED_BYTE_ARRAY => {
+            Value::from(value.to::<PackedByteArray>().as_slice().to_vec())
+        }
+        VariantType::PACKED_INT32_ARRAY => {
+            Value::from(value.to::<PackedInt32Array>().as_slice().to_vec())
+        }
+        VariantType::PACKED_INT64_ARRAY => {
+            Value::from(value.to::<PackedInt64Array>().as_slice().to_vec())
+        }
+        VariantType::PACKED_FLOAT32_ARRAY => Value::Array(
+            value
+                .to::<PackedFloat32Array>()
+                .as_slice()
+                .iter()
+                .map(|&f| json_float(f as f64))
+                .collect::<Result<_, _>>()?,
+        ),
+        VariantType::PACKED_FLOAT64_ARRAY => Value::Array(
+            value
+                .to::<PackedFloat64Array>()
+                .as_slice()
+                .iter()
+                .map(|&f| json_float(f))
+                .collect::<Result<_, _>>()?,
+        ),
+        VariantType::PACKED_STRING_ARRAY => {
+            Value::from(value.to::<PackedStringArray>().to_vec_string())
+        }
+        other => {
+            return Err(ConvertError::new(format!(
+                "{other:?} has no JSON representation"
+            )))
+        }
+    };
+
+    Ok(json)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Errors
+
+/// Error while loading a configuration file.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The file could not be opened or read.
+    Io { path: String, err: std::io::Error },
+
+    /// The file extension does not correspond to a supported [`ConfigFormat`].
+    UnknownFormat { path: String },
+
+    /// The content is not valid in its format, or does not match the structure of the target type.
+    Parse {
+        path: String,
+
+        /// 1-based line of the error, if known.
+        line: Option<usize>,
+
+        /// 1-based column of the error, if known.
+        column: Option<usize>,
+
+        message: String,
+    },
+}
+
+impl ConfigError {
+    /// Path of the file in which the error occurred.
+    pub fn path(&self) -> &str {
+        match self {
+            Self::Io { path, .. } | Self::UnknownFormat { path } | Self::Parse { path, .. } => path,
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, err } => write!(f, "{path}: cannot read file: {err}"),
+            Self::UnknownFormat { path } => write!(
+                f,
+                "{path}: unknown config format; expected extension .json, .json5 or .toml"
+            ),
+            Self::Parse {
+                path,
+                line,
+                column,
+                message,
+            } => match (line, column) {
+                (Some(line), Some(column)) => write!(f, "{path}:{line}:{column}: {message}"),
+                (Some(line), None) => write!(f, "{path}:{line}: {message}"),
+                _ => write!(f, "{path}: {message}"),
+            },
+        }
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io { err, .. } => Some(err),
+            _ => None,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+const STRING_PATH: &str = "<string>";
+
+fn read_file(path: GString) -> Result<(String, ConfigFormat, String), ConfigError> {
+    let path_str = path.to_string();
+    let Some(format) = ConfigFormat::from_path(&path_str) else {
+        return Err(ConfigError::UnknownFormat { path: path_str });
+    };
+
+    let mut text = String::new();
+    let result =
+        GFile::open(path, ModeFlags::READ).and_then(|mut file| file.read_to_string(&mut text));
+    if let Err(err) = result {
+        return Err(ConfigError::Io {
+            path: path_str,
+            err,
+        });
+    }
+
+    Ok((path_str, format, text))
+}
+
+fn parse_with_path<T: DeserializeOwned>(
+    text: &str,
+    format: ConfigFormat,
+    path: &str,
+) -> Result<T, ConfigError> {
+    match format {
+        ConfigFormat::Json => serde_json::from_str(text).map_err(|err| json_error(err, path)),
+        ConfigFormat::Json5 => json5::from_str(text).map_err(|err| json5_error(err, path)),
+        ConfigFormat::Toml => toml::from_str(text).map_err(|err| toml_error(err, text, path)),
+    }
+}
+
+fn parse_dictionary_with_path(
+    text: &str,
+    format: ConfigFormat,
+    path: &str,
+) -> Result<Dictionary, ConfigError> {
+    let root = match format {
+        // JSON5 is a superset of JSON, so both can share the conversion.
+        ConfigFormat::Json | ConfigFormat::Json5 => {
+            let value: serde_json::Value = parse_with_path(text, format, path)?;
+            from_json_value(&value)
+        }
+        ConfigFormat::Toml => {
+            let table: toml::Table = parse_with_path(text, format, path)?;
+            from_toml_table(&table).to_variant()
+        }
+    };
+
+    root.try_to::<Dictionary>().map_err(|_| ConfigError::Parse {
+        path: path.to_string(),
+        line: None,
+        column: None,
+        message: format!(
+            "top-level value must be an object, found {:?}",
+            root.get_type()
+        ),
+    })
+}
+
+fn from_toml_table(table: &toml::Table) -> Dictionary {
+    table
+        .iter()
+        .map(|(key, value)| (key.as_str(), from_toml_value(value)))
+        .collect()
+}
+
+fn from_toml_value(value: &toml::Value) -> Variant {
+    use toml::Value;
+
+    match value {
+        Value::String(string) => string.to_variant(),
+        Value::Integer(int) => int.to_variant(),
+        Value::Float(float) => float.to_variant(),
+        Value::Boolean(b) => b.to_variant(),
+        Value::Datetime(datetime) => datetime.to_string().to_variant(),
+        Value::Array(elements) => elements
+            .iter()
+            .map(from_toml_value)
+            .collect::<VariantArray>()
+            .to_variant(),
+        Value::Table(table) => from_toml_table(table).to_variant(),
+    }
+}
+
+fn json_float(value: f64) -> Result<serde_json::Value, ConvertError> {
+    serde_json::Number::from_f64(value)
+        .map(serde_json::Value::Number)
+        .ok_or_else(|| ConvertError::new(format!("float {value} has no JSON representation")))
+}
+
+fn json_error(err: serde_json::Error, path: &str) -> ConfigError {
+    // serde_json reports line 0 for errors without position, e.g. I/O.
+    let line = Some(err.line()).filter(|&line| line > 0);
+
+    ConfigError::Parse {
+        path: path.to_string(),
+        line,
+        column: line.map(|_| err.column()),
+        message: strip_position_suffix(&err.to_string()),
+    }
+}
+
+fn json5_error(err: json5::Error, path: &str) -> ConfigError {
+    let json5::Error::Message { msg, location } = err;
+
+    ConfigError::Parse {
+        path: path.to_string(),
+        line: location.as_ref().map(|loc| loc.line),
+        column: location.as_ref().map(|loc| loc.column),
+        message: msg,
+    }
+}
+
+fn toml_error(err: toml::de::Error, text: &str, path: &str) -> ConfigError {
+    let position = err.span().map(|span| line_column(text, span.start));
+
+    ConfigError::Parse {
+        path: path.to_string(),
+        line: position.map(|(line, _)| line),
+        column: position.map(|(_, column)| column),
+        message: err.message().to_string(),
+    }
+}
+
+/// Removes the " at line X column Y" suffix from serde_json messages, since the position is displayed separately.
+fn strip_position_suffix(message: &str) -> String {
+    match message.rfind(" at line ") {
+        Some(index) => message[..index].to_string(),
+        None => message.to_string(),
+    }
+}
+
+/// 1-based line and column (in characters) of the byte offset `offset` in `text`.
+fn line_column(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..offset.min(text.len())];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+    let line = before.matches('\n').count() + 1;
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_from_path() {
+        assert_eq!(
+            ConfigFormat::from_path("res://a/b.json"),
+            Some(ConfigFormat::Json)
+        );
+        assert_eq!(
+            ConfigFormat::from_path("user://mods/x.JSON5"),
+            Some(ConfigFormat::Json5)
+        );
+        assert_eq!(
+            ConfigFormat::from_path("settings.toml"),
+            Some(ConfigFormat::Toml)
+        );
+        assert_eq!(ConfigFormat::from_path("res://readme.txt"), None);
+        assert_eq!(ConfigFormat::from_path("res://json"), None);
+    }
+
+    #[test]
+    fn line_column_positions() {
+        let text = "a = 1\nbé = 2\n";
+        assert_eq!(line_column(text, 0), (1, 1));
+        assert_eq!(line_column(text, 4), (1, 5));
+        assert_eq!(line_column(text, 6), (2, 1));
+        assert_eq!(line_column(text, text.rfind('=').unwrap()), (2, 4));
+    }
+
+    #[test]
+    fn position_suffix_stripped() {
+        assert_eq!(
+            strip_position_suffix("expected `,` or `}` at line 3 column 14"),
+            "expected `,` or `}`"
+        );
+        assert_eq!(strip_position_suffix("EOF"), "EOF");
+    }
+}
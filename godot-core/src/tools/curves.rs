@@ -0,0 +1,709 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Easing functions, splines and curves, evaluated in pure Rust.
+//!
+//! Engine objects like `Curve` or `Tween` can only be used on the main thread (without `experimental-threads`), and each evaluation is an
+//! FFI call. The types in this module reproduce the engine's math in Rust, so they can be sampled in tight loops and on worker threads:
+//!
+//! - [`ease()`] and [`transition()`] match `@GlobalScope.ease()` and `Tween.interpolate_value()`.
+//! - [`CurveData`] is a snapshot of a `Curve` resource, sampled like `Curve.sample()`.
+//! - [`BezierPath2D`] and [`BezierPath3D`] are snapshots of `Curve2D` and `Curve3D`, sampled like `samplef()`, or by distance.
+//! - [`CatmullRom`] interpolates smoothly through a list of points, like repeated `cubic_interpolate()` calls.
+//!
+//! ```no_run
+//! use godot::prelude::*;
+//! use godot::classes::Curve;
+//! use godot::tools::curves::CurveData;
+//!
+//! let curve: Gd<Curve> = load("res://falloff.tres");
+//! let falloff = CurveData::from_curve(&curve); // Send + Sync, can be moved to other threads.
+//!
+//! let weights: Vec<real> = (0..100).map(|i| falloff.sample(i as real / 99.0)).collect();
+//! ```
+
+use crate::builtin::math::FloatExt;
+use crate::builtin::real_consts::PI;
+use crate::builtin::{real, Vector2, Vector3, Vector4};
+use crate::classes::tween::{EaseType, TransitionType};
+use crate::classes::{Curve, Curve2D, Curve3D};
+
+/// Eases `x` with exponent `curve`, like `@GlobalScope.ease()`.
+///
+/// `x` is clamped to `0..=1`. A `curve` of 1 is linear, larger values ease in, values between 0 and 1 ease out, and negative values
+/// ease in and out (-1 being linear). 0 always returns 0.
+pub fn ease(x: real, curve: real) -> real {
+    let x = x.clamp(0.0, 1.0);
+
+    if curve > 0.0 {
+        if curve < 1.0 {
+            1.0 - (1.0 - x).powf(1.0 / curve)
+        } else {
+            x.powf(curve)
+        }
+    } else if curve < 0.0 {
+        if x < 0.5 {
+            (x * 2.0).powf(-curve) * 0.5
+        } else {
+            (1.0 - (1.0 - (x - 0.5) * 2.0).powf(-curve)) * 0.5 + 0.5
+        }
+    } else {
+        0.0
+    }
+}
+
+/// Evaluates a tween easing curve at `t` in `0..=1`, like `Tween.interpolate_value()` from 0 to 1 with duration 1.
+///
+/// The result is 0 at `t = 0` and 1 at `t = 1` (`EXPO` is off by up to 0.001, like in the engine). In between, it can leave `0..=1` for
+/// overshooting transitions (`BACK`, `ELASTIC`, `SPRING`). Unknown transition or ease types are treated as linear.
+pub fn transition(transition: TransitionType, ease: EaseType, t: real) -> real {
+    let Some(curve) = EasingCurve::from_type(transition) else {
+        return t;
+    };
+
+    // Easing equations with start 0, change 1 and duration 1, see Godot's `easing_equations.h`.
+    match ease {
+        EaseType::IN => curve.ease_in(t),
+        EaseType::OUT => curve.ease_out(t),
+        EaseType::IN_OUT => curve.ease_in_out(t),
+        EaseType::OUT_IN => {
+            if t < 0.5 {
+                curve.ease_out(t * 2.0) * 0.5
+            } else {
+                curve.ease_in(t * 2.0 - 1.0) * 0.5 + 0.5
+            }
+        }
+        _ => t,
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Curve
+
+/// Point of a [`CurveData`], corresponding to a point of a `Curve` resource.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CurvePoint {
+    /// Position; `x` is the offset along the curve and `y` the value.
+    pub position: Vector2,
+
+    /// Slope of the curve left of the point.
+    pub left_tangent: real,
+
+    /// Slope of the curve right of the point.
+    pub right_tangent: real,
+}
+
+/// Rust-evaluable version of a `Curve` resource, mapping offsets to values.
+///
+/// Created from an engine curve with [`from_curve()`][Self::from_curve], or from points with [`new()`][Self::new]. The data is a
+/// snapshot: later changes to the `Curve` resource are not reflected.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct CurveData {
+    points: Vec<CurvePoint>,
+}
+
+impl CurveData {
+    /// Creates a curve from points, which are sorted by their `x` position.
+    pub fn new(mut points: Vec<CurvePoint>) -> Self {
+        points.sort_by(|a, b| a.position.x.total_cmp(&b.position.x));
+        Self { points }
+    }
+
+    /// Copies all points of an engine `Curve`. Must be called on the main thread.
+    pub fn from_curve(curve: &Curve) -> Self {
+        let points = (0..curve.get_point_count())
+            .map(|i| CurvePoint {
+                position: curve.get_point_position(i),
+                left_tangent: curve.get_point_left_tangent(i),
+                right_tangent: curve.get_point_right_tangent(i),
+            })
+            .collect();
+
+        // Curve keeps its points sorted already.
+        Self { points }
+    }
+
+    /// Points of the curve, sorted by `x`.
+    pub fn points(&self) -> &[CurvePoint] {
+        &self.points
+    }
+
+    /// Returns the value at `offset`, like `Curve.sample()`.
+    ///
+    /// Offsets before the first or after the last point return the value of that point. An empty curve returns 0.
+    pub fn sample(&self, offset: real) -> real {
+        let (first, last) = match (self.points.first(), self.points.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return 0.0,
+        };
+
+        if offset <= first.position.x {
+            return first.position.y;
+        }
+        if offset >= last.position.x {
+            return last.position.y;
+        }
+
+        // Index of the last point at or before `offset`; exists due to the checks above.
+        let i = self.points.partition_point(|p| p.position.x <= offset) - 1;
+        let a = &self.points[i];
+        let b = &self.points[i + 1];
+
+        let d = b.position.x - a.position.x;
+        if d.is_zero_approx() {
+            return b.position.y;
+        }
+
+        let local_offset = (offset - a.position.x) / d;
+        let third = d / 3.0;
+        let control_a = a.position.y + third * a.right_tangent;
+        let control_b = b.position.y - third * b.left_tangent;
+
+        a.position
+            .y
+            .bezier_interpolate(control_a, control_b, b.position.y, local_offset)
+    }
+}
+
+impl From<&Curve> for CurveData {
+    fn from(curve: &Curve) -> Self {
+        Self::from_curve(curve)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Bézier paths
+
+macro_rules! impl_bezier_path {
+    (
+        $(#[$attr:meta])*
+        $Path:ident, $Point:ident, $Vector:ty, $EngineCurve:ty, $from_fn:ident
+    ) => {
+        /// Point of a Bézier path, with control points relative to its position.
+        #[derive(Copy, Clone, PartialEq, Debug)]
+        pub struct $Point {
+            /// Position of the point.
+            pub position: $Vector,
+
+            /// Control point of the incoming segment, relative to `position`.
+            pub in_control: $Vector,
+
+            /// Control point of the outgoing segment, relative to `position`.
+            pub out_control: $Vector,
+        }
+
+        $(#[$attr])*
+        #[derive(Clone, PartialEq, Debug, Default)]
+        pub struct $Path {
+            points: Vec<$Point>,
+            /// Cumulative length at the end of each subdivision; computed on demand.
+            lengths: Vec<real>,
+        }
+
+        impl $Path {
+            /// Creates a path through `points`.
+            pub fn new(points: Vec<$Point>) -> Self {
+                let mut path = Self { points, lengths: Vec::new() };
+                path.compute_lengths();
+                path
+            }
+
+            /// Copies all points of the engine curve. Must be called on the main thread.
+            pub fn $from_fn(curve: &$EngineCurve) -> Self {
+                let points = (0..curve.get_point_count())
+                    .map(|i| $Point {
+                        position: curve.get_point_position(i),
+                        in_control: curve.get_point_in(i),
+                        out_control: curve.get_point_out(i),
+                    })
+                    .collect();
+
+                Self::new(points)
+            }
+
+            /// Points of the path.
+            pub fn points(&self) -> &[$Point] {
+                &self.points
+            }
+
+            /// Returns the position on segment `index` at `t` in `0..=1`, like `sample()` of the engine curve.
+            ///
+            /// Indices before the first segment return the first point, indices after the last segment return the last point.
+            ///
+            /// # Panics
+            /// If the path has no points.
+            pub fn sample(&self, index: usize, t: real) -> $Vector {
+                assert!(!self.points.is_empty(), "cannot sample an empty path");
+
+                if index >= self.points.len() - 1 {
+                    return self.points[self.points.len() - 1].position;
+                }
+
+                let a = &self.points[index];
+                let b = &self.points[index + 1];
+                a.position.bezier_interpolate(
+                    a.position + a.out_control,
+                    b.position + b.in_control,
+                    b.position,
+                    t,
+                )
+            }
+
+            /// Returns the position at fractional index `findex`, like `samplef()` of the engine curve.
+            ///
+            /// The integer part selects the segment and the fractional part is the position within it.
+            ///
+            /// # Panics
+            /// If the path has no points.
+            pub fn samplef(&self, findex: real) -> $Vector {
+                if findex < 0.0 {
+                    return self.sample(0, 0.0);
+                }
+
+                self.sample(findex as usize, findex.fract())
+            }
+
+            /// Approximate length of the path.
+            pub fn length(&self) -> real {
+                self.lengths.last().copied().unwrap_or(0.0)
+            }
+
+            /// Returns the position at `distance` along the path, measured from the first point.
+            ///
+            /// This corresponds to `sample_baked()`, but uses a fixed number of subdivisions per segment instead of the curve's
+            /// bake interval; results can thus differ slightly from the engine's. Distances are clamped to `0..=length()`.
+            ///
+            /// # Panics
+            /// If the path has no points.
+            pub fn sample_by_distance(&self, distance: real) -> $Vector {
+                if self.lengths.is_empty() {
+                    return self.sample(0, 0.0);
+                }
+
+                let distance = distance.clamp(0.0, self.length());
+                let step = self.lengths.partition_point(|&len| len < distance).min(self.lengths.len() - 1);
+
+                let step_start = if step == 0 { 0.0 } else { self.lengths[step - 1] };
+                let step_len = self.lengths[step] - step_start;
+                let within = if step_len > 0.0 { (distance - step_start) / step_len } else { 0.0 };
+
+                let findex = (step as real + within) / SUBDIVISIONS as real;
+                self.samplef(findex)
+            }
+
+            fn compute_lengths(&mut self) {
+                let segments = self.points.len().saturating_sub(1);
+                let mut total = 0.0;
+                let mut prev = self.points.first().map(|p| p.position);
+
+                self.lengths.clear();
+                for step in 1..=segments * SUBDIVISIONS {
+                    let pos = self.samplef(step as real / SUBDIVISIONS as real);
+
+                    total += prev.map_or(0.0, |prev| prev.distance_to(pos));
+                    prev = Some(pos);
+                    self.lengths.push(total);
+                }
+            }
+        }
+
+        impl From<&$EngineCurve> for $Path {
+            fn from(curve: &$EngineCurve) -> Self {
+                Self::$from_fn(curve)
+            }
+        }
+    };
+}
+
+/// Subdivisions per segment for arc length computation.
+const SUBDIVISIONS: usize = 32;
+
+impl_bezier_path!(
+    /// Rust-evaluable version of a `Curve2D` resource: a path of cubic Bézier segments in 2D.
+    ///
+    /// The data is a snapshot: later changes to the `Curve2D` resource are not reflected.
+    BezierPath2D, BezierPoint2D, Vector2, Curve2D, from_curve2d
+);
+
+impl_bezier_path!(
+    /// Rust-evaluable version of a `Curve3D` resource: a path of cubic Bézier segments in 3D.
+    ///
+    /// Point tilts are not included. The data is a snapshot: later changes to the `Curve3D` resource are not reflected.
+    BezierPath3D, BezierPoint3D, Vector3, Curve3D, from_curve3d
+);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Catmull-Rom
+
+/// Value that can be interpolated by a [`CatmullRom`] spline.
+pub trait CubicInterpolate: Copy {
+    /// Cubic interpolation between `self` and `to`, like Godot's `cubic_interpolate()`.
+    fn cubic_interpolate(self, to: Self, pre: Self, post: Self, weight: real) -> Self;
+}
+
+impl CubicInterpolate for real {
+    fn cubic_interpolate(self, to: Self, pre: Self, post: Self, weight: real) -> Self {
+        FloatExt::cubic_interpolate(self, to, pre, post, weight)
+    }
+}
+
+macro_rules! impl_cubic_interpolate {
+    ($($Vector:ty),+) => {
+        $(
+            impl CubicInterpolate for $Vector {
+                fn cubic_interpolate(self, to: Self, pre: Self, post: Self, weight: real) -> Self {
+                    <$Vector>::cubic_interpolate(self, to, pre, post, weight)
+                }
+            }
+        )+
+    };
+}
+
+impl_cubic_interpolate!(Vector2, Vector3, Vector4);
+
+/// Uniform Catmull-Rom spline, passing through all its points.
+///
+/// Each segment is interpolated with `cubic_interpolate()`, using the neighbor points as pre- and post-points. At both ends, the end
+/// point itself is used instead of the missing neighbor.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct CatmullRom<T> {
+    points: Vec<T>,
+}
+
+impl<T: CubicInterpolate> CatmullRom<T> {
+    /// Creates a spline through `points`.
+    pub fn new(points: Vec<T>) -> Self {
+        Self { points }
+    }
+
+    /// Points of the spline.
+    pub fn points(&self) -> &[T] {
+        &self.points
+    }
+
+    /// Returns the value at fractional index `findex` in `0..=points().len() - 1`, e.g. 1.5 is halfway between the second and third point.
+    ///
+    /// Indices outside the range are clamped.
+    ///
+    /// # Panics
+    /// If the spline has no points.
+    pub fn sample(&self, findex: real) -> T {
+        assert!(!self.points.is_empty(), "cannot sample an empty spline");
+
+        let last = self.points.len() - 1;
+        let findex = findex.clamp(0.0, last as real);
+        let index = (findex as usize).min(last.saturating_sub(1));
+
+        if last == 0 {
+            return self.points[0];
+        }
+
+        let from = self.points[index];
+        let to = self.points[index + 1];
+        let pre = self.points[index.saturating_sub(1)];
+        let post = self.points[(index + 2).min(last)];
+
+        from.cubic_interpolate(to, pre, post, findex - index as real)
+    }
+
+    /// Returns the value at `t` in `0..=1`, spread uniformly over all segments.
+    ///
+    /// # Panics
+    /// If the spline has no points.
+    pub fn sample_normalized(&self, t: real) -> T {
+        self.sample(t * self.points.len().saturating_sub(1) as real)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+/// Easing curves from Godot's `easing_equations.h`, normalized to start 0, change 1 and duration 1.
+#[derive(Copy, Clone)]
+enum EasingCurve {
+    Linear,
+    Sine,
+    Quint,
+    Quart,
+    Quad,
+    Expo,
+    Elastic,
+    Cubic,
+    Circ,
+    Bounce,
+    Back,
+    Spring,
+}
+
+impl EasingCurve {
+    fn from_type(transition: TransitionType) -> Option<Self> {
+        let curve = match transition {
+            TransitionType::LINEAR => Self::Linear,
+            TransitionType::SINE => Self::Sine,
+            TransitionType::QUINT => Self::Quint,
+            TransitionType::QUART => Self::Quart,
+            TransitionType::QUAD => Self::Quad,
+            TransitionType::EXPO => Self::Expo,
+            TransitionType::ELASTIC => Self::Elastic,
+            TransitionType::CUBIC => Self::Cubic,
+            TransitionType::CIRC => Self::Circ,
+            TransitionType::BOUNCE => Self::Bounce,
+            TransitionType::BACK => Self::Back,
+            #[cfg(since_api = "4.2")]
+            TransitionType::SPRING => Self::Spring,
+            _ => return None,
+        };
+
+        Some(curve)
+    }
+
+    fn ease_in(self, t: real) -> real {
+        match self {
+            Self::Linear => t,
+            Self::Sine => 1.0 - (t * PI / 2.0).cos(),
+            Self::Quint => t.powi(5),
+            Self::Quart => t.powi(4),
+            Self::Quad => t * t,
+            Self::Expo => {
+                if t == 0.0 {
+                    0.0
+                } else {
+                    (2.0 as real).powf(10.0 * (t - 1.0)) - 0.001
+                }
+            }
+            Self::Elastic => {
+                if t == 0.0 || t == 1.0 {
+                    return t;
+                }
+                let t = t - 1.0;
+                let p = 0.3;
+                let s = p / 4.0;
+                -((2.0 as real).powf(10.0 * t) * ((t - s) * (2.0 * PI) / p).sin())
+            }
+            Self::Cubic => t * t * t,
+            Self::Circ => -((1.0 - t * t).sqrt() - 1.0),
+            Self::Bounce | Self::Spring => 1.0 - self.ease_out(1.0 - t),
+            Self::Back => {
+                let s = 1.70158;
+                t * t * ((s + 1.0) * t - s)
+            }
+        }
+    }
+
+    fn ease_in_out(self, t: real) -> real {
+        // Most curves are symmetric compositions of in and out; these three use separate constants in the engine.
+        let t2 = t * 2.0;
+        match self {
+            Self::Expo => {
+                if t == 0.0 || t == 1.0 {
+                    t
+                } else if t2 < 1.0 {
+                    0.5 * (2.0 as real).powf(10.0 * (t2 - 1.0)) - 0.0005
+                } else {
+                    0.5 * 1.0005 * (2.0 - (2.0 as real).powf(-10.0 * (t2 - 1.0)))
+                }
+            }
+            Self::Elastic => {
+                if t == 0.0 || t == 1.0 {
+                    return t;
+                }
+                let p = 0.3 * 1.5;
+                let s = p / 4.0;
+                let u = t2 - 1.0;
+                let wave = ((u - s) * (2.0 * PI) / p).sin();
+
+                if t2 < 1.0 {
+                    -0.5 * (2.0 as real).powf(10.0 * u) * wave
+                } else {
+                    0.5 * (2.0 as real).powf(-10.0 * u) * wave + 1.0
+                }
+            }
+            Self::Back => {
+                let s = 1.70158 * 1.525;
+                if t2 < 1.0 {
+                    0.5 * (t2 * t2 * ((s + 1.0) * t2 - s))
+                } else {
+                    let u = t2 - 2.0;
+                    0.5 * (u * u * ((s + 1.0) * u + s) + 2.0)
+                }
+            }
+            _ => {
+                if t < 0.5 {
+                    self.ease_in(t2) * 0.5
+                } else {
+                    self.ease_out(t2 - 1.0) * 0.5 + 0.5
+                }
+            }
+        }
+    }
+
+    fn ease_out(self, t: real) -> real {
+        match self {
+            Self::Linear => t,
+            Self::Sine => (t * PI / 2.0).sin(),
+            Self::Quint => (t - 1.0).powi(5) + 1.0,
+            Self::Quart => -((t - 1.0).powi(4) - 1.0),
+            Self::Quad => -t * (t - 2.0),
+            Self::Expo => {
+                if t == 1.0 {
+                    1.0
+                } else {
+                    1.001 * (1.0 - (2.0 as real).powf(-10.0 * t))
+                }
+            }
+            Self::Elastic => {
+                if t == 0.0 || t == 1.0 {
+                    return t;
+                }
+                let p = 0.3;
+                let s = p / 4.0;
+                (2.0 as real).powf(-10.0 * t) * ((t - s) * (2.0 * PI) / p).sin() + 1.0
+            }
+            Self::Cubic => {
+                let t = t - 1.0;
+                t * t * t + 1.0
+            }
+            Self::Circ => {
+                let t = t - 1.0;
+                (1.0 - t * t).sqrt()
+            }
+            Self::Bounce => {
+                if t < 1.0 / 2.75 {
+                    7.5625 * t * t
+                } else if t < 2.0 / 2.75 {
+                    let t = t - 1.5 / 2.75;
+                    7.5625 * t * t + 0.75
+                } else if t < 2.5 / 2.75 {
+                    let t = t - 2.25 / 2.75;
+                    7.5625 * t * t + 0.9375
+                } else {
+                    let t = t - 2.625 / 2.75;
+                    7.5625 * t * t + 0.984375
+                }
+            }
+            Self::Back => {
+                let s = 1.70158;
+                let t = t - 1.0;
+                t * t * ((s + 1.0) * t + s) + 1.0
+            }
+            Self::Spring => {
+                let s = 1.0 - t;
+                ((t * PI * (0.2 + 2.5 * t * t * t)).sin() * s.powf(2.2) + t) * (1.0 + 1.2 * s)
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_eq_approx;
+
+    #[test]
+    fn ease_matches_engine() {
+        assert_eq_approx!(ease(0.5, 1.0), 0.5);
+        assert_eq_approx!(ease(0.5, 2.0), 0.25);
+        assert_eq_approx!(ease(0.5, 0.5), 0.75);
+        assert_eq_approx!(ease(0.25, -2.0), 0.125);
+        assert_eq_approx!(ease(2.0, 3.0), 1.0);
+        assert_eq_approx!(ease(0.3, 0.0), 0.0);
+    }
+
+    #[test]
+    fn transition_endpoints() {
+        let transitions = [
+            TransitionType::LINEAR,
+            TransitionType::SINE,
+            TransitionType::QUINT,
+            TransitionType::QUART,
+            TransitionType::QUAD,
+            TransitionType::ELASTIC,
+            TransitionType::CUBIC,
+            TransitionType::CIRC,
+            TransitionType::BOUNCE,
+            TransitionType::BACK,
+        ];
+        let eases = [
+            EaseType::IN,
+            EaseType::OUT,
+            EaseType::IN_OUT,
+            EaseType::OUT_IN,
+        ];
+
+        for trans in transitions {
+            for ease in eases {
+                assert_eq_approx!(transition(trans, ease, 0.0), 0.0);
+                assert_eq_approx!(transition(trans, ease, 1.0), 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn transition_shapes() {
+        assert_eq_approx!(transition(TransitionType::QUAD, EaseType::IN, 0.5), 0.25);
+        assert_eq_approx!(transition(TransitionType::QUAD, EaseType::OUT, 0.5), 0.75);
+        assert_eq_approx!(
+            transition(TransitionType::CUBIC, EaseType::IN_OUT, 0.25),
+            0.0625
+        );
+
+        // BACK overshoots below 0 when easing in.
+        assert!(transition(TransitionType::BACK, EaseType::IN, 0.2) < 0.0);
+    }
+
+    #[test]
+    fn curve_sample() {
+        let point = |x, y, tangent| CurvePoint {
+            position: Vector2::new(x, y),
+            left_tangent: tangent,
+            right_tangent: tangent,
+        };
+
+        let linear = CurveData::new(vec![point(1.0, 1.0, 1.0), point(0.0, 0.0, 1.0)]);
+        assert_eq_approx!(linear.sample(0.25), 0.25);
+        assert_eq_approx!(linear.sample(-1.0), 0.0);
+        assert_eq_approx!(linear.sample(2.0), 1.0);
+
+        let flat = CurveData::new(vec![point(0.0, 0.0, 0.0), point(1.0, 1.0, 0.0)]);
+        assert_eq_approx!(flat.sample(0.5), 0.5);
+        assert!(flat.sample(0.25) < 0.25);
+
+        assert_eq!(CurveData::default().sample(0.5), 0.0);
+    }
+
+    #[test]
+    fn bezier_path_sample() {
+        let point = |x: real| BezierPoint2D {
+            position: Vector2::new(x, 0.0),
+            in_control: Vector2::ZERO,
+            out_control: Vector2::ZERO,
+        };
+
+        let path = BezierPath2D::new(vec![point(0.0), point(10.0), point(30.0)]);
+        assert_eq_approx!(path.samplef(0.5), Vector2::new(5.0, 0.0));
+        assert_eq_approx!(path.samplef(5.0), Vector2::new(30.0, 0.0));
+        assert_eq_approx!(path.length(), 30.0);
+        assert_eq_approx!(path.sample_by_distance(20.0), Vector2::new(20.0, 0.0));
+        assert_eq_approx!(path.sample_by_distance(100.0), Vector2::new(30.0, 0.0));
+    }
+
+    #[test]
+    fn catmull_rom_passes_through_points() {
+        let spline = CatmullRom::new(vec![0.0 as real, 1.0, 4.0, 9.0]);
+
+        assert_eq_approx!(spline.sample(0.0), 0.0);
+        assert_eq_approx!(spline.sample(1.0), 1.0);
+        assert_eq_approx!(spline.sample(2.0), 4.0);
+        assert_eq_approx!(spline.sample(3.0), 9.0);
+        assert_eq_approx!(spline.sample_normalized(1.0), 9.0);
+        assert_eq_approx!(spline.sample(1.5), 2.25);
+
+        assert_eq!(CatmullRom::new(vec![7.0 as real]).sample(0.5), 7.0);
+    }
+}
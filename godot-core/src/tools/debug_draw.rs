@@ -0,0 +1,600 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Immediate-mode drawing of lines, shapes and text, for visual debugging.
+//!
+//! The `draw_*` functions can be called from anywhere on the main thread, e.g. in the middle of gameplay code. All primitives
+//! requested during a frame are collected into one batch, which is rendered right before the frame is drawn (when the
+//! [`RenderingServer`][crate::classes::RenderingServer] emits `frame_pre_draw`). 3D primitives end up as a single
+//! [`ImmediateMesh`][crate::classes::ImmediateMesh] surface, text is drawn on a canvas layer above the game.
+//!
+//! Every function takes a `duration` in seconds. With `0.0`, the primitive is drawn for a single frame, so it must be requested
+//! again every frame (typical for values that change). Positive durations keep it visible for that long, which is handy for one-off
+//! events such as raycasts or collisions.
+//!
+//! When the first batch is rendered, a `DebugDraw` node holding the mesh and canvas layer is added as a child of the root window (and
+//! recreated if freed together with the scene tree). Code iterating over the root's children may therefore encounter it.
+//!
+//! Debug drawing is only available in debug builds (`debug_assertions`). In release builds, the functions do nothing and no node is
+//! added. Their arguments are still evaluated, though, as for any function call: a `format!()` passed as text allocates even in
+//! release. Guard such calls with [`is_enabled()`], which is always `false` in release builds. At runtime, drawing can additionally
+//! be toggled with [`set_enabled()`].
+//!
+//! # Example
+//! ```no_run
+//! use godot::prelude::*;
+//! use godot::tools::debug_draw;
+//!
+//! fn debug_raycast(from: Vector3, to: Vector3, hit: Option<Vector3>) {
+//!     debug_draw::draw_line_3d(from, to, Color::YELLOW, 0.0);
+//!
+//!     if let Some(hit) = hit {
+//!         debug_draw::draw_sphere(hit, 0.1, Color::RED, 2.0);
+//!
+//!         // Skips building the string when nothing is drawn.
+//!         if debug_draw::is_enabled() {
+//!             debug_draw::draw_text_3d(hit, format!("hit at {hit}"), Color::WHITE, 2.0);
+//!         }
+//!     }
+//!
+//!     debug_draw::print_overlay("raycast active", Color::GREEN, 0.0);
+//! }
+//! ```
+
+// In release builds, primitives are never constructed or read.
+#![cfg_attr(not(debug_assertions), allow(dead_code))]
+
+use std::time::Instant;
+
+use crate::builtin::{real, Aabb, Color, GString, Vector2, Vector3};
+
+/// Draws a line between two points in world space.
+pub fn draw_line_3d(from: Vector3, to: Vector3, color: Color, duration: f64) {
+    with_batch(|batch| batch.push(Primitive::Line { from, to, color }, duration));
+}
+
+/// Draws a wireframe sphere, consisting of one circle around each axis.
+pub fn draw_sphere(center: Vector3, radius: real, color: Color, duration: f64) {
+    with_batch(|batch| {
+        batch.push(
+            Primitive::Sphere {
+                center,
+                radius,
+                color,
+            },
+            duration,
+        )
+    });
+}
+
+/// Draws the edges of an axis-aligned box.
+pub fn draw_aabb(aabb: Aabb, color: Color, duration: f64) {
+    with_batch(|batch| batch.push(Primitive::Aabb { aabb, color }, duration));
+}
+
+/// Draws text at a point in world space, always facing the screen.
+///
+/// The text is projected with the camera of the root viewport; it is not drawn while there is no current [`Camera3D`][crate::classes::Camera3D],
+/// or when the point is behind it.
+pub fn draw_text_3d(position: Vector3, text: impl Into<GString>, color: Color, duration: f64) {
+    with_batch(|batch| {
+        batch.push(
+            Primitive::Text3d {
+                position,
+                text: text.into(),
+                color,
+            },
+            duration,
+        )
+    });
+}
+
+/// Draws text at a position on the screen, in pixels from the top-left corner.
+pub fn draw_text_2d(position: Vector2, text: impl Into<GString>, color: Color, duration: f64) {
+    with_batch(|batch| {
+        batch.push(
+            Primitive::Text2d {
+                position,
+                text: text.into(),
+                color,
+            },
+            duration,
+        )
+    });
+}
+
+/// Adds a line of text to the overlay in the top-left corner of the screen.
+///
+/// Overlay lines are stacked in the order they were added, without having to compute positions. Useful for values that are printed
+/// every frame, such as velocities or state machine states.
+pub fn print_overlay(text: impl Into<GString>, color: Color, duration: f64) {
+    with_batch(|batch| {
+        batch.push(
+            Primitive::Overlay {
+                text: text.into(),
+                color,
+            },
+            duration,
+        )
+    });
+}
+
+/// Removes all primitives, including those whose duration has not yet elapsed.
+pub fn clear() {
+    with_batch(|batch| batch.entries.clear());
+}
+
+/// Enables or disables debug drawing at runtime. Enabled by default.
+///
+/// While disabled, `draw_*` calls are ignored and nothing is rendered. Has no effect in release builds, where drawing is always disabled.
+pub fn set_enabled(enabled: bool) {
+    imp::set_enabled(enabled);
+}
+
+/// Whether debug drawing is currently enabled. Always `false` in release builds.
+pub fn is_enabled() -> bool {
+    imp::is_enabled()
+}
+
+/// Number of primitives that will be rendered in the next frame.
+pub fn pending_count() -> usize {
+    let mut count = 0;
+    with_batch(|batch| count = batch.entries.len());
+    count
+}
+
+/// Renders the current batch immediately, and discards primitives whose duration has elapsed.
+///
+/// This happens automatically before each frame is drawn, so it is rarely needed. It can be useful when the main loop is not a
+/// [`SceneTree`][crate::classes::SceneTree], or in tests.
+pub fn flush() {
+    imp::flush();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+/// Font size of text primitives, in pixels.
+const FONT_SIZE: i32 = 14;
+
+/// Number of line segments per circle of a sphere.
+const CIRCLE_SEGMENTS: usize = 24;
+
+/// Offset of the overlay from the top-left corner, in pixels.
+const OVERLAY_MARGIN: Vector2 = Vector2::new(8.0, 8.0);
+
+enum Primitive {
+    Line {
+        from: Vector3,
+        to: Vector3,
+        color: Color,
+    },
+    Sphere {
+        center: Vector3,
+        radius: real,
+        color: Color,
+    },
+    Aabb {
+        aabb: Aabb,
+        color: Color,
+    },
+    Text3d {
+        position: Vector3,
+        text: GString,
+        color: Color,
+    },
+    Text2d {
+        position: Vector2,
+        text: GString,
+        color: Color,
+    },
+    Overlay {
+        text: GString,
+        color: Color,
+    },
+}
+
+struct Entry {
+    primitive: Primitive,
+
+    /// `None` if the primitive is only drawn in the current frame.
+    expires_at: Option<Instant>,
+}
+
+#[derive(Default)]
+struct Batch {
+    entries: Vec<Entry>,
+}
+
+impl Batch {
+    fn push(&mut self, primitive: Primitive, duration: f64) {
+        let expires_at =
+            (duration > 0.0).then(|| Instant::now() + std::time::Duration::from_secs_f64(duration));
+
+        self.entries.push(Entry {
+            primitive,
+            expires_at,
+        });
+    }
+}
+
+/// Runs `f` on the current batch, if drawing is enabled.
+fn with_batch(f: impl FnOnce(&mut Batch)) {
+    imp::with_batch(f);
+}
+
+/// Appends the line segments (as pairs of points) that `primitive` consists of. Does nothing for text.
+fn append_segments(primitive: &Primitive, out: &mut Vec<(Vector3, Vector3, Color)>) {
+    match *primitive {
+        Primitive::Line { from, to, color } => out.push((from, to, color)),
+
+        Primitive::Sphere {
+            center,
+            radius,
+            color,
+        } => {
+            let axes = [
+                (Vector3::RIGHT, Vector3::UP),
+                (Vector3::RIGHT, Vector3::BACK),
+                (Vector3::UP, Vector3::BACK),
+            ];
+
+            for (u, v) in axes {
+                let point = |i: usize| {
+                    let angle =
+                        crate::builtin::real_consts::TAU * i as real / CIRCLE_SEGMENTS as real;
+                    center + (u * angle.cos() + v * angle.sin()) * radius
+                };
+
+                for i in 0..CIRCLE_SEGMENTS {
+                    out.push((point(i), point(i + 1), color));
+                }
+            }
+        }
+
+        Primitive::Aabb { aabb, color } => {
+            let corner = |i: usize| {
+                let offset = Vector3::new(
+                    if i & 1 != 0 { aabb.size.x } else { 0.0 },
+                    if i & 2 != 0 { aabb.size.y } else { 0.0 },
+                    if i & 4 != 0 { aabb.size.z } else { 0.0 },
+                );
+                aabb.position + offset
+            };
+
+            // Each edge connects two corners whose indices differ in exactly one bit.
+            for i in 0..8 {
+                for bit in [1, 2, 4] {
+                    if i & bit == 0 {
+                        out.push((corner(i), corner(i | bit), color));
+                    }
+                }
+            }
+        }
+
+        Primitive::Text3d { .. } | Primitive::Text2d { .. } | Primitive::Overlay { .. } => {}
+    }
+}
+
+/// Release builds: nothing is drawn. Closures passed to `with_batch()` are never invoked, so primitives are not constructed. The
+/// arguments of the public functions are still evaluated by the caller.
+#[cfg(not(debug_assertions))]
+mod imp {
+    use super::Batch;
+
+    #[inline(always)]
+    pub fn with_batch(_f: impl FnOnce(&mut Batch)) {}
+
+    pub fn set_enabled(_enabled: bool) {}
+
+    pub fn is_enabled() -> bool {
+        false
+    }
+
+    pub fn flush() {}
+}
+
+#[cfg(debug_assertions)]
+mod imp {
+    use std::cell::RefCell;
+
+    use super::*;
+    use crate::builtin::{Callable, Variant};
+    use crate::classes::base_material_3d::{Flags, ShadingMode, Transparency};
+    use crate::classes::geometry_instance_3d::ShadowCastingSetting;
+    use crate::classes::mesh::PrimitiveType;
+    use crate::classes::{
+        CanvasLayer, Engine, Font, ImmediateMesh, MeshInstance3D, Node, Node2D, RenderingServer,
+        SceneTree, StandardMaterial3D, ThemeDB, Window,
+    };
+    use crate::obj::{Gd, NewAlloc, NewGd};
+
+    thread_local! {
+        static STATE: RefCell<State> = RefCell::new(State::new());
+    }
+
+    struct State {
+        batch: Batch,
+        renderer: Option<Renderer>,
+        enabled: bool,
+        is_hooked: bool,
+    }
+
+    impl State {
+        fn new() -> Self {
+            Self {
+                batch: Batch::default(),
+                renderer: None,
+                enabled: true,
+                is_hooked: false,
+            }
+        }
+    }
+
+    pub fn with_batch(f: impl FnOnce(&mut Batch)) {
+        let needs_hook = STATE.with(|state| {
+            let mut state = state.borrow_mut();
+            if !state.enabled {
+                return false;
+            }
+
+            f(&mut state.batch);
+
+            let needs_hook = !state.is_hooked;
+            state.is_hooked = true;
+            needs_hook
+        });
+
+        // Connect outside the RefCell borrow, in case Godot calls back synchronously.
+        if needs_hook {
+            let callable = Callable::from_fn("debug_draw_flush", |_args: &[&Variant]| {
+                flush();
+                Ok(Variant::nil())
+            });
+
+            RenderingServer::singleton().connect("frame_pre_draw".into(), callable);
+        }
+    }
+
+    pub fn set_enabled(enabled: bool) {
+        STATE.with(|state| {
+            let mut state = state.borrow_mut();
+            state.enabled = enabled;
+
+            if !enabled {
+                state.batch.entries.clear();
+                if let Some(renderer) = state.renderer.as_mut() {
+                    renderer.render(&[]);
+                }
+            }
+        });
+    }
+
+    pub fn is_enabled() -> bool {
+        STATE.with(|state| state.borrow().enabled)
+    }
+
+    pub fn flush() {
+        STATE.with(|state| {
+            let mut state = state.borrow_mut();
+            let state = &mut *state;
+
+            // Nodes of the renderer are owned by the scene tree, and may have been freed together with it.
+            let renderer = match state.renderer.take() {
+                Some(renderer) if renderer.is_valid() => state.renderer.insert(renderer),
+                Some(_) | None => match Renderer::new() {
+                    Some(renderer) => state.renderer.insert(renderer),
+                    None => return,
+                },
+            };
+
+            renderer.render(&state.batch.entries);
+
+            let now = Instant::now();
+            state
+                .batch
+                .entries
+                .retain(|entry| entry.expires_at.is_some_and(|expires_at| expires_at > now));
+        });
+    }
+
+    /// Nodes that display the batch. They are added to the root window, so they are freed together with the scene tree.
+    struct Renderer {
+        root: Gd<Window>,
+        container: Gd<Node>,
+        mesh: Gd<ImmediateMesh>,
+        canvas: Gd<Node2D>,
+        font: Option<Gd<Font>>,
+    }
+
+    impl Renderer {
+        /// Returns `None` if there is no scene tree to attach to.
+        fn new() -> Option<Self> {
+            let tree = Engine::singleton()
+                .get_main_loop()?
+                .try_cast::<SceneTree>()
+                .ok()?;
+            let mut root = tree.get_root()?;
+
+            let mut material = StandardMaterial3D::new_gd();
+            material.set_shading_mode(ShadingMode::UNSHADED);
+            material.set_flag(Flags::ALBEDO_FROM_VERTEX_COLOR, true);
+            material.set_transparency(Transparency::ALPHA);
+
+            let mesh = ImmediateMesh::new_gd();
+            let mut mesh_instance = MeshInstance3D::new_alloc();
+            mesh_instance.set_name("Lines".into());
+            mesh_instance.set_mesh(mesh.clone().upcast());
+            mesh_instance.set_material_override(material.upcast());
+            mesh_instance.set_cast_shadows_setting(ShadowCastingSetting::OFF);
+
+            // Highest layer, so that text is drawn above the game UI.
+            let mut canvas_layer = CanvasLayer::new_alloc();
+            canvas_layer.set_name("Text".into());
+            canvas_layer.set_layer(128);
+
+            let canvas = Node2D::new_alloc();
+            canvas_layer.add_child(canvas.clone().upcast());
+
+            let mut container = Node::new_alloc();
+            container.set_name("DebugDraw".into());
+            container.add_child(mesh_instance.upcast());
+            container.add_child(canvas_layer.upcast());
+            root.add_child(container.clone());
+
+            Some(Self {
+                root,
+                container,
+                mesh,
+                canvas,
+                font: ThemeDB::singleton().get_fallback_font(),
+            })
+        }
+
+        fn is_valid(&self) -> bool {
+            self.container.is_instance_valid() && self.canvas.is_instance_valid()
+        }
+
+        fn render(&mut self, entries: &[Entry]) {
+            self.render_lines(entries);
+            self.render_text(entries);
+        }
+
+        fn render_lines(&mut self, entries: &[Entry]) {
+            let mut segments = Vec::new();
+            for entry in entries {
+                append_segments(&entry.primitive, &mut segments);
+            }
+
+            self.mesh.clear_surfaces();
+
+            // Godot rejects empty surfaces.
+            if segments.is_empty() {
+                return;
+            }
+
+            self.mesh.surface_begin(PrimitiveType::LINES);
+            for (from, to, color) in segments {
+                self.mesh.surface_set_color(color);
+                self.mesh.surface_add_vertex(from);
+                self.mesh.surface_set_color(color);
+                self.mesh.surface_add_vertex(to);
+            }
+            self.mesh.surface_end();
+        }
+
+        fn render_text(&mut self, entries: &[Entry]) {
+            let canvas_item = self.canvas.get_canvas_item();
+            RenderingServer::singleton().canvas_item_clear(canvas_item);
+
+            let Some(font) = self.font.as_ref() else {
+                return;
+            };
+
+            let camera = self.root.get_camera_3d();
+            let line_height = font.get_height_ex().font_size(FONT_SIZE).done() as real;
+            let ascent = font.get_ascent_ex().font_size(FONT_SIZE).done() as real;
+            let mut overlay_line = 0;
+
+            for entry in entries {
+                let (position, text, color) = match &entry.primitive {
+                    Primitive::Text2d {
+                        position,
+                        text,
+                        color,
+                    } => (*position, text, *color),
+
+                    Primitive::Text3d {
+                        position,
+                        text,
+                        color,
+                    } => {
+                        let Some(camera) = camera.as_ref() else {
+                            continue;
+                        };
+                        if camera.is_position_behind(*position) {
+                            continue;
+                        }
+                        (camera.unproject_position(*position), text, *color)
+                    }
+
+                    Primitive::Overlay { text, color } => {
+                        let offset = Vector2::new(0.0, line_height * overlay_line as real);
+                        overlay_line += 1;
+                        (OVERLAY_MARGIN + offset, text, *color)
+                    }
+
+                    Primitive::Line { .. } | Primitive::Sphere { .. } | Primitive::Aabb { .. } => {
+                        continue
+                    }
+                };
+
+                // Fonts are drawn from the baseline; shift down so that `position` is the top-left corner.
+                font.draw_string_ex(
+                    canvas_item,
+                    position + Vector2::new(0.0, ascent),
+                    text.clone(),
+                )
+                .font_size(FONT_SIZE)
+                .modulate(color)
+                .done();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segments(primitive: Primitive) -> Vec<(Vector3, Vector3, Color)> {
+        let mut out = Vec::new();
+        append_segments(&primitive, &mut out);
+        out
+    }
+
+    #[test]
+    fn aabb_has_twelve_edges() {
+        let aabb = Aabb::new(Vector3::ZERO, Vector3::new(1.0, 2.0, 3.0));
+        let edges = segments(Primitive::Aabb {
+            aabb,
+            color: Color::RED,
+        });
+
+        assert_eq!(edges.len(), 12);
+        for (from, to, _) in edges {
+            // Every edge is parallel to one axis.
+            let diff = (to - from).abs();
+            let nonzero = [diff.x, diff.y, diff.z]
+                .iter()
+                .filter(|c| **c != 0.0)
+                .count();
+            assert_eq!(nonzero, 1);
+        }
+    }
+
+    #[test]
+    fn sphere_circles_are_closed() {
+        let center = Vector3::new(1.0, 0.0, 0.0);
+        let circles = segments(Primitive::Sphere {
+            center,
+            radius: 2.0,
+            color: Color::RED,
+        });
+
+        assert_eq!(circles.len(), 3 * CIRCLE_SEGMENTS);
+        for circle in circles.chunks(CIRCLE_SEGMENTS) {
+            let first = circle.first().unwrap().0;
+            let last = circle.last().unwrap().1;
+            assert!((first - last).is_zero_approx());
+
+            for (from, _, _) in circle {
+                assert!(((*from - center).length() - 2.0).abs() < 1e-4);
+            }
+        }
+    }
+}
@@ -0,0 +1,679 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Helpers for editor plugins, implemented through [`IEditorPlugin`][crate::classes::IEditorPlugin].
+//!
+//! Controls added with `EditorPlugin::add_control_to_dock()` or `add_control_to_bottom_panel()` must be removed and freed by the plugin.
+//! Forgetting this (or not getting the chance, e.g. during hot reload) leaves stale controls in the editor, which then refer to code
+//! that no longer exists. [`EditorPanels`] keeps track of added controls, removes them in `exit_tree()`, and frees leftovers when dropped.
+//!
+//! Changes made by tool code should be undoable. [`undo_scope()`] records them as a single action in the editor history, with undo
+//! operations derived from the current state; see [`UndoRedoScope`]. [`undo_scope_with()`] does the same for any `UndoRedo` history.
+//!
+//! # Example
+//! ```no_run
+//! use godot::prelude::*;
+//! use godot::classes::{ConfigFile, Control, EditorPlugin, IEditorPlugin, Label};
+//! use godot::classes::editor_plugin::DockSlot;
+//! use godot::tools::editor::EditorPanels;
+//!
+//! #[derive(GodotClass)]
+//! #[class(tool, init, editor_plugin, base=EditorPlugin)]
+//! struct LevelTools {
+//!     panels: EditorPanels,
+//!     base: Base<EditorPlugin>,
+//! }
+//!
+//! #[godot_api]
+//! impl IEditorPlugin for LevelTools {
+//!     fn enter_tree(&mut self) {
+//!         let mut dock = Control::new_alloc();
+//!         dock.set_name("Level Tools".into()); // Name is shown as tab title and used to persist the layout.
+//!
+//!         let plugin = self.to_gd();
+//!         self.panels.add_dock(&plugin, DockSlot::RIGHT_UL, dock);
+//!         self.panels.add_bottom_panel(&plugin, Label::new_alloc(), "Level Log");
+//!     }
+//!
+//!     fn exit_tree(&mut self) {
+//!         self.panels.remove_all();
+//!     }
+//!
+//!     fn get_window_layout(&mut self, configuration: Gd<ConfigFile>) {
+//!         self.panels.save_layout(&configuration);
+//!     }
+//!
+//!     fn set_window_layout(&mut self, configuration: Gd<ConfigFile>) {
+//!         self.panels.restore_layout(&configuration);
+//!     }
+//! }
+//! ```
+
+use crate::builtin::{Callable, GString, StringName, Variant};
+use crate::classes::editor_plugin::DockSlot;
+use crate::classes::undo_redo::MergeMode;
+use crate::classes::{
+    Button, ConfigFile, Control, EditorPlugin, EditorUndoRedoManager, Object, UndoRedo,
+};
+use crate::meta::ToGodot;
+use crate::obj::{EngineEnum, Gd, Inherits, InstanceId};
+
+/// Section in the editor layout file, under which the dock slots are stored.
+const LAYOUT_SECTION: &str = "godot_rust_docks";
+
+/// Docks and bottom panels added by an editor plugin.
+///
+/// Store this as a field of your `EditorPlugin` class and add controls through it. Call [`remove_all()`][Self::remove_all] in
+/// `exit_tree()`, which Godot invokes when the plugin is disabled, when the editor shuts down and before hot reload.
+///
+/// Controls which are still present when this object is dropped (i.e. when the plugin is freed) are detached and freed as well. At that
+/// point the plugin is already being destroyed, so it is not used anymore; the editor may then briefly show an empty dock tab or panel
+/// button. Removing the panels in `exit_tree()` avoids this.
+///
+/// The dock slot of each dock can be persisted across editor sessions, by forwarding the plugin's `get_window_layout()` and
+/// `set_window_layout()` to [`save_layout()`][Self::save_layout] and [`restore_layout()`][Self::restore_layout]. Docks are identified
+/// by their node name, so give them a unique name before adding them.
+#[derive(Debug, Default)]
+pub struct EditorPanels {
+    plugin: Option<InstanceId>,
+    panels: Vec<Panel>,
+    containers: DockContainers,
+}
+
+impl EditorPanels {
+    /// Creates an empty set of panels.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `control` as a dock in `slot`, see `EditorPlugin::add_control_to_dock()`.
+    ///
+    /// The control's name is displayed as the title of the dock tab.
+    ///
+    /// # Panics
+    /// If panels were already added through a different plugin.
+    pub fn add_dock<P, C>(&mut self, plugin: &Gd<P>, slot: DockSlot, control: Gd<C>)
+    where
+        P: Inherits<EditorPlugin>,
+        C: Inherits<Control>,
+    {
+        let mut plugin = self.bind_plugin(plugin);
+        let control = control.upcast::<Control>();
+
+        plugin.add_control_to_dock(slot, control.clone());
+        self.containers.learn(&control, slot);
+        self.panels.push(Panel {
+            name: control.get_name(),
+            control: control.instance_id(),
+            kind: PanelKind::Dock,
+        });
+    }
+
+    /// Adds `control` to the bottom panel, with a button labeled `title`. See `EditorPlugin::add_control_to_bottom_panel()`.
+    ///
+    /// Returns the button toggling the panel, which can be hidden or restyled. It is freed together with the panel.
+    ///
+    /// # Panics
+    /// If panels were already added through a different plugin.
+    pub fn add_bottom_panel<P, C>(
+        &mut self,
+        plugin: &Gd<P>,
+        control: Gd<C>,
+        title: &str,
+    ) -> Gd<Button>
+    where
+        P: Inherits<EditorPlugin>,
+        C: Inherits<Control>,
+    {
+        let mut plugin = self.bind_plugin(plugin);
+        let control = control.upcast::<Control>();
+
+        let button = plugin.add_control_to_bottom_panel(control.clone(), title.into());
+        self.panels.push(Panel {
+            name: control.get_name(),
+            control: control.instance_id(),
+            kind: PanelKind::BottomPanel,
+        });
+
+        button
+    }
+
+    /// Removes `control` from the editor and frees it. Returns `false` if the control was not added through `self`.
+    pub fn remove<C>(&mut self, control: &Gd<C>) -> bool
+    where
+        C: Inherits<Control>,
+    {
+        let instance_id = control.instance_id();
+        let Some(index) = self.panels.iter().position(|p| p.control == instance_id) else {
+            return false;
+        };
+
+        let panel = self.panels.remove(index);
+        panel.remove_from(self.plugin());
+        true
+    }
+
+    /// Removes all controls from the editor and frees them.
+    ///
+    /// Call this in `IEditorPlugin::exit_tree()`, while the plugin is still fully alive.
+    pub fn remove_all(&mut self) {
+        let plugin = self.plugin();
+        for panel in self.panels.drain(..) {
+            panel.remove_from(plugin.clone());
+        }
+    }
+
+    /// Number of docks and bottom panels currently added.
+    pub fn len(&self) -> usize {
+        self.panels.len()
+    }
+
+    /// Whether no docks or bottom panels are added.
+    pub fn is_empty(&self) -> bool {
+        self.panels.is_empty()
+    }
+
+    /// Stores the current slot of each dock in the editor layout, to be called from `IEditorPlugin::get_window_layout()`.
+    ///
+    /// Godot has no API to query the slot of a dock. Slots are therefore recognized by their container node, which is learned whenever a
+    /// dock is added to a slot through `self`. Docks which are currently floating, or which the user moved to a slot that none of the
+    /// docks was added to, keep their previously stored slot.
+    pub fn save_layout(&self, layout: &Gd<ConfigFile>) {
+        let mut layout = layout.clone();
+
+        for panel in &self.panels {
+            let Some(slot) = panel
+                .control()
+                .and_then(|control| self.containers.current_slot(&control))
+            else {
+                continue;
+            };
+
+            layout.set_value(
+                LAYOUT_SECTION.into(),
+                GString::from(&panel.name),
+                slot.ord().to_variant(),
+            );
+        }
+    }
+
+    /// Moves each dock to the slot stored in the editor layout, to be called from `IEditorPlugin::set_window_layout()`.
+    ///
+    /// Docks without stored slot stay where they are.
+    pub fn restore_layout(&mut self, layout: &Gd<ConfigFile>) {
+        let Some(mut plugin) = self.plugin() else {
+            return;
+        };
+
+        for panel in &self.panels {
+            if panel.kind != PanelKind::Dock {
+                continue;
+            }
+
+            let key = GString::from(&panel.name);
+            if !layout.has_section_key(LAYOUT_SECTION.into(), key.clone()) {
+                continue;
+            }
+
+            let stored = layout
+                .get_value(LAYOUT_SECTION.into(), key)
+                .try_to::<i32>()
+                .ok()
+                .and_then(DockSlot::try_from_ord)
+                .filter(|stored| stored.ord() < DockSlot::MAX.ord());
+
+            let (Some(stored), Some(control)) = (stored, panel.control()) else {
+                continue;
+            };
+
+            if self.containers.current_slot(&control) != Some(stored) {
+                plugin.remove_control_from_docks(control.clone());
+                plugin.add_control_to_dock(stored, control.clone());
+                self.containers.learn(&control, stored);
+            }
+        }
+    }
+
+    fn bind_plugin<P: Inherits<EditorPlugin>>(&mut self, plugin: &Gd<P>) -> Gd<EditorPlugin> {
+        let instance_id = plugin.instance_id();
+        let bound = *self.plugin.get_or_insert(instance_id);
+
+        assert_eq!(
+            bound, instance_id,
+            "EditorPanels: panels must be added through the same plugin"
+        );
+
+        plugin.clone().upcast()
+    }
+
+    fn plugin(&self) -> Option<Gd<EditorPlugin>> {
+        self.plugin
+            .and_then(|id| Gd::<EditorPlugin>::try_from_instance_id(id).ok())
+    }
+}
+
+impl Drop for EditorPanels {
+    fn drop(&mut self) {
+        // The plugin (if any) is in the middle of being destroyed, so don't call into it.
+        for panel in self.panels.drain(..) {
+            panel.remove_from(None);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Undo/redo
+
+/// Records an undoable editor action named `name`, through the editor's undo/redo manager.
+///
+/// See [`UndoRedoScope`] for details. Returns the value returned by `f`.
+///
+/// # Panics
+/// If not running inside the editor.
+#[cfg(since_api = "4.2")]
+pub fn undo_scope<R>(name: &str, f: impl FnOnce(&mut UndoRedoScope) -> R) -> R {
+    let manager = crate::classes::EditorInterface::singleton()
+        .get_editor_undo_redo()
+        .expect("undo_scope() requires the editor");
+
+    undo_scope_with(&manager, name, f)
+}
+
+/// Like [`undo_scope()`], but with an explicit history: an `EditorUndoRedoManager` (e.g. from `EditorPlugin::get_undo_redo()`) or an
+/// `UndoRedo` object, which also works outside the editor.
+pub fn undo_scope_with<H, R>(
+    history: &Gd<H>,
+    name: &str,
+    f: impl FnOnce(&mut UndoRedoScope) -> R,
+) -> R
+where
+    H: UndoRedoHistory,
+{
+    let mut scope = UndoRedoScope {
+        do_ops: Vec::new(),
+        undo_ops: Vec::new(),
+        merge_mode: MergeMode::DISABLE,
+    };
+
+    let result = f(&mut scope);
+    if !scope.do_ops.is_empty() || !scope.undo_ops.is_empty() {
+        H::__commit(history.clone(), name, scope);
+    }
+    result
+}
+
+/// History that [`undo_scope_with()`] records actions in: `EditorUndoRedoManager` or `UndoRedo`.
+///
+/// This trait is sealed and cannot be implemented outside of godot-rust.
+pub trait UndoRedoHistory: Inherits<Object> + sealed::Sealed {
+    #[doc(hidden)]
+    fn __commit(history: Gd<Self>, name: &str, scope: UndoRedoScope);
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+impl sealed::Sealed for EditorUndoRedoManager {}
+impl UndoRedoHistory for EditorUndoRedoManager {
+    fn __commit(mut history: Gd<Self>, name: &str, scope: UndoRedoScope) {
+        history
+            .create_action_ex(name.into())
+            .merge_mode(scope.merge_mode)
+            .done();
+
+        for op in scope.do_ops {
+            match op {
+                UndoOp::Property {
+                    object,
+                    property,
+                    value,
+                } => history.add_do_property(object, property, value),
+                UndoOp::Method {
+                    object,
+                    method,
+                    args,
+                } => history.add_do_method(object, method, &args),
+                UndoOp::Reference(object) => history.add_do_reference(object),
+            }
+        }
+
+        for op in scope.undo_ops {
+            match op {
+                UndoOp::Property {
+                    object,
+                    property,
+                    value,
+                } => history.add_undo_property(object, property, value),
+                UndoOp::Method {
+                    object,
+                    method,
+                    args,
+                } => history.add_undo_method(object, method, &args),
+                UndoOp::Reference(object) => history.add_undo_reference(object),
+            }
+        }
+
+        history.commit_action();
+    }
+}
+
+impl sealed::Sealed for UndoRedo {}
+impl UndoRedoHistory for UndoRedo {
+    fn __commit(mut history: Gd<Self>, name: &str, scope: UndoRedoScope) {
+        history
+            .create_action_ex(name.into())
+            .merge_mode(scope.merge_mode)
+            .done();
+
+        for op in scope.do_ops {
+            match op {
+                UndoOp::Property {
+                    object,
+                    property,
+                    value,
+                } => history.add_do_property(object, property, value),
+                UndoOp::Method { .. } => history.add_do_method(op.into_callable()),
+                UndoOp::Reference(object) => history.add_do_reference(object),
+            }
+        }
+
+        for op in scope.undo_ops {
+            match op {
+                UndoOp::Property {
+                    object,
+                    property,
+                    value,
+                } => history.add_undo_property(object, property, value),
+                UndoOp::Method { .. } => history.add_undo_method(op.into_callable()),
+                UndoOp::Reference(object) => history.add_undo_reference(object),
+            }
+        }
+
+        history.commit_action();
+    }
+}
+
+/// Collects the operations of an undoable editor action, created by [`undo_scope()`] or [`undo_scope_with()`].
+///
+/// Each method registers matching _do_ and _undo_ operations: [`set_property()`][Self::set_property] stores the current value of the
+/// property for undo, and [`method()`][Self::method] calls a method in both directions. Once the closure returns, the action is
+/// created and committed, which executes all do operations. Nothing is sent to the editor before that, so a panic inside the closure
+/// leaves the history untouched. Actions without any operations are not committed.
+///
+/// Both do and undo operations run in the order they were added. A method like `rebuild` added last thus runs after all properties
+/// have been set -- or restored.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::Line2D;
+/// use godot::tools::editor::undo_scope;
+///
+/// fn move_points(line: &Gd<Line2D>, offset: Vector2) {
+///     let points: PackedVector2Array = line.get_points().as_slice().iter().map(|p| *p + offset).collect();
+///
+///     undo_scope("Move points", |s| {
+///         s.set_property(line, "points", points);
+///         s.method(line, "queue_redraw");
+///     });
+/// }
+/// ```
+pub struct UndoRedoScope {
+    do_ops: Vec<UndoOp>,
+    undo_ops: Vec<UndoOp>,
+    merge_mode: MergeMode,
+}
+
+impl UndoRedoScope {
+    /// Sets `property` on `object` to `value`; undo restores the value the property has now.
+    pub fn set_property<T, V>(&mut self, object: &Gd<T>, property: &str, value: V)
+    where
+        T: Inherits<Object>,
+        V: ToGodot,
+    {
+        let object = object.clone().upcast::<Object>();
+        let property = StringName::from(property);
+        let old_value = object.get(property.clone());
+
+        self.do_ops.push(UndoOp::Property {
+            object: object.clone(),
+            property: property.clone(),
+            value: value.to_variant(),
+        });
+        self.undo_ops.push(UndoOp::Property {
+            object,
+            property,
+            value: old_value,
+        });
+    }
+
+    /// Calls `method` on `object` without arguments, both when doing and undoing the action.
+    pub fn method<T>(&mut self, object: &Gd<T>, method: &str)
+    where
+        T: Inherits<Object>,
+    {
+        self.method_with_args(object, method, &[], &[]);
+    }
+
+    /// Calls `method` on `object` with `do_args` when doing, and with `undo_args` when undoing the action.
+    pub fn method_with_args<T>(
+        &mut self,
+        object: &Gd<T>,
+        method: &str,
+        do_args: &[Variant],
+        undo_args: &[Variant],
+    ) where
+        T: Inherits<Object>,
+    {
+        self.do_method(object, method, do_args);
+        self.undo_method(object, method, undo_args);
+    }
+
+    /// Calls `method` on `object` only when doing the action.
+    pub fn do_method<T>(&mut self, object: &Gd<T>, method: &str, args: &[Variant])
+    where
+        T: Inherits<Object>,
+    {
+        self.do_ops.push(UndoOp::method(object, method, args));
+    }
+
+    /// Calls `method` on `object` only when undoing the action.
+    pub fn undo_method<T>(&mut self, object: &Gd<T>, method: &str, args: &[Variant])
+    where
+        T: Inherits<Object>,
+    {
+        self.undo_ops.push(UndoOp::method(object, method, args));
+    }
+
+    /// Keeps `object` alive while the action is in the history and done, and frees it once the action is discarded in that state.
+    ///
+    /// Use this for nodes that the action creates and adds to the scene.
+    pub fn keep_on_do<T>(&mut self, object: &Gd<T>)
+    where
+        T: Inherits<Object>,
+    {
+        self.do_ops
+            .push(UndoOp::Reference(object.clone().upcast::<Object>()));
+    }
+
+    /// Keeps `object` alive while the action is in the history and undone, and frees it once the action is discarded in that state.
+    ///
+    /// Use this for nodes that the action removes from the scene.
+    pub fn keep_on_undo<T>(&mut self, object: &Gd<T>)
+    where
+        T: Inherits<Object>,
+    {
+        self.undo_ops
+            .push(UndoOp::Reference(object.clone().upcast::<Object>()));
+    }
+
+    /// Sets how the action is merged with the previous one of the same name. Default is `MergeMode::DISABLE`.
+    ///
+    /// With `MergeMode::ENDS`, repeated actions (e.g. while dragging) become a single history entry.
+    pub fn set_merge_mode(&mut self, merge_mode: MergeMode) {
+        self.merge_mode = merge_mode;
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+#[derive(Debug)]
+struct Panel {
+    name: StringName,
+    control: InstanceId,
+    kind: PanelKind,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum PanelKind {
+    Dock,
+    BottomPanel,
+}
+
+enum UndoOp {
+    Property {
+        object: Gd<Object>,
+        property: StringName,
+        value: Variant,
+    },
+    Method {
+        object: Gd<Object>,
+        method: StringName,
+        args: Vec<Variant>,
+    },
+    Reference(Gd<Object>),
+}
+
+impl UndoOp {
+    fn method<T: Inherits<Object>>(object: &Gd<T>, method: &str, args: &[Variant]) -> Self {
+        Self::Method {
+            object: object.clone().upcast(),
+            method: method.into(),
+            args: args.to_vec(),
+        }
+    }
+
+    /// Method operation as callable with bound arguments, as `UndoRedo` expects it.
+    fn into_callable(self) -> Callable {
+        let Self::Method {
+            object,
+            method,
+            args,
+        } = self
+        else {
+            unreachable!("only method operations are callables");
+        };
+
+        let callable = Callable::from_object_method(&object, method);
+        if args.is_empty() {
+            callable
+        } else {
+            callable.bindv(args.into_iter().collect())
+        }
+    }
+}
+
+impl Panel {
+    fn control(&self) -> Option<Gd<Control>> {
+        Gd::try_from_instance_id(self.control).ok()
+    }
+
+    /// Removes the control from the editor through `plugin`, or detaches it from its parent if there is no plugin. Then frees it.
+    fn remove_from(self, plugin: Option<Gd<EditorPlugin>>) {
+        // The control may have been freed by the editor already, e.g. on shutdown.
+        let Some(mut control) = self.control() else {
+            return;
+        };
+
+        if let Some(mut plugin) = plugin {
+            match self.kind {
+                PanelKind::Dock => plugin.remove_control_from_docks(control.clone()),
+                PanelKind::BottomPanel => plugin.remove_control_from_bottom_panel(control.clone()),
+            }
+        } else if let Some(mut parent) = control.get_parent() {
+            parent.remove_child(control.clone().upcast());
+        }
+
+        control.queue_free();
+    }
+}
+
+/// Dock containers of the editor, as observed when adding docks to them.
+#[derive(Debug, Default)]
+struct DockContainers {
+    slots: Vec<(InstanceId, DockSlot)>,
+}
+
+impl DockContainers {
+    /// Remembers the parent of `control` as container of `slot`, after the control has been added to that slot.
+    fn learn(&mut self, control: &Gd<Control>, slot: DockSlot) {
+        if let Some(container) = control.get_parent() {
+            self.insert(container.instance_id(), slot);
+        }
+    }
+
+    fn insert(&mut self, container: InstanceId, slot: DockSlot) {
+        // A slot has one container; a replaced container (e.g. after an editor layout change) is forgotten.
+        self.slots
+            .retain(|(known, known_slot)| *known != container && *known_slot != slot);
+        self.slots.push((container, slot));
+    }
+
+    /// Slot of a docked control. `None` if the control is floating, not docked, or its container is not known.
+    fn current_slot(&self, control: &Gd<Control>) -> Option<DockSlot> {
+        let container = control.get_parent()?.instance_id();
+        self.slot_of(container)
+    }
+
+    fn slot_of(&self, container: InstanceId) -> Option<DockSlot> {
+        self.slots
+            .iter()
+            .find(|(known, _)| *known == container)
+            .map(|(_, slot)| *slot)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id(value: i64) -> InstanceId {
+        InstanceId::from_i64(value)
+    }
+
+    #[test]
+    fn dock_containers_lookup() {
+        let mut containers = DockContainers::default();
+        containers.insert(id(10), DockSlot::LEFT_UL);
+        containers.insert(id(20), DockSlot::RIGHT_BR);
+
+        assert_eq!(containers.slot_of(id(10)), Some(DockSlot::LEFT_UL));
+        assert_eq!(containers.slot_of(id(20)), Some(DockSlot::RIGHT_BR));
+        assert_eq!(containers.slot_of(id(30)), None);
+    }
+
+    #[test]
+    fn dock_containers_replace() {
+        let mut containers = DockContainers::default();
+        containers.insert(id(10), DockSlot::LEFT_UL);
+
+        // New container for the same slot.
+        containers.insert(id(11), DockSlot::LEFT_UL);
+        assert_eq!(containers.slot_of(id(10)), None);
+        assert_eq!(containers.slot_of(id(11)), Some(DockSlot::LEFT_UL));
+
+        // Same container observed in another slot.
+        containers.insert(id(11), DockSlot::LEFT_BR);
+        assert_eq!(containers.slot_of(id(11)), Some(DockSlot::LEFT_BR));
+        assert_eq!(containers.slots.len(), 1);
+    }
+}
@@ -0,0 +1,173 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+
+use crate::builtin::{Callable, Variant};
+use crate::classes::{Engine, SceneTree};
+use crate::obj::Gd;
+use crate::tools::subscribers::{self, Registry, Subscribers};
+
+/// Handle to a callback registered with [`add_process_callback`] or [`add_physics_process_callback`].
+///
+/// Pass it to [`remove_frame_callback`] to unregister the callback again. Dropping the handle does _not_ remove the callback.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct FrameCallbackHandle {
+    kind: FrameKind,
+    id: u64,
+}
+
+/// Registers a closure to be invoked once per frame, with the process delta time in seconds.
+///
+/// This is a lightweight alternative to creating a `Node` only to override `INode::process()`. The callback runs when the
+/// [`SceneTree`] emits its `process_frame` signal, i.e. before the `process()` methods of nodes are invoked.
+///
+/// Callbacks are only invoked on the main thread and can thus capture non-thread-safe state.
+///
+/// # Example
+/// ```no_run
+/// use godot::tools::{add_process_callback, remove_frame_callback};
+///
+/// let mut elapsed = 0.0;
+/// let handle = add_process_callback(move |delta| {
+///     elapsed += delta;
+///     godot::global::godot_print!("Elapsed: {elapsed:.2}s");
+/// });
+///
+/// // Later, to stop the callback:
+/// remove_frame_callback(handle);
+/// ```
+///
+/// # Panics
+/// - If not called on the main thread.
+/// - If the main loop is not a `SceneTree` (or derived class). Custom `MainLoop` implementations can forward their
+///   `process()` virtual to [`dispatch_process_callbacks`] instead.
+pub fn add_process_callback<F>(callback: F) -> FrameCallbackHandle
+where
+    F: FnMut(f64) + 'static,
+{
+    add_frame_callback(FrameKind::Process, Box::new(callback))
+}
+
+/// Registers a closure to be invoked once per physics tick, with the physics delta time in seconds.
+///
+/// Equivalent to [`add_process_callback`], but hooked to the `physics_frame` signal of the [`SceneTree`].
+///
+/// # Panics
+/// - If not called on the main thread.
+/// - If the main loop is not a `SceneTree` (or derived class). Custom `MainLoop` implementations can forward their
+///   `physics_process()` virtual to [`dispatch_physics_process_callbacks`] instead.
+pub fn add_physics_process_callback<F>(callback: F) -> FrameCallbackHandle
+where
+    F: FnMut(f64) + 'static,
+{
+    add_frame_callback(FrameKind::PhysicsProcess, Box::new(callback))
+}
+
+/// Unregisters a callback previously added with [`add_process_callback`] or [`add_physics_process_callback`].
+///
+/// Returns `false` if the callback has already been removed. Removing a callback while it is running is allowed; it will
+/// not be invoked anymore afterward.
+pub fn remove_frame_callback(handle: FrameCallbackHandle) -> bool {
+    subscribers::unsubscribe(handle.kind.registry(), handle.id)
+}
+
+/// Invokes all callbacks registered with [`add_process_callback`].
+///
+/// This is done automatically when the main loop is a [`SceneTree`]. You only need to call this function if you implement
+/// your own `MainLoop` and want to support process callbacks; call it from `IMainLoop::process()`.
+pub fn dispatch_process_callbacks(delta: f64) {
+    dispatch(FrameKind::Process, delta);
+}
+
+/// Invokes all callbacks registered with [`add_physics_process_callback`].
+///
+/// See [`dispatch_process_callbacks`]; call this function from `IMainLoop::physics_process()`.
+pub fn dispatch_physics_process_callbacks(delta: f64) {
+    dispatch(FrameKind::PhysicsProcess, delta);
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+type BoxedCallback = Box<dyn FnMut(f64)>;
+
+thread_local! {
+    static PROCESS_CALLBACKS: RefCell<Subscribers<BoxedCallback>> = RefCell::default();
+    static PHYSICS_PROCESS_CALLBACKS: RefCell<Subscribers<BoxedCallback>> = RefCell::default();
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+enum FrameKind {
+    Process,
+    PhysicsProcess,
+}
+
+impl FrameKind {
+    fn signal_name(self) -> &'static str {
+        match self {
+            FrameKind::Process => "process_frame",
+            FrameKind::PhysicsProcess => "physics_frame",
+        }
+    }
+
+    fn registry(self) -> &'static Registry<BoxedCallback> {
+        match self {
+            FrameKind::Process => &PROCESS_CALLBACKS,
+            FrameKind::PhysicsProcess => &PHYSICS_PROCESS_CALLBACKS,
+        }
+    }
+}
+
+fn add_frame_callback(kind: FrameKind, callback: BoxedCallback) -> FrameCallbackHandle {
+    let (id, needs_connect) = subscribers::subscribe(kind.registry(), "frame callbacks", callback);
+
+    // Connect outside the RefCell borrow, in case Godot calls back synchronously.
+    if needs_connect {
+        connect_to_scene_tree(kind);
+    }
+
+    FrameCallbackHandle { kind, id }
+}
+
+fn connect_to_scene_tree(kind: FrameKind) {
+    let mut tree = scene_tree();
+
+    let callable = Callable::from_fn(kind.signal_name(), move |_args: &[&Variant]| {
+        let tree = scene_tree();
+        let root = tree.get_root().expect("SceneTree has no root window");
+
+        let delta = match kind {
+            FrameKind::Process => root.get_process_delta_time(),
+            FrameKind::PhysicsProcess => root.get_physics_process_delta_time(),
+        };
+
+        dispatch(kind, delta);
+        Ok(Variant::nil())
+    });
+
+    tree.connect(kind.signal_name().into(), callable);
+}
+
+fn scene_tree() -> Gd<SceneTree> {
+    let main_loop = Engine::singleton()
+        .get_main_loop()
+        .expect("frame callbacks require a running main loop");
+
+    main_loop
+        .try_cast::<SceneTree>()
+        .unwrap_or_else(|main_loop| {
+            panic!(
+            "frame callbacks require the main loop to be a SceneTree, but it is {main_loop:?}; \
+            call dispatch_process_callbacks() from your custom MainLoop instead"
+        )
+        })
+}
+
+fn dispatch(kind: FrameKind, delta: f64) {
+    subscribers::dispatch(kind.registry(), |callback| callback(delta));
+}
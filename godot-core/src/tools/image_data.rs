@@ -0,0 +1,516 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::{Color, PackedByteArray};
+use crate::classes::image::Format;
+use crate::classes::Image;
+use crate::obj::Gd;
+
+#[cfg(since_api = "4.2")]
+pub use readback::*;
+
+/// Pixel type that can be used to view the data of an [`Image`] in a matching format.
+///
+/// Implemented for the following types:
+///
+/// | Type       | Formats            |
+/// |------------|--------------------|
+/// | `u8`       | `L8`, `R8`         |
+/// | `[u8; 2]`  | `LA8`, `RG8`       |
+/// | `[u8; 3]`  | `RGB8`             |
+/// | `[u8; 4]`  | `RGBA8`            |
+/// | `f32`      | `RF`               |
+/// | `[f32; 2]` | `RGF`              |
+/// | `[f32; 3]` | `RGBF`             |
+/// | `[f32; 4]` | `RGBAF`            |
+/// | [`Color`]  | `RGBAF`            |
+///
+/// This trait is sealed and cannot be implemented outside of godot-rust.
+pub trait ImagePixel: Copy + sealed::Sealed + 'static {
+    /// Image formats whose pixels have exactly the memory layout of `Self`.
+    const FORMATS: &'static [Format];
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_image_pixel {
+    ($( $Ty:ty => [$($format:ident),+]; )+) => {
+        $(
+            impl sealed::Sealed for $Ty {}
+            impl ImagePixel for $Ty {
+                const FORMATS: &'static [Format] = &[$(Format::$format),+];
+            }
+        )+
+    };
+}
+
+impl_image_pixel! {
+    u8 => [L8, R8];
+    [u8; 2] => [LA8, RG8];
+    [u8; 3] => [RGB8];
+    [u8; 4] => [RGBA8];
+    f32 => [RF];
+    [f32; 2] => [RGF];
+    [f32; 3] => [RGBF];
+    [f32; 4] => [RGBAF];
+    Color => [RGBAF];
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Read-only view of the base mip level of an [`Image`], obtained from [`Image::data_view()`].
+///
+/// Holds a reference to the image's pixel buffer. Godot's packed arrays are copy-on-write, so creating the view does not copy any pixels;
+/// if the image is modified while the view exists, Godot copies the buffer for the image, and the view keeps seeing the old data.
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::Image;
+/// use godot::classes::image::Format;
+///
+/// let image = Image::create(64, 64, false, Format::RGBA8).unwrap();
+/// let view = image.data_view();
+///
+/// let pixels: &[[u8; 4]] = view.pixels().expect("RGBA8 format");
+/// let opaque = pixels.iter().filter(|[_, _, _, a]| *a == 255).count();
+/// ```
+pub struct ImageData {
+    data: PackedByteArray,
+    format: Format,
+    width: u32,
+    height: u32,
+}
+
+impl ImageData {
+    /// Pixel format of the viewed image.
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Width of the viewed image, in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Height of the viewed image, in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Raw bytes of the base mip level, in the image's format.
+    ///
+    /// For compressed formats, this is the compressed data of the first mip level.
+    pub fn bytes(&self) -> &[u8] {
+        let data = self.data.as_slice();
+        let len = base_level_len(self.format, self.width, self.height).unwrap_or(data.len());
+
+        &data[..len.min(data.len())]
+    }
+
+    /// Pixels of the base mip level as `P`, in row-major order.
+    ///
+    /// Returns `None` if the image format doesn't match `P`, see [`ImagePixel`] for the supported combinations.
+    pub fn pixels<P: ImagePixel>(&self) -> Option<&[P]> {
+        if !P::FORMATS.contains(&self.format) {
+            return None;
+        }
+
+        // SAFETY: all `ImagePixel` types are plain data without padding or invalid bit patterns, matching the layout of the image format.
+        let (prefix, pixels, _) = unsafe { self.bytes().align_to::<P>() };
+
+        // Godot allocates packed arrays with at least 8-byte alignment, so this only fails if that assumption breaks.
+        if !prefix.is_empty() {
+            return None;
+        }
+
+        Some(pixels)
+    }
+
+    /// Pixels of row `y` as `P`. Returns `None` if the format doesn't match `P`, or `y` is out of bounds.
+    pub fn row<P: ImagePixel>(&self, y: u32) -> Option<&[P]> {
+        if y >= self.height {
+            return None;
+        }
+
+        let width = self.width as usize;
+        let start = y as usize * width;
+        self.pixels::<P>()
+            .and_then(|pixels| pixels.get(start..start + width))
+    }
+
+    /// Converts all pixels to 8-bit RGBA, if the format can be converted without Godot.
+    ///
+    /// Supports all formats listed in [`ImagePixel`]; float channels are clamped to `0.0..=1.0`. Luminance is replicated to
+    /// RGB, missing color channels become 0 and missing alpha 255, like Godot's `Image.convert()`.
+    ///
+    /// Returns `None` for other formats, see [`Image::to_rgba8_pixels()`] for a version that falls back to Godot.
+    pub fn to_rgba8(&self) -> Option<Vec<[u8; 4]>> {
+        let u8_from_f32 = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+        match self.format {
+            Format::L8 => self.map_pixels(|&l: &u8| [l, l, l, 255]),
+            Format::LA8 => self.map_pixels(|&[l, a]: &[u8; 2]| [l, l, l, a]),
+            Format::R8 => self.map_pixels(|&r: &u8| [r, 0, 0, 255]),
+            Format::RG8 => self.map_pixels(|&[r, g]: &[u8; 2]| [r, g, 0, 255]),
+            Format::RGB8 => self.map_pixels(|&[r, g, b]: &[u8; 3]| [r, g, b, 255]),
+            Format::RGBA8 => self.pixels::<[u8; 4]>().map(<[_]>::to_vec),
+            Format::RF => self.map_pixels(|&r: &f32| [u8_from_f32(r), 0, 0, 255]),
+            Format::RGF => {
+                self.map_pixels(|&[r, g]: &[f32; 2]| [u8_from_f32(r), u8_from_f32(g), 0, 255])
+            }
+            Format::RGBF => self.map_pixels(|rgb: &[f32; 3]| {
+                [
+                    u8_from_f32(rgb[0]),
+                    u8_from_f32(rgb[1]),
+                    u8_from_f32(rgb[2]),
+                    255,
+                ]
+            }),
+            Format::RGBAF => self.map_pixels(|rgba: &[f32; 4]| rgba.map(u8_from_f32)),
+            _ => None,
+        }
+    }
+
+    /// Converts all pixels to float RGBA, if the format can be converted without Godot.
+    ///
+    /// Uses the same channel rules as [`to_rgba8()`][Self::to_rgba8]. Returns `None` for other formats.
+    pub fn to_colors(&self) -> Option<Vec<Color>> {
+        let f32_from_u8 = |c: u8| c as f32 / 255.0;
+
+        match self.format {
+            Format::RGBAF => self.pixels::<Color>().map(<[_]>::to_vec),
+            Format::RF => self.map_pixels(|&r: &f32| Color::from_rgb(r, 0.0, 0.0)),
+            Format::RGF => self.map_pixels(|&[r, g]: &[f32; 2]| Color::from_rgb(r, g, 0.0)),
+            Format::RGBF => self.map_pixels(|&[r, g, b]: &[f32; 3]| Color::from_rgb(r, g, b)),
+            _ => self.to_rgba8().map(|pixels| {
+                pixels
+                    .into_iter()
+                    .map(|rgba| {
+                        let [r, g, b, a] = rgba.map(f32_from_u8);
+                        Color::from_rgba(r, g, b, a)
+                    })
+                    .collect()
+            }),
+        }
+    }
+
+    fn map_pixels<P: ImagePixel, T>(&self, f: impl FnMut(&P) -> T) -> Option<Vec<T>> {
+        self.pixels::<P>()
+            .map(|pixels| pixels.iter().map(f).collect())
+    }
+}
+
+impl fmt::Debug for ImageData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ImageData")
+            .field("format", &self.format)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .finish_non_exhaustive()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `Image` class, to access pixel data from Rust.
+impl Image {
+    /// Returns a read-only view of the pixel data, without copying.
+    ///
+    /// See [`ImageData`] for typed access to the pixels.
+    pub fn data_view(&self) -> ImageData {
+        let to_u32 = |dim: i32| u32::try_from(dim).expect("image dimension is negative");
+
+        ImageData {
+            data: self.get_data(),
+            format: self.get_format(),
+            width: to_u32(self.get_width()),
+            height: to_u32(self.get_height()),
+        }
+    }
+
+    /// Returns all pixels of the base mip level as 8-bit RGBA.
+    ///
+    /// Common formats are converted in Rust, see [`ImageData::to_rgba8()`]. Others (including compressed formats) are converted by
+    /// Godot, on a copy of this image.
+    pub fn to_rgba8_pixels(&self) -> Vec<[u8; 4]> {
+        self.data_view().to_rgba8().unwrap_or_else(|| {
+            self.converted_copy(Format::RGBA8)
+                .data_view()
+                .to_rgba8()
+                .expect("image has RGBA8 format after conversion")
+        })
+    }
+
+    /// Returns all pixels of the base mip level as float RGBA.
+    ///
+    /// Like [`to_rgba8_pixels()`][Self::to_rgba8_pixels], uncommon formats are converted by Godot first.
+    pub fn to_color_pixels(&self) -> Vec<Color> {
+        self.data_view().to_colors().unwrap_or_else(|| {
+            self.converted_copy(Format::RGBAF)
+                .data_view()
+                .to_colors()
+                .expect("image has RGBAF format after conversion")
+        })
+    }
+
+    fn converted_copy(&self, format: Format) -> Gd<Image> {
+        let mut copy = self
+            .duplicate()
+            .and_then(|res| res.try_cast::<Image>().ok())
+            .expect("Image::duplicate() failed");
+
+        if copy.is_compressed() {
+            copy.decompress();
+        }
+        copy.convert(format);
+        copy
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Asynchronous readback
+
+#[cfg(since_api = "4.2")]
+mod readback {
+    use std::cell::RefCell;
+    use std::fmt;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::rc::Rc;
+    use std::task::{Context, Poll, Waker};
+
+    use crate::builtin::{Callable, Variant};
+    use crate::classes::object::ConnectFlags;
+    use crate::classes::{Image, RenderingServer, Viewport};
+    use crate::obj::{Gd, InstanceId};
+
+    /// Future that captures the contents of a viewport, returned by [`Viewport::readback_async()`].
+    ///
+    /// Resolves to the image once it has been read back, or `None` if the viewport was freed in the meantime or has no texture. The waker
+    /// is invoked by the engine when the data is available, so the future does not need to be polled each frame.
+    #[must_use = "futures do nothing unless awaited"]
+    pub struct ImageReadback {
+        request: Rc<RefCell<ReadbackRequest>>,
+    }
+
+    impl ImageReadback {
+        /// Returns `true` if the frame has been drawn and the image is available.
+        pub fn is_ready(&self) -> bool {
+            self.request.borrow().image.is_some()
+        }
+    }
+
+    impl Future for ImageReadback {
+        type Output = Option<Gd<Image>>;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let mut request = self.request.borrow_mut();
+
+            match request.image.take() {
+                Some(image) => Poll::Ready(image),
+                None => {
+                    request.waker = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            }
+        }
+    }
+
+    impl fmt::Debug for ImageReadback {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("ImageReadback")
+                .field("ready", &self.is_ready())
+                .finish()
+        }
+    }
+
+    /// Manual extensions for the `Viewport` class, to read back rendered images.
+    impl Viewport {
+        /// Captures the viewport contents after the current frame has been drawn.
+        ///
+        /// Reading the viewport texture directly with `get_texture().get_image()` during `process()` returns the previous frame, and
+        /// blocks until the GPU has finished. This method instead waits for `RenderingServer.frame_post_draw`, and then:
+        /// - Since Godot 4.4, with a `RenderingDevice`-based renderer (Forward+, Mobile) and rendering on the main thread, downloads the
+        ///   texture with `RenderingDevice.texture_get_data_async()`. The future resolves a few frames later, without stalling the CPU.
+        /// - Otherwise, reads the texture with `get_image()`. This still waits for the GPU to finish the frame, so it is not truly
+        ///   asynchronous, but at least captures the current frame.
+        ///
+        /// # Example
+        /// ```no_run
+        /// # async fn screenshot(viewport: godot::obj::Gd<godot::classes::Viewport>) {
+        /// if let Some(image) = viewport.readback_async().await {
+        ///     let pixels = image.to_rgba8_pixels();
+        /// }
+        /// # }
+        /// ```
+        pub fn readback_async(&self) -> ImageReadback {
+            let request = Rc::new(RefCell::new(ReadbackRequest {
+                viewport: InstanceId::from_i64(self.get_instance_id()),
+                image: None,
+                waker: None,
+            }));
+
+            // Only a weak reference, so that dropping the future before the frame is drawn releases the request.
+            let weak_request = Rc::downgrade(&request);
+            let callable =
+                Callable::from_local_fn("readback_async", move |_args: &[&Variant]| {
+                    if let Some(request) = weak_request.upgrade() {
+                        complete_request(&request);
+                    }
+                    Ok(Variant::nil())
+                });
+
+            RenderingServer::singleton()
+                .connect_ex("frame_post_draw".into(), callable)
+                .flags(ConnectFlags::ONE_SHOT)
+                .done();
+
+            ImageReadback { request }
+        }
+    }
+
+    // ------------------------------------------------------------------------------------------------------------------------------------------
+    // Implementation
+
+    struct ReadbackRequest {
+        viewport: InstanceId,
+
+        /// `Some` once the frame has been drawn; the inner `None` means that no image could be captured.
+        image: Option<Option<Gd<Image>>>,
+        waker: Option<Waker>,
+    }
+
+    fn complete_request(request: &Rc<RefCell<ReadbackRequest>>) {
+        let texture = Gd::<Viewport>::try_from_instance_id(request.borrow().viewport)
+            .ok()
+            .and_then(|viewport| viewport.get_texture());
+
+        #[cfg(since_api = "4.4")]
+        if let Some(texture) = &texture {
+            if rendering_device::download(texture, Rc::downgrade(request)) {
+                return;
+            }
+        }
+
+        finish_request(request, texture.and_then(|texture| texture.get_image()));
+    }
+
+    fn finish_request(request: &RefCell<ReadbackRequest>, image: Option<Gd<Image>>) {
+        // Wake outside the borrow, in case the executor polls synchronously.
+        let waker = {
+            let mut request = request.borrow_mut();
+            request.image = Some(image);
+            request.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+
+    #[cfg(since_api = "4.4")]
+    mod rendering_device {
+        use std::cell::RefCell;
+        use std::rc::Weak;
+
+        use super::{finish_request, ReadbackRequest};
+        use crate::builtin::{Callable, PackedByteArray, Variant};
+        use crate::classes::image::Format;
+        use crate::classes::rendering_device::DataFormat;
+        use crate::classes::{Image, ProjectSettings, RenderingServer, Texture2D};
+        use crate::global::Error;
+        use crate::obj::Gd;
+
+        /// Value of `rendering/driver/threads/thread_model` for a separate render thread.
+        const THREAD_MODEL_SEPARATE: i64 = 2;
+
+        /// Starts an asynchronous download of `texture`, which completes `request`. Returns `false` if not supported in this setup.
+        pub(super) fn download(
+            texture: &Gd<Texture2D>,
+            request: Weak<RefCell<ReadbackRequest>>,
+        ) -> bool {
+            // The callback is invoked on the render thread, but the request lives on the main thread.
+            let thread_model = ProjectSettings::singleton()
+                .get_setting("rendering/driver/threads/thread_model".into())
+                .try_to::<i64>();
+            if thread_model == Ok(THREAD_MODEL_SEPARATE) {
+                return false;
+            }
+
+            // No RenderingDevice in the Compatibility renderer.
+            let mut server = RenderingServer::singleton();
+            let Some(mut device) = server.get_rendering_device() else {
+                return false;
+            };
+
+            let rd_texture = server.texture_get_rd_texture(texture.get_rid());
+            let Some(texture_format) = device.texture_get_format(rd_texture) else {
+                return false;
+            };
+            let Some(format) = image_format(texture_format.get_format()) else {
+                return false;
+            };
+
+            let width = texture_format.get_width() as i32;
+            let height = texture_format.get_height() as i32;
+
+            let callback = Callable::from_local_fn("readback_async", move |args: &[&Variant]| {
+                let data = args
+                    .first()
+                    .ok_or(())?
+                    .try_to::<PackedByteArray>()
+                    .map_err(|_| ())?;
+
+                if let Some(request) = request.upgrade() {
+                    finish_request(
+                        &request,
+                        Image::create_from_data(width, height, false, format, data),
+                    );
+                }
+                Ok(Variant::nil())
+            });
+
+            device.texture_get_data_async(rd_texture, 0, callback) == Error::OK
+        }
+
+        /// Image format with the same memory layout as a texture format, for those used by viewports.
+        fn image_format(data_format: DataFormat) -> Option<Format> {
+            let format = match data_format {
+                DataFormat::R8G8B8A8_UNORM | DataFormat::R8G8B8A8_SRGB => Format::RGBA8,
+                DataFormat::R16G16B16A16_SFLOAT => Format::RGBAH,
+                DataFormat::R32G32B32A32_SFLOAT => Format::RGBAF,
+                _ => return None,
+            };
+
+            Some(format)
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+/// Size in bytes of the first mip level, or `None` for formats whose size Godot computes internally (compressed ones).
+fn base_level_len(format: Format, width: u32, height: u32) -> Option<usize> {
+    let bytes_per_pixel = match format {
+        Format::L8 | Format::R8 => 1,
+        Format::LA8 | Format::RG8 | Format::RGBA4444 | Format::RGB565 | Format::RH => 2,
+        Format::RGB8 => 3,
+        Format::RGBA8 | Format::RF | Format::RGH | Format::RGBE9995 => 4,
+        Format::RGBH => 6,
+        Format::RGF | Format::RGBAH => 8,
+        Format::RGBF => 12,
+        Format::RGBAF => 16,
+        _ => return None,
+    };
+
+    Some(width as usize * height as usize * bytes_per_pixel)
+}
@@ -0,0 +1,156 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use ::image::{DynamicImage, ImageBuffer, Pixel, Rgba};
+
+use crate::builtin::PackedByteArray;
+use crate::classes::image::Format;
+use crate::classes::Image;
+use crate::obj::Gd;
+
+/// Manual extensions for the `Image` class, to interoperate with the [`image`](https://docs.rs/image) crate (requires feature `image`).
+impl Image {
+    /// Copies the pixels of the base mip level into an [`image::DynamicImage`][DynamicImage].
+    ///
+    /// The formats `L8`, `LA8`, `RGB8`, `RGBA8`, `RGBF` and `RGBAF` are copied as-is. All other formats (including compressed ones) are
+    /// converted to `RGBA8` first, on a copy of this image.
+    pub fn to_dynamic_image(&self) -> DynamicImage {
+        let format = self.get_format();
+        let Some(bytes_per_pixel) = direct_bytes_per_pixel(format) else {
+            return self.to_rgba8_copy().to_dynamic_image();
+        };
+
+        let (width, height) = self.size_u32();
+        let data = self.get_data();
+        let bytes = &data.as_slice()[..width as usize * height as usize * bytes_per_pixel];
+
+        match format {
+            Format::L8 => DynamicImage::ImageLuma8(make_buffer(width, height, bytes.to_vec())),
+            Format::LA8 => DynamicImage::ImageLumaA8(make_buffer(width, height, bytes.to_vec())),
+            Format::RGB8 => DynamicImage::ImageRgb8(make_buffer(width, height, bytes.to_vec())),
+            Format::RGBA8 => DynamicImage::ImageRgba8(make_buffer(width, height, bytes.to_vec())),
+            Format::RGBF => DynamicImage::ImageRgb32F(make_buffer(width, height, to_floats(bytes))),
+            Format::RGBAF => {
+                DynamicImage::ImageRgba32F(make_buffer(width, height, to_floats(bytes)))
+            }
+            _ => unreachable!("format {format:?} has direct mapping"),
+        }
+    }
+
+    /// Creates a Godot image from an [`image::DynamicImage`][DynamicImage], without mipmaps.
+    ///
+    /// 8-bit and 32-bit float images keep their channel layout. 16-bit images are converted to `RGBAF`, since Godot has no
+    /// 16-bit integer formats.
+    pub fn from_dynamic_image(image: &DynamicImage) -> Gd<Image> {
+        let (format, data) = match image {
+            DynamicImage::ImageLuma8(buf) => {
+                (Format::L8, PackedByteArray::from(buf.as_raw().as_slice()))
+            }
+            DynamicImage::ImageLumaA8(buf) => {
+                (Format::LA8, PackedByteArray::from(buf.as_raw().as_slice()))
+            }
+            DynamicImage::ImageRgb8(buf) => {
+                (Format::RGB8, PackedByteArray::from(buf.as_raw().as_slice()))
+            }
+            DynamicImage::ImageRgba8(buf) => (
+                Format::RGBA8,
+                PackedByteArray::from(buf.as_raw().as_slice()),
+            ),
+            DynamicImage::ImageRgb32F(buf) => (Format::RGBF, from_floats(buf.as_raw())),
+            DynamicImage::ImageRgba32F(buf) => (Format::RGBAF, from_floats(buf.as_raw())),
+            other => (Format::RGBAF, from_floats(other.to_rgba32f().as_raw())),
+        };
+
+        Image::create_from_data(
+            image.width() as i32,
+            image.height() as i32,
+            false,
+            format,
+            data,
+        )
+        .expect("Image::create_from_data() failed")
+    }
+
+    /// Invokes `f` with an [`image::ImageBuffer`][ImageBuffer] that borrows the RGBA8 pixels of the base mip level, without copying.
+    ///
+    /// Returns `None` if the image format is not `RGBA8`; in that case, use [`convert()`][Self::convert] or
+    /// [`to_dynamic_image()`][Self::to_dynamic_image].
+    pub fn with_rgba8_view<R>(
+        &self,
+        f: impl FnOnce(ImageBuffer<Rgba<u8>, &[u8]>) -> R,
+    ) -> Option<R> {
+        if self.get_format() != Format::RGBA8 {
+            return None;
+        }
+
+        let (width, height) = self.size_u32();
+
+        // get_data() shares the image's buffer through copy-on-write, so there is no copy as long as the image isn't modified.
+        let data = self.get_data();
+        let len = width as usize * height as usize * Rgba::<u8>::CHANNEL_COUNT as usize;
+        let view = ImageBuffer::from_raw(width, height, &data.as_slice()[..len])
+            .expect("buffer size matches image dimensions");
+
+        Some(f(view))
+    }
+
+    fn size_u32(&self) -> (u32, u32) {
+        let to_u32 = |dim: i32| u32::try_from(dim).expect("image dimension is negative");
+        (to_u32(self.get_width()), to_u32(self.get_height()))
+    }
+
+    fn to_rgba8_copy(&self) -> Gd<Image> {
+        let mut copy = self
+            .duplicate()
+            .and_then(|res| res.try_cast::<Image>().ok())
+            .expect("Image::duplicate() failed");
+
+        if copy.is_compressed() {
+            copy.decompress();
+        }
+        copy.convert(Format::RGBA8);
+        copy
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+/// Bytes per pixel for formats with a 1:1 counterpart in the `image` crate.
+fn direct_bytes_per_pixel(format: Format) -> Option<usize> {
+    let bytes = match format {
+        Format::L8 => 1,
+        Format::LA8 => 2,
+        Format::RGB8 => 3,
+        Format::RGBA8 => 4,
+        Format::RGBF => 12,
+        Format::RGBAF => 16,
+        _ => return None,
+    };
+
+    Some(bytes)
+}
+
+fn make_buffer<P: Pixel>(
+    width: u32,
+    height: u32,
+    data: Vec<P::Subpixel>,
+) -> ImageBuffer<P, Vec<P::Subpixel>> {
+    ImageBuffer::from_raw(width, height, data).expect("buffer size matches image dimensions")
+}
+
+/// Godot stores float images as little-endian bytes.
+fn to_floats(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes(chunk.try_into().unwrap()))
+        .collect()
+}
+
+fn from_floats(floats: &[f32]) -> PackedByteArray {
+    floats.iter().flat_map(|f| f.to_le_bytes()).collect()
+}
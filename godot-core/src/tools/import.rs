@@ -0,0 +1,281 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Helpers for custom editor importers, implemented through [`IEditorImportPlugin`][crate::classes::IEditorImportPlugin].
+//!
+//! Godot passes import options as untyped dictionaries, both when asking for the available options and when importing. The
+//! [`import_options!`] macro declares a struct holding typed options, which can be converted in both directions.
+//! [`save_imported()`] and [`save_imported_scene()`] write the result of an import to the location Godot expects.
+//!
+//! # Example
+//! ```no_run
+//! use godot::prelude::*;
+//! use godot::classes::{EditorImportPlugin, IEditorImportPlugin};
+//! use godot::global::Error;
+//! use godot::tools::import::{import_options, save_imported_scene, ImportOptionSet};
+//!
+//! import_options! {
+//!     /// Options shown in the import dock.
+//!     pub struct ModelOptions {
+//!         scale: f32 = 1.0 => .range(0.01, 100.0, 0.01),
+//!         flip_y: bool = false,
+//!         root_name: GString = GString::from("Model"),
+//!     }
+//! }
+//!
+//! #[derive(GodotClass)]
+//! #[class(tool, init, base=EditorImportPlugin)]
+//! struct ModelImporter {}
+//!
+//! #[godot_api]
+//! impl IEditorImportPlugin for ModelImporter {
+//!     fn get_import_options(&self, _path: GString, _preset_index: i32) -> Array<Dictionary> {
+//!         ModelOptions::import_options()
+//!     }
+//!
+//!     fn import(
+//!         &self,
+//!         _source_file: GString,
+//!         save_path: GString,
+//!         options: Dictionary,
+//!         _platform_variants: Array<GString>,
+//!         _gen_files: Array<GString>,
+//!     ) -> Error {
+//!         let options = match ModelOptions::from_import_options(&options) {
+//!             Ok(options) => options,
+//!             Err(_) => return Error::ERR_INVALID_PARAMETER,
+//!         };
+//!
+//!         let mut root = Node3D::new_alloc();
+//!         root.set_name(options.root_name.clone());
+//!         root.set_scale(Vector3::ONE * options.scale);
+//!         // ... parse the file and add child nodes ...
+//!
+//!         save_imported_scene(root.upcast(), &save_path, "scn")
+//!     }
+//!
+//!     // Other required methods: get_importer_name(), get_recognized_extensions(), get_save_extension() ("scn") etc.
+//! }
+//! ```
+
+use crate::builtin::{Array, Dictionary, GString, Variant};
+use crate::classes::{Node, PackedScene, Resource, ResourceSaver};
+use crate::global::{Error, PropertyHint, PropertyUsageFlags};
+use crate::meta::error::ConvertError;
+use crate::meta::{FromGodot, ToGodot};
+use crate::obj::{EngineBitfield, EngineEnum, Gd, Inherits, NewGd};
+use crate::registry::property::PropertyHintInfo;
+
+pub use crate::import_options;
+
+/// One option of an importer, as returned by `IEditorImportPlugin::get_import_options()`.
+#[derive(Clone, Debug)]
+pub struct ImportOption {
+    name: GString,
+    default_value: Variant,
+    hint: Option<PropertyHintInfo>,
+    usage: Option<PropertyUsageFlags>,
+}
+
+impl ImportOption {
+    /// Creates an option with the given name and default value, without a property hint.
+    ///
+    /// Use `/` in the name to group options in the import dock, e.g. `"mesh/scale"`.
+    pub fn new(name: impl Into<GString>, default_value: impl ToGodot) -> Self {
+        Self {
+            name: name.into(),
+            default_value: default_value.to_variant(),
+            hint: None,
+            usage: None,
+        }
+    }
+
+    /// Sets the property hint, e.g. from one of the [`export_info_functions`][crate::registry::property::export_info_functions].
+    pub fn hint(mut self, hint: PropertyHintInfo) -> Self {
+        self.hint = Some(hint);
+        self
+    }
+
+    /// Shows a slider between `min` and `max`, like `@export_range(min, max, step)`.
+    pub fn range(self, min: f64, max: f64, step: f64) -> Self {
+        self.hint(PropertyHintInfo {
+            hint: PropertyHint::RANGE,
+            hint_string: format!("{min},{max},{step}").into(),
+        })
+    }
+
+    /// Shows a dropdown with the given names, like `@export_enum(...)`. The option value is the index of the selected name.
+    pub fn enum_names(self, names: &[&str]) -> Self {
+        self.hint(PropertyHintInfo {
+            hint: PropertyHint::ENUM,
+            hint_string: names.join(",").into(),
+        })
+    }
+
+    /// Shows a file picker with the given filter (e.g. `"*.png,*.jpg"`), like `@export_file(filter)`.
+    pub fn file(self, filter: &str) -> Self {
+        self.hint(PropertyHintInfo {
+            hint: PropertyHint::FILE,
+            hint_string: filter.into(),
+        })
+    }
+
+    /// Overrides the usage flags, e.g. to hide an option from the import dock while still storing it.
+    pub fn usage(mut self, usage: PropertyUsageFlags) -> Self {
+        self.usage = Some(usage);
+        self
+    }
+
+    /// Name of the option, which is the key in the options dictionary passed to `import()`.
+    pub fn name(&self) -> &GString {
+        &self.name
+    }
+
+    /// Converts to the dictionary format expected by Godot.
+    pub fn to_dictionary(&self) -> Dictionary {
+        let mut dict = Dictionary::new();
+        dict.set("name", self.name.clone());
+        dict.set("default_value", self.default_value.clone());
+
+        if let Some(hint) = &self.hint {
+            dict.set("property_hint", hint.hint.ord());
+            dict.set("hint_string", hint.hint_string.clone());
+        }
+
+        if let Some(usage) = self.usage {
+            dict.set("usage", usage.ord() as i64);
+        }
+
+        dict
+    }
+}
+
+/// Typed set of import options, usually declared with [`import_options!`].
+pub trait ImportOptionSet: Sized {
+    /// All options, with their default values.
+    fn option_list() -> Vec<ImportOption>;
+
+    /// Reads the options from the dictionary passed to `import()` and similar methods.
+    ///
+    /// Missing entries use the default value. Fails if an entry has a type that cannot be converted.
+    fn from_import_options(options: &Dictionary) -> Result<Self, ConvertError>;
+
+    /// Return value for `IEditorImportPlugin::get_import_options()`.
+    fn import_options() -> Array<Dictionary> {
+        Self::option_list()
+            .iter()
+            .map(ImportOption::to_dictionary)
+            .collect()
+    }
+}
+
+/// Declares a struct of typed import options, implementing [`ImportOptionSet`] and `Default`.
+///
+/// Each field is declared as `name: Type = default`, optionally followed by `=>` and [`ImportOption`] builder calls that configure the
+/// option (for example `=> .range(0.0, 1.0, 0.1)`). All fields are `pub`; field types must implement `ToGodot` and `FromGodot`.
+///
+/// See the [module docs](crate::tools::import) for an example.
+#[macro_export]
+macro_rules! import_options {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $Name:ident {
+            $(
+                $(#[$field_attr:meta])*
+                $field:ident : $Ty:ty = $default:expr $( => $( .$method:ident ( $($arg:expr),* $(,)? ) )+ )?
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $Name {
+            $(
+                $(#[$field_attr])*
+                pub $field: $Ty,
+            )*
+        }
+
+        impl ::std::default::Default for $Name {
+            fn default() -> Self {
+                Self {
+                    $( $field: $default, )*
+                }
+            }
+        }
+
+        impl $crate::tools::import::ImportOptionSet for $Name {
+            fn option_list() -> ::std::vec::Vec<$crate::tools::import::ImportOption> {
+                let defaults = <Self as ::std::default::Default>::default();
+
+                ::std::vec![
+                    $(
+                        $crate::tools::import::ImportOption::new(stringify!($field), defaults.$field)
+                            $($( .$method( $($arg),* ) )+)?,
+                    )*
+                ]
+            }
+
+            fn from_import_options(
+                options: &$crate::builtin::Dictionary,
+            ) -> ::std::result::Result<Self, $crate::meta::error::ConvertError> {
+                let mut result = <Self as ::std::default::Default>::default();
+
+                $(
+                    if let ::std::option::Option::Some(value) = options.get(stringify!($field)) {
+                        result.$field = $crate::tools::import::__read_option(stringify!($field), &value)?;
+                    }
+                )*
+
+                ::std::result::Result::Ok(result)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+pub fn __read_option<T: FromGodot>(name: &str, value: &Variant) -> Result<T, ConvertError> {
+    value.try_to::<T>().map_err(|err| err.at_key(name))
+}
+
+/// Saves the result of an import to `save_path` with the given extension, as expected by `IEditorImportPlugin::import()`.
+///
+/// Godot passes `save_path` without extension; `extension` must match the one returned by `get_save_extension()`. The returned error
+/// can be returned from `import()` directly.
+pub fn save_imported<T>(resource: Gd<T>, save_path: &GString, extension: &str) -> Error
+where
+    T: Inherits<Resource>,
+{
+    let path = format!("{save_path}.{extension}");
+
+    ResourceSaver::singleton()
+        .save_ex(resource.upcast())
+        .path(path.into())
+        .done()
+}
+
+/// Packs the scene rooted at `root` and saves it like [`save_imported()`]. Frees `root` afterward.
+///
+/// All descendants of `root` are made owned by it, so that nodes added from Rust are included in the packed scene.
+pub fn save_imported_scene(root: Gd<Node>, save_path: &GString, extension: &str) -> Error {
+    set_owner_recursive(&root, root.clone());
+
+    let mut scene = PackedScene::new_gd();
+    let err = scene.pack(root.clone());
+    root.free();
+
+    if err != Error::OK {
+        return err;
+    }
+
+    save_imported(scene, save_path, extension)
+}
+
+fn set_owner_recursive(node: &Gd<Node>, owner: Gd<Node>) {
+    for mut child in node.get_children().iter_shared() {
+        child.set_owner(owner.clone());
+        set_owner_recursive(&child, owner.clone());
+    }
+}
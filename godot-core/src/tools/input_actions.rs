@@ -0,0 +1,327 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::StringName;
+use crate::classes::{
+    Input, InputEvent, InputEventJoypadButton, InputEventJoypadMotion, InputEventKey,
+    InputEventMouseButton, InputMap,
+};
+use crate::global::{JoyAxis, JoyButton, Key, MouseButton};
+use crate::obj::{Gd, Inherits, NewGd};
+
+/// Device index that makes an input map event match all devices, like "All Devices" in the editor.
+const ALL_DEVICES: i32 = -1;
+
+/// Default deadzone of actions, same as in the editor.
+const DEFAULT_DEADZONE: f32 = 0.5;
+
+/// Entry point to declare [`InputMap`] actions from Rust.
+///
+/// Instead of configuring actions in the project settings, an extension can define them in code, typically during initialization.
+/// This keeps bindings next to the code that uses them, and works for extensions that are used in several projects.
+///
+/// Registration is idempotent: defining an action that already exists replaces its bindings and deadzone. This is what happens on hot
+/// reload, but it also means that actions configured in `project.godot` are overridden by the Rust definition of the same name.
+///
+/// To access actions in a typed way afterward, declare their names as [`InputAction`] constants, or use the [`input_actions!`] macro,
+/// which does both.
+///
+/// # Example
+/// ```no_run
+/// use godot::global::{JoyAxis, JoyButton, Key};
+/// use godot::tools::InputActions;
+///
+/// InputActions::define("jump")
+///     .key(Key::SPACE)
+///     .gamepad_button(JoyButton::A)
+///     .register();
+///
+/// InputActions::define("move_left")
+///     .key(Key::A)
+///     .gamepad_axis(JoyAxis::LEFT_X, -1.0)
+///     .deadzone(0.2)
+///     .register();
+/// ```
+pub struct InputActions;
+
+impl InputActions {
+    /// Starts defining the action `name`. Nothing is changed until [`ActionBuilder::register()`] is called.
+    pub fn define(name: impl Into<StringName>) -> ActionBuilder {
+        ActionBuilder {
+            name: name.into(),
+            deadzone: DEFAULT_DEADZONE,
+            events: Vec::new(),
+        }
+    }
+
+    /// Removes the action `name` from the input map, if it exists.
+    ///
+    /// Returns whether the action existed.
+    pub fn erase(name: impl Into<StringName>) -> bool {
+        let name = name.into();
+        let mut input_map = InputMap::singleton();
+
+        if !input_map.has_action(name.clone()) {
+            return false;
+        }
+
+        input_map.erase_action(name);
+        true
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Builder for one input map action, created with [`InputActions::define()`].
+///
+/// All events are bound for any device, as with the "All Devices" option in the editor.
+#[must_use = "the action is only added to the input map by register()"]
+pub struct ActionBuilder {
+    name: StringName,
+    deadzone: f32,
+    events: Vec<Gd<InputEvent>>,
+}
+
+impl ActionBuilder {
+    /// Binds a key, by its keycode in the current keyboard layout.
+    pub fn key(self, key: Key) -> Self {
+        let mut event = InputEventKey::new_gd();
+        event.set_keycode(key);
+        self.event(event)
+    }
+
+    /// Binds a key, by its physical location on a US QWERTY keyboard.
+    ///
+    /// Prefer this for movement keys such as WASD, so that they keep their location on other keyboard layouts.
+    pub fn physical_key(self, key: Key) -> Self {
+        let mut event = InputEventKey::new_gd();
+        event.set_physical_keycode(key);
+        self.event(event)
+    }
+
+    /// Binds a mouse button.
+    pub fn mouse_button(self, button: MouseButton) -> Self {
+        let mut event = InputEventMouseButton::new_gd();
+        event.set_button_index(button);
+        self.event(event)
+    }
+
+    /// Binds a gamepad button.
+    pub fn gamepad_button(self, button: JoyButton) -> Self {
+        let mut event = InputEventJoypadButton::new_gd();
+        event.set_button_index(button);
+        self.event(event)
+    }
+
+    /// Binds one direction of a gamepad axis. `direction` is `-1.0` for left/up or `1.0` for right/down.
+    ///
+    /// # Panics
+    /// If `direction` is zero.
+    pub fn gamepad_axis(self, axis: JoyAxis, direction: f32) -> Self {
+        assert!(
+            direction != 0.0,
+            "gamepad_axis(): direction must be negative or positive, not zero"
+        );
+
+        let mut event = InputEventJoypadMotion::new_gd();
+        event.set_axis(axis);
+        event.set_axis_value(direction.signum());
+        self.event(event)
+    }
+
+    /// Binds an arbitrary input event, e.g. a key combination with modifiers.
+    ///
+    /// The event's device is overwritten to match all devices.
+    pub fn event<E>(mut self, event: Gd<E>) -> Self
+    where
+        E: Inherits<InputEvent>,
+    {
+        let mut event = event.upcast::<InputEvent>();
+        event.set_device(ALL_DEVICES);
+        self.events.push(event);
+        self
+    }
+
+    /// Sets how far an analog input must be moved for the action to count as pressed. Defaults to `0.5`.
+    pub fn deadzone(mut self, deadzone: f32) -> Self {
+        self.deadzone = deadzone;
+        self
+    }
+
+    /// Adds the action to the input map, or replaces the bindings and deadzone if it already exists.
+    pub fn register(self) {
+        let mut input_map = InputMap::singleton();
+        let name = self.name;
+
+        if input_map.has_action(name.clone()) {
+            input_map.action_erase_events(name.clone());
+            input_map.action_set_deadzone(name.clone(), self.deadzone);
+        } else {
+            input_map
+                .add_action_ex(name.clone())
+                .deadzone(self.deadzone)
+                .done();
+        }
+
+        for event in self.events {
+            input_map.action_add_event(name.clone(), event);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Typed name of an input map action.
+///
+/// Meant to be declared as a constant, so that action names are not repeated as strings throughout the code. Provides shortcuts for
+/// the most common [`Input`] queries, and converts into `StringName` for everything else.
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::Input;
+/// use godot::tools::InputAction;
+///
+/// const JUMP: InputAction = InputAction::new("jump");
+///
+/// if JUMP.is_just_pressed() {
+///     // ...
+/// }
+///
+/// // Equivalent:
+/// if Input::singleton().is_action_just_pressed(JUMP.into()) {
+///     // ...
+/// }
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct InputAction {
+    name: &'static str,
+}
+
+impl InputAction {
+    /// Refers to the action `name`, which does not need to exist yet.
+    pub const fn new(name: &'static str) -> Self {
+        Self { name }
+    }
+
+    /// Name of the action.
+    pub const fn name(self) -> &'static str {
+        self.name
+    }
+
+    /// Starts (re)defining this action, see [`InputActions::define()`].
+    pub fn define(self) -> ActionBuilder {
+        InputActions::define(self)
+    }
+
+    /// Whether the action exists in the input map.
+    pub fn exists(self) -> bool {
+        InputMap::singleton().has_action(self.into())
+    }
+
+    /// Whether any event bound to the action is held down.
+    pub fn is_pressed(self) -> bool {
+        Input::singleton().is_action_pressed(self.into())
+    }
+
+    /// Whether the action started being pressed in the current frame.
+    pub fn is_just_pressed(self) -> bool {
+        Input::singleton().is_action_just_pressed(self.into())
+    }
+
+    /// Whether the action stopped being pressed in the current frame.
+    pub fn is_just_released(self) -> bool {
+        Input::singleton().is_action_just_released(self.into())
+    }
+
+    /// How strongly the action is pressed, between `0.0` and `1.0`. Digital inputs are either of the two.
+    pub fn strength(self) -> f32 {
+        Input::singleton().get_action_strength(self.into())
+    }
+}
+
+impl From<InputAction> for StringName {
+    fn from(action: InputAction) -> Self {
+        StringName::from(action.name)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Declares a set of input actions together with their bindings, as constants of a struct.
+///
+/// Each entry `NAME = "action" { bindings... }` generates an associated constant `NAME` of type [`InputAction`]. The bindings are calls
+/// of [`ActionBuilder`] methods, separated by commas. The generated `register()` function defines all actions; call it during
+/// initialization (and again after hot reload, which is harmless).
+///
+/// # Example
+/// ```no_run
+/// use godot::global::{JoyAxis, JoyButton, Key, MouseButton};
+/// use godot::tools::input_actions;
+///
+/// input_actions! {
+///     pub struct Actions {
+///         JUMP = "jump" {
+///             key(Key::SPACE),
+///             gamepad_button(JoyButton::A),
+///         }
+///         FIRE = "fire" {
+///             mouse_button(MouseButton::LEFT),
+///             gamepad_axis(JoyAxis::TRIGGER_RIGHT, 1.0),
+///             deadzone(0.3),
+///         }
+///     }
+/// }
+///
+/// // In ExtensionLibrary::on_level_init(InitLevel::Scene):
+/// Actions::register();
+///
+/// // In gameplay code:
+/// if Actions::JUMP.is_just_pressed() {
+///     // ...
+/// }
+/// ```
+#[macro_export]
+macro_rules! input_actions {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $Name:ident {
+            $(
+                $(#[$action_attr:meta])*
+                $ACTION:ident = $action_name:literal {
+                    $( $binding:ident ( $($arg:expr),* $(,)? ) ),* $(,)?
+                }
+            )*
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $Name;
+
+        impl $Name {
+            $(
+                $(#[$action_attr])*
+                #[doc = concat!("Input action `", $action_name, "`.")]
+                pub const $ACTION: $crate::tools::InputAction = $crate::tools::InputAction::new($action_name);
+            )*
+
+            /// Adds all actions to the input map, replacing previous bindings of actions with the same name.
+            pub fn register() {
+                $(
+                    $crate::tools::InputActions::define(Self::$ACTION)
+                        $( .$binding($($arg),*) )*
+                        .register();
+                )*
+            }
+
+            /// All declared actions, in declaration order.
+            pub fn all() -> &'static [$crate::tools::InputAction] {
+                &[ $( Self::$ACTION ),* ]
+            }
+        }
+    };
+}
+
+pub use crate::input_actions;
@@ -0,0 +1,424 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Backends for the [`log`](https://docs.rs/log) and [`tracing`](https://docs.rs/tracing) crates, printing to Godot's output.
+//!
+//! Rust libraries commonly log through one of these facades. By default, their records go nowhere (or to stdout, which is not visible
+//! in the editor). With the adapters in this module, records are routed to Godot instead:
+//!
+//! | Level             | Godot equivalent                               |
+//! |-------------------|------------------------------------------------|
+//! | `Error`           | [`godot_error!`][crate::global::godot_error]   |
+//! | `Warn`            | [`godot_warn!`][crate::global::godot_warn]     |
+//! | `Info` and below  | [`godot_print!`][crate::global::godot_print]   |
+//!
+//! Errors and warnings show up in the debugger with the source file and line of the log statement.
+//!
+//! Requires the `log` or `tracing` Cargo feature, respectively.
+//!
+//! # Example
+//! ```no_run
+//! # #[cfg(feature = "log")] {
+//! use godot::tools::logging::{GodotLogger, LevelFilter};
+//!
+//! GodotLogger::new()
+//!     .max_level(LevelFilter::Debug)
+//!     .target("wgpu", LevelFilter::Warn) // Less noise from a dependency.
+//!     .init()
+//!     .expect("no other logger installed");
+//!
+//! log::info!("logged to Godot");
+//! # }
+//! ```
+
+use std::ffi::CString;
+
+use crate::builtin::Variant;
+use crate::sys;
+
+/// Severity of a message, independent of the logging facade.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+enum Severity {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Severity {
+    fn label(self) -> &'static str {
+        match self {
+            Severity::Error => "ERROR",
+            Severity::Warn => "WARN",
+            Severity::Info => "INFO",
+            Severity::Debug => "DEBUG",
+            Severity::Trace => "TRACE",
+        }
+    }
+}
+
+/// Per-target maximum severities, matched by longest module path prefix.
+#[derive(Clone, Debug)]
+struct TargetFilter {
+    default: Option<Severity>,
+    targets: Vec<(String, Option<Severity>)>,
+}
+
+impl TargetFilter {
+    fn new(default: Option<Severity>) -> Self {
+        Self {
+            default,
+            targets: Vec::new(),
+        }
+    }
+
+    fn set_target(&mut self, prefix: &str, max: Option<Severity>) {
+        self.targets.retain(|(existing, _)| existing != prefix);
+        self.targets.push((prefix.to_string(), max));
+
+        // Longest prefix first, so that more specific targets win.
+        self.targets.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()));
+    }
+
+    fn max_for(&self, target: &str) -> Option<Severity> {
+        self.targets
+            .iter()
+            .find(|(prefix, _)| matches_target(target, prefix))
+            .map_or(self.default, |(_, max)| *max)
+    }
+
+    fn enabled(&self, target: &str, severity: Severity) -> bool {
+        self.max_for(target).is_some_and(|max| severity <= max)
+    }
+
+    /// Most verbose severity enabled for any target.
+    fn most_verbose(&self) -> Option<Severity> {
+        self.targets
+            .iter()
+            .map(|(_, max)| *max)
+            .chain(std::iter::once(self.default))
+            .max()
+            .flatten()
+    }
+}
+
+/// `target` is `prefix` or a submodule of it, e.g. `my_crate::net` matches `my_crate`, but `my_crate_ext` does not.
+fn matches_target(target: &str, prefix: &str) -> bool {
+    target
+        .strip_prefix(prefix)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+}
+
+fn emit(severity: Severity, target: &str, message: &str, file: Option<&str>, line: Option<u32>) {
+    if !sys::is_initialized() {
+        eprintln!("[{} {target}] {message}", severity.label());
+        return;
+    }
+
+    match severity {
+        Severity::Error | Severity::Warn => {
+            let to_c = |s: &str| CString::new(s.replace('\0', "")).unwrap_or_default();
+            let message = to_c(message);
+            let function = to_c(target);
+            let file = to_c(file.unwrap_or(""));
+            let line = line.map_or(0, |line| line as i32);
+
+            // SAFETY: all pointers are valid null-terminated strings for the duration of the call.
+            unsafe {
+                let print_fn = if severity == Severity::Error {
+                    sys::interface_fn!(print_error)
+                } else {
+                    sys::interface_fn!(print_warning)
+                };
+
+                print_fn(
+                    message.as_ptr(),
+                    function.as_ptr(),
+                    file.as_ptr(),
+                    line,
+                    false as sys::GDExtensionBool,
+                );
+            }
+        }
+        _ => {
+            let line = format!("[{} {target}] {message}", severity.label());
+            crate::global::print(&[Variant::from(line)]);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// log
+
+#[cfg(feature = "log")]
+pub use log_impl::*;
+
+#[cfg(feature = "log")]
+mod log_impl {
+    use super::*;
+
+    pub use ::log::LevelFilter;
+
+    /// Implementation of [`log::Log`](::log::Log) that prints to Godot.
+    ///
+    /// See [module docs](super) for the level mapping and an example.
+    #[derive(Clone, Debug)]
+    pub struct GodotLogger {
+        filter: TargetFilter,
+    }
+
+    impl GodotLogger {
+        /// Creates a logger with maximum level `Info` for all targets.
+        pub fn new() -> Self {
+            Self {
+                filter: TargetFilter::new(Some(Severity::Info)),
+            }
+        }
+
+        /// Sets the maximum level for targets without a more specific [`target()`][Self::target] setting.
+        pub fn max_level(mut self, level: LevelFilter) -> Self {
+            self.filter.default = to_severity(level);
+            self
+        }
+
+        /// Sets the maximum level for records whose target (usually the module path) is `prefix` or a submodule of it.
+        pub fn target(mut self, prefix: &str, level: LevelFilter) -> Self {
+            self.filter.set_target(prefix, to_severity(level));
+            self
+        }
+
+        /// Installs this logger as the global `log` backend.
+        ///
+        /// Fails if another logger has already been installed. Call this once, e.g. in
+        /// [`ExtensionLibrary::on_level_init()`][crate::init::ExtensionLibrary::on_level_init].
+        pub fn init(self) -> Result<(), ::log::SetLoggerError> {
+            let max = self.filter.most_verbose();
+            ::log::set_boxed_logger(Box::new(self))?;
+            ::log::set_max_level(from_severity(max));
+            Ok(())
+        }
+    }
+
+    impl Default for GodotLogger {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl ::log::Log for GodotLogger {
+        fn enabled(&self, metadata: &::log::Metadata) -> bool {
+            self.filter
+                .enabled(metadata.target(), level_severity(metadata.level()))
+        }
+
+        fn log(&self, record: &::log::Record) {
+            if !self.enabled(record.metadata()) {
+                return;
+            }
+
+            emit(
+                level_severity(record.level()),
+                record.target(),
+                &record.args().to_string(),
+                record.file(),
+                record.line(),
+            );
+        }
+
+        fn flush(&self) {}
+    }
+
+    fn level_severity(level: ::log::Level) -> Severity {
+        match level {
+            ::log::Level::Error => Severity::Error,
+            ::log::Level::Warn => Severity::Warn,
+            ::log::Level::Info => Severity::Info,
+            ::log::Level::Debug => Severity::Debug,
+            ::log::Level::Trace => Severity::Trace,
+        }
+    }
+
+    fn to_severity(level: LevelFilter) -> Option<Severity> {
+        level.to_level().map(level_severity)
+    }
+
+    fn from_severity(severity: Option<Severity>) -> LevelFilter {
+        match severity {
+            None => LevelFilter::Off,
+            Some(Severity::Error) => LevelFilter::Error,
+            Some(Severity::Warn) => LevelFilter::Warn,
+            Some(Severity::Info) => LevelFilter::Info,
+            Some(Severity::Debug) => LevelFilter::Debug,
+            Some(Severity::Trace) => LevelFilter::Trace,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// tracing
+
+#[cfg(feature = "tracing")]
+pub use tracing_impl::*;
+
+#[cfg(feature = "tracing")]
+mod tracing_impl {
+    use std::fmt::Write as _;
+
+    use super::*;
+
+    /// [`tracing_subscriber::Layer`] that prints events to Godot.
+    ///
+    /// Span information is not printed; only events are. Fields other than `message` are appended as `key=value` pairs.
+    ///
+    /// ```no_run
+    /// use godot::tools::logging::GodotTracingLayer;
+    /// use tracing_subscriber::prelude::*;
+    ///
+    /// tracing_subscriber::registry()
+    ///     .with(GodotTracingLayer::new().target("naga", tracing::Level::WARN))
+    ///     .init();
+    /// ```
+    #[derive(Clone, Debug)]
+    pub struct GodotTracingLayer {
+        filter: TargetFilter,
+    }
+
+    impl GodotTracingLayer {
+        /// Creates a layer with maximum level `INFO` for all targets.
+        pub fn new() -> Self {
+            Self {
+                filter: TargetFilter::new(Some(Severity::Info)),
+            }
+        }
+
+        /// Sets the maximum level for targets without a more specific [`target()`][Self::target] setting.
+        pub fn max_level(mut self, level: ::tracing::Level) -> Self {
+            self.filter.default = Some(level_severity(&level));
+            self
+        }
+
+        /// Sets the maximum level for events whose target (usually the module path) is `prefix` or a submodule of it.
+        pub fn target(mut self, prefix: &str, level: ::tracing::Level) -> Self {
+            self.filter.set_target(prefix, Some(level_severity(&level)));
+            self
+        }
+    }
+
+    impl Default for GodotTracingLayer {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<S: ::tracing::Subscriber> ::tracing_subscriber::Layer<S> for GodotTracingLayer {
+        fn enabled(
+            &self,
+            metadata: &::tracing::Metadata<'_>,
+            _ctx: ::tracing_subscriber::layer::Context<'_, S>,
+        ) -> bool {
+            self.filter
+                .enabled(metadata.target(), level_severity(metadata.level()))
+        }
+
+        fn on_event(
+            &self,
+            event: &::tracing::Event<'_>,
+            _ctx: ::tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let metadata = event.metadata();
+            let severity = level_severity(metadata.level());
+            if !self.filter.enabled(metadata.target(), severity) {
+                return;
+            }
+
+            let mut visitor = MessageVisitor::default();
+            event.record(&mut visitor);
+
+            emit(
+                severity,
+                metadata.target(),
+                &visitor.finish(),
+                metadata.file(),
+                metadata.line(),
+            );
+        }
+    }
+
+    fn level_severity(level: &::tracing::Level) -> Severity {
+        match *level {
+            ::tracing::Level::ERROR => Severity::Error,
+            ::tracing::Level::WARN => Severity::Warn,
+            ::tracing::Level::INFO => Severity::Info,
+            ::tracing::Level::DEBUG => Severity::Debug,
+            _ => Severity::Trace,
+        }
+    }
+
+    #[derive(Default)]
+    struct MessageVisitor {
+        message: String,
+        fields: String,
+    }
+
+    impl MessageVisitor {
+        fn finish(self) -> String {
+            if self.fields.is_empty() {
+                self.message
+            } else if self.message.is_empty() {
+                self.fields
+            } else {
+                format!("{} {}", self.message, self.fields)
+            }
+        }
+    }
+
+    impl ::tracing::field::Visit for MessageVisitor {
+        fn record_debug(&mut self, field: &::tracing::field::Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "message" {
+                let _ = write!(self.message, "{value:?}");
+            } else {
+                if !self.fields.is_empty() {
+                    self.fields.push(' ');
+                }
+                let _ = write!(self.fields, "{}={value:?}", field.name());
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn target_prefix_matching() {
+        assert!(matches_target("my_crate", "my_crate"));
+        assert!(matches_target("my_crate::net::tcp", "my_crate::net"));
+        assert!(!matches_target("my_crate_ext", "my_crate"));
+        assert!(!matches_target("other", "my_crate"));
+    }
+
+    #[test]
+    fn target_filter_most_specific_wins() {
+        let mut filter = TargetFilter::new(Some(Severity::Info));
+        filter.set_target("wgpu", Some(Severity::Warn));
+        filter.set_target("wgpu::core", Some(Severity::Trace));
+        filter.set_target("noisy", None);
+
+        assert!(filter.enabled("game", Severity::Info));
+        assert!(!filter.enabled("game", Severity::Debug));
+        assert!(!filter.enabled("wgpu::hal", Severity::Info));
+        assert!(filter.enabled("wgpu::hal", Severity::Warn));
+        assert!(filter.enabled("wgpu::core::device", Severity::Trace));
+        assert!(!filter.enabled("noisy", Severity::Error));
+
+        assert_eq!(filter.most_verbose(), Some(Severity::Trace));
+    }
+}
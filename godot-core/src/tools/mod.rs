@@ -10,10 +10,63 @@
 //! Contains functionality that extends existing Godot classes and functions, to make them more versatile
 //! or better integrated with Rust.
 
+// Background loading constructs Rust classes on Godot's loader thread, which the single-threaded object model does not allow.
+#[cfg(feature = "experimental-threads")]
+mod async_load;
+mod body_state;
+#[cfg(since_api = "4.2")]
+mod frame_callbacks;
 mod gfile;
+mod image_data;
+#[cfg(feature = "image")]
+mod image_interop;
+mod input_actions;
+mod optional_method;
+mod physics_query;
 mod save_load;
+mod settings;
+mod startup;
+mod subscribers;
+#[cfg(since_api = "4.2")]
+mod timers;
 mod translate;
+mod typed_scene;
 
+pub mod audio;
+pub mod compute;
+#[cfg(feature = "config")]
+pub mod config;
+pub mod curves;
+#[cfg(since_api = "4.2")]
+pub mod debug_draw;
+pub mod editor;
+pub mod import;
+#[cfg(any(feature = "log", feature = "tracing"))]
+pub mod logging;
+pub mod navigation;
+pub mod persist;
+pub mod pool;
+pub mod scene;
+pub mod shader;
+pub mod snapshot;
+pub mod table;
+#[cfg(since_api = "4.2")]
+pub mod tween;
+
+#[cfg(feature = "experimental-threads")]
+pub use async_load::*;
+pub use body_state::*;
+#[cfg(since_api = "4.2")]
+pub use frame_callbacks::*;
 pub use gfile::*;
+pub use image_data::*;
+pub use input_actions::*;
+pub use optional_method::*;
+pub use physics_query::*;
 pub use save_load::*;
+pub use settings::*;
+pub use startup::*;
+#[cfg(since_api = "4.2")]
+pub use timers::*;
 pub use translate::*;
+pub use typed_scene::*;
@@ -0,0 +1,675 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Typed wrappers around the [`NavigationServer2D`] and [`NavigationServer3D`] APIs.
+//!
+//! The navigation servers identify maps, regions and agents by [`Rid`]. Resources created through the server must be freed manually,
+//! and query results are returned as packed arrays. This module wraps each kind of RID in its own type:
+//! - [`NavMap2D`]/[`NavMap3D`] for navigation maps, including path queries.
+//! - [`NavRegion2D`]/[`NavRegion3D`] for regions, which contribute a navigation polygon/mesh to a map.
+//! - [`NavAgent2D`]/[`NavAgent3D`] for avoidance agents.
+//!
+//! Objects created with `new()` own their RID and free it when dropped. A map obtained from a world
+//! ([`NavMap3D::of_world()`]) is owned by Godot and not freed.
+//!
+//! # Example
+//! ```no_run
+//! use godot::prelude::*;
+//! use godot::classes::NavigationMesh;
+//! use godot::tools::navigation::{NavMap3D, NavRegion3D};
+//!
+//! let map = NavMap3D::new();
+//! map.set_active(true);
+//!
+//! let region = NavRegion3D::new();
+//! region.set_map(&map);
+//! region.set_navigation_mesh(NavigationMesh::new_gd());
+//!
+//! // Once the map has synchronized (next physics frame):
+//! let path: Vec<Vector3> = map.path(Vector3::ZERO, Vector3::new(10.0, 0.0, 5.0));
+//! ```
+//!
+//! # Map changes
+//! Maps are synchronized once per physics frame, after which the server emits `map_changed`. Instead of polling the map, register a
+//! callback with [`NavMap3D::on_changed()`], or await [`NavMap3D::changed()`] in an async context (Godot 4.2+).
+
+use std::fmt;
+#[cfg(since_api = "4.2")]
+use std::future::Future;
+#[cfg(since_api = "4.2")]
+use std::pin::Pin;
+#[cfg(since_api = "4.2")]
+use std::task::{Context, Poll};
+
+use crate::builtin::{Rid, Transform2D, Transform3D, Vector2, Vector3};
+use crate::classes::{
+    NavigationMesh, NavigationPolygon, NavigationServer2D, NavigationServer3D, World2D, World3D,
+};
+use crate::obj::Gd;
+
+/// Implements RID ownership and common accessors.
+macro_rules! impl_nav_rid {
+    ($Type:ident, $Server:ident) => {
+        impl $Type {
+            /// The underlying RID, for use with server methods not covered by this wrapper.
+            pub fn rid(&self) -> Rid {
+                self.rid
+            }
+
+            /// Whether the RID is freed when this object is dropped.
+            pub fn is_owned(&self) -> bool {
+                self.owned
+            }
+
+            /// Releases ownership and returns the RID, which must then be freed manually with `free_rid()`.
+            pub fn into_rid(mut self) -> Rid {
+                self.owned = false;
+                self.rid
+            }
+
+            fn server() -> Gd<$Server> {
+                $Server::singleton()
+            }
+        }
+
+        impl Drop for $Type {
+            fn drop(&mut self) {
+                if self.owned {
+                    $Server::singleton().free_rid(self.rid);
+                }
+            }
+        }
+
+        impl fmt::Debug for $Type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($Type))
+                    .field("rid", &self.rid)
+                    .field("owned", &self.owned)
+                    .finish()
+            }
+        }
+
+        impl PartialEq for $Type {
+            fn eq(&self, other: &Self) -> bool {
+                self.rid == other.rid
+            }
+        }
+
+        impl Eq for $Type {}
+    };
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Maps
+
+/// Navigation map in 3D, see [module docs](self).
+pub struct NavMap3D {
+    rid: Rid,
+    owned: bool,
+}
+
+impl NavMap3D {
+    /// Creates a new, inactive map that is freed when dropped.
+    #[allow(clippy::new_without_default)] // Creating RIDs is not a cheap default.
+    pub fn new() -> Self {
+        Self {
+            rid: Self::server().map_create(),
+            owned: true,
+        }
+    }
+
+    /// The default map of a world, used by `NavigationRegion3D` and `NavigationAgent3D` nodes. Not freed when dropped.
+    pub fn of_world(world: &Gd<World3D>) -> Self {
+        Self::from_rid_unowned(world.get_navigation_map())
+    }
+
+    /// Wraps an existing map without taking ownership.
+    pub fn from_rid_unowned(rid: Rid) -> Self {
+        Self { rid, owned: false }
+    }
+
+    /// Wraps an existing map and frees it when dropped.
+    pub fn from_rid_owned(rid: Rid) -> Self {
+        Self { rid, owned: true }
+    }
+
+    /// Enables or disables the map. Only active maps are synchronized and answer queries.
+    pub fn set_active(&self, active: bool) {
+        Self::server().map_set_active(self.rid, active);
+    }
+
+    pub fn is_active(&self) -> bool {
+        Self::server().map_is_active(self.rid)
+    }
+
+    /// Sets the cell size, which must match the cell size of the navigation meshes used in this map.
+    pub fn set_cell_size(&self, cell_size: f32) {
+        Self::server().map_set_cell_size(self.rid, cell_size);
+    }
+
+    /// Shortest path from `from` to `to`, using all navigation layers. Returns an empty vector if there is no path.
+    pub fn path(&self, from: Vector3, to: Vector3) -> Vec<Vector3> {
+        self.path_ex(from, to, true, u32::MAX)
+    }
+
+    /// Like [`path()`][Self::path], but restricted to `navigation_layers`, and optionally without path optimization (string pulling).
+    pub fn path_ex(
+        &self,
+        from: Vector3,
+        to: Vector3,
+        optimize: bool,
+        navigation_layers: u32,
+    ) -> Vec<Vector3> {
+        Self::server()
+            .map_get_path_ex(self.rid, from, to, optimize)
+            .navigation_layers(navigation_layers)
+            .done()
+            .to_vec()
+    }
+
+    /// Point on the navigation mesh closest to `point`.
+    pub fn closest_point(&self, point: Vector3) -> Vector3 {
+        Self::server().map_get_closest_point(self.rid, point)
+    }
+
+    /// Surface normal of the navigation mesh at the point closest to `point`.
+    pub fn closest_point_normal(&self, point: Vector3) -> Vector3 {
+        Self::server().map_get_closest_point_normal(self.rid, point)
+    }
+
+    /// All regions currently assigned to this map, as RIDs (regions don't know their Rust owner).
+    pub fn region_rids(&self) -> Vec<Rid> {
+        Self::server()
+            .map_get_regions(self.rid)
+            .iter_shared()
+            .collect()
+    }
+
+    /// All agents currently assigned to this map, as RIDs.
+    pub fn agent_rids(&self) -> Vec<Rid> {
+        Self::server()
+            .map_get_agents(self.rid)
+            .iter_shared()
+            .collect()
+    }
+}
+
+impl_nav_rid!(NavMap3D, NavigationServer3D);
+
+/// Navigation map in 2D, see [module docs](self).
+pub struct NavMap2D {
+    rid: Rid,
+    owned: bool,
+}
+
+impl NavMap2D {
+    /// Creates a new, inactive map that is freed when dropped.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            rid: Self::server().map_create(),
+            owned: true,
+        }
+    }
+
+    /// The default map of a world, used by `NavigationRegion2D` and `NavigationAgent2D` nodes. Not freed when dropped.
+    pub fn of_world(world: &Gd<World2D>) -> Self {
+        Self::from_rid_unowned(world.get_navigation_map())
+    }
+
+    /// Wraps an existing map without taking ownership.
+    pub fn from_rid_unowned(rid: Rid) -> Self {
+        Self { rid, owned: false }
+    }
+
+    /// Wraps an existing map and frees it when dropped.
+    pub fn from_rid_owned(rid: Rid) -> Self {
+        Self { rid, owned: true }
+    }
+
+    /// Enables or disables the map. Only active maps are synchronized and answer queries.
+    pub fn set_active(&self, active: bool) {
+        Self::server().map_set_active(self.rid, active);
+    }
+
+    pub fn is_active(&self) -> bool {
+        Self::server().map_is_active(self.rid)
+    }
+
+    /// Sets the cell size, which must match the cell size of the navigation polygons used in this map.
+    pub fn set_cell_size(&self, cell_size: f32) {
+        Self::server().map_set_cell_size(self.rid, cell_size);
+    }
+
+    /// Shortest path from `from` to `to`, using all navigation layers. Returns an empty vector if there is no path.
+    pub fn path(&self, from: Vector2, to: Vector2) -> Vec<Vector2> {
+        self.path_ex(from, to, true, u32::MAX)
+    }
+
+    /// Like [`path()`][Self::path], but restricted to `navigation_layers`, and optionally without path optimization (string pulling).
+    pub fn path_ex(
+        &self,
+        from: Vector2,
+        to: Vector2,
+        optimize: bool,
+        navigation_layers: u32,
+    ) -> Vec<Vector2> {
+        Self::server()
+            .map_get_path_ex(self.rid, from, to, optimize)
+            .navigation_layers(navigation_layers)
+            .done()
+            .to_vec()
+    }
+
+    /// Point on the navigation polygons closest to `point`.
+    pub fn closest_point(&self, point: Vector2) -> Vector2 {
+        Self::server().map_get_closest_point(self.rid, point)
+    }
+
+    /// All regions currently assigned to this map, as RIDs.
+    pub fn region_rids(&self) -> Vec<Rid> {
+        Self::server()
+            .map_get_regions(self.rid)
+            .iter_shared()
+            .collect()
+    }
+
+    /// All agents currently assigned to this map, as RIDs.
+    pub fn agent_rids(&self) -> Vec<Rid> {
+        Self::server()
+            .map_get_agents(self.rid)
+            .iter_shared()
+            .collect()
+    }
+}
+
+impl_nav_rid!(NavMap2D, NavigationServer2D);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Regions
+
+/// Navigation region in 3D, contributing a [`NavigationMesh`] to a map.
+pub struct NavRegion3D {
+    rid: Rid,
+    owned: bool,
+}
+
+impl NavRegion3D {
+    /// Creates a new region that is not part of any map. Freed when dropped.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            rid: Self::server().region_create(),
+            owned: true,
+        }
+    }
+
+    /// Adds the region to `map`, removing it from its previous map.
+    pub fn set_map(&self, map: &NavMap3D) {
+        Self::server().region_set_map(self.rid, map.rid);
+    }
+
+    /// Removes the region from its map.
+    pub fn clear_map(&self) {
+        Self::server().region_set_map(self.rid, Rid::Invalid);
+    }
+
+    pub fn set_navigation_mesh(&self, mesh: Gd<NavigationMesh>) {
+        Self::server().region_set_navigation_mesh(self.rid, mesh);
+    }
+
+    pub fn set_transform(&self, transform: Transform3D) {
+        Self::server().region_set_transform(self.rid, transform);
+    }
+
+    /// Bitmask of navigation layers this region belongs to; queries can be restricted to layers.
+    pub fn set_navigation_layers(&self, layers: u32) {
+        Self::server().region_set_navigation_layers(self.rid, layers);
+    }
+}
+
+impl_nav_rid!(NavRegion3D, NavigationServer3D);
+
+/// Navigation region in 2D, contributing a [`NavigationPolygon`] to a map.
+pub struct NavRegion2D {
+    rid: Rid,
+    owned: bool,
+}
+
+impl NavRegion2D {
+    /// Creates a new region that is not part of any map. Freed when dropped.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            rid: Self::server().region_create(),
+            owned: true,
+        }
+    }
+
+    /// Adds the region to `map`, removing it from its previous map.
+    pub fn set_map(&self, map: &NavMap2D) {
+        Self::server().region_set_map(self.rid, map.rid);
+    }
+
+    /// Removes the region from its map.
+    pub fn clear_map(&self) {
+        Self::server().region_set_map(self.rid, Rid::Invalid);
+    }
+
+    pub fn set_navigation_polygon(&self, polygon: Gd<NavigationPolygon>) {
+        Self::server().region_set_navigation_polygon(self.rid, polygon);
+    }
+
+    pub fn set_transform(&self, transform: Transform2D) {
+        Self::server().region_set_transform(self.rid, transform);
+    }
+
+    /// Bitmask of navigation layers this region belongs to; queries can be restricted to layers.
+    pub fn set_navigation_layers(&self, layers: u32) {
+        Self::server().region_set_navigation_layers(self.rid, layers);
+    }
+}
+
+impl_nav_rid!(NavRegion2D, NavigationServer2D);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Agents
+
+/// Avoidance agent in 3D.
+pub struct NavAgent3D {
+    rid: Rid,
+    owned: bool,
+}
+
+impl NavAgent3D {
+    /// Creates a new agent that is not part of any map. Freed when dropped.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            rid: Self::server().agent_create(),
+            owned: true,
+        }
+    }
+
+    /// Adds the agent to `map`, removing it from its previous map.
+    pub fn set_map(&self, map: &NavMap3D) {
+        Self::server().agent_set_map(self.rid, map.rid);
+    }
+
+    /// Removes the agent from its map.
+    pub fn clear_map(&self) {
+        Self::server().agent_set_map(self.rid, Rid::Invalid);
+    }
+
+    pub fn set_position(&self, position: Vector3) {
+        Self::server().agent_set_position(self.rid, position);
+    }
+
+    pub fn set_velocity(&self, velocity: Vector3) {
+        Self::server().agent_set_velocity(self.rid, velocity);
+    }
+
+    pub fn set_radius(&self, radius: f32) {
+        Self::server().agent_set_radius(self.rid, radius);
+    }
+
+    pub fn set_max_speed(&self, max_speed: f32) {
+        Self::server().agent_set_max_speed(self.rid, max_speed);
+    }
+}
+
+impl_nav_rid!(NavAgent3D, NavigationServer3D);
+
+/// Avoidance agent in 2D.
+pub struct NavAgent2D {
+    rid: Rid,
+    owned: bool,
+}
+
+impl NavAgent2D {
+    /// Creates a new agent that is not part of any map. Freed when dropped.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            rid: Self::server().agent_create(),
+            owned: true,
+        }
+    }
+
+    /// Adds the agent to `map`, removing it from its previous map.
+    pub fn set_map(&self, map: &NavMap2D) {
+        Self::server().agent_set_map(self.rid, map.rid);
+    }
+
+    /// Removes the agent from its map.
+    pub fn clear_map(&self) {
+        Self::server().agent_set_map(self.rid, Rid::Invalid);
+    }
+
+    pub fn set_position(&self, position: Vector2) {
+        Self::server().agent_set_position(self.rid, position);
+    }
+
+    pub fn set_velocity(&self, velocity: Vector2) {
+        Self::server().agent_set_velocity(self.rid, velocity);
+    }
+
+    pub fn set_radius(&self, radius: f32) {
+        Self::server().agent_set_radius(self.rid, radius);
+    }
+
+    pub fn set_max_speed(&self, max_speed: f32) {
+        Self::server().agent_set_max_speed(self.rid, max_speed);
+    }
+}
+
+impl_nav_rid!(NavAgent2D, NavigationServer2D);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Change notifications
+
+/// Implements `on_changed()` and `changed()` for maps.
+macro_rules! impl_map_changed {
+    ($Type:ident, $dimension:expr) => {
+        #[cfg(since_api = "4.2")]
+        impl $Type {
+            /// Invokes `callback` each time the server reports that this map has changed, i.e. after it has been synchronized.
+            ///
+            /// The returned handle can be used to unsubscribe; dropping it does not.
+            ///
+            /// # Panics
+            /// If not called on the main thread.
+            pub fn on_changed(&self, callback: impl FnMut() + 'static) -> MapChangedHandle {
+                map_changed::subscribe($dimension, self.rid, Box::new(callback))
+            }
+
+            /// Future that completes the next time the server reports that this map has changed.
+            pub fn changed(&self) -> MapChanged {
+                MapChanged::new($dimension, self.rid)
+            }
+        }
+    };
+}
+
+impl_map_changed!(NavMap3D, map_changed::Dimension::Three);
+impl_map_changed!(NavMap2D, map_changed::Dimension::Two);
+
+/// Handle returned by `on_changed()` of [`NavMap2D`] and [`NavMap3D`], to stop receiving notifications.
+#[cfg(since_api = "4.2")]
+#[derive(Eq, PartialEq, Hash, Debug)]
+pub struct MapChangedHandle {
+    dimension: map_changed::Dimension,
+    id: u64,
+}
+
+#[cfg(since_api = "4.2")]
+impl MapChangedHandle {
+    /// Stops invoking the callback. Returns `false` if it has already been unsubscribed.
+    pub fn unsubscribe(self) -> bool {
+        map_changed::unsubscribe(self.dimension, self.id)
+    }
+}
+
+/// Future returned by `changed()` of [`NavMap2D`] and [`NavMap3D`].
+///
+/// Completes once the map has changed _after_ the future was created. Must be polled on the main thread.
+#[cfg(since_api = "4.2")]
+pub struct MapChanged {
+    state: std::rc::Rc<std::cell::RefCell<ChangedState>>,
+    handle: Option<MapChangedHandle>,
+}
+
+#[cfg(since_api = "4.2")]
+#[derive(Default)]
+struct ChangedState {
+    fired: bool,
+    waker: Option<std::task::Waker>,
+}
+
+#[cfg(since_api = "4.2")]
+impl MapChanged {
+    fn new(dimension: map_changed::Dimension, rid: Rid) -> Self {
+        let state = std::rc::Rc::new(std::cell::RefCell::new(ChangedState::default()));
+        let callback_state = state.clone();
+
+        let handle = map_changed::subscribe(
+            dimension,
+            rid,
+            Box::new(move || {
+                let mut state = callback_state.borrow_mut();
+                state.fired = true;
+                if let Some(waker) = state.waker.take() {
+                    waker.wake();
+                }
+            }),
+        );
+
+        Self {
+            state,
+            handle: Some(handle),
+        }
+    }
+
+    /// Whether the map has changed since this future was created.
+    pub fn is_changed(&self) -> bool {
+        self.state.borrow().fired
+    }
+}
+
+#[cfg(since_api = "4.2")]
+impl Future for MapChanged {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let fired = {
+            let mut state = self.state.borrow_mut();
+            if !state.fired {
+                state.waker = Some(cx.waker().clone());
+            }
+            state.fired
+        };
+
+        if fired {
+            if let Some(handle) = self.handle.take() {
+                handle.unsubscribe();
+            }
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(since_api = "4.2")]
+impl Drop for MapChanged {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.unsubscribe();
+        }
+    }
+}
+
+#[cfg(since_api = "4.2")]
+impl fmt::Debug for MapChanged {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapChanged")
+            .field("changed", &self.is_changed())
+            .finish()
+    }
+}
+
+#[cfg(since_api = "4.2")]
+mod map_changed {
+    use std::cell::RefCell;
+
+    use super::MapChangedHandle;
+    use crate::builtin::{Callable, Rid, Variant};
+    use crate::classes::{NavigationServer2D, NavigationServer3D};
+    use crate::tools::subscribers::{self, Registry, Subscribers};
+
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub(super) enum Dimension {
+        Two,
+        Three,
+    }
+
+    type Callback = Box<dyn FnMut()>;
+
+    thread_local! {
+        static SUBSCRIBERS_2D: RefCell<Subscribers<(Rid, Callback)>> = RefCell::default();
+        static SUBSCRIBERS_3D: RefCell<Subscribers<(Rid, Callback)>> = RefCell::default();
+    }
+
+    fn registry(dimension: Dimension) -> &'static Registry<(Rid, Callback)> {
+        match dimension {
+            Dimension::Two => &SUBSCRIBERS_2D,
+            Dimension::Three => &SUBSCRIBERS_3D,
+        }
+    }
+
+    pub(super) fn subscribe(
+        dimension: Dimension,
+        map: Rid,
+        callback: Callback,
+    ) -> MapChangedHandle {
+        let (id, needs_connect) =
+            subscribers::subscribe(registry(dimension), "map change callbacks", (map, callback));
+
+        if needs_connect {
+            connect(dimension);
+        }
+
+        MapChangedHandle { dimension, id }
+    }
+
+    pub(super) fn unsubscribe(dimension: Dimension, id: u64) -> bool {
+        subscribers::unsubscribe(registry(dimension), id)
+    }
+
+    fn connect(dimension: Dimension) {
+        // Only captures the dimension, so the callable is Send + Sync; callbacks themselves stay in the thread-local.
+        let callable = Callable::from_fn("map_changed", move |args: &[&Variant]| {
+            if let Some(map) = args.first().and_then(|arg| arg.try_to::<Rid>().ok()) {
+                subscribers::dispatch(registry(dimension), |(entry_map, callback)| {
+                    if *entry_map == map {
+                        callback();
+                    }
+                });
+            }
+            Ok(Variant::nil())
+        });
+
+        match dimension {
+            Dimension::Two => {
+                NavigationServer2D::singleton().connect("map_changed".into(), callable)
+            }
+            Dimension::Three => {
+                NavigationServer3D::singleton().connect("map_changed".into(), callable)
+            }
+        };
+    }
+}
@@ -0,0 +1,231 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+use std::sync::OnceLock;
+
+use godot_ffi as sys;
+
+use crate::builtin::{StringName, Variant};
+use crate::classes::Object;
+use crate::meta::error::CallError;
+use crate::meta::CallContext;
+use crate::obj::{Gd, Inherits};
+use sys::GodotFfi as _;
+
+/// Engine method that is looked up at runtime, and may not exist in the running Godot version.
+///
+/// The generated class APIs only contain methods of the API level that gdext was compiled against. A build targeting Godot 4.1 can
+/// still run on 4.3, but cannot call anything added in 4.2 or 4.3. An `OptionalMethod` closes this gap: it names a method by class, name
+/// and hash, resolves it on first use and lets you call it via varcall. If the running engine does not provide it, calls return
+/// [`OptionalCallError::Unavailable`] instead of crashing, so that code can fall back to an older approach.
+///
+/// The hash identifies the exact signature of the method. It is taken from the `extension_api.json` of the Godot version that
+/// introduced the method (`godot --dump-extension-api`), and guards against calling a method whose signature changed.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::OptionalMethod;
+///
+/// // Class, name and hash as listed in `extension_api.json`. `Object.get_class()` exists in every 4.x version; in practice, this
+/// // would be a method that only newer versions provide.
+/// static GET_CLASS: OptionalMethod = OptionalMethod::new("Object", "get_class", 201_670_096);
+///
+/// fn class_of(object: &Gd<Object>) -> String {
+///     match GET_CLASS.call(object, &[]) {
+///         Ok(class) => class.to::<String>(),
+///         Err(_) => String::from("<unknown>"), // Fallback for engines without the method.
+///     }
+/// }
+/// ```
+pub struct OptionalMethod {
+    class_name: &'static str,
+    method_name: &'static str,
+    hash: i64,
+    bind: OnceLock<sys::ClassMethodBind>,
+}
+
+impl OptionalMethod {
+    /// Declares a method `class_name::method_name` with signature hash `hash`.
+    ///
+    /// Nothing is looked up here, so this can initialize a `static`.
+    pub const fn new(class_name: &'static str, method_name: &'static str, hash: i64) -> Self {
+        Self {
+            class_name,
+            method_name,
+            hash,
+            bind: OnceLock::new(),
+        }
+    }
+
+    /// Whether the running engine provides the method with the declared hash.
+    ///
+    /// A found method is cached. Failed lookups are repeated on the next use, as a class may be registered later (e.g. by another
+    /// extension).
+    pub fn is_available(&self) -> bool {
+        self.method_bind().is_some()
+    }
+
+    /// Calls the method on `object`, with arguments passed as variants.
+    ///
+    /// Default parameters of the method are not filled in; all arguments must be passed explicitly.
+    ///
+    /// The dynamic class of `object` must be the declaring class or inherit from it; otherwise, [`OptionalCallError::WrongClass`] is
+    /// returned.
+    ///
+    /// # Panics
+    /// If `object` has been freed.
+    pub fn call<T>(&self, object: &Gd<T>, args: &[Variant]) -> Result<Variant, OptionalCallError>
+    where
+        T: Inherits<Object>,
+    {
+        let call_ctx = CallContext::outbound(self.class_name, self.method_name);
+        let object_ptr = object.obj_sys();
+        crate::classes::ensure_object_alive(object.instance_id(), object_ptr, &call_ctx);
+
+        // The method bind is only valid for instances of its class; Godot does not check this.
+        let object_ref = object.upcast_ref::<Object>();
+        if !object_ref.is_class(self.class_name.into()) {
+            return Err(OptionalCallError::WrongClass {
+                class_name: self.class_name,
+                method_name: self.method_name,
+                actual_class: object_ref.get_class().to_string(),
+            });
+        }
+
+        let Some(method_bind) = self.method_bind() else {
+            return Err(OptionalCallError::Unavailable {
+                class_name: self.class_name,
+                method_name: self.method_name,
+                hash: self.hash,
+            });
+        };
+
+        let class_fn = sys::interface_fn!(object_method_bind_call);
+        let arg_ptrs = args.iter().map(Variant::var_sys).collect::<Vec<_>>();
+
+        // SAFETY: the method bind was obtained from Godot for the declared class, the object is alive and an instance of that class. Argument types are validated
+        // by Godot, since this is a varcall.
+        let result = unsafe {
+            Variant::new_with_var_uninit_result(|return_ptr| {
+                let mut err = sys::default_call_error();
+                class_fn(
+                    method_bind.0,
+                    object_ptr,
+                    arg_ptrs.as_ptr(),
+                    arg_ptrs.len() as i64,
+                    return_ptr,
+                    std::ptr::addr_of_mut!(err),
+                );
+
+                CallError::check_out_varcall::<Variant>(&call_ctx, err, &[], args)
+            })
+        };
+
+        result.map_err(OptionalCallError::Call)
+    }
+
+    /// Class that declares the method.
+    pub fn class_name(&self) -> &'static str {
+        self.class_name
+    }
+
+    /// Name of the method.
+    pub fn method_name(&self) -> &'static str {
+        self.method_name
+    }
+
+    fn method_bind(&self) -> Option<sys::ClassMethodBind> {
+        if let Some(method_bind) = self.bind.get() {
+            return Some(*method_bind);
+        }
+
+        let class_name = StringName::from(self.class_name);
+        let method_name = StringName::from(self.method_name);
+        let get_method_bind = sys::interface_fn!(classdb_get_method_bind);
+
+        // SAFETY: string names are valid for the duration of the call. A null result means the method does not exist.
+        let method_bind = unsafe {
+            get_method_bind(class_name.string_sys(), method_name.string_sys(), self.hash)
+        };
+
+        if method_bind.is_null() {
+            return None;
+        }
+
+        // Concurrent lookups yield the same bind, so it does not matter which one is stored.
+        Some(*self.bind.get_or_init(|| sys::ClassMethodBind(method_bind)))
+    }
+}
+
+impl fmt::Debug for OptionalMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OptionalMethod")
+            .field("class_name", &self.class_name)
+            .field("method_name", &self.method_name)
+            .field("hash", &self.hash)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Error returned by [`OptionalMethod::call()`].
+#[derive(Debug)]
+pub enum OptionalCallError {
+    /// The running engine does not provide the method with the declared hash.
+    Unavailable {
+        class_name: &'static str,
+        method_name: &'static str,
+        hash: i64,
+    },
+
+    /// The object is not an instance of the class that declares the method.
+    WrongClass {
+        class_name: &'static str,
+        method_name: &'static str,
+        actual_class: String,
+    },
+
+    /// The method exists, but the call failed, e.g. due to wrong argument count or types.
+    Call(CallError),
+}
+
+impl fmt::Display for OptionalCallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unavailable {
+                class_name,
+                method_name,
+                hash,
+            } => write!(
+                f,
+                "method {class_name}::{method_name} (hash {hash}) is not available in this Godot version"
+            ),
+            Self::WrongClass {
+                class_name,
+                method_name,
+                actual_class,
+            } => write!(
+                f,
+                "method {class_name}::{method_name} called on instance of unrelated class {actual_class}"
+            ),
+            Self::Call(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for OptionalCallError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Call(err) => Some(err),
+            Self::Unavailable { .. } | Self::WrongClass { .. } => None,
+        }
+    }
+}
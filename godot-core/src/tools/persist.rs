@@ -0,0 +1,381 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Saving and restoring the state of user classes.
+//!
+//! Mark a class with `#[class(persist)]` and the fields to save with `#[persist]`. The derive macro then implements [`Persist`], which
+//! converts the marked fields to a [`Dictionary`] and back. Field types must implement `ToGodot` and `FromGodot`.
+//!
+//! [`SaveGame`] builds on this: it walks a scene subtree, collects the state of all persistent nodes by their path, and restores it later.
+//! The result can be stored as a dictionary, as binary data, or in a file.
+//!
+//! # Versioning
+//! Saved states carry a version number, `1` unless specified with `#[class(persist, persist_version = N)]`. When loading a state with
+//! an older version, the function given by `persist_migrate = fn_name` is invoked first, to update the field dictionary in place.
+//! States with a newer version than the class are rejected.
+//!
+//! Fields missing from a saved state keep their current value, so adding a field does not require a migration.
+//!
+//! # Example
+//! ```no_run
+//! use godot::prelude::*;
+//! use godot::tools::persist::SaveGame;
+//!
+//! #[derive(GodotClass)]
+//! #[class(init, base=Node, persist, persist_version = 2, persist_migrate = migrate)]
+//! struct Player {
+//!     #[persist]
+//!     health: i32,
+//!
+//!     // Renamed from `name` in version 2; the key stays the same.
+//!     #[persist(key = "name")]
+//!     display_name: GString,
+//!
+//!     // Not persisted.
+//!     is_invincible: bool,
+//!
+//!     base: Base<Node>,
+//! }
+//!
+//! impl Player {
+//!     fn migrate(version: u32, fields: &mut Dictionary) {
+//!         if version < 2 {
+//!             // Version 1 stored health as percentage.
+//!             let percent = fields.get("health").map_or(100, |v| v.to::<i32>());
+//!             fields.set("health", percent * 5);
+//!         }
+//!     }
+//! }
+//!
+//! fn quicksave(level: &Gd<Node>) {
+//!     let save = SaveGame::capture(level);
+//!     save.save_to_file("user://quicksave.dat").expect("save failed");
+//! }
+//!
+//! fn quickload(level: &Gd<Node>) {
+//!     let save = SaveGame::load_from_file("user://quicksave.dat").expect("load failed");
+//!     save.restore(level).expect("restore failed");
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::{Dictionary, GString, NodePath, PackedByteArray, Variant};
+use crate::classes::file_access::ModeFlags;
+use crate::classes::{Node, Object};
+use crate::meta::error::ConvertError;
+use crate::meta::{FromGodot, ToGodot};
+use crate::obj::{bounds, Bounds, Gd, GodotClass};
+use crate::registry::plugin::{PersistImpl, PluginItem};
+use crate::sys::Global;
+use crate::tools::GFile;
+
+const KEY_CLASS: &str = "class";
+const KEY_VERSION: &str = "version";
+const KEY_FIELDS: &str = "fields";
+const KEY_NODES: &str = "nodes";
+
+/// Implemented by user classes with `#[class(persist)]`, to save and restore `#[persist]` fields.
+///
+/// Do not implement this manually; see the [module docs](self).
+pub trait Persist: GodotClass + Bounds<Declarer = bounds::DeclUser> {
+    /// Version written to saved states.
+    const PERSIST_VERSION: u32;
+
+    /// Writes all `#[persist]` fields into a dictionary, keyed by their names.
+    #[doc(hidden)]
+    fn __save_fields(&self) -> Dictionary;
+
+    /// Reads `#[persist]` fields from a dictionary. Missing entries are skipped.
+    #[doc(hidden)]
+    fn __load_fields(&mut self, fields: &Dictionary) -> Result<(), ConvertError>;
+
+    /// Updates fields saved by an older version, see `#[class(persist_migrate)]`.
+    #[doc(hidden)]
+    fn __migrate(_version: u32, _fields: &mut Dictionary) {}
+
+    /// Returns the persistent state, including class name and version.
+    fn save_state(&self) -> Dictionary {
+        let mut state = Dictionary::new();
+        state.set(KEY_CLASS, Self::class_name().to_gstring());
+        state.set(KEY_VERSION, Self::PERSIST_VERSION as i64);
+        state.set(KEY_FIELDS, self.__save_fields());
+        state
+    }
+
+    /// Restores a state returned by [`save_state()`][Self::save_state], migrating it if it was saved by an older version.
+    fn load_state(&mut self, state: &Dictionary) -> Result<(), PersistError> {
+        let class_name = Self::class_name().to_string();
+
+        let saved_class: GString = read_entry(state, KEY_CLASS)?;
+        if saved_class.to_string() != class_name {
+            return Err(PersistError::ClassMismatch {
+                expected: class_name,
+                actual: saved_class.to_string(),
+            });
+        }
+
+        let version: i64 = read_entry(state, KEY_VERSION)?;
+        let version = u32::try_from(version)
+            .map_err(|_| PersistError::Malformed(format!("invalid version {version}")))?;
+
+        if version > Self::PERSIST_VERSION {
+            return Err(PersistError::VersionTooNew {
+                class_name,
+                saved: version,
+                current: Self::PERSIST_VERSION,
+            });
+        }
+
+        let mut fields: Dictionary = read_entry(state, KEY_FIELDS)?;
+        if version < Self::PERSIST_VERSION {
+            Self::__migrate(version, &mut fields);
+        }
+
+        self.__load_fields(&fields)
+            .map_err(|err| PersistError::Convert { class_name, err })
+    }
+}
+
+#[doc(hidden)]
+pub fn __read_field<T: FromGodot>(key: &str, value: &Variant) -> Result<T, ConvertError> {
+    value.try_to::<T>().map_err(|err| err.at_key(key))
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// SaveGame
+
+/// States of all persistent nodes in a scene subtree, keyed by their path relative to the root.
+///
+/// See [module docs](self) for an example.
+#[derive(Clone, Debug, Default)]
+pub struct SaveGame {
+    nodes: Dictionary,
+}
+
+impl SaveGame {
+    /// Collects the state of `root` and all its descendants whose classes have `#[class(persist)]`.
+    ///
+    /// # Panics
+    /// If one of the persistent nodes is currently bound mutably.
+    pub fn capture(root: &Gd<Node>) -> Self {
+        let mut nodes = Dictionary::new();
+        collect(root, root, &mut nodes);
+
+        Self { nodes }
+    }
+
+    /// Applies the saved states to the nodes under `root` with matching paths. Returns the number of restored nodes.
+    ///
+    /// Saved nodes that no longer exist are skipped. Persistent nodes without saved state are left unchanged.
+    ///
+    /// # Panics
+    /// If one of the persistent nodes is currently bound.
+    pub fn restore(&self, root: &Gd<Node>) -> Result<usize, PersistError> {
+        let mut restored = 0;
+
+        for (path, state) in self.nodes.iter_shared() {
+            let path: NodePath = path.try_to().map_err(malformed)?;
+            let state: Dictionary = state.try_to().map_err(malformed)?;
+
+            let Some(node) = root.get_node_or_null(path) else {
+                continue;
+            };
+
+            let class_name = node.get_class().to_string();
+            if let Some(glue) = find_impl(&class_name) {
+                glue.load(node.upcast(), &state)?;
+                restored += 1;
+            }
+        }
+
+        Ok(restored)
+    }
+
+    /// Number of nodes with saved state.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Saved state of the node at `path` (relative to the root), as returned by [`Persist::save_state()`].
+    pub fn node_state(&self, path: impl Into<NodePath>) -> Option<Dictionary> {
+        self.nodes
+            .get(path.into())
+            .and_then(|state| state.try_to().ok())
+    }
+
+    /// Returns all states as one dictionary, e.g. to embed it in a larger save file.
+    pub fn to_dictionary(&self) -> Dictionary {
+        let mut dict = Dictionary::new();
+        dict.set(KEY_NODES, self.nodes.clone());
+        dict
+    }
+
+    /// Inverse of [`to_dictionary()`][Self::to_dictionary].
+    pub fn from_dictionary(dict: &Dictionary) -> Result<Self, PersistError> {
+        Ok(Self {
+            nodes: read_entry(dict, KEY_NODES)?,
+        })
+    }
+
+    /// Serializes all states into Godot's binary variant format.
+    pub fn to_bytes(&self) -> PackedByteArray {
+        crate::global::var_to_bytes(self.to_dictionary().to_variant())
+    }
+
+    /// Inverse of [`to_bytes()`][Self::to_bytes].
+    pub fn from_bytes(bytes: &PackedByteArray) -> Result<Self, PersistError> {
+        let dict = crate::global::bytes_to_var(bytes.clone())
+            .try_to::<Dictionary>()
+            .map_err(malformed)?;
+
+        Self::from_dictionary(&dict)
+    }
+
+    /// Writes [`to_dictionary()`][Self::to_dictionary] to a file, e.g. `"user://save.dat"`.
+    pub fn save_to_file(&self, path: impl Into<GString>) -> Result<(), PersistError> {
+        let mut file = GFile::open(path, ModeFlags::WRITE).map_err(PersistError::Io)?;
+        file.write_variant(self.to_dictionary().to_variant(), false)
+            .map_err(PersistError::Io)
+    }
+
+    /// Reads a file written by [`save_to_file()`][Self::save_to_file].
+    pub fn load_from_file(path: impl Into<GString>) -> Result<Self, PersistError> {
+        let mut file = GFile::open(path, ModeFlags::READ).map_err(PersistError::Io)?;
+        let value = file.read_variant(false).map_err(PersistError::Io)?;
+        let dict = value.try_to::<Dictionary>().map_err(malformed)?;
+
+        Self::from_dictionary(&dict)
+    }
+}
+
+fn collect(root: &Gd<Node>, node: &Gd<Node>, nodes: &mut Dictionary) {
+    let class_name = node.get_class().to_string();
+    if let Some(glue) = find_impl(&class_name) {
+        let path = root.get_path_to(node.clone());
+        nodes.set(path, glue.save(node.clone().upcast()));
+    }
+
+    for child in node.get_children().iter_shared() {
+        collect(root, &child, nodes);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Errors
+
+/// Error while restoring persistent state.
+#[derive(Debug)]
+pub enum PersistError {
+    /// The state was saved by a different class.
+    ClassMismatch { expected: String, actual: String },
+
+    /// The state was saved by a newer version of the class.
+    VersionTooNew {
+        class_name: String,
+        saved: u32,
+        current: u32,
+    },
+
+    /// A field could not be converted to its Rust type.
+    Convert {
+        class_name: String,
+        err: ConvertError,
+    },
+
+    /// The data does not have the expected structure.
+    Malformed(String),
+
+    /// Reading or writing a file failed.
+    Io(std::io::Error),
+}
+
+impl fmt::Display for PersistError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ClassMismatch { expected, actual } => {
+                write!(f, "state of class `{actual}` cannot be loaded into `{expected}`")
+            }
+            Self::VersionTooNew {
+                class_name,
+                saved,
+                current,
+            } => write!(
+                f,
+                "state of `{class_name}` has version {saved}, but the class only supports up to {current}"
+            ),
+            Self::Convert { class_name, err } => {
+                write!(f, "invalid field in state of `{class_name}`: {err}")
+            }
+            Self::Malformed(message) => write!(f, "malformed save data: {message}"),
+            Self::Io(err) => write!(f, "I/O error: {err}"),
+        }
+    }
+}
+
+impl Error for PersistError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Convert { err, .. } => Some(err),
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+fn malformed(err: ConvertError) -> PersistError {
+    PersistError::Malformed(err.to_string())
+}
+
+fn read_entry<T: FromGodot>(dict: &Dictionary, key: &str) -> Result<T, PersistError> {
+    let value = dict
+        .get(key)
+        .ok_or_else(|| PersistError::Malformed(format!("missing entry `{key}`")))?;
+
+    __read_field(key, &value).map_err(malformed)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Runtime lookup of persistent classes
+
+static PERSIST_IMPLS: Global<Option<HashMap<String, PersistImpl>>> = Global::default();
+
+fn find_impl(class_name: &str) -> Option<PersistImpl> {
+    let mut guard = PERSIST_IMPLS.lock();
+    let map = guard.get_or_insert_with(|| {
+        let mut map = HashMap::new();
+
+        crate::private::iterate_plugins(|plugin| {
+            if let PluginItem::Persist(glue) = &plugin.item {
+                map.insert(plugin.class_name.to_string(), glue.clone());
+            }
+        });
+
+        map
+    });
+
+    map.get(class_name).cloned()
+}
+
+pub(crate) fn save_erased<C: Persist>(obj: Gd<Object>) -> Dictionary {
+    obj.cast::<C>().bind().save_state()
+}
+
+pub(crate) fn load_erased<C: Persist>(
+    obj: Gd<Object>,
+    state: &Dictionary,
+) -> Result<(), PersistError> {
+    let mut obj = obj.cast::<C>();
+    let result = obj.bind_mut().load_state(state);
+    result
+}
@@ -0,0 +1,584 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::{real, Array, Dictionary, Rid, Transform2D, Transform3D, Vector2, Vector3};
+use crate::classes::{
+    CollisionObject2D, CollisionObject3D, Object, PhysicsDirectSpaceState2D,
+    PhysicsDirectSpaceState3D, PhysicsRayQueryParameters2D, PhysicsRayQueryParameters3D,
+    PhysicsShapeQueryParameters2D, PhysicsShapeQueryParameters3D, Shape2D, Shape3D,
+};
+use crate::meta::FromGodot;
+use crate::obj::{Gd, GodotClass, Inherits, InstanceId, NewGd};
+
+/// Default number of results for shape intersections, like in Godot.
+const DEFAULT_MAX_RESULTS: usize = 32;
+
+/// Builder methods shared by all queries, mapping to the collision filter properties of the query parameters.
+macro_rules! impl_query_filters {
+    ($Query:ty, $CollisionObject:ty) => {
+        impl $Query {
+            /// Physics layers the query detects, as a bitmask. By default, all layers are detected.
+            pub fn mask(mut self, collision_mask: u32) -> Self {
+                self.filter.collision_mask = collision_mask;
+                self
+            }
+
+            /// Excludes the given collision objects from the query, e.g. the body casting a ray.
+            ///
+            /// Can be called multiple times; exclusions accumulate.
+            pub fn exclude<T>(mut self, objects: &[Gd<T>]) -> Self
+            where
+                T: Inherits<$CollisionObject>,
+            {
+                for obj in objects {
+                    let rid = obj.upcast_ref::<$CollisionObject>().get_rid();
+                    self.filter.exclude.push(rid);
+                }
+                self
+            }
+
+            /// Excludes collision objects by their RIDs, e.g. for bodies created directly through the physics server.
+            pub fn exclude_rids(mut self, rids: &[Rid]) -> Self {
+                for rid in rids {
+                    self.filter.exclude.push(*rid);
+                }
+                self
+            }
+
+            /// Whether `Area` nodes are detected. Default: `false`.
+            pub fn collide_with_areas(mut self, enabled: bool) -> Self {
+                self.filter.collide_with_areas = enabled;
+                self
+            }
+
+            /// Whether physics bodies are detected. Default: `true`.
+            pub fn collide_with_bodies(mut self, enabled: bool) -> Self {
+                self.filter.collide_with_bodies = enabled;
+                self
+            }
+        }
+    };
+}
+
+/// Collision filter common to all query types.
+#[derive(Clone, Debug)]
+struct QueryFilter {
+    collision_mask: u32,
+    exclude: Array<Rid>,
+    collide_with_areas: bool,
+    collide_with_bodies: bool,
+}
+
+impl Default for QueryFilter {
+    fn default() -> Self {
+        Self {
+            collision_mask: u32::MAX,
+            exclude: Array::new(),
+            collide_with_areas: false,
+            collide_with_bodies: true,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Ray queries
+
+/// Typed builder for [`PhysicsDirectSpaceState2D::intersect_ray()`].
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::classes::PhysicsDirectSpaceState2D;
+/// use godot::tools::RayQuery2D;
+///
+/// fn has_line_of_sight(space: &mut Gd<PhysicsDirectSpaceState2D>, eye: Vector2, target: Vector2) -> bool {
+///     RayQuery2D::new(eye, target).mask(0b101).cast(space).is_none()
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RayQuery2D {
+    from: Vector2,
+    to: Vector2,
+    hit_from_inside: bool,
+    filter: QueryFilter,
+}
+
+impl RayQuery2D {
+    /// Creates a query for a ray from `from` to `to`, in global coordinates.
+    pub fn new(from: Vector2, to: Vector2) -> Self {
+        Self {
+            from,
+            to,
+            hit_from_inside: false,
+            filter: QueryFilter::default(),
+        }
+    }
+
+    /// Whether a ray starting inside a shape detects that shape (with zero normal). Default: `false`.
+    pub fn hit_from_inside(mut self, enabled: bool) -> Self {
+        self.hit_from_inside = enabled;
+        self
+    }
+
+    /// Casts the ray and returns the closest hit, if any.
+    pub fn cast(&self, space: &mut Gd<PhysicsDirectSpaceState2D>) -> Option<RayHit2D> {
+        let mut params = PhysicsRayQueryParameters2D::new_gd();
+        params.set_from(self.from);
+        params.set_to(self.to);
+        params.set_hit_from_inside(self.hit_from_inside);
+        params.set_collision_mask(self.filter.collision_mask);
+        params.set_exclude(self.filter.exclude.clone());
+        params.set_collide_with_areas(self.filter.collide_with_areas);
+        params.set_collide_with_bodies(self.filter.collide_with_bodies);
+
+        let result = space.intersect_ray(params);
+        RayHit2D::from_dictionary(&result)
+    }
+}
+
+impl_query_filters!(RayQuery2D, CollisionObject2D);
+
+/// Typed builder for [`PhysicsDirectSpaceState3D::intersect_ray()`].
+///
+/// See [`RayQuery2D`] for an example.
+#[derive(Clone, Debug)]
+pub struct RayQuery3D {
+    from: Vector3,
+    to: Vector3,
+    hit_from_inside: bool,
+    hit_back_faces: bool,
+    filter: QueryFilter,
+}
+
+impl RayQuery3D {
+    /// Creates a query for a ray from `from` to `to`, in global coordinates.
+    pub fn new(from: Vector3, to: Vector3) -> Self {
+        Self {
+            from,
+            to,
+            hit_from_inside: false,
+            hit_back_faces: true,
+            filter: QueryFilter::default(),
+        }
+    }
+
+    /// Whether a ray starting inside a shape detects that shape (with zero normal). Default: `false`.
+    pub fn hit_from_inside(mut self, enabled: bool) -> Self {
+        self.hit_from_inside = enabled;
+        self
+    }
+
+    /// Whether back faces of concave polygon shapes and height maps are detected. Default: `true`.
+    pub fn hit_back_faces(mut self, enabled: bool) -> Self {
+        self.hit_back_faces = enabled;
+        self
+    }
+
+    /// Casts the ray and returns the closest hit, if any.
+    pub fn cast(&self, space: &mut Gd<PhysicsDirectSpaceState3D>) -> Option<RayHit3D> {
+        let mut params = PhysicsRayQueryParameters3D::new_gd();
+        params.set_from(self.from);
+        params.set_to(self.to);
+        params.set_hit_from_inside(self.hit_from_inside);
+        params.set_hit_back_faces(self.hit_back_faces);
+        params.set_collision_mask(self.filter.collision_mask);
+        params.set_exclude(self.filter.exclude.clone());
+        params.set_collide_with_areas(self.filter.collide_with_areas);
+        params.set_collide_with_bodies(self.filter.collide_with_bodies);
+
+        let result = space.intersect_ray(params);
+        RayHit3D::from_dictionary(&result)
+    }
+}
+
+impl_query_filters!(RayQuery3D, CollisionObject3D);
+
+/// Result of a [`RayQuery2D`].
+#[derive(Clone, Debug)]
+pub struct RayHit2D {
+    /// Intersection point, in global coordinates.
+    pub position: Vector2,
+
+    /// Surface normal at the intersection point.
+    pub normal: Vector2,
+
+    /// Object that was hit, or `None` if the collision object has no associated object (e.g. created through the physics server).
+    pub collider: Option<Gd<Object>>,
+
+    /// Instance ID of [`collider`][Self::collider].
+    pub collider_id: Option<InstanceId>,
+
+    /// RID of the collision object.
+    pub rid: Rid,
+
+    /// Index of the shape within the collision object.
+    pub shape: i32,
+}
+
+impl RayHit2D {
+    fn from_dictionary(result: &Dictionary) -> Option<Self> {
+        // Godot returns an empty dictionary if nothing was hit.
+        if result.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            position: field(result, "position")?,
+            normal: field(result, "normal")?,
+            collider: collider(result),
+            collider_id: field(result, "collider_id"),
+            rid: field(result, "rid")?,
+            shape: field(result, "shape")?,
+        })
+    }
+}
+
+/// Result of a [`RayQuery3D`].
+#[derive(Clone, Debug)]
+pub struct RayHit3D {
+    /// Intersection point, in global coordinates.
+    pub position: Vector3,
+
+    /// Surface normal at the intersection point.
+    pub normal: Vector3,
+
+    /// Object that was hit, or `None` if the collision object has no associated object (e.g. created through the physics server).
+    pub collider: Option<Gd<Object>>,
+
+    /// Instance ID of [`collider`][Self::collider].
+    pub collider_id: Option<InstanceId>,
+
+    /// RID of the collision object.
+    pub rid: Rid,
+
+    /// Index of the shape within the collision object.
+    pub shape: i32,
+
+    /// Index of the hit face for concave polygon shapes, or -1 for other shapes.
+    pub face_index: i32,
+}
+
+impl RayHit3D {
+    fn from_dictionary(result: &Dictionary) -> Option<Self> {
+        if result.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            position: field(result, "position")?,
+            normal: field(result, "normal")?,
+            collider: collider(result),
+            collider_id: field(result, "collider_id"),
+            rid: field(result, "rid")?,
+            shape: field(result, "shape")?,
+            face_index: field(result, "face_index").unwrap_or(-1),
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Shape queries
+
+/// Typed builder for [`PhysicsDirectSpaceState2D`] shape queries (`intersect_shape()`, `cast_motion()`).
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::classes::{CircleShape2D, PhysicsDirectSpaceState2D};
+/// use godot::tools::ShapeQuery2D;
+///
+/// fn bodies_in_blast(space: &mut Gd<PhysicsDirectSpaceState2D>, center: Vector2) -> Vec<Gd<Object>> {
+///     let mut circle = CircleShape2D::new_gd();
+///     circle.set_radius(64.0);
+///
+///     ShapeQuery2D::new(circle.upcast(), Transform2D::IDENTITY.translated(center))
+///         .intersect(space)
+///         .into_iter()
+///         .filter_map(|hit| hit.collider)
+///         .collect()
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ShapeQuery2D {
+    shape: ShapeSource<Shape2D>,
+    transform: Transform2D,
+    motion: Vector2,
+    margin: real,
+    max_results: usize,
+    filter: QueryFilter,
+}
+
+impl ShapeQuery2D {
+    /// Creates a query for `shape`, placed at `transform` in global coordinates.
+    pub fn new(shape: Gd<Shape2D>, transform: Transform2D) -> Self {
+        Self::with_source(ShapeSource::Resource(shape), transform)
+    }
+
+    /// Creates a query for a shape created through the physics server, placed at `transform` in global coordinates.
+    pub fn from_rid(shape_rid: Rid, transform: Transform2D) -> Self {
+        Self::with_source(ShapeSource::Rid(shape_rid), transform)
+    }
+
+    fn with_source(shape: ShapeSource<Shape2D>, transform: Transform2D) -> Self {
+        Self {
+            shape,
+            transform,
+            motion: Vector2::ZERO,
+            margin: 0.0,
+            max_results: DEFAULT_MAX_RESULTS,
+            filter: QueryFilter::default(),
+        }
+    }
+
+    /// Motion of the shape, used by [`cast_motion()`][Self::cast_motion]. Default: zero.
+    pub fn motion(mut self, motion: Vector2) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Collision margin of the shape. Default: `0.0`.
+    pub fn margin(mut self, margin: real) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Maximum number of results returned by [`intersect()`][Self::intersect]. Default: 32.
+    pub fn max_results(mut self, max_results: usize) -> Self {
+        self.max_results = max_results;
+        self
+    }
+
+    /// Returns all collision objects that overlap the shape, up to [`max_results`](Self::max_results).
+    pub fn intersect(&self, space: &mut Gd<PhysicsDirectSpaceState2D>) -> Vec<ShapeHit> {
+        let results = space
+            .intersect_shape_ex(self.to_params())
+            .max_results(to_i32(self.max_results))
+            .done();
+
+        ShapeHit::from_results(&results)
+    }
+
+    /// Moves the shape along [`motion`](Self::motion) and determines how far it can move without colliding.
+    ///
+    /// Returns `None` if the query failed, e.g. because the shape is invalid.
+    pub fn cast_motion(&self, space: &mut Gd<PhysicsDirectSpaceState2D>) -> Option<MotionCast> {
+        let fractions = space.cast_motion(self.to_params());
+        MotionCast::from_fractions(fractions.as_slice())
+    }
+
+    fn to_params(&self) -> Gd<PhysicsShapeQueryParameters2D> {
+        let mut params = PhysicsShapeQueryParameters2D::new_gd();
+        match &self.shape {
+            ShapeSource::Resource(shape) => params.set_shape(shape.clone().upcast()),
+            ShapeSource::Rid(rid) => params.set_shape_rid(*rid),
+        }
+        params.set_transform(self.transform);
+        params.set_motion(self.motion);
+        params.set_margin(self.margin);
+        params.set_collision_mask(self.filter.collision_mask);
+        params.set_exclude(self.filter.exclude.clone());
+        params.set_collide_with_areas(self.filter.collide_with_areas);
+        params.set_collide_with_bodies(self.filter.collide_with_bodies);
+        params
+    }
+}
+
+impl_query_filters!(ShapeQuery2D, CollisionObject2D);
+
+/// Typed builder for [`PhysicsDirectSpaceState3D`] shape queries (`intersect_shape()`, `cast_motion()`).
+///
+/// See [`ShapeQuery2D`] for an example.
+#[derive(Clone, Debug)]
+pub struct ShapeQuery3D {
+    shape: ShapeSource<Shape3D>,
+    transform: Transform3D,
+    motion: Vector3,
+    margin: real,
+    max_results: usize,
+    filter: QueryFilter,
+}
+
+impl ShapeQuery3D {
+    /// Creates a query for `shape`, placed at `transform` in global coordinates.
+    pub fn new(shape: Gd<Shape3D>, transform: Transform3D) -> Self {
+        Self::with_source(ShapeSource::Resource(shape), transform)
+    }
+
+    /// Creates a query for a shape created through the physics server, placed at `transform` in global coordinates.
+    pub fn from_rid(shape_rid: Rid, transform: Transform3D) -> Self {
+        Self::with_source(ShapeSource::Rid(shape_rid), transform)
+    }
+
+    fn with_source(shape: ShapeSource<Shape3D>, transform: Transform3D) -> Self {
+        Self {
+            shape,
+            transform,
+            motion: Vector3::ZERO,
+            margin: 0.0,
+            max_results: DEFAULT_MAX_RESULTS,
+            filter: QueryFilter::default(),
+        }
+    }
+
+    /// Motion of the shape, used by [`cast_motion()`][Self::cast_motion]. Default: zero.
+    pub fn motion(mut self, motion: Vector3) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Collision margin of the shape. Default: `0.0`.
+    pub fn margin(mut self, margin: real) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Maximum number of results returned by [`intersect()`][Self::intersect]. Default: 32.
+    pub fn max_results(mut self, max_results: usize) -> Self {
+        self.max_results = max_results;
+        self
+    }
+
+    /// Returns all collision objects that overlap the shape, up to [`max_results`](Self::max_results).
+    pub fn intersect(&self, space: &mut Gd<PhysicsDirectSpaceState3D>) -> Vec<ShapeHit> {
+        let results = space
+            .intersect_shape_ex(self.to_params())
+            .max_results(to_i32(self.max_results))
+            .done();
+
+        ShapeHit::from_results(&results)
+    }
+
+    /// Moves the shape along [`motion`](Self::motion) and determines how far it can move without colliding.
+    ///
+    /// Returns `None` if the query failed, e.g. because the shape is invalid.
+    pub fn cast_motion(&self, space: &mut Gd<PhysicsDirectSpaceState3D>) -> Option<MotionCast> {
+        let fractions = space.cast_motion(self.to_params());
+        MotionCast::from_fractions(fractions.as_slice())
+    }
+
+    fn to_params(&self) -> Gd<PhysicsShapeQueryParameters3D> {
+        let mut params = PhysicsShapeQueryParameters3D::new_gd();
+        match &self.shape {
+            ShapeSource::Resource(shape) => params.set_shape(shape.clone().upcast()),
+            ShapeSource::Rid(rid) => params.set_shape_rid(*rid),
+        }
+        params.set_transform(self.transform);
+        params.set_motion(self.motion);
+        params.set_margin(self.margin);
+        params.set_collision_mask(self.filter.collision_mask);
+        params.set_exclude(self.filter.exclude.clone());
+        params.set_collide_with_areas(self.filter.collide_with_areas);
+        params.set_collide_with_bodies(self.filter.collide_with_bodies);
+        params
+    }
+}
+
+impl_query_filters!(ShapeQuery3D, CollisionObject3D);
+
+/// Single result of [`ShapeQuery2D::intersect()`] or [`ShapeQuery3D::intersect()`].
+#[derive(Clone, Debug)]
+pub struct ShapeHit {
+    /// Overlapping object, or `None` if the collision object has no associated object (e.g. created through the physics server).
+    pub collider: Option<Gd<Object>>,
+
+    /// Instance ID of [`collider`][Self::collider].
+    pub collider_id: Option<InstanceId>,
+
+    /// RID of the collision object.
+    pub rid: Rid,
+
+    /// Index of the shape within the collision object.
+    pub shape: i32,
+}
+
+impl ShapeHit {
+    fn from_results(results: &Array<Dictionary>) -> Vec<Self> {
+        results
+            .iter_shared()
+            .filter_map(|result| {
+                Some(Self {
+                    collider: collider(&result),
+                    collider_id: field(&result, "collider_id"),
+                    rid: field(&result, "rid")?,
+                    shape: field(&result, "shape")?,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Result of [`ShapeQuery2D::cast_motion()`] or [`ShapeQuery3D::cast_motion()`].
+///
+/// Both values are fractions of the motion, between 0.0 and 1.0. If no collision occurs along the motion, both are 1.0.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct MotionCast {
+    /// How far the shape can move without colliding.
+    pub safe_fraction: f32,
+
+    /// How far the shape must move to collide.
+    pub unsafe_fraction: f32,
+}
+
+impl MotionCast {
+    /// Returns whether the shape can move the full motion without colliding.
+    pub fn is_free(&self) -> bool {
+        self.safe_fraction >= 1.0
+    }
+
+    fn from_fractions(fractions: &[f32]) -> Option<Self> {
+        match *fractions {
+            [safe_fraction, unsafe_fraction] => Some(Self {
+                safe_fraction,
+                unsafe_fraction,
+            }),
+            _ => None,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+/// Shape of a query, either as a resource or as a RID of the physics server.
+enum ShapeSource<S: GodotClass> {
+    Resource(Gd<S>),
+    Rid(Rid),
+}
+
+// Manual impls, since derives would require `S: Clone + Debug`.
+impl<S: GodotClass> Clone for ShapeSource<S> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Resource(shape) => Self::Resource(shape.clone()),
+            Self::Rid(rid) => Self::Rid(*rid),
+        }
+    }
+}
+
+impl<S: GodotClass> fmt::Debug for ShapeSource<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Resource(shape) => f.debug_tuple("Resource").field(shape).finish(),
+            Self::Rid(rid) => f.debug_tuple("Rid").field(rid).finish(),
+        }
+    }
+}
+
+/// Reads a typed value from a query result.
+fn field<T: FromGodot>(result: &Dictionary, key: &str) -> Option<T> {
+    result.get(key).and_then(|value| value.try_to().ok())
+}
+
+fn collider(result: &Dictionary) -> Option<Gd<Object>> {
+    field::<Option<Gd<Object>>>(result, "collider").flatten()
+}
+
+fn to_i32(max_results: usize) -> i32 {
+    max_results.try_into().unwrap_or(i32::MAX)
+}
@@ -0,0 +1,249 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Object pools, to reuse frequently instantiated objects instead of constructing and destroying them repeatedly.
+//!
+//! Games with many short-lived objects (bullets, particles, damage numbers...) often spend a lot of time in instantiation. A [`Pool`]
+//! keeps released objects around and hands them out again on [`acquire()`][Pool::acquire].
+//!
+//! Pools work with both nodes and ref-counted objects:
+//! - **Nodes** are removed from their parent when released, and freed when the pool is dropped. Acquired nodes are owned by the caller
+//!   (usually the scene tree) until they are released again.
+//! - **Ref-counted objects** are kept alive by the pool while released. Acquired objects don't need to be released; if they are simply
+//!   dropped, the pool creates new ones as needed.
+//!
+//! # Example
+//! ```no_run
+//! # use godot::prelude::*;
+//! use godot::tools::pool::{IPoolable, Pool};
+//!
+//! #[derive(GodotClass)]
+//! #[class(init, base=Node2D)]
+//! struct Bullet {
+//!     lifetime: f64,
+//!     base: Base<Node2D>,
+//! }
+//!
+//! impl IPoolable for Bullet {
+//!     fn on_acquire(&mut self) {
+//!         self.lifetime = 3.0;
+//!         self.base_mut().show();
+//!     }
+//!
+//!     fn on_release(&mut self) {
+//!         self.base_mut().hide();
+//!     }
+//! }
+//!
+//! fn shoot(pool: &mut Pool<Bullet>, parent: &mut Gd<Node>) {
+//!     let bullet = pool.acquire();
+//!     parent.add_child(bullet.upcast());
+//! }
+//!
+//! let mut pool = Pool::<Bullet>::new_default().with_poolable_hooks().with_max_size(256);
+//! pool.prewarm(64);
+//! ```
+
+use std::fmt;
+
+use crate::classes::{Node, Object, PackedScene};
+use crate::obj::{bounds, cap, Bounds, Gd, Inherits};
+
+/// Hooks invoked when a user object is handed out from a [`Pool`] or returned to it.
+///
+/// Implement this for your class and enable it with [`Pool::with_poolable_hooks()`]. Both methods have empty default implementations.
+pub trait IPoolable {
+    /// Called when the object is about to be returned from [`Pool::acquire()`]; typically resets state.
+    fn on_acquire(&mut self) {}
+
+    /// Called when the object is returned to the pool with [`Pool::release()`], after a node has been removed from its parent.
+    fn on_release(&mut self) {}
+}
+
+type Factory<T> = Box<dyn FnMut() -> Gd<T>>;
+type Hook<T> = Box<dyn FnMut(&mut Gd<T>)>;
+
+/// Pool of reusable objects of class `T`.
+///
+/// See [module docs](self) for an overview.
+pub struct Pool<T: Inherits<Object>> {
+    available: Vec<Gd<T>>,
+    factory: Factory<T>,
+    acquire_hooks: Vec<Hook<T>>,
+    release_hooks: Vec<Hook<T>>,
+    max_size: Option<usize>,
+    created_count: usize,
+}
+
+impl<T: Inherits<Object>> Pool<T> {
+    /// Creates an empty pool, which constructs new objects using `factory`.
+    pub fn new(factory: impl FnMut() -> Gd<T> + 'static) -> Self {
+        Self {
+            available: Vec::new(),
+            factory: Box::new(factory),
+            acquire_hooks: Vec::new(),
+            release_hooks: Vec::new(),
+            max_size: None,
+            created_count: 0,
+        }
+    }
+
+    /// Creates an empty pool, which constructs new objects with the default constructor (`init` for user classes, `new` for engine classes).
+    pub fn new_default() -> Self
+    where
+        T: cap::GodotDefault,
+    {
+        Self::new(T::__godot_default)
+    }
+
+    /// Creates an empty pool, which instantiates `scene` to create new objects.
+    ///
+    /// # Panics
+    /// When instantiating, if the root node of the scene is not of class `T`.
+    pub fn from_scene(scene: Gd<PackedScene>) -> Self
+    where
+        T: Inherits<Node>,
+    {
+        Self::new(move || scene.instantiate_as::<T>())
+    }
+
+    /// Registers a `callback` invoked on every object before it is returned from [`acquire()`][Self::acquire].
+    pub fn on_acquire(mut self, callback: impl FnMut(&mut Gd<T>) + 'static) -> Self {
+        self.acquire_hooks.push(Box::new(callback));
+        self
+    }
+
+    /// Registers a `callback` invoked on every object that is passed to [`release()`][Self::release].
+    pub fn on_release(mut self, callback: impl FnMut(&mut Gd<T>) + 'static) -> Self {
+        self.release_hooks.push(Box::new(callback));
+        self
+    }
+
+    /// Invokes the [`IPoolable`] methods of the user class `T` on acquire and release.
+    pub fn with_poolable_hooks(self) -> Self
+    where
+        T: IPoolable + Bounds<Declarer = bounds::DeclUser>,
+    {
+        self.on_acquire(|obj| obj.bind_mut().on_acquire())
+            .on_release(|obj| obj.bind_mut().on_release())
+    }
+
+    /// Limits how many released objects the pool keeps around. Objects released beyond this limit are destroyed.
+    pub fn with_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = Some(max_size);
+        while self.available.len() > max_size {
+            destroy(self.available.pop().unwrap());
+        }
+        self
+    }
+
+    /// Creates objects until at least `count` are available, e.g. during a loading screen.
+    ///
+    /// Release hooks are not invoked for prewarmed objects; acquire hooks will be invoked once they are acquired.
+    pub fn prewarm(&mut self, count: usize) {
+        let count = self.max_size.map_or(count, |max| count.min(max));
+
+        while self.available.len() < count {
+            let obj = self.create();
+            self.available.push(obj);
+        }
+    }
+
+    /// Returns a pooled object, or creates a new one if none is available.
+    pub fn acquire(&mut self) -> Gd<T> {
+        let mut obj = loop {
+            match self.available.pop() {
+                // The object might have been freed externally while in the pool, e.g. by a queue_free() call.
+                Some(obj) if obj.is_instance_valid() => break obj,
+                Some(_) => continue,
+                None => break self.create(),
+            }
+        };
+
+        for hook in self.acquire_hooks.iter_mut() {
+            hook(&mut obj);
+        }
+
+        obj
+    }
+
+    /// Returns an object to the pool, so it can be handed out again.
+    ///
+    /// Nodes are removed from their parent. If the pool is at its maximum size, the object is destroyed instead.
+    pub fn release(&mut self, mut obj: Gd<T>) {
+        if !obj.is_instance_valid() {
+            return;
+        }
+
+        if let Ok(node) = obj.clone().upcast::<Object>().try_cast::<Node>() {
+            if let Some(mut parent) = node.get_parent() {
+                parent.remove_child(node);
+            }
+        }
+
+        for hook in self.release_hooks.iter_mut() {
+            hook(&mut obj);
+        }
+
+        if self.max_size.is_some_and(|max| self.available.len() >= max) {
+            destroy(obj);
+        } else {
+            self.available.push(obj);
+        }
+    }
+
+    /// Number of released objects that are ready to be acquired.
+    pub fn available_count(&self) -> usize {
+        self.available.len()
+    }
+
+    /// Total number of objects created by this pool so far.
+    pub fn created_count(&self) -> usize {
+        self.created_count
+    }
+
+    /// Destroys all available objects. Acquired objects are not affected.
+    pub fn clear(&mut self) {
+        for obj in self.available.drain(..) {
+            destroy(obj);
+        }
+    }
+
+    fn create(&mut self) -> Gd<T> {
+        self.created_count += 1;
+        (self.factory)()
+    }
+}
+
+impl<T: Inherits<Object>> Drop for Pool<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Inherits<Object>> fmt::Debug for Pool<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pool")
+            .field("class", &T::class_name())
+            .field("available", &self.available.len())
+            .field("created", &self.created_count)
+            .field("max_size", &self.max_size)
+            .finish()
+    }
+}
+
+/// Frees manually managed objects; ref-counted ones are destroyed once the last reference is dropped.
+fn destroy<T: Inherits<Object>>(obj: Gd<T>) {
+    if !obj.is_instance_valid() {
+        return;
+    }
+
+    let obj = obj.upcast::<Object>();
+    if !obj.is_class("RefCounted".into()) {
+        obj.free();
+    }
+}
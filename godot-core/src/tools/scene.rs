@@ -0,0 +1,781 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Building [`PackedScene`]s from Rust, and comparing them.
+//!
+//! A [`SceneBuilder`] turns a declarative tree of [`NodeSpec`]s -- classes, names, properties and signal connections -- into a
+//! `PackedScene`. Every part of the description is validated before packing, and errors name the offending node, instead of
+//! surfacing as warnings from `PackedScene::pack()` or as silently missing properties.
+//!
+//! [`SceneDiff`] compares two packed scenes and lists added and removed nodes, changed classes, properties and connections. This is
+//! useful to review the output of procedural tools, or to assert in tests that only the expected parts of a scene changed.
+//!
+//! # Example
+//! ```no_run
+//! use godot::prelude::*;
+//! use godot::classes::{Button, Label, VBoxContainer};
+//! use godot::tools::scene::{NodeSpec, SceneBuilder, SceneDiff};
+//!
+//! let menu = SceneBuilder::new(
+//!     NodeSpec::of::<VBoxContainer>("Menu")
+//!         .child(NodeSpec::of::<Label>("Title").property("text", "Main menu"))
+//!         .child(
+//!             NodeSpec::of::<Button>("Start")
+//!                 .property("text", "Start")
+//!                 .connect("pressed", "..", "queue_free"),
+//!         ),
+//! )
+//! .build()
+//! .expect("valid scene");
+//!
+//! let original = load::<PackedScene>("res://menu.tscn");
+//! godot_print!("{}", SceneDiff::between(&original, &menu));
+//! ```
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::{Callable, NodePath, StringName, Variant, VariantType};
+use crate::classes::object::ConnectFlags;
+use crate::classes::{ClassDb, Node, PackedScene, SceneState};
+use crate::global;
+use crate::meta::{class_db, ToGodot};
+use crate::obj::{EngineEnum as _, Gd, GodotClass, Inherits, NewGd};
+
+/// Declarative description of a node and its subtree, to be built by [`SceneBuilder`].
+#[derive(Clone, Debug)]
+pub struct NodeSpec {
+    class_name: StringName,
+    name: StringName,
+    properties: Vec<(StringName, Variant)>,
+    groups: Vec<StringName>,
+    connections: Vec<ConnectionSpec>,
+    children: Vec<NodeSpec>,
+}
+
+#[derive(Clone, Debug)]
+struct ConnectionSpec {
+    signal: StringName,
+    target: NodePath,
+    method: StringName,
+}
+
+impl NodeSpec {
+    /// Describes a node of the Godot class `class_name`, e.g. one registered by another extension.
+    ///
+    /// The class is only checked when building.
+    pub fn new(class_name: impl Into<StringName>, name: impl Into<StringName>) -> Self {
+        Self {
+            class_name: class_name.into(),
+            name: name.into(),
+            properties: Vec::new(),
+            groups: Vec::new(),
+            connections: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Describes a node of class `T`, which can be an engine class or a Rust class.
+    pub fn of<T>(name: impl Into<StringName>) -> Self
+    where
+        T: GodotClass + Inherits<Node>,
+    {
+        Self::new(T::class_name().to_string_name(), name)
+    }
+
+    /// Sets a property of the node. The property must exist, and `value` must have its type.
+    pub fn property(mut self, name: impl Into<StringName>, value: impl ToGodot) -> Self {
+        self.properties.push((name.into(), value.to_variant()));
+        self
+    }
+
+    /// Adds the node to a group, which is saved in the scene.
+    pub fn group(mut self, group: impl Into<StringName>) -> Self {
+        self.groups.push(group.into());
+        self
+    }
+
+    /// Connects `signal` of this node to `method` of the node at `target`.
+    ///
+    /// `target` is relative to this node, e.g. `".."` for the parent or `"."` for the node itself. The connection is persisted in the
+    /// scene, like connections made in the editor.
+    pub fn connect(
+        mut self,
+        signal: impl Into<StringName>,
+        target: impl Into<NodePath>,
+        method: impl Into<StringName>,
+    ) -> Self {
+        self.connections.push(ConnectionSpec {
+            signal: signal.into(),
+            target: target.into(),
+            method: method.into(),
+        });
+        self
+    }
+
+    /// Appends a child node.
+    pub fn child(mut self, child: NodeSpec) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Appends several child nodes, in iteration order.
+    pub fn children(mut self, children: impl IntoIterator<Item = NodeSpec>) -> Self {
+        self.children.extend(children);
+        self
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Builds a [`PackedScene`] from a [`NodeSpec`] tree.
+///
+/// See the [module docs](crate::tools::scene) for an example.
+#[derive(Clone, Debug)]
+pub struct SceneBuilder {
+    root: NodeSpec,
+}
+
+impl SceneBuilder {
+    /// Creates a builder for the scene with root node `root`.
+    pub fn new(root: NodeSpec) -> Self {
+        Self { root }
+    }
+
+    /// Validates the description and packs it into a new `PackedScene`.
+    ///
+    /// The nodes created in the process are freed again before returning.
+    pub fn build(&self) -> Result<Gd<PackedScene>, SceneBuildError> {
+        let root = self.instantiate()?;
+
+        let mut scene = PackedScene::new_gd();
+        let result = scene.pack(root.clone());
+        root.free();
+
+        if result != global::Error::OK {
+            return Err(SceneBuildError {
+                node_path: self.root.name.to_string(),
+                kind: SceneBuildErrorKind::PackFailed(result),
+            });
+        }
+
+        Ok(scene)
+    }
+
+    /// Validates the description and creates the node tree, without packing it.
+    ///
+    /// All nodes are owned by the returned root, as if they had been instantiated from a scene. The caller is responsible for
+    /// freeing the tree, e.g. by adding it to the scene tree.
+    pub fn instantiate(&self) -> Result<Gd<Node>, SceneBuildError> {
+        let path = self.root.name.to_string();
+        let root = create_node(&self.root, &path)?;
+
+        // Connections are made in a second pass, since their targets can be anywhere in the tree.
+        let result = create_children(&root, &root, &self.root, &path)
+            .and_then(|()| connect_all(&root, &self.root, &path));
+
+        if let Err(err) = result {
+            root.free();
+            return Err(err);
+        }
+
+        Ok(root)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Differences between two packed scenes, as computed by [`SceneDiff::between()`].
+///
+/// Nodes are matched by their path relative to the root (`.` for the root itself). Properties are compared as stored in the scene,
+/// i.e. only values that differ from the class defaults are considered. The `Display` impl prints one change per line.
+#[derive(Clone, Debug, Default)]
+pub struct SceneDiff {
+    changes: Vec<SceneChange>,
+}
+
+impl SceneDiff {
+    /// Computes the changes that turn `old` into `new`.
+    ///
+    /// Removed nodes come first, followed by added and modified nodes in the order of `new`, then by connection changes.
+    pub fn between(old: &Gd<PackedScene>, new: &Gd<PackedScene>) -> Self {
+        let old = SceneData::read(&old.get_state().expect("PackedScene has no state"));
+        let new = SceneData::read(&new.get_state().expect("PackedScene has no state"));
+
+        let mut changes = Vec::new();
+
+        for old_node in &old.nodes {
+            if new.find(&old_node.path).is_none() {
+                changes.push(SceneChange::NodeRemoved {
+                    path: old_node.path.clone(),
+                    class_name: old_node.class_name.clone(),
+                });
+            }
+        }
+
+        for new_node in &new.nodes {
+            let Some(old_node) = old.find(&new_node.path) else {
+                changes.push(SceneChange::NodeAdded {
+                    path: new_node.path.clone(),
+                    class_name: new_node.class_name.clone(),
+                });
+                continue;
+            };
+
+            if old_node.class_name != new_node.class_name {
+                changes.push(SceneChange::ClassChanged {
+                    path: new_node.path.clone(),
+                    old: old_node.class_name.clone(),
+                    new: new_node.class_name.clone(),
+                });
+            }
+
+            diff_properties(old_node, new_node, &mut changes);
+        }
+
+        for connection in &old.connections {
+            if !new.connections.contains(connection) {
+                changes.push(SceneChange::ConnectionRemoved(connection.clone()));
+            }
+        }
+
+        for connection in &new.connections {
+            if !old.connections.contains(connection) {
+                changes.push(SceneChange::ConnectionAdded(connection.clone()));
+            }
+        }
+
+        Self { changes }
+    }
+
+    /// Whether the scenes are equivalent.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Number of changes.
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Iterates over the changes.
+    pub fn iter(&self) -> std::slice::Iter<'_, SceneChange> {
+        self.changes.iter()
+    }
+
+    /// Returns the changes as a slice.
+    pub fn as_slice(&self) -> &[SceneChange] {
+        &self.changes
+    }
+}
+
+impl IntoIterator for SceneDiff {
+    type Item = SceneChange;
+    type IntoIter = std::vec::IntoIter<SceneChange>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.changes.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a SceneDiff {
+    type Item = &'a SceneChange;
+    type IntoIter = std::slice::Iter<'a, SceneChange>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.changes.iter()
+    }
+}
+
+impl fmt::Display for SceneDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+/// One difference between two packed scenes, see [`SceneDiff`].
+///
+/// Node paths are relative to the scene root, which itself has path `.`.
+#[derive(Clone, PartialEq, Debug)]
+pub enum SceneChange {
+    /// A node exists only in the new scene.
+    NodeAdded { path: String, class_name: String },
+
+    /// A node exists only in the old scene.
+    NodeRemoved { path: String, class_name: String },
+
+    /// The node exists in both scenes, but with a different class.
+    ClassChanged {
+        path: String,
+        old: String,
+        new: String,
+    },
+
+    /// A property is stored in the new scene, but has its default value in the old one.
+    PropertyAdded {
+        path: String,
+        property: String,
+        value: Variant,
+    },
+
+    /// A property is stored in the old scene, but has its default value in the new one.
+    PropertyRemoved {
+        path: String,
+        property: String,
+        value: Variant,
+    },
+
+    /// A property has different values in the two scenes.
+    PropertyChanged {
+        path: String,
+        property: String,
+        old: Variant,
+        new: Variant,
+    },
+
+    /// A signal connection exists only in the new scene.
+    ConnectionAdded(SceneConnection),
+
+    /// A signal connection exists only in the old scene.
+    ConnectionRemoved(SceneConnection),
+}
+
+impl fmt::Display for SceneChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NodeAdded { path, class_name } => write!(f, "+ {path} ({class_name})"),
+            Self::NodeRemoved { path, class_name } => write!(f, "- {path} ({class_name})"),
+            Self::ClassChanged { path, old, new } => write!(f, "~ {path}: class {old} -> {new}"),
+            Self::PropertyAdded {
+                path,
+                property,
+                value,
+            } => write!(f, "~ {path}: {property} = {value}"),
+            Self::PropertyRemoved {
+                path,
+                property,
+                value,
+            } => write!(f, "~ {path}: {property} = {value} -> (default)"),
+            Self::PropertyChanged {
+                path,
+                property,
+                old,
+                new,
+            } => write!(f, "~ {path}: {property} = {old} -> {new}"),
+            Self::ConnectionAdded(connection) => write!(f, "+ {connection}"),
+            Self::ConnectionRemoved(connection) => write!(f, "- {connection}"),
+        }
+    }
+}
+
+/// Signal connection stored in a packed scene. Paths are relative to the scene root.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct SceneConnection {
+    pub source: String,
+    pub signal: String,
+    pub target: String,
+    pub method: String,
+}
+
+impl fmt::Display for SceneConnection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            source,
+            signal,
+            target,
+            method,
+        } = self;
+
+        write!(f, "connection {source}.{signal} -> {target}.{method}")
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Errors
+
+/// Error returned by [`SceneBuilder::build()`] and [`SceneBuilder::instantiate()`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct SceneBuildError {
+    node_path: String,
+    kind: SceneBuildErrorKind,
+}
+
+impl SceneBuildError {
+    /// Path of the offending node, starting with the name of the root, e.g. `"Menu/Buttons/Start"`.
+    pub fn node_path(&self) -> &str {
+        &self.node_path
+    }
+
+    /// What went wrong.
+    pub fn kind(&self) -> &SceneBuildErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for SceneBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "node `{}`: {}", self.node_path, self.kind)
+    }
+}
+
+impl Error for SceneBuildError {}
+
+/// Kind of [`SceneBuildError`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum SceneBuildErrorKind {
+    /// No class with this name is registered.
+    UnknownClass { class_name: String },
+
+    /// The class does not inherit `Node`.
+    NotANode { class_name: String },
+
+    /// The class is abstract.
+    NotInstantiable { class_name: String },
+
+    /// Two children of the same parent have the same name.
+    DuplicateName { name: String },
+
+    /// The node has no property with this name.
+    UnknownProperty {
+        class_name: String,
+        property: String,
+    },
+
+    /// The value has a different type than the property.
+    PropertyTypeMismatch {
+        property: String,
+        expected: VariantType,
+        actual: VariantType,
+    },
+
+    /// The node has no signal with this name.
+    UnknownSignal { signal: String },
+
+    /// No node exists at the connection's target path.
+    TargetNotFound { target: String },
+
+    /// The connection's target node has no method with this name.
+    UnknownMethod { target: String, method: String },
+
+    /// `PackedScene::pack()` failed.
+    PackFailed(global::Error),
+}
+
+impl fmt::Display for SceneBuildErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownClass { class_name } => write!(f, "unknown class {class_name}"),
+            Self::NotANode { class_name } => write!(f, "class {class_name} does not inherit Node"),
+            Self::NotInstantiable { class_name } => {
+                write!(f, "class {class_name} cannot be instantiated")
+            }
+            Self::DuplicateName { name } => write!(f, "duplicate child name `{name}`"),
+            Self::UnknownProperty {
+                class_name,
+                property,
+            } => write!(f, "class {class_name} has no property `{property}`"),
+            Self::PropertyTypeMismatch {
+                property,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "property `{property}` has type {expected:?}, but value has type {actual:?}"
+            ),
+            Self::UnknownSignal { signal } => write!(f, "no signal `{signal}`"),
+            Self::TargetNotFound { target } => write!(f, "connection target `{target}` not found"),
+            Self::UnknownMethod { target, method } => {
+                write!(f, "connection target `{target}` has no method `{method}`")
+            }
+            Self::PackFailed(err) => write!(f, "packing failed with {err:?}"),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation: building
+
+fn create_node(spec: &NodeSpec, path: &str) -> Result<Gd<Node>, SceneBuildError> {
+    let error = |kind| SceneBuildError {
+        node_path: path.to_string(),
+        kind,
+    };
+
+    let class_name = spec.class_name.to_string();
+    if !class_db::class_exists(&class_name) {
+        return Err(error(SceneBuildErrorKind::UnknownClass { class_name }));
+    }
+    if !class_db::class_inherits(&class_name, "Node") {
+        return Err(error(SceneBuildErrorKind::NotANode { class_name }));
+    }
+    if !class_db::can_instantiate(&class_name) {
+        return Err(error(SceneBuildErrorKind::NotInstantiable { class_name }));
+    }
+
+    let mut node = ClassDb::singleton()
+        .instantiate(spec.class_name.clone())
+        .to::<Gd<Node>>();
+    node.set_name(spec.name.to_string().into());
+
+    if let Err(kind) = apply_properties(&mut node, spec) {
+        node.free();
+        return Err(error(kind));
+    }
+
+    for group in &spec.groups {
+        node.add_to_group_ex(group.clone()).persistent(true).done();
+    }
+
+    Ok(node)
+}
+
+fn apply_properties(node: &mut Gd<Node>, spec: &NodeSpec) -> Result<(), SceneBuildErrorKind> {
+    let property_list = node.get_property_list();
+
+    for (property, value) in &spec.properties {
+        let info = property_list.iter_shared().find(|info| {
+            info.get("name")
+                .is_some_and(|name| name.stringify().to_string() == property.to_string())
+        });
+
+        let Some(info) = info else {
+            return Err(SceneBuildErrorKind::UnknownProperty {
+                class_name: spec.class_name.to_string(),
+                property: property.to_string(),
+            });
+        };
+
+        let expected = info
+            .get("type")
+            .map_or(VariantType::NIL, |ty| VariantType::from_ord(ty.to::<i32>()));
+        let actual = value.get_type();
+
+        if !is_compatible(expected, actual) {
+            return Err(SceneBuildErrorKind::PropertyTypeMismatch {
+                property: property.to_string(),
+                expected,
+                actual,
+            });
+        }
+
+        node.set(property.clone(), value.clone());
+    }
+
+    Ok(())
+}
+
+/// Whether a value of type `actual` can be assigned to a property of type `expected` without losing it.
+fn is_compatible(expected: VariantType, actual: VariantType) -> bool {
+    use VariantType as T;
+
+    // NIL: property typed as Variant. Godot converts int to float on assignment. Object properties accept null.
+    expected == actual
+        || expected == T::NIL
+        || (expected, actual) == (T::FLOAT, T::INT)
+        || (expected, actual) == (T::OBJECT, T::NIL)
+}
+
+/// Creates the children of `node` recursively, and makes `owner` their owner.
+fn create_children(
+    owner: &Gd<Node>,
+    node: &Gd<Node>,
+    spec: &NodeSpec,
+    path: &str,
+) -> Result<(), SceneBuildError> {
+    let mut names = Vec::with_capacity(spec.children.len());
+    let mut node = node.clone();
+
+    for child_spec in &spec.children {
+        let child_path = format!("{path}/{}", child_spec.name);
+
+        if names.contains(&child_spec.name) {
+            return Err(SceneBuildError {
+                node_path: child_path,
+                kind: SceneBuildErrorKind::DuplicateName {
+                    name: child_spec.name.to_string(),
+                },
+            });
+        }
+        names.push(child_spec.name.clone());
+
+        // Once added as a child, the node is freed together with the root in case of errors.
+        let mut child = create_node(child_spec, &child_path)?;
+        node.add_child(child.clone());
+        child.set_owner(owner.clone());
+
+        create_children(owner, &child, child_spec, &child_path)?;
+    }
+
+    Ok(())
+}
+
+/// Makes the connections of `node` and all its descendants.
+fn connect_all(node: &Gd<Node>, spec: &NodeSpec, path: &str) -> Result<(), SceneBuildError> {
+    let error = |kind| SceneBuildError {
+        node_path: path.to_string(),
+        kind,
+    };
+
+    let mut source = node.clone();
+    for connection in &spec.connections {
+        if !source.has_signal(connection.signal.clone()) {
+            return Err(error(SceneBuildErrorKind::UnknownSignal {
+                signal: connection.signal.to_string(),
+            }));
+        }
+
+        let Some(target) = source.get_node_or_null(connection.target.clone()) else {
+            return Err(error(SceneBuildErrorKind::TargetNotFound {
+                target: connection.target.to_string(),
+            }));
+        };
+
+        if !target.has_method(connection.method.clone()) {
+            return Err(error(SceneBuildErrorKind::UnknownMethod {
+                target: connection.target.to_string(),
+                method: connection.method.to_string(),
+            }));
+        }
+
+        // PERSIST: saved in the packed scene, like connections made in the editor.
+        let callable = Callable::from_object_method(&target, connection.method.clone());
+        source
+            .connect_ex(connection.signal.clone(), callable)
+            .flags(ConnectFlags::PERSIST)
+            .done();
+    }
+
+    // Look up by name, as the node's own logic may have added further children that shift indices.
+    for child_spec in &spec.children {
+        let child = node
+            .get_node_or_null(NodePath::from(&child_spec.name))
+            .expect("child was added by create_children()");
+
+        connect_all(&child, child_spec, &format!("{path}/{}", child_spec.name))?;
+    }
+
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation: diffing
+
+struct NodeData {
+    path: String,
+    class_name: String,
+    properties: Vec<(String, Variant)>,
+}
+
+struct SceneData {
+    nodes: Vec<NodeData>,
+    connections: Vec<SceneConnection>,
+}
+
+impl SceneData {
+    fn read(state: &Gd<SceneState>) -> Self {
+        let nodes = (0..state.get_node_count())
+            .map(|index| NodeData {
+                path: relative_path(state.get_node_path(index)),
+                class_name: state.get_node_type(index).to_string(),
+                properties: (0..state.get_node_property_count(index))
+                    .map(|prop| {
+                        (
+                            state.get_node_property_name(index, prop).to_string(),
+                            state.get_node_property_value(index, prop),
+                        )
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let connections = (0..state.get_connection_count())
+            .map(|index| SceneConnection {
+                source: relative_path(state.get_connection_source(index)),
+                signal: state.get_connection_signal(index).to_string(),
+                target: relative_path(state.get_connection_target(index)),
+                method: state.get_connection_method(index).to_string(),
+            })
+            .collect();
+
+        Self { nodes, connections }
+    }
+
+    fn find(&self, path: &str) -> Option<&NodeData> {
+        self.nodes.iter().find(|node| node.path == path)
+    }
+}
+
+/// Normalizes a path as returned by `SceneState` (e.g. `"."`, `"Child"` or `"./Child"`) to `"."` or `"Child/Grandchild"`.
+fn relative_path(path: NodePath) -> String {
+    let path = path.to_string();
+    let path = path.strip_prefix("./").unwrap_or(&path);
+
+    if path.is_empty() {
+        ".".to_string()
+    } else {
+        path.to_string()
+    }
+}
+
+fn diff_properties(old: &NodeData, new: &NodeData, changes: &mut Vec<SceneChange>) {
+    let old_properties: HashMap<&str, &Variant> = old
+        .properties
+        .iter()
+        .map(|(name, value)| (name.as_str(), value))
+        .collect();
+
+    for (name, old_value) in &old.properties {
+        if !new.properties.iter().any(|(new_name, _)| new_name == name) {
+            changes.push(SceneChange::PropertyRemoved {
+                path: new.path.clone(),
+                property: name.clone(),
+                value: old_value.clone(),
+            });
+        }
+    }
+
+    for (name, new_value) in &new.properties {
+        match old_properties.get(name.as_str()) {
+            None => changes.push(SceneChange::PropertyAdded {
+                path: new.path.clone(),
+                property: name.clone(),
+                value: new_value.clone(),
+            }),
+            Some(old_value) if *old_value != new_value => {
+                changes.push(SceneChange::PropertyChanged {
+                    path: new.path.clone(),
+                    property: name.clone(),
+                    old: (*old_value).clone(),
+                    new: new_value.clone(),
+                })
+            }
+            Some(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compatible_types() {
+        assert!(is_compatible(VariantType::FLOAT, VariantType::INT));
+        assert!(is_compatible(VariantType::NIL, VariantType::STRING));
+        assert!(is_compatible(VariantType::VECTOR2, VariantType::VECTOR2));
+        assert!(!is_compatible(VariantType::INT, VariantType::FLOAT));
+        assert!(!is_compatible(
+            VariantType::STRING,
+            VariantType::STRING_NAME
+        ));
+    }
+}
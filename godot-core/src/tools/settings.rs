@@ -0,0 +1,367 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::{Dictionary, GString, Variant};
+use crate::classes::ProjectSettings;
+use crate::meta::error::ConvertError;
+use crate::meta::{FromGodot, GodotType, ToGodot};
+use crate::obj::{EngineEnum, Gd};
+use crate::registry::property::{PropertyHintInfo, Var};
+use crate::sys::GodotFfi;
+
+#[cfg(since_api = "4.2")]
+use crate::classes::{EditorInterface, EditorSettings};
+
+/// Where a [`Setting`] is stored.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum SettingScope {
+    /// Stored in `project.godot`, shared by everyone working on the project, and available in exported games.
+    Project,
+
+    /// Stored in the user's editor settings; only available when running inside the editor.
+    #[cfg(since_api = "4.2")]
+    Editor,
+}
+
+/// Typed handle to a project or editor setting, with a default value.
+///
+/// Declare the setting once, call [`register()`][Self::register] during initialization (so it shows up in the settings dialog),
+/// then read and write it through [`get()`][Self::get] and [`set()`][Self::set] without dealing with variants.
+///
+/// # Example
+/// ```no_run
+/// use godot::tools::Setting;
+/// use godot::register::property::export_info_functions::export_range;
+///
+/// fn max_enemies() -> Setting<i32> {
+///     Setting::project("my_extension/gameplay/max_enemies", 32)
+///         .with_hint(export_range(1.0, 256.0, Some(1.0), false, false, false, false, false, false))
+///         .basic()
+/// }
+///
+/// // In ExtensionLibrary::on_level_init(InitLevel::Scene):
+/// max_enemies().register();
+///
+/// // Anywhere:
+/// let count: i32 = max_enemies().get();
+/// ```
+pub struct Setting<T> {
+    name: GString,
+    scope: SettingScope,
+    default: T,
+    hint: PropertyHintInfo,
+    is_basic: bool,
+    restart_if_changed: bool,
+}
+
+impl<T> Setting<T>
+where
+    T: ToGodot + FromGodot + Var + Clone,
+{
+    /// Declares a project setting with the given path (e.g. `"my_extension/audio/volume"`) and default value.
+    pub fn project(name: impl Into<GString>, default: T) -> Self {
+        Self::new(SettingScope::Project, name.into(), default)
+    }
+
+    /// Declares an editor setting with the given path and default value.
+    ///
+    /// Editor settings are only available when running inside the editor. Accessing them elsewhere panics.
+    #[cfg(since_api = "4.2")]
+    pub fn editor(name: impl Into<GString>, default: T) -> Self {
+        Self::new(SettingScope::Editor, name.into(), default)
+    }
+
+    fn new(scope: SettingScope, name: GString, default: T) -> Self {
+        Self {
+            name,
+            scope,
+            default,
+            hint: T::property_hint(),
+            is_basic: false,
+            restart_if_changed: false,
+        }
+    }
+
+    /// Sets the hint shown in the settings dialog, e.g. a range or an enum. By default, the hint of `T` is used.
+    pub fn with_hint(mut self, hint: PropertyHintInfo) -> Self {
+        self.hint = hint;
+        self
+    }
+
+    /// Shows the setting without having to enable "Advanced Settings". Only affects project settings.
+    pub fn basic(mut self) -> Self {
+        self.is_basic = true;
+        self
+    }
+
+    /// Asks the user to restart the editor when the setting is changed. Only affects project settings.
+    pub fn restart_if_changed(mut self) -> Self {
+        self.restart_if_changed = true;
+        self
+    }
+
+    /// Path of the setting.
+    pub fn name(&self) -> &GString {
+        &self.name
+    }
+
+    /// Where the setting is stored.
+    pub fn scope(&self) -> SettingScope {
+        self.scope
+    }
+
+    /// The default value.
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// Adds the setting to the project or editor settings, if not yet present, and registers its default and hint.
+    ///
+    /// Values already stored (e.g. in `project.godot`) are kept. Calling this again is harmless.
+    pub fn register(&self) {
+        let default = self.default.to_variant();
+
+        let mut info = Dictionary::new();
+        info.set("name", self.name.clone());
+        info.set("type", <T::Via as GodotType>::Ffi::variant_type().ord());
+        info.set("hint", self.hint.hint.ord());
+        info.set("hint_string", self.hint.hint_string.clone());
+
+        match self.backend() {
+            Backend::Project(mut settings) => {
+                if !settings.has_setting(self.name.clone()) {
+                    settings.set_setting(self.name.clone(), default.clone());
+                }
+
+                settings.set_initial_value(self.name.clone(), default);
+                settings.add_property_info(info);
+                settings.set_as_basic(self.name.clone(), self.is_basic);
+                settings.set_restart_if_changed(self.name.clone(), self.restart_if_changed);
+            }
+
+            #[cfg(since_api = "4.2")]
+            Backend::Editor(mut settings) => {
+                if !settings.has_setting(self.name.clone()) {
+                    settings.set_setting(self.name.clone(), default.clone());
+                }
+
+                settings.set_initial_value(self.name.clone().into(), default, false);
+                settings.add_property_info(info);
+            }
+        }
+    }
+
+    /// Returns the current value, or the default if the setting is missing or has an incompatible type.
+    pub fn get(&self) -> T {
+        self.try_get().unwrap_or_else(|_| self.default.clone())
+    }
+
+    /// Returns the current value, or an error if the stored value cannot be converted to `T`.
+    ///
+    /// If the setting is missing, the default is returned.
+    pub fn try_get(&self) -> Result<T, ConvertError> {
+        match self.get_variant() {
+            Some(value) => value.try_to::<T>(),
+            None => Ok(self.default.clone()),
+        }
+    }
+
+    /// Changes the value. For project settings, call `ProjectSettings::save()` to persist the change.
+    pub fn set(&self, value: T) {
+        let value = value.to_variant();
+
+        match self.backend() {
+            Backend::Project(mut settings) => settings.set_setting(self.name.clone(), value),
+            #[cfg(since_api = "4.2")]
+            Backend::Editor(mut settings) => settings.set_setting(self.name.clone(), value),
+        }
+    }
+
+    /// Sets the value back to the default.
+    pub fn reset(&self) {
+        self.set(self.default.clone());
+    }
+
+    /// Whether the current value differs from the default.
+    pub fn is_changed(&self) -> bool {
+        self.get_variant()
+            .is_some_and(|value| value != self.default.to_variant())
+    }
+
+    fn get_variant(&self) -> Option<Variant> {
+        match self.backend() {
+            Backend::Project(settings) => settings
+                .has_setting(self.name.clone())
+                .then(|| settings.get_setting(self.name.clone())),
+
+            #[cfg(since_api = "4.2")]
+            Backend::Editor(settings) => settings
+                .has_setting(self.name.clone())
+                .then(|| settings.get_setting(self.name.clone())),
+        }
+    }
+
+    fn backend(&self) -> Backend {
+        match self.scope {
+            SettingScope::Project => Backend::Project(ProjectSettings::singleton()),
+
+            #[cfg(since_api = "4.2")]
+            SettingScope::Editor => {
+                let settings = EditorInterface::singleton()
+                    .get_editor_settings()
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "editor setting '{}' is only available inside the editor",
+                            self.name
+                        )
+                    });
+
+                Backend::Editor(settings)
+            }
+        }
+    }
+}
+
+#[cfg(since_api = "4.2")]
+impl<T> Setting<T>
+where
+    T: ToGodot + FromGodot + Var + Clone + 'static,
+{
+    /// Invokes `callback` with the new value whenever the setting changes.
+    ///
+    /// Godot notifies about changes to _any_ setting; the callback only runs if the value of this one differs from the last seen value.
+    /// The returned handle can be used to unsubscribe; dropping it does not.
+    ///
+    /// # Panics
+    /// If not called on the main thread.
+    pub fn on_changed(&self, mut callback: impl FnMut(T) + 'static) -> SettingSubscription {
+        let setting = self.clone();
+        let mut last_value = setting.get_variant();
+
+        subscriptions::subscribe(
+            self.scope,
+            Box::new(move || {
+                let value = setting.get_variant();
+                if value != last_value {
+                    last_value = value;
+                    callback(setting.get());
+                }
+            }),
+        )
+    }
+}
+
+impl<T: Clone> Clone for Setting<T> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            scope: self.scope,
+            default: self.default.clone(),
+            hint: self.hint.clone(),
+            is_basic: self.is_basic,
+            restart_if_changed: self.restart_if_changed,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Setting<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Setting")
+            .field("name", &format_args!("{}", self.name))
+            .field("scope", &self.scope)
+            .field("default", &self.default)
+            .finish()
+    }
+}
+
+enum Backend {
+    Project(Gd<ProjectSettings>),
+    #[cfg(since_api = "4.2")]
+    Editor(Gd<EditorSettings>),
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Change notifications
+
+/// Handle returned by [`Setting::on_changed()`], to stop receiving notifications.
+#[cfg(since_api = "4.2")]
+#[derive(Eq, PartialEq, Hash, Debug)]
+pub struct SettingSubscription {
+    scope: SettingScope,
+    id: u64,
+}
+
+#[cfg(since_api = "4.2")]
+impl SettingSubscription {
+    /// Stops invoking the callback. Returns `false` if it has already been unsubscribed.
+    pub fn unsubscribe(self) -> bool {
+        subscriptions::unsubscribe(self.scope, self.id)
+    }
+}
+
+#[cfg(since_api = "4.2")]
+mod subscriptions {
+    use std::cell::RefCell;
+
+    use super::{Backend, SettingScope, SettingSubscription};
+    use crate::builtin::{Callable, Variant};
+    use crate::classes::{EditorInterface, ProjectSettings};
+    use crate::tools::subscribers::{self, Registry, Subscribers};
+
+    type Callback = Box<dyn FnMut()>;
+
+    thread_local! {
+        static PROJECT_SUBSCRIBERS: RefCell<Subscribers<Callback>> = RefCell::default();
+        static EDITOR_SUBSCRIBERS: RefCell<Subscribers<Callback>> = RefCell::default();
+    }
+
+    fn registry(scope: SettingScope) -> &'static Registry<Callback> {
+        match scope {
+            SettingScope::Project => &PROJECT_SUBSCRIBERS,
+            SettingScope::Editor => &EDITOR_SUBSCRIBERS,
+        }
+    }
+
+    pub(super) fn subscribe(scope: SettingScope, callback: Callback) -> SettingSubscription {
+        let (id, needs_connect) =
+            subscribers::subscribe(registry(scope), "setting subscriptions", callback);
+
+        if needs_connect {
+            connect(scope);
+        }
+
+        SettingSubscription { scope, id }
+    }
+
+    pub(super) fn unsubscribe(scope: SettingScope, id: u64) -> bool {
+        subscribers::unsubscribe(registry(scope), id)
+    }
+
+    fn connect(scope: SettingScope) {
+        let callable = Callable::from_fn("settings_changed", move |_args: &[&Variant]| {
+            // Callbacks can subscribe/unsubscribe or change settings while being invoked.
+            subscribers::dispatch(registry(scope), |callback| callback());
+            Ok(Variant::nil())
+        });
+
+        let backend = match scope {
+            SettingScope::Project => Backend::Project(ProjectSettings::singleton()),
+            SettingScope::Editor => Backend::Editor(
+                EditorInterface::singleton()
+                    .get_editor_settings()
+                    .expect("editor settings are only available inside the editor"),
+            ),
+        };
+
+        match backend {
+            Backend::Project(mut settings) => settings.connect("settings_changed".into(), callable),
+            Backend::Editor(mut settings) => settings.connect("settings_changed".into(), callable),
+        };
+    }
+}
@@ -0,0 +1,298 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Typed access to the uniforms of a [`ShaderMaterial`].
+//!
+//! `ShaderMaterial::set_shader_parameter()` takes uniform names as strings and values as variants. Typos and type mismatches are
+//! silently ignored by Godot, which makes them hard to track down. The [`shader_params!`] macro declares a wrapper around a material with
+//! one typed getter and setter per uniform. Names are converted to `StringName` once, and in debug builds, the declared uniforms are
+//! checked against the material's shader when the wrapper is created.
+//!
+//! # Example
+//! ```no_run
+//! use godot::prelude::*;
+//! use godot::classes::ShaderMaterial;
+//! use godot::tools::shader::{shader_params, ShaderParams};
+//!
+//! shader_params! {
+//!     /// Uniforms of `water.gdshader`.
+//!     pub struct WaterParams {
+//!         wave_speed: f32,
+//!         foam_color: Color,
+//!     }
+//! }
+//!
+//! fn make_stormy(material: Gd<ShaderMaterial>) {
+//!     let mut water = WaterParams::from_material(material);
+//!     water.set_wave_speed(water.wave_speed() * 2.0);
+//!     water.set_foam_color(Color::WHITE_SMOKE);
+//! }
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::{StringName, VariantType};
+use crate::classes::{RenderingServer, ShaderMaterial};
+use crate::meta::{FromGodot, GodotType, ToGodot};
+use crate::obj::{EngineEnum, Gd};
+use crate::sys::GodotFfi;
+
+pub use crate::shader_params;
+
+/// Name and type of one shader uniform, as declared in [`shader_params!`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ShaderParamInfo {
+    /// Name of the uniform in the shader code.
+    pub name: &'static str,
+
+    /// Variant type that values are converted to before being passed to Godot.
+    pub variant_type: VariantType,
+}
+
+/// Typed wrapper around a [`ShaderMaterial`], usually declared with [`shader_params!`].
+pub trait ShaderParams: Sized {
+    /// All uniforms accessed by this wrapper.
+    fn param_list() -> Vec<ShaderParamInfo>;
+
+    /// Wraps `material` without validating it.
+    fn from_material_unchecked(material: Gd<ShaderMaterial>) -> Self;
+
+    /// The wrapped material.
+    fn material(&self) -> &Gd<ShaderMaterial>;
+
+    /// Wraps `material`.
+    ///
+    /// # Panics
+    /// In debug builds, if [`validate()`][Self::validate] fails.
+    fn from_material(material: Gd<ShaderMaterial>) -> Self {
+        let params = Self::from_material_unchecked(material);
+
+        #[cfg(debug_assertions)]
+        if let Err(err) = params.validate() {
+            panic!("{err}");
+        }
+
+        params
+    }
+
+    /// Checks that the material's shader declares all uniforms, with compatible types.
+    ///
+    /// Succeeds if the material has no shader (yet), since there is nothing to check against.
+    fn validate(&self) -> Result<(), ShaderParamError> {
+        validate_params(self.material(), &Self::param_list())
+    }
+}
+
+/// Declares a typed wrapper around a [`ShaderMaterial`], implementing [`ShaderParams`].
+///
+/// Each field `name: Type` corresponds to the uniform `name` in the shader, and generates a getter `name()` and a setter `set_name()`.
+/// Field types must implement `ToGodot` and `FromGodot`.
+///
+/// Getters return the value set on the material, or the default value declared in the shader if none was set. They panic if the uniform
+/// doesn't exist or holds a value of another type.
+///
+/// See the [module docs](crate::tools::shader) for an example.
+#[macro_export]
+macro_rules! shader_params {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $Name:ident {
+            $(
+                $(#[$field_attr:meta])*
+                $field:ident : $Ty:ty
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $Name {
+            __material: $crate::obj::Gd<$crate::classes::ShaderMaterial>,
+            $( $field: $crate::builtin::StringName, )*
+        }
+
+        $crate::sys::paste::paste! {
+            impl $Name {
+                $(
+                    $(#[$field_attr])*
+                    #[doc = concat!("Returns the value of the `", stringify!($field), "` uniform.")]
+                    pub fn $field(&self) -> $Ty {
+                        $crate::tools::shader::__get_param(&self.__material, &self.$field)
+                    }
+
+                    #[doc = concat!("Sets the value of the `", stringify!($field), "` uniform.")]
+                    pub fn [<set_ $field>](&mut self, value: $Ty) {
+                        $crate::tools::shader::__set_param(&mut self.__material, &self.$field, value)
+                    }
+                )*
+            }
+        }
+
+        impl $crate::tools::shader::ShaderParams for $Name {
+            fn param_list() -> ::std::vec::Vec<$crate::tools::shader::ShaderParamInfo> {
+                ::std::vec![
+                    $(
+                        $crate::tools::shader::ShaderParamInfo {
+                            name: stringify!($field),
+                            variant_type: $crate::tools::shader::__variant_type::<$Ty>(),
+                        },
+                    )*
+                ]
+            }
+
+            fn from_material_unchecked(material: $crate::obj::Gd<$crate::classes::ShaderMaterial>) -> Self {
+                Self {
+                    __material: material,
+                    $( $field: $crate::builtin::StringName::from(stringify!($field)), )*
+                }
+            }
+
+            fn material(&self) -> &$crate::obj::Gd<$crate::classes::ShaderMaterial> {
+                &self.__material
+            }
+        }
+
+        impl ::std::fmt::Debug for $Name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.debug_struct(stringify!($Name))
+                    .field("material", &self.__material)
+                    .finish()
+            }
+        }
+    };
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Errors
+
+/// Mismatch between the uniforms declared in Rust and the ones in a shader, as returned by [`ShaderParams::validate()`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum ShaderParamError {
+    /// The shader has no uniform with this name.
+    Missing { name: String, shader_path: String },
+
+    /// The shader declares the uniform with a type that Rust values cannot be converted to.
+    TypeMismatch {
+        name: String,
+        shader_path: String,
+        expected: VariantType,
+        actual: VariantType,
+    },
+}
+
+impl fmt::Display for ShaderParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing { name, shader_path } => {
+                write!(f, "shader '{shader_path}' has no uniform `{name}`")
+            }
+            Self::TypeMismatch {
+                name,
+                shader_path,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "uniform `{name}` in shader '{shader_path}' has type {actual:?}, but is declared as {expected:?} in Rust"
+            ),
+        }
+    }
+}
+
+impl Error for ShaderParamError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+fn validate_params(
+    material: &Gd<ShaderMaterial>,
+    params: &[ShaderParamInfo],
+) -> Result<(), ShaderParamError> {
+    let Some(shader) = material.get_shader() else {
+        return Ok(());
+    };
+
+    let shader_path = shader.get_path().to_string();
+    let uniforms = shader.get_shader_uniform_list();
+
+    for param in params {
+        let uniform = uniforms.iter_shared().find(|uniform| {
+            uniform
+                .get("name")
+                .is_some_and(|name| name.stringify().to_string() == param.name)
+        });
+
+        let Some(uniform) = uniform else {
+            return Err(ShaderParamError::Missing {
+                name: param.name.to_string(),
+                shader_path,
+            });
+        };
+
+        let actual = uniform
+            .get("type")
+            .map_or(VariantType::NIL, |ty| VariantType::from_ord(ty.to::<i32>()));
+
+        if !is_compatible(param.variant_type, actual) {
+            return Err(ShaderParamError::TypeMismatch {
+                name: param.name.to_string(),
+                shader_path,
+                expected: param.variant_type,
+                actual,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether Godot accepts a value of type `rust` for a uniform of type `shader`.
+fn is_compatible(rust: VariantType, shader: VariantType) -> bool {
+    use VariantType as T;
+
+    if rust == shader {
+        return true;
+    }
+
+    matches!(
+        (rust, shader),
+        // Numbers are converted; `vec3`/`vec4` uniforms with `source_color` are reported as COLOR, but accept vectors and vice versa.
+        (T::INT, T::FLOAT)
+            | (T::FLOAT, T::INT)
+            | (T::COLOR, T::VECTOR3 | T::VECTOR4)
+            | (T::VECTOR3 | T::VECTOR4, T::COLOR)
+    )
+}
+
+#[doc(hidden)]
+pub fn __variant_type<T: ToGodot>() -> VariantType {
+    <T::Via as GodotType>::Ffi::variant_type()
+}
+
+#[doc(hidden)]
+pub fn __get_param<T: FromGodot>(material: &Gd<ShaderMaterial>, name: &StringName) -> T {
+    let mut value = material.get_shader_parameter(name.clone());
+
+    // Parameters that were never set are nil; fall back to the default declared in the shader.
+    if value.is_nil() {
+        if let Some(shader) = material.get_shader() {
+            value = RenderingServer::singleton()
+                .shader_get_parameter_default(shader.get_rid(), name.clone());
+        }
+    }
+
+    value.try_to::<T>().unwrap_or_else(|err| {
+        panic!(
+            "shader uniform `{name}` cannot be read as {}: {err}",
+            std::any::type_name::<T>()
+        )
+    })
+}
+
+#[doc(hidden)]
+pub fn __set_param<T: ToGodot>(material: &mut Gd<ShaderMaterial>, name: &StringName, value: T) {
+    material.set_shader_parameter(name.clone(), value.to_variant());
+}
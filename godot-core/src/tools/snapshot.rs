@@ -0,0 +1,449 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Structural tests for scene trees.
+//!
+//! A [`SceneSnapshot`] captures a node subtree -- names, classes and selected properties -- in a stable, line-based text format. Tests
+//! compare it against an expected snapshot, which is either inlined or stored in a file next to the test. On mismatch, the assertion
+//! message contains a line diff, so regressions in tool code or UI construction are visible without opening the editor.
+//!
+//! ```no_run
+//! use godot::prelude::*;
+//! use godot::tools::snapshot::{assert_scene_valid, SceneSnapshot};
+//!
+//! fn check_menu(menu: Gd<Node>) {
+//!     assert_scene_valid!(menu);
+//!
+//!     SceneSnapshot::options()
+//!         .property("text")
+//!         .property("visible")
+//!         .capture(&menu)
+//!         .assert_matches(
+//!             r#"
+//!             Menu (VBoxContainer) visible=true
+//!               Title (Label) text="Main menu" visible=true
+//!               Start (Button) text="Start" visible=true
+//!             "#,
+//!         );
+//! }
+//! ```
+//!
+//! Snapshot files are created by [`SceneSnapshot::assert_matches_file()`] on first use. To accept changes after an intentional
+//! modification, set the environment variable `GODOT_RUST_UPDATE_SNAPSHOTS=1` and run the tests again, which overwrites the files.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::io::{Read, Write};
+
+use crate::builtin::{NodePath, VariantArray, VariantType};
+use crate::classes::file_access::ModeFlags;
+use crate::classes::{FileAccess, Node};
+use crate::obj::{EngineEnum as _, Gd};
+use crate::tools::GFile;
+
+/// Environment variable which makes [`SceneSnapshot::assert_matches_file()`] overwrite stored snapshots.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "GODOT_RUST_UPDATE_SNAPSHOTS";
+
+/// Textual representation of a node subtree, for comparison in tests.
+///
+/// Each node occupies one line, indented by two spaces per level: `Name (Class) prop=value ...`. Properties are listed in the order in
+/// which they were requested, and only for nodes that have them. Strings are quoted; other values use Godot's `str()` representation.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct SceneSnapshot {
+    text: String,
+}
+
+impl SceneSnapshot {
+    /// Captures the subtree of `root` with default options: names and classes only, without internal children.
+    pub fn capture(root: &Gd<Node>) -> Self {
+        Self::options().capture(root)
+    }
+
+    /// Returns a builder to customize which information is captured.
+    pub fn options() -> SnapshotOptions {
+        SnapshotOptions::default()
+    }
+
+    /// Creates a snapshot from its text representation, e.g. as stored in a file.
+    ///
+    /// Common indentation and surrounding blank lines are removed, so that expected snapshots can be written as indented raw strings.
+    pub fn from_text(text: &str) -> Self {
+        Self {
+            text: normalize(text),
+        }
+    }
+
+    /// The text representation, with one line per node and a trailing newline.
+    pub fn as_text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns a line diff between `self` and `expected`, or `None` if they are equal.
+    ///
+    /// Lines only in `expected` are prefixed with `-`, lines only in `self` with `+`.
+    pub fn diff(&self, expected: &SceneSnapshot) -> Option<String> {
+        if self == expected {
+            return None;
+        }
+
+        Some(line_diff(&expected.text, &self.text))
+    }
+
+    /// ⚠️ Asserts that the snapshot matches `expected` (see [`from_text()`][Self::from_text] for the accepted format).
+    ///
+    /// # Panics
+    /// If the snapshots differ. The message contains a line diff.
+    #[track_caller]
+    pub fn assert_matches(&self, expected: &str) {
+        if let Some(diff) = self.diff(&Self::from_text(expected)) {
+            panic!("scene snapshot does not match (- expected, + actual):\n{diff}");
+        }
+    }
+
+    /// ⚠️ Asserts that the snapshot matches the one stored in the file at `path` (e.g. `res://tests/snapshots/menu.txt`).
+    ///
+    /// If the file does not exist, or the environment variable [`GODOT_RUST_UPDATE_SNAPSHOTS`][UPDATE_SNAPSHOTS_ENV] is set to `1`,
+    /// the snapshot is written to the file instead.
+    ///
+    /// # Panics
+    /// If the snapshots differ, or the file cannot be read or written.
+    #[track_caller]
+    pub fn assert_matches_file(&self, path: &str) {
+        let update = std::env::var(UPDATE_SNAPSHOTS_ENV).is_ok_and(|value| value == "1");
+
+        if update || !FileAccess::file_exists(path.into()) {
+            let mut file = GFile::open(path, ModeFlags::WRITE)
+                .unwrap_or_else(|err| panic!("cannot write scene snapshot `{path}`: {err}"));
+            file.write_all(self.text.as_bytes())
+                .unwrap_or_else(|err| panic!("cannot write scene snapshot `{path}`: {err}"));
+            return;
+        }
+
+        let mut text = String::new();
+        GFile::open(path, ModeFlags::READ)
+            .and_then(|mut file| file.read_to_string(&mut text))
+            .unwrap_or_else(|err| panic!("cannot read scene snapshot `{path}`: {err}"));
+
+        if let Some(diff) = self.diff(&Self::from_text(&text)) {
+            panic!(
+                "scene snapshot does not match `{path}` (- expected, + actual):\n{diff}\n\
+                To accept the new snapshot, re-run with {UPDATE_SNAPSHOTS_ENV}=1."
+            );
+        }
+    }
+}
+
+impl fmt::Display for SceneSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// Builder for [`SceneSnapshot`], see [`SceneSnapshot::options()`].
+#[derive(Clone, Default, Debug)]
+pub struct SnapshotOptions {
+    properties: Vec<String>,
+    include_internal: bool,
+    max_depth: Option<usize>,
+}
+
+impl SnapshotOptions {
+    /// Includes the property `name` for every node that has it.
+    pub fn property(mut self, name: impl Into<String>) -> Self {
+        self.properties.push(name.into());
+        self
+    }
+
+    /// Includes internal children, e.g. the scroll bars of a `ScrollContainer`. Default: `false`.
+    pub fn include_internal(mut self, include: bool) -> Self {
+        self.include_internal = include;
+        self
+    }
+
+    /// Only captures nodes up to `depth` levels below the root. Default: unlimited.
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Captures the subtree of `root`.
+    pub fn capture(&self, root: &Gd<Node>) -> SceneSnapshot {
+        let mut text = String::new();
+        self.capture_node(root, 0, &mut text);
+
+        SceneSnapshot { text }
+    }
+
+    fn capture_node(&self, node: &Gd<Node>, depth: usize, text: &mut String) {
+        use std::fmt::Write as _;
+
+        let indent = "  ".repeat(depth);
+        let _ = write!(text, "{indent}{} ({})", node.get_name(), node.get_class());
+
+        if !self.properties.is_empty() {
+            let available = node
+                .get_property_list()
+                .iter_shared()
+                .filter_map(|info| info.get("name"))
+                .map(|name| name.to_string())
+                .collect::<HashSet<_>>();
+
+            for property in &self.properties {
+                if !available.contains(property) {
+                    continue;
+                }
+
+                let value = node.get(property.as_str().into());
+                let value = match value.get_type() {
+                    VariantType::STRING | VariantType::STRING_NAME | VariantType::NODE_PATH => {
+                        format!("{:?}", value.to_string())
+                    }
+                    _ => value.to_string(),
+                };
+                let _ = write!(text, " {property}={value}");
+            }
+        }
+        text.push('\n');
+
+        if self.max_depth.is_some_and(|max| depth >= max) {
+            return;
+        }
+
+        let count = node
+            .get_child_count_ex()
+            .include_internal(self.include_internal)
+            .done();
+        for index in 0..count {
+            let child = node
+                .get_child_ex(index)
+                .include_internal(self.include_internal)
+                .done();
+
+            if let Some(child) = child {
+                self.capture_node(&child, depth + 1, text);
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Validation
+
+/// Problem found by [`validate_scene()`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum SceneIssue {
+    /// The node is queued for deletion, and will disappear at the end of the frame.
+    QueuedForDeletion { node: NodePath },
+
+    /// A `NodePath` property (or element of a `NodePath` array) is set, but does not point to a node.
+    BrokenNodePath {
+        node: NodePath,
+        property: String,
+        path: NodePath,
+    },
+}
+
+impl fmt::Display for SceneIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::QueuedForDeletion { node } => write!(f, "`{node}`: queued for deletion"),
+            Self::BrokenNodePath {
+                node,
+                property,
+                path,
+            } => write!(
+                f,
+                "`{node}`: property `{property}` refers to `{path}`, which does not exist"
+            ),
+        }
+    }
+}
+
+/// Checks the subtree of `root` for common mistakes; returns the problems found, in tree order.
+///
+/// Nodes are identified by their path relative to `root` (`.` for the root itself). See [`SceneIssue`] for the checks performed. Internal
+/// children are not checked.
+pub fn validate_scene(root: &Gd<Node>) -> Vec<SceneIssue> {
+    let mut issues = Vec::new();
+    validate_node(root, root, &mut issues);
+    issues
+}
+
+/// ⚠️ Asserts that [`validate_scene()`] finds no problems in the subtree of a `Gd<T>` node.
+///
+/// # Panics
+/// If there are problems; the message lists all of them.
+#[macro_export]
+macro_rules! assert_scene_valid {
+    ($root:expr $(,)?) => {
+        $crate::tools::snapshot::__assert_scene_valid(&$root.clone().upcast())
+    };
+}
+
+pub use crate::assert_scene_valid;
+
+#[doc(hidden)]
+#[track_caller]
+pub fn __assert_scene_valid(root: &Gd<Node>) {
+    let issues = validate_scene(root);
+    if issues.is_empty() {
+        return;
+    }
+
+    let list = issues
+        .iter()
+        .map(|issue| format!("\n  {issue}"))
+        .collect::<String>();
+
+    panic!(
+        "scene below `{}` has {} problem(s):{list}",
+        root.get_name(),
+        issues.len()
+    );
+}
+
+fn validate_node(root: &Gd<Node>, node: &Gd<Node>, issues: &mut Vec<SceneIssue>) {
+    let node_path = || root.get_path_to(node.clone());
+
+    if node.is_queued_for_deletion() {
+        issues.push(SceneIssue::QueuedForDeletion { node: node_path() });
+    }
+
+    for info in node.get_property_list().iter_shared() {
+        let is_array = match info.get("type").map(|ty| ty.to::<i64>()) {
+            Some(ty) if ty == VariantType::NODE_PATH.ord() as i64 => false,
+            Some(ty) if ty == VariantType::ARRAY.ord() as i64 => true,
+            _ => continue,
+        };
+
+        let Some(property) = info.get("name").map(|name| name.to_string()) else {
+            continue;
+        };
+
+        let value = node.get(property.as_str().into());
+        let paths = if is_array {
+            let Ok(array) = value.try_to::<VariantArray>() else {
+                continue;
+            };
+            array
+                .iter_shared()
+                .filter_map(|element| element.try_to::<NodePath>().ok())
+                .collect()
+        } else {
+            value.try_to::<NodePath>().into_iter().collect::<Vec<_>>()
+        };
+
+        for path in paths {
+            if !path.is_empty() && node.get_node_or_null(path.clone()).is_none() {
+                issues.push(SceneIssue::BrokenNodePath {
+                    node: node_path(),
+                    property: property.clone(),
+                    path,
+                });
+            }
+        }
+    }
+
+    for child in node.get_children().iter_shared() {
+        validate_node(root, &child, issues);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Text utilities
+
+/// Removes surrounding blank lines, trailing whitespace and the common indentation; ensures a trailing newline.
+fn normalize(text: &str) -> String {
+    let lines = text
+        .lines()
+        .map(str::trim_end)
+        .skip_while(|line| line.is_empty())
+        .collect::<Vec<_>>();
+
+    let end = lines
+        .iter()
+        .rposition(|line| !line.is_empty())
+        .map_or(0, |last| last + 1);
+    let lines = &lines[..end];
+
+    let indent = lines
+        .iter()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    lines
+        .iter()
+        .map(|line| format!("{}\n", line.get(indent..).unwrap_or("")))
+        .collect()
+}
+
+/// Line diff based on the longest common subsequence. Snapshots are small, so quadratic complexity is fine.
+fn line_diff(expected: &str, actual: &str) -> String {
+    let old = expected.lines().collect::<Vec<_>>();
+    let new = actual.lines().collect::<Vec<_>>();
+
+    // lcs[i][j] = length of the LCS of old[i..] and new[j..].
+    let mut lcs = vec![vec![0_usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            out.push(format!("  {}", old[i]));
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            out.push(format!("+ {}", new[j]));
+            j += 1;
+        } else {
+            out.push(format!("- {}", old[i]));
+            i += 1;
+        }
+    }
+
+    out.join("\n")
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_strips_indentation() {
+        let text = "
+            Root (Node)
+              Child (Node2D)   \n
+        ";
+
+        assert_eq!(normalize(text), "Root (Node)\n  Child (Node2D)\n");
+        assert_eq!(normalize(""), "");
+    }
+
+    #[test]
+    fn line_diff_marks_changes() {
+        let expected = "Root (Node)\n  A (Node)\n  B (Node)\n";
+        let actual = "Root (Node)\n  B (Node)\n  C (Node)\n";
+
+        assert_eq!(
+            line_diff(expected, actual),
+            "  Root (Node)\n-   A (Node)\n    B (Node)\n+   C (Node)"
+        );
+    }
+}
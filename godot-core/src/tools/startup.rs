@@ -0,0 +1,272 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::str::FromStr;
+
+use crate::builtin::PackedStringArray;
+use crate::classes::{DisplayServer, Os};
+
+/// Parsed command-line arguments, split into flags, `key=value` options and positional arguments.
+///
+/// Arguments are classified as follows:
+/// - `--key=value`, `-key=value` and `key=value` are options.
+/// - `--flag` and `-flag` are flags.
+/// - Everything else is positional, as is every argument after a bare `--`. This includes options with empty keys, such as `--=value`.
+///
+/// Space-separated values (`--key value`) are not recognized, since it is impossible to tell them apart from a flag followed by a
+/// positional argument. Use `--key=value` instead.
+///
+/// # Example
+/// ```no_run
+/// use godot::tools::CmdlineArgs;
+///
+/// // godot --headless -- --port=7000 --verbose level_1
+/// let args = CmdlineArgs::user();
+///
+/// let port: u16 = args.value_as("port").and_then(Result::ok).unwrap_or(8000);
+/// let verbose = args.has_flag("verbose");
+/// let level = args.positional().first();
+/// ```
+#[derive(Clone, Eq, PartialEq, Default, Debug)]
+pub struct CmdlineArgs {
+    flags: Vec<String>,
+    options: Vec<(String, String)>,
+    positional: Vec<String>,
+}
+
+impl CmdlineArgs {
+    /// Arguments passed to the engine, from `OS.get_cmdline_args()`.
+    ///
+    /// These exclude user arguments, see [`user()`][Self::user].
+    pub fn engine() -> Self {
+        Self::from_packed(&Os::singleton().get_cmdline_args())
+    }
+
+    /// User arguments after `--` or `++` on the command line, from `OS.get_cmdline_user_args()`.
+    ///
+    /// These are not interpreted by Godot and are the recommended way to pass custom arguments to a game.
+    pub fn user() -> Self {
+        Self::from_packed(&Os::singleton().get_cmdline_user_args())
+    }
+
+    /// Parses an arbitrary list of arguments.
+    pub fn parse<I, S>(args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut result = Self::default();
+        let mut only_positional = false;
+
+        for arg in args {
+            let arg = arg.as_ref();
+
+            if only_positional {
+                result.positional.push(arg.to_string());
+                continue;
+            }
+
+            if arg == "--" {
+                only_positional = true;
+                continue;
+            }
+
+            let name = arg
+                .strip_prefix("--")
+                .or_else(|| arg.strip_prefix('-'))
+                .filter(|name| !name.is_empty());
+
+            match (name, arg.split_once('=')) {
+                (Some(name), _) => match name.split_once('=') {
+                    Some((key, value)) if !key.is_empty() => result.push_option(key, value),
+                    Some(_) => result.positional.push(arg.to_string()),
+                    None => result.flags.push(name.to_string()),
+                },
+                (None, Some((key, value))) if !key.is_empty() => result.push_option(key, value),
+                _ => result.positional.push(arg.to_string()),
+            }
+        }
+
+        result
+    }
+
+    /// Whether the flag `name` (without leading dashes) was passed.
+    pub fn has_flag(&self, name: &str) -> bool {
+        self.flags.iter().any(|flag| flag == name)
+    }
+
+    /// Value of option `key`. If the option was passed multiple times, the last value wins.
+    pub fn value(&self, key: &str) -> Option<&str> {
+        self.values(key).last()
+    }
+
+    /// All values of option `key`, in the order they were passed.
+    pub fn values<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.options
+            .iter()
+            .filter(move |(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Value of option `key`, parsed as `T`.
+    ///
+    /// Returns `None` if the option was not passed, and `Some(Err)` if its value could not be parsed.
+    pub fn value_as<T: FromStr>(&self, key: &str) -> Option<Result<T, T::Err>> {
+        self.value(key).map(str::parse)
+    }
+
+    /// All flags, without leading dashes.
+    pub fn flags(&self) -> &[String] {
+        &self.flags
+    }
+
+    /// All options as `(key, value)` pairs, in the order they were passed.
+    pub fn options(&self) -> &[(String, String)] {
+        &self.options
+    }
+
+    /// Positional arguments, in the order they were passed.
+    pub fn positional(&self) -> &[String] {
+        &self.positional
+    }
+
+    fn from_packed(args: &PackedStringArray) -> Self {
+        Self::parse(args.as_slice().iter().map(|arg| arg.to_string()))
+    }
+
+    fn push_option(&mut self, key: &str, value: &str) {
+        self.options.push((key.to_string(), value.to_string()));
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Typed checks for the [feature tags] of the running Godot instance.
+///
+/// All checks go through `OS.has_feature()`, so they reflect the export preset and the platform at runtime, not the platform the
+/// extension was compiled for. Custom tags defined in export presets can be checked with [`has()`][Self::has].
+///
+/// # Example
+/// ```no_run
+/// use godot::tools::Features;
+///
+/// if Features::is_dedicated_server() || Features::is_headless() {
+///     // Skip loading audio and visual effects.
+/// }
+///
+/// if Features::has("demo") {
+///     // Custom tag from the export preset.
+/// }
+/// ```
+///
+/// [feature tags]: https://docs.godotengine.org/en/stable/tutorials/export/feature_tags.html
+pub struct Features {
+    _private: (),
+}
+
+impl Features {
+    /// Whether the feature tag `tag` is present. Works for both built-in and custom tags.
+    pub fn has(tag: &str) -> bool {
+        Os::singleton().has_feature(tag.into())
+    }
+
+    /// Running a project exported with the "Dedicated Server" export mode.
+    pub fn is_dedicated_server() -> bool {
+        Self::has("dedicated_server")
+    }
+
+    /// Running without a display, e.g. with `--headless`.
+    ///
+    /// Unlike [`is_dedicated_server()`][Self::is_dedicated_server], this is not a feature tag, but checks whether Godot uses its
+    /// headless display server.
+    pub fn is_headless() -> bool {
+        DisplayServer::singleton().get_name().to_string() == "headless"
+    }
+
+    /// Running in the editor, or from the editor binary (e.g. `godot --script`).
+    pub fn is_editor() -> bool {
+        Self::has("editor")
+    }
+
+    /// Running from an export template, i.e. an exported project.
+    pub fn is_template() -> bool {
+        Self::has("template")
+    }
+
+    /// Running a debug build (editor or debug export template).
+    pub fn is_debug() -> bool {
+        Self::has("debug")
+    }
+
+    /// Running a release export template.
+    pub fn is_release() -> bool {
+        Self::has("release")
+    }
+
+    /// Running on a mobile platform (Android, iOS).
+    pub fn is_mobile() -> bool {
+        Self::has("mobile")
+    }
+
+    /// Running on a desktop platform (Windows, macOS, Linux/BSD).
+    pub fn is_pc() -> bool {
+        Self::has("pc")
+    }
+
+    /// Running in a web browser.
+    pub fn is_web() -> bool {
+        Self::has("web")
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_mixed_args() {
+        let args = CmdlineArgs::parse([
+            "--port=7000",
+            "-verbose",
+            "map=arena",
+            "level_1",
+            "--port=7001",
+            "--server",
+        ]);
+
+        assert_eq!(args.value("port"), Some("7001"));
+        assert_eq!(args.values("port").collect::<Vec<_>>(), ["7000", "7001"]);
+        assert_eq!(args.value("map"), Some("arena"));
+        assert_eq!(args.value_as::<u16>("port"), Some(Ok(7001)));
+        assert!(args.value_as::<u16>("map").unwrap().is_err());
+        assert_eq!(args.value("missing"), None);
+
+        assert!(args.has_flag("verbose"));
+        assert!(args.has_flag("server"));
+        assert!(!args.has_flag("port"));
+
+        assert_eq!(args.positional(), ["level_1"]);
+    }
+
+    #[test]
+    fn parse_edge_cases() {
+        let args = CmdlineArgs::parse([
+            "-", "=value", "--=x", "-=y", "--empty=", "--", "--flag", "a=b",
+        ]);
+
+        assert_eq!(args.value("empty"), Some(""));
+        assert_eq!(args.value(""), None);
+        assert!(args.flags().is_empty());
+        assert_eq!(args.options().len(), 1);
+        assert_eq!(
+            args.positional(),
+            ["-", "=value", "--=x", "-=y", "--flag", "a=b"]
+        );
+    }
+}
@@ -0,0 +1,174 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Thread-local registries of Rust callbacks that are invoked from a Godot signal.
+//!
+//! Used by frame callbacks, setting subscriptions and navigation map events. Each registry is a `thread_local!` of
+//! `RefCell<Subscribers<E>>`, where `E` is the stored entry (usually a boxed closure). Callbacks can subscribe and unsubscribe while
+//! the registry is being dispatched, including from the callbacks themselves.
+
+use std::cell::RefCell;
+use std::thread::LocalKey;
+
+use godot_ffi as sys;
+
+pub(crate) type Registry<E> = LocalKey<RefCell<Subscribers<E>>>;
+
+pub(crate) struct Subscribers<E> {
+    /// Entries in subscription order.
+    entries: Vec<(u64, E)>,
+
+    /// Entries added while the registry is being dispatched.
+    pending: Vec<(u64, E)>,
+
+    /// IDs of the entries taken out of `entries` for the running dispatches.
+    dispatching_ids: Vec<u64>,
+
+    /// IDs of entries removed while the registry is being dispatched.
+    removed_during_dispatch: Vec<u64>,
+
+    next_id: u64,
+
+    /// Number of running dispatches; more than one if a callback synchronously emits the dispatching signal again.
+    dispatch_depth: u32,
+    is_connected: bool,
+}
+
+impl<E> Default for Subscribers<E> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            pending: Vec::new(),
+            dispatching_ids: Vec::new(),
+            removed_during_dispatch: Vec::new(),
+            next_id: 0,
+            dispatch_depth: 0,
+            is_connected: false,
+        }
+    }
+}
+
+/// Adds an entry and returns its ID, as well as whether the caller still needs to connect the signal that dispatches the registry.
+///
+/// # Panics
+/// If not called on the main thread. Registries are thread-local, and the signals are only dispatched on the main thread.
+pub(crate) fn subscribe<E>(registry: &'static Registry<E>, what: &str, entry: E) -> (u64, bool) {
+    assert!(
+        sys::is_main_thread(),
+        "{what} can only be registered on the main thread"
+    );
+
+    registry.with(|subs| {
+        let mut subs = subs.borrow_mut();
+        let id = subs.next_id;
+        subs.next_id += 1;
+
+        if subs.dispatch_depth > 0 {
+            subs.pending.push((id, entry));
+        } else {
+            subs.entries.push((id, entry));
+        }
+
+        let needs_connect = !subs.is_connected;
+        subs.is_connected = true;
+
+        (id, needs_connect)
+    })
+}
+
+/// Removes an entry. Returns `false` if there is no such entry, also if it has already been removed during the current dispatch.
+pub(crate) fn unsubscribe<E>(registry: &'static Registry<E>, id: u64) -> bool {
+    // The removed entry is only dropped after the registry is released, since dropping its captures may (un)subscribe.
+    let (removed, _dropped) = registry.with(|subs| {
+        let mut subs = subs.borrow_mut();
+
+        let dropped =
+            take_entry(&mut subs.entries, id).or_else(|| take_entry(&mut subs.pending, id));
+        if dropped.is_some() {
+            return (true, dropped);
+        }
+
+        // While dispatching, the entries are temporarily taken out of the registry, so they must be removed afterward.
+        if subs.dispatching_ids.contains(&id) && !subs.removed_during_dispatch.contains(&id) {
+            subs.removed_during_dispatch.push(id);
+            return (true, None);
+        }
+
+        (false, None)
+    });
+
+    removed
+}
+
+/// Invokes `invoke` for each entry, in subscription order.
+///
+/// Entries added during dispatch are first invoked on the next dispatch. If an invocation panics, the registry stays intact.
+pub(crate) fn dispatch<E>(registry: &'static Registry<E>, mut invoke: impl FnMut(&mut E)) {
+    // Take entries out of the registry, so that they can (un)subscribe without double-borrowing the RefCell.
+    let mut guard = registry.with(|subs| {
+        let mut subs = subs.borrow_mut();
+        subs.dispatch_depth += 1;
+
+        let entries = std::mem::take(&mut subs.entries);
+        subs.dispatching_ids
+            .extend(entries.iter().map(|(id, _)| *id));
+
+        DispatchGuard { registry, entries }
+    });
+
+    for (id, entry) in guard.entries.iter_mut() {
+        // Skip entries that an earlier callback in this dispatch has removed.
+        let is_removed = registry.with(|subs| subs.borrow().removed_during_dispatch.contains(id));
+        if !is_removed {
+            invoke(entry);
+        }
+    }
+}
+
+/// Puts dispatched entries back into their registry, also if one of them panics.
+struct DispatchGuard<E: 'static> {
+    registry: &'static Registry<E>,
+    entries: Vec<(u64, E)>,
+}
+
+impl<E> Drop for DispatchGuard<E> {
+    fn drop(&mut self) {
+        let entries = std::mem::take(&mut self.entries);
+
+        // Like in `unsubscribe()`, removed entries are dropped after the registry is released.
+        let _dropped = self.registry.with(|subs| {
+            let mut subs = subs.borrow_mut();
+            subs.dispatch_depth -= 1;
+
+            let (mut kept, dropped): (Vec<_>, Vec<_>) = entries
+                .into_iter()
+                .partition(|(id, _)| !subs.removed_during_dispatch.contains(id));
+
+            let Subscribers {
+                dispatching_ids,
+                removed_during_dispatch,
+                ..
+            } = &mut *subs;
+            dispatching_ids.retain(|id| !kept.iter().chain(&dropped).any(|(own, _)| own == id));
+            removed_during_dispatch.retain(|id| dispatching_ids.contains(id));
+
+            // A nested dispatch finds no entries, as they are all taken out by the outer one. Entries added in between are kept pending
+            // until the outermost dispatch ends.
+            if subs.dispatch_depth == 0 {
+                kept.append(&mut subs.pending);
+            }
+            subs.entries = kept;
+
+            dropped
+        });
+    }
+}
+
+fn take_entry<E>(entries: &mut Vec<(u64, E)>, id: u64) -> Option<E> {
+    let index = entries.iter().position(|(entry_id, _)| *entry_id == id)?;
+    Some(entries.remove(index).1)
+}
@@ -0,0 +1,551 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Loading of CSV and TSV tables into typed rows.
+//!
+//! Game data such as balancing values is often maintained in spreadsheets and exported as CSV. This module parses such files into a
+//! `Vec` of structs, one per row, where each field corresponds to a column. Rows are described with `#[derive(TableRow)]`:
+//!
+//! ```no_run
+//! use godot::prelude::*;
+//! use godot::tools::table::{self, TableRow};
+//!
+//! #[derive(TableRow)]
+//! struct Enemy {
+//!     name: GString,
+//!     #[table(column = "Max HP")]
+//!     max_hp: u32,
+//!     speed: f32,
+//!     #[table(default)]
+//!     boss: bool,           // Column optional; empty or missing cells become `false`.
+//!     loot: Option<String>, // Empty cells become `None`.
+//! }
+//!
+//! let enemies: Vec<Enemy> = table::load("res://data/enemies.csv").unwrap();
+//! ```
+//!
+//! # Format
+//! The first row contains the column names, which are matched against the field names (or the `column` key). Column order is
+//! irrelevant, and additional columns are ignored. Files ending in `.tsv` are separated by tabs, all others by commas. Cells can be
+//! quoted with `"`, in which case they may contain separators, line breaks, and `""` for a literal quote. Lines without any content
+//! are skipped.
+//!
+//! Cells are parsed through [`FromCell`], which is implemented for numbers, `bool`, strings and `Option<T>`.
+//!
+//! # Hot reload
+//! [`WatchedTable`] keeps the rows together with the file's modification time, and reloads them when polled after the file has changed.
+//! This allows tweaking values in a spreadsheet while the game runs from the editor.
+
+use std::fmt;
+use std::io::Read;
+use std::str::FromStr;
+
+use crate::builtin::{GString, StringName};
+use crate::classes::file_access::ModeFlags;
+use crate::tools::GFile;
+
+/// Struct that can be created from one row of a table. Usually implemented with `#[derive(TableRow)]`.
+///
+/// The derive reads each field from the column with the same name. Fields support the following `#[table(...)]` keys:
+/// - `column = "Name"`: read from a column with a different name.
+/// - `default`: use `Default::default()` if the cell is empty or the column is missing entirely.
+///
+/// Field types must implement [`FromCell`].
+pub trait TableRow: Sized {
+    /// Columns that must be present in the header.
+    fn required_columns() -> &'static [&'static str];
+
+    /// Creates a row value from the cells of one row.
+    fn from_row(row: &RowCells<'_>) -> Result<Self, TableError>;
+}
+
+/// Value that can be parsed from a single cell of a table.
+///
+/// Implement this for your own types to use them in [`TableRow`] structs.
+pub trait FromCell: Sized {
+    /// Parses the trimmed content of a cell. The error message is embedded into [`TableError::Cell`].
+    fn from_cell(cell: &str) -> Result<Self, String>;
+}
+
+macro_rules! impl_from_cell_via_from_str {
+    ($($Ty:ty),+ $(,)?) => {
+        $(
+            impl FromCell for $Ty {
+                fn from_cell(cell: &str) -> Result<Self, String> {
+                    <$Ty as FromStr>::from_str(cell).map_err(|err| err.to_string())
+                }
+            }
+        )+
+    };
+}
+
+impl_from_cell_via_from_str!(i8, i16, i32, i64, u8, u16, u32, u64, isize, usize, f32, f64, String);
+
+impl FromCell for bool {
+    /// Accepts `true`/`false`, `yes`/`no` and `1`/`0` (case-insensitive), as spreadsheets export booleans inconsistently.
+    fn from_cell(cell: &str) -> Result<Self, String> {
+        match cell.to_ascii_lowercase().as_str() {
+            "true" | "yes" | "1" => Ok(true),
+            "false" | "no" | "0" => Ok(false),
+            _ => Err("expected `true` or `false`".to_string()),
+        }
+    }
+}
+
+impl FromCell for GString {
+    fn from_cell(cell: &str) -> Result<Self, String> {
+        Ok(GString::from(cell))
+    }
+}
+
+impl FromCell for StringName {
+    fn from_cell(cell: &str) -> Result<Self, String> {
+        Ok(StringName::from(cell))
+    }
+}
+
+impl<T: FromCell> FromCell for Option<T> {
+    /// Empty cells are `None`, all others are parsed as `T`.
+    fn from_cell(cell: &str) -> Result<Self, String> {
+        if cell.is_empty() {
+            Ok(None)
+        } else {
+            T::from_cell(cell).map(Some)
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Error while loading a table.
+#[derive(Debug)]
+pub enum TableError {
+    /// The file could not be read.
+    Io { path: String, error: std::io::Error },
+
+    /// The file is not valid CSV/TSV, e.g. because a quoted cell is not terminated.
+    Malformed {
+        path: String,
+        line: usize,
+        message: String,
+    },
+
+    /// A column required by the row type is not in the header.
+    MissingColumn { path: String, column: String },
+
+    /// A cell could not be parsed into the field type.
+    Cell {
+        path: String,
+        /// 1-based line of the row in the file.
+        line: usize,
+        column: String,
+        value: String,
+        message: String,
+    },
+}
+
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, error } => write!(f, "cannot read table {path}: {error}"),
+            Self::Malformed {
+                path,
+                line,
+                message,
+            } => write!(f, "{path}:{line}: {message}"),
+            Self::MissingColumn { path, column } => {
+                write!(f, "{path}: missing column `{column}`")
+            }
+            Self::Cell {
+                path,
+                line,
+                column,
+                value,
+                message,
+            } => write!(
+                f,
+                "{path}:{line}: cannot parse `{value}` in column `{column}`: {message}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TableError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Cells of one table row, passed to [`TableRow::from_row()`].
+pub struct RowCells<'a> {
+    path: &'a str,
+    header: &'a [String],
+    cells: &'a [String],
+    line: usize,
+}
+
+impl<'a> RowCells<'a> {
+    /// 1-based line in the file at which this row starts.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Raw content of the cell in `column`, trimmed. `None` if the column doesn't exist; missing trailing cells are empty.
+    pub fn cell(&self, column: &str) -> Option<&'a str> {
+        let index = self.header.iter().position(|name| name == column)?;
+        let cell = self.cells.get(index).map_or("", |cell| cell.trim());
+
+        Some(cell)
+    }
+
+    /// Parses the cell in `column`.
+    pub fn parse<T: FromCell>(&self, column: &str) -> Result<T, TableError> {
+        let Some(cell) = self.cell(column) else {
+            return Err(TableError::MissingColumn {
+                path: self.path.to_string(),
+                column: column.to_string(),
+            });
+        };
+
+        T::from_cell(cell).map_err(|message| TableError::Cell {
+            path: self.path.to_string(),
+            line: self.line,
+            column: column.to_string(),
+            value: cell.to_string(),
+            message,
+        })
+    }
+
+    /// Parses the cell in `column`, or returns `T::default()` if the cell is empty or the column doesn't exist.
+    pub fn parse_or_default<T: FromCell + Default>(&self, column: &str) -> Result<T, TableError> {
+        match self.cell(column) {
+            None | Some("") => Ok(T::default()),
+            Some(_) => self.parse(column),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Loads all rows of the CSV or TSV file at `path`.
+///
+/// Works with `res://` and `user://` paths. Note that CSV files in the project are imported as translations by default; set their
+/// import mode to "Keep File (exported as is)" so that they are included in exports.
+pub fn load<T: TableRow>(path: &str) -> Result<Vec<T>, TableError> {
+    let text = read_file(path)?;
+    parse(&text, separator_for(path), path)
+}
+
+/// Parses rows from `text`, with cells separated by `separator`.
+///
+/// `source` is only used in error messages, e.g. a file name.
+pub fn parse<T: TableRow>(text: &str, separator: char, source: &str) -> Result<Vec<T>, TableError> {
+    let lines =
+        split_records(text, separator).map_err(|(line, message)| TableError::Malformed {
+            path: source.to_string(),
+            line,
+            message,
+        })?;
+
+    let mut records = lines.into_iter();
+    let Some((_, header)) = records.next() else {
+        return Ok(Vec::new());
+    };
+    let header: Vec<String> = header.iter().map(|name| name.trim().to_string()).collect();
+
+    if let Some(column) = T::required_columns()
+        .iter()
+        .find(|column| !header.iter().any(|name| name == *column))
+    {
+        return Err(TableError::MissingColumn {
+            path: source.to_string(),
+            column: column.to_string(),
+        });
+    }
+
+    records
+        .map(|(line, cells)| {
+            T::from_row(&RowCells {
+                path: source,
+                header: &header,
+                cells: &cells,
+                line,
+            })
+        })
+        .collect()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Table rows that are reloaded when the file changes.
+///
+/// Call [`reload_if_changed()`][Self::reload_if_changed] regularly, e.g. from `process()` or a timer. It only reads the file's
+/// modification time unless the file has changed, so polling is cheap. If reloading fails, the previous rows are kept.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::tools::table::{TableRow, WatchedTable};
+/// # #[derive(TableRow)] struct Enemy { name: GString }
+/// let mut enemies = WatchedTable::<Enemy>::load("res://data/enemies.csv").unwrap();
+///
+/// // Each frame (or less often):
+/// match enemies.reload_if_changed() {
+///     Ok(true) => godot_print!("reloaded {} enemies", enemies.rows().len()),
+///     Ok(false) => {}
+///     Err(err) => godot_error!("{err}"),
+/// }
+/// ```
+pub struct WatchedTable<T> {
+    path: String,
+    modified_time: u64,
+    rows: Vec<T>,
+}
+
+impl<T: TableRow> WatchedTable<T> {
+    /// Loads the table at `path` for the first time.
+    pub fn load(path: &str) -> Result<Self, TableError> {
+        let modified_time = modified_time(path)?;
+        let rows = load(path)?;
+
+        Ok(Self {
+            path: path.to_string(),
+            modified_time,
+            rows,
+        })
+    }
+
+    /// Reloads the rows if the file was modified since the last load. Returns whether the rows were replaced.
+    pub fn reload_if_changed(&mut self) -> Result<bool, TableError> {
+        let modified_time = modified_time(&self.path)?;
+        if modified_time == self.modified_time {
+            return Ok(false);
+        }
+
+        // Update the time first, so a broken file is not parsed again on every poll.
+        self.modified_time = modified_time;
+        self.rows = load(&self.path)?;
+        Ok(true)
+    }
+
+    /// Rows from the most recent successful load.
+    pub fn rows(&self) -> &[T] {
+        &self.rows
+    }
+
+    /// Path of the watched file.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for WatchedTable<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WatchedTable")
+            .field("path", &self.path)
+            .field("rows", &self.rows)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+fn read_file(path: &str) -> Result<String, TableError> {
+    let io_error = |error| TableError::Io {
+        path: path.to_string(),
+        error,
+    };
+
+    let mut file = GFile::open(path, ModeFlags::READ).map_err(io_error)?;
+    let mut text = String::new();
+    file.read_to_string(&mut text).map_err(io_error)?;
+
+    Ok(text)
+}
+
+fn modified_time(path: &str) -> Result<u64, TableError> {
+    GFile::modified_time(path).map_err(|error| TableError::Io {
+        path: path.to_string(),
+        error,
+    })
+}
+
+fn separator_for(path: &str) -> char {
+    if path.to_ascii_lowercase().ends_with(".tsv") {
+        '\t'
+    } else {
+        ','
+    }
+}
+
+/// Splits `text` into records of cells, each with the 1-based line at which it starts. Records without content are skipped.
+///
+/// On error, returns the line and a message.
+fn split_records(
+    text: &str,
+    separator: char,
+) -> Result<Vec<(usize, Vec<String>)>, (usize, String)> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+
+    let mut records = Vec::new();
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut in_quotes = false;
+    let mut quote_line = 0;
+
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    cell.push('"');
+                }
+                '"' => in_quotes = false,
+                '\n' => {
+                    line += 1;
+                    cell.push('\n');
+                }
+                _ => cell.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' if cell.trim().is_empty() => {
+                cell.clear();
+                in_quotes = true;
+                quote_line = line;
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                cells.push(std::mem::take(&mut cell));
+                push_record(&mut records, record_line, std::mem::take(&mut cells));
+
+                line += 1;
+                record_line = line;
+            }
+            c if c == separator => cells.push(std::mem::take(&mut cell)),
+            _ => cell.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err((quote_line, "quoted cell is not terminated".to_string()));
+    }
+
+    cells.push(cell);
+    push_record(&mut records, record_line, cells);
+
+    Ok(records)
+}
+
+fn push_record(records: &mut Vec<(usize, Vec<String>)>, line: usize, cells: Vec<String>) {
+    if cells.iter().any(|cell| !cell.trim().is_empty()) {
+        records.push((line, cells));
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(line: usize, cells: &[&str]) -> (usize, Vec<String>) {
+        (line, cells.iter().map(|cell| cell.to_string()).collect())
+    }
+
+    #[test]
+    fn split_plain_and_quoted() {
+        let text = "name,hp\r\n\"Orc, big\",10\n\n\"say \"\"hi\"\"\nthere\",\n";
+
+        assert_eq!(
+            split_records(text, ',').unwrap(),
+            [
+                record(1, &["name", "hp"]),
+                record(2, &["Orc, big", "10"]),
+                record(4, &["say \"hi\"\nthere", ""]),
+            ]
+        );
+    }
+
+    #[test]
+    fn split_tabs_and_bom() {
+        assert_eq!(
+            split_records("\u{feff}a\tb\n1\t2", '\t').unwrap(),
+            [record(1, &["a", "b"]), record(2, &["1", "2"])]
+        );
+    }
+
+    #[test]
+    fn split_unterminated_quote() {
+        assert_eq!(split_records("a\n\"open,1\n2", ',').unwrap_err().0, 2);
+    }
+
+    struct Row {
+        name: String,
+        hp: u32,
+        boss: bool,
+    }
+
+    impl TableRow for Row {
+        fn required_columns() -> &'static [&'static str] {
+            &["name", "Max HP"]
+        }
+
+        fn from_row(row: &RowCells<'_>) -> Result<Self, TableError> {
+            Ok(Self {
+                name: row.parse("name")?,
+                hp: row.parse("Max HP")?,
+                boss: row.parse_or_default("boss")?,
+            })
+        }
+    }
+
+    #[test]
+    fn parse_typed_rows() {
+        let rows: Vec<Row> =
+            parse("Max HP, name\n10,orc\n 250 ,dragon\n", ',', "test.csv").unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            (rows[1].name.as_str(), rows[1].hp, rows[1].boss),
+            ("dragon", 250, false)
+        );
+    }
+
+    #[test]
+    fn parse_errors_name_location() {
+        let err = parse::<Row>("name,Max HP\norc,10\ntroll,lots\n", ',', "test.csv").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "test.csv:3: cannot parse `lots` in column `Max HP`: invalid digit found in string"
+        );
+
+        let err = parse::<Row>("name\norc\n", ',', "test.csv").unwrap_err();
+        assert_eq!(err.to_string(), "test.csv: missing column `Max HP`");
+    }
+
+    #[test]
+    fn parse_bool_and_option() {
+        assert_eq!(bool::from_cell("Yes"), Ok(true));
+        assert_eq!(bool::from_cell("0"), Ok(false));
+        assert!(bool::from_cell("maybe").is_err());
+
+        assert_eq!(Option::<i32>::from_cell(""), Ok(None));
+        assert_eq!(Option::<i32>::from_cell("7"), Ok(Some(7)));
+    }
+}
@@ -0,0 +1,295 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+use crate::builtin::{Callable, Variant};
+use crate::classes::object::ConnectFlags;
+use crate::classes::{Engine, SceneTree, SceneTreeTimer, Timer};
+use crate::obj::Gd;
+
+/// Handle to a callback scheduled with [`after()`] or [`next_frame()`].
+///
+/// Dropping the handle does _not_ cancel the callback; use [`cancel()`][Self::cancel] for that.
+pub struct DelayHandle {
+    callback: Rc<RefCell<Option<BoxedCallback>>>,
+    timer: Option<Gd<SceneTreeTimer>>,
+}
+
+impl DelayHandle {
+    /// Prevents the callback from running. Returns `false` if it has already run or been cancelled.
+    pub fn cancel(self) -> bool {
+        self.callback.borrow_mut().take().is_some()
+    }
+
+    /// Returns `true` if the callback has neither run nor been cancelled.
+    pub fn is_pending(&self) -> bool {
+        self.callback.borrow().is_some()
+    }
+
+    /// Time until the callback runs, or `None` for [`next_frame()`] callbacks.
+    pub fn time_left(&self) -> Option<Duration> {
+        self.timer.as_ref().map(|timer| timer.time_left_duration())
+    }
+}
+
+impl fmt::Debug for DelayHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DelayHandle")
+            .field("pending", &self.is_pending())
+            .field("time_left", &self.time_left())
+            .finish()
+    }
+}
+
+/// Invokes `callback` once, after `delay` has passed.
+///
+/// Uses a [`SceneTreeTimer`] with default settings: it runs while the tree is paused, during process frames, and respects
+/// `Engine.time_scale`. For other settings, create the timer with [`SceneTree::create_timer_ex()`] and connect to its `timeout` signal.
+///
+/// Callbacks are invoked on the main thread and can thus capture non-thread-safe state.
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// use godot::tools::after;
+///
+/// let handle = after(Duration::from_millis(500), || {
+///     godot::global::godot_print!("half a second later");
+/// });
+///
+/// // Changed our mind:
+/// handle.cancel();
+/// ```
+///
+/// # Panics
+/// If the main loop is not a `SceneTree` (or derived class).
+pub fn after<F>(delay: Duration, callback: F) -> DelayHandle
+where
+    F: FnOnce() + 'static,
+{
+    let timer = scene_tree().create_timer_duration(delay);
+    let callback = connect_once(timer.clone().upcast(), "timeout", Box::new(callback));
+
+    DelayHandle {
+        callback,
+        timer: Some(timer),
+    }
+}
+
+/// Invokes `callback` once, at the beginning of the next process frame.
+///
+/// The callback runs when the [`SceneTree`] emits `process_frame`, i.e. before the `process()` methods of nodes are invoked.
+///
+/// # Panics
+/// If the main loop is not a `SceneTree` (or derived class).
+pub fn next_frame<F>(callback: F) -> DelayHandle
+where
+    F: FnOnce() + 'static,
+{
+    let callback = connect_once(scene_tree().upcast(), "process_frame", Box::new(callback));
+
+    DelayHandle {
+        callback,
+        timer: None,
+    }
+}
+
+/// Returns a future that completes after `delay` has passed.
+///
+/// Timing is the same as for [`after()`]. Dropping the future before completion cancels the underlying callback.
+///
+/// # Panics
+/// If the main loop is not a `SceneTree` (or derived class).
+pub fn sleep(delay: Duration) -> Delay {
+    Delay::schedule(|callback| after(delay, callback))
+}
+
+/// Returns a future that completes at the beginning of the next process frame.
+///
+/// # Panics
+/// If the main loop is not a `SceneTree` (or derived class).
+pub fn next_frame_future() -> Delay {
+    Delay::schedule(next_frame)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Future returned by [`sleep()`] and [`next_frame_future()`].
+///
+/// The waker is only invoked once the delay has elapsed, so the future does not need to be polled every frame.
+#[must_use = "futures do nothing unless awaited"]
+pub struct Delay {
+    state: Rc<RefCell<DelayState>>,
+    handle: Option<DelayHandle>,
+}
+
+#[derive(Default)]
+struct DelayState {
+    elapsed: bool,
+    waker: Option<Waker>,
+}
+
+impl Delay {
+    fn schedule(schedule_fn: impl FnOnce(Box<dyn FnOnce()>) -> DelayHandle) -> Self {
+        let state = Rc::new(RefCell::new(DelayState::default()));
+        let state_in_callback = state.clone();
+
+        let handle = schedule_fn(Box::new(move || {
+            let mut state = state_in_callback.borrow_mut();
+            state.elapsed = true;
+
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        }));
+
+        Self {
+            state,
+            handle: Some(handle),
+        }
+    }
+
+    /// Returns `true` if the delay has elapsed.
+    pub fn is_elapsed(&self) -> bool {
+        self.state.borrow().elapsed
+    }
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.state.borrow_mut();
+
+        if state.elapsed {
+            Poll::Ready(())
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for Delay {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.cancel();
+        }
+    }
+}
+
+impl fmt::Debug for Delay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Delay")
+            .field("elapsed", &self.is_elapsed())
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `SceneTree` class.
+impl SceneTree {
+    /// Like [`create_timer()`][Self::create_timer], but with a [`Duration`] instead of seconds.
+    pub fn create_timer_duration(&mut self, duration: Duration) -> Gd<SceneTreeTimer> {
+        self.create_timer(duration.as_secs_f64())
+            .expect("SceneTree::create_timer() returned null")
+    }
+}
+
+/// Manual extensions for the `SceneTreeTimer` class.
+impl SceneTreeTimer {
+    /// Remaining time until `timeout` is emitted.
+    pub fn time_left_duration(&self) -> Duration {
+        to_duration(self.get_time_left())
+    }
+
+    /// Sets the remaining time until `timeout` is emitted.
+    pub fn set_time_left_duration(&mut self, duration: Duration) {
+        self.set_time_left(duration.as_secs_f64());
+    }
+}
+
+/// Manual extensions for the `Timer` class.
+impl Timer {
+    /// Wait time between start and `timeout`, as a [`Duration`].
+    pub fn wait_duration(&self) -> Duration {
+        to_duration(self.get_wait_time())
+    }
+
+    /// Sets the wait time between start and `timeout`.
+    ///
+    /// Godot does not allow a wait time of zero; very short durations are effectively limited by the frame rate.
+    pub fn set_wait_duration(&mut self, duration: Duration) {
+        self.set_wait_time(duration.as_secs_f64());
+    }
+
+    /// Remaining time until `timeout` is emitted. Zero if the timer is stopped.
+    pub fn time_left_duration(&self) -> Duration {
+        to_duration(self.get_time_left())
+    }
+
+    /// Sets the wait time to `duration` and starts the timer.
+    pub fn start_duration(&mut self, duration: Duration) {
+        self.start_ex().time_sec(duration.as_secs_f64()).done();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+type BoxedCallback = Box<dyn FnOnce()>;
+
+/// Connects a one-shot callable to `signal`, which runs `callback`. Returns the shared slot, which is emptied to cancel the callback.
+fn connect_once(
+    mut object: Gd<crate::classes::Object>,
+    signal: &str,
+    callback: BoxedCallback,
+) -> Rc<RefCell<Option<BoxedCallback>>> {
+    let slot = Rc::new(RefCell::new(Some(callback)));
+    let slot_in_callable = slot.clone();
+
+    let callable = Callable::from_local_fn(signal, move |_args: &[&Variant]| {
+        // Run outside the borrow, so the callback can schedule further callbacks.
+        let callback = slot_in_callable.borrow_mut().take();
+        if let Some(callback) = callback {
+            callback();
+        }
+        Ok(Variant::nil())
+    });
+
+    object
+        .connect_ex(signal.into(), callable)
+        .flags(ConnectFlags::ONE_SHOT)
+        .done();
+
+    slot
+}
+
+fn scene_tree() -> Gd<SceneTree> {
+    let main_loop = Engine::singleton()
+        .get_main_loop()
+        .expect("timers require a running main loop");
+
+    main_loop
+        .try_cast::<SceneTree>()
+        .unwrap_or_else(|main_loop| {
+            panic!("timers require the main loop to be a SceneTree, but it is {main_loop:?}")
+        })
+}
+
+/// Converts seconds to a duration; negative values (e.g. from a stopped timer) become zero.
+fn to_duration(secs: f64) -> Duration {
+    Duration::try_from_secs_f64(secs).unwrap_or(Duration::ZERO)
+}
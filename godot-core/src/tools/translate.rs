@@ -7,74 +7,77 @@
 
 //! Macros for translation.
 
+use std::fmt;
+
+use crate::builtin::{GString, PackedStringArray, StringName};
+use crate::classes::{ProjectSettings, Translation};
+use crate::obj::Gd;
+use crate::sys;
+use crate::tools::try_load;
+
 pub use crate::{tr, tr_n};
 
 /// A convenience macro for using the [`Object::tr()`](crate::classes::Object::tr()) and [`Object::tr_ex()`](crate::classes::Object::tr_ex())
 ///  methods.
 ///
-/// Takes a format string literal, with optional arguments. Optionally, `context` for potentially ambiguous words can be
-/// added before the format arguments, separated with a `;`.
+/// Takes a message literal, with optional arguments. Optionally, `context` for potentially ambiguous words can be
+/// added before the message, separated with a `;`.
+///
+/// The literal is looked up in the translation catalogs _as is_; placeholders are substituted afterwards, in the translated text.
+/// This allows translators to reorder arguments. Placeholders can be implicit (`{}`), positional (`{0}`) or named (`{name}`);
+/// `{{` and `}}` produce literal braces. Arguments are formatted with [`Display`](std::fmt::Display).
+///
+/// Placeholders in the literal are checked against the arguments at compile time: every placeholder needs an argument, and every
+/// argument must be used. Unlike `format!`, variables are not captured implicitly, and format specs such as `{x:.2}` are not supported.
 ///
-/// Using named or positional parameters instead of `{}` may make it easier to use dynamic formatting once gdext supports it:
+/// Earlier versions of this macro passed the message to `format!`, so existing code relying on captures, e.g. `tr!("{x}")`, fails to
+/// compile now. Pass the variable as a named argument instead: `tr!("{x}", x = x)`.
 /// ```no_run
 /// # #[macro_use] extern crate godot;
 /// # use godot::builtin::Vector2i;
 /// # let a = Vector2i { x: 0, y: 0 };
-/// # let b = Vector2i { x: 0, y: 0 };
+/// # let player_name = "Ana";
 /// # let context = "context";
 /// use godot::tools::tr;
 ///
-/// // Good.
-/// tr!(context; "{a} is a {b}"); // inlined, with context
-/// tr!("{0} is a {1}", a, b); // positional, without context
-/// tr!("{c} is a {d}", c = a.x, d = b.y); // named (inlining not possible here)
-///
-/// // Not as good, much more fragile.
-/// tr!("{} is a {}", a, b);
+/// tr!("GREETING", name = player_name); // named, key-style message
+/// tr!(context; "{name} joined", name = player_name); // named, with context
+/// tr!("{0} is at {1}", player_name, a); // positional
 /// ```
 /// The methods are called from the [`Engine`](crate::classes::Engine) singleton.
 ///
+/// In Debug builds, every use of `tr!` is recorded, so that catalogs can be checked for missing messages and placeholders; see
+/// [`check_translations()`][crate::tools::check_translations].
+///
 /// See also: [Translation contexts](https://docs.godotengine.org/en/stable/tutorials/i18n/internationalizing_games.html#translation-contexts)
 /// in Godot.
 #[macro_export]
 macro_rules! tr {
     ($fmt:literal $(, $($args:tt)*)?) => {
-        $crate::classes::Engine::singleton()
-            .tr(format!($fmt $(, $($args)*)?).into())
+        $crate::__tr_parse!([tr] [$fmt] [] [] $($($args)*)?)
     };
 
     ($context:expr; $fmt:literal $(, $($args:tt)*)?) => {
-        $crate::classes::Engine::singleton()
-            .tr_ex(format!($fmt $(, $($args)*)?).into())
-            .context(format!("{}", $context).into())
-            .done()
+        $crate::__tr_parse!([tr $context] [$fmt] [] [] $($($args)*)?)
     };
 }
 
 /// A convenience macro for using the [`Object::tr_n()`](crate::classes::Object::tr_n()) and
 /// [`Object::tr_n_ex()`](crate::classes::Object::tr_n_ex()) methods.
 ///
-/// Allows for the use of format strings with arbitrary arguments. `n` is given prior to the format string, followed by `;`.
-/// Optionally, `context` for potentially ambiguous words can be added with `,` after `n` and before `;`.
+/// `n` is given prior to the message literals, followed by `;`. Optionally, `context` for potentially ambiguous words can be added
+/// with `,` after `n` and before `;`.
 ///
-/// Using named or positional parameters instead of `{}` may make it easier to use dynamic formatting once gdext supports it:
+/// Placeholders follow the same rules as in [`tr!`]. Each argument must be used by at least one of the two literals; the count
+/// itself is not substituted implicitly, pass it as an argument if needed.
 /// ```no_run
 /// # #[macro_use] extern crate godot;
-/// # use godot::builtin::Vector2i;
-/// # let a = Vector2i { x: 0, y: 0 };
-/// # let b = Vector2i { x: 0, y: 0 };
 /// # let context = "context";
 /// # let n = 2;
 /// use godot::tools::tr_n;
 ///
-/// // Good.
-/// tr_n!(n, context; "{a} is a {b}", "{a}s are {b}s"); // inlined, with context
-/// tr_n!(n; "{0} is a {1}", "{0}s are {1}s", a, b); // positional, without context
-/// tr_n!(n; "{c} is a {d}", "{c}s are {d}s", c = a.x, d = b.y); // named (inlining not possible here)
-///
-/// // Not as good, much more fragile.
-/// // Additionally, such syntax requires that BOTH format strings use ALL listed arguments.
-/// tr_n!(n; "{} is a {}", "{}s are {}s", a, b);
+/// tr_n!(n; "{n} enemy left", "{n} enemies left", n = n);
+/// tr_n!(n, context; "One file", "{0} files", n);
 /// ```
 /// The methods are called from the [`Engine`](crate::classes::Engine) singleton.
 ///
@@ -83,22 +86,537 @@ macro_rules! tr {
 #[macro_export]
 macro_rules! tr_n {
     ($n:expr; $singular:literal, $plural:literal $(, $($args:tt)*)?) => {
-        $crate::classes::Engine::singleton()
-            .tr_n(
-                format!($singular$(, $($args)*)?).into(),
-                format!($plural$(, $($args)*)?).into(),
-                $n,
-            )
+        $crate::__tr_parse!([tr_n $n] [$singular, $plural] [] [] $($($args)*)?)
     };
 
     ($n:expr, $context:expr; $singular:literal, $plural:literal $(, $($args:tt)*)?) => {
+        $crate::__tr_parse!([tr_n $n, $context] [$singular, $plural] [] [] $($($args)*)?)
+    };
+}
+
+/// Splits the arguments of `tr!`/`tr_n!` into positional and named ones, then expands to the translation call.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tr_parse {
+    // All arguments consumed.
+    ([$($mode:tt)*] [$($fmt:literal),+] [$(($pos:expr))*] [$(($name:ident, $value:expr))*]) => {{
+        const _: () = $crate::tools::__tr_check(
+            &[$($fmt),+],
+            &[$(stringify!($name)),*],
+            <[&str]>::len(&[$(stringify!($pos)),*]),
+        );
+
+        #[cfg(debug_assertions)]
+        $crate::sys::plugin_add!(__GODOT_TR_USAGES in $crate::tools; $crate::tools::TrUsage {
+            messages: &[$($fmt),+],
+            params: &[$(stringify!($name)),*],
+            positional: <[&str]>::len(&[$(stringify!($pos)),*]),
+            has_context: $crate::__tr_has_context!($($mode)*),
+            location: concat!(file!(), ":", line!()),
+        });
+
+        $crate::tools::__tr_format(
+            $crate::__tr_translate!([$($mode)*] [$($fmt),+]),
+            &[$(&$pos as &dyn ::std::fmt::Display),*],
+            &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),*],
+        )
+    }};
+
+    ($mode:tt $fmts:tt [$($pos:tt)*] [$($named:tt)*] $name:ident = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__tr_parse!($mode $fmts [$($pos)*] [$($named)* ($name, $value)] $($($rest)*)?)
+    };
+
+    ($mode:tt $fmts:tt [$($pos:tt)*] [$($named:tt)*] $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__tr_parse!($mode $fmts [$($pos)* ($value)] [$($named)*] $($($rest)*)?)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tr_translate {
+    ([tr] [$fmt:literal]) => {
+        $crate::classes::Engine::singleton().tr($crate::builtin::StringName::from($fmt))
+    };
+
+    ([tr $context:expr] [$fmt:literal]) => {
+        $crate::classes::Engine::singleton()
+            .tr_ex($crate::builtin::StringName::from($fmt))
+            .context(format!("{}", $context).into())
+            .done()
+    };
+
+    ([tr_n $n:expr] [$singular:literal, $plural:literal]) => {
+        $crate::classes::Engine::singleton().tr_n(
+            $crate::builtin::StringName::from($singular),
+            $crate::builtin::StringName::from($plural),
+            $n,
+        )
+    };
+
+    ([tr_n $n:expr, $context:expr] [$singular:literal, $plural:literal]) => {
         $crate::classes::Engine::singleton()
             .tr_n_ex(
-                format!($singular$(, $($args)*)?).into(),
-                format!($plural$(, $($args)*)?).into(),
+                $crate::builtin::StringName::from($singular),
+                $crate::builtin::StringName::from($plural),
                 $n,
             )
             .context(format!("{}", $context).into())
             .done()
     };
 }
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tr_has_context {
+    (tr) => {
+        false
+    };
+    (tr_n $n:expr) => {
+        false
+    };
+    ($($other:tt)*) => {
+        true
+    };
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Catalog validation
+
+/// Problem found by [`check_translations()`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum TranslationIssue {
+    /// A message used in `tr!` or `tr_n!` has no translation for the locale.
+    MissingMessage {
+        locale: String,
+        message: String,
+        location: &'static str,
+    },
+
+    /// The translation of a message contains a placeholder, for which the `tr!`/`tr_n!` call provides no argument.
+    UnknownPlaceholder {
+        locale: String,
+        message: String,
+        placeholder: String,
+        location: &'static str,
+    },
+}
+
+impl fmt::Display for TranslationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingMessage {
+                locale,
+                message,
+                location,
+            } => write!(f, "{location}: [{locale}] no translation for {message:?}"),
+            Self::UnknownPlaceholder {
+                locale,
+                message,
+                placeholder,
+                location,
+            } => write!(
+                f,
+                "{location}: [{locale}] translation of {message:?} uses {{{placeholder}}}, which is not an argument"
+            ),
+        }
+    }
+}
+
+/// Checks all `tr!` and `tr_n!` invocations of this extension against the given translations.
+///
+/// For each translation, reports messages that are not translated, and translations that use placeholders which the corresponding
+/// invocation does not provide. Invocations with a `context` are skipped, since the context is only known at runtime.
+///
+/// Invocations are only recorded in Debug builds; in Release builds, this always returns an empty list. A typical use is an
+/// integration test or a debug-only check at startup.
+pub fn check_translations(translations: &[Gd<Translation>]) -> Vec<TranslationIssue> {
+    let mut usages = Vec::new();
+    sys::plugin_foreach!(__GODOT_TR_USAGES; |usage: &TrUsage| {
+        if !usage.has_context {
+            usages.push(usage.clone());
+        }
+    });
+    usages.sort_by_key(|usage| usage.location);
+    usages.dedup_by_key(|usage| usage.location);
+
+    let mut issues = Vec::new();
+    for translation in translations {
+        let locale = translation.get_locale().to_string();
+
+        for usage in &usages {
+            let translated = match usage.messages {
+                [singular, plural] => [1, 2, 5]
+                    .iter()
+                    .map(|&n| {
+                        translation.get_plural_message(
+                            StringName::from(*singular),
+                            StringName::from(*plural),
+                            n,
+                        )
+                    })
+                    .collect(),
+                messages => messages
+                    .iter()
+                    .map(|message| translation.get_message(StringName::from(*message)))
+                    .collect::<Vec<_>>(),
+            };
+
+            if translated.iter().any(|text| text.is_empty()) {
+                issues.push(TranslationIssue::MissingMessage {
+                    locale: locale.clone(),
+                    message: usage.messages[0].to_string(),
+                    location: usage.location,
+                });
+                continue;
+            }
+
+            let mut unknown = translated
+                .iter()
+                .flat_map(|text| usage.unknown_placeholders(&text.to_string()))
+                .collect::<Vec<_>>();
+            unknown.dedup();
+
+            for placeholder in unknown {
+                issues.push(TranslationIssue::UnknownPlaceholder {
+                    locale: locale.clone(),
+                    message: usage.messages[0].to_string(),
+                    placeholder,
+                    location: usage.location,
+                });
+            }
+        }
+    }
+
+    issues
+}
+
+/// Checks all `tr!` and `tr_n!` invocations against the translations configured in the project settings.
+///
+/// These are the catalogs listed under _Project Settings > Localization > Translations_, i.e. imported CSV files and PO files.
+/// Catalogs that fail to load are skipped. See [`check_translations()`] for details.
+pub fn check_project_translations() -> Vec<TranslationIssue> {
+    let paths = ProjectSettings::singleton()
+        .get_setting("internationalization/locale/translations".into())
+        .try_to::<PackedStringArray>()
+        .unwrap_or_default();
+
+    let translations = paths
+        .as_slice()
+        .iter()
+        .filter_map(|path| try_load::<Translation>(path.clone()).ok())
+        .collect::<Vec<_>>();
+
+    check_translations(&translations)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+sys::plugin_registry!(pub __GODOT_TR_USAGES: TrUsage);
+
+/// One invocation of `tr!` or `tr_n!`.
+#[doc(hidden)]
+#[derive(Clone, Debug)]
+pub struct TrUsage {
+    pub messages: &'static [&'static str],
+    pub params: &'static [&'static str],
+    pub positional: usize,
+    pub has_context: bool,
+    pub location: &'static str,
+}
+
+impl TrUsage {
+    fn unknown_placeholders(&self, text: &str) -> Vec<String> {
+        let mut unknown = Vec::new();
+        let mut implicit = 0;
+
+        for_each_placeholder(text, |placeholder| {
+            let is_known = match placeholder {
+                "" => {
+                    implicit += 1;
+                    implicit <= self.positional
+                }
+                _ => match placeholder.parse::<usize>() {
+                    Ok(index) => index < self.positional,
+                    Err(_) => self.params.contains(&placeholder),
+                },
+            };
+
+            if !is_known {
+                unknown.push(placeholder.to_string());
+            }
+        });
+
+        unknown
+    }
+}
+
+/// Substitutes placeholders in the translated text.
+#[doc(hidden)]
+pub fn __tr_format(
+    translated: GString,
+    positional: &[&dyn fmt::Display],
+    named: &[(&str, &dyn fmt::Display)],
+) -> GString {
+    // Also without arguments, as `{{` and `}}` must be unescaped.
+    substitute(&translated.to_string(), positional, named).into()
+}
+
+/// Replaces `{}`, `{index}` and `{name}`; unknown placeholders are kept verbatim, so mistakes in translations remain visible.
+fn substitute(
+    text: &str,
+    positional: &[&dyn fmt::Display],
+    named: &[(&str, &dyn fmt::Display)],
+) -> String {
+    use std::fmt::Write as _;
+
+    let mut result = String::with_capacity(text.len());
+    let mut implicit = 0;
+    let mut rest = text;
+
+    while let Some(pos) = rest.find(['{', '}']) {
+        result.push_str(&rest[..pos]);
+        let tail = &rest[pos..];
+
+        if tail.starts_with("{{") || tail.starts_with("}}") {
+            result.push_str(&tail[..1]);
+            rest = &tail[2..];
+            continue;
+        }
+
+        let Some(end) = tail.find('}').filter(|_| tail.starts_with('{')) else {
+            // Unmatched brace.
+            result.push_str(&tail[..1]);
+            rest = &tail[1..];
+            continue;
+        };
+
+        let placeholder = &tail[1..end];
+        let value = match placeholder {
+            "" => {
+                implicit += 1;
+                positional.get(implicit - 1)
+            }
+            _ => match placeholder.parse::<usize>() {
+                Ok(index) => positional.get(index),
+                Err(_) => named
+                    .iter()
+                    .find(|(name, _)| *name == placeholder)
+                    .map(|(_, value)| value),
+            },
+        };
+
+        match value {
+            Some(value) => {
+                let _ = write!(result, "{value}");
+            }
+            None => result.push_str(&tail[..=end]),
+        }
+        rest = &tail[end + 1..];
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Calls `visit` with the content of each `{...}` placeholder (without braces).
+fn for_each_placeholder<'a>(text: &'a str, mut visit: impl FnMut(&'a str)) {
+    let mut rest = text;
+    while let Some(pos) = rest.find('{') {
+        let tail = &rest[pos..];
+        if tail.starts_with("{{") {
+            rest = &tail[2..];
+            continue;
+        }
+
+        let Some(end) = tail.find('}') else {
+            return;
+        };
+        visit(&tail[1..end]);
+        rest = &tail[end + 1..];
+    }
+}
+
+/// Compile-time validation of placeholders against the arguments of `tr!`/`tr_n!`.
+///
+/// Each literal may only use placeholders for which an argument exists; each argument must be used in at least one literal.
+#[doc(hidden)]
+pub const fn __tr_check(messages: &[&str], params: &[&str], positional: usize) {
+    assert!(
+        params.len() <= 64 && positional <= 64,
+        "tr!: at most 64 positional and 64 named arguments are supported"
+    );
+
+    let mut used_params = 0u64;
+    let mut used_positional = 0u64;
+
+    let mut m = 0;
+    while m < messages.len() {
+        let bytes = messages[m].as_bytes();
+        let mut implicit = 0;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let byte = bytes[i];
+            if byte == b'}' {
+                assert!(
+                    i + 1 < bytes.len() && bytes[i + 1] == b'}',
+                    "tr!: unmatched closing brace in message; double it for a literal brace"
+                );
+                i += 2;
+                continue;
+            }
+
+            if byte != b'{' {
+                i += 1;
+                continue;
+            }
+
+            if i + 1 < bytes.len() && bytes[i + 1] == b'{' {
+                i += 2;
+                continue;
+            }
+
+            // Placeholder content is bytes[start..end].
+            let start = i + 1;
+            let mut end = start;
+            while end < bytes.len() && bytes[end] != b'}' {
+                assert!(
+                    bytes[end] != b':',
+                    "tr!: format specs (after `:`) are not supported; format the argument beforehand"
+                );
+                assert!(
+                    bytes[end] != b'{',
+                    "tr!: unmatched opening brace in message; double it for a literal brace"
+                );
+                end += 1;
+            }
+            assert!(
+                end < bytes.len(),
+                "tr!: unmatched opening brace in message; double it for a literal brace"
+            );
+
+            if start == end {
+                assert!(
+                    implicit < positional,
+                    "tr!: message has more implicit placeholders than positional arguments"
+                );
+                used_positional |= 1 << implicit;
+                implicit += 1;
+            } else if bytes[start].is_ascii_digit() {
+                let mut index = 0;
+                let mut d = start;
+                while d < end {
+                    assert!(
+                        bytes[d].is_ascii_digit(),
+                        "tr!: invalid placeholder; expected empty, an index or a name"
+                    );
+                    index = index * 10 + (bytes[d] - b'0') as usize;
+                    d += 1;
+                }
+                assert!(
+                    index < positional,
+                    "tr!: positional placeholder has no matching argument"
+                );
+                used_positional |= 1 << index;
+            } else {
+                let mut p = 0;
+                let mut found = false;
+                while p < params.len() && !found {
+                    if bytes_eq(params[p].as_bytes(), bytes, start, end) {
+                        used_params |= 1 << p;
+                        found = true;
+                    }
+                    p += 1;
+                }
+                assert!(
+                    found,
+                    "tr!: named placeholder has no matching argument; variables are not captured implicitly, pass `name = value`"
+                );
+            }
+
+            i = end + 1;
+        }
+
+        m += 1;
+    }
+
+    let all_positional = if positional == 64 {
+        u64::MAX
+    } else {
+        (1 << positional) - 1
+    };
+    let all_params = if params.len() == 64 {
+        u64::MAX
+    } else {
+        (1 << params.len()) - 1
+    };
+
+    assert!(
+        used_positional == all_positional,
+        "tr!: positional argument is never used in the message"
+    );
+    assert!(
+        used_params == all_params,
+        "tr!: named argument is never used in the message"
+    );
+}
+
+const fn bytes_eq(name: &[u8], text: &[u8], start: usize, end: usize) -> bool {
+    if name.len() != end - start {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < name.len() {
+        if name[i] != text[start + i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn substitute_placeholders() {
+        let result = substitute(
+            "{name} has {} of {1} {{coins}}, {unknown}",
+            &[&3, &"10"],
+            &[("name", &"Ana")],
+        );
+        assert_eq!(result, "Ana has 3 of 10 {coins}, {unknown}");
+    }
+
+    #[test]
+    fn substitute_without_arguments() {
+        assert_eq!(
+            substitute("{{literal}} {kept}", &[], &[]),
+            "{literal} {kept}"
+        );
+    }
+
+    #[test]
+    fn check_accepts_valid_messages() {
+        __tr_check(&["{0} and {}"], &[], 1);
+        __tr_check(&["One file", "{n} files"], &["n"], 0);
+        __tr_check(&["{{literal}}"], &[], 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "never used")]
+    fn check_rejects_unused_argument() {
+        __tr_check(&["Hello"], &["name"], 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "no matching argument")]
+    fn check_rejects_captured_variable() {
+        __tr_check(&["Hello {name}"], &[], 0);
+    }
+}
@@ -0,0 +1,612 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Typed builder for [`Tween`] animations.
+//!
+//! `Tween::tween_property()` takes the property as a string and the final value as a variant. If either is wrong, Godot prints an error
+//! once the tween runs, and the animation silently does nothing. The builder returned by [`tween()`] checks each property against the
+//! target's property list when it is added, and accepts Rust closures for method and callback steps.
+//!
+//! ```no_run
+//! use godot::prelude::*;
+//! use godot::tools::tween::{tween, Ease};
+//!
+//! fn slide_in(panel: &Gd<Node2D>) {
+//!     tween(panel)
+//!         .prop("position", Vector2::new(100.0, 0.0))
+//!         .duration(0.3)
+//!         .ease(Ease::OutCubic)
+//!         .prop("modulate:a", 1.0)
+//!         .duration(0.2)
+//!         .parallel()
+//!         .callback(|| godot_print!("panel visible"));
+//! }
+//! ```
+//!
+//! Steps run one after another, unless they are marked with [`parallel()`][TweenBuilder::parallel]. Settings like `duration()` and
+//! `ease()` apply to the most recently added step. The steps are handed over to Godot when the builder is dropped, or explicitly
+//! with [`start()`][TweenBuilder::start].
+//!
+//! Closures are invoked on the main thread and can thus capture non-thread-safe state such as `Gd` pointers. They are released
+//! together with the tween.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::{real, Callable, NodePath, Variant, VariantType};
+use crate::classes::tween::{EaseType, TransitionType};
+use crate::classes::{Node, Object, Tween};
+use crate::meta::{FromGodot, ToGodot};
+use crate::obj::{EngineEnum, Gd, Inherits};
+
+/// Starts building a tween that animates properties of `node`.
+///
+/// The tween is bound to `node`: it stops when the node leaves the tree, and is paused while the node is paused.
+///
+/// # Panics
+/// If `node` is not inside the scene tree.
+pub fn tween<T>(node: &Gd<T>) -> TweenBuilder
+where
+    T: Inherits<Node>,
+{
+    let mut node = node.clone().upcast::<Node>();
+    let tween = node
+        .create_tween()
+        .expect("Node::create_tween() returned null");
+
+    TweenBuilder {
+        tween,
+        target: node.upcast(),
+        pending: None,
+    }
+}
+
+/// Easing curve of a tween step: combination of Godot's [`TransitionType`] and [`EaseType`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum Ease {
+    /// Constant speed.
+    #[default]
+    Linear,
+
+    InSine,
+    OutSine,
+    InOutSine,
+
+    InQuad,
+    OutQuad,
+    InOutQuad,
+
+    InCubic,
+    OutCubic,
+    InOutCubic,
+
+    InQuart,
+    OutQuart,
+    InOutQuart,
+
+    InQuint,
+    OutQuint,
+    InOutQuint,
+
+    InExpo,
+    OutExpo,
+    InOutExpo,
+
+    InCirc,
+    OutCirc,
+    InOutCirc,
+
+    /// Overshoots backwards at the start.
+    InBack,
+    /// Overshoots the target at the end.
+    OutBack,
+    InOutBack,
+
+    InElastic,
+    OutElastic,
+    InOutElastic,
+
+    InBounce,
+    OutBounce,
+    InOutBounce,
+
+    /// Any other combination, e.g. with [`EaseType::OUT_IN`].
+    Custom(TransitionType, EaseType),
+}
+
+impl Ease {
+    /// Evaluates the curve at `t` in `0..=1` in Rust, without creating a tween. See [`curves::transition()`][crate::tools::curves::transition].
+    pub fn sample(self, t: real) -> real {
+        let (transition, ease) = self.to_godot_types();
+        crate::tools::curves::transition(transition, ease, t)
+    }
+
+    /// The Godot transition and ease type for this curve.
+    pub fn to_godot_types(self) -> (TransitionType, EaseType) {
+        use EaseType as E;
+        use TransitionType as T;
+
+        match self {
+            Self::Linear => (T::LINEAR, E::IN_OUT),
+            Self::InSine => (T::SINE, E::IN),
+            Self::OutSine => (T::SINE, E::OUT),
+            Self::InOutSine => (T::SINE, E::IN_OUT),
+            Self::InQuad => (T::QUAD, E::IN),
+            Self::OutQuad => (T::QUAD, E::OUT),
+            Self::InOutQuad => (T::QUAD, E::IN_OUT),
+            Self::InCubic => (T::CUBIC, E::IN),
+            Self::OutCubic => (T::CUBIC, E::OUT),
+            Self::InOutCubic => (T::CUBIC, E::IN_OUT),
+            Self::InQuart => (T::QUART, E::IN),
+            Self::OutQuart => (T::QUART, E::OUT),
+            Self::InOutQuart => (T::QUART, E::IN_OUT),
+            Self::InQuint => (T::QUINT, E::IN),
+            Self::OutQuint => (T::QUINT, E::OUT),
+            Self::InOutQuint => (T::QUINT, E::IN_OUT),
+            Self::InExpo => (T::EXPO, E::IN),
+            Self::OutExpo => (T::EXPO, E::OUT),
+            Self::InOutExpo => (T::EXPO, E::IN_OUT),
+            Self::InCirc => (T::CIRC, E::IN),
+            Self::OutCirc => (T::CIRC, E::OUT),
+            Self::InOutCirc => (T::CIRC, E::IN_OUT),
+            Self::InBack => (T::BACK, E::IN),
+            Self::OutBack => (T::BACK, E::OUT),
+            Self::InOutBack => (T::BACK, E::IN_OUT),
+            Self::InElastic => (T::ELASTIC, E::IN),
+            Self::OutElastic => (T::ELASTIC, E::OUT),
+            Self::InOutElastic => (T::ELASTIC, E::IN_OUT),
+            Self::InBounce => (T::BOUNCE, E::IN),
+            Self::OutBounce => (T::BOUNCE, E::OUT),
+            Self::InOutBounce => (T::BOUNCE, E::IN_OUT),
+            Self::Custom(transition, ease) => (transition, ease),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Builder
+
+/// Builder for a [`Tween`], created with [`tween()`].
+///
+/// See [module docs](self) for an overview.
+#[must_use = "steps are only added once the builder is dropped; call start() to obtain the tween"]
+pub struct TweenBuilder {
+    tween: Gd<Tween>,
+    target: Gd<Object>,
+    pending: Option<Step>,
+}
+
+impl TweenBuilder {
+    /// Adds a step that animates `property` of the target towards `final_value`.
+    ///
+    /// The property can also refer to a component, e.g. `"position:x"`. Defaults to a duration of 1 second and [`Ease::Linear`].
+    ///
+    /// # Panics
+    /// If the target has no such property, or the property's type doesn't match `final_value`. See [`try_prop()`][Self::try_prop]
+    /// for a non-panicking version.
+    pub fn prop(self, property: &str, final_value: impl ToGodot) -> Self {
+        self.try_prop(property, final_value)
+            .unwrap_or_else(|err| panic!("tween(): {err}"))
+    }
+
+    /// Like [`prop()`][Self::prop], but returns an error instead of panicking.
+    ///
+    /// The builder is consumed on error; steps added before have already been handed to the tween.
+    pub fn try_prop(
+        mut self,
+        property: &str,
+        final_value: impl ToGodot,
+    ) -> Result<Self, TweenError> {
+        let final_value = final_value.to_variant();
+        check_property(&self.target, property, final_value.get_type())?;
+
+        self.push(StepKind::Property {
+            path: NodePath::from(property),
+            final_value,
+            from: None,
+            is_relative: false,
+            #[cfg(since_api = "4.3")]
+            interpolator: None,
+        });
+        Ok(self)
+    }
+
+    /// Adds a step that calls `method` with values interpolated from `from` to `to`.
+    ///
+    /// Useful for animating state that is not a property, e.g. a field of a Rust class.
+    pub fn method<V, F>(mut self, from: V, to: V, mut method: F) -> Self
+    where
+        V: ToGodot + FromGodot,
+        F: FnMut(V) + 'static,
+    {
+        let callable = Callable::from_local_fn("TweenBuilder::method", move |args| {
+            let value = args.first().ok_or(())?.try_to::<V>().map_err(|_| ())?;
+            method(value);
+            Ok(Variant::nil())
+        });
+
+        self.push(StepKind::Method {
+            callable,
+            from: from.to_variant(),
+            to: to.to_variant(),
+        });
+        self
+    }
+
+    /// Adds a step that invokes `callback` once.
+    pub fn callback<F>(mut self, callback: F) -> Self
+    where
+        F: FnOnce() + 'static,
+    {
+        let mut callback = Some(callback);
+        let callable = Callable::from_local_fn("TweenBuilder::callback", move |_args| {
+            if let Some(callback) = callback.take() {
+                callback();
+            }
+            Ok(Variant::nil())
+        });
+
+        self.push(StepKind::Callback(callable));
+        self
+    }
+
+    /// Adds a step that waits for `seconds` without animating anything.
+    pub fn interval(mut self, seconds: f64) -> Self {
+        self.push(StepKind::Interval);
+        self.pending_mut("interval").duration = seconds;
+        self
+    }
+
+    /// Sets the duration of the last step, in seconds.
+    ///
+    /// # Panics
+    /// If there is no step, or the last step is a callback.
+    pub fn duration(mut self, seconds: f64) -> Self {
+        let step = self.pending_mut("duration");
+        assert!(
+            !matches!(step.kind, StepKind::Callback(_)),
+            "tween(): duration() is not supported for callback steps"
+        );
+
+        step.duration = seconds;
+        self
+    }
+
+    /// Sets the easing curve of the last step.
+    ///
+    /// # Panics
+    /// If there is no step, or the last step is not a property or method step.
+    pub fn ease(mut self, ease: Ease) -> Self {
+        let step = self.pending_mut("ease");
+        assert!(
+            matches!(
+                step.kind,
+                StepKind::Property { .. } | StepKind::Method { .. }
+            ),
+            "tween(): ease() is only supported for property and method steps"
+        );
+
+        step.ease = Some(ease);
+        self
+    }
+
+    /// Delays the start of the last step by `seconds`.
+    ///
+    /// # Panics
+    /// If there is no step, or the last step is an interval.
+    pub fn delay(mut self, seconds: f64) -> Self {
+        let step = self.pending_mut("delay");
+        assert!(
+            !matches!(step.kind, StepKind::Interval),
+            "tween(): delay() is not supported for interval steps"
+        );
+
+        step.delay = seconds;
+        self
+    }
+
+    /// Runs the last step together with the previous one, instead of after it.
+    ///
+    /// # Panics
+    /// If there is no step.
+    pub fn parallel(mut self) -> Self {
+        self.pending_mut("parallel").is_parallel = true;
+        self
+    }
+
+    /// Starts the property of the last step at `value`, instead of its current value.
+    ///
+    /// # Panics
+    /// If there is no step, the last step is not a property step, or `value` has the wrong type.
+    pub fn from(mut self, value: impl ToGodot) -> Self {
+        let value = value.to_variant();
+        let target = self.target.clone();
+
+        let StepKind::Property { path, from, .. } = &mut self.pending_mut("from").kind else {
+            panic!("tween(): from() is only supported for property steps");
+        };
+
+        check_property(&target, &path.to_string(), value.get_type())
+            .unwrap_or_else(|err| panic!("tween(): {err}"));
+
+        *from = Some(value);
+        self
+    }
+
+    /// Interprets the final value of the last step as an offset to the current value.
+    ///
+    /// # Panics
+    /// If there is no step, or the last step is not a property step.
+    pub fn relative(mut self) -> Self {
+        let StepKind::Property { is_relative, .. } = &mut self.pending_mut("relative").kind else {
+            panic!("tween(): relative() is only supported for property steps");
+        };
+
+        *is_relative = true;
+        self
+    }
+
+    /// Maps the progress of the last step through `curve`, instead of an easing curve.
+    ///
+    /// `curve` receives the elapsed fraction of the duration in `0.0..=1.0`, and returns the interpolation weight. Values outside
+    /// `0.0..=1.0` overshoot.
+    ///
+    /// # Panics
+    /// If there is no step, or the last step is not a property step.
+    #[cfg(since_api = "4.3")]
+    pub fn interpolate<F>(mut self, mut curve: F) -> Self
+    where
+        F: FnMut(f64) -> f64 + 'static,
+    {
+        let callable = Callable::from_local_fn("TweenBuilder::interpolate", move |args| {
+            let progress = args.first().ok_or(())?.try_to::<f64>().map_err(|_| ())?;
+            Ok(curve(progress).to_variant())
+        });
+
+        let StepKind::Property { interpolator, .. } = &mut self.pending_mut("interpolate").kind
+        else {
+            panic!("tween(): interpolate() is only supported for property steps");
+        };
+
+        *interpolator = Some(callable);
+        self
+    }
+
+    /// Repeats the whole tween `count` times. A count of 0 repeats infinitely.
+    ///
+    /// Infinite loops require at least one step with a non-zero duration; otherwise Godot stops the tween.
+    pub fn loops(mut self, count: u32) -> Self {
+        self.tween.set_loops_ex().loops(count as i32).done();
+        self
+    }
+
+    /// Adds all steps to the tween, and returns it.
+    ///
+    /// Calling this is only necessary to access the tween, e.g. to connect to its `finished` signal or to kill it.
+    pub fn start(mut self) -> Gd<Tween> {
+        self.flush();
+        self.tween.clone()
+    }
+
+    fn push(&mut self, kind: StepKind) {
+        self.flush();
+        self.pending = Some(Step {
+            kind,
+            duration: 1.0,
+            ease: None,
+            delay: 0.0,
+            is_parallel: false,
+        });
+    }
+
+    fn pending_mut(&mut self, setting: &str) -> &mut Step {
+        self.pending
+            .as_mut()
+            .unwrap_or_else(|| panic!("tween(): {setting}() requires a preceding step"))
+    }
+
+    /// Hands the pending step over to the Godot tween.
+    fn flush(&mut self) {
+        let Some(step) = self.pending.take() else {
+            return;
+        };
+
+        let tween = &mut self.tween;
+        if step.is_parallel {
+            tween.parallel();
+        }
+
+        let (transition, ease) = step.ease.unwrap_or_default().to_godot_types();
+
+        match step.kind {
+            StepKind::Property {
+                path,
+                final_value,
+                from,
+                is_relative,
+                #[cfg(since_api = "4.3")]
+                interpolator,
+            } => {
+                let mut tweener = tween
+                    .tween_property(self.target.clone(), path, final_value, step.duration)
+                    .expect("Tween::tween_property() returned null");
+
+                tweener.set_trans(transition);
+                tweener.set_ease(ease);
+                tweener.set_delay(step.delay);
+
+                if let Some(from) = from {
+                    tweener.from(from);
+                }
+                if is_relative {
+                    tweener.as_relative();
+                }
+                #[cfg(since_api = "4.3")]
+                if let Some(interpolator) = interpolator {
+                    tweener.set_custom_interpolator(interpolator);
+                }
+            }
+            StepKind::Method { callable, from, to } => {
+                let mut tweener = tween
+                    .tween_method(callable, from, to, step.duration)
+                    .expect("Tween::tween_method() returned null");
+
+                tweener.set_trans(transition);
+                tweener.set_ease(ease);
+                tweener.set_delay(step.delay);
+            }
+            StepKind::Callback(callable) => {
+                let mut tweener = tween
+                    .tween_callback(callable)
+                    .expect("Tween::tween_callback() returned null");
+
+                tweener.set_delay(step.delay);
+            }
+            StepKind::Interval => {
+                tween.tween_interval(step.duration);
+            }
+        }
+    }
+}
+
+impl Drop for TweenBuilder {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+impl fmt::Debug for TweenBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TweenBuilder")
+            .field("tween", &self.tween)
+            .field("target", &self.target)
+            .finish()
+    }
+}
+
+struct Step {
+    kind: StepKind,
+    duration: f64,
+    ease: Option<Ease>,
+    delay: f64,
+    is_parallel: bool,
+}
+
+enum StepKind {
+    Property {
+        path: NodePath,
+        final_value: Variant,
+        from: Option<Variant>,
+        is_relative: bool,
+        #[cfg(since_api = "4.3")]
+        interpolator: Option<Callable>,
+    },
+    Method {
+        callable: Callable,
+        from: Variant,
+        to: Variant,
+    },
+    Callback(Callable),
+    Interval,
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Errors
+
+/// Invalid property step, as returned by [`TweenBuilder::try_prop()`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum TweenError {
+    /// The target has no property with this name.
+    UnknownProperty {
+        class_name: String,
+        property: String,
+    },
+
+    /// The value has a different type than the property.
+    TypeMismatch {
+        class_name: String,
+        property: String,
+        expected: VariantType,
+        actual: VariantType,
+    },
+}
+
+impl fmt::Display for TweenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownProperty {
+                class_name,
+                property,
+            } => write!(f, "class {class_name} has no property `{property}`"),
+            Self::TypeMismatch {
+                class_name,
+                property,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "property `{property}` of class {class_name} has type {expected:?}, but value has type {actual:?}"
+            ),
+        }
+    }
+}
+
+impl Error for TweenError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+/// Checks that `target` has the (possibly indexed) property `path`, and that values of type `actual` can be interpolated with it.
+fn check_property(target: &Gd<Object>, path: &str, actual: VariantType) -> Result<(), TweenError> {
+    let class_name = target.get_class().to_string();
+
+    // For components like "position:x", only the base property is listed; the component type is known from the current value.
+    let (base_property, component) = match path.split_once(':') {
+        Some((base, component)) => (base, Some(component)),
+        None => (path, None),
+    };
+
+    let info = target.get_property_list().iter_shared().find(|info| {
+        info.get("name")
+            .is_some_and(|name| name.stringify().to_string() == base_property)
+    });
+
+    let Some(info) = info else {
+        return Err(TweenError::UnknownProperty {
+            class_name,
+            property: path.to_string(),
+        });
+    };
+
+    let expected = if component.is_some() {
+        target.get_indexed(NodePath::from(path)).get_type()
+    } else {
+        info.get("type")
+            .map_or(VariantType::NIL, |ty| VariantType::from_ord(ty.to::<i32>()))
+    };
+
+    if !is_compatible(expected, actual) {
+        return Err(TweenError::TypeMismatch {
+            class_name,
+            property: path.to_string(),
+            expected,
+            actual,
+        });
+    }
+
+    Ok(())
+}
+
+/// Whether Godot can interpolate between values of type `expected` (the property) and `actual` (the tween value).
+fn is_compatible(expected: VariantType, actual: VariantType) -> bool {
+    use VariantType as T;
+
+    // NIL: property typed as Variant, or component of a value that is currently null; Godot checks at runtime.
+    expected == actual
+        || expected == T::NIL
+        || matches!((expected, actual), (T::INT, T::FLOAT) | (T::FLOAT, T::INT))
+}
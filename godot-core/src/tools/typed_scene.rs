@@ -0,0 +1,204 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::builtin::{GString, StringName};
+use crate::classes::{ClassDb, Node, PackedScene};
+use crate::godot_error;
+use crate::meta::error::{ConvertError, IoError};
+use crate::meta::{FromGodot, GodotConvert, ToGodot};
+use crate::obj::{Gd, Inherits};
+use crate::registry::property::{Export, PropertyHintInfo, TypeStringHint, Var};
+use crate::tools::try_load;
+
+/// A [`PackedScene`] whose root node is known to be of class `T`.
+///
+/// The root class is validated when the scene is loaded or assigned, so that [`instantiate()`][Self::instantiate] can directly return
+/// `Gd<T>`, without casting at every call site.
+///
+/// # Properties
+/// `TypedScene<T>` can be used in `#[export]` fields (typically wrapped in `Option`). The inspector then accepts any `PackedScene`;
+/// assigning a scene with a different root class is rejected with an error.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::tools::TypedScene;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Spawner {
+///     #[export]
+///     enemy_scene: Option<TypedScene<Node2D>>,
+///     base: Base<Node>,
+/// }
+///
+/// let bullet = TypedScene::<Node2D>::load("res://bullet.tscn");
+/// let instance: Gd<Node2D> = bullet.instantiate();
+/// ```
+pub struct TypedScene<T: Inherits<Node>> {
+    scene: Gd<PackedScene>,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Inherits<Node>> TypedScene<T> {
+    /// ⚠️ Loads a scene from `path`, panicking on error.
+    ///
+    /// # Panics
+    /// If the scene cannot be loaded, or its root node is not of class `T` or derived.
+    pub fn load(path: impl Into<GString>) -> Self {
+        Self::try_load(path).unwrap_or_else(|err| panic!("failed: {err}"))
+    }
+
+    /// Loads a scene from `path`, validating the class of its root node.
+    pub fn try_load(path: impl Into<GString>) -> Result<Self, IoError> {
+        let scene = try_load::<PackedScene>(path)?;
+
+        Self::try_from_scene(scene)
+    }
+
+    /// Wraps an already loaded scene, validating the class of its root node.
+    ///
+    /// If the root class cannot be determined statically (e.g. for an empty scene), validation is deferred to
+    /// [`instantiate()`][Self::instantiate].
+    pub fn try_from_scene(scene: Gd<PackedScene>) -> Result<Self, IoError> {
+        if let Some(actual) = scene_root_class(&scene) {
+            let expected = T::class_name().to_string_name();
+
+            if !ClassDb::singleton().is_parent_class(actual.clone(), expected) {
+                return Err(IoError::loading_scene_root(
+                    T::class_name().to_string(),
+                    actual.to_string(),
+                    scene.get_path().to_string(),
+                ));
+            }
+        }
+
+        Ok(Self {
+            scene,
+            _marker: PhantomData,
+        })
+    }
+
+    /// ⚠️ Instantiates the scene.
+    ///
+    /// # Panics
+    /// If instantiation fails, or the root node is not of class `T` (only possible when validation during construction was skipped).
+    pub fn instantiate(&self) -> Gd<T> {
+        self.scene.instantiate_as::<T>()
+    }
+
+    /// Instantiates the scene (fallible).
+    pub fn try_instantiate(&self) -> Option<Gd<T>> {
+        self.scene.try_instantiate_as::<T>()
+    }
+
+    /// Returns the underlying packed scene.
+    pub fn scene(&self) -> &Gd<PackedScene> {
+        &self.scene
+    }
+
+    /// Returns the underlying packed scene, consuming `self`.
+    pub fn into_scene(self) -> Gd<PackedScene> {
+        self.scene
+    }
+}
+
+impl<T: Inherits<Node>> Clone for TypedScene<T> {
+    fn clone(&self) -> Self {
+        Self {
+            scene: self.scene.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Inherits<Node>> PartialEq for TypedScene<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.scene == other.scene
+    }
+}
+
+impl<T: Inherits<Node>> fmt::Debug for TypedScene<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedScene")
+            .field("root", &T::class_name())
+            .field("path", &self.scene.get_path())
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Conversions and properties
+
+impl<T: Inherits<Node>> GodotConvert for TypedScene<T> {
+    type Via = Gd<PackedScene>;
+}
+
+impl<T: Inherits<Node>> ToGodot for TypedScene<T> {
+    fn to_godot(&self) -> Self::Via {
+        self.scene.clone()
+    }
+
+    fn into_godot(self) -> Self::Via {
+        self.scene
+    }
+}
+
+impl<T: Inherits<Node>> FromGodot for TypedScene<T> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Self::try_from_scene(via).map_err(ConvertError::with_error)
+    }
+}
+
+impl<T: Inherits<Node>> Var for TypedScene<T> {
+    fn get_property(&self) -> Self::Via {
+        self.to_godot()
+    }
+
+    fn set_property(&mut self, value: Self::Via) {
+        // Reject mismatching scenes assigned in the inspector, keeping the previous value.
+        match Self::try_from_scene(value) {
+            Ok(scene) => *self = scene,
+            Err(err) => godot_error!("{err}"),
+        }
+    }
+}
+
+impl<T: Inherits<Node>> Export for TypedScene<T> {
+    fn default_export_info() -> PropertyHintInfo {
+        <Gd<PackedScene> as Export>::default_export_info()
+    }
+}
+
+impl<T: Inherits<Node>> TypeStringHint for TypedScene<T> {
+    fn type_string() -> String {
+        <Gd<PackedScene> as TypeStringHint>::type_string()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+/// Determines the class of the scene's root node without instantiating it.
+fn scene_root_class(scene: &Gd<PackedScene>) -> Option<StringName> {
+    let state = scene.get_state()?;
+    if state.get_node_count() == 0 {
+        return None;
+    }
+
+    let class_name = state.get_node_type(0);
+    if !class_name.is_empty() {
+        return Some(class_name);
+    }
+
+    // Root of an inherited scene: the class is defined by the base scene.
+    let base_scene = state.get_node_instance(0)?;
+    scene_root_class(&base_scene)
+}
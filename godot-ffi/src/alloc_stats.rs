@@ -0,0 +1,122 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Counters for constructions and destructions of heap-allocated builtins.
+//!
+//! The hooks are invoked from the generic FFI constructors and from `Drop` impls. Without the `alloc-stats` feature, they compile to
+//! nothing. The public API lives in `godot::diagnostics`.
+
+use crate::{GodotFfi, VariantType};
+
+/// Called whenever a new builtin value is created on the Rust side.
+#[inline(always)]
+pub fn on_construct<T: GodotFfi>() {
+    #[cfg(feature = "alloc-stats")]
+    counters::record_construct(T::variant_type());
+}
+
+/// Called whenever a builtin value owned by Rust is destroyed.
+#[inline(always)]
+pub fn on_destruct<T: GodotFfi>() {
+    #[cfg(feature = "alloc-stats")]
+    counters::record_destruct(T::variant_type());
+}
+
+/// Whether values of type `ty` are counted.
+///
+/// Only types which own engine-side memory are tracked; value types like `Vector2` are not interesting for allocation statistics.
+pub fn is_tracked(ty: VariantType) -> bool {
+    ty == VariantType::STRING
+        || ty == VariantType::STRING_NAME
+        || ty == VariantType::NODE_PATH
+        || ty == VariantType::DICTIONARY
+        || ty == VariantType::ARRAY
+        || is_packed_array(ty)
+}
+
+fn is_packed_array(ty: VariantType) -> bool {
+    // Packed arrays are the last variant types, after ARRAY.
+    let ord = ty.sys();
+    ord > VariantType::ARRAY.sys() && ord < VariantType::MAX.sys()
+}
+
+#[cfg(feature = "alloc-stats")]
+pub use counters::{reset_peaks, snapshot, RawCounters};
+
+#[cfg(feature = "alloc-stats")]
+mod counters {
+    use super::is_tracked;
+    use crate::VariantType;
+    use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+
+    // Upper bound for VariantType ordinals, with some headroom for future Godot versions.
+    const SLOTS: usize = 64;
+
+    #[allow(clippy::declare_interior_mutable_const)] // Only used as array initializer.
+    const ZERO_U64: AtomicU64 = AtomicU64::new(0);
+    #[allow(clippy::declare_interior_mutable_const)]
+    const ZERO_I64: AtomicI64 = AtomicI64::new(0);
+
+    static CONSTRUCTED: [AtomicU64; SLOTS] = [ZERO_U64; SLOTS];
+    static DESTRUCTED: [AtomicU64; SLOTS] = [ZERO_U64; SLOTS];
+    static LIVE: [AtomicI64; SLOTS] = [ZERO_I64; SLOTS];
+    static PEAK: [AtomicI64; SLOTS] = [ZERO_I64; SLOTS];
+
+    /// Counter values for one variant type.
+    #[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+    pub struct RawCounters {
+        pub constructed: u64,
+        pub destructed: u64,
+        pub live: i64,
+        pub peak: i64,
+    }
+
+    pub(super) fn record_construct(ty: VariantType) {
+        let Some(slot) = slot(ty) else {
+            return;
+        };
+
+        CONSTRUCTED[slot].fetch_add(1, Ordering::Relaxed);
+        let live = LIVE[slot].fetch_add(1, Ordering::Relaxed) + 1;
+        PEAK[slot].fetch_max(live, Ordering::Relaxed);
+    }
+
+    pub(super) fn record_destruct(ty: VariantType) {
+        let Some(slot) = slot(ty) else {
+            return;
+        };
+
+        DESTRUCTED[slot].fetch_add(1, Ordering::Relaxed);
+        LIVE[slot].fetch_sub(1, Ordering::Relaxed);
+    }
+
+    /// Returns the current counters for `ty`, or `None` if the type is not tracked.
+    pub fn snapshot(ty: VariantType) -> Option<RawCounters> {
+        let slot = slot(ty)?;
+
+        Some(RawCounters {
+            constructed: CONSTRUCTED[slot].load(Ordering::Relaxed),
+            destructed: DESTRUCTED[slot].load(Ordering::Relaxed),
+            live: LIVE[slot].load(Ordering::Relaxed),
+            peak: PEAK[slot].load(Ordering::Relaxed),
+        })
+    }
+
+    /// Resets the high-water marks to the current number of live values.
+    pub fn reset_peaks() {
+        for slot in 0..SLOTS {
+            let live = LIVE[slot].load(Ordering::Relaxed);
+            PEAK[slot].store(live, Ordering::Relaxed);
+        }
+    }
+
+    fn slot(ty: VariantType) -> Option<usize> {
+        let slot = ty.sys() as usize;
+
+        (is_tracked(ty) && slot < SLOTS).then_some(slot)
+    }
+}
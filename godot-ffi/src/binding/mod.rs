@@ -11,6 +11,15 @@ use crate::{
     GdextRuntimeMetadata, ManualInitCell, UtilityFunctionTable,
 };
 
+// Without atomics (`-C target-feature=+atomics`, which requires a Godot web export with thread support), wasm runs strictly single-threaded,
+// so only the single-threaded binding storage applies.
+#[cfg(all(
+    target_family = "wasm",
+    feature = "experimental-threads",
+    not(target_feature = "atomics")
+))]
+compile_error!("Feature `experimental-threads` on WebAssembly requires the `atomics` target feature (Godot web export with threads).");
+
 #[cfg(feature = "experimental-threads")]
 mod multi_threaded;
 #[cfg(not(feature = "experimental-threads"))]
@@ -154,6 +163,65 @@ pub unsafe fn class_editor_api() -> &'static ClassEditorMethodTable {
     )
 }
 
+/// Verifies in debug builds that engine method `class_name::method_name()` can be called, i.e. that the Godot binding is initialized
+/// and the method table for `level` has been loaded.
+///
+/// Called by generated engine methods before fetching their method bind. Without this check, calling e.g. a scene class at init level
+/// `Core` accesses an uninitialized table (or function pointer), which crashes without indication of the cause. In release builds,
+/// this is a no-op.
+#[inline]
+pub fn debug_check_class_api_level(
+    level: crate::ClassApiLevel,
+    class_name: &str,
+    method_name: &str,
+) {
+    if cfg!(debug_assertions) && !is_class_api_level_loaded(level) {
+        report_class_api_level(level, class_name, method_name);
+    }
+}
+
+fn is_class_api_level_loaded(level: crate::ClassApiLevel) -> bool {
+    if !is_initialized() {
+        return false;
+    }
+
+    // SAFETY: binding is initialized.
+    let binding = unsafe { get_binding() };
+    match level {
+        crate::ClassApiLevel::Server => binding.class_server_method_table.is_initialized(),
+        crate::ClassApiLevel::Scene => binding.class_scene_method_table.is_initialized(),
+        crate::ClassApiLevel::Editor => binding.class_editor_method_table.is_initialized(),
+    }
+}
+
+#[cold]
+fn report_class_api_level(level: crate::ClassApiLevel, class_name: &str, method_name: &str) -> ! {
+    let call = format!("{class_name}::{method_name}()");
+
+    if !is_initialized() {
+        panic!(
+            "{call} was called before the GDExtension interface was initialized.\n\
+            Engine APIs are only available once Godot has loaded the extension; this typically happens when calling them from \
+            static initializers, `Drop` of statics, or after the extension has been unloaded."
+        );
+    }
+
+    let (level_name, hint) = match level {
+        crate::ClassApiLevel::Server => ("Servers", ""),
+        crate::ClassApiLevel::Scene => ("Scene", ""),
+        crate::ClassApiLevel::Editor => (
+            "Editor",
+            "\nEditor classes are only available when running inside the editor, never in exported games. \
+            Check `Engine::is_editor_hint()` before using them at runtime.",
+        ),
+    };
+
+    panic!(
+        "{call} requires init level `{level_name}`, which is not loaded yet.\n\
+        Move the call to `ExtensionLibrary::on_level_init(InitLevel::{level_name})` or a later point.{hint}"
+    );
+}
+
 /// # Safety
 /// The table must not have been initialized yet.
 unsafe fn initialize_table<T>(table: &ManualInitCell<T>, value: T, what: &str) {
@@ -178,10 +178,11 @@ macro_rules! ffi_methods_one {
         $( #[$attr] )? $vis
         unsafe fn $new_from_sys(ptr: <$Ptr as $crate::SysPtr>::Const) -> Self {
             // TODO: Directly use copy constructors here?
+            // The borrowed value is owned by the caller, so it must neither be dropped nor counted in the allocation statistics.
+            // Only the returned copy is counted, on construction (in `clone()`) and on drop.
             let opaque = std::ptr::read(ptr.cast());
-            let new = Self::from_opaque(opaque);
-            std::mem::forget(new.clone());
-            new
+            let borrowed = std::mem::ManuallyDrop::new(Self::from_opaque(opaque));
+            std::mem::ManuallyDrop::into_inner(borrowed.clone())
         }
     };
     (OpaquePtr $Ptr:ty; $( #[$attr:meta] )? $vis:vis $new_with_uninit:ident = new_with_uninit) => {
@@ -190,6 +191,7 @@ macro_rules! ffi_methods_one {
             let mut raw = std::mem::MaybeUninit::uninit();
             init(raw.as_mut_ptr() as *mut _);
 
+            $crate::alloc_stats::on_construct::<Self>();
             Self::from_opaque(raw.assume_init())
         }
     };
@@ -222,6 +224,7 @@ macro_rules! ffi_methods_one {
     (OpaquePtr $Ptr:ty; $( #[$attr:meta] )? $vis:vis $move_return_ptr:ident = move_return_ptr) => {
         $( #[$attr] )? $vis
         unsafe fn $move_return_ptr(mut self, dst: $Ptr, _call_type: $crate::PtrcallType) {
+            // `self` now holds the previous value of `dst`, which is dropped instead. This keeps allocation statistics balanced.
             std::ptr::swap(dst.cast(), std::ptr::addr_of_mut!(self.opaque))
         }
     };
@@ -240,6 +243,7 @@ macro_rules! ffi_methods_one {
             let mut raw = std::mem::MaybeUninit::<Self>::uninit();
             init(raw.as_mut_ptr().cast());
 
+            $crate::alloc_stats::on_construct::<Self>();
             raw.assume_init()
         }
     };
@@ -29,6 +29,8 @@ pub(crate) mod gen {
     include!(concat!(env!("OUT_DIR"), "/mod.rs"));
 }
 
+#[doc(hidden)]
+pub mod alloc_stats;
 pub mod conv;
 
 mod compat;
@@ -106,7 +108,7 @@ use binding::{
     initialize_class_scene_method_table, initialize_class_server_method_table, runtime_metadata,
 };
 
-#[derive(Debug)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub enum ClassApiLevel {
     Server,
     Scene,
@@ -115,6 +117,10 @@ pub enum ClassApiLevel {
 
 pub struct GdextRuntimeMetadata {
     godot_version: GDExtensionGodotVersion,
+
+    /// Kept to look up interface functions by name, after the interface has been loaded.
+    #[cfg(since_api = "4.1")]
+    get_proc_address: GDExtensionInterfaceGetProcAddress,
 }
 
 impl GdextRuntimeMetadata {
@@ -122,8 +128,37 @@ impl GdextRuntimeMetadata {
     ///
     /// - The `string` field of `godot_version` must not be written to while this struct exists.
     /// - The `string` field of `godot_version` must be safe to read from while this struct exists.
-    pub unsafe fn new(godot_version: GDExtensionGodotVersion) -> Self {
-        Self { godot_version }
+    pub unsafe fn new(godot_version: GDExtensionGodotVersion, compat: InitCompat) -> Self {
+        #[cfg(before_api = "4.1")]
+        let _ = compat;
+
+        Self {
+            godot_version,
+            #[cfg(since_api = "4.1")]
+            get_proc_address: compat,
+        }
+    }
+
+    /// Whether the running Godot binary provides the GDExtension interface function `name`.
+    #[cfg(since_api = "4.1")]
+    fn has_interface_fn(&self, name: &str) -> bool {
+        let Ok(c_name) = std::ffi::CString::new(name) else {
+            return false;
+        };
+
+        let get_proc_address = self
+            .get_proc_address
+            .expect("get_proc_address unexpectedly null");
+
+        // SAFETY: get_proc_address is valid for the lifetime of the library; c_name is null-terminated.
+        let fn_ptr = unsafe { get_proc_address(c_name.as_ptr()) };
+        fn_ptr.is_some()
+    }
+
+    // Godot 4.0 has no way to look up functions by name; the interface struct is fixed at compile time.
+    #[cfg(before_api = "4.1")]
+    fn has_interface_fn(&self, _name: &str) -> bool {
+        false
     }
 }
 
@@ -149,6 +184,9 @@ pub unsafe fn initialize(
 ) {
     out!("Initialize gdext...");
 
+    // Godot loads extensions on its main thread. Re-initialization (hot reload) happens on the same thread.
+    MAIN_THREAD_ID.get_or_init(|| std::thread::current().id());
+
     out!(
         "Godot version against which gdext was compiled: {}",
         GdextBuild::godot_static_version_string()
@@ -171,7 +209,7 @@ pub unsafe fn initialize(
     let utility_function_table = UtilityFunctionTable::load(&interface, &mut string_names);
     out!("Loaded utility function table.");
 
-    let runtime_metadata = GdextRuntimeMetadata::new(version);
+    let runtime_metadata = GdextRuntimeMetadata::new(version, compat);
 
     let builtin_method_table = {
         #[cfg(feature = "codegen-lazy-fptrs")]
@@ -216,6 +254,26 @@ pub unsafe fn initialize(
     print_preamble(version);
 }
 
+/// Thread on which [`initialize`] was first called, i.e. Godot's main thread.
+static MAIN_THREAD_ID: std::sync::OnceLock<std::thread::ThreadId> = std::sync::OnceLock::new();
+
+/// Whether the calling thread is Godot's main thread. Returns `false` before the library is initialized.
+///
+/// Unlike engine APIs such as `OS.get_main_thread_id()`, this can be called from any thread, even without `experimental-threads`.
+pub fn is_main_thread() -> bool {
+    MAIN_THREAD_ID.get() == Some(&std::thread::current().id())
+}
+
+/// Returns whether the running Godot binary provides the GDExtension interface function `name`, e.g. `"get_godot_version"`.
+///
+/// Always returns `false` for Godot 4.0, which does not support looking up functions by name.
+///
+/// # Safety
+/// The interface must have been initialized with [`initialize`] before calling this function.
+pub unsafe fn has_interface_fn(name: &str) -> bool {
+    runtime_metadata().has_interface_fn(name)
+}
+
 /// Deinitializes the library.
 ///
 /// Does not perform much logic, mostly used for consistency:
@@ -39,11 +39,17 @@ macro_rules! plugin_add_inner_wasm {
         // list of bytes on the wasm target (with no extra levels of indirection such as references).
         //
         // As such, instead we export a fn with a random name of predictable format to be used
-        // by the embedder.
+        // by the embedder. The embedder may run it more than once (e.g. if the side module is
+        // initialized again), but the plugin must only be added once.
         $crate::paste::paste! {
             #[no_mangle]
             extern "C" fn [< rust_gdext_registrant_ $gensym >] () {
-                __init();
+                static REGISTERED: ::std::sync::atomic::AtomicBool =
+                    ::std::sync::atomic::AtomicBool::new(false);
+
+                if !REGISTERED.swap(true, ::std::sync::atomic::Ordering::Relaxed) {
+                    __init();
+                }
             }
         }
     };
@@ -5,10 +5,10 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::util::bail;
+use crate::util::{bail, path_ends_with_complex};
 use crate::{util, ParseResult};
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{quote, ToTokens};
 
 pub struct ConstDefinition {
     pub raw_constant: venial::Constant,
@@ -16,6 +16,7 @@ pub struct ConstDefinition {
 
 pub fn make_constant_registration(
     consts: Vec<ConstDefinition>,
+    enums: Vec<TokenStream>,
     class_name: &Ident,
     class_name_obj: &TokenStream,
 ) -> ParseResult<TokenStream> {
@@ -44,7 +45,7 @@ pub fn make_constant_registration(
         integer_constant_values.push(quote! { #class_name::#name });
     }
 
-    let tokens = if !integer_constant_names.is_empty() {
+    let tokens = if !integer_constant_names.is_empty() || !enums.is_empty() {
         quote! {
             use ::godot::register::private::constant::*;
             use ::godot::meta::ClassName;
@@ -62,6 +63,13 @@ pub fn make_constant_registration(
                     )
                 ).register();
             )*
+
+            #(
+                ExportConstant::new(
+                    #class_name_obj,
+                    ConstantKind::from_enum::<#enums>()
+                ).register();
+            )*
         }
     } else {
         TokenStream::new()
@@ -69,3 +77,24 @@ pub fn make_constant_registration(
 
     Ok(tokens)
 }
+
+/// Return type of the static getter through which a non-integer `#[constant]` is exposed, or `None` for integer constants.
+///
+/// GDExtension can only register integer constants. Floats and strings are instead made available through a static method of the same
+/// name, see `add_constant_getters()`.
+pub fn non_integer_getter_type(ty: &venial::TypeExpr) -> Option<TokenStream> {
+    // References, e.g. `&str` or `&'static str`.
+    let is_reference = matches!(
+        ty.tokens.first(),
+        Some(proc_macro2::TokenTree::Punct(punct)) if punct.as_char() == '&'
+    );
+
+    if is_reference || path_ends_with_complex(ty, "String") {
+        return Some(quote! { ::godot::builtin::GString });
+    }
+
+    ["f32", "f64", "real"]
+        .iter()
+        .any(|name| path_ends_with_complex(ty, name))
+        .then(|| ty.to_token_stream())
+}
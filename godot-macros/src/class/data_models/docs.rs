@@ -0,0 +1,133 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Collection of `///` doc comments, registered at runtime with the `register-docs` feature.
+
+use proc_macro2::{Ident, Punct, TokenStream};
+#[cfg(feature = "register-docs")]
+use quote::quote;
+
+use crate::class::{ConstDefinition, FuncDefinition, SignalDefinition};
+
+/// Returns the values of all `#[doc = ...]` attributes (i.e. `///` lines), as expressions evaluating to `&'static str`.
+///
+/// The values are not parsed, so that `#[doc = include_str!(...)]` is supported as well.
+#[cfg(feature = "register-docs")]
+pub fn extract_doc_lines(attributes: &[venial::Attribute]) -> Vec<TokenStream> {
+    attributes
+        .iter()
+        .filter(|attr| {
+            attr.get_single_path_segment()
+                .map_or(false, |name| name == "doc")
+        })
+        .filter_map(|attr| match &attr.value {
+            venial::AttributeValue::Equals(_, tokens) => Some(quote! { #(#tokens)* }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Registers docs of the class itself and its properties.
+#[cfg(feature = "register-docs")]
+pub fn make_class_docs_registration(
+    class_name: &Ident,
+    class_attributes: &[venial::Attribute],
+    named_fields: &[(venial::NamedField, Punct)],
+) -> TokenStream {
+    use crate::util::path_is_single;
+
+    let class_lines = extract_doc_lines(class_attributes);
+
+    // Only fields that are visible in Godot.
+    let properties = named_fields
+        .iter()
+        .map(|(field, _punct)| field)
+        .filter(|field| {
+            field.attributes.iter().any(|attr| {
+                path_is_single(&attr.path, "var") || path_is_single(&attr.path, "export")
+            })
+        })
+        .map(|field| {
+            make_member(
+                &field.name.to_string(),
+                &extract_doc_lines(&field.attributes),
+            )
+        });
+
+    quote! {
+        ::godot::docs::__register_class_docs::<#class_name>(
+            &[ #(#class_lines),* ],
+            &[ #(#properties),* ],
+        );
+    }
+}
+
+#[cfg(not(feature = "register-docs"))]
+pub fn make_class_docs_registration(
+    _class_name: &Ident,
+    _class_attributes: &[venial::Attribute],
+    _named_fields: &[(venial::NamedField, Punct)],
+) -> TokenStream {
+    TokenStream::new()
+}
+
+/// Registers docs of methods, signals and constants in a `#[godot_api]` block.
+#[cfg(feature = "register-docs")]
+pub fn make_member_docs_registration(
+    class_name: &Ident,
+    funcs: &[FuncDefinition],
+    signals: &[SignalDefinition],
+    consts: &[ConstDefinition],
+) -> TokenStream {
+    let methods = funcs.iter().map(|func| {
+        let name = func
+            .rename
+            .clone()
+            .unwrap_or_else(|| func.signature_info.method_name.to_string());
+
+        make_member(&name, &extract_doc_lines(&func.external_attributes))
+    });
+
+    let signals = signals.iter().map(|signal| {
+        make_member(
+            &signal.signature.name.to_string(),
+            &extract_doc_lines(&signal.external_attributes),
+        )
+    });
+
+    let constants = consts.iter().map(|constant| {
+        make_member(
+            &constant.raw_constant.name.to_string(),
+            &extract_doc_lines(&constant.raw_constant.attributes),
+        )
+    });
+
+    quote! {
+        ::godot::docs::__register_member_docs::<#class_name>(
+            &[ #(#methods),* ],
+            &[ #(#signals),* ],
+            &[ #(#constants),* ],
+        );
+    }
+}
+
+#[cfg(not(feature = "register-docs"))]
+pub fn make_member_docs_registration(
+    _class_name: &Ident,
+    _funcs: &[FuncDefinition],
+    _signals: &[SignalDefinition],
+    _consts: &[ConstDefinition],
+) -> TokenStream {
+    TokenStream::new()
+}
+
+#[cfg(feature = "register-docs")]
+fn make_member(name: &str, doc_lines: &[TokenStream]) -> TokenStream {
+    quote! {
+        (#name, &[ #(#doc_lines),* ] as &[&str])
+    }
+}
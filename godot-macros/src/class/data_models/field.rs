@@ -15,6 +15,16 @@ pub struct Field {
     pub var: Option<FieldVar>,
     pub export: Option<FieldExport>,
     pub is_onready: bool,
+    pub is_prop: bool,
+    pub is_state_machine: bool,
+    /// Whether the field has `#[export(required)]`.
+    pub is_required: bool,
+    /// Dictionary key, if the field has `#[persist]`.
+    pub persist_key: Option<String>,
+    /// Validation method, if the field has `#[var(validate = ...)]` or `#[export(validate = ...)]`.
+    pub validate: Option<Ident>,
+    /// Type `T` of an `Injectable<T>` field, if the field has `#[inject]`.
+    pub inject_ty: Option<TokenStream>,
 }
 
 impl Field {
@@ -26,6 +36,12 @@ impl Field {
             var: None,
             export: None,
             is_onready: false,
+            is_prop: false,
+            is_state_machine: false,
+            is_required: false,
+            persist_key: None,
+            validate: None,
+            inject_ty: None,
         }
     }
 }
@@ -5,11 +5,11 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Delimiter, Ident, TokenStream, TokenTree};
 use quote::quote;
 use std::collections::HashSet;
 
-use crate::util::{KvParser, ListParser};
+use crate::util::{bail, KvParser, KvValue, ListParser};
 use crate::ParseResult;
 
 /// Store info from `#[export]` attribute.
@@ -64,6 +64,13 @@ pub enum FieldExport {
     /// - `FLAGS`
     Flags { bits: Vec<ValueWithKey> },
 
+    /// ### GDScript annotations
+    /// - `@export_flags`, with names and bits from a `GodotFlags` type
+    ///
+    /// ### Property hints
+    /// - `FLAGS`
+    FlagsOfType { flags_type: TokenStream },
+
     /// ### GDScript annotations
     /// - `@export_flags_2d_physics`
     /// - `@export_flags_2d_render`
@@ -111,12 +118,41 @@ pub enum FieldExport {
     /// - `PLACEHOLDER_TEXT`
     PlaceholderText { placeholder: TokenStream },
 
+    /// ### GDScript annotations
+    /// - `@export var dict: Dictionary[K, V]`
+    ///
+    /// ### Property hints
+    /// - `TYPE_STRING` (Godot 4.4+; untyped `NONE` before)
+    TypedDictionary {
+        key_type: TokenStream,
+        value_type: TokenStream,
+    },
+
     /// ### GDScript annotations
     /// - `@export_color_no_alpha`
     ///
     /// ### Property hints
     /// - `COLOR_NO_ALPHA`
     ColorNoAlpha,
+
+    /// ### GDScript annotations
+    /// - `@export_node_path`
+    ///
+    /// ### Property hints
+    /// - `NODE_PATH_VALID_TYPES` (for `NodePath`)
+    /// - `TYPE_STRING` (for `Array<NodePath>` and `Vec<NodePath>`)
+    NodePath { class_types: Vec<TokenStream> },
+
+    /// No GDScript equivalent.
+    ///
+    /// Registers each field of an `ExportGroup` struct as a separate property `field/sub_field`.
+    Flatten,
+}
+
+impl FieldExport {
+    pub fn is_flatten(&self) -> bool {
+        matches!(self, Self::Flatten)
+    }
 }
 
 impl FieldExport {
@@ -143,8 +179,8 @@ impl FieldExport {
             return Self::new_exp_easing(list_parser);
         }
 
-        if let Some(list_parser) = parser.handle_list("flags")? {
-            return Self::new_flags(list_parser);
+        if let Some((key, value)) = parser.handle_any_entry("flags") {
+            return Self::new_flags_entry(key, value);
         }
 
         if parser.handle_alone("flags_2d_render")? {
@@ -200,7 +236,7 @@ impl FieldExport {
                 return Ok(Self::File {
                     global: false,
                     kind: FileKind::File {
-                        filter: Some(kv.expr()?),
+                        filter: Some(parse_file_filter(kv)?),
                     },
                 })
             }
@@ -218,7 +254,7 @@ impl FieldExport {
                 return Ok(Self::File {
                     global: true,
                     kind: FileKind::File {
-                        filter: Some(kv.expr()?),
+                        filter: Some(parse_file_filter(kv)?),
                     },
                 })
             }
@@ -251,6 +287,24 @@ impl FieldExport {
             return Ok(Self::ColorNoAlpha);
         }
 
+        if let Some(list_parser) = parser.handle_list("dictionary")? {
+            return Self::new_typed_dictionary(list_parser);
+        }
+
+        if let Some(mut list_parser) = parser.handle_list("node_path")? {
+            let mut class_types = Vec::new();
+            while list_parser.peek().is_some() {
+                class_types.push(list_parser.next_expr()?);
+            }
+            list_parser.finish()?;
+
+            return Ok(Self::NodePath { class_types });
+        }
+
+        if parser.handle_alone("flatten")? {
+            return Ok(Self::Flatten);
+        }
+
         Ok(FieldExport::Default)
     }
 
@@ -332,6 +386,44 @@ impl FieldExport {
         })
     }
 
+    fn new_typed_dictionary(mut parser: ListParser) -> ParseResult<Self> {
+        let key_type = parser.next_expr()?;
+        let value_type = parser.next_expr()?;
+
+        parser.finish()?;
+
+        Ok(Self::TypedDictionary {
+            key_type,
+            value_type,
+        })
+    }
+
+    fn new_flags_entry(key: Ident, value: Option<KvValue>) -> ParseResult<Self> {
+        let Some(value) = value else {
+            return Ok(Self::Flags { bits: Vec::new() });
+        };
+
+        let mut tokens = value.into_tokens();
+        let is_list = matches!(
+            tokens.as_slice(),
+            [TokenTree::Group(group)] if group.delimiter() == Delimiter::Parenthesis
+        );
+
+        if is_list {
+            Self::new_flags(ListParser::new_from_tree(
+                tokens.remove(0),
+                Delimiter::Parenthesis,
+            )?)
+        } else if tokens.is_empty() {
+            bail!(key, "expected `flags = (...)` or `flags = Type`")
+        } else {
+            // `flags = Type`: names and bits come from a type implementing `GodotFlags`.
+            Ok(Self::FlagsOfType {
+                flags_type: tokens.into_iter().collect(),
+            })
+        }
+    }
+
     fn new_flags(mut parser: ListParser) -> ParseResult<Self> {
         let mut bits = Vec::new();
 
@@ -359,7 +451,7 @@ macro_rules! quote_export_func {
 }
 
 impl FieldExport {
-    pub fn to_export_hint(&self) -> Option<TokenStream> {
+    pub fn to_export_hint(&self, field_type: &venial::TypeExpr) -> Option<TokenStream> {
         match self {
             FieldExport::Default => None,
 
@@ -400,6 +492,10 @@ impl FieldExport {
                 }
             }
 
+            FieldExport::FlagsOfType { flags_type } => quote_export_func! {
+                export_flags_of::<#flags_type>()
+            },
+
             FieldExport::Layers {
                 dimension: LayerDimension::_2d,
                 kind: LayerKind::Physics,
@@ -455,6 +551,22 @@ impl FieldExport {
                 export_placeholder(#placeholder)
             },
             FieldExport::ColorNoAlpha => quote_export_func! { export_color_no_alpha() },
+
+            FieldExport::TypedDictionary {
+                key_type,
+                value_type,
+            } => quote_export_func! {
+                export_typed_dictionary::<#key_type, #value_type>()
+            },
+
+            FieldExport::NodePath { class_types } => quote_export_func! {
+                export_node_path::<#field_type>(&[
+                    #( <#class_types as ::godot::obj::GodotClass>::class_name() ),*
+                ])
+            },
+
+            // Handled separately, each field of the group has its own hint.
+            FieldExport::Flatten => None,
         }
     }
 }
@@ -478,6 +590,22 @@ pub enum FileKind {
     Dir,
 }
 
+/// Parses the filter of `#[export(file = ...)]` or `#[export(global_file = ...)]`.
+///
+/// Accepts either a single expression evaluating to a string, or an array of filters like `["*.json", "*.cfg"]`, which are joined with
+/// commas as expected by Godot.
+fn parse_file_filter(kv: KvValue) -> ParseResult<TokenStream> {
+    let tokens = kv.into_tokens();
+
+    if let [TokenTree::Group(group)] = tokens.as_slice() {
+        if group.delimiter() == Delimiter::Bracket {
+            return Ok(quote! { #group.join(",") });
+        }
+    }
+
+    Ok(tokens.into_iter().collect())
+}
+
 /// A `key = value` pair used for enums and bitflags.
 ///
 /// `key` must be an identifier, and `value` some tokenstream that can be coerced into the appropriate
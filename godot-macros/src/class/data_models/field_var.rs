@@ -12,7 +12,7 @@ use crate::class::{
     into_signature_info, make_existence_check, make_method_registration, Field, FieldHint,
     FuncDefinition,
 };
-use crate::util::KvParser;
+use crate::util::{bail, KvParser};
 use crate::{util, ParseResult};
 
 /// Store info from `#[var]` attribute.
@@ -22,6 +22,7 @@ pub struct FieldVar {
     pub setter: GetterSetter,
     pub hint: FieldHint,
     pub usage_flags: UsageFlags,
+    pub replicate: Option<Ident>,
 }
 
 impl FieldVar {
@@ -33,6 +34,7 @@ impl FieldVar {
     /// - `hint = ident`
     /// - `hint_string = expr`
     /// - `usage_flags =
+    /// - `replicate = always | on_change | spawn`
     pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
         let mut getter = GetterSetter::parse(parser, "get")?;
         let mut setter = GetterSetter::parse(parser, "set")?;
@@ -66,11 +68,31 @@ impl FieldVar {
             UsageFlags::Inferred
         };
 
+        let replicate = match parser.handle_ident("replicate")? {
+            Some(mode) => {
+                let variant = match mode.to_string().as_str() {
+                    "always" => "Always",
+                    "on_change" => "OnChange",
+                    "spawn" => "SpawnOnly",
+                    _ => {
+                        return bail!(
+                            mode,
+                            "#[var(replicate)]: expected one of `always`, `on_change` or `spawn`"
+                        )
+                    }
+                };
+
+                Some(Ident::new(variant, mode.span()))
+            }
+            None => None,
+        };
+
         Ok(FieldVar {
             getter,
             setter,
             hint,
             usage_flags,
+            replicate,
         })
     }
 }
@@ -179,23 +201,132 @@ impl GetterSetterImpl {
                 signature = quote! {
                     fn #function_name(&mut self, #field_name: <#field_type as ::godot::meta::GodotConvert>::Via)
                 };
+                function_body = match &field.validate {
+                    Some(validate) => {
+                        let property_path = format!("{class_name}.{field_name}");
+
+                        quote! {
+                            // Apply the assignment to a copy, so that types implementing only `Var` (not `FromGodot`) are supported.
+                            let mut new_value = ::std::clone::Clone::clone(&self.#field_name);
+                            <#field_type as ::godot::register::property::Var>::set_property(&mut new_value, #field_name);
+                            match self.#validate(new_value) {
+                                ::std::result::Result::Ok(value) => self.#field_name = value,
+                                ::std::result::Result::Err(err) => ::godot::global::godot_error!(
+                                    "invalid value for property `{}`, keeping previous value: {}",
+                                    #property_path,
+                                    err
+                                ),
+                            }
+                        }
+                    }
+                    None => quote! {
+                        <#field_type as ::godot::register::property::Var>::set_property(&mut self.#field_name, #field_name);
+                    },
+                };
+            }
+        }
+
+        Self::from_signature_and_body(class_name, function_name, signature, function_body)
+    }
+
+    /// Generates the indexed getter or setter for a field annotated with `#[export(flatten)]`.
+    ///
+    /// Godot passes the index of the property within the group as first argument.
+    pub fn for_export_group(class_name: &Ident, kind: GetSet, field: &Field) -> Self {
+        let Field {
+            name: field_name,
+            ty: field_type,
+            ..
+        } = field;
+
+        let function_name = format_ident!("_{}group_{field_name}", kind.prefix());
+
+        let signature;
+        let function_body;
+
+        match kind {
+            GetSet::Get => {
+                signature = quote! {
+                    fn #function_name(&self, index: i64) -> ::godot::builtin::Variant
+                };
                 function_body = quote! {
-                    <#field_type as ::godot::register::property::Var>::set_property(&mut self.#field_name, #field_name);
+                    <#field_type as ::godot::register::property::ExportGroup>::get_group_property(&self.#field_name, index as usize)
+                };
+            }
+            GetSet::Set => {
+                signature = quote! {
+                    fn #function_name(&mut self, index: i64, value: ::godot::builtin::Variant)
+                };
+                function_body = quote! {
+                    <#field_type as ::godot::register::property::ExportGroup>::set_group_property(&mut self.#field_name, index as usize, value);
                 };
             }
         }
 
+        Self::from_signature_and_body(class_name, function_name, signature, function_body)
+    }
+
+    /// Generates the getter or setter of the `state` property of an enum class.
+    ///
+    /// The setter takes `Gd<Self>` instead of `&mut self`, so that it can emit the `state_changed` signal after the transition.
+    pub fn for_state_class(class_name: &Ident, kind: GetSet) -> Self {
+        let function_name = format_ident!("{}state", kind.prefix());
+
+        match kind {
+            GetSet::Get => {
+                let signature = quote! {
+                    fn #function_name(&self) -> ::godot::builtin::StringName
+                };
+                let function_body = quote! {
+                    ::godot::builtin::StringName::from(::godot::obj::StateEnum::variant_name(self))
+                };
+
+                Self::from_signature_and_body(class_name, function_name, signature, function_body)
+            }
+            GetSet::Set => {
+                let function_impl = quote! {
+                    pub fn #function_name(this: ::godot::obj::Gd<Self>, state: ::godot::builtin::StringName) {
+                        <Self as ::godot::obj::StateClass>::__set_state_by_name(this, state)
+                    }
+                };
+
+                // The registered signature excludes `this`, like for #[func(gd_self)].
+                let signature = quote! {
+                    fn #function_name(state: ::godot::builtin::StringName)
+                };
+
+                Self::from_parts(class_name, function_name, function_impl, signature, true)
+            }
+        }
+    }
+
+    fn from_signature_and_body(
+        class_name: &Ident,
+        function_name: Ident,
+        signature: TokenStream,
+        function_body: TokenStream,
+    ) -> Self {
         let function_impl = quote! {
             pub #signature {
                 #function_body
             }
         };
 
+        Self::from_parts(class_name, function_name, function_impl, signature, false)
+    }
+
+    fn from_parts(
+        class_name: &Ident,
+        function_name: Ident,
+        function_impl: TokenStream,
+        signature: TokenStream,
+        has_gd_self: bool,
+    ) -> Self {
         let signature = util::parse_signature(signature);
         let export_token = make_method_registration(
             class_name,
             FuncDefinition {
-                signature_info: into_signature_info(signature, class_name, false),
+                signature_info: into_signature_info(signature, class_name, has_gd_self),
                 // Since we're analyzing a struct's field, we don't have access to the corresponding get/set function's
                 // external (non-#[func]) attributes. We have to assume the function exists and has the name the user
                 // gave us, with the expected signature.
@@ -204,6 +335,9 @@ impl GetterSetterImpl {
                 external_attributes: Vec::new(),
                 rename: None,
                 is_script_virtual: false,
+                panic_policy: None,
+                default_parameters: Vec::new(),
+                expect_ptrcall: false,
             },
         );
 
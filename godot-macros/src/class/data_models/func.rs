@@ -19,6 +19,12 @@ pub struct FuncDefinition {
     /// The name the function will be exposed as in Godot. If `None`, the Rust function name is used.
     pub rename: Option<String>,
     pub is_script_virtual: bool,
+    /// Overrides the global panic policy, if set via `#[func(on_panic = ...)]`.
+    pub panic_policy: Option<Ident>,
+    /// Default values of the trailing parameters marked with `#[opt(default = ...)]`, in order.
+    pub default_parameters: Vec<TokenStream>,
+    /// Whether varcalls should be reported, set via `#[func(expect_ptrcall)]`.
+    pub expect_ptrcall: bool,
 }
 
 /// Returns a C function which acts as the callback when a virtual method of this instance is invoked.
@@ -52,7 +58,10 @@ pub fn make_virtual_callback(
                 ret: sys::GDExtensionTypePtr,
             ) {
                 let call_ctx = #call_ctx;
-                #invocation;
+                let _success = ::godot::private::handle_panic(
+                    || &call_ctx,
+                    || #invocation
+                );
             }
             Some(virtual_fn)
         }
@@ -85,9 +94,17 @@ pub fn make_method_registration(
         method_name.to_string()
     };
 
+    let panic_policy = match &func_definition.panic_policy {
+        Some(policy) => quote! { Some(::godot::init::PanicPolicy::#policy) },
+        None => quote! { None },
+    };
+
+    let default_args_fn_decl = make_default_args_fn(class_name, &func_definition)?;
+    let expect_ptrcall = func_definition.expect_ptrcall;
+
     let call_ctx = make_call_context(&class_name_str, &method_name_str);
-    let varcall_fn_decl = make_varcall_fn(&call_ctx, &forwarding_closure);
-    let ptrcall_fn_decl = make_ptrcall_fn(&call_ctx, &forwarding_closure);
+    let varcall_fn_decl = make_varcall_fn(&call_ctx, &forwarding_closure, &panic_policy);
+    let ptrcall_fn_decl = make_ptrcall_fn(&call_ctx, &forwarding_closure, &panic_policy);
 
     // String literals II
     let param_ident_strs = signature_info
@@ -106,13 +123,19 @@ pub fn make_method_registration(
         {
             use ::godot::obj::GodotClass;
             use ::godot::register::private::method::ClassMethodInfo;
-            use ::godot::builtin::{StringName, Variant};
+            use ::godot::builtin::Variant;
             use ::godot::sys;
 
             type Sig = #sig_tuple;
 
-            let method_name = StringName::from(#method_name_str);
+            let method_name = ::godot::builtin::sname!(#method_name_str).clone();
+
+            // Shared by both entry points, see `godot::register::call_stats`.
+            static CALL_COUNTER: ::godot::private::MethodCallCounter =
+                ::godot::private::MethodCallCounter::new(#expect_ptrcall);
+            CALL_COUNTER.register(#class_name::class_name(), #method_name_str);
 
+            #default_args_fn_decl;
             #varcall_fn_decl;
             #ptrcall_fn_decl;
 
@@ -129,7 +152,7 @@ pub fn make_method_registration(
                     &[
                         #( #param_ident_strs ),*
                     ],
-                    Vec::new()
+                    default_args()
                 )
             };
 
@@ -178,6 +201,16 @@ impl SignatureInfo {
         }
     }
 
+    pub fn fn_get_configuration_warnings() -> Self {
+        Self {
+            method_name: ident("get_configuration_warnings"),
+            receiver_type: ReceiverType::Ref,
+            param_idents: vec![],
+            param_types: vec![],
+            ret_type: quote! { ::godot::builtin::PackedStringArray },
+        }
+    }
+
     pub fn tuple_type(&self) -> TokenStream {
         // Note: for GdSelf receivers, first parameter is not even part of SignatureInfo anymore.
         util::make_signature_tuple_type(&self.ret_type, &self.param_types)
@@ -193,6 +226,12 @@ pub enum BeforeKind {
 
     /// Call **only** `before_{method}`, not the method itself.
     OnlyBefore,
+
+    /// Call the method, then pass its result to `after_{method}` for amendments (e.g. generated configuration warnings).
+    WithAfter,
+
+    /// Call **only** `after_{method}` with a default-constructed result, not the method itself.
+    OnlyAfter,
 }
 
 /// Returns a closure expression that forwards the parameters to the Rust instance.
@@ -219,17 +258,29 @@ fn make_forwarding_closure(
             let before_method = format_ident!("__before_{}", method_name);
             quote! { instance.#before_method(); }
         }
-        BeforeKind::Without => TokenStream::new(),
+        BeforeKind::Without | BeforeKind::WithAfter | BeforeKind::OnlyAfter => TokenStream::new(),
     };
+    let after_method = format_ident!("__after_{}", method_name);
 
     match signature_info.receiver_type {
         ReceiverType::Ref | ReceiverType::Mut => {
             // Generated default virtual methods (e.g. for ready) may not have an actual implementation (user code), so
             // all they need to do is call the __before_ready() method. This means the actual method call may be optional.
-            let method_call = if matches!(before_kind, BeforeKind::OnlyBefore) {
-                TokenStream::new()
-            } else {
-                quote! { instance.#method_name( #(#params),* ) }
+            let method_call = match before_kind {
+                BeforeKind::OnlyBefore => TokenStream::new(),
+                BeforeKind::WithAfter => quote! {
+                    let mut result = instance.#method_name( #(#params),* );
+                    instance.#after_method(&mut result);
+                    result
+                },
+                BeforeKind::OnlyAfter => quote! {
+                    let mut result = ::std::default::Default::default();
+                    instance.#after_method(&mut result);
+                    result
+                },
+                BeforeKind::Without | BeforeKind::WithBefore => {
+                    quote! { instance.#method_name( #(#params),* ) }
+                }
             };
 
             quote! {
@@ -246,8 +297,38 @@ fn make_forwarding_closure(
             }
         }
         ReceiverType::GdSelf => {
+            // Only bind for the before-call (e.g. OnReady initialization); the method itself runs unbound.
+            let before_method_call = if before_method_call.is_empty() {
+                TokenStream::new()
+            } else {
+                quote! {
+                    {
+                        let mut instance = ::godot::private::Storage::get_mut(storage);
+                        #before_method_call
+                    }
+                }
+            };
+
             // Method call is always present, since GdSelf implies that the user declares the method.
             // (Absent method is only used in the case of a generated default virtual method, e.g. for ready()).
+            let method_call = quote! {
+                #class_name::#method_name(::godot::private::Storage::get_gd(storage), #(#params),*)
+            };
+
+            // The after-call binds shared, once the method has returned.
+            let method_call = if matches!(before_kind, BeforeKind::WithAfter) {
+                quote! {
+                    let mut result = #method_call;
+                    {
+                        let instance = ::godot::private::Storage::get(storage);
+                        instance.#after_method(&mut result);
+                    }
+                    result
+                }
+            } else {
+                method_call
+            };
+
             quote! {
                 |instance_ptr, params| {
                     let ( #(#params,)* ) = params;
@@ -256,7 +337,7 @@ fn make_forwarding_closure(
                         unsafe { ::godot::private::as_storage::<#class_name>(instance_ptr) };
 
                     #before_method_call
-                    #class_name::#method_name(::godot::private::Storage::get_gd(storage), #(#params),*)
+                    #method_call
                 }
             }
         }
@@ -405,8 +486,50 @@ fn make_method_flags(
     Ok(flags)
 }
 
+/// Generate code for a function returning the default values of `#[opt]` parameters.
+///
+/// Used both for registration, and to fill in omitted arguments of varcalls (Godot doesn't do this for extension methods).
+fn make_default_args_fn(
+    class_name: &Ident,
+    func_definition: &FuncDefinition,
+) -> ParseResult<TokenStream> {
+    let signature_info = &func_definition.signature_info;
+    let defaults = &func_definition.default_parameters;
+
+    let param_count = signature_info.param_types.len();
+    if defaults.len() > param_count {
+        return bail_fn(
+            "#[opt] is not allowed on the Gd<Self> parameter",
+            &signature_info.method_name,
+        );
+    }
+
+    // Each default is assigned to a variable of the parameter type, so type mismatches are reported at the #[opt] expression.
+    let default_types = &signature_info.param_types[param_count - defaults.len()..];
+    let default_exprs = defaults
+        .iter()
+        .map(|expr| map_self_to_class_name::<_, TokenStream>(expr.clone(), class_name));
+
+    Ok(quote! {
+        fn default_args() -> Vec<Variant> {
+            vec![
+                #(
+                    {
+                        let value: #default_types = #default_exprs;
+                        ::godot::meta::ToGodot::to_variant(&value)
+                    }
+                ),*
+            ]
+        }
+    })
+}
+
 /// Generate code for a C FFI function that performs a varcall.
-fn make_varcall_fn(call_ctx: &TokenStream, wrapped_method: &TokenStream) -> TokenStream {
+fn make_varcall_fn(
+    call_ctx: &TokenStream,
+    wrapped_method: &TokenStream,
+    panic_policy: &TokenStream,
+) -> TokenStream {
     let invocation = make_varcall_invocation(wrapped_method);
 
     // TODO reduce amount of code generated, by delegating work to a library function. Could even be one that produces this function pointer.
@@ -420,9 +543,11 @@ fn make_varcall_fn(call_ctx: &TokenStream, wrapped_method: &TokenStream) -> Toke
             err: *mut sys::GDExtensionCallError,
         ) {
             let call_ctx = #call_ctx;
+            CALL_COUNTER.record_varcall(&call_ctx);
             ::godot::private::handle_varcall_panic(
                 &call_ctx,
                 &mut *err,
+                #panic_policy,
                 || #invocation
             );
         }
@@ -430,7 +555,11 @@ fn make_varcall_fn(call_ctx: &TokenStream, wrapped_method: &TokenStream) -> Toke
 }
 
 /// Generate code for a C FFI function that performs a ptrcall.
-fn make_ptrcall_fn(call_ctx: &TokenStream, wrapped_method: &TokenStream) -> TokenStream {
+fn make_ptrcall_fn(
+    call_ctx: &TokenStream,
+    wrapped_method: &TokenStream,
+    panic_policy: &TokenStream,
+) -> TokenStream {
     let invocation = make_ptrcall_invocation(wrapped_method, false);
 
     quote! {
@@ -441,8 +570,10 @@ fn make_ptrcall_fn(call_ctx: &TokenStream, wrapped_method: &TokenStream) -> Toke
             ret: sys::GDExtensionTypePtr,
         ) {
             let call_ctx = #call_ctx;
-            let _success = ::godot::private::handle_panic(
+            CALL_COUNTER.record_ptrcall();
+            let _success = ::godot::private::handle_panic_with_policy(
                 || &call_ctx,
+                #panic_policy,
                 || #invocation
             );
 
@@ -483,6 +614,7 @@ fn make_varcall_invocation(wrapped_method: &TokenStream) -> TokenStream {
             arg_count,
             ret,
             err,
+            default_args,
             #wrapped_method,
         )
     }
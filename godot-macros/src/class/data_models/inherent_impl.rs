@@ -6,8 +6,9 @@
  */
 
 use crate::class::{
-    into_signature_info, make_constant_registration, make_method_registration,
-    make_signal_registrations, ConstDefinition, FuncDefinition, SignalDefinition, SignatureInfo,
+    into_signature_info, make_constant_registration, make_member_docs_registration,
+    make_method_registration, make_signal_registrations, non_integer_getter_type, ConstDefinition,
+    FuncDefinition, SignalDefinition, SignatureInfo,
 };
 use crate::util::{bail, require_api_version, KvParser};
 use crate::{util, ParseResult};
@@ -22,6 +23,8 @@ enum ItemAttrType {
         rename: Option<String>,
         is_virtual: bool,
         has_gd_self: bool,
+        panic_policy: Option<Ident>,
+        expect_ptrcall: bool,
     },
     Signal(venial::AttributeValue),
     Const(#[allow(dead_code)] venial::AttributeValue),
@@ -41,16 +44,30 @@ impl ItemAttr {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
-/// Codegen for `#[godot_api] impl MyType`
-pub fn transform_inherent_impl(mut impl_block: venial::Impl) -> ParseResult<TokenStream> {
+/// Codegen for `#[godot_api] impl MyType` and `#[godot_api(secondary)] impl MyType`.
+pub fn transform_inherent_impl(
+    mut impl_block: venial::Impl,
+    enums: Vec<TokenStream>,
+    is_secondary: bool,
+) -> ParseResult<TokenStream> {
     let class_name = util::validate_impl(&impl_block, None, "godot_api")?;
     let class_name_obj = util::class_name_obj(&class_name);
     let prv = quote! { ::godot::private };
 
+    if is_secondary && !enums.is_empty() {
+        return bail!(
+            impl_block,
+            "#[godot_api(enums)] is only supported on the primary block, not with `secondary`"
+        );
+    }
+
     // Can add extra functions to the end of the impl block.
+    add_constant_getters(&mut impl_block)?;
     let (funcs, signals) = process_godot_fns(&class_name, &mut impl_block)?;
     let consts = process_godot_constants(&mut impl_block)?;
 
+    let docs_registration = make_member_docs_registration(&class_name, &funcs, &signals, &consts);
+    let duplicate_guards = make_duplicate_guards(&class_name, &funcs, &signals);
     let signal_registrations = make_signal_registrations(signals, &class_name_obj);
 
     let method_registrations: Vec<TokenStream> = funcs
@@ -58,15 +75,47 @@ pub fn transform_inherent_impl(mut impl_block: venial::Impl) -> ParseResult<Toke
         .map(|func_def| make_method_registration(&class_name, func_def))
         .collect::<ParseResult<Vec<TokenStream>>>()?; // <- FIXME transpose this
 
-    let constant_registration = make_constant_registration(consts, &class_name, &class_name_obj)?;
+    let constant_registration =
+        make_constant_registration(consts, enums, &class_name, &class_name_obj)?;
+
+    if is_secondary {
+        let result = quote! {
+            #impl_block
+            #duplicate_guards
+
+            const _: () = {
+                fn __register_secondary(_class_builder: &mut dyn ::std::any::Any) {
+                    #( #method_registrations )*
+                    #( #signal_registrations )*
+                    #docs_registration
+                    #constant_registration
+                }
+
+                ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
+                    class_name: #class_name_obj,
+                    item: #prv::PluginItem::InherentImpl {
+                        register_methods_constants_fn: #prv::ErasedRegisterFn {
+                            raw: __register_secondary,
+                        },
+                        is_secondary: true,
+                    },
+                    init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
+                });
+            };
+        };
+
+        return Ok(result);
+    }
 
     let result = quote! {
         #impl_block
+        #duplicate_guards
 
         impl ::godot::obj::cap::ImplementsGodotApi for #class_name {
             fn __register_methods() {
                 #( #method_registrations )*
                 #( #signal_registrations )*
+                #docs_registration
             }
 
             fn __register_constants() {
@@ -80,6 +129,7 @@ pub fn transform_inherent_impl(mut impl_block: venial::Impl) -> ParseResult<Toke
                 register_methods_constants_fn: #prv::ErasedRegisterFn {
                     raw: #prv::callbacks::register_user_methods_constants::<#class_name>,
                 },
+                is_secondary: false,
             },
             init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
         });
@@ -88,6 +138,49 @@ pub fn transform_inherent_impl(mut impl_block: venial::Impl) -> ParseResult<Toke
     Ok(result)
 }
 
+/// Declares one associated constant per Godot-visible name, so that the same method or signal name declared in two `#[godot_api]`
+/// blocks of a class fails to compile ("duplicate definitions"). Same-named Rust methods and constants are already caught by rustc.
+fn make_duplicate_guards(
+    class_name: &Ident,
+    funcs: &[FuncDefinition],
+    signals: &[SignalDefinition],
+) -> TokenStream {
+    let is_ident = |name: &str| name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    let func_guards = funcs.iter().filter_map(|func| {
+        let name = func
+            .rename
+            .clone()
+            .unwrap_or_else(|| func.signature_info.method_name.to_string());
+
+        is_ident(&name).then(|| {
+            let cfg_attrs = util::extract_cfg_attrs(&func.external_attributes).into_iter();
+            let guard = format_ident!(
+                "__godot_func_{}",
+                name,
+                span = func.signature_info.method_name.span()
+            );
+            quote! { #( #cfg_attrs )* const #guard: () = (); }
+        })
+    });
+
+    let signal_guards = signals.iter().map(|signal| {
+        let cfg_attrs = util::extract_cfg_attrs(&signal.external_attributes).into_iter();
+        let name = &signal.signature.name;
+        let guard = format_ident!("__godot_signal_{}", name, span = name.span());
+        quote! { #( #cfg_attrs )* const #guard: () = (); }
+    });
+
+    quote! {
+        #[doc(hidden)]
+        #[allow(non_upper_case_globals, dead_code)]
+        impl #class_name {
+            #( #func_guards )*
+            #( #signal_guards )*
+        }
+    }
+}
+
 fn process_godot_fns(
     class_name: &Ident,
     impl_block: &mut venial::Impl,
@@ -128,8 +221,11 @@ fn process_godot_fns(
                 rename,
                 is_virtual,
                 has_gd_self,
+                panic_policy,
+                expect_ptrcall,
             } => {
                 let external_attributes = function.attributes.clone();
+                let default_parameters = extract_param_defaults(function)?;
 
                 // Signatures are the same thing without body.
                 let mut signature = util::reduce_to_signature(function);
@@ -180,6 +276,9 @@ fn process_godot_fns(
                     external_attributes,
                     rename,
                     is_script_virtual: is_virtual,
+                    panic_policy,
+                    default_parameters,
+                    expect_ptrcall,
                 });
             }
             ItemAttrType::Signal(ref _attr_val) => {
@@ -258,6 +357,55 @@ fn process_godot_constants(decl: &mut venial::Impl) -> ParseResult<Vec<ConstDefi
     Ok(constant_signatures)
 }
 
+/// Replaces float and string `#[constant]`s with static `#[func]` getters of the same name.
+///
+/// GDExtension can only register integer constants, so GDScript accesses the others as `MyClass.NAME()`.
+fn add_constant_getters(decl: &mut venial::Impl) -> ParseResult<()> {
+    let mut getters = vec![];
+
+    for item in decl.body_items.iter_mut() {
+        let venial::ImplMember::AssocConstant(constant) = item else {
+            continue;
+        };
+
+        let Some(return_ty) = non_integer_getter_type(&constant.ty) else {
+            continue;
+        };
+
+        let Some(attr) = extract_attributes(&constant, &constant.attributes)? else {
+            continue;
+        };
+
+        if !matches!(attr.ty, ItemAttrType::Const(_)) {
+            continue; // Error reported in process_godot_constants().
+        }
+
+        constant.attributes.remove(attr.index);
+
+        // Docs and #[cfg] attributes carry over to the getter.
+        let attributes = &constant.attributes;
+        let name = &constant.name;
+        let getter_name = format_ident!("__godot_constant_{}", name);
+
+        let getter = quote! {
+            #( #attributes )*
+            #[func(rename = #name)]
+            fn #getter_name() -> #return_ty {
+                ::std::convert::Into::into(Self::#name)
+            }
+        };
+
+        let getter = venial::parse_item(getter)?
+            .as_function()
+            .expect("constant getter is a function")
+            .clone();
+        getters.push(venial::ImplMember::AssocFunction(getter));
+    }
+
+    decl.body_items.extend(getters);
+    Ok(())
+}
+
 fn add_virtual_script_call(
     virtual_functions: &mut Vec<venial::Function>,
     function: &mut venial::Function,
@@ -299,7 +447,7 @@ fn add_virtual_script_call(
 
     let code = quote! {
         let object_ptr = #object_ptr;
-        let method_sname = ::godot::builtin::StringName::from(#method_name_str);
+        let method_sname = ::godot::builtin::sname!(#method_name_str);
         let method_sname_ptr = method_sname.string_sys();
         let has_virtual_override = unsafe { ::godot::private::has_virtual_script_method(object_ptr, method_sname_ptr) };
 
@@ -366,6 +514,12 @@ where
                 // #[func(gd_self)]
                 let has_gd_self = parser.handle_alone("gd_self")?;
 
+                // #[func(on_panic = Abort)]
+                let panic_policy = parser.handle_ident("on_panic")?;
+
+                // #[func(expect_ptrcall)]
+                let expect_ptrcall = parser.handle_alone("expect_ptrcall")?;
+
                 parser.finish()?;
 
                 ItemAttr {
@@ -375,6 +529,8 @@ where
                         rename,
                         is_virtual,
                         has_gd_self,
+                        panic_policy,
+                        expect_ptrcall,
                     },
                 }
             }
@@ -416,6 +572,47 @@ where
     Ok(found)
 }
 
+/// Parses `#[opt(default = expr)]` on parameters and removes these attributes from the function.
+///
+/// Returns the default expressions of the optional parameters, which must all be trailing.
+fn extract_param_defaults(function: &mut venial::Function) -> ParseResult<Vec<TokenStream>> {
+    let mut defaults = vec![];
+
+    for (param, _) in function.params.inner.iter_mut() {
+        let venial::FnParam::Typed(param) = param else {
+            continue;
+        };
+
+        let default = match KvParser::parse(&param.attributes, "opt")? {
+            Some(mut parser) => {
+                // #[opt(default = expr)]
+                let default = parser.handle_expr_required("default")?;
+                parser.finish()?;
+                Some(default)
+            }
+            None => None,
+        };
+
+        param
+            .attributes
+            .retain(|attr| !util::path_is_single(&attr.path, "opt"));
+
+        match default {
+            Some(default) => defaults.push(default),
+            None if !defaults.is_empty() => {
+                return bail!(
+                    &param.name,
+                    "parameter `{}` must be #[opt], as it follows an #[opt] parameter",
+                    param.name
+                );
+            }
+            None => {}
+        }
+    }
+
+    Ok(defaults)
+}
+
 fn bail_attr<R>(attr_name: Ident, msg: &str, method: &venial::Function) -> ParseResult<R> {
     bail!(&method.name, "#[{}]: {}", attr_name, msg)
 }
@@ -6,16 +6,21 @@
  */
 
 use crate::class::{into_signature_info, make_virtual_callback, BeforeKind, SignatureInfo};
+use crate::util::{bail, KvParser};
 use crate::{util, ParseResult};
 
 use proc_macro2::TokenStream;
 use quote::quote;
 
 /// Codegen for `#[godot_api] impl ISomething for MyType`
-pub fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStream> {
+pub fn transform_trait_impl(mut original_impl: venial::Impl) -> ParseResult<TokenStream> {
     let (class_name, trait_path) = util::validate_trait_impl_virtual(&original_impl, "godot_api")?;
+    let trait_path = trait_path.clone();
     let class_name_obj = util::class_name_obj(&class_name);
 
+    // Methods with #[func(gd_self)] don't match the trait signature, so they are moved out of the trait impl.
+    let gd_self_methods = extract_gd_self_methods(&mut original_impl)?;
+
     let mut godot_init_impl = TokenStream::new();
     let mut to_string_impl = TokenStream::new();
     let mut register_class_impl = TokenStream::new();
@@ -210,33 +215,17 @@ pub fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStr
                 });
             }
 
-            #[cfg(before_api = "4.3")]
-            "get_property_list" => {
-                get_property_list_impl = quote! {
-                    #(#cfg_attrs)*
-                    compile_error!("`get_property_list` is only supported for Godot versions of at least 4.3");
-                };
-
-                // Set these variables otherwise rust complains that these variables arent changed in Godot < 4.3.
-                get_property_list_fn = None;
-                free_property_list_fn = None;
-            }
-
-            #[cfg(since_api = "4.3")]
             "get_property_list" => {
                 get_property_list_impl = quote! {
                     #(#cfg_attrs)*
                     impl ::godot::obj::cap::GodotGetPropertyList for #class_name {
                         fn __godot_get_property_list(&mut self) -> Vec<::godot::meta::PropertyInfo> {
-                            // `get_property_list` is only supported in Godot API >= 4.3. If we add support for `get_property_list` to earlier
-                            // versions of Godot then this code is still needed and should be uncommented.
-                            //
-                            // use ::godot::obj::UserClass as _;
-                            //
-                            // #[cfg(before_api = "4.3")]
-                            // if ::godot::private::is_class_inactive(Self::__config().is_tool) {
-                            //     return false;
-                            // }
+                            use ::godot::obj::UserClass as _;
+
+                            #[cfg(before_api = "4.3")]
+                            if ::godot::private::is_class_inactive(Self::__config().is_tool) {
+                                return Vec::new();
+                            }
 
                             <Self as #trait_path>::get_property_list(self)
                         }
@@ -301,11 +290,8 @@ pub fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStr
                 let signature_info = into_signature_info(method, &class_name, false);
 
                 // Overridden ready() methods additionally have an additional `__before_ready()` call (for OnReady inits).
-                let before_kind = if method_name == "ready" {
-                    BeforeKind::WithBefore
-                } else {
-                    BeforeKind::Without
-                };
+                // Overridden get_configuration_warnings() methods are amended with generated warnings (#[export(required)] etc.).
+                let before_kind = virtual_before_kind(&method_name);
 
                 // Note that, if the same method is implemented multiple times (with different cfg attr combinations),
                 // then there will be multiple match arms annotated with the same cfg attr combinations, thus they will
@@ -318,6 +304,24 @@ pub fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStr
         }
     }
 
+    for method in gd_self_methods.iter() {
+        let method_name = method.name.to_string();
+        let cfg_attrs = util::extract_cfg_attrs(&method.attributes)
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        // First parameter `this: Gd<Self>` is not part of the signature; the callback passes it separately.
+        let mut signature = util::reduce_to_signature(method);
+        signature.params.inner.remove(0);
+
+        let signature_info = into_signature_info(signature, &class_name, true);
+        let before_kind = virtual_before_kind(&method_name);
+
+        virtual_method_cfg_attrs.push(cfg_attrs);
+        virtual_method_names.push(format!("_{method_name}"));
+        virtual_methods.push((signature_info, before_kind));
+    }
+
     // If there is no ready() method explicitly overridden, we need to add one, to ensure that __before_ready() is called to
     // initialize the OnReady fields.
     if !virtual_methods
@@ -331,6 +335,25 @@ pub fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStr
         virtual_methods.push((signature_info, BeforeKind::OnlyBefore));
     }
 
+    // If get_configuration_warnings() is not overridden, generated warnings still need to reach Godot. Unlike _ready, this is only
+    // registered for classes that have such warnings, to not shadow the warnings of other classes.
+    let config_warnings_arm = if virtual_methods
+        .iter()
+        .any(|(sig, _)| sig.method_name == "get_configuration_warnings")
+    {
+        TokenStream::new()
+    } else {
+        let callback = make_virtual_callback(
+            &class_name,
+            SignatureInfo::fn_get_configuration_warnings(),
+            BeforeKind::OnlyAfter,
+        );
+
+        quote! {
+            "_get_configuration_warnings" if <#class_name as ::godot::obj::UserClass>::__HAS_CONFIG_WARNINGS => #callback,
+        }
+    };
+
     let tool_check = util::make_virtual_tool_check();
     let virtual_method_callbacks: Vec<TokenStream> = virtual_methods
         .into_iter()
@@ -356,8 +379,19 @@ pub fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStr
     let property_get_revert_fn = convert_to_match_expression_or_none(property_get_revert_fn);
     let property_can_revert_fn = convert_to_match_expression_or_none(property_can_revert_fn);
 
+    let gd_self_impl = if gd_self_methods.is_empty() {
+        TokenStream::new()
+    } else {
+        quote! {
+            impl #class_name {
+                #( #gd_self_methods )*
+            }
+        }
+    };
+
     let result = quote! {
         #original_impl
+        #gd_self_impl
         #godot_init_impl
         #to_string_impl
         #on_notification_impl
@@ -380,6 +414,7 @@ pub fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStr
                        #(#virtual_method_cfg_attrs)*
                        #virtual_method_names => #virtual_method_callbacks,
                     )*
+                    #config_warnings_arm
                     _ => None,
                 }
             }
@@ -408,6 +443,84 @@ pub fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStr
     Ok(result)
 }
 
+/// Which generated code runs around an overridden virtual method.
+fn virtual_before_kind(method_name: &str) -> BeforeKind {
+    match method_name {
+        "ready" => BeforeKind::WithBefore,
+        "get_configuration_warnings" => BeforeKind::WithAfter,
+        _ => BeforeKind::Without,
+    }
+}
+
+/// Removes all `#[func(gd_self)]` methods from the trait impl and returns them, without the `#[func]` attribute.
+///
+/// Such methods receive `this: Gd<Self>` instead of `&mut self`, so the object is not bound for the duration of the call. They are later
+/// declared in an inherent impl, while the trait keeps its default implementation.
+fn extract_gd_self_methods(original_impl: &mut venial::Impl) -> ParseResult<Vec<venial::Function>> {
+    // Methods that are not dispatched through the generic virtual callback, and thus need a `self` receiver.
+    const SPECIAL_METHODS: &[&str] = &[
+        "register_class",
+        "init",
+        "to_string",
+        "on_notification",
+        "get_property",
+        "set_property",
+        "get_property_list",
+        "property_get_revert",
+    ];
+
+    let mut gd_self_methods = vec![];
+    let mut remaining_items = vec![];
+
+    for item in std::mem::take(&mut original_impl.body_items) {
+        let venial::ImplMember::AssocFunction(mut method) = item else {
+            remaining_items.push(item);
+            continue;
+        };
+
+        let Some(attr_index) = method.attributes.iter().position(|attr| {
+            attr.get_single_path_segment()
+                .is_some_and(|seg| seg == "func")
+        }) else {
+            remaining_items.push(venial::ImplMember::AssocFunction(method));
+            continue;
+        };
+
+        let mut parser = KvParser::parse(&method.attributes, "func")?.unwrap();
+        if !parser.handle_alone("gd_self")? {
+            return bail!(
+                &method.name,
+                "#[func] in trait impls is only supported with the `gd_self` key"
+            );
+        }
+        parser.finish()?;
+
+        if SPECIAL_METHODS.contains(&method.name.to_string().as_str()) {
+            return bail!(
+                &method.name,
+                "#[func(gd_self)] is not supported for `{}`, which requires a `self` receiver",
+                method.name
+            );
+        }
+
+        match method.params.inner.first() {
+            Some((venial::FnParam::Typed(_), _)) => {}
+            _ => {
+                return bail!(
+                    &method.name,
+                    "with attribute key `gd_self`, the first parameter must be Gd<Self> (not a `self` receiver)"
+                );
+            }
+        }
+
+        method.attributes.remove(attr_index);
+        gd_self_methods.push(method);
+    }
+
+    original_impl.body_items = remaining_items;
+    Ok(gd_self_methods)
+}
+
 /// Expects either Some(quote! { () => A, () => B, ... }) or None as the 'tokens' parameter.
 /// The idea is that the () => ... arms can be annotated by cfg attrs, so, if any of them compiles (and assuming the cfg
 /// attrs only allow one arm to 'survive' compilation), their return value (Some(...)) will be prioritized over the
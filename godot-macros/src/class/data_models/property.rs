@@ -7,7 +7,7 @@
 
 //! Parsing the `var` and `export` attributes on fields.
 
-use crate::class::{Field, FieldVar, Fields, GetSet, GetterSetterImpl, UsageFlags};
+use crate::class::{Field, FieldExport, FieldVar, Fields, GetSet, GetterSetterImpl, UsageFlags};
 use crate::util;
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
@@ -37,7 +37,11 @@ impl FieldHint {
     }
 }
 
-pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
+pub fn make_property_impl(
+    class_name: &Ident,
+    fields: &Fields,
+    class_docs: TokenStream,
+) -> TokenStream {
     let class_name_obj = util::class_name_obj(class_name);
 
     let mut getter_setter_impls = Vec::new();
@@ -52,6 +56,24 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             ..
         } = field;
 
+        if field.is_prop || field.is_state_machine {
+            let signal_name = format!("{field_ident}_changed");
+
+            export_tokens.push(quote! {
+                <#field_type>::__register_changed_signal(#class_name_obj, #signal_name);
+            });
+        }
+
+        if export.as_ref().is_some_and(FieldExport::is_flatten) {
+            make_export_group(
+                class_name,
+                field,
+                &mut getter_setter_impls,
+                &mut export_tokens,
+            );
+            continue;
+        }
+
         // Ensure we add a var if the user only provided a `#[export]`.
         let var = match (export, var) {
             (Some(_), None) => Some(FieldVar {
@@ -59,6 +81,15 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
                 ..Default::default()
             }),
 
+            // StateMachine<S> shows its current state in the inspector, without storing it in scenes.
+            (None, None) if field.is_state_machine => Some(FieldVar {
+                usage_flags: UsageFlags::Custom(vec![
+                    util::ident("EDITOR"),
+                    util::ident("READ_ONLY"),
+                ]),
+                ..Default::default()
+            }),
+
             (_, var) => var.clone(),
         };
 
@@ -75,12 +106,13 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             setter,
             hint,
             mut usage_flags,
+            replicate,
         } = var;
 
         let mut export_hint = None;
 
         if let Some(export) = export {
-            export_hint = export.to_export_hint();
+            export_hint = export.to_export_hint(field_type);
 
             if usage_flags.is_inferred() {
                 usage_flags = UsageFlags::InferredExport;
@@ -159,6 +191,16 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             &mut export_tokens,
         );
 
+        if let Some(mode) = replicate {
+            export_tokens.push(quote! {
+                ::godot::register::replication::register_replicated_property(
+                    #class_name_obj,
+                    #field_name,
+                    ::godot::register::replication::Replication::#mode,
+                );
+            });
+        }
+
         export_tokens.push(quote! {
             use ::godot::sys::GodotFfi;
 
@@ -168,14 +210,14 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             let property_info = ::godot::meta::PropertyInfo {
                 variant_type: #field_variant_type,
                 class_name: #field_class_name,
-                property_name: #field_name.into(),
+                property_name: ::godot::builtin::sname!(#field_name).clone(),
                 hint,
                 hint_string,
                 usage,
             };
 
-            let getter_name = ::godot::builtin::StringName::from(#getter_name);
-            let setter_name = ::godot::builtin::StringName::from(#setter_name);
+            let getter_name = ::godot::builtin::sname!(#getter_name);
+            let setter_name = ::godot::builtin::sname!(#setter_name);
 
             let property_info_sys = property_info.property_sys();
 
@@ -198,6 +240,8 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
 
         impl ::godot::obj::cap::ImplementsGodotExports for #class_name {
             fn __register_exports() {
+                #class_docs
+
                 #(
                     {
                         #export_tokens
@@ -208,6 +252,55 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
     }
 }
 
+/// Registers the fields of an `#[export(flatten)]` field as indexed properties, sharing one getter and setter.
+fn make_export_group(
+    class_name: &Ident,
+    field: &Field,
+    getter_setter_impls: &mut Vec<TokenStream>,
+    export_tokens: &mut Vec<TokenStream>,
+) {
+    let class_name_obj = util::class_name_obj(class_name);
+    let field_type = &field.ty;
+    let field_name = field.name.to_string();
+
+    let getter_name = make_getter_setter(
+        Some(GetterSetterImpl::for_export_group(
+            class_name,
+            GetSet::Get,
+            field,
+        )),
+        getter_setter_impls,
+        export_tokens,
+    );
+    let setter_name = make_getter_setter(
+        Some(GetterSetterImpl::for_export_group(
+            class_name,
+            GetSet::Set,
+            field,
+        )),
+        getter_setter_impls,
+        export_tokens,
+    );
+
+    // Indexed properties are only available since Godot 4.2.
+    let registration = if cfg!(since_api = "4.2") {
+        quote! {
+            ::godot::register::property::register_export_group::<#field_type>(
+                #class_name_obj,
+                #field_name,
+                #getter_name,
+                #setter_name,
+            );
+        }
+    } else {
+        quote! {
+            compile_error!("#[export(flatten)] requires Godot 4.2 or later");
+        }
+    };
+
+    export_tokens.push(registration);
+}
+
 fn make_getter_setter(
     getter_setter_impl: Option<GetterSetterImpl>,
     getter_setter_impls: &mut Vec<TokenStream>,
@@ -73,7 +73,7 @@ pub fn make_signal_registrations(
                 let mut parameters_info_sys: [sys::GDExtensionPropertyInfo; #signal_parameters_count] =
                     std::array::from_fn(|i| parameters_info[i].property_sys());
 
-                let signal_name = ::godot::builtin::StringName::from(#signal_name_str);
+                let signal_name = ::godot::builtin::sname!(#signal_name_str);
 
                 sys::interface_fn!(classdb_register_extension_class_signal)(
                     sys::get_library(),
@@ -9,38 +9,88 @@ use proc_macro2::{Ident, Punct, TokenStream};
 use quote::{format_ident, quote};
 
 use crate::class::{
-    make_property_impl, make_virtual_callback, BeforeKind, Field, FieldExport, FieldVar, Fields,
-    SignatureInfo,
+    make_class_docs_registration, make_property_impl, make_virtual_callback, BeforeKind, Field,
+    FieldExport, FieldVar, Fields, GetSet, GetterSetter, GetterSetterImpl, SignatureInfo,
 };
 use crate::util::{bail, ident, path_ends_with_complex, require_api_version, KvParser};
 use crate::{util, ParseResult};
 
 pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
-    let class = item
-        .as_struct()
-        .ok_or_else(|| venial::Error::new("Not a valid struct"))?;
+    // Enums are registered as state classes: the object holds one variant, and there are no fields.
+    let (class_name, class_attributes, named_fields, state_enum) = match &item {
+        venial::Item::Struct(class) => (&class.name, &class.attributes, named_fields(class)?, None),
+        venial::Item::Enum(enum_) => (&enum_.name, &enum_.attributes, vec![], Some(enum_)),
+        _ => {
+            return bail!(
+                &item,
+                "#[derive(GodotClass)] can only be applied on structs and enums"
+            )
+        }
+    };
 
-    let named_fields = named_fields(class)?;
-    let struct_cfg = parse_struct_attributes(class)?;
+    let struct_cfg = parse_struct_attributes(class_attributes)?;
+    let class_docs = make_class_docs_registration(class_name, class_attributes, &named_fields);
     let fields = parse_fields(named_fields, struct_cfg.init_strategy)?;
 
-    let class_name = &class.name;
-    let class_name_str: String = struct_cfg
-        .rename
-        .map_or_else(|| class.name.clone(), |rename| rename)
-        .to_string();
+    if let Some(enum_) = state_enum {
+        validate_state_class(enum_, &struct_cfg)?;
+    }
+
+    if !struct_cfg.persist.is_enabled {
+        if let Some(field) = fields.all_fields.iter().find(|f| f.persist_key.is_some()) {
+            return bail!(
+                &field.name,
+                "field attribute #[persist] requires struct attribute #[class(persist)]"
+            );
+        }
+    }
+
+    let class_name_str: String = format!(
+        "{namespace}{name}",
+        namespace = struct_cfg.namespace.as_deref().unwrap_or_default(),
+        name = struct_cfg.rename.unwrap_or_else(|| class_name.to_string()),
+    );
     let class_name_cstr = util::cstr_u8_slice(&class_name_str);
+
+    // Classes without explicit namespace receive the extension-wide prefix, if one is configured.
+    let class_name_expr = if struct_cfg.namespace.is_some() {
+        quote! { ::godot::meta::ClassName::from_ascii_cstr(#class_name_cstr) }
+    } else {
+        quote! {
+            static PREFIXED: ::std::sync::OnceLock<&'static str> = ::std::sync::OnceLock::new();
+            ::godot::meta::ClassName::prefixable_from_ascii_cstr(#class_name_cstr, &PREFIXED)
+        }
+    };
     let class_name_obj = util::class_name_obj(class_name);
 
     let is_editor_plugin = struct_cfg.is_editor_plugin;
     let is_hidden = struct_cfg.is_hidden;
+    let is_lazy = struct_cfg.is_lazy;
+    let is_abstract = struct_cfg.is_abstract;
+    let required_classes = &struct_cfg.required_classes;
+    let (shared_class_fn, imported_token_hook) = if struct_cfg.is_shared {
+        make_shared_class(class_name, &fields)
+    } else {
+        (quote! { None }, TokenStream::new())
+    };
+    let (layout, inline_storage_warning) = if struct_cfg.is_inline_storage {
+        (
+            quote! { LayoutInline },
+            quote! { ::godot::__deprecated::emit_deprecated_warning!(inline_storage_with_threads); },
+        )
+    } else {
+        (quote! { LayoutBoxed }, TokenStream::new())
+    };
     let base_ty = &struct_cfg.base_ty;
     let base_class = quote! { ::godot::classes::#base_ty };
     let base_class_name_obj = util::class_name_obj(&base_class);
     let inherits_macro = format_ident!("unsafe_inherits_transitive_{}", base_ty);
 
     let prv = quote! { ::godot::private };
-    let godot_exports_impl = make_property_impl(class_name, &fields);
+    let godot_exports_impl = match state_enum {
+        Some(enum_) => make_state_class_impl(enum_, class_docs)?,
+        None => make_property_impl(class_name, &fields, class_docs),
+    };
 
     let godot_withbase_impl = if let Some(Field { name, .. }) = &fields.base_field {
         quote! {
@@ -64,8 +114,22 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         TokenStream::new()
     };
 
-    let (user_class_impl, has_default_virtual) =
-        make_user_class_impl(class_name, struct_cfg.is_tool, &fields.all_fields);
+    let (debug_hook, debug_impl) = make_debug_impl(class_name, &fields, struct_cfg.debug_strategy);
+
+    let (user_class_impl, has_default_virtual) = make_user_class_impl(
+        class_name,
+        struct_cfg.is_tool,
+        &fields.all_fields,
+        struct_cfg.warnings_fn.as_ref(),
+    );
+
+    // Computed before `fields` is consumed by the generated init.
+    let persist_impl = make_persist_impl(class_name, &fields, &struct_cfg.persist);
+    let inject_impl = make_inject_impl(class_name, &fields);
+    let property_default_impl = match struct_cfg.init_strategy {
+        InitStrategy::Generated => make_property_default_impl(class_name, &fields),
+        _ => None,
+    };
 
     let mut init_expecter = TokenStream::new();
     let mut godot_init_impl = TokenStream::new();
@@ -75,7 +139,10 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
 
     match struct_cfg.init_strategy {
         InitStrategy::Generated => {
-            godot_init_impl = make_godot_init_impl(class_name, fields);
+            godot_init_impl = match state_enum {
+                Some(enum_) => make_state_class_init_impl(enum_)?,
+                None => make_godot_init_impl(class_name, fields),
+            };
             create_fn = quote! { Some(#prv::callbacks::create::<#class_name>) };
 
             if cfg!(since_api = "4.2") {
@@ -104,6 +171,17 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         quote! { None }
     };
 
+    // Inspector reverts exported properties to their field initializers.
+    let (default_property_can_revert_fn, default_property_get_revert_fn) =
+        if property_default_impl.is_some() {
+            (
+                quote! { Some(#prv::callbacks::default_property_can_revert::<#class_name>) },
+                quote! { Some(#prv::callbacks::default_property_get_revert::<#class_name>) },
+            )
+        } else {
+            (quote! { None }, quote! { None })
+        };
+
     let is_tool = struct_cfg.is_tool;
 
     Ok(quote! {
@@ -111,20 +189,28 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
             type Base = #base_class;
 
             fn class_name() -> ::godot::meta::ClassName {
-                ::godot::meta::ClassName::from_ascii_cstr(#class_name_cstr)
+                #class_name_expr
             }
+
+            #debug_hook
+            #imported_token_hook
         }
 
         unsafe impl ::godot::obj::Bounds for #class_name {
             type Memory = <<Self as ::godot::obj::GodotClass>::Base as ::godot::obj::Bounds>::Memory;
             type DynMemory = <<Self as ::godot::obj::GodotClass>::Base as ::godot::obj::Bounds>::DynMemory;
             type Declarer = ::godot::obj::bounds::DeclUser;
+            type Layout = ::godot::obj::bounds::#layout;
         }
 
         #godot_init_impl
         #godot_withbase_impl
         #godot_exports_impl
         #user_class_impl
+        #debug_impl
+        #persist_impl
+        #inject_impl
+        #property_default_impl
         #init_expecter
 
         ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
@@ -138,10 +224,18 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
                 },
                 free_fn: #prv::callbacks::free::<#class_name>,
                 default_get_virtual_fn: #default_get_virtual_fn,
+                default_property_can_revert_fn: #default_property_can_revert_fn,
+                default_property_get_revert_fn: #default_property_get_revert_fn,
                 is_tool: #is_tool,
                 is_editor_plugin: #is_editor_plugin,
                 is_hidden: #is_hidden,
                 is_instantiable: #is_instantiable,
+                is_lazy: #is_lazy,
+                is_abstract: #is_abstract,
+                shared_class_fn: #shared_class_fn,
+                required_classes: vec![
+                    #( <#required_classes as ::godot::obj::GodotClass>::class_name(), )*
+                ],
             },
             init_level: {
                 let level = <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL;
@@ -156,12 +250,24 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
                     class = #class_name_str,
                 );
 
+                #(
+                    let required_level = <#required_classes as ::godot::obj::GodotClass>::INIT_LEVEL;
+                    assert!(
+                        level >= required_level,
+                        "Class `{class}` has init level `{level:?}`, but its required class `{required}` has init level `{required_level:?}`.\n\
+                        A class cannot be registered before the classes it requires.",
+                        class = #class_name_str,
+                        required = stringify!(#required_classes),
+                    );
+                )*
+
                 level
             }
         });
 
         #prv::class_macros::#inherits_macro!(#class_name);
         #deprecated_base_warning
+        #inline_storage_warning
     })
 }
 
@@ -184,16 +290,43 @@ enum InitStrategy {
     Absent,
 }
 
+#[derive(Copy, Clone)]
+enum DebugStrategy {
+    /// No user instance in `Gd<T>`'s `Debug` output.
+    Absent,
+    /// `#[class(debug)]`: generate field-wise `Debug` impl.
+    Generated,
+    /// `#[class(debug = manual)]`: use user-provided `Debug` impl.
+    UserDefined,
+}
+
+/// `#[class(persist, persist_version = N, persist_migrate = fn)]`.
+struct PersistConfig {
+    is_enabled: bool,
+    version: usize,
+    migrate_fn: Option<Ident>,
+}
+
 struct ClassAttributes {
     base_ty: Ident,
     init_strategy: InitStrategy,
     is_tool: bool,
     is_editor_plugin: bool,
     is_hidden: bool,
-    rename: Option<Ident>,
+    is_lazy: bool,
+    is_abstract: bool,
+    is_shared: bool,
+    is_inline_storage: bool,
+    required_classes: Vec<TokenStream>,
+    rename: Option<String>,
+    namespace: Option<String>,
+    debug_strategy: DebugStrategy,
+    warnings_fn: Option<Ident>,
+    persist: PersistConfig,
 }
 
 fn make_godot_init_impl(class_name: &Ident, fields: Fields) -> TokenStream {
+    let has_base = fields.base_field.is_some();
     let base_init = if let Some(Field { name, .. }) = fields.base_field {
         quote! { #name: base, }
     } else {
@@ -206,6 +339,20 @@ fn make_godot_init_impl(class_name: &Ident, fields: Fields) -> TokenStream {
             .default
             .unwrap_or_else(|| quote! { ::std::default::Default::default() });
 
+        // Prop<T> and StateMachine<S> fields emit their changed signal on the object itself.
+        if (field.is_prop || field.is_state_machine) && has_base {
+            let field_type = field.ty;
+            let signal_name = format!("{field_name}_changed");
+
+            return quote! {
+                #field_name: {
+                    let mut prop: #field_type = #value_expr;
+                    prop.attach(&base, #signal_name);
+                    prop
+                },
+            };
+        }
+
         quote! { #field_name: #value_expr, }
     });
 
@@ -221,10 +368,334 @@ fn make_godot_init_impl(class_name: &Ident, fields: Fields) -> TokenStream {
     }
 }
 
+/// Rejects class attributes that depend on fields, which enums don't have.
+fn validate_state_class(enum_: &venial::Enum, struct_cfg: &ClassAttributes) -> ParseResult<()> {
+    if enum_.generic_params.is_some() {
+        return bail!(
+            &enum_.generic_params,
+            "#[derive(GodotClass)] does not support generic parameters"
+        );
+    }
+
+    let unsupported = [
+        (struct_cfg.persist.is_enabled, "persist"),
+        (struct_cfg.is_shared, "shared"),
+        (struct_cfg.warnings_fn.is_some(), "warnings"),
+        (
+            matches!(struct_cfg.debug_strategy, DebugStrategy::Generated),
+            "debug",
+        ),
+    ];
+
+    if let Some((_, key)) = unsupported.into_iter().find(|(is_used, _)| *is_used) {
+        return bail!(
+            &enum_.name,
+            "#[class({key})] is not supported for enums; see `StateClass` for what enum classes provide"
+        );
+    }
+
+    Ok(())
+}
+
+/// Generated `init()` for enum classes, starting in the first variant.
+fn make_state_class_init_impl(enum_: &venial::Enum) -> ParseResult<TokenStream> {
+    let class_name = &enum_.name;
+    let Some(first) = enum_.variants.items().next() else {
+        return bail!(
+            class_name,
+            "#[derive(GodotClass)] on enums requires at least one variant"
+        );
+    };
+
+    if !matches!(first.fields, venial::Fields::Unit) {
+        return bail!(
+            &first.name,
+            "#[class(init)] on enums starts in the first variant, which must not carry data; reorder the variants or use a custom init()"
+        );
+    }
+
+    let initial = &first.name;
+
+    Ok(quote! {
+        impl ::godot::obj::cap::GodotDefault for #class_name {
+            fn __godot_user_init(_base: ::godot::obj::Base<Self::Base>) -> Self {
+                Self::#initial
+            }
+        }
+    })
+}
+
+/// `StateEnum` and `StateClass` impls of enum classes, and registration of their `state` property and `state_changed` signal.
+fn make_state_class_impl(
+    enum_: &venial::Enum,
+    class_docs: TokenStream,
+) -> ParseResult<TokenStream> {
+    let class_name = &enum_.name;
+    let state_enum_impl = crate::derive::derive_state_enum(venial::Item::Enum(enum_.clone()))?;
+
+    let getter = GetterSetterImpl::for_state_class(class_name, GetSet::Get);
+    let setter = GetterSetterImpl::for_state_class(class_name, GetSet::Set);
+    let getter_name = getter.function_name.to_string();
+    let setter_name = setter.function_name.to_string();
+    let (getter_impl, getter_registration) = (getter.function_impl, getter.export_token);
+    let (setter_impl, setter_registration) = (setter.function_impl, setter.export_token);
+
+    Ok(quote! {
+        #state_enum_impl
+
+        impl ::godot::obj::StateClass for #class_name {}
+
+        impl #class_name {
+            #getter_impl
+            #setter_impl
+        }
+
+        impl ::godot::obj::cap::ImplementsGodotExports for #class_name {
+            fn __register_exports() {
+                #class_docs
+
+                { #getter_registration }
+                { #setter_registration }
+                <#class_name as ::godot::obj::StateClass>::__register_state_class(#getter_name, #setter_name);
+            }
+        }
+    })
+}
+
+/// Returns the plugin's `shared_class_fn` for a `#[class(shared)]` class, and the `GodotClass` hook holding its library token.
+fn make_shared_class(class_name: &Ident, fields: &Fields) -> (TokenStream, TokenStream) {
+    let field_layouts = fields
+        .base_field
+        .iter()
+        .chain(&fields.all_fields)
+        .map(|field| {
+            let field_name = &field.name;
+            let field_type = &field.ty;
+            let field_name_str = field_name.to_string();
+
+            quote! {
+                ::godot::private::FieldLayout {
+                    name: #field_name_str,
+                    type_name: ::std::any::type_name::<#field_type>(),
+                    type_id: ::std::any::TypeId::of::<#field_type>(),
+                    offset: ::std::mem::offset_of!(#class_name, #field_name),
+                    size: ::std::mem::size_of::<#field_type>(),
+                    align: ::std::mem::align_of::<#field_type>(),
+                }
+            }
+        });
+
+    let shared_class_fn = quote! {
+        Some(|| ::godot::private::shared_class::<#class_name>(&[ #( #field_layouts ),* ]))
+    };
+
+    let imported_token_hook = quote! {
+        fn __imported_token() -> ::std::option::Option<&'static ::std::sync::atomic::AtomicUsize> {
+            static IMPORTED_TOKEN: ::std::sync::atomic::AtomicUsize = ::std::sync::atomic::AtomicUsize::new(0);
+            ::std::option::Option::Some(&IMPORTED_TOKEN)
+        }
+    };
+
+    (shared_class_fn, imported_token_hook)
+}
+
+/// Returns the values of `#[export]` fields right after the generated init, which the inspector reverts to.
+///
+/// `OnReady` and `#[export(required)]` fields have no meaningful value before `ready()`, and flattened groups no single one.
+fn make_property_default_impl(class_name: &Ident, fields: &Fields) -> Option<TokenStream> {
+    let entries = fields
+        .all_fields
+        .iter()
+        .filter(|field| {
+            field
+                .export
+                .as_ref()
+                .is_some_and(|export| !export.is_flatten())
+                && !field.is_onready
+                && !field.is_required
+        })
+        .map(|field| {
+            let field_type = &field.ty;
+            let property_name = field.name.to_string();
+            let value_expr = field
+                .default
+                .clone()
+                .unwrap_or_else(|| quote! { ::std::default::Default::default() });
+
+            quote! {
+                {
+                    let value: #field_type = #value_expr;
+                    (
+                        ::godot::builtin::StringName::from(#property_name),
+                        ::godot::meta::ToGodot::to_variant(
+                            &::godot::register::property::Var::get_property(&value)
+                        ),
+                    )
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if entries.is_empty() {
+        return None;
+    }
+
+    Some(quote! {
+        impl ::godot::obj::cap::GodotPropertyDefault for #class_name {
+            fn __godot_property_defaults(
+            ) -> ::std::vec::Vec<(::godot::builtin::StringName, ::godot::builtin::Variant)> {
+                ::std::vec![ #( #entries, )* ]
+            }
+        }
+    })
+}
+
+/// Returns the `GodotClass` hook for `Gd<T>`'s `Debug` output, and the (possibly generated) `Debug` impl for the struct.
+fn make_debug_impl(
+    class_name: &Ident,
+    fields: &Fields,
+    debug_strategy: DebugStrategy,
+) -> (TokenStream, TokenStream) {
+    let debug_hook = quote! {
+        fn __godot_fmt_debug_instance(
+            obj: &::godot::obj::Gd<Self>,
+            s: &mut ::std::fmt::DebugStruct<'_, '_>,
+        ) {
+            ::godot::private::fmt_debug_instance(obj, s);
+        }
+    };
+
+    match debug_strategy {
+        DebugStrategy::Absent => (TokenStream::new(), TokenStream::new()),
+        DebugStrategy::UserDefined => (debug_hook, TokenStream::new()),
+        DebugStrategy::Generated => {
+            let class_name_str = class_name.to_string();
+
+            // The base field is omitted; its ID and class are already part of the enclosing `Gd` output.
+            let field_entries = fields.all_fields.iter().map(|field| {
+                let field_name = &field.name;
+                let field_name_str = field_name.to_string();
+                quote! { .field(#field_name_str, &self.#field_name) }
+            });
+
+            let debug_impl = quote! {
+                impl ::std::fmt::Debug for #class_name {
+                    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                        f.debug_struct(#class_name_str)
+                            #( #field_entries )*
+                            .finish()
+                    }
+                }
+            };
+
+            (debug_hook, debug_impl)
+        }
+    }
+}
+
+fn make_persist_impl(class_name: &Ident, fields: &Fields, persist: &PersistConfig) -> TokenStream {
+    if !persist.is_enabled {
+        return TokenStream::new();
+    }
+
+    let persisted = fields
+        .all_fields
+        .iter()
+        .filter_map(|field| Some((&field.name, field.persist_key.as_ref()?)));
+
+    let saves = persisted.clone().map(|(field_name, key)| {
+        quote! {
+            fields.set(#key, ::godot::meta::ToGodot::to_variant(&self.#field_name));
+        }
+    });
+
+    let loads = persisted.map(|(field_name, key)| {
+        quote! {
+            if let ::std::option::Option::Some(value) = fields.get(#key) {
+                self.#field_name = ::godot::tools::persist::__read_field(#key, &value)?;
+            }
+        }
+    });
+
+    let version = persist.version as u32;
+    let migrate = persist.migrate_fn.as_ref().map(|migrate_fn| {
+        quote! {
+            fn __migrate(version: u32, fields: &mut ::godot::builtin::Dictionary) {
+                Self::#migrate_fn(version, fields)
+            }
+        }
+    });
+
+    let prv = quote! { ::godot::private };
+
+    quote! {
+        impl ::godot::tools::persist::Persist for #class_name {
+            const PERSIST_VERSION: u32 = #version;
+
+            fn __save_fields(&self) -> ::godot::builtin::Dictionary {
+                let mut fields = ::godot::builtin::Dictionary::new();
+                #( #saves )*
+                fields
+            }
+
+            fn __load_fields(
+                &mut self,
+                fields: &::godot::builtin::Dictionary,
+            ) -> ::std::result::Result<(), ::godot::meta::error::ConvertError> {
+                #( #loads )*
+                ::std::result::Result::Ok(())
+            }
+
+            #migrate
+        }
+
+        ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
+            class_name: <#class_name as ::godot::obj::GodotClass>::class_name(),
+            item: #prv::PluginItem::Persist(#prv::PersistImpl::new::<#class_name>()),
+            init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
+        });
+    }
+}
+
+/// Generates `inject_<field>()` methods for fields with `#[inject]`. Only available in debug builds.
+fn make_inject_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
+    let methods = fields
+        .all_fields
+        .iter()
+        .filter_map(|field| {
+            let inject_ty = field.inject_ty.as_ref()?;
+            let field_name = &field.name;
+            let method_name = format_ident!("inject_{field_name}");
+            let doc = format!(
+                "Replaces the `{field_name}` dependency with `value`, returning the previous one. Only available in debug builds."
+            );
+
+            Some(quote! {
+                #[doc = #doc]
+                pub fn #method_name(&mut self, value: #inject_ty) -> ::std::option::Option<#inject_ty> {
+                    ::godot::obj::Injectable::inject(&mut self.#field_name, value)
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if methods.is_empty() {
+        return TokenStream::new();
+    }
+
+    quote! {
+        #[cfg(debug_assertions)]
+        impl #class_name {
+            #( #methods )*
+        }
+    }
+}
+
 fn make_user_class_impl(
     class_name: &Ident,
     is_tool: bool,
     all_fields: &[Field],
+    warnings_fn: Option<&Ident>,
 ) -> (TokenStream, bool) {
     let onready_field_inits = all_fields
         .iter()
@@ -236,20 +707,40 @@ fn make_user_class_impl(
             }
         });
 
-    let default_virtual_fn = if all_fields.iter().any(|field| field.is_onready) {
+    let has_onready = all_fields.iter().any(|field| field.is_onready);
+    let has_config_warnings =
+        warnings_fn.is_some() || all_fields.iter().any(|field| field.is_required);
+
+    let default_virtual_fn = if has_onready || has_config_warnings {
         let tool_check = util::make_virtual_tool_check();
-        let signature_info = SignatureInfo::fn_ready();
 
-        let callback = make_virtual_callback(class_name, signature_info, BeforeKind::OnlyBefore);
+        let ready_arm = has_onready.then(|| {
+            let callback = make_virtual_callback(
+                class_name,
+                SignatureInfo::fn_ready(),
+                BeforeKind::OnlyBefore,
+            );
+            quote! { "_ready" => #callback, }
+        });
+
+        let warnings_arm = has_config_warnings.then(|| {
+            let callback = make_virtual_callback(
+                class_name,
+                SignatureInfo::fn_get_configuration_warnings(),
+                BeforeKind::OnlyAfter,
+            );
+            quote! { "_get_configuration_warnings" => #callback, }
+        });
+
         let default_virtual_fn = quote! {
             fn __default_virtual_call(name: &str) -> ::godot::sys::GDExtensionClassCallVirtual {
                 use ::godot::obj::UserClass as _;
                 #tool_check
 
-                if name == "_ready" {
-                    #callback
-                } else {
-                    None
+                match name {
+                    #ready_arm
+                    #warnings_arm
+                    _ => None,
                 }
             }
         };
@@ -258,6 +749,36 @@ fn make_user_class_impl(
         None
     };
 
+    let config_warnings_impl = if has_config_warnings {
+        let required_checks = all_fields
+            .iter()
+            .filter(|field| field.is_required)
+            .map(|field| {
+                let field = &field.name;
+                let property = field.to_string();
+                quote! {
+                    ::godot::private::push_required_warning(warnings, #property, &self.#field);
+                }
+            });
+
+        let user_warnings = warnings_fn.map(|warnings_fn| {
+            quote! {
+                warnings.extend(::std::iter::IntoIterator::into_iter(Self::#warnings_fn(self)));
+            }
+        });
+
+        quote! {
+            const __HAS_CONFIG_WARNINGS: bool = true;
+
+            fn __after_get_configuration_warnings(&self, warnings: &mut ::godot::builtin::PackedStringArray) {
+                #( #required_checks )*
+                #user_warnings
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
     let user_class_impl = quote! {
         impl ::godot::obj::UserClass for #class_name {
             fn __config() -> ::godot::private::ClassConfig {
@@ -271,6 +792,7 @@ fn make_user_class_impl(
             }
 
             #default_virtual_fn
+            #config_warnings_impl
         }
     };
 
@@ -278,16 +800,29 @@ fn make_user_class_impl(
 }
 
 /// Returns the name of the base and the default mode
-fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttributes> {
+fn parse_struct_attributes(class_attributes: &[venial::Attribute]) -> ParseResult<ClassAttributes> {
     let mut base_ty = ident("RefCounted");
     let mut init_strategy = InitStrategy::UserDefined;
     let mut is_tool = false;
     let mut is_editor_plugin = false;
     let mut is_hidden = false;
-    let mut rename: Option<Ident> = None;
+    let mut is_lazy = false;
+    let mut is_abstract = false;
+    let mut is_shared = false;
+    let mut is_inline_storage = false;
+    let mut required_classes = Vec::new();
+    let mut rename: Option<String> = None;
+    let mut namespace: Option<String> = None;
+    let mut debug_strategy = DebugStrategy::Absent;
+    let mut warnings_fn: Option<Ident> = None;
+    let mut persist = PersistConfig {
+        is_enabled: false,
+        version: 1,
+        migrate_fn: None,
+    };
 
     // #[class] attribute on struct
-    if let Some(mut parser) = KvParser::parse(&class.attributes, "class")? {
+    if let Some(mut parser) = KvParser::parse(class_attributes, "class")? {
         // #[class(base = Base)]
         if let Some(base) = parser.handle_ident("base")? {
             base_ty = base;
@@ -328,8 +863,27 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
             }
         }
 
-        // #[class(rename = NewName)]
-        rename = parser.handle_ident("rename")?;
+        // #[class(rename = NewName)], #[class(rename = "NewName")]
+        if let Some((key, name)) = parser.handle_ident_or_string("rename")? {
+            if !is_class_identifier(&name) {
+                return bail!(
+                    key,
+                    "#[class(rename)] must be a valid identifier (ASCII letters, digits and `_`), got `{name}`"
+                );
+            }
+            rename = Some(name);
+        }
+
+        // #[class(namespace = "Prefix")]
+        if let Some((key, prefix)) = parser.handle_ident_or_string("namespace")? {
+            if !prefix.is_empty() && !is_class_identifier(&prefix) {
+                return bail!(
+                    key,
+                    "#[class(namespace)] must be empty or a valid identifier (ASCII letters, digits and `_`), got `{prefix}`"
+                );
+            }
+            namespace = Some(prefix);
+        }
 
         // #[class(hidden)]
         // TODO consider naming this "internal"; godot-cpp uses that terminology:
@@ -339,6 +893,101 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
             is_hidden = true;
         }
 
+        // #[class(lazy)]
+        if let Some(span) = parser.handle_alone_with_span("lazy")? {
+            if is_editor_plugin {
+                return bail!(
+                    span,
+                    "#[class(lazy)] cannot be combined with `editor_plugin`, which Godot instantiates on startup"
+                );
+            }
+            is_lazy = true;
+        }
+
+        // #[class(abstract)]
+        if let Some(span) = parser.handle_alone_with_span("abstract")? {
+            if matches!(init_strategy, InitStrategy::Absent) {
+                return bail!(
+                    span,
+                    "#[class(abstract)] cannot be combined with `no_init`; scripts inheriting the class need a constructor"
+                );
+            }
+            if is_editor_plugin {
+                return bail!(
+                    span,
+                    "#[class(abstract)] cannot be combined with `editor_plugin`, which Godot instantiates on startup"
+                );
+            }
+            is_abstract = true;
+        }
+
+        // #[class(shared)]
+        if parser.handle_alone("shared")? {
+            is_shared = true;
+        }
+
+        // #[class(inline_storage)]
+        if parser.handle_alone("inline_storage")? {
+            is_inline_storage = true;
+        }
+
+        // #[class(requires = [Class1, Class2])]
+        if let Some(mut list) = parser.handle_array("requires")? {
+            while list.peek().is_some() {
+                required_classes.push(list.next_expr()?);
+            }
+            list.finish()?;
+        }
+
+        // #[class(debug)], #[class(debug = manual)]
+        if let Some((key, value)) = parser.handle_any_entry("debug") {
+            debug_strategy = match value {
+                None => DebugStrategy::Generated,
+                Some(value) => {
+                    let value = value.ident()?;
+                    if value != "manual" {
+                        return bail!(
+                            key,
+                            "#[class(debug)] expects either no value or `debug = manual`"
+                        );
+                    }
+                    DebugStrategy::UserDefined
+                }
+            };
+        }
+
+        // #[class(warnings = fn)]
+        if let Some(ident) = parser.handle_ident("warnings")? {
+            warnings_fn = Some(ident);
+        }
+
+        // #[class(persist)], #[class(persist_version = N)], #[class(persist_migrate = fn)]
+        persist.is_enabled = parser.handle_alone("persist")?;
+        if let Some(version) = parser.handle_usize("persist_version")? {
+            if !persist.is_enabled {
+                return bail!(
+                    parser.span(),
+                    "#[class(persist_version)] requires key `persist`"
+                );
+            }
+            if version == 0 || version > u32::MAX as usize {
+                return bail!(
+                    parser.span(),
+                    "#[class(persist_version)] must be between 1 and 2^32-1"
+                );
+            }
+            persist.version = version;
+        }
+        if let Some(migrate_fn) = parser.handle_ident("persist_migrate")? {
+            if !persist.is_enabled {
+                return bail!(
+                    migrate_fn,
+                    "#[class(persist_migrate)] requires key `persist`"
+                );
+            }
+            persist.migrate_fn = Some(migrate_fn);
+        }
+
         parser.finish()?;
     }
 
@@ -348,10 +997,29 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
         is_tool,
         is_editor_plugin,
         is_hidden,
+        is_lazy,
+        is_abstract,
+        is_shared,
+        is_inline_storage,
+        required_classes,
         rename,
+        namespace,
+        debug_strategy,
+        warnings_fn,
+        persist,
     })
 }
 
+/// Godot class names must be valid GDScript identifiers.
+fn is_class_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
 /// Fetches data for all named fields for a struct.
 ///
 /// Errors if `class` is a tuple struct.
@@ -398,6 +1066,16 @@ fn parse_fields(
             field.is_onready = true;
         }
 
+        // Prop<T> type inference
+        if path_ends_with_complex(&field.ty, "Prop") {
+            field.is_prop = true;
+        }
+
+        // StateMachine<S> type inference
+        if path_ends_with_complex(&field.ty, "StateMachine") {
+            field.is_state_machine = true;
+        }
+
         // #[init]
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "init")? {
             // #[init] on fields is useless if there is no generated constructor.
@@ -416,6 +1094,10 @@ fn parse_fields(
 
         // #[export]
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export")? {
+            // #[export(required)] combines with the other keys.
+            field.is_required = parser.handle_alone("required")?;
+            field.validate = parser.handle_ident("validate")?;
+
             let export = FieldExport::new_from_kv(&mut parser)?;
             field.export = Some(export);
             parser.finish()?;
@@ -423,11 +1105,73 @@ fn parse_fields(
 
         // #[var]
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "var")? {
+            if let Some(validate) = parser.handle_ident("validate")? {
+                if field.validate.is_some() {
+                    return bail!(
+                        validate,
+                        "`validate` key must not be specified in both #[var] and #[export]"
+                    );
+                }
+                field.validate = Some(validate);
+            }
+
             let var = FieldVar::new_from_kv(&mut parser)?;
             field.var = Some(var);
             parser.finish()?;
         }
 
+        // #[export(flatten)] registers the group's own properties, so it cannot be combined with #[var].
+        if field.var.is_some() && field.export.as_ref().is_some_and(FieldExport::is_flatten) {
+            return bail!(
+                named_field,
+                "#[export(flatten)] cannot be combined with #[var]"
+            );
+        }
+
+        // Validation happens in the generated setter, so a user-defined or omitted setter cannot be validated.
+        if let Some(validate) = &field.validate {
+            if field.export.as_ref().is_some_and(FieldExport::is_flatten) {
+                return bail!(
+                    validate,
+                    "`validate` cannot be used with #[export(flatten)]"
+                );
+            }
+
+            if field
+                .var
+                .as_ref()
+                .is_some_and(|var| var.setter != GetterSetter::Generated)
+            {
+                return bail!(
+                    validate,
+                    "`validate` requires a generated setter; call the validation from the custom `set` function instead"
+                );
+            }
+        }
+
+        // #[persist], #[persist(key = "name")]
+        if let Some(mut parser) = KvParser::parse(&named_field.attributes, "persist")? {
+            let key = match parser.handle_ident_or_string("key")? {
+                Some((_, key)) => key,
+                None => field.name.to_string(),
+            };
+            field.persist_key = Some(key);
+            parser.finish()?;
+        }
+
+        // #[inject]
+        if let Some(mut parser) = KvParser::parse(&named_field.attributes, "inject")? {
+            let Some(inject_ty) = util::extract_generic_arg(&field.ty, "Injectable") else {
+                return bail!(
+                    parser.span(),
+                    "#[inject] requires a field of type `Injectable<T>`"
+                );
+            };
+
+            field.inject_ty = Some(inject_ty);
+            parser.finish()?;
+        }
+
         // #[hint] to override type inference (must be at the end).
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "hint")? {
             if let Some(override_base) = handle_opposite_keys(&mut parser, "base", "hint")? {
@@ -437,19 +1181,32 @@ fn parse_fields(
             if let Some(override_onready) = handle_opposite_keys(&mut parser, "onready", "hint")? {
                 field.is_onready = override_onready;
             }
+
+            if let Some(override_prop) = handle_opposite_keys(&mut parser, "prop", "hint")? {
+                field.is_prop = override_prop;
+            }
+
+            if let Some(override_state_machine) =
+                handle_opposite_keys(&mut parser, "state_machine", "hint")?
+            {
+                field.is_state_machine = override_state_machine;
+            }
             parser.finish()?;
         }
 
         // Extra validation; eventually assign to base_fields or all_fields.
         if is_base {
             if field.is_onready
+                || field.is_prop
+                || field.is_state_machine
                 || field.var.is_some()
                 || field.export.is_some()
                 || field.default.is_some()
+                || field.persist_key.is_some()
             {
                 return bail!(
                     named_field,
-                    "base field cannot have type `OnReady<T>` or attributes #[var], #[export] or #[init]"
+                    "base field cannot have type `OnReady<T>`, `Prop<T>` or `StateMachine<S>`, or attributes #[var], #[export], #[init] or #[persist]"
                 );
             }
 
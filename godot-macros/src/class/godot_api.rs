@@ -8,11 +8,11 @@
 use proc_macro2::TokenStream;
 
 use crate::class::{transform_inherent_impl, transform_trait_impl};
-use crate::util::bail;
+use crate::util::{bail, path_is_single, KvParser};
 use crate::ParseResult;
 
 pub fn attribute_godot_api(input_decl: venial::Item) -> ParseResult<TokenStream> {
-    let decl = match input_decl {
+    let mut decl = match input_decl {
         venial::Item::Impl(decl) => decl,
         _ => bail!(
             input_decl,
@@ -31,9 +31,37 @@ pub fn attribute_godot_api(input_decl: venial::Item) -> ParseResult<TokenStream>
         return bail!(decl, "invalid Self type for #[godot_api] impl");
     };
 
+    let mut parser = KvParser::parse_required(&decl.attributes, "godot_api", &decl.self_ty)?;
+    let mut enums = Vec::new();
+    if let Some(mut list) = parser.handle_array("enums")? {
+        while list.peek().is_some() {
+            enums.push(list.next_expr()?);
+        }
+        list.finish()?;
+    }
+    let is_secondary = parser.handle_alone("secondary")?;
+    parser.finish()?;
+
+    decl.attributes
+        .retain(|attr| !path_is_single(&attr.path, "godot_api"));
+
     if decl.trait_ty.is_some() {
+        if !enums.is_empty() {
+            return bail!(
+                decl,
+                "#[godot_api(enums)] can only be used on inherent impl blocks"
+            );
+        }
+
+        if is_secondary {
+            return bail!(
+                decl,
+                "#[godot_api(secondary)] can only be used on inherent impl blocks"
+            );
+        }
+
         transform_trait_impl(decl)
     } else {
-        transform_inherent_impl(decl)
+        transform_inherent_impl(decl, enums, is_secondary)
     }
 }
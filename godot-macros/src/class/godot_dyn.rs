@@ -0,0 +1,69 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::util::{bail, path_is_single, KvParser};
+use crate::ParseResult;
+
+/// Codegen for `#[godot_dyn] impl Trait for Class`
+pub fn attribute_godot_dyn(input_decl: venial::Item) -> ParseResult<TokenStream> {
+    let mut decl = match input_decl {
+        venial::Item::Impl(decl) => decl,
+        _ => bail!(
+            input_decl,
+            "#[godot_dyn] can only be applied on `impl Trait for Class` blocks",
+        )?,
+    };
+
+    if decl.impl_generic_params.is_some() {
+        return bail!(&decl, "#[godot_dyn] does not support generic parameters");
+    }
+
+    let Some(trait_ty) = decl.trait_ty.clone() else {
+        return bail!(
+            &decl,
+            "#[godot_dyn] requires a trait impl, e.g. `impl MyTrait for MyClass`"
+        );
+    };
+
+    if decl.self_ty.as_path().is_none() {
+        return bail!(decl, "invalid Self type for #[godot_dyn] impl");
+    };
+
+    let parser = KvParser::parse_required(&decl.attributes, "godot_dyn", &decl.self_ty)?;
+    parser.finish()?;
+
+    decl.attributes
+        .retain(|attr| !path_is_single(&attr.path, "godot_dyn"));
+
+    let class_name = &decl.self_ty;
+    let prv = quote! { ::godot::private };
+
+    Ok(quote! {
+        #decl
+
+        impl ::godot::obj::AsDyn<dyn #trait_ty> for #class_name {
+            fn dyn_upcast(&self) -> &(dyn #trait_ty + 'static) {
+                self
+            }
+
+            fn dyn_upcast_mut(&mut self) -> &mut (dyn #trait_ty + 'static) {
+                self
+            }
+        }
+
+        ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
+            class_name: <#class_name as ::godot::obj::GodotClass>::class_name(),
+            item: #prv::PluginItem::DynTraitImpl(
+                #prv::DynTraitImpl::new::<#class_name, dyn #trait_ty>()
+            ),
+            init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
+        });
+    })
+}
@@ -0,0 +1,75 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::util::{bail, path_is_single, KvParser};
+use crate::ParseResult;
+
+/// Codegen for `#[godot_enum] enum MyEnum`
+pub fn attribute_godot_enum(input_decl: venial::Item) -> ParseResult<TokenStream> {
+    let mut enum_ = match input_decl {
+        venial::Item::Enum(enum_) => enum_,
+        _ => bail!(input_decl, "#[godot_enum] can only be applied on enums")?,
+    };
+
+    if enum_.generic_params.is_some() {
+        return bail!(
+            &enum_.generic_params,
+            "#[godot_enum] does not support generic parameters"
+        );
+    }
+
+    let mut parser = KvParser::parse_required(&enum_.attributes, "godot_enum", &enum_.name)?;
+    let rename = parser.handle_ident("rename")?;
+    let is_bitfield = parser.handle_alone("bitfield")?;
+    parser.finish()?;
+
+    enum_
+        .attributes
+        .retain(|attr| !path_is_single(&attr.path, "godot_enum"));
+
+    let mut enumerator_names = Vec::new();
+    for variant in enum_.variants.items() {
+        if !matches!(variant.fields, venial::Fields::Unit) {
+            return bail!(
+                &variant.fields,
+                "#[godot_enum] only supports C-style enums, without fields"
+            );
+        }
+
+        enumerator_names.push(variant.name.clone());
+    }
+
+    let enum_name = &enum_.name;
+    let godot_name = rename.as_ref().unwrap_or(enum_name).to_string();
+    let enumerator_strs = enumerator_names.iter().map(|name| name.to_string());
+
+    Ok(quote! {
+        #enum_
+
+        impl ::godot::register::private::constant::ExportEnum for #enum_name {
+            const ENUM_NAME: &'static str = #godot_name;
+            const IS_BITFIELD: bool = #is_bitfield;
+
+            fn enumerators() -> Vec<::godot::register::private::constant::IntegerConstant> {
+                use ::godot::register::private::constant::IntegerConstant;
+                use ::godot::builtin::StringName;
+
+                vec![
+                    #(
+                        IntegerConstant::new(
+                            StringName::from(#enumerator_strs),
+                            #enum_name::#enumerator_names as i64,
+                        ),
+                    )*
+                ]
+            }
+        }
+    })
+}
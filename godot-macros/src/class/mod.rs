@@ -7,8 +7,11 @@
 
 mod derive_godot_class;
 mod godot_api;
+mod godot_dyn;
+mod godot_enum;
 mod data_models {
     pub mod constant;
+    pub mod docs;
     pub mod field;
     pub mod field_export;
     pub mod field_var;
@@ -20,6 +23,7 @@ mod data_models {
 }
 
 pub(crate) use data_models::constant::*;
+pub(crate) use data_models::docs::*;
 pub(crate) use data_models::field::*;
 pub(crate) use data_models::field_export::*;
 pub(crate) use data_models::field_var::*;
@@ -30,3 +34,5 @@ pub(crate) use data_models::property::*;
 pub(crate) use data_models::signal::*;
 pub(crate) use derive_godot_class::*;
 pub(crate) use godot_api::*;
+pub(crate) use godot_dyn::*;
+pub(crate) use godot_enum::*;
@@ -0,0 +1,124 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::class::FieldExport;
+use crate::util::{bail, KvParser};
+use crate::ParseResult;
+
+/// Derives `ExportGroup` for a struct with named fields.
+pub fn derive_export_group(item: venial::Item) -> ParseResult<TokenStream> {
+    let struct_ = match item {
+        venial::Item::Struct(struct_) => struct_,
+        _ => {
+            return bail!(
+                item,
+                "#[derive(ExportGroup)] can only be applied on structs"
+            )
+        }
+    };
+
+    if struct_.generic_params.is_some() {
+        return bail!(
+            &struct_.generic_params,
+            "#[derive(ExportGroup)] does not support generic parameters"
+        );
+    }
+
+    let named_fields = match &struct_.fields {
+        venial::Fields::Named(fields) => fields.fields.inner.clone(),
+        _ => {
+            return bail!(
+                &struct_.fields,
+                "#[derive(ExportGroup)] requires a struct with named fields"
+            )
+        }
+    };
+
+    let mut property_infos = Vec::new();
+    let mut get_arms = Vec::new();
+    let mut set_arms = Vec::new();
+
+    for (index, (field, _punct)) in named_fields.iter().enumerate() {
+        let field_name = &field.name;
+        let field_type = &field.ty;
+        let field_name_str = field_name.to_string();
+
+        // #[export(...)] is optional and only customizes the hint; all fields are part of the group.
+        let export_hint = match KvParser::parse(&field.attributes, "export")? {
+            Some(mut parser) => {
+                let export = FieldExport::new_from_kv(&mut parser)?;
+                parser.finish()?;
+
+                if export.is_flatten() {
+                    return bail!(field, "#[export(flatten)] cannot be nested inside groups");
+                }
+
+                export.to_export_hint(field_type)
+            }
+            None => None,
+        };
+
+        let property_info = quote! {
+            ::godot::meta::PropertyInfo::new_export::<#field_type>(#field_name_str)
+        };
+
+        property_infos.push(match export_hint {
+            Some(export_hint) => quote! { #property_info.with_hint_info(#export_hint) },
+            None => property_info,
+        });
+
+        get_arms.push(quote! {
+            #index => ::godot::meta::ToGodot::to_variant(
+                &::godot::register::property::Var::get_property(&self.#field_name)
+            ),
+        });
+
+        let property_path = format!("{}.{field_name_str}", struct_.name);
+        set_arms.push(quote! {
+            #index => match value.try_to::<<#field_type as ::godot::meta::GodotConvert>::Via>() {
+                ::std::result::Result::Ok(value) => {
+                    ::godot::register::property::Var::set_property(&mut self.#field_name, value)
+                }
+                ::std::result::Result::Err(err) => ::godot::global::godot_error!(
+                    "invalid value for property `{}`, keeping previous value: {}",
+                    #property_path,
+                    err
+                ),
+            },
+        });
+    }
+
+    let name = &struct_.name;
+    let name_str = name.to_string();
+
+    Ok(quote! {
+        impl ::godot::register::property::ExportGroup for #name {
+            fn group_properties() -> Vec<::godot::meta::PropertyInfo> {
+                vec![
+                    #( #property_infos, )*
+                ]
+            }
+
+            fn get_group_property(&self, index: usize) -> ::godot::builtin::Variant {
+                match index {
+                    #( #get_arms )*
+                    _ => panic!("invalid property index {index} for group `{}`", #name_str),
+                }
+            }
+
+            fn set_group_property(&mut self, index: usize, value: ::godot::builtin::Variant) {
+                match index {
+                    #( #set_arms )*
+                    _ => panic!("invalid property index {index} for group `{}`", #name_str),
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,78 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::util::bail;
+use crate::ParseResult;
+
+/// Derives `GodotFlags` for a fieldless enum, whose discriminants are the bits.
+pub fn derive_godot_flags(item: venial::Item) -> ParseResult<TokenStream> {
+    let enum_ = match item {
+        venial::Item::Enum(enum_) => enum_,
+        _ => return bail!(item, "#[derive(GodotFlags)] can only be applied on enums"),
+    };
+
+    if enum_.generic_params.is_some() {
+        return bail!(
+            &enum_.generic_params,
+            "#[derive(GodotFlags)] does not support generic parameters"
+        );
+    }
+
+    let name = &enum_.name;
+    let mut flags = Vec::new();
+    let mut assertions = Vec::new();
+
+    for variant in enum_.variants.items() {
+        let variant_ident = &variant.name;
+        let variant_name = variant_ident.to_string();
+
+        if !matches!(variant.fields, venial::Fields::Unit) {
+            return bail!(
+                variant_ident,
+                "#[derive(GodotFlags)] only supports variants without fields"
+            );
+        }
+
+        if variant.value.is_none() {
+            return bail!(
+                variant_ident,
+                "#[derive(GodotFlags)] requires explicit discriminants holding the bits, e.g. `{variant_name} = 1 << 0`"
+            );
+        }
+
+        let message = format!("GodotFlags: variant `{variant_name}` must have a non-zero value");
+        assertions.push(quote! {
+            assert!(#name::#variant_ident as u32 != 0, #message);
+        });
+        flags.push(quote! { (#variant_name, #name::#variant_ident) });
+    }
+
+    Ok(quote! {
+        impl ::godot::register::flags::GodotFlags for #name {
+            const FLAGS: &'static [(&'static str, Self)] = &[ #( #flags ),* ];
+
+            fn bits(self) -> u32 {
+                self as u32
+            }
+        }
+
+        impl ::std::ops::BitOr for #name {
+            type Output = ::godot::register::flags::FlagSet<Self>;
+
+            fn bitor(self, rhs: Self) -> Self::Output {
+                ::godot::register::flags::FlagSet::from(self) | rhs
+            }
+        }
+
+        const _: () = {
+            #( #assertions )*
+        };
+    })
+}
@@ -0,0 +1,77 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::util::bail;
+use crate::ParseResult;
+
+/// Derives `StateEnum` for an enum, whose variants may carry data.
+pub fn derive_state_enum(item: venial::Item) -> ParseResult<TokenStream> {
+    let enum_ = match item {
+        venial::Item::Enum(enum_) => enum_,
+        _ => return bail!(item, "#[derive(StateEnum)] can only be applied on enums"),
+    };
+
+    if enum_.generic_params.is_some() {
+        return bail!(
+            &enum_.generic_params,
+            "#[derive(StateEnum)] does not support generic parameters"
+        );
+    }
+
+    if enum_.variants.items().next().is_none() {
+        return bail!(
+            &enum_.name,
+            "#[derive(StateEnum)] requires at least one variant"
+        );
+    }
+
+    let name = &enum_.name;
+    let mut variant_names = Vec::new();
+    let mut index_arms = Vec::new();
+    let mut from_name_arms = Vec::new();
+
+    for (index, variant) in enum_.variants.items().enumerate() {
+        let variant_ident = &variant.name;
+        let variant_name = variant_ident.to_string();
+
+        let pattern = match &variant.fields {
+            venial::Fields::Unit => {
+                from_name_arms.push(quote! {
+                    #variant_name => ::std::option::Option::Some(Self::#variant_ident),
+                });
+                quote! { Self::#variant_ident }
+            }
+            venial::Fields::Tuple(_) => quote! { Self::#variant_ident(..) },
+            venial::Fields::Named(_) => quote! { Self::#variant_ident { .. } },
+        };
+
+        index_arms.push(quote! { #pattern => #index, });
+        variant_names.push(variant_name);
+    }
+
+    Ok(quote! {
+        impl ::godot::obj::StateEnum for #name {
+            const VARIANT_NAMES: &'static [&'static str] = &[ #( #variant_names ),* ];
+
+            fn variant_index(&self) -> usize {
+                match self {
+                    #( #index_arms )*
+                }
+            }
+
+            fn from_variant_name(name: &str) -> ::std::option::Option<Self> {
+                match name {
+                    #( #from_name_arms )*
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
+    })
+}
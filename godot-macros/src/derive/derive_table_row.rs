@@ -0,0 +1,85 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::util::{bail, KvParser};
+use crate::ParseResult;
+
+/// Derives `TableRow` for a struct with named fields.
+pub fn derive_table_row(item: venial::Item) -> ParseResult<TokenStream> {
+    let struct_ = match item {
+        venial::Item::Struct(struct_) => struct_,
+        _ => return bail!(item, "#[derive(TableRow)] can only be applied on structs"),
+    };
+
+    if struct_.generic_params.is_some() {
+        return bail!(
+            &struct_.generic_params,
+            "#[derive(TableRow)] does not support generic parameters"
+        );
+    }
+
+    let named_fields = match &struct_.fields {
+        venial::Fields::Named(fields) => fields.fields.inner.clone(),
+        _ => {
+            return bail!(
+                &struct_.fields,
+                "#[derive(TableRow)] requires a struct with named fields"
+            )
+        }
+    };
+
+    let mut required_columns = Vec::new();
+    let mut field_inits = Vec::new();
+
+    for (field, _punct) in named_fields.iter() {
+        let field_name = &field.name;
+        let field_type = &field.ty;
+
+        let mut column = field_name.to_string();
+        let mut is_default = false;
+
+        if let Some(mut parser) = KvParser::parse(&field.attributes, "table")? {
+            if let Some((_, name)) = parser.handle_ident_or_string("column")? {
+                column = name;
+            }
+            is_default = parser.handle_alone("default")?;
+            parser.finish()?;
+        }
+
+        let parse_fn = if is_default {
+            quote! { parse_or_default }
+        } else {
+            required_columns.push(column.clone());
+            quote! { parse }
+        };
+
+        field_inits.push(quote! {
+            #field_name: row.#parse_fn::<#field_type>(#column)?,
+        });
+    }
+
+    let name = &struct_.name;
+
+    Ok(quote! {
+        impl ::godot::tools::table::TableRow for #name {
+            fn required_columns() -> &'static [&'static str] {
+                &[ #( #required_columns ),* ]
+            }
+
+            fn from_row(
+                row: &::godot::tools::table::RowCells<'_>,
+            ) -> ::std::result::Result<Self, ::godot::tools::table::TableError> {
+                ::std::result::Result::Ok(Self {
+                    #( #field_inits )*
+                })
+            }
+        }
+    })
+}
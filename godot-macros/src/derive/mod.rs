@@ -9,13 +9,21 @@
 
 mod data_models;
 mod derive_export;
+mod derive_export_group;
 mod derive_from_godot;
 mod derive_godot_convert;
+mod derive_godot_flags;
+mod derive_state_enum;
+mod derive_table_row;
 mod derive_to_godot;
 mod derive_var;
 
 pub(crate) use derive_export::*;
+pub(crate) use derive_export_group::*;
 pub(crate) use derive_from_godot::*;
 pub(crate) use derive_godot_convert::*;
+pub(crate) use derive_godot_flags::*;
+pub(crate) use derive_state_enum::*;
+pub(crate) use derive_table_row::*;
 pub(crate) use derive_to_godot::*;
 pub(crate) use derive_var::*;
@@ -51,28 +51,42 @@ pub fn attribute_gdextension(item: venial::Item) -> ParseResult<TokenStream> {
             // involved, but I don't know what guarantees we have here.
             //
             // We should keep an eye out for these sorts of failures!
+            //
+            // The side module is found by its file name, which is the crate name (not the package name, which can differ through
+            // `[lib] name`). Godot may have loaded it from a path inside the virtual file system, so keys are matched by suffix.
             let script = std::ffi::CString::new(concat!(
-                "var pkgName = '", env!("CARGO_PKG_NAME"), "';", r#"
-                var libName = pkgName.replaceAll('-', '_') + '.wasm';
+                "var libName = '", env!("CARGO_CRATE_NAME"), ".wasm';", r#"
                 var dso = LDSO.loadedLibsByName[libName];
-                // This property was renamed as of emscripten 3.1.34
-                var dso_exports = "module" in dso ? dso["module"] : dso["exports"];
-                var registrants = [];
-                for (sym in dso_exports) {
-                    if (sym.startsWith("dynCall_")) {
-                        if (!(sym in Module)) {
-                            console.log(`Patching Module with ${sym}`);
-                            Module[sym] = dso_exports[sym];
+                if (!dso) {
+                    for (var name in LDSO.loadedLibsByName) {
+                        if (name.endsWith('/' + libName)) {
+                            dso = LDSO.loadedLibsByName[name];
+                            break;
                         }
-                    } else if (sym.startsWith("rust_gdext_registrant_")) {
-                        registrants.push(sym);
                     }
                 }
-                for (sym of registrants) {
-                    console.log(`Running registrant ${sym}`);
-                    dso_exports[sym]();
+                if (!dso) {
+                    console.error(`godot-rust: side module ${libName} not loaded; classes cannot be registered.`);
+                } else {
+                    // This property was renamed as of emscripten 3.1.34
+                    var dso_exports = "module" in dso ? dso["module"] : dso["exports"];
+                    var registrants = [];
+                    for (var sym in dso_exports) {
+                        if (sym.startsWith("dynCall_")) {
+                            if (!(sym in Module)) {
+                                console.log(`Patching Module with ${sym}`);
+                                Module[sym] = dso_exports[sym];
+                            }
+                        } else if (sym.startsWith("rust_gdext_registrant_")) {
+                            registrants.push(sym);
+                        }
+                    }
+                    for (var sym of registrants) {
+                        console.log(`Running registrant ${sym}`);
+                        dso_exports[sym]();
+                    }
+                    console.log("Added",  registrants.length, "plugins to registry!");
                 }
-                console.log("Added",  registrants.length, "plugins to registry!");
             "#)).expect("Unable to create CString from script");
 
             extern "C" { fn emscripten_run_script(script: *const std::ffi::c_char); }
@@ -85,14 +99,19 @@ pub fn attribute_gdextension(item: venial::Item) -> ParseResult<TokenStream> {
             library: ::godot::sys::GDExtensionClassLibraryPtr,
             init: *mut ::godot::sys::GDExtensionInitialization,
         ) -> ::godot::sys::GDExtensionBool {
-            // Required due to the lack of a constructor facility such as .init_array in rust wasm
+            // Required due to the lack of a constructor facility such as .init_array in rust wasm.
+            // Registrants must only run once, even if the entry point is invoked again; otherwise plugins are registered twice.
             #[cfg(target_os = "emscripten")]
-            emscripten_preregistration();
+            {
+                static PREREGISTRATION: ::std::sync::Once = ::std::sync::Once::new();
+                PREREGISTRATION.call_once(emscripten_preregistration);
+            }
 
             ::godot::init::__gdext_load_library::<#impl_ty>(
                 interface_or_get_proc_address,
                 library,
-                init
+                init,
+                ::godot::init::LibraryMetadata::new(env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION")),
             )
         }
 
@@ -217,6 +217,49 @@ use crate::util::ident;
 /// }
 /// ```
 ///
+/// ## Validation
+///
+/// Instead of writing a full setter, `#[var(validate = fn_name)]` or `#[export(validate = fn_name)]` names a method
+/// `fn fn_name(&self, new_value: T) -> Result<T, String>`, where `T` is the field type. The generated setter calls it on every write,
+/// including edits in the inspector and assignments from GDScript. On `Ok`, the returned value is stored, which allows clamping or
+/// normalizing it. On `Err`, the message is logged as error and the field keeps its previous value.
+///
+/// The new value is produced by applying [`Var::set_property()`](../register/property/trait.Var.html#tymethod.set_property) to a clone
+/// of the current one, so `T` must implement `Clone`. Validation requires a generated setter, so it cannot be combined with `set = ...`.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct Character {
+///     #[export(validate = clamp_health)]
+///     health: i32,
+/// }
+///
+/// impl Character {
+///     fn clamp_health(&self, new_value: i32) -> Result<i32, String> {
+///         Ok(new_value.clamp(0, 100))
+///     }
+/// }
+/// ```
+///
+/// ## Network replication
+///
+/// Properties can be marked for replication by a `MultiplayerSynchronizer` with `#[var(replicate = ...)]`, where the mode is one of
+/// `always`, `on_change` or `spawn`. A matching `SceneReplicationConfig` can then be generated at runtime, see
+/// [`godot::register::replication`](../register/replication/index.html).
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init, base=Node)]
+/// struct Player {
+///     #[var(replicate = on_change)]
+///     health: i32,
+/// #   base: Base<Node>,
+/// }
+/// ```
+///
 /// ## Property exports
 ///
 /// For exporting properties to the editor, you can use the `#[export]` attribute:
@@ -232,6 +275,11 @@ use crate::util::ident;
 /// ```
 ///
 /// If you don't also include a `#[var]` attribute, then a default one will be generated.
+///
+/// With a generated `init`, the inspector's "revert" button restores the field's initial value, i.e. `#[init(default = ...)]` or
+/// `Default::default()`. The initializers are evaluated on their own, no instance is constructed. This happens once per class, when the
+/// editor first asks for a revert value; keep them free of side effects. Classes with their own `init()` have no automatic revert values. To customize this, override `property_get_revert()` in the `I*` trait impl.
+///
 /// `#[export]` also supports all of GDScript's annotations, in a slightly different format. The format is
 /// translated from an annotation by following these four rules:
 ///
@@ -247,6 +295,7 @@ use crate::util::ident;
 ///
 /// ```
 /// # use godot::prelude::*;
+/// # use godot::classes::{Area2D, CollisionShape2D, Texture2D};
 /// #[derive(GodotClass)]
 /// # #[class(init)]
 /// struct MyStruct {
@@ -266,6 +315,18 @@ use crate::util::ident;
 ///     #[export(file = "*.gd")]
 ///     gdscript_file: GString,
 ///
+///     // @export_file("*.json", "*.cfg")
+///     #[export(file = ["*.json", "*.cfg"])]
+///     config_file: String,
+///
+///     // @export_dir
+///     #[export(dir)]
+///     save_dir: GString,
+///
+///     // @export_global_file("*.png")
+///     #[export(global_file = "*.png")]
+///     screenshot: GString,
+///
 ///     // @export_flags_3d_physics
 ///     #[export(flags_3d_physics)]
 ///     physics: u32,
@@ -281,10 +342,35 @@ use crate::util::ident;
 ///     // @export_flags("A:1", "B:2", "AB:3")
 ///     #[export(flags = (A = 1, B = 2, AB = 3))]
 ///     flags: u32,
+///
+///     // @export var textures: Array[Texture2D]
+///     #[export]
+///     textures: Array<Option<Gd<Texture2D>>>,
+///
+///     // @export var icons: Dictionary[String, Texture2D] (Godot 4.4+)
+///     #[export(dictionary = (GString, Option<Gd<Texture2D>>))]
+///     icons: Dictionary,
+///
+///     // @export_node_path("Area2D", "CollisionShape2D") var triggers: Array[NodePath]
+///     #[export(node_path = (Area2D, CollisionShape2D))]
+///     triggers: Vec<NodePath>,
 /// }
 ///
 /// ```
 ///
+/// Typed arrays are exported with their element type, so the editor only accepts matching values. Since `Dictionary` is
+/// not generic, its key and value types are declared in `#[export(dictionary = (K, V))]`. Typed dictionaries require
+/// Godot 4.4; on earlier versions, the dictionary is exported untyped.
+///
+/// `Vec<T>` fields are exported like `Array<T>`. `NodePath` fields, as well as `Array<NodePath>` and `Vec<NodePath>`, let the user
+/// pick nodes from the scene tree; `#[export(node_path = (...))]` restricts the picker to the listed classes and their subclasses.
+/// In `ready()`, [`Node::resolve_nodes_as()`](../classes/struct.Node.html#method.resolve_nodes_as) turns the paths into `Gd<T>`
+/// objects, reporting all invalid paths at once.
+///
+/// Instead of listing flags inline, `#[export(flags = Type)]` takes names and bits from an enum deriving
+/// [`GodotFlags`](../register/flags/trait.GodotFlags.html). Fields of type [`FlagSet<F>`](../register/flags/struct.FlagSet.html)
+/// need no argument; a plain `#[export]` already exports them as flags, and offers typed `contains/insert/remove` in Rust.
+///
 /// Most values in expressions like `key = value`, can be an arbitrary expression that evaluates to the
 /// right value. Meaning you can use constants or variables, as well as any other rust syntax you'd like in
 /// the export attributes.
@@ -325,6 +411,46 @@ use crate::util::ident;
 /// }
 /// ```
 ///
+/// ## Configuration warnings
+///
+/// Exported fields marked `#[export(required)]` display a warning on the node in the scene dock, as long as they are not assigned.
+/// This works for `Option<T>` (e.g. `Option<Gd<T>>`) as well as `GString`, `StringName` and `NodePath`, which count as unassigned
+/// while empty. The `required` key can be combined with other export keys.
+///
+/// Further warnings can be provided with `#[class(warnings = fn_name)]`, by a method `fn fn_name(&self)` returning an iterator of
+/// strings (e.g. `Vec<String>`). Warnings are combined with those returned by an overridden `get_configuration_warnings()`.
+///
+/// As configuration warnings are computed in the editor, the class must be a `#[class(tool)]`. Godot evaluates warnings when the
+/// scene is opened, and whenever `Node::update_configuration_warnings()` is called.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(tool, init, base=Node3D, warnings = check_speed)]
+/// struct Turret {
+///     #[export(required)]
+///     projectile: Option<Gd<PackedScene>>,
+///
+///     #[export(required)]
+///     target: NodePath,
+///
+///     #[export]
+///     speed: f32,
+///
+///     base: Base<Node3D>,
+/// }
+///
+/// impl Turret {
+///     fn check_speed(&self) -> Vec<String> {
+///         if self.speed <= 0.0 {
+///             vec!["Speed must be positive.".to_string()]
+///         } else {
+///             vec![]
+///         }
+///     }
+/// }
+/// ```
+///
 /// # Signals
 ///
 /// The `#[signal]` attribute is quite limited at the moment. The functions it decorates (the signals) can accept parameters.
@@ -343,9 +469,42 @@ use crate::util::ident;
 ///
 ///     #[signal]
 ///     fn some_signal_with_parameters(my_parameter: Gd<Node>);
+///
+///     #[signal]
+///     fn bodies_hit(bodies: Vec<Gd<Node>>);
+/// }
+/// ```
+///
+/// Parameters of type `Vec<T>` and `Array<T>` are registered as typed arrays (`Array[T]` in GDScript). Handlers declared with `#[func]`
+/// can take the same types; `Vec<T>` parameters also accept untyped arrays, checking each element. To emit from Rust without manual
+/// `Variant` conversions, use [`Signal::emit_typed()`](../builtin/struct.Signal.html#method.emit_typed).
+///
+/// Fields of type [`Prop<T>`](../obj/struct.Prop.html) additionally register a signal `<field>_changed(value: T)`, which requires
+/// `T: Var`. If the class has a generated constructor and a `Base<T>` field, the signal is emitted whenever the property changes.
+///
+/// Similarly, fields of type [`StateMachine<S>`](../obj/struct.StateMachine.html) register a signal `<field>_changed(from, to)`, emitted
+/// on every transition with the variant names. Unless the field has `#[var]` or `#[export]`, they also register a read-only property
+/// `<field>` with the name of the current state. See [`StateEnum`](../obj/trait.StateEnum.html) for the enum side.
+///
+/// # Enums as state classes
+///
+/// `#[derive(GodotClass)]` can also be applied on enums, whose variants may carry data. Each object then holds one variant as its state.
+/// The derive implements [`StateEnum`](../obj/trait.StateEnum.html) and [`StateClass`](../obj/trait.StateClass.html), registers an
+/// exported property `state` with the current variant name, and a signal `state_changed(from, to)` emitted on transitions.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// enum Enemy {
+///     Idle,
+///     Chasing { target: InstanceId },
 /// }
 /// ```
 ///
+/// With `#[class(init)]`, objects start in the first variant, which must be a unit variant. Keys that depend on fields (`persist`,
+/// `shared`, `warnings` and generated `debug`) are not supported for enums. See `StateClass` for transitions and per-frame dispatch.
+///
 /// # Further class customization
 ///
 /// ## Running code in the editor
@@ -394,7 +553,25 @@ use crate::util::ident;
 /// }
 /// ```
 ///
-/// These classes will appear in the Godot editor and GDScript as "AnimalToad" or "NpcToad".
+/// These classes will appear in the Godot editor and GDScript as "AnimalToad" or "NpcToad". The new name can also be given as a
+/// string literal: `rename = "AnimalToad"`.
+///
+/// To avoid clashes with other extensions, all classes of an extension can be given a common prefix with
+/// [`ExtensionLibrary::class_name_prefix()`](../init/trait.ExtensionLibrary.html#method.class_name_prefix).
+/// Individual classes can override it using the `namespace` key, which is prepended to the (possibly renamed) class name:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// // Registered as "AcmeToad", regardless of the extension-wide prefix.
+/// #[derive(GodotClass)]
+/// #[class(init, namespace = "Acme")]
+/// pub struct Toad {}
+///
+/// // Registered as "Frog", even if the extension has a prefix.
+/// #[derive(GodotClass)]
+/// #[class(init, namespace = "")]
+/// pub struct Frog {}
+/// ```
 ///
 /// ## Class hiding
 ///
@@ -410,11 +587,150 @@ use crate::util::ident;
 /// Even though this class is a `Node` and it has an init function, it still won't show up in the editor as a node you can add to a scene
 /// because we have added a `hidden` key to the class. This will also prevent it from showing up in documentation.
 ///
+/// ## Abstract classes
+///
+/// A class marked with `#[class(abstract)]` is registered as an abstract base class (called "virtual" in Godot). It cannot be created
+/// from the editor's "Create New Node" dialog, but scripts can inherit it, and Rust code can still instantiate it.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node2D, abstract)]
+/// pub struct Enemy {
+///     base: Base<Node2D>,
+/// }
+/// ```
+///
+/// Unlike `#[class(no_init)]` -- which Godot also calls "abstract", meaning a class without constructor -- the class still needs a
+/// constructor, because instances of inheriting scripts are created through it. Combining `abstract` with `no_init` is thus an error.
+///
+/// ## Lazy registration
+///
+/// Extensions with many classes spend noticeable time registering them on startup. With `#[class(lazy)]`, a class is only registered
+/// when it is first instantiated from Rust (e.g. via `new_alloc()` or `Gd::from_init_fn()`). This suits rarely used classes that are only
+/// created by Rust code. If Godot needs the class before that -- for example when loading a scene containing it, or in GDScript --
+/// call [`register_lazy_classes()`](../init/fn.register_lazy_classes.html) beforehand.
+///
+/// In the editor, lazy classes are registered eagerly, so they remain available in dialogs and scenes. With the `register-profiling`
+/// feature, registration times are available in [`godot::init::profiling`](../init/profiling/index.html).
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node, lazy)]
+/// pub struct DebugOverlay {}
+/// ```
+///
+/// ## Sharing classes between extensions
+///
+/// If a game consists of multiple Rust GDExtensions that depend on a common crate, the classes of that crate would be registered by
+/// each library. With `#[class(shared)]`, only the first library loaded registers the class; the others reuse that registration, so that
+/// `Gd<T>::bind()` and `bind_mut()` access the same instances in all libraries. All libraries must be built with the same godot-rust
+/// version and compiler; see [`godot::register::extensions`](../register/extensions/index.html#shared-classes) for details.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Resource, namespace = "", shared)]
+/// pub struct Inventory {
+///     items: Vec<GString>,
+/// }
+/// ```
+///
+/// ## Registration order
+///
+/// Classes of one init level are registered in alphabetical order. If a class needs another one to be registered first -- for example,
+/// because an editor plugin refers to a custom node, or a default property value is an instance of another class -- list the
+/// dependencies with `requires`:
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Resource)]
+/// struct Weapon {}
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node, requires = [Weapon])]
+/// struct Armory {
+///     #[export]
+///     default_weapon: Option<Gd<Weapon>>,
+///     base: Base<Node>,
+/// }
+/// ```
+///
+/// Required classes are registered before the class itself, even if they use `#[class(lazy)]`. A required class must not have a later
+/// init level. Cyclic requirements are reported as an error on startup.
+///
+/// ## Inline storage
+///
+/// Each instance of a user class normally needs two allocations on the Rust side: the instance storage that Godot refers to, and the
+/// struct itself. With `#[class(inline_storage)]`, the struct is stored directly inside the instance storage instead. This saves an
+/// allocation and a pointer indirection on every `bind()`/`bind_mut()`, which adds up for classes with many short-lived instances, such as
+/// bullets or particles with a few fields of state.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node2D, inline_storage)]
+/// struct Bullet {
+///     velocity: Vector2,
+///     damage: i32,
+///     base: Base<Node2D>,
+/// }
+/// ```
+///
+/// Borrowing behaves exactly as with the default storage, including re-borrows through `base_mut()`. Since the whole instance storage
+/// grows by the size of the struct, this is not worthwhile for large classes. With the `experimental-threads` feature, the key currently
+/// has no effect, and a warning is emitted at compile time.
+///
+/// ## Debug output
+///
+/// `Gd<T>` always implements `Debug`, printing instance ID and class name. With `#[class(debug)]`, a field-wise `Debug` impl is generated
+/// for the struct, and `Gd<T>`'s output includes the user instance as well. The `Base<T>` field is omitted.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node, debug)]
+/// struct Player {
+///     hp: i32,
+///     base: Base<Node>,
+/// }
+///
+/// // format!("{:?}", player) -> Gd { id: 1234, class: Player, instance: Player { hp: 100 } }
+/// ```
+///
+/// If you want to implement `Debug` for the struct yourself, use `#[class(debug = manual)]` instead.
+///
+/// Formatting never panics due to bind guards: if the object is currently bound mutably (e.g. printing `self.to_gd()` inside a
+/// `&mut self` method), the instance is shown as `<bound>`. Objects nested inside the instance (e.g. `Gd` fields) are printed without
+/// their own instance, which avoids infinite recursion for reference cycles.
+///
+/// ## Persistence
+///
+/// With `#[class(persist)]`, fields marked with `#[persist]` can be saved to and restored from a `Dictionary`, individually or for a whole
+/// scene subtree. Optional keys `persist_version = N` and `persist_migrate = fn_name` support upgrading states saved by older versions.
+/// A field can be stored under a different key with `#[persist(key = "name")]`.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node, persist)]
+/// struct Chest {
+///     #[persist]
+///     is_open: bool,
+///     base: Base<Node>,
+/// }
+/// ```
+///
+/// See [`godot::tools::persist`](../tools/persist/index.html) for details.
+///
 /// # Further field customization
 ///
 /// ## Fine-grained inference hints
 ///
-/// The derive macro is relatively smart about recognizing `Base<T>` and `OnReady<T>` types, and works also if those are qualified.
+/// The derive macro is relatively smart about recognizing `Base<T>`, `OnReady<T>`, `Prop<T>` and `StateMachine<S>`
+/// types, and works also if those are qualified.
 ///
 /// However, there may be situations where you need to help it out -- for example, if you have a type alias for `Base<T>`, or use an unrelated
 /// `my_module::Base<T>` with a different meaning.
@@ -422,6 +738,8 @@ use crate::util::ident;
 /// In this case, you can manually override the behavior with the `#[hint]` attribute. It takes multiple standalone keys:
 /// - `base` and `no_base`
 /// - `onready` and `no_onready`
+/// - `prop` and `no_prop`
+/// - `state_machine` and `no_state_machine`
 ///
 /// ```no_run
 /// use godot::classes::Node;
@@ -444,7 +762,10 @@ use crate::util::ident;
 /// #     fn init(base: godot::obj::Base<Self::Base>) -> Self { todo!() }
 /// # }
 /// ```
-#[proc_macro_derive(GodotClass, attributes(class, base, hint, var, export, init, signal))]
+#[proc_macro_derive(
+    GodotClass,
+    attributes(class, base, hint, var, export, init, signal, persist, inject)
+)]
 pub fn derive_godot_class(input: TokenStream) -> TokenStream {
     translate(input, class::derive_godot_class)
 }
@@ -563,6 +884,36 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ## Lifecycle functions without binding
+///
+/// Like user-defined functions, lifecycle functions implicitly bind the object for the duration of the call. This panics on re-entrant calls,
+/// i.e. when an engine method called inside `process()` synchronously calls back into the same object (e.g. through a signal).
+///
+/// To avoid this, annotate the method with `#[func(gd_self)]` and declare `this: Gd<Self>` instead of the `self` receiver. You can then
+/// bind the object only where needed. This is available for all virtual methods except `init`, `to_string`, `on_notification`,
+/// `register_class` and the property methods (`get_property` etc.).
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// pub struct MyNode {
+///     ticks: u64,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl INode for MyNode {
+///     #[func(gd_self)]
+///     fn process(mut this: Gd<Self>, _delta: f64) {
+///         this.bind_mut().ticks += 1;
+///
+///         // Not bound here: signal handlers may call back into this object.
+///         this.emit_signal("ticked".into(), &[]);
+///     }
+/// }
+/// ```
+///
 /// # User-defined functions
 ///
 /// You can use the `#[func]` attribute to declare your own functions. These are exposed to Godot and callable from GDScript.
@@ -608,6 +959,32 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ## Optional parameters
+///
+/// Trailing parameters can be marked with `#[opt(default = expr)]`. They are registered with a default value, so that callers from
+/// GDScript may omit them. The expression is evaluated when the class is registered, and again for each call omitting the argument;
+/// it must be convertible to the parameter type.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node2D)]
+/// struct Turret {
+///     base: Base<Node2D>,
+/// }
+///
+/// #[godot_api]
+/// impl Turret {
+///     // GDScript: turret.shoot(Vector2.UP) or turret.shoot(Vector2.UP, 2.5).
+///     #[func]
+///     fn shoot(&mut self, dir: Vector2, #[opt(default = 1.0)] speed: f32) {
+///         godot_print!("shooting in direction {dir} with speed {speed}");
+///     }
+/// }
+/// ```
+///
+/// Rust callers still need to provide all arguments. Once a parameter is `#[opt]`, all following parameters must be as well.
+///
 /// ## Virtual methods
 ///
 /// Functions with the `#[func(virtual)]` attribute are virtual functions, meaning attached scripts can override them.
@@ -643,14 +1020,218 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 ///
 /// Now, `obj.language()` from Rust will dynamically dispatch the call.
 ///
+/// To check whether a script overrides a method, use [`Gd::has_script_method()`](../obj/struct.Gd.html#method.has_script_method).
+/// Optional hooks without a Rust declaration can be invoked with
+/// [`Gd::call_script_virtual()`](../obj/struct.Gd.html#method.call_script_virtual), which takes the fallback as a closure.
+///
 /// Make sure you understand the limitations in the [tutorial](https://godot-rust.github.io/book/register/virtual-functions.html).
 ///
+/// ## Panic handling
+///
+/// By default, a panic inside a `#[func]` is printed to the Godot console, and the caller receives a default value. You can choose a different
+/// behavior globally with `godot::init::set_panic_policy()`, or per method with the `on_panic` key, which accepts any
+/// `godot::init::PanicPolicy` variant:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct MyStruct;
+///
+/// #[godot_api]
+/// impl MyStruct {
+///     // Panics show up as script errors in the editor debugger, with a Rust backtrace.
+///     #[func(on_panic = ScriptError)]
+///     fn validate(&self, value: i64) {
+///         assert!(value >= 0, "value must not be negative");
+///     }
+///
+///     // Invariant violations are not recoverable; terminate the process.
+///     #[func(on_panic = Abort)]
+///     fn critical(&self) {}
+/// }
+/// ```
+///
+/// ## Calling convention
+///
+/// Godot calls `#[func]` methods either through _ptrcall_, which passes arguments in their native representation, or through _varcall_,
+/// which boxes every argument in a `Variant`. The caller decides: dynamic calls such as `Object.call()`, signals or untyped GDScript
+/// use varcall. To make sure that a performance-sensitive method stays on the fast path, mark it with `expect_ptrcall`. In debug builds,
+/// the first varcall of such a method then prints an error. Statistics for all methods are available in `godot::register::call_stats`.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Physics;
+///
+/// #[godot_api]
+/// impl Physics {
+///     #[func(expect_ptrcall)]
+///     fn step(&mut self, delta: f64, position: Vector3) -> Vector3 {
+///         position + Vector3::DOWN * delta as f32
+///     }
+/// }
+/// ```
+///
 /// # Constants and signals
 ///
 /// Please refer to [the book](https://godot-rust.github.io/book/register/constants.html).
+///
+/// Godot only supports integer constants for GDExtension classes. A `#[constant]` of float or string type (`f32`, `f64`, `real`,
+/// `&str`, `String`) is instead exposed as a static function of the same name, returning the value; GDScript accesses it as
+/// `MyClass.NAME()`.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct MyStruct {}
+/// #[godot_api]
+/// impl MyStruct {
+///     #[constant]
+///     const MAX_HEALTH: i32 = 100; // MyStruct.MAX_HEALTH
+///
+///     #[constant]
+///     const GRAVITY: f64 = 9.81; // MyStruct.GRAVITY()
+///
+///     #[constant]
+///     const TAG: &'static str = "enemy"; // MyStruct.TAG()
+/// }
+/// ```
+///
+/// ## Enums
+///
+/// Rust enums annotated with [`#[godot_enum]`](attr.godot_enum.html) can be registered as class-scoped enums, by listing them in
+/// `#[godot_api(enums = [...])]`. GDScript then sees them as `MyClass.Direction`, with autocompletion for the enumerators.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[godot_enum]
+/// #[repr(i64)]
+/// #[derive(Copy, Clone)]
+/// enum Direction {
+///     Up,
+///     Down,
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Player {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api(enums = [Direction])]
+/// impl Player {
+///     #[constant]
+///     const MAX_HEALTH: i64 = 100;
+///
+///     #[func]
+///     fn speed_in(&self, direction: i64) -> f32 {
+///         if direction == Direction::Up as i64 { 1.0 } else { 2.0 }
+///     }
+/// }
+/// ```
+///
+/// # Multiple impl blocks
+///
+/// A class can have one primary `#[godot_api]` block and any number of `#[godot_api(secondary)]` blocks, e.g. to split a large class
+/// across modules or to combine handwritten and generated code. Methods, signals and constants of all blocks are registered together.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Inventory {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Inventory {
+///     #[signal]
+///     fn item_added(name: GString);
+/// }
+///
+/// // In another module:
+/// #[godot_api(secondary)]
+/// impl Inventory {
+///     #[func]
+///     fn add_item(&mut self, name: GString) {
+///         self.base_mut().emit_signal("item_added".into(), &[name.to_variant()]);
+///     }
+/// }
+/// ```
+///
+/// Declaring the same method or signal in two blocks is a compile error -- also when the names only collide in Godot, through
+/// `#[func(rename)]`. `enums = [...]` is only accepted on the primary block.
 #[proc_macro_attribute]
-pub fn godot_api(_meta: TokenStream, input: TokenStream) -> TokenStream {
-    translate(input, class::attribute_godot_api)
+pub fn godot_api(meta: TokenStream, input: TokenStream) -> TokenStream {
+    translate_meta("godot_api", meta, input, class::attribute_godot_api)
+}
+
+/// Makes a Rust trait implemented by a user class available through [`DynGd`](../obj/struct.DynGd.html).
+///
+/// Apply this to an `impl Trait for Class` block, where `Class` is a `#[derive(GodotClass)]` type. This implements `AsDyn<dyn Trait>`
+/// (enabling [`Gd::into_dyn()`](../obj/struct.Gd.html#method.into_dyn)) and registers the class, so that objects whose concrete class
+/// is only known at runtime can be converted to `DynGd<_, dyn Trait>`.
+///
+/// Exported `DynGd` fields use this registration to restrict the inspector's picker to classes implementing the trait.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// trait Health {
+///     fn hit_points(&self) -> i32;
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Monster {
+///     hp: i32,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_dyn]
+/// impl Health for Monster {
+///     fn hit_points(&self) -> i32 {
+///         self.hp
+///     }
+/// }
+///
+/// fn total_hp(entities: &[DynGd<Node, dyn Health>]) -> i32 {
+///     entities.iter().map(|e| e.dyn_bind().hit_points()).sum()
+/// }
+/// ```
+///
+/// The trait must be object-safe and `'static`. Generic classes and generic impls are not supported.
+#[proc_macro_attribute]
+pub fn godot_dyn(meta: TokenStream, input: TokenStream) -> TokenStream {
+    translate_meta("godot_dyn", meta, input, class::attribute_godot_dyn)
+}
+
+/// Marks a C-style enum as exportable to Godot, as a class-scoped enum or bitfield.
+///
+/// The enum can then be registered on a class with [`#[godot_api(enums = [...])]`](attr.godot_api.html#enums). Enumerators keep their
+/// Rust names and discriminants (cast to `i64`); the enum should thus be `#[repr(i64)]` or have discriminants that fit into `i64`.
+///
+/// The following keys are supported:
+/// - `rename = Name`: register the enum under a different name.
+/// - `bitfield`: register as bitfield, whose enumerators can be combined with `|` in GDScript.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[godot_enum(rename = Permissions, bitfield)]
+/// #[repr(i64)]
+/// enum PermissionFlags {
+///     Read = 1,
+///     Write = 2,
+///     Execute = 4,
+/// }
+/// ```
+///
+/// To pass such enums through `#[func]` parameters or `#[var]` fields, additionally derive [`GodotConvert`](derive.GodotConvert.html).
+#[proc_macro_attribute]
+pub fn godot_enum(meta: TokenStream, input: TokenStream) -> TokenStream {
+    translate_meta("godot_enum", meta, input, class::attribute_godot_enum)
 }
 
 /// Derive macro for [`GodotConvert`](../builtin/meta/trait.GodotConvert.html) on structs.
@@ -784,6 +1365,49 @@ pub fn derive_godot_convert(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_godot_convert)
 }
 
+/// Derive macro for [`GodotFlags`](../register/flags/trait.GodotFlags.html) on enums.
+///
+/// The enum must be fieldless, and each variant needs an explicit non-zero discriminant holding its bits, typically `#[repr(u32)]` with
+/// values `1 << n`. The enum must also derive `Clone` and `Copy`. Besides the trait, `|` is implemented on the enum, yielding a
+/// [`FlagSet`](../register/flags/struct.FlagSet.html).
+#[proc_macro_derive(GodotFlags)]
+pub fn derive_godot_flags(input: TokenStream) -> TokenStream {
+    translate(input, derive::derive_godot_flags)
+}
+
+/// Derive macro for [`StateEnum`](../obj/trait.StateEnum.html) on enums.
+///
+/// Variants may be unit, tuple or struct variants. Only unit variants can be constructed by name, e.g. when the `state` property of a
+/// [`StateMachine`](../obj/struct.StateMachine.html) is assigned from GDScript.
+#[proc_macro_derive(StateEnum)]
+pub fn derive_state_enum(input: TokenStream) -> TokenStream {
+    translate(input, derive::derive_state_enum)
+}
+
+/// Derive macro for [`TableRow`](../tools/table/trait.TableRow.html) on structs.
+///
+/// Each field is read from the column with the same name, and parsed through [`FromCell`](../tools/table/trait.FromCell.html).
+/// Fields can carry a `#[table(...)]` attribute with the following keys:
+/// - `column = "Name"`: read from a column with a different name.
+/// - `default`: use `Default::default()` if the cell is empty or the column is missing.
+///
+/// ```no_run
+/// use godot::register::TableRow;
+///
+/// #[derive(TableRow)]
+/// struct Weapon {
+///     name: String,
+///     #[table(column = "DPS")]
+///     damage_per_second: f32,
+///     #[table(default)]
+///     two_handed: bool,
+/// }
+/// ```
+#[proc_macro_derive(TableRow, attributes(table))]
+pub fn derive_table_row(input: TokenStream) -> TokenStream {
+    translate(input, derive::derive_table_row)
+}
+
 /// Derive macro for [`Var`](../register/property/trait.Var.html) on enums.
 ///
 /// This expects a derived [`GodotConvert`](../builtin/meta/trait.GodotConvert.html) implementation, using a manual
@@ -801,6 +1425,15 @@ pub fn derive_export(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_export)
 }
 
+/// Derive macro for [`ExportGroup`](../register/property/trait.ExportGroup.html) on structs.
+///
+/// All fields are exported as properties of the group. Each field can optionally carry an `#[export(...)]` attribute with the same keys
+/// as class fields, to customize the property hint. The struct can then be used in a class field annotated with `#[export(flatten)]`.
+#[proc_macro_derive(ExportGroup, attributes(export))]
+pub fn derive_export_group(input: TokenStream) -> TokenStream {
+    translate(input, derive::derive_export_group)
+}
+
 /// Similar to `#[test]`, but runs an integration test with Godot.
 ///
 /// Transforms the `fn` into one returning `bool` (success of the test), which must be called explicitly.
@@ -109,6 +109,25 @@ impl KvParser {
         }
     }
 
+    /// Handles an optional key whose value is either an identifier or a string literal, e.g. `key = Name` or `key = "Name"`.
+    ///
+    /// Returns the key (for error spans) and the value as a string. Escape sequences in string literals are not supported.
+    pub fn handle_ident_or_string(&mut self, key: &str) -> ParseResult<Option<(Ident, String)>> {
+        match self.map.remove_entry(&ident(key)) {
+            None => Ok(None),
+            Some((key, value)) => match value {
+                None => bail!(
+                    key,
+                    "expected `{key}` to be followed by `= identifier` or `= \"string\"`"
+                ),
+                Some(value) => {
+                    let string = value.ident_or_string()?;
+                    Ok(Some((key, string)))
+                }
+            },
+        }
+    }
+
     /// Handles an array of the form `[elem1, elem2, ...]`.
     pub fn handle_array(&mut self, key: &str) -> ParseResult<Option<ListParser>> {
         ListParser::new_from_kv(self, key, Delimiter::Bracket)
@@ -258,6 +277,20 @@ impl KvValue {
         }
     }
 
+    pub fn ident_or_string(self) -> ParseResult<String> {
+        match self.single()? {
+            TokenTree::Ident(ident) => Ok(ident.to_string()),
+            TokenTree::Literal(lit) => {
+                let repr = lit.to_string();
+                match repr.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+                    Some(content) if !content.contains('\\') => Ok(content.to_string()),
+                    _ => bail!(lit, "expected identifier or plain string literal"),
+                }
+            }
+            tt => bail!(tt, "expected identifier or string literal"),
+        }
+    }
+
     pub fn as_key_value(&self) -> ParseResult<(Ident, Self)> {
         if self.tokens.len() < 3 {
             return bail!(&self.tokens[0], "expected `key = expression`");
@@ -15,7 +15,7 @@ use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 mod kv_parser;
 mod list_parser;
 
-pub(crate) use kv_parser::KvParser;
+pub(crate) use kv_parser::{KvParser, KvValue};
 pub(crate) use list_parser::ListParser;
 
 pub fn ident(s: &str) -> Ident {
@@ -251,6 +251,24 @@ pub(crate) fn path_ends_with_complex(path: &venial::TypeExpr, expected: &str) ->
         .unwrap_or(false)
 }
 
+/// If the type is `Name<Arg>` (possibly with a path prefix), returns the tokens of `Arg`.
+pub(crate) fn extract_generic_arg(ty: &venial::TypeExpr, expected: &str) -> Option<TokenStream> {
+    let segment = extract_typename(ty).filter(|seg| seg.ident == expected)?;
+    let generic_args = segment.generic_args?;
+
+    // Strip optional turbofish `::` as well as the surrounding `<` and `>`.
+    let tokens = generic_args
+        .to_token_stream()
+        .into_iter()
+        .collect::<Vec<_>>();
+    let start = tokens
+        .iter()
+        .position(|tt| matches!(tt, TokenTree::Punct(punct) if punct.as_char() == '<'))?;
+    let inner = tokens.get(start + 1..tokens.len().checked_sub(1)?)?;
+
+    Some(inner.iter().cloned().collect())
+}
+
 pub(crate) fn extract_cfg_attrs(
     attrs: &[venial::Attribute],
 ) -> impl IntoIterator<Item = &venial::Attribute> {
@@ -33,6 +33,7 @@
 //! * [`register`], used to register **your own** Rust symbols (classes, methods, constants etc.) with Godot.
 //! * [`obj`], everything related to handling Godot objects, such as the `Gd<T>` type.
 //! * [`tools`], higher-level utilities that extend the generated code, e.g. `load<T>()`.
+//! * [`task`], scheduling of closures relative to the frame loop, e.g. `defer()`, or on worker threads.
 //!
 //! The [`prelude`] contains often-imported symbols; feel free to `use godot::prelude::*` in your code.
 //! <br><br>
@@ -95,12 +96,51 @@
 //!   Experimental threading support. This enables `Send`/`Sync` traits for `Gd<T>` and makes the guard types `Gd`/`GdMut` aware of
 //!   multithreaded references. The safety aspects are not ironed out yet; there is a high risk of unsoundness at the moment.
 //!   As this evolves, it is very likely that the API becomes stricter.<br><br>
+//!   Also enables `task::spawn_blocking()` and `task::parallel_for()`, which run closures on Godot's `WorkerThreadPool`, as well as
+//!   `ResourceLoader::load_async()`, which loads resources on a background thread.<br><br>
 //!
 //! * **`experimental-wasm`**
 //!
 //!   Support for WebAssembly exports is still a work-in-progress and is not yet well tested. This feature is in place for users
 //!   to explicitly opt in to any instabilities or rough edges that may result. Due to a limitation in Godot, it might currently not
-//!   work Firefox browser.<br><br>
+//!   work Firefox browser. For web exports without thread support, build without the `atomics` target feature; `experimental-threads`
+//!   is then unavailable.<br><br>
+//!
+//! * **`conversion-paths`**
+//!
+//!   Annotate conversion errors inside nested arrays and dictionaries with the location of the failing value, such as
+//!   `at [3].config.max_hp: expected type INT, got STRING`. See [`ConvertError`][meta::error::ConvertError] for details.<br><br>
+//!
+//! * **`conversion-audit`**
+//!
+//!   Detect lossy numeric conversions at the Rust/Godot boundary at runtime: `f64` values that lose range or precision as `f32`,
+//!   `u64` values wrapping into negative `i64`, and NaN or infinite floats passed to engine calls or returned from `#[func]`s. Reported
+//!   as warnings or panics, see [`meta::audit`]. Checks every argument and return value, so only enable it for debugging.<br><br>
+//!
+//! * **`fast-math`**
+//!
+//!   Compute batch operations in Rust using glam's SIMD types (SSE2, NEON or WASM SIMD, where available).
+//!   [`Transform3D::xform_points()`][builtin::Transform3D::xform_points] and [`Basis::xform_vectors()`][builtin::Basis::xform_vectors]
+//!   transform four vectors per instruction. [`Basis::mul_all()`][builtin::Basis::mul_all] and
+//!   [`Quaternion::slerp_all()`][builtin::Quaternion::slerp_all] still process one element at a time, but avoid calling into Godot for each.
+//!   Also lets glam use faster floating-point operations. Results may differ slightly between platforms. Only affects single precision;
+//!   with `double-precision`, batch operations use scalar code.<br><br>
+//!
+//! * **`alloc-stats`**
+//!
+//!   Count constructions and destructions of builtins that own engine memory, such as `GString`, `Array` or `Dictionary`. Statistics
+//!   are available in the [`diagnostics`] module. Adds a small overhead to every such construction, so only enable it for profiling.<br><br>
+//!
+//! * **`register-docs`**
+//!
+//!   Collect the `///` doc comments of Rust classes and their registered members, and make them available as a structured model in the
+//!   [`docs`] module, e.g. to generate a documentation website. The docs are only available on the Rust side; they are not
+//!   registered with Godot, so the editor's built-in help does not show them.<br><br>
+//!
+//! * **`register-profiling`**
+//!
+//!   Measure the time spent loading the library, in each initialization level and for registering each class (and its docs).
+//!   Results can be queried through [`init::profiling`], to find classes that slow down editor and game startup.<br><br>
 //!
 //! * **`codegen-rustfmt`**
 //!
@@ -114,6 +154,30 @@
 //!   Implement the [serde](https://serde.rs/) traits `Serialize` and `Deserialize` traits for certain built-in types.
 //!   The serialized representation underlies **no stability guarantees** and may change at any time, even without a SemVer-breaking change.
 //!
+//! * **`ndarray`**
+//!
+//!   Conversions between numeric packed arrays (e.g. `PackedFloat32Array`) and [ndarray](https://docs.rs/ndarray) arrays.
+//!   Views are created without copying; arbitrary shapes can be used, e.g. for multi-channel image or audio data.
+//!
+//! * **`rayon`**
+//!
+//!   Parallel iteration over `Array<T>` and packed arrays with [rayon](https://docs.rs/rayon), via `par_iter()` and `collect()`.
+//!   Packed arrays are iterated in place; typed arrays are first converted into a contiguous buffer on the calling thread.
+//!
+//! * **`image`**
+//!
+//!   Conversions between Godot's `Image` class and the [image](https://docs.rs/image) crate's `DynamicImage` and `ImageBuffer` types.
+//!
+//! * **`log`**, **`tracing`**
+//!
+//!   Backends for the [log](https://docs.rs/log) and [tracing](https://docs.rs/tracing) crates, which route records to Godot's output panel
+//!   and debugger, see `godot::tools::logging`. Useful for third-party libraries that log through these crates.
+//!
+//! * **`config`**
+//!
+//!   Loading of JSON, JSON5 and TOML configuration files into `Dictionary` or `serde` types, and conversions between variants and
+//!   `serde_json::Value`, see `godot::tools::config`. Implies `serde`.
+//!
 
 #[cfg(doc)]
 pub mod __docs;
@@ -156,7 +220,16 @@ const _: () = _validate_features();
 // Modules
 
 #[doc(inline)]
-pub use godot_core::{builtin, classes, global, meta, obj, tools};
+pub use godot_core::{builtin, classes, global, meta, obj, sys_ext, task, tools};
+
+/// Math functions and types, including deterministic [fixed-point arithmetic][math::fixed]. Same as [`builtin::math`].
+pub use godot_core::builtin::math;
+
+#[cfg(feature = "alloc-stats")]
+pub use godot_core::diagnostics;
+
+#[cfg(feature = "register-docs")]
+pub use godot_core::docs;
 
 #[allow(deprecated)]
 pub use godot_core::{engine, log};
@@ -174,8 +247,16 @@ pub mod init {
 
 /// Register/export Rust symbols to Godot: classes, methods, enums...
 pub mod register {
+    pub use godot_core::registry::call_stats;
+    pub use godot_core::registry::extensions;
+    pub use godot_core::registry::flags;
     pub use godot_core::registry::property;
-    pub use godot_macros::{godot_api, Export, GodotClass, GodotConvert, Var};
+    pub use godot_core::registry::reflection;
+    pub use godot_core::registry::replication;
+    pub use godot_macros::{
+        godot_api, godot_dyn, godot_enum, Export, ExportGroup, GodotClass, GodotConvert,
+        GodotFlags, StateEnum, TableRow, Var,
+    };
 
     /// Re-exports used by proc-macro API.
     #[doc(hidden)]
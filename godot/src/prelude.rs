@@ -5,10 +5,14 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-pub use super::register::property::{Export, TypeStringHint, Var};
+pub use super::register::flags::{FlagSet, GodotFlags};
+pub use super::register::property::{Export, ExportGroup, TypeStringHint, Var};
 
 // Re-export macros.
-pub use super::register::{godot_api, Export, GodotClass, GodotConvert, Var};
+pub use super::register::{
+    godot_api, godot_dyn, godot_enum, Export, ExportGroup, GodotClass, GodotConvert, GodotFlags,
+    StateEnum, TableRow, Var,
+};
 
 pub use super::builtin::__prelude_reexport::*;
 pub use super::builtin::math::FloatExt as _;
@@ -26,7 +30,10 @@ pub use super::global::{
 pub use super::tools::{load, save, try_load, try_save, GFile};
 
 pub use super::init::{gdextension, ExtensionLibrary, InitLevel};
-pub use super::obj::{Base, Gd, GdMut, GdRef, GodotClass, Inherits, InstanceId, OnReady};
+pub use super::obj::{
+    Base, DynGd, Gd, GdMut, GdRef, GodotClass, Inherits, InstanceId, OnReady, StateClass,
+    StateEnum, StateMachine,
+};
 
 // Make trait methods available.
 pub use super::obj::EngineBitfield as _;
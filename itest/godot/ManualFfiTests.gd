@@ -327,6 +327,29 @@ func test_gd_self_obj_succeeds():
 
 	assert_eq(gd_self_obj.succeed_at_updating_internal_value(10), 10)
 
+func test_call_stats_ptrcall_and_varcall():
+	var before: PackedInt64Array = CallStatsObj.ping_call_counts()
+
+	var typed: CallStatsObj = CallStatsObj.new()
+	assert_eq(typed.ping(1), 1)
+
+	var after_ptrcall: PackedInt64Array = CallStatsObj.ping_call_counts()
+
+	var untyped = CallStatsObj.new()
+	assert_eq(untyped.ping(2), 2)
+
+	var after_varcall: PackedInt64Array = CallStatsObj.ping_call_counts()
+
+	# Counters are not registered in release builds.
+	if before.is_empty():
+		assert_that(after_varcall.is_empty(), "no call stats in release builds")
+		return
+
+	assert_eq(after_ptrcall[0], before[0] + 1, "typed call counted as ptrcall")
+	assert_eq(after_ptrcall[1], before[1], "typed call not counted as varcall")
+	assert_eq(after_varcall[0], after_ptrcall[0], "untyped call not counted as ptrcall")
+	assert_eq(after_varcall[1], after_ptrcall[1] + 1, "untyped call counted as varcall")
+
 func sample_func():
 	pass
 
@@ -7,7 +7,7 @@
 
 use crate::framework::itest;
 use godot::builtin::math::assert_eq_approx;
-use godot::builtin::{Color, ColorChannelOrder, ColorHsv};
+use godot::builtin::{palette, Color, ColorChannelOrder, ColorHsv, ColorOklab, ColorOklch};
 
 #[itest]
 fn color_from_rgba8() {
@@ -198,3 +198,127 @@ fn color_hsv_multi_roundtrip() {
         assert_eq_approx!(original, c_back);
     }
 }
+
+#[itest]
+fn color_oklab_reference_values() {
+    let white = Color::WHITE.to_oklab();
+    assert_eq_approx!(white.l, 1.0, fn = approx_loose);
+    assert_eq_approx!(white.a, 0.0, fn = approx_loose);
+    assert_eq_approx!(white.b, 0.0, fn = approx_loose);
+
+    let black = Color::BLACK.to_oklab();
+    assert_eq_approx!(black.l, 0.0, fn = approx_loose);
+
+    // Reference from https://bottosson.github.io/posts/oklab (linear sRGB red, which is also sRGB red).
+    let red = Color::from_rgb(1.0, 0.0, 0.0).to_oklab();
+    assert_eq_approx!(red.l, 0.6279554, fn = approx_loose);
+    assert_eq_approx!(red.a, 0.22486307, fn = approx_loose);
+    assert_eq_approx!(red.b, 0.12584630, fn = approx_loose);
+}
+
+#[itest]
+fn color_oklab_roundtrip() {
+    let colors = [
+        Color::from_rgba(0.2, 0.6, 0.9, 0.5),
+        Color::from_rgb(0.74, 0.69, 0.18),
+        Color::from_rgb(0.0, 1.0, 0.0),
+        Color::from_rgb(0.5, 0.5, 0.5),
+    ];
+
+    for color in colors {
+        let lab = color.to_oklab();
+        assert_eq_approx!(lab.to_rgb(), color, fn = color_approx_loose);
+
+        let lch = color.to_oklch();
+        assert_eq_approx!(lch.to_rgb(), color, fn = color_approx_loose);
+        assert_eq_approx!(lch.to_oklab(), lab, fn = oklab_approx_loose);
+    }
+
+    let gray = Color::from_rgb(0.5, 0.5, 0.5).to_oklch();
+    assert_eq_approx!(gray.c, 0.0, fn = approx_loose);
+}
+
+#[itest]
+fn color_oklab_lerp() {
+    let from = Color::from_rgb(1.0, 0.0, 0.0);
+    let to = Color::from_rgb(0.0, 0.0, 1.0);
+
+    assert_eq_approx!(from.lerp_oklab(to, 0.0), from, fn = color_approx_loose);
+    assert_eq_approx!(from.lerp_oklab(to, 1.0), to, fn = color_approx_loose);
+
+    // Perceptual midpoint is brighter than the sRGB one, which is a dark purple.
+    let mid = from.lerp_oklab(to, 0.5).to_oklab().l;
+    let srgb_mid = from.lerp(to, 0.5).to_oklab().l;
+    assert!(mid > srgb_mid, "{mid} > {srgb_mid}");
+
+    let gradient = from.gradient_oklab(to, 5);
+    assert_eq!(gradient.len(), 5);
+    assert_eq_approx!(gradient[0], from, fn = color_approx_loose);
+    assert_eq_approx!(gradient[4], to, fn = color_approx_loose);
+    assert!(from.gradient_oklab(to, 0).is_empty());
+    assert_eq!(from.gradient_oklab(to, 1), vec![from]);
+}
+
+#[itest]
+fn color_oklch_lerp_shortest_hue() {
+    let a = ColorOklch::from_lch(0.7, 0.1, 0.9);
+    let b = ColorOklch::from_lch(0.7, 0.1, 0.1);
+
+    // Shorter path crosses 0.0 instead of going through 0.5.
+    let mid = a.lerp(b, 0.5);
+    assert_eq_approx!(mid.h, 0.0, fn = |a: &f32, b: &f32| (a - b).abs() < 1e-4 || (a - b).abs() > 1.0 - 1e-4);
+
+    let lab = ColorOklab::from_lab(0.5, 0.1, -0.1);
+    assert_eq_approx!(lab.distance_to(lab), 0.0, fn = approx_loose);
+}
+
+#[itest]
+fn color_contrast_ratio() {
+    assert_eq_approx!(Color::BLACK.contrast_ratio(Color::WHITE), 21.0, fn = approx_loose);
+    assert_eq_approx!(Color::WHITE.contrast_ratio(Color::BLACK), 21.0, fn = approx_loose);
+    assert_eq_approx!(Color::RED.contrast_ratio(Color::RED), 1.0, fn = approx_loose);
+
+    // Known value: #777777 on white is just below the AA threshold of 4.5.
+    let gray = Color::from_html("#777777").unwrap();
+    let ratio = gray.contrast_ratio(Color::WHITE);
+    assert!(ratio > 4.4 && ratio < 4.5, "ratio {ratio}");
+}
+
+palette! {
+    struct TestPalette {
+        ACCENT = "#3366ff",
+        SHORT = "f80",
+        TRANSLUCENT = "#11223344",
+    }
+}
+
+#[itest]
+fn color_palette_macro() {
+    assert_eq!(TestPalette::ACCENT, Color::from_html("#3366ff").unwrap());
+    assert_eq!(TestPalette::SHORT, Color::from_html("#ff8800").unwrap());
+    assert_eq!(
+        TestPalette::TRANSLUCENT,
+        Color::from_html("#11223344").unwrap()
+    );
+
+    let names: Vec<&str> = TestPalette::ENTRIES.iter().map(|(name, _)| *name).collect();
+    assert_eq!(names, ["ACCENT", "SHORT", "TRANSLUCENT"]);
+}
+
+fn approx_loose(a: &f32, b: &f32) -> bool {
+    (a - b).abs() < 1e-3
+}
+
+fn color_approx_loose(a: &Color, b: &Color) -> bool {
+    approx_loose(&a.r, &b.r)
+        && approx_loose(&a.g, &b.g)
+        && approx_loose(&a.b, &b.b)
+        && approx_loose(&a.a, &b.a)
+}
+
+fn oklab_approx_loose(a: &ColorOklab, b: &ColorOklab) -> bool {
+    approx_loose(&a.l, &b.l)
+        && approx_loose(&a.a, &b.a)
+        && approx_loose(&a.b, &b.b)
+        && approx_loose(&a.alpha, &b.alpha)
+}
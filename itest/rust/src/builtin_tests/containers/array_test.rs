@@ -75,6 +75,29 @@ fn array_from_slice() {
     assert_eq!(array.at(1), 2);
 }
 
+#[itest]
+fn array_string_conversions() {
+    let expected: Array<GString> = array!["a".into(), "bc".into(), "".into()];
+
+    let array = Array::<GString>::from_iter(["a", "bc", ""]);
+    assert_eq!(array, expected);
+
+    let array = Array::<GString>::from(vec!["a".to_string(), "bc".to_string(), String::new()]);
+    assert_eq!(array, expected);
+
+    let array = Array::<GString>::from(&["a", "bc", ""][..]);
+    assert_eq!(array, expected);
+    assert_eq!(array.to_vec_string(), vec!["a", "bc", ""]);
+
+    // Iterator without exact length.
+    let array: Array<GString> = "a bc x".split(' ').filter(|s| *s != "x").collect();
+    assert_eq!(array, array!["a".into(), "bc".into()]);
+
+    let mut array = array;
+    array.extend(["d".to_string()]);
+    assert_eq!(Vec::<String>::from(&array), vec!["a", "bc", "d"]);
+}
+
 #[itest]
 fn array_try_into_vec() {
     let array = array![1, 2];
@@ -84,6 +107,28 @@ fn array_try_into_vec() {
     assert_eq!(result, Ok(vec![1, 2]));
 }
 
+#[itest]
+fn array_fixed_size_conversions() {
+    let array = array![1, 2, 3];
+
+    let fixed = <[i64; 3]>::try_from(&array).expect("length matches");
+    assert_eq!(fixed, [1, 2, 3]);
+
+    let err = <[i64; 2]>::try_from(&array).expect_err("length mismatch");
+    assert!(
+        err.to_string().contains("expected array of length 2"),
+        "{err}"
+    );
+
+    // Variant round-trip goes through a typed Array.
+    let matrix: [f32; 4] = [1.0, 0.0, 0.0, 1.0];
+    let variant = matrix.to_variant();
+    assert_eq!(variant.get_type(), VariantType::ARRAY);
+    assert_eq!(variant.to::<Array<f32>>().len(), 4);
+    assert_eq!(variant.to::<[f32; 4]>(), matrix);
+    assert!(variant.try_to::<[f32; 3]>().is_err());
+}
+
 #[itest]
 fn array_iter_shared() {
     let array = array![1, 2];
@@ -544,6 +589,64 @@ fn array_resize() {
     assert_eq!(a, array![GString::from("hello"), GString::from("bar"),]);
 }
 
+#[itest]
+fn array_deep_eq() {
+    let inner: Array<i64> = array![1, 2];
+    let a = varray![1, inner.clone(), dict! { "nan": f64::NAN }];
+    let b = a.deep_clone();
+
+    assert!(a.deep_eq(&b));
+    assert_ne!(a, b, "NaN is not equal to itself with ==");
+
+    let c = varray![1, array![1, 3], dict! { "nan": f64::NAN }];
+    assert!(!a.deep_eq(&c));
+    assert!(!a.deep_eq(&varray![1, inner]));
+}
+
+#[itest]
+fn array_deep_clone() {
+    let inner = varray![1, 2];
+    let a = varray![inner.clone()];
+
+    let mut clone = a.deep_clone();
+    clone.set(0, &varray![3].to_variant());
+
+    assert_eq!(inner, varray![1, 2]);
+    assert!(a.deep_eq(&varray![varray![1, 2]]));
+}
+
+#[itest]
+fn array_diff() {
+    use godot::builtin::diff::{Change, PathSegment};
+
+    let typed: Array<i64> = array![1, 2];
+    let old = varray![1, typed, dict! { "hp": 10, "name": "orc" }, "removed"];
+    let new = varray![1, varray![1, 5], dict! { "hp": 7, "name": "orc" }];
+
+    let changes = old.diff(&new).into_vec();
+    let paths: Vec<String> = changes.iter().map(|c| c.path().to_string()).collect();
+    assert_eq!(paths, ["[1][1]", "[2].hp", "[3]"]);
+
+    assert!(matches!(
+        &changes[0],
+        Change::Modified { old, new, .. } if *old == 2.to_variant() && *new == 5.to_variant()
+    ));
+    assert!(matches!(
+        &changes[2],
+        Change::Removed { value, .. } if *value == "removed".to_variant()
+    ));
+    assert_eq!(
+        changes[1].path().segments(),
+        [PathSegment::Index(2), PathSegment::Key("hp".to_variant())]
+    );
+
+    let added = new.diff(&old);
+    assert_eq!(added.len(), 3);
+    assert!(matches!(added.as_slice()[2], Change::Added { .. }));
+
+    assert!(old.diff(&old.deep_clone()).is_empty());
+}
+
 #[derive(GodotClass, Debug)]
 #[class(init, base=RefCounted)]
 struct ArrayTest;
@@ -6,8 +6,9 @@
  */
 
 use godot::builtin::inner::InnerCallable;
-use godot::builtin::{varray, Callable, GString, StringName, Variant};
+use godot::builtin::{varray, Callable, GString, StringName, Variant, VariantType};
 use godot::classes::{Node2D, Object};
+use godot::meta::error::CallableError;
 use godot::meta::ToGodot;
 use godot::obj::{NewAlloc, NewGd};
 use godot::register::{godot_api, GodotClass};
@@ -143,6 +144,81 @@ fn callable_bindv() {
     );
 }
 
+#[itest]
+fn callable_bind_typed() {
+    let obj = CallableTestObj::new_gd();
+
+    let bound = obj
+        .callable("bar")
+        .bind_typed((10,))
+        .expect("matching signature");
+    assert_eq!(
+        bound.callv(varray![]),
+        10.to_variant().stringify().to_variant()
+    );
+
+    let err = obj.callable("bar").bind_typed(("ten",)).unwrap_err();
+    assert_eq!(
+        err,
+        CallableError::ArgumentType {
+            method: "bar".to_string(),
+            index: 0,
+            expected: VariantType::INT,
+            actual: VariantType::STRING,
+        }
+    );
+
+    let err = obj.callable("bar").bind_typed((1, 2)).unwrap_err();
+    assert!(matches!(
+        err,
+        CallableError::ArgumentCount { actual: 2, .. }
+    ));
+
+    let err = obj.callable("baz").bind_typed((1,)).unwrap_err();
+    assert!(matches!(err, CallableError::MethodNotFound { .. }));
+}
+
+#[itest]
+fn callable_unbind() {
+    let obj = CallableTestObj::new_gd();
+    let callable = obj.callable("foo").unbind(1);
+
+    // Last argument is dropped.
+    callable.callv(varray![5, "ignored"]);
+    assert_eq!(obj.bind().value, 5);
+}
+
+#[itest]
+fn callable_from_object_method_checked() {
+    let obj = CallableTestObj::new_gd();
+
+    let callable = Callable::from_object_method_checked(&obj, "foo", 1).expect("method exists");
+    assert_eq!(callable, obj.callable("foo"));
+
+    let err = Callable::from_object_method_checked(&obj, "fooo", 1).unwrap_err();
+    assert_eq!(
+        err,
+        CallableError::MethodNotFound {
+            class: "CallableTestObj".to_string(),
+            method: "fooo".to_string(),
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "method `fooo` not found on class `CallableTestObj`"
+    );
+
+    let err = Callable::from_object_method_checked(&obj, "foo", 2).unwrap_err();
+    assert_eq!(
+        err,
+        CallableError::ArgumentCount {
+            method: "foo".to_string(),
+            expected: (1, 1),
+            actual: 2,
+        }
+    );
+}
+
 // Testing https://github.com/godot-rust/gdext/issues/410
 
 #[derive(GodotClass)]
@@ -197,6 +273,29 @@ mod custom_callable {
         assert_ne!(a, c, "same function, different instance -> not equal");
     }
 
+    #[itest]
+    fn callable_from_local_fn() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let count = Rc::new(Cell::new(0));
+        let count_in_fn = count.clone();
+        let callable = Callable::from_local_fn("increment", move |args: &[&Variant]| {
+            count_in_fn.set(count_in_fn.get() + args.len());
+            Ok(count_in_fn.get().to_variant())
+        });
+
+        assert!(callable.is_custom());
+        assert_eq!(callable.to_string(), "increment");
+
+        assert_eq!(callable.callv(varray![1, 2]), 2.to_variant());
+        assert_eq!(callable.callv(varray![3]), 3.to_variant());
+        assert_eq!(count.get(), 3);
+
+        drop(callable);
+        assert_eq!(Rc::strong_count(&count), 1, "closure dropped with callable");
+    }
+
     fn sum(args: &[&Variant]) -> Result<Variant, ()> {
         let sum: i32 = args.iter().map(|arg| arg.to::<i32>()).sum();
         Ok(sum.to_variant())
@@ -7,7 +7,7 @@
 
 use std::collections::{HashMap, HashSet};
 
-use godot::builtin::{dict, varray, Dictionary, Variant};
+use godot::builtin::{dict, varray, DictEntry, Dictionary, Variant, VariantArray};
 use godot::meta::{FromGodot, ToGodot};
 use godot::sys::GdextBuild;
 
@@ -88,6 +88,36 @@ fn dictionary_macro() {
     assert_eq!(dict_complex.get(3), Some(Variant::nil()));
 }
 
+#[itest]
+fn dictionary_macro_spread() {
+    let defaults = dict! {
+        "volume": 0.5,
+        "fullscreen": false,
+    };
+
+    let config = dict! {
+        "name": "config",
+        ..defaults,
+        "fullscreen": true,
+        "audio": dict! { "music": true },
+    };
+
+    assert_eq!(config.len(), 4);
+    assert_eq!(config.get("name"), Some("config".to_variant()));
+    assert_eq!(config.get("volume"), Some(0.5.to_variant()));
+    assert_eq!(config.get("fullscreen"), Some(true.to_variant()));
+    assert_eq!(
+        config.get("audio"),
+        Some(dict! { "music": true }.to_variant())
+    );
+
+    // Spread source is not consumed or modified.
+    assert_eq!(defaults.get("fullscreen"), Some(false.to_variant()));
+
+    let only_spread = dict! { ..defaults };
+    assert_eq!(only_spread, defaults);
+}
+
 #[itest]
 fn dictionary_clone() {
     let subdictionary = dict! {
@@ -301,6 +331,66 @@ fn dictionary_extend() {
     assert_eq!(dictionary.get("bar"), Some("new".to_variant()));
 }
 
+#[itest]
+fn dictionary_entry() {
+    let mut dictionary = Dictionary::new();
+
+    for word in ["apple", "pear", "apple"] {
+        dictionary
+            .entry(word)
+            .and_modify(|count| *count = (count.to::<i64>() + 1).to_variant())
+            .or_insert(1);
+    }
+    assert_eq!(dictionary.get("apple"), Some(2.to_variant()));
+    assert_eq!(dictionary.get("pear"), Some(1.to_variant()));
+
+    // `or_insert_with` is only invoked for absent keys.
+    let value = dictionary
+        .entry("apple")
+        .or_insert_with(|| -> i64 { panic!("key is present") });
+    assert_eq!(value, 2.to_variant());
+
+    // Nested containers are modified in place.
+    let mut nested: VariantArray = dictionary
+        .entry("list")
+        .or_insert_with(VariantArray::new)
+        .to();
+    nested.push(1.to_variant());
+    let mut nested: VariantArray = dictionary
+        .entry("list")
+        .or_insert_with(VariantArray::new)
+        .to();
+    nested.push(2.to_variant());
+    assert_eq!(dictionary.get("list"), Some(varray![1, 2].to_variant()));
+
+    assert_eq!(dictionary.entry("nil").or_nil(), Variant::nil());
+    assert!(dictionary.contains_key("nil"));
+}
+
+#[itest]
+fn dictionary_entry_occupied_vacant() {
+    let mut dictionary = dict! { "foo": 0 };
+
+    match dictionary.entry("foo") {
+        DictEntry::Occupied(mut entry) => {
+            assert_eq!(entry.key(), &"foo".to_variant());
+            assert_eq!(entry.get(), 0.to_variant());
+            assert_eq!(entry.insert(1), 0.to_variant());
+            assert_eq!(entry.remove(), 1.to_variant());
+        }
+        DictEntry::Vacant(_) => panic!("key should be present"),
+    }
+    assert!(!dictionary.contains_key("foo"));
+
+    match dictionary.entry("bar") {
+        DictEntry::Occupied(_) => panic!("key should be absent"),
+        DictEntry::Vacant(entry) => {
+            assert_eq!(entry.insert(true), true.to_variant());
+        }
+    }
+    assert_eq!(dictionary.get("bar"), Some(true.to_variant()));
+}
+
 #[itest]
 fn dictionary_remove() {
     let mut dictionary = dict! {
@@ -685,3 +775,45 @@ fn dictionary_should_format_with_display() {
     };
     assert_eq!(format!("{d}"), "{ one: 1, two: true, three: <null> }")
 }
+
+#[itest]
+fn dictionary_deep_eq() {
+    let a = dict! { "a": 1, "nested": varray![1.5, dict! { "x": f64::NAN }] };
+    let b = dict! { "nested": varray![1.5, dict! { "x": f64::NAN }], "a": 1 };
+
+    assert!(a.deep_eq(&b), "key order is irrelevant, NaN equals NaN");
+    assert!(a.deep_eq(&a.deep_clone()));
+    assert!(!a.deep_eq(&dict! { "a": 1 }));
+    assert!(!a.deep_eq(&dict! { "a": 1, "nested": varray![1.5, Dictionary::new()] }));
+}
+
+#[itest]
+fn dictionary_diff() {
+    use godot::builtin::diff::Change;
+
+    let old = dict! {
+        "config": dict! { "max_hp": 100, "name": "hero" },
+        "removed": true,
+        "key with spaces": 1,
+    };
+    let new = dict! {
+        "config": dict! { "max_hp": 120, "name": "hero" },
+        "key with spaces": 2,
+        3: "added",
+    };
+
+    let changes = old.diff(&new);
+    let lines: Vec<String> = changes.iter().map(|c| c.to_string()).collect();
+    assert_eq!(
+        lines,
+        [
+            "~ config.max_hp: 100 -> 120",
+            "- removed: true",
+            "~ [\"key with spaces\"]: 1 -> 2",
+            "+ [3]: added",
+        ]
+    );
+
+    assert!(matches!(changes.as_slice()[3], Change::Added { .. }));
+    assert!(new.diff(&new.deep_clone()).is_empty());
+}
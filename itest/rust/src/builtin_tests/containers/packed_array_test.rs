@@ -6,7 +6,7 @@
  */
 
 use crate::framework::{expect_panic, itest};
-use godot::builtin::{PackedByteArray, PackedFloat32Array, PackedStringArray};
+use godot::builtin::{GString, PackedByteArray, PackedFloat32Array, PackedStringArray};
 
 #[itest]
 fn packed_array_default() {
@@ -235,3 +235,38 @@ fn packed_array_format() {
     let a = PackedByteArray::new();
     assert_eq!(format!("{a}"), "[]");
 }
+
+#[itest]
+fn packed_array_fixed_size_conversions() {
+    let bytes = PackedByteArray::from(&[1, 2, 3, 4]);
+    let fixed = <[u8; 4]>::try_from(&bytes).expect("length matches");
+    assert_eq!(fixed, [1, 2, 3, 4]);
+    assert!(<[u8; 3]>::try_from(&bytes).is_err());
+
+    let strings = PackedStringArray::from(&["a".into(), "b".into()]);
+    let fixed = <[GString; 2]>::try_from(&strings).expect("length matches");
+    assert_eq!(fixed, ["a".into(), "b".into()] as [GString; 2]);
+}
+
+#[itest]
+fn packed_array_string_conversions() {
+    let expected = PackedStringArray::from(&["a".into(), "bc".into(), "".into()]);
+
+    let array = PackedStringArray::from_iter(["a", "bc", ""]);
+    assert_eq!(array, expected);
+
+    let array = PackedStringArray::from(vec!["a".to_string(), "bc".to_string(), String::new()]);
+    assert_eq!(array, expected);
+
+    let array = PackedStringArray::from(&["a", "bc", ""][..]);
+    assert_eq!(array, expected);
+    assert_eq!(array.to_vec_string(), vec!["a", "bc", ""]);
+
+    // Iterator without exact length.
+    let array: PackedStringArray = "a bc x".split(' ').filter(|s| *s != "x").collect();
+    assert_eq!(array.to_vec_string(), vec!["a", "bc"]);
+
+    let mut array = array;
+    array.extend(["d".to_string()]);
+    assert_eq!(Vec::<String>::from(&array), vec!["a", "bc", "d"]);
+}
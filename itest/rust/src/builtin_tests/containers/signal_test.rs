@@ -5,13 +5,13 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 
-use godot::builtin::{Callable, GString, Signal, StringName, Variant};
+use godot::builtin::{varray, Array, Callable, GString, Signal, StringName, Variant};
 use godot::meta::ToGodot;
 use godot::register::{godot_api, GodotClass};
 
-use godot::classes::{Object, RefCounted};
+use godot::classes::{Node, Node3D, Object, RefCounted};
 use godot::obj::{Base, Gd, NewAlloc, NewGd, WithBaseField};
 use godot::sys;
 
@@ -149,3 +149,203 @@ fn connect_signal() {
 
     receiver.free();
 }
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct UserTypedEmitter {}
+
+#[godot_api]
+impl UserTypedEmitter {
+    #[signal]
+    fn receiver_changed(receiver: Gd<Receiver>, parent: Gd<Node3D>);
+}
+
+/// Names, variant types and class names of the arguments of `signal`, as registered with Godot.
+#[cfg(since_api = "4.2")]
+fn signal_params(object: &Gd<Object>, signal: &str) -> Vec<(String, i64, String)> {
+    use godot::builtin::{Dictionary, VariantArray};
+
+    let signal = object
+        .get_signal_list()
+        .iter_shared()
+        .find(|info| {
+            info.get("name")
+                .is_some_and(|name| name.stringify().to_string() == signal)
+        })
+        .expect("signal registered");
+
+    let args = signal.get("args").unwrap().to::<VariantArray>();
+    args.iter_shared()
+        .map(|arg| {
+            let arg = arg.to::<Dictionary>();
+            let field = |key: &str| arg.get(key).unwrap();
+            (
+                field("name").stringify().to_string(),
+                field("type").to::<i64>(),
+                field("class_name").stringify().to_string(),
+            )
+        })
+        .collect()
+}
+
+// Editor autocomplete of connections relies on argument names and object classes.
+#[cfg(since_api = "4.2")]
+#[itest]
+fn signal_params_metadata() {
+    use godot::builtin::VariantType;
+    use godot::obj::EngineEnum;
+
+    let emitter = Emitter::new_alloc();
+    let params = signal_params(&emitter.clone().upcast(), "signal_2_arg");
+
+    let object_type = VariantType::OBJECT.ord() as i64;
+    let string_type = VariantType::STRING.ord() as i64;
+    assert_eq!(
+        params,
+        [
+            ("arg1".to_string(), object_type, "Object".to_string()),
+            ("arg2".to_string(), string_type, String::new()),
+        ]
+    );
+
+    emitter.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn signal_params_metadata_user_class() {
+    use godot::builtin::VariantType;
+    use godot::obj::EngineEnum;
+
+    let emitter = UserTypedEmitter::new_alloc();
+    let params = signal_params(&emitter.clone().upcast(), "receiver_changed");
+
+    // Rust classes are reported with their registered name, like engine classes.
+    let object_type = VariantType::OBJECT.ord() as i64;
+    assert_eq!(
+        params,
+        [
+            ("receiver".to_string(), object_type, "Receiver".to_string()),
+            ("parent".to_string(), object_type, "Node3D".to_string()),
+        ]
+    );
+
+    emitter.free();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Object arrays as signal arguments
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct NodeListEmitter {}
+
+#[godot_api]
+impl NodeListEmitter {
+    #[signal]
+    fn nodes_vec(nodes: Vec<Gd<Node>>);
+
+    #[signal]
+    fn nodes_array(nodes: Array<Gd<Node>>);
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct NodeListReceiver {
+    received: RefCell<Vec<Gd<Node>>>,
+}
+
+#[godot_api]
+impl NodeListReceiver {
+    #[func]
+    fn receive_vec(&self, nodes: Vec<Gd<Node>>) {
+        *self.received.borrow_mut() = nodes;
+    }
+
+    #[func]
+    fn receive_array(&self, nodes: Array<Gd<Node>>) {
+        *self.received.borrow_mut() = nodes.iter_shared().collect();
+    }
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn signal_object_array_params_typed() {
+    use godot::builtin::{Dictionary, VariantArray};
+    use godot::global::PropertyHint;
+    use godot::obj::EngineEnum;
+
+    let emitter = NodeListEmitter::new_alloc();
+
+    for signal_name in ["nodes_vec", "nodes_array"] {
+        let signal = emitter
+            .get_signal_list()
+            .iter_shared()
+            .find(|info| {
+                info.get("name")
+                    .is_some_and(|name| name.stringify().to_string() == signal_name)
+            })
+            .expect("signal registered");
+
+        let param = signal
+            .get("args")
+            .unwrap()
+            .to::<VariantArray>()
+            .at(0)
+            .to::<Dictionary>();
+
+        let hint = PropertyHint::ARRAY_TYPE.ord();
+        assert_eq!(param.get("hint"), Some(hint.to_variant()));
+        assert_eq!(param.get("hint_string"), Some("Node".to_variant()));
+    }
+
+    emitter.free();
+}
+
+#[itest]
+fn signal_object_array_emit_typed() {
+    let emitter = NodeListEmitter::new_alloc();
+    let receiver = NodeListReceiver::new_alloc();
+    let nodes = vec![Node::new_alloc(), Node3D::new_alloc().upcast()];
+
+    for (signal_name, method) in [
+        ("nodes_vec", "receive_vec"),
+        ("nodes_array", "receive_array"),
+    ] {
+        let signal = Signal::from_object_signal(&emitter, signal_name);
+        signal.connect(receiver.callable(method), 0);
+        signal.emit_typed((nodes.clone(),));
+
+        let received = std::mem::take(&mut *receiver.bind().received.borrow_mut());
+        assert_eq!(received, nodes, "{signal_name}");
+    }
+
+    for node in nodes {
+        node.free();
+    }
+    receiver.free();
+    emitter.free();
+}
+
+#[itest]
+fn signal_object_array_from_untyped() {
+    let mut emitter = NodeListEmitter::new_alloc();
+    let receiver = NodeListReceiver::new_alloc();
+    let node = Node::new_alloc();
+
+    // Untyped arrays, like GDScript `[a, b]` literals, are converted element-wise.
+    emitter.connect("nodes_vec".into(), receiver.callable("receive_vec"));
+    emitter.emit_signal("nodes_vec".into(), &[varray![node.clone()].to_variant()]);
+    assert_eq!(*receiver.bind().received.borrow(), vec![node.clone()]);
+
+    // Elements of the wrong type fail the call, leaving the previous value.
+    emitter.emit_signal(
+        "nodes_vec".into(),
+        &[varray![node.clone(), 42].to_variant()],
+    );
+    assert_eq!(*receiver.bind().received.borrow(), vec![node.clone()]);
+
+    node.free();
+    receiver.free();
+    emitter.free();
+}
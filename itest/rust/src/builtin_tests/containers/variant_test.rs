@@ -6,12 +6,17 @@
  */
 
 use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Display;
+use std::hash::{Hash, Hasher};
 
 use godot::builtin::{
     dict, varray, GString, NodePath, Signal, StringName, Variant, Vector2, Vector3,
 };
-use godot::builtin::{Basis, Dictionary, VariantArray, VariantOperator, VariantType};
+use godot::builtin::{
+    Basis, Dictionary, VariantArray, VariantDispatch, VariantOperator, VariantOrd, VariantType,
+};
 use godot::classes::{Node, Node2D};
 use godot::meta::{FromGodot, ToGodot};
 use godot::obj::{Gd, InstanceId, NewAlloc};
@@ -343,6 +348,61 @@ fn variant_sys_conversion2() {
     assert_eq!(v2, v);
 }
 
+#[itest]
+fn variant_dispatch() {
+    assert_eq!(Variant::nil().dispatch(), VariantDispatch::Nil);
+    assert_eq!(7.to_variant().dispatch(), VariantDispatch::Int(7));
+    assert_eq!(true.to_variant().dispatch(), VariantDispatch::Bool(true));
+    assert_eq!(
+        gstr("hello").to_variant().dispatch(),
+        VariantDispatch::String(gstr("hello"))
+    );
+    assert_eq!(
+        Vector2::new(1.0, 2.0).to_variant().dispatch(),
+        VariantDispatch::Vector2(Vector2::new(1.0, 2.0))
+    );
+
+    let node = Node2D::new_alloc();
+    match node.to_variant().dispatch() {
+        VariantDispatch::Object(obj) => assert_eq!(obj, node.clone().upcast()),
+        other => panic!("expected object, got {other:?}"),
+    }
+    node.free();
+}
+
+#[itest]
+fn variant_dispatch_freed_object() {
+    let node = Node2D::new_alloc();
+    let variant = node.to_variant();
+    node.free();
+
+    let dispatch = variant.dispatch();
+    assert_eq!(dispatch, VariantDispatch::FreedObject);
+    assert_eq!(dispatch.variant_type(), VariantType::OBJECT);
+    assert_eq!(dispatch.into_variant(), Variant::nil());
+
+    // Debug output goes through dispatch, and must not panic.
+    assert_eq!(format!("{variant:?}"), "<Freed Object>");
+}
+
+#[itest]
+fn variant_dispatch_roundtrip() {
+    let values = [
+        Variant::nil(),
+        (-2.5).to_variant(),
+        gname("name").to_variant(),
+        varray![1, "two"].to_variant(),
+        dict! { "key": 3 }.to_variant(),
+        Basis::IDENTITY.to_variant(),
+    ];
+
+    for value in values {
+        let dispatch = value.dispatch();
+        assert_eq!(dispatch.variant_type(), value.get_type());
+        assert_eq!(dispatch.into_variant(), value);
+    }
+}
+
 #[itest]
 fn variant_null_object_is_nil() {
     use godot::sys;
@@ -442,6 +502,141 @@ fn variant_hash_correct() {
     assert_ne!(dict! { 0: dict!{ 0: 0 } }, dict! { 0: dict!{ 0: 1 } });
 }
 
+#[itest]
+fn variant_hash_map_key() {
+    let key = VariantOrd;
+
+    let mut map = HashMap::new();
+    map.insert(key(1.to_variant()), "int");
+    map.insert(key(gstr("key").to_variant()), "string");
+    map.insert(key(varray![1, 2].to_variant()), "array");
+
+    // Equal in GDScript, thus same key.
+    assert_eq!(map.get(&key(1.0.to_variant())), Some(&"int"));
+    assert_eq!(map.get(&key(gstr("key").to_variant())), Some(&"string"));
+    assert_eq!(map.get(&key(varray![1, 2].to_variant())), Some(&"array"));
+    assert_eq!(map.get(&key(2.to_variant())), None);
+
+    // Hash is consistent with equality across int and float.
+    let mut hasher_int = DefaultHasher::new();
+    let mut hasher_float = DefaultHasher::new();
+    Hash::hash(&1.to_variant(), &mut hasher_int);
+    Hash::hash(&1.0.to_variant(), &mut hasher_float);
+    assert_eq!(hasher_int.finish(), hasher_float.finish());
+}
+
+#[itest]
+fn variant_partial_cmp() {
+    assert!(1.to_variant() < 1.5.to_variant());
+    assert!(gstr("b").to_variant() > gstr("a").to_variant());
+    assert_eq!(
+        Vector2::new(1.0, 2.0)
+            .to_variant()
+            .partial_cmp(&Vector2::new(1.0, 2.0).to_variant()),
+        Some(Ordering::Equal)
+    );
+
+    // Not comparable in GDScript.
+    assert_eq!(1.to_variant().partial_cmp(&gstr("a").to_variant()), None);
+    assert_eq!(
+        f64::NAN.to_variant().partial_cmp(&f64::NAN.to_variant()),
+        None
+    );
+}
+
+#[itest]
+fn variant_total_cmp() {
+    let nan = f64::NAN.to_variant();
+    assert_eq!(nan.total_cmp(&nan), Ordering::Equal);
+    assert_eq!(nan.total_cmp(&1e300.to_variant()), Ordering::Greater);
+    assert_eq!(
+        3.to_variant().total_cmp(&2.5.to_variant()),
+        Ordering::Greater
+    );
+
+    let mut values = vec![
+        gstr("b").to_variant(),
+        3.to_variant(),
+        Variant::nil(),
+        nan.clone(),
+        gstr("a").to_variant(),
+        1.5.to_variant(),
+    ];
+    values.sort_by(Variant::total_cmp);
+
+    let expected_head = [Variant::nil(), 1.5.to_variant(), 3.to_variant()];
+    assert_eq!(values[..3], expected_head);
+    assert!(values[3].to::<f64>().is_nan());
+    assert_eq!(
+        values[4..],
+        [gstr("a").to_variant(), gstr("b").to_variant()]
+    );
+
+    // Incomparable values of the same type still get a deterministic order.
+    let a = dict! { "a": 1 }.to_variant();
+    let b = dict! { "b": 2 }.to_variant();
+    assert_eq!(a.total_cmp(&b), b.total_cmp(&a).reverse());
+}
+
+#[itest]
+fn variant_total_cmp_transitive() {
+    let values = [
+        Variant::nil(),
+        false.to_variant(),
+        (-3).to_variant(),
+        (-2.5).to_variant(),
+        1.to_variant(),
+        1.0.to_variant(),
+        9_007_199_254_740_993_i64.to_variant(), // 2^53 + 1, not representable as float.
+        9_007_199_254_740_992.0.to_variant(),
+        f64::NAN.to_variant(),
+        gstr("a").to_variant(),
+        StringName::from("a").to_variant(),
+        gstr("b").to_variant(),
+        Vector2::new(1.0, 2.0).to_variant(),
+        varray![1, gstr("x")].to_variant(),
+        varray![1.0, 2].to_variant(),
+        varray![1].to_variant(),
+        dict! { "a": 1 }.to_variant(),
+        dict! { "b": 2 }.to_variant(),
+    ];
+
+    for a in &values {
+        for b in &values {
+            assert_eq!(a.total_cmp(b), b.total_cmp(a).reverse(), "{a:?} vs {b:?}");
+
+            for c in &values {
+                let (ab, bc) = (a.total_cmp(b), b.total_cmp(c));
+                if ab == bc || bc == Ordering::Equal {
+                    assert_eq!(a.total_cmp(c), ab, "{a:?} vs {b:?} vs {c:?}");
+                } else if ab == Ordering::Equal {
+                    assert_eq!(a.total_cmp(c), bc, "{a:?} vs {b:?} vs {c:?}");
+                }
+            }
+        }
+    }
+
+    // Exact comparison between int and float.
+    assert_eq!(values[6].total_cmp(&values[7]), Ordering::Greater);
+    assert_eq!(values[4].total_cmp(&values[5]), Ordering::Equal);
+    assert_eq!(values[9].total_cmp(&values[10]), Ordering::Equal);
+}
+
+#[itest]
+fn variant_ord_btree_map() {
+    let mut map = BTreeMap::new();
+    map.insert(VariantOrd(3.to_variant()), "three");
+    map.insert(VariantOrd(1.5.to_variant()), "one and a half");
+    map.insert(VariantOrd(3.0.to_variant()), "three again");
+    map.insert(VariantOrd(f64::NAN.to_variant()), "nan");
+
+    assert_eq!(map.len(), 3);
+    assert_eq!(map.get(&VariantOrd(3.to_variant())), Some(&"three again"));
+
+    let values: Vec<&str> = map.values().copied().collect();
+    assert_eq!(values, ["one and a half", "three again", "nan"]);
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 fn truncate_bad<T>(original_value: i64)
@@ -0,0 +1,176 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{
+    array, dict, real, varray, Array, Color, Dictionary, GString, NodePath, PackedFloat32Array,
+    PackedInt32Array, PackedStringArray, StringName, Variant, VariantArray, VariantText, Vector2,
+    Vector2i, Vector3,
+};
+use godot::classes::Node;
+use godot::global::{str_to_var, var_to_str};
+use godot::meta::ToGodot;
+use godot::obj::NewAlloc;
+
+use crate::framework::itest;
+
+#[itest]
+fn variant_text_matches_engine() {
+    let values = [
+        Variant::nil(),
+        true.to_variant(),
+        (-17).to_variant(),
+        1.5.to_variant(),
+        3.0.to_variant(),
+        "say \"hi\"\\".to_variant(),
+        StringName::from("name").to_variant(),
+        NodePath::from("../Node:position").to_variant(),
+        Vector2::new(1.0, -2.5).to_variant(),
+        Vector2i::new(3, 4).to_variant(),
+        Color::from_rgba(1.0, 0.5, 0.0, 1.0).to_variant(),
+        varray![1, "two", 3.5].to_variant(),
+        array![1, 2, 3].to_variant(),
+        dict! { "key": 1 }.to_variant(),
+        Dictionary::new().to_variant(),
+        PackedInt32Array::from(&[1, 2, 3]).to_variant(),
+        PackedStringArray::from(&["a".into(), "b".into()]).to_variant(),
+    ];
+
+    for value in values {
+        let expected = var_to_str(value.clone()).to_string();
+        assert_eq!(value.to_text().unwrap(), expected, "{value:?}");
+    }
+}
+
+#[itest]
+fn variant_text_typed_array() {
+    let value = array![Vector2::new(1.0, 2.0)].to_variant();
+    let text = value.to_text().unwrap();
+    assert_eq!(text, "Array[Vector2]([Vector2(1, 2)])");
+
+    let parsed = Variant::from_text(&text).unwrap();
+    assert_eq!(parsed, value);
+    assert!(
+        parsed.try_to::<Array<Vector2>>().is_ok(),
+        "element type preserved"
+    );
+}
+
+#[itest]
+fn variant_text_unsupported() {
+    let node = Node::new_alloc();
+    assert!(node.to_variant().to_text().is_err());
+    assert!(varray![node.clone()].to_variant().to_text().is_err());
+    node.free();
+
+    assert!(Variant::from_text("Object(Node)").is_err());
+    assert!(Variant::from_text("[1, 2").is_err());
+}
+
+#[itest]
+fn variant_text_fuzz_roundtrip() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+    for _ in 0..300 {
+        let value = rng.variant(2);
+
+        // Our text is understood by the engine...
+        let text = value.to_text().unwrap();
+        let engine_parsed = str_to_var(GString::from(&text));
+        assert_eq!(engine_parsed, value, "engine parsing {text:?}");
+
+        // ...and the engine's text by us.
+        let engine_text = var_to_str(value.clone()).to_string();
+        let parsed = Variant::from_text(&engine_text)
+            .unwrap_or_else(|err| panic!("parsing {engine_text:?}: {err}"));
+        assert_eq!(parsed, value, "parsing {engine_text:?}");
+
+        // The engine-independent representation survives a round-trip through its own text.
+        let repr = VariantText::parse(&text).unwrap();
+        assert_eq!(VariantText::parse(&repr.to_string()).unwrap(), repr);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Helpers
+
+/// Xorshift generator, so that failures are reproducible.
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    fn int(&mut self) -> i64 {
+        self.below(20001) as i64 - 10000
+    }
+
+    /// Floats that are exactly representable with 6 significant digits, since the text format is not lossless.
+    fn float(&mut self) -> f64 {
+        (self.below(8001) as f64 - 4000.0) / 4.0
+    }
+
+    fn real(&mut self) -> real {
+        self.float() as real
+    }
+
+    fn string(&mut self) -> String {
+        const CHARS: &[char] = &['a', 'Z', '0', ' ', '"', '\\', '\n', 'é', '_', '/'];
+
+        let len = self.below(8);
+        (0..len)
+            .map(|_| CHARS[self.below(CHARS.len() as u64) as usize])
+            .collect()
+    }
+
+    fn variant(&mut self, depth: u32) -> Variant {
+        let kinds = if depth == 0 { 10 } else { 13 };
+
+        match self.below(kinds) {
+            0 => Variant::nil(),
+            1 => (self.below(2) == 0).to_variant(),
+            2 => self.int().to_variant(),
+            3 => self.float().to_variant(),
+            4 => self.string().to_variant(),
+            5 => StringName::from(self.string()).to_variant(),
+            6 => Vector2::new(self.real(), self.real()).to_variant(),
+            7 => Vector3::new(self.real(), self.real(), self.real()).to_variant(),
+            8 => {
+                let values = (0..self.below(4))
+                    .map(|_| self.float() as f32)
+                    .collect::<Vec<_>>();
+                PackedFloat32Array::from(values.as_slice()).to_variant()
+            }
+            9 => Color::from_rgba(0.25, 0.5, 0.75, 1.0).to_variant(),
+            10 => {
+                let mut array = VariantArray::new();
+                for _ in 0..self.below(4) {
+                    array.push(self.variant(depth - 1));
+                }
+                array.to_variant()
+            }
+            11 => {
+                let mut dict = Dictionary::new();
+                for _ in 0..self.below(4) {
+                    dict.set(self.string(), self.variant(depth - 1));
+                }
+                dict.to_variant()
+            }
+            _ => {
+                let values = (0..self.below(4)).map(|_| self.int()).collect::<Vec<_>>();
+                values.into_iter().collect::<Array<i64>>().to_variant()
+            }
+        }
+    }
+}
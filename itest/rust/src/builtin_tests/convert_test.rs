@@ -6,7 +6,8 @@
  */
 
 use godot::builtin::{
-    dict, Array, Dictionary, GString, Variant, VariantArray, Vector2, Vector2Axis,
+    dict, varray, Array, Dictionary, GString, PackedByteArray, Variant, VariantArray, VariantType,
+    Vector2, Vector2Axis,
 };
 use godot::classes::{Node, Resource};
 use godot::meta::error::ConvertError;
@@ -233,3 +234,136 @@ fn custom_convert_error_from_variant() {
         format!("{:?}", i64::MAX)
     );
 }
+
+#[derive(Debug)]
+struct Monster {
+    max_hp: i64,
+}
+
+impl GodotConvert for Monster {
+    type Via = Dictionary;
+}
+
+impl FromGodot for Monster {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        let config: Dictionary = via.try_get_as("config")?;
+
+        Ok(Self {
+            max_hp: config.try_get_as("max_hp")?,
+        })
+    }
+}
+
+#[itest]
+fn convert_error_nested_path() {
+    let monster = |max_hp: Variant| dict! { "config": dict! { "max_hp": max_hp } };
+
+    let valid = VariantArray::from(&[monster(10.to_variant()).to_variant()]);
+    let monsters = valid.try_to_vec::<Monster>().expect("valid monsters");
+    assert_eq!(monsters[0].max_hp, 10);
+
+    let invalid = VariantArray::from(&[
+        monster(10.to_variant()).to_variant(),
+        monster("10".to_variant()).to_variant(),
+    ]);
+    let err = invalid
+        .try_to_vec::<Monster>()
+        .expect_err("`max_hp` is not an integer");
+
+    assert_eq!(err.value(), Some(&"10".to_variant()));
+
+    assert_eq!(err.path(), expected_path("[1].config.max_hp"));
+    if paths_enabled() {
+        assert!(err.to_string().starts_with("at [1].config.max_hp: "));
+    }
+}
+
+#[itest]
+fn convert_error_manual_path() {
+    let err = ConvertError::new("bad").at_key("field").at_index(2);
+
+    assert_eq!(err.path(), expected_path("[2].field"));
+    assert_eq!(ConvertError::new("bad").path(), None);
+}
+
+#[itest]
+fn convert_error_vec_element_path() {
+    let untyped = varray![1, 2, "three"];
+
+    let err = untyped
+        .to_variant()
+        .try_to::<Vec<i64>>()
+        .expect_err("element 2 is not an integer");
+
+    assert_eq!(err.path(), expected_path("[2]"));
+    assert_eq!(err.value(), Some(&"three".to_variant()));
+}
+
+#[itest]
+fn convert_error_typed_array_element_path() {
+    let untyped = varray![1, "two", 3];
+
+    let err = untyped
+        .to_variant()
+        .try_to::<Array<i64>>()
+        .expect_err("untyped array is not Array<i64>");
+
+    // The first element that prevents the conversion.
+    assert_eq!(err.path(), expected_path("[1]"));
+}
+
+#[itest]
+fn convert_vec_u8_packed_byte_array() {
+    let bytes = vec![1u8, 2, 255];
+
+    let variant = bytes.to_variant();
+    assert_eq!(variant.get_type(), VariantType::PACKED_BYTE_ARRAY);
+    assert_eq!(variant.to::<PackedByteArray>().as_slice(), &[1, 2, 255]);
+    assert_eq!(variant.to::<Vec<u8>>(), bytes);
+
+    // Arrays of integers are still accepted, as long as each element fits into a byte.
+    assert_eq!(varray![1, 2, 255].to_variant().to::<Vec<u8>>(), bytes);
+
+    let err = varray![1, 256]
+        .to_variant()
+        .try_to::<Vec<u8>>()
+        .expect_err("256 does not fit into u8");
+    assert_eq!(err.path(), expected_path("[1]"));
+}
+
+/// Paths are only recorded with the `conversion-paths` feature; itest runs with and without it.
+fn paths_enabled() -> bool {
+    ConvertError::new("probe").at_index(0).path().is_some()
+}
+
+fn expected_path(path: &str) -> Option<String> {
+    paths_enabled().then(|| path.to_string())
+}
+
+#[itest]
+fn fixed_point_variant_roundtrip() {
+    use godot::builtin::math::fixed::{Fix64, FixedVector3};
+
+    let value = Fix64::from_f64(-1234.5678);
+    let variant = value.to_variant();
+    assert_eq!(variant.get_type(), godot::builtin::VariantType::INT);
+    assert_eq!(variant.to::<Fix64>(), value);
+
+    let vector = FixedVector3::new(value, Fix64::ONE, Fix64::MIN);
+    assert_eq!(vector.to_variant().to::<FixedVector3>(), vector);
+
+    let err = Vector2::ZERO
+        .to_variant()
+        .try_to::<FixedVector3>()
+        .expect_err("not a packed array");
+    assert!(err.value().is_some());
+
+    let err = godot::builtin::PackedInt64Array::from(&[1, 2])
+        .to_variant()
+        .try_to::<FixedVector3>()
+        .expect_err("wrong length");
+    assert!(
+        err.to_string().contains("expected array of length 3"),
+        "{err}"
+    );
+}
@@ -0,0 +1,79 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Only compiled with the `alloc-stats` feature of itest.
+
+use godot::builtin::{Dictionary, GString, PackedInt32Array, VariantArray, VariantType, Vector2};
+use godot::diagnostics::{reset_peaks, AllocStats};
+
+use crate::framework::itest;
+
+fn counters(stats: &AllocStats, ty: VariantType) -> (u64, u64, i64) {
+    let entry = stats.get(ty).expect("type is tracked");
+    (entry.constructed, entry.destructed, entry.live)
+}
+
+#[itest]
+fn alloc_stats_construct_and_drop() {
+    let before = AllocStats::capture();
+
+    let strings = [GString::from("a"), GString::from("b")];
+    let array = VariantArray::new();
+    let dict = Dictionary::new();
+    let packed = PackedInt32Array::new();
+
+    let alive = AllocStats::capture().since(&before);
+    assert_eq!(counters(&alive, VariantType::STRING), (2, 0, 2));
+    assert_eq!(counters(&alive, VariantType::ARRAY), (1, 0, 1));
+    assert_eq!(counters(&alive, VariantType::DICTIONARY), (1, 0, 1));
+    assert_eq!(counters(&alive, VariantType::PACKED_INT32_ARRAY), (1, 0, 1));
+
+    drop(strings);
+    drop(array);
+    drop(dict);
+    drop(packed);
+
+    let dropped = AllocStats::capture().since(&before);
+    assert_eq!(counters(&dropped, VariantType::STRING), (2, 2, 0));
+    assert_eq!(counters(&dropped, VariantType::ARRAY), (1, 1, 0));
+    assert_eq!(counters(&dropped, VariantType::DICTIONARY), (1, 1, 0));
+    assert_eq!(
+        counters(&dropped, VariantType::PACKED_INT32_ARRAY),
+        (1, 1, 0)
+    );
+}
+
+#[itest]
+fn alloc_stats_peak() {
+    reset_peaks();
+    let base = AllocStats::capture().get(VariantType::STRING).unwrap().peak;
+
+    let strings = (0..5)
+        .map(|i| GString::from(i.to_string()))
+        .collect::<Vec<_>>();
+    drop(strings);
+
+    let stats = AllocStats::capture();
+    let string_stats = stats.get(VariantType::STRING).unwrap();
+    assert_eq!(string_stats.peak, base + 5);
+
+    reset_peaks();
+    let stats = AllocStats::capture();
+    assert_eq!(
+        stats.get(VariantType::STRING).unwrap().peak,
+        string_stats.live
+    );
+}
+
+#[itest]
+fn alloc_stats_value_types_untracked() {
+    let _vector = Vector2::new(1.0, 2.0);
+
+    let stats = AllocStats::capture();
+    assert!(stats.get(VariantType::VECTOR2).is_none());
+    assert!(stats.get(VariantType::STRING).is_some());
+}
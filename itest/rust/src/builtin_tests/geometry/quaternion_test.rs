@@ -83,6 +83,35 @@ fn quaternion_slerp() {
     assert_eq!(outcome, Quaternion::default());
 }
 
+#[itest]
+fn quaternion_slerp_all() {
+    let from = [
+        Quaternion::new(-1.0, -1.0, -1.0, 10.0).normalized(),
+        Quaternion::default(),
+        Quaternion::new(0.5, -0.5, 0.5, 0.5),
+        Quaternion::new(1.0, 2.0, 3.0, 4.0).normalized(),
+        Quaternion::new(0.0, 1.0, 0.0, 0.0),
+    ];
+    let to = [
+        Quaternion::new(3.0, 3.0, 3.0, 5.0).normalized(),
+        Quaternion::new(0.0, 0.0, 1.0, 0.0),
+        Quaternion::default(),
+        Quaternion::new(-4.0, 3.0, -2.0, 1.0).normalized(),
+        Quaternion::new(0.0, -0.6, 0.0, 0.8),
+    ];
+
+    let mut batch = from;
+    Quaternion::slerp_all(&mut batch, &to, 0.3);
+
+    for ((outcome, from), to) in batch.iter().zip(from).zip(to) {
+        assert_eq_approx!(*outcome, from.slerp(to, 0.3));
+    }
+
+    expect_panic("slerp_all() with different lengths", || {
+        Quaternion::slerp_all(&mut batch, &to[..2], 0.5);
+    });
+}
+
 #[itest]
 fn quaternion_slerpni() {
     let a = Quaternion::new(-1.0, -1.0, -1.0, 10.0);
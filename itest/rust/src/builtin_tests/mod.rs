@@ -32,6 +32,7 @@ mod containers {
     mod rid_test;
     mod signal_test;
     mod variant_test;
+    mod variant_text_test;
 }
 
 mod string {
@@ -48,5 +49,12 @@ mod color_test;
 
 mod convert_test;
 
+#[cfg(feature = "alloc-stats")]
+mod diagnostics_test;
+
+#[cfg(feature = "ndarray")]
+mod ndarray_test;
+#[cfg(feature = "rayon")]
+mod rayon_test;
 #[cfg(feature = "serde")]
 mod serde_test;
@@ -0,0 +1,65 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+use godot::builtin::{PackedByteArray, PackedFloat32Array, PackedVector2Array, Vector2};
+use ndarray::{array, s, Array2, Ix2};
+
+#[itest]
+fn ndarray_view_1d() {
+    let mut packed = PackedFloat32Array::from(&[1.0, 2.0, 3.0]);
+
+    assert_eq!(packed.as_ndarray(), array![1.0, 2.0, 3.0]);
+
+    packed.as_ndarray_mut().mapv_inplace(|x| x * 2.0);
+    assert_eq!(packed.as_slice(), &[2.0, 4.0, 6.0]);
+    assert_eq!(packed.to_ndarray(), array![2.0, 4.0, 6.0]);
+}
+
+#[itest]
+fn ndarray_view_shaped() {
+    let mut packed = PackedByteArray::from(&[0, 1, 2, 3, 4, 5]);
+
+    let view = packed.as_ndarray_shaped::<Ix2, _>((2, 3)).unwrap();
+    assert_eq!(view, array![[0, 1, 2], [3, 4, 5]]);
+    assert!(packed.as_ndarray_shaped::<Ix2, _>((4, 2)).is_err());
+
+    let mut view = packed.as_ndarray_shaped_mut::<Ix2, _>((2, 3)).unwrap();
+    view.slice_mut(s![.., 0]).fill(9);
+    assert_eq!(packed.as_slice(), &[9, 1, 2, 9, 4, 5]);
+}
+
+#[itest]
+fn ndarray_to_packed() {
+    let matrix: Array2<f32> = array![[1.0, 2.0], [3.0, 4.0]];
+    assert_eq!(
+        PackedFloat32Array::from(&matrix).as_slice(),
+        &[1.0, 2.0, 3.0, 4.0]
+    );
+
+    // Non-standard layout is copied in logical order.
+    let transposed = matrix.t();
+    assert_eq!(
+        PackedFloat32Array::from(&transposed).as_slice(),
+        &[1.0, 3.0, 2.0, 4.0]
+    );
+}
+
+#[itest]
+fn ndarray_vector2_frames() {
+    let mut frames = PackedVector2Array::from(&[Vector2::new(0.5, -0.5), Vector2::new(1.0, 0.0)]);
+
+    let view = frames.as_ndarray();
+    assert_eq!(view.shape(), &[2, 2]);
+    assert_eq!(view.column(0), array![0.5, 1.0]);
+
+    frames.as_ndarray_mut().column_mut(1).fill(0.25);
+    assert_eq!(frames[0], Vector2::new(0.5, 0.25));
+
+    let rebuilt = PackedVector2Array::from(&frames.as_ndarray());
+    assert_eq!(rebuilt, frames);
+}
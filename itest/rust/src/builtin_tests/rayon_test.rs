@@ -0,0 +1,56 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+use godot::builtin::{array, Array, PackedFloat32Array, PackedVector2Array, Vector2};
+use rayon::prelude::*;
+
+#[itest]
+fn rayon_packed_par_iter() {
+    let packed: PackedFloat32Array = (0..1000).map(|i| i as f32).collect();
+
+    let sum: f32 = packed.par_iter().sum();
+    assert_eq!(sum, 499_500.0);
+
+    let doubled: PackedFloat32Array = packed.par_iter().map(|x| x * 2.0).collect();
+    assert_eq!(doubled.len(), 1000);
+    assert_eq!(doubled.get(999), Some(1998.0));
+}
+
+#[itest]
+fn rayon_packed_par_iter_mut() {
+    let original = PackedVector2Array::from(&[Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)]);
+    let mut copy = original.clone();
+
+    copy.par_iter_mut().for_each(|v| *v = *v * 2.0);
+
+    assert_eq!(
+        copy.as_slice(),
+        &[Vector2::new(2.0, 4.0), Vector2::new(6.0, 8.0)]
+    );
+    assert_eq!(
+        original.get(0),
+        Some(Vector2::new(1.0, 2.0)),
+        "copy-on-write"
+    );
+}
+
+#[itest]
+fn rayon_array_par_iter() {
+    let array: Array<i64> = (1..=100).collect();
+
+    let evens: Array<i64> = array.par_iter().filter(|x| x % 2 == 0).collect();
+    assert_eq!(evens.len(), 50);
+    assert_eq!(evens.get(0), Some(2));
+    assert_eq!(evens.get(49), Some(100));
+
+    let halves: Array<f64> = array![1, 2, 3]
+        .par_iter()
+        .map(|x: i64| x as f64 / 2.0)
+        .collect();
+    assert_eq!(halves, array![0.5, 1.0, 1.5]);
+}
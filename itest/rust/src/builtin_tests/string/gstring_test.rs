@@ -8,7 +8,7 @@
 use std::collections::HashSet;
 
 use crate::framework::itest;
-use godot::builtin::GString;
+use godot::builtin::{GString, StringName};
 
 // TODO use tests from godot-rust/gdnative
 
@@ -129,3 +129,72 @@ fn string_with_null() {
         assert_eq!(left, right);
     }
 }
+
+#[cfg(since_api = "4.1")]
+#[itest]
+fn string_gstr_view() {
+    let string = GString::from("  key=välue  ");
+    let view = string.as_gstr();
+
+    assert_eq!(view.len(), 13);
+    assert_eq!(view.trim(), "key=välue");
+    assert!(view.trim().starts_with("key"));
+    assert!(view.trim().ends_with("lue"));
+    assert!(view.contains("välu"));
+    assert_eq!(view.find("="), Some(5));
+    assert_eq!(view.find("missing"), None);
+
+    let (key, value) = view.trim().split_once('=').expect("contains =");
+    assert_eq!(key, "key");
+    assert_eq!(value, "välue");
+    assert_eq!(value.to_gstring(), GString::from("välue"));
+
+    assert_eq!(view.to_string(), string.to_string());
+    assert_eq!(format!("{:?}", key), "\"key\"");
+}
+
+#[cfg(since_api = "4.1")]
+#[itest]
+fn string_gstr_split_parse() {
+    let string = GString::from("1,22,333");
+    let numbers: Vec<i32> = string
+        .as_gstr()
+        .split(',')
+        .map(|part| part.parse().unwrap())
+        .collect();
+
+    assert_eq!(numbers, vec![1, 22, 333]);
+    assert_eq!(&string.as_gstr()[2..4], "22");
+    assert!(string.as_gstr().get(5..100).is_none());
+}
+
+#[cfg(since_api = "4.1")]
+#[itest]
+fn string_name_gstr_view() {
+    let name = StringName::from("  node_välue  ");
+    let view = name.as_gstr();
+
+    assert_eq!(view.len(), 14);
+    assert_eq!(view, "  node_välue  ");
+    assert_eq!(view.trim(), "node_välue");
+    assert!(view.trim().starts_with("node"));
+
+    let (prefix, suffix) = view.trim().split_once('_').expect("contains _");
+    assert_eq!(prefix, "node");
+    assert_eq!(suffix.to_gstring(), GString::from("välue"));
+
+    assert_eq!(view.to_string(), name.to_string());
+    assert_eq!(format!("{view:?}"), "\"  node_välue  \"");
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn string_name_gstr_view_latin1() {
+    // Static Latin-1 names are stored without a string representation.
+    let name = StringName::from(c"latin1_name");
+    let view = name.as_gstr();
+
+    assert_eq!(view, "latin1_name");
+    assert_eq!(view.find("_"), Some(6));
+    assert!(StringName::default().as_gstr().is_empty());
+}
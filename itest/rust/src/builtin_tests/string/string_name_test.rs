@@ -8,7 +8,7 @@
 use std::collections::HashSet;
 
 use crate::framework::{assert_eq_self, itest};
-use godot::builtin::{GString, NodePath, StringName};
+use godot::builtin::{sname, GString, NodePath, StringName};
 
 #[itest]
 fn string_name_default() {
@@ -162,3 +162,29 @@ fn string_name_with_null() {
         assert_eq!(left, right);
     }
 }
+
+#[itest]
+fn string_name_static_cached() {
+    fn cached() -> &'static StringName {
+        sname!("cached name")
+    }
+
+    let first = cached();
+    let second = cached();
+
+    assert_eq!(*first, StringName::from("cached name"));
+    assert!(std::ptr::eq(first, second), "same instance is returned");
+
+    // Different call sites have separate caches, but equal values.
+    let other = sname!("cached name");
+    assert_eq!(other, first);
+}
+
+#[itest]
+fn string_name_sname_literals() {
+    // Non-ASCII literals cannot use the Latin-1 path, but must produce the same name.
+    assert_eq!(*sname!("sprünge"), StringName::from("sprünge"));
+    assert_eq!(sname!("sprünge").len(), 7);
+
+    assert!(sname!("").is_empty());
+}
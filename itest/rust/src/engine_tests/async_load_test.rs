@@ -0,0 +1,143 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::{PackedScene, Resource, ResourceLoader};
+use godot::obj::NewGd;
+use godot::tools::{save, AsyncLoadStatus};
+
+use crate::framework::itest;
+
+const RESOURCE_PATH: &str = "res://async_load_test.tres";
+
+fn remove_test_file() {
+    let file_path = concat!(env!("CARGO_MANIFEST_DIR"), "/../godot/async_load_test.tres");
+    std::fs::remove_file(file_path).expect("couldn't remove test file");
+}
+
+#[itest]
+fn async_load_wait() {
+    // Engine class only, so that no Rust code runs on the loader thread.
+    let mut resource = Resource::new_gd();
+    resource.set_name("async".into());
+    save(resource, RESOURCE_PATH);
+
+    let load = ResourceLoader::load_async::<Resource>(RESOURCE_PATH).expect("request started");
+    assert_eq!(load.path(), &RESOURCE_PATH.into());
+
+    let loaded = load.wait().expect("resource loaded");
+    assert_eq!(loaded.get_name(), "async".into());
+
+    remove_test_file();
+}
+
+#[itest]
+fn async_load_status() {
+    save(Resource::new_gd(), RESOURCE_PATH);
+
+    let mut load = ResourceLoader::load_async::<Resource>(RESOURCE_PATH).expect("request started");
+
+    // Loading from the main thread without a frame loop: block until done.
+    while !load.is_done() {
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+
+    assert_eq!(load.poll_status(), AsyncLoadStatus::Loaded);
+    assert_eq!(load.progress(), 1.0);
+
+    let first = load.try_get().expect("done").expect("loaded");
+    let second = load.try_get().expect("done").expect("loaded");
+    assert_eq!(first, second);
+
+    remove_test_file();
+}
+
+#[itest]
+fn async_load_wrong_class() {
+    save(Resource::new_gd(), RESOURCE_PATH);
+
+    let load = ResourceLoader::load_async::<PackedScene>(RESOURCE_PATH).expect("request started");
+    assert!(load.wait().is_err());
+
+    remove_test_file();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn async_load_future_wakes_once() {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+
+    use godot::tools::dispatch_process_callbacks;
+
+    struct WakeCounter(AtomicUsize);
+
+    impl Wake for WakeCounter {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    save(Resource::new_gd(), RESOURCE_PATH);
+
+    let wake_count = Arc::new(WakeCounter(AtomicUsize::new(0)));
+    let waker = Waker::from(wake_count.clone());
+    let mut cx = Context::from_waker(&waker);
+
+    let mut load = ResourceLoader::load_async::<Resource>(RESOURCE_PATH).expect("request started");
+
+    let mut result = Pin::new(&mut load).poll(&mut cx);
+    while result.is_pending() {
+        // Pending polls do not re-schedule the task; only the per-frame check wakes it once loading has finished.
+        assert_eq!(wake_count.0.load(Ordering::SeqCst), 0);
+
+        while !load.is_done() {
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        dispatch_process_callbacks(0.0);
+        assert_eq!(wake_count.0.load(Ordering::SeqCst), 1);
+
+        result = Pin::new(&mut load).poll(&mut cx);
+    }
+
+    let Poll::Ready(loaded) = result else {
+        unreachable!()
+    };
+    assert!(loaded.is_ok());
+
+    remove_test_file();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn async_load_drop_pending() {
+    use godot::tools::dispatch_process_callbacks;
+
+    save(Resource::new_gd(), RESOURCE_PATH);
+
+    let load = ResourceLoader::load_async::<Resource>(RESOURCE_PATH).expect("request started");
+    drop(load);
+
+    // The abandoned request is released once it finishes; afterward, the path can be requested again.
+    for _ in 0..100 {
+        dispatch_process_callbacks(0.0);
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+
+    let load = ResourceLoader::load_async::<Resource>(RESOURCE_PATH).expect("request started");
+    assert!(load.wait().is_ok());
+
+    remove_test_file();
+}
+
+#[itest]
+fn async_load_missing_file() {
+    let load = ResourceLoader::load_async::<Resource>("res://does_not_exist.tres");
+    assert!(load.is_err());
+}
@@ -0,0 +1,71 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::Vector2;
+use godot::classes::{AudioStreamGenerator, AudioStreamGeneratorPlayback};
+use godot::obj::{Gd, NewGd};
+use godot::tools::audio::{FrameRingBuffer, GeneratorWriter};
+
+use crate::framework::itest;
+
+fn make_writer() -> (Gd<AudioStreamGenerator>, GeneratorWriter) {
+    let mut generator = AudioStreamGenerator::new_gd();
+    generator.set_mix_rate(8000.0);
+    generator.set_buffer_length(0.1);
+
+    let playback = generator
+        .instantiate_playback()
+        .expect("playback")
+        .cast::<AudioStreamGeneratorPlayback>();
+
+    let writer = GeneratorWriter::new(playback, generator.get_mix_rate());
+    (generator, writer)
+}
+
+#[itest]
+fn audio_push_frames() {
+    let (_generator, mut writer) = make_writer();
+
+    let available = writer.frames_available();
+    assert!(available > 0);
+
+    let frames = vec![Vector2::new(0.5, -0.5); 100];
+    assert_eq!(writer.push_frames(&frames), 100);
+    assert_eq!(writer.frames_available(), available - 100);
+
+    // More than fits: only the available part is pushed.
+    let many = vec![Vector2::ZERO; available * 2];
+    assert_eq!(writer.push_frames(&many), available - 100);
+    assert_eq!(writer.frames_available(), 0);
+    assert_eq!(writer.push_frames(&frames), 0);
+
+    writer.clear();
+    assert_eq!(writer.frames_available(), available);
+}
+
+#[itest]
+fn audio_fill_available_and_ring() {
+    let (generator, mut writer) = make_writer();
+    let available = writer.frames_available();
+
+    let mut ring = FrameRingBuffer::for_generator(&generator);
+    assert_eq!(ring.capacity(), 800);
+    ring.push(&[Vector2::ONE; 50]);
+
+    assert_eq!(writer.push_from_ring(&mut ring), 50);
+    assert!(ring.is_empty());
+
+    let mut seen = 0;
+    let filled = writer.fill_available(|frames| {
+        seen = frames.len();
+        frames.fill(Vector2::ZERO);
+    });
+    assert_eq!(filled, available - 50);
+    assert_eq!(seen, filled);
+
+    assert_eq!(writer.take_underruns(), 0);
+}
@@ -0,0 +1,88 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::tools::compute::ComputeDevice;
+
+use crate::framework::itest;
+
+const DOUBLE_SHADER: &str = r#"
+#version 450
+
+layout(local_size_x = 4, local_size_y = 1, local_size_z = 1) in;
+
+layout(set = 0, binding = 0, std430) restrict buffer Values {
+    float data[];
+} values;
+
+layout(push_constant, std430) uniform Params {
+    float factor;
+} params;
+
+void main() {
+    values.data[gl_GlobalInvocationID.x] *= params.factor;
+}
+"#;
+
+// Headless runs have no rendering device; the tests are then skipped.
+fn local_device() -> Option<ComputeDevice> {
+    ComputeDevice::local()
+}
+
+#[itest]
+fn compute_buffer_roundtrip() {
+    let Some(device) = local_device() else {
+        return;
+    };
+    assert!(device.is_local());
+
+    let buffer = device.storage_buffer(&[1_u32, 2, 3, 4]);
+    assert_eq!(buffer.len(), 4);
+    assert_eq!(buffer.size_bytes(), 16);
+    assert_eq!(buffer.read(), [1, 2, 3, 4]);
+
+    buffer.write(1, &[20, 30]);
+    assert_eq!(buffer.read_range(1..3), [20, 30]);
+
+    let zeroed = device.storage_buffer_zeroed::<[f32; 4]>(2);
+    assert_eq!(zeroed.read(), [[0.0; 4]; 2]);
+}
+
+#[itest]
+fn compute_dispatch() {
+    let Some(device) = local_device() else {
+        return;
+    };
+
+    let shader = device
+        .compile_shader(DOUBLE_SHADER)
+        .expect("shader compiles");
+
+    let buffer = device.storage_buffer(&[1.0_f32, 2.0, 3.0, 4.0]);
+    let uniforms = shader.uniform_set(0).storage(0, &buffer).build();
+
+    device
+        .pass(&shader)
+        .uniform_set(&uniforms)
+        .push_constants(&[2.5_f32])
+        .dispatch(1, 1, 1);
+    device.submit_and_wait();
+
+    assert_eq!(buffer.read(), [2.5, 5.0, 7.5, 10.0]);
+}
+
+#[itest]
+fn compute_compile_error() {
+    let Some(device) = local_device() else {
+        return;
+    };
+
+    let err = device
+        .compile_shader("#version 450\nvoid main() { undefined(); }")
+        .expect_err("invalid shader");
+
+    assert!(err.to_string().starts_with("failed to compile"), "{err}");
+}
@@ -0,0 +1,138 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::io::Write;
+
+use godot::builtin::{dict, varray, Dictionary, PackedStringArray, Variant};
+use godot::classes::file_access::ModeFlags;
+use godot::meta::ToGodot;
+use godot::tools::config::{self, ConfigError, ConfigFormat};
+use godot::tools::GFile;
+use serde::Deserialize;
+
+use crate::framework::itest;
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Balancing {
+    max_health: i32,
+    spawn_rate: f32,
+    enemies: Vec<String>,
+}
+
+#[itest]
+fn config_parse_dictionary_json() {
+    let text = r#"{ "name": "orc", "level": 3, "speed": 1.5, "tags": ["melee", null], "loot": { "gold": true } }"#;
+    let dict = config::parse_dictionary(text, ConfigFormat::Json).expect("valid JSON");
+
+    let expected = dict! {
+        "name": "orc",
+        "level": 3,
+        "speed": 1.5,
+        "tags": varray!["melee", Variant::nil()],
+        "loot": dict! { "gold": true },
+    };
+    assert_eq!(dict, expected);
+}
+
+#[itest]
+fn config_parse_dictionary_json5_toml() {
+    let json5 = "{ // comment\n  level: 3, tags: ['a', 'b',], }";
+    let dict = config::parse_dictionary(json5, ConfigFormat::Json5).expect("valid JSON5");
+    assert_eq!(dict.get("level"), Some(3.to_variant()));
+    assert_eq!(dict.get("tags"), Some(varray!["a", "b"].to_variant()));
+
+    let toml = "level = 3\n\n[loot]\ngold = true\nfound = 1979-05-27\n";
+    let dict = config::parse_dictionary(toml, ConfigFormat::Toml).expect("valid TOML");
+    assert_eq!(dict.get("level"), Some(3.to_variant()));
+    assert_eq!(
+        dict.get("loot"),
+        Some(dict! { "gold": true, "found": "1979-05-27" }.to_variant())
+    );
+}
+
+#[itest]
+fn config_parse_errors_have_position() {
+    let err = config::parse_dictionary("{\n  \"a\": 1,\n  \"b\" 2\n}", ConfigFormat::Json)
+        .expect_err("missing colon");
+    let ConfigError::Parse { line, column, .. } = &err else {
+        panic!("unexpected error: {err}");
+    };
+    assert_eq!((*line, *column), (Some(3), Some(7)));
+    assert!(err.to_string().starts_with("<string>:3:7: "), "{err}");
+
+    let err = config::parse::<Balancing>(
+        "max_health = 10\nspawn_rate = \"fast\"\nenemies = []\n",
+        ConfigFormat::Toml,
+    )
+    .expect_err("wrong type");
+    let ConfigError::Parse { line, .. } = &err else {
+        panic!("unexpected error: {err}");
+    };
+    assert_eq!(*line, Some(2));
+
+    let err = config::parse_dictionary("[1, 2]", ConfigFormat::Json).expect_err("not an object");
+    assert!(
+        matches!(err, ConfigError::Parse { line: None, .. }),
+        "{err}"
+    );
+}
+
+#[itest]
+fn config_load_file() {
+    const PATH: &str = "user://config_test.toml";
+
+    let mut file = GFile::open(PATH, ModeFlags::WRITE).unwrap();
+    file.write_all(b"max_health = 100\nspawn_rate = 0.5\nenemies = [\"orc\", \"troll\"]\n")
+        .unwrap();
+    drop(file);
+
+    let balancing = config::load::<Balancing>(PATH).expect("load typed");
+    assert_eq!(
+        balancing,
+        Balancing {
+            max_health: 100,
+            spawn_rate: 0.5,
+            enemies: vec!["orc".to_string(), "troll".to_string()],
+        }
+    );
+
+    let dict = config::load_dictionary(PATH).expect("load dictionary");
+    assert_eq!(dict.get("max_health"), Some(100.to_variant()));
+
+    let err = config::load_dictionary("user://does_not_exist.json").expect_err("missing file");
+    assert!(matches!(err, ConfigError::Io { .. }), "{err}");
+    assert_eq!(err.path(), "user://does_not_exist.json");
+
+    let err = config::load_dictionary("user://config_test.ini").expect_err("unknown format");
+    assert!(matches!(err, ConfigError::UnknownFormat { .. }), "{err}");
+}
+
+#[itest]
+fn config_json_value_roundtrip() {
+    let value = dict! {
+        "name": "orc",
+        "stats": varray![1, 2, 3],
+        "names": PackedStringArray::from(&["a", "b"][..]),
+    }
+    .to_variant();
+
+    let json = config::to_json_value(&value).expect("convertible");
+    assert_eq!(
+        json,
+        serde_json::json!({ "name": "orc", "stats": [1, 2, 3], "names": ["a", "b"] })
+    );
+
+    let back = config::from_json_value(&json).to::<Dictionary>();
+    assert_eq!(back.get("name"), Some("orc".to_variant()));
+    assert_eq!(back.get("names"), Some(varray!["a", "b"].to_variant()));
+
+    let err = config::to_json_value(&dict! { 1: "int key" }.to_variant()).expect_err("int key");
+    assert!(err.to_string().contains("keys must be strings"), "{err}");
+
+    let err = config::to_json_value(&f64::NAN.to_variant()).expect_err("NaN");
+    assert!(err.to_string().contains("no JSON representation"), "{err}");
+}
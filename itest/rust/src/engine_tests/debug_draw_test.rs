@@ -0,0 +1,59 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Debug drawing is compiled out in release builds.
+#![cfg(debug_assertions)]
+
+use godot::builtin::{Aabb, Color, Vector2, Vector3};
+use godot::tools::debug_draw;
+
+use crate::framework::itest;
+
+#[itest]
+fn debug_draw_durations() {
+    debug_draw::clear();
+
+    debug_draw::draw_line_3d(Vector3::ZERO, Vector3::ONE, Color::RED, 0.0);
+    debug_draw::draw_aabb(Aabb::new(Vector3::ZERO, Vector3::ONE), Color::GREEN, 0.0);
+    debug_draw::draw_sphere(Vector3::ZERO, 1.0, Color::BLUE, 60.0);
+    debug_draw::draw_text_3d(Vector3::ZERO, "origin", Color::WHITE, 60.0);
+    debug_draw::draw_text_2d(Vector2::new(10.0, 10.0), "text", Color::WHITE, 0.0);
+    debug_draw::print_overlay("overlay", Color::WHITE, 0.0);
+    assert_eq!(debug_draw::pending_count(), 6);
+
+    // Single-frame primitives are dropped after rendering, timed ones are kept.
+    debug_draw::flush();
+    assert_eq!(debug_draw::pending_count(), 2);
+
+    debug_draw::clear();
+    assert_eq!(debug_draw::pending_count(), 0);
+    debug_draw::flush();
+}
+
+#[itest]
+fn debug_draw_disabled() {
+    debug_draw::clear();
+    assert!(debug_draw::is_enabled());
+
+    debug_draw::draw_line_3d(Vector3::ZERO, Vector3::ONE, Color::RED, 60.0);
+    debug_draw::set_enabled(false);
+    assert!(!debug_draw::is_enabled());
+    assert_eq!(
+        debug_draw::pending_count(),
+        0,
+        "disabling discards primitives"
+    );
+
+    debug_draw::draw_line_3d(Vector3::ZERO, Vector3::ONE, Color::RED, 60.0);
+    assert_eq!(debug_draw::pending_count(), 0);
+
+    debug_draw::set_enabled(true);
+    debug_draw::draw_line_3d(Vector3::ZERO, Vector3::ONE, Color::RED, 0.0);
+    assert_eq!(debug_draw::pending_count(), 1);
+
+    debug_draw::clear();
+}
@@ -0,0 +1,120 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use godot::task::{
+    defer, defer_coalesced, defer_physics, defer_physics_coalesced, deferred_count,
+    deferred_physics_count,
+};
+
+use crate::framework::{itest, TestContext};
+
+fn emit_physics_frame(ctx: &TestContext) {
+    ctx.scene_tree
+        .get_tree()
+        .unwrap()
+        .emit_signal("physics_frame".into(), &[]);
+}
+
+#[itest]
+fn defer_physics_fifo_order(ctx: &TestContext) {
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    for i in 0..3 {
+        let log = log.clone();
+        defer_physics(move || log.borrow_mut().push(i));
+    }
+    assert_eq!(deferred_physics_count(), 3);
+    assert!(log.borrow().is_empty(), "not run immediately");
+
+    emit_physics_frame(ctx);
+    assert_eq!(*log.borrow(), [0, 1, 2]);
+    assert_eq!(deferred_physics_count(), 0);
+
+    // Runs only once.
+    emit_physics_frame(ctx);
+    assert_eq!(*log.borrow(), [0, 1, 2]);
+}
+
+#[itest]
+fn defer_physics_coalesces_by_key(ctx: &TestContext) {
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    let push = |value: &'static str| {
+        let log = log.clone();
+        move || log.borrow_mut().push(value)
+    };
+
+    assert!(!defer_physics_coalesced("ui", push("ui 1")));
+    defer_physics(push("other"));
+    assert!(defer_physics_coalesced("ui", push("ui 2")));
+
+    // Same value, different type: not coalesced.
+    assert!(!defer_physics_coalesced(
+        String::from("ui"),
+        push("string key")
+    ));
+    assert_eq!(deferred_physics_count(), 3);
+
+    emit_physics_frame(ctx);
+    assert_eq!(*log.borrow(), ["ui 2", "other", "string key"]);
+}
+
+#[itest]
+fn defer_physics_nested(ctx: &TestContext) {
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    let log_outer = log.clone();
+    defer_physics(move || {
+        log_outer.borrow_mut().push("outer");
+
+        let log_inner = log_outer.clone();
+        defer_physics(move || log_inner.borrow_mut().push("inner"));
+    });
+
+    emit_physics_frame(ctx);
+    assert_eq!(*log.borrow(), ["outer"]);
+
+    emit_physics_frame(ctx);
+    assert_eq!(*log.borrow(), ["outer", "inner"]);
+}
+
+#[itest]
+fn defer_idle_is_queued() {
+    let before = deferred_count();
+
+    defer(|| {});
+    assert!(!defer_coalesced(1u32, || {}));
+    assert!(defer_coalesced(1u32, || {}));
+
+    // Flushed at the end of the frame, by Godot's message queue.
+    assert_eq!(deferred_count(), before + 2);
+}
+
+#[itest]
+fn defer_physics_panic_keeps_remaining(ctx: &TestContext) {
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    let log_before = log.clone();
+    defer_physics(move || log_before.borrow_mut().push("before"));
+    defer_physics(|| panic!("deferred task panics on purpose"));
+    let log_after = log.clone();
+    defer_physics(move || log_after.borrow_mut().push("after"));
+
+    emit_physics_frame(ctx);
+    assert_eq!(*log.borrow(), ["before", "after"]);
+    assert_eq!(deferred_physics_count(), 0);
+}
+
+#[itest]
+fn defer_requires_main_thread() {
+    let result = std::thread::spawn(|| defer(|| {})).join();
+    assert!(result.is_err(), "defer() off the main thread");
+    assert_eq!(deferred_count(), 0);
+}
@@ -0,0 +1,43 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Adding docks requires a running editor; these tests cover the behavior that does not.
+
+use godot::classes::{ConfigFile, Control};
+use godot::obj::{NewAlloc, NewGd};
+use godot::tools::editor::EditorPanels;
+
+use crate::framework::itest;
+
+#[itest]
+fn editor_panels_empty() {
+    let mut panels = EditorPanels::new();
+    assert!(panels.is_empty());
+    assert_eq!(panels.len(), 0);
+
+    // Controls not added through the panels are left alone.
+    let control = Control::new_alloc();
+    assert!(!panels.remove(&control));
+    assert!(control.is_instance_valid());
+    control.free();
+
+    panels.remove_all();
+    assert!(panels.is_empty());
+}
+
+#[itest]
+fn editor_panels_layout_without_docks() {
+    let mut panels = EditorPanels::new();
+    let layout = ConfigFile::new_gd();
+
+    panels.save_layout(&layout);
+    assert!(layout.get_sections().is_empty());
+
+    // Nothing to move, and no plugin to move docks with.
+    panels.restore_layout(&layout);
+    assert!(panels.is_empty());
+}
@@ -0,0 +1,135 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::{Arc, Mutex};
+
+use godot::builtin::{Callable, StringName, Variant};
+use godot::classes::{EditorProperty, Label, Node, Resource};
+use godot::meta::ToGodot;
+use godot::obj::{Gd, NewAlloc, NewGd};
+use godot::register::{godot_api, GodotClass};
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct EditedObject {
+    #[var]
+    resource: Option<Gd<Resource>>,
+}
+
+#[godot_api]
+impl EditedObject {}
+
+#[itest]
+fn editor_property_edited_value() {
+    let mut editor = EditorProperty::new_alloc();
+    assert_eq!(editor.get_edited_value(), Variant::nil());
+    assert_eq!(editor.try_get_edited_value_as::<StringName>(), None);
+
+    let mut node = Node::new_alloc();
+    node.set_name("Edited".into());
+    editor.set_object_and_property(node.clone().upcast(), "name".into());
+
+    assert_eq!(
+        editor.get_edited_value(),
+        StringName::from("Edited").to_variant()
+    );
+    assert_eq!(
+        editor.try_get_edited_value_as::<StringName>(),
+        Some(StringName::from("Edited"))
+    );
+    assert_eq!(editor.try_get_edited_value_as::<i64>(), None);
+
+    editor.free();
+    node.free();
+}
+
+#[itest]
+fn editor_property_set_edited_value() {
+    let mut editor = EditorProperty::new_alloc();
+    let mut node = Node::new_alloc();
+    editor.set_object_and_property(node.clone().upcast(), "name".into());
+
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let changes_in = changes.clone();
+    let record = Callable::from_fn("record", move |args: &[&Variant]| {
+        let property = args[0].to::<StringName>();
+        changes_in.lock().unwrap().push((property, args[1].clone()));
+        Ok(Variant::nil())
+    });
+    editor.connect("property_changed".into(), record);
+
+    editor.set_edited_value(StringName::from("Renamed"));
+
+    let changes = changes.lock().unwrap();
+    assert_eq!(
+        *changes,
+        [(
+            StringName::from("name"),
+            StringName::from("Renamed").to_variant()
+        )]
+    );
+
+    // The editor only submits the value; applying it is up to the inspector.
+    assert_ne!(node.get_name(), StringName::from("Renamed"));
+
+    editor.free();
+    node.free();
+}
+
+#[itest]
+fn editor_property_add_editor_control() {
+    let mut editor = EditorProperty::new_alloc();
+    let label = Label::new_alloc();
+
+    editor.add_editor_control(label.clone());
+
+    assert_eq!(editor.get_child_count(), 1);
+    let child = editor.get_child(0).expect("control is a child");
+    assert_eq!(child.instance_id(), label.instance_id());
+
+    editor.free();
+}
+
+#[itest]
+fn editor_property_refresh_on_signal() {
+    let editor = EditorProperty::new_alloc();
+    let resource = Resource::new_gd();
+
+    editor.refresh_on_signal(&resource, "changed");
+    editor.refresh_on_signal(&resource, "changed");
+
+    let update = Callable::from_object_method(&editor, "update_property");
+    assert!(resource.is_connected("changed".into(), update));
+    assert_eq!(
+        resource.get_signal_connection_list("changed".into()).len(),
+        1
+    );
+
+    editor.free();
+}
+
+#[itest]
+fn editor_property_refresh_on_resource_changed() {
+    let mut editor = EditorProperty::new_alloc();
+    let mut edited = EditedObject::new_alloc();
+    editor.set_object_and_property(edited.clone().upcast(), "resource".into());
+
+    // No resource assigned yet.
+    assert!(!editor.refresh_on_resource_changed());
+
+    let resource = Resource::new_gd();
+    edited.bind_mut().resource = Some(resource.clone());
+    assert!(editor.refresh_on_resource_changed());
+
+    let update = Callable::from_object_method(&editor, "update_property");
+    assert!(resource.is_connected("changed".into(), update));
+
+    editor.free();
+    edited.free();
+}
@@ -0,0 +1,48 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::init::{engine_supports, engine_version, if_godot, EngineVersion, GdextBuild};
+
+use crate::framework::itest;
+
+#[itest]
+fn engine_version_runtime() {
+    let version = engine_version();
+    let (major, minor, patch) = GdextBuild::godot_runtime_version_triple();
+
+    assert_eq!(version, EngineVersion::new(major, minor, patch));
+    assert!(version >= EngineVersion::compiled());
+    assert_eq!(version.to_string(), format!("{major}.{minor}.{patch}"));
+}
+
+#[itest]
+fn engine_supports_interface_fn() {
+    assert!(!engine_supports("no_such_interface_function"));
+    assert!(!engine_supports("with\0null"));
+
+    #[cfg(since_api = "4.1")]
+    assert!(engine_supports("get_godot_version"));
+}
+
+#[itest]
+fn if_godot_branches() {
+    let since_40 = if_godot!(>= 4.0 { true } else { false });
+    assert!(since_40);
+
+    let before_40 = if_godot!(< 4.0 { true } else { false });
+    assert!(!before_40);
+
+    let minor = engine_version().minor;
+    let since_current = if_godot!(>= 4.2 { 1 } else { 0 });
+    assert_eq!(since_current == 1, minor >= 2);
+
+    let mut executed = false;
+    if_godot!(>= 4.0 {
+        executed = true;
+    });
+    assert!(executed);
+}
@@ -0,0 +1,158 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use crate::framework::{expect_panic, itest, TestContext};
+use godot::tools::{
+    add_physics_process_callback, add_process_callback, dispatch_physics_process_callbacks,
+    dispatch_process_callbacks, remove_frame_callback,
+};
+
+#[itest]
+fn frame_callbacks_add_remove() {
+    let elapsed = Rc::new(Cell::new(0.0));
+
+    let elapsed_in = elapsed.clone();
+    let handle = add_process_callback(move |delta| elapsed_in.set(elapsed_in.get() + delta));
+
+    dispatch_process_callbacks(0.25);
+    dispatch_process_callbacks(0.5);
+    assert_eq!(elapsed.get(), 0.75);
+
+    // Physics callbacks are independent.
+    dispatch_physics_process_callbacks(1.0);
+    assert_eq!(elapsed.get(), 0.75);
+
+    assert!(remove_frame_callback(handle));
+    assert!(!remove_frame_callback(handle));
+
+    dispatch_process_callbacks(0.25);
+    assert_eq!(elapsed.get(), 0.75);
+}
+
+#[itest]
+fn frame_callbacks_remove_during_dispatch() {
+    let calls = Rc::new(Cell::new(0));
+    let handle = Rc::new(Cell::new(None));
+
+    let calls_in = calls.clone();
+    let handle_in = handle.clone();
+    let registered = add_physics_process_callback(move |_delta| {
+        calls_in.set(calls_in.get() + 1);
+
+        // Callback removes itself on first invocation.
+        if let Some(handle) = handle_in.take() {
+            remove_frame_callback(handle);
+        }
+    });
+    handle.set(Some(registered));
+
+    dispatch_physics_process_callbacks(0.1);
+    dispatch_physics_process_callbacks(0.1);
+    assert_eq!(calls.get(), 1);
+}
+
+#[itest]
+fn frame_callbacks_remove_stale_during_dispatch() {
+    let stale = add_process_callback(|_delta| {});
+    assert!(remove_frame_callback(stale));
+
+    let results = Rc::new(Cell::new(None));
+    let results_in = results.clone();
+    let handle = add_process_callback(move |_delta| {
+        results_in.set(Some(remove_frame_callback(stale)));
+    });
+
+    dispatch_process_callbacks(0.1);
+    assert_eq!(
+        results.get(),
+        Some(false),
+        "stale handle is not removed again"
+    );
+
+    assert!(remove_frame_callback(handle));
+}
+
+#[itest]
+fn frame_callbacks_survive_panic() {
+    let calls = Rc::new(Cell::new(0));
+
+    let calls_in = calls.clone();
+    let handle = add_process_callback(move |_delta| calls_in.set(calls_in.get() + 1));
+    let panicking = add_process_callback(|_delta| panic!("frame callback panic"));
+
+    expect_panic("panicking frame callback", || {
+        dispatch_process_callbacks(0.1)
+    });
+    assert_eq!(calls.get(), 1);
+
+    // Both callbacks are still registered.
+    assert!(remove_frame_callback(panicking));
+    dispatch_process_callbacks(0.1);
+    assert_eq!(calls.get(), 2);
+
+    assert!(remove_frame_callback(handle));
+}
+
+#[itest]
+fn frame_callbacks_scene_tree_frames(ctx: &TestContext) {
+    let ticks = Rc::new(Cell::new(0));
+    let physics_ticks = Rc::new(Cell::new(0));
+
+    let ticks_in = ticks.clone();
+    let handle = add_process_callback(move |_delta| ticks_in.set(ticks_in.get() + 1));
+    let physics_ticks_in = physics_ticks.clone();
+    let physics_handle = add_physics_process_callback(move |_delta| {
+        physics_ticks_in.set(physics_ticks_in.get() + 1)
+    });
+
+    // Frames as emitted by the SceneTree, which invokes the connected callbacks.
+    let mut tree = ctx.scene_tree.get_tree().unwrap();
+    tree.emit_signal("process_frame".into(), &[]);
+    tree.emit_signal("process_frame".into(), &[]);
+    tree.emit_signal("physics_frame".into(), &[]);
+    assert_eq!(ticks.get(), 2);
+    assert_eq!(physics_ticks.get(), 1);
+
+    assert!(remove_frame_callback(handle));
+    assert!(remove_frame_callback(physics_handle));
+    tree.emit_signal("process_frame".into(), &[]);
+    tree.emit_signal("physics_frame".into(), &[]);
+    assert_eq!(ticks.get(), 2);
+    assert_eq!(physics_ticks.get(), 1);
+}
+
+#[itest]
+fn frame_callbacks_remove_twice_during_dispatch() {
+    let target = add_process_callback(|_delta| {});
+
+    let results = Rc::new(Cell::new(None));
+    let results_in = results.clone();
+    let handle = add_process_callback(move |_delta| {
+        let first = remove_frame_callback(target);
+        let second = remove_frame_callback(target);
+        results_in.set(Some((first, second)));
+    });
+
+    dispatch_process_callbacks(0.1);
+    assert_eq!(results.get(), Some((true, false)));
+
+    assert!(remove_frame_callback(handle));
+}
+
+#[itest]
+fn frame_callbacks_require_main_thread() {
+    std::thread::spawn(|| {
+        expect_panic("frame callback added on other thread", || {
+            add_process_callback(|_delta| {});
+        });
+    })
+    .join()
+    .expect("thread completes");
+}
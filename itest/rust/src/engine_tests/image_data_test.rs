@@ -0,0 +1,70 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::Color;
+use godot::classes::image::Format;
+use godot::classes::Image;
+
+use crate::framework::itest;
+
+#[itest]
+fn image_data_view_typed_pixels() {
+    let mut image = Image::create(3, 2, false, Format::RGBA8).unwrap();
+    image.set_pixel(2, 1, Color::from_rgba8(10, 20, 30, 40));
+
+    let view = image.data_view();
+    assert_eq!((view.width(), view.height()), (3, 2));
+    assert_eq!(view.format(), Format::RGBA8);
+    assert_eq!(view.bytes().len(), 3 * 2 * 4);
+
+    let pixels = view.pixels::<[u8; 4]>().expect("RGBA8 matches [u8; 4]");
+    assert_eq!(pixels.len(), 6);
+    assert_eq!(pixels[5], [10, 20, 30, 40]);
+    assert_eq!(view.row::<[u8; 4]>(1).unwrap()[2], [10, 20, 30, 40]);
+    assert!(view.row::<[u8; 4]>(2).is_none());
+
+    assert!(view.pixels::<[u8; 3]>().is_none());
+    assert!(view.pixels::<Color>().is_none());
+}
+
+#[itest]
+fn image_data_view_float_formats() {
+    let mut image = Image::create(2, 2, false, Format::RGBAF).unwrap();
+    image.set_pixel(0, 1, Color::from_rgba(0.25, 0.5, 0.75, 1.0));
+
+    let view = image.data_view();
+    assert_eq!(
+        view.pixels::<Color>().unwrap()[2],
+        Color::from_rgba(0.25, 0.5, 0.75, 1.0)
+    );
+    assert_eq!(
+        view.pixels::<[f32; 4]>().unwrap()[2],
+        [0.25, 0.5, 0.75, 1.0]
+    );
+
+    let rgba8 = view.to_rgba8().unwrap();
+    assert_eq!(rgba8[2], [64, 128, 191, 255]);
+}
+
+#[itest]
+fn image_data_conversions() {
+    let mut image = Image::create(2, 1, false, Format::LA8).unwrap();
+    image.set_pixel(1, 0, Color::from_rgba8(100, 100, 100, 50));
+
+    let view = image.data_view();
+    assert_eq!(view.to_rgba8().unwrap()[1], [100, 100, 100, 50]);
+    assert_eq!(image.to_rgba8_pixels()[1], [100, 100, 100, 50]);
+    assert_eq!(
+        image.to_color_pixels()[1],
+        Color::from_rgba8(100, 100, 100, 50)
+    );
+
+    // Not convertible in Rust; falls back to Godot.
+    let image = Image::create(3, 2, false, Format::RGB565).unwrap();
+    assert!(image.data_view().to_rgba8().is_none());
+    assert_eq!(image.to_rgba8_pixels().len(), 6);
+}
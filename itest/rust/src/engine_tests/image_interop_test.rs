@@ -0,0 +1,58 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::Color;
+use godot::classes::image::Format;
+use godot::classes::Image;
+use image::{DynamicImage, ImageBuffer, Luma, Rgba};
+
+use crate::framework::itest;
+
+#[itest]
+fn image_to_dynamic_rgba8() {
+    let mut godot_image = Image::create(2, 1, false, Format::RGBA8).unwrap();
+    godot_image.set_pixel(1, 0, Color::from_rgba8(10, 20, 30, 40));
+
+    let DynamicImage::ImageRgba8(buffer) = godot_image.to_dynamic_image() else {
+        panic!("RGBA8 maps to ImageRgba8");
+    };
+    assert_eq!(buffer.dimensions(), (2, 1));
+    assert_eq!(buffer.get_pixel(1, 0), &Rgba([10, 20, 30, 40]));
+
+    let sum = godot_image
+        .with_rgba8_view(|view| view.pixels().map(|p| p.0[0] as u32).sum::<u32>())
+        .unwrap();
+    assert_eq!(sum, 10);
+}
+
+#[itest]
+fn image_to_dynamic_converts_other_formats() {
+    let godot_image = Image::create(3, 2, false, Format::RGB565).unwrap();
+
+    let converted = godot_image.to_dynamic_image();
+    assert!(matches!(converted, DynamicImage::ImageRgba8(_)));
+    assert_eq!((converted.width(), converted.height()), (3, 2));
+
+    assert!(godot_image.with_rgba8_view(|_| ()).is_none());
+}
+
+#[itest]
+fn image_from_dynamic_roundtrip() {
+    let luma = ImageBuffer::from_fn(4, 4, |x, y| Luma([(x + 4 * y) as u8]));
+    let dynamic = DynamicImage::ImageLuma8(luma);
+
+    let godot_image = Image::from_dynamic_image(&dynamic);
+    assert_eq!(godot_image.get_format(), Format::L8);
+    assert_eq!(godot_image.get_width(), 4);
+
+    let back = godot_image.to_dynamic_image();
+    assert_eq!(back, dynamic);
+
+    // 16-bit images become float.
+    let wide = DynamicImage::new_rgb16(2, 2);
+    assert_eq!(Image::from_dynamic_image(&wide).get_format(), Format::RGBAF);
+}
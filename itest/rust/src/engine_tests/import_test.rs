@@ -0,0 +1,88 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{dict, Dictionary, GString, Variant};
+use godot::global::{PropertyHint, PropertyUsageFlags};
+use godot::meta::ToGodot;
+use godot::obj::{EngineBitfield, EngineEnum};
+use godot::tools::import::{import_options, ImportOption, ImportOptionSet};
+
+use crate::framework::itest;
+
+import_options! {
+    /// Options for a test importer.
+    #[derive(Debug, PartialEq)]
+    struct TestImportOptions {
+        scale: f32 = 1.0 => .range(0.5, 2.0, 0.1),
+        flip_y: bool = false,
+        mode: i64 = 1 => .enum_names(&["Fast", "Precise"]),
+        prefix: GString = GString::from("mesh_"),
+    }
+}
+
+fn entry(options: &[Dictionary], name: &str) -> Dictionary {
+    options
+        .iter()
+        .find(|dict| dict.get("name") == Some(name.to_variant()))
+        .unwrap_or_else(|| panic!("option {name} exists"))
+        .clone()
+}
+
+#[itest]
+fn import_options_list() {
+    let options: Vec<Dictionary> = TestImportOptions::import_options().iter_shared().collect();
+    assert_eq!(options.len(), 4);
+
+    let scale = entry(&options, "scale");
+    assert_eq!(scale.get("default_value"), Some(1.0f32.to_variant()));
+    assert_eq!(
+        scale.get("property_hint"),
+        Some(PropertyHint::RANGE.ord().to_variant())
+    );
+    assert_eq!(scale.get("hint_string"), Some("0.5,2,0.1".to_variant()));
+
+    let flip = entry(&options, "flip_y");
+    assert_eq!(flip.get("default_value"), Some(false.to_variant()));
+    assert_eq!(flip.get("property_hint"), None);
+
+    let mode = entry(&options, "mode");
+    assert_eq!(mode.get("hint_string"), Some("Fast,Precise".to_variant()));
+}
+
+#[itest]
+fn import_options_from_dictionary() {
+    let parsed = TestImportOptions::from_import_options(&dict! {
+        "scale": 1.5,
+        "flip_y": true,
+    })
+    .expect("valid options");
+
+    assert_eq!(
+        parsed,
+        TestImportOptions {
+            scale: 1.5,
+            flip_y: true,
+            ..Default::default()
+        }
+    );
+
+    let err =
+        TestImportOptions::from_import_options(&dict! { "flip_y": "yes" }).expect_err("wrong type");
+    assert!(err.to_string().contains("flip_y"), "{err}");
+}
+
+#[itest]
+fn import_option_usage() {
+    let dict = ImportOption::new("hidden", Variant::nil())
+        .usage(PropertyUsageFlags::STORAGE)
+        .to_dictionary();
+
+    assert_eq!(
+        dict.get("usage"),
+        Some((PropertyUsageFlags::STORAGE.ord() as i64).to_variant())
+    );
+}
@@ -0,0 +1,100 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::StringName;
+use godot::classes::{InputEventJoypadButton, InputEventKey, InputMap};
+use godot::global::{JoyAxis, JoyButton, Key, MouseButton};
+use godot::tools::{input_actions, InputAction, InputActions};
+
+use crate::framework::itest;
+
+input_actions! {
+    struct TestActions {
+        JUMP = "itest_jump" {
+            key(Key::SPACE),
+            gamepad_button(JoyButton::A),
+        }
+        STEER = "itest_steer" {
+            gamepad_axis(JoyAxis::LEFT_X, -1.0),
+            deadzone(0.25),
+        }
+    }
+}
+
+fn action_events(name: &str) -> usize {
+    InputMap::singleton()
+        .action_get_events(StringName::from(name))
+        .len()
+}
+
+#[itest]
+fn input_actions_define_and_register() {
+    let name = "itest_define";
+    InputActions::define(name)
+        .physical_key(Key::W)
+        .mouse_button(MouseButton::LEFT)
+        .deadzone(0.1)
+        .register();
+
+    let mut input_map = InputMap::singleton();
+    assert!(input_map.has_action(name.into()));
+    assert_eq!(action_events(name), 2);
+    assert_eq!(input_map.action_get_deadzone(name.into()), 0.1);
+
+    let events = input_map.action_get_events(name.into());
+    let key = events.get(0).unwrap().cast::<InputEventKey>();
+    assert_eq!(key.get_physical_keycode(), Key::W);
+    assert_eq!(key.get_device(), -1);
+
+    assert!(InputActions::erase(name));
+    assert!(!InputActions::erase(name));
+    assert!(!input_map.has_action(name.into()));
+}
+
+#[itest]
+fn input_actions_reregister_replaces() {
+    let name = "itest_reregister";
+    InputActions::define(name)
+        .key(Key::A)
+        .key(Key::B)
+        .register();
+    assert_eq!(action_events(name), 2);
+
+    // Simulates hot reload with changed bindings.
+    InputActions::define(name).key(Key::C).register();
+    assert_eq!(action_events(name), 1);
+
+    InputActions::erase(name);
+}
+
+#[itest]
+fn input_actions_macro() {
+    const CUSTOM: InputAction = InputAction::new("itest_jump");
+    assert_eq!(TestActions::JUMP, CUSTOM);
+    assert_eq!(TestActions::all(), &[TestActions::JUMP, TestActions::STEER]);
+    assert!(!TestActions::JUMP.exists());
+
+    TestActions::register();
+    TestActions::register();
+    assert!(TestActions::JUMP.exists());
+    assert_eq!(action_events("itest_jump"), 2);
+    assert_eq!(
+        InputMap::singleton().action_get_deadzone(TestActions::STEER.into()),
+        0.25
+    );
+
+    let events = InputMap::singleton().action_get_events(TestActions::JUMP.into());
+    let button = events.get(1).unwrap().cast::<InputEventJoypadButton>();
+    assert_eq!(button.get_button_index(), JoyButton::A);
+
+    assert!(!TestActions::JUMP.is_pressed());
+    assert_eq!(TestActions::STEER.strength(), 0.0);
+
+    for action in TestActions::all() {
+        InputActions::erase(*action);
+    }
+}
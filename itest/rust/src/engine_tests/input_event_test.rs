@@ -0,0 +1,50 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::{
+    InputEvent, InputEventAction, InputEventKey, InputEventKind, InputEventMouseButton,
+    InputEventMouseMotion,
+};
+use godot::global::Key;
+use godot::obj::{Gd, NewGd};
+
+use crate::framework::itest;
+
+#[itest]
+fn input_event_dispatch_key() {
+    let mut key = InputEventKey::new_gd();
+    key.set_keycode(Key::A);
+
+    match key.clone().upcast::<InputEvent>().dispatch() {
+        InputEventKind::Key(dispatched) => {
+            assert_eq!(dispatched, key);
+            assert_eq!(dispatched.get_keycode(), Key::A);
+        }
+        other => panic!("expected key event, got {other:?}"),
+    }
+}
+
+#[itest]
+fn input_event_dispatch_mouse() {
+    let button: Gd<InputEvent> = InputEventMouseButton::new_gd().upcast();
+    assert!(matches!(button.dispatch(), InputEventKind::MouseButton(_)));
+
+    let motion: Gd<InputEvent> = InputEventMouseMotion::new_gd().upcast();
+    assert!(matches!(
+        InputEventKind::from(motion),
+        InputEventKind::MouseMotion(_)
+    ));
+}
+
+#[itest]
+fn input_event_dispatch_roundtrip() {
+    let action: Gd<InputEvent> = InputEventAction::new_gd().upcast();
+
+    let kind = action.clone().dispatch();
+    assert!(matches!(kind, InputEventKind::Action(_)));
+    assert_eq!(kind.into_event(), action);
+}
@@ -0,0 +1,31 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::tools::logging::{GodotLogger, LevelFilter};
+
+use crate::framework::itest;
+
+#[itest]
+fn logging_godot_logger_installs() {
+    let result = GodotLogger::new()
+        .max_level(LevelFilter::Info)
+        .target("itest::verbose", LevelFilter::Trace)
+        .target("itest::silent", LevelFilter::Off)
+        .init();
+
+    // Only one logger can be installed per process.
+    if result.is_err() {
+        return;
+    }
+
+    assert_eq!(log::max_level(), LevelFilter::Trace, "most verbose target");
+    assert!(log::log_enabled!(target: "itest::verbose::inner", log::Level::Trace));
+    assert!(!log::log_enabled!(target: "itest::silent", log::Level::Error));
+    assert!(!log::log_enabled!(target: "itest", log::Level::Debug));
+
+    log::info!(target: "itest", "message from the log crate");
+}
@@ -5,11 +5,56 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+#[cfg(feature = "experimental-threads")]
+mod async_load_test;
+mod audio_test;
 mod codegen_enums_test;
 mod codegen_test;
+mod compute_test;
+#[cfg(feature = "config")]
+mod config_test;
+#[cfg(since_api = "4.2")]
+mod debug_draw_test;
+#[cfg(since_api = "4.2")]
+mod defer_test;
+mod editor_panels_test;
+#[cfg(since_api = "4.2")]
+mod editor_property_test;
+mod engine_version_test;
+#[cfg(since_api = "4.2")]
+mod frame_callbacks_test;
 mod gfile_test;
+mod image_data_test;
+#[cfg(feature = "image")]
+mod image_interop_test;
+mod import_test;
+mod input_actions_test;
+mod input_event_test;
+#[cfg(feature = "log")]
+mod logging_test;
 mod native_structures_test;
+mod navigation_test;
 mod node_test;
+mod optional_method_test;
+mod physics_query_test;
+mod pool_test;
 mod save_load_test;
+mod scene_test;
+mod settings_test;
+mod shader_test;
+mod snapshot_test;
+mod startup_test;
+mod sys_ext_test;
+mod table_test;
+#[cfg(since_api = "4.2")]
+mod threadsafe_test;
+#[cfg(since_api = "4.2")]
+mod timers_test;
 mod translate_test;
+#[cfg(since_api = "4.2")]
+mod tween_test;
+mod typed_scene_test;
+mod undo_scope_test;
 mod utilities_test;
+#[cfg(all(feature = "experimental-threads", since_api = "4.2"))]
+mod worker_test;
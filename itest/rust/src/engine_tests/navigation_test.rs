@@ -0,0 +1,94 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Rid, Vector2, Vector3};
+use godot::classes::{NavigationMesh, NavigationServer2D, NavigationServer3D, World3D};
+use godot::obj::NewGd;
+use godot::tools::navigation::{NavAgent3D, NavMap2D, NavMap3D, NavRegion3D};
+
+use crate::framework::itest;
+
+#[itest]
+fn navigation_map_ownership() {
+    let map = NavMap3D::new();
+    let rid = map.rid();
+    assert!(rid.is_valid());
+    assert!(map.is_owned());
+
+    map.set_active(true);
+    assert!(map.is_active());
+    drop(map);
+
+    // Freed with the wrapper; the server no longer knows the map.
+    assert!(!NavigationServer3D::singleton().get_maps().contains(&rid));
+
+    // Unowned maps are kept alive.
+    let world = World3D::new_gd();
+    let world_map = NavMap3D::of_world(&world);
+    assert!(!world_map.is_owned());
+    let world_rid = world_map.rid();
+    drop(world_map);
+    assert!(NavigationServer3D::singleton()
+        .get_maps()
+        .contains(world_rid));
+}
+
+#[itest]
+fn navigation_into_rid_releases_ownership() {
+    let map = NavMap2D::new();
+    let rid = map.into_rid();
+    assert!(NavigationServer2D::singleton().get_maps().contains(&rid));
+
+    NavigationServer2D::singleton().free_rid(rid);
+}
+
+#[itest]
+fn navigation_region_and_agent_membership() {
+    let map = NavMap3D::new();
+    map.set_active(true);
+
+    let region = NavRegion3D::new();
+    region.set_map(&map);
+    region.set_navigation_mesh(NavigationMesh::new_gd());
+
+    let agent = NavAgent3D::new();
+    agent.set_map(&map);
+    agent.set_position(Vector3::new(1.0, 0.0, 1.0));
+
+    // Map membership is applied on synchronization.
+    NavigationServer3D::singleton().map_force_update(map.rid());
+    assert_eq!(map.region_rids(), vec![region.rid()]);
+    assert_eq!(map.agent_rids(), vec![agent.rid()]);
+
+    region.clear_map();
+    agent.clear_map();
+    NavigationServer3D::singleton().map_force_update(map.rid());
+    assert!(map.region_rids().is_empty());
+    assert!(map.agent_rids().is_empty());
+}
+
+#[itest]
+fn navigation_path_on_empty_map() {
+    let map = NavMap2D::new();
+    map.set_active(true);
+    NavigationServer2D::singleton().map_force_update(map.rid());
+
+    let path = map.path(Vector2::ZERO, Vector2::new(100.0, 0.0));
+    assert!(path.is_empty());
+    assert_ne!(map.rid(), Rid::Invalid);
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn navigation_map_changed_subscription() {
+    let map = NavMap3D::new();
+    let handle = map.on_changed(|| {});
+    assert!(handle.unsubscribe());
+
+    let changed = map.changed();
+    assert!(!changed.is_changed());
+}
@@ -10,7 +10,7 @@ use std::str::FromStr;
 use godot::builtin::{NodePath, Variant};
 use godot::classes::{Node, Node3D, PackedScene, SceneTree};
 use godot::global;
-use godot::obj::{NewAlloc, NewGd};
+use godot::obj::{Gd, NewAlloc, NewGd};
 
 use crate::framework::{itest, TestContext};
 
@@ -51,6 +51,85 @@ fn node_get_node_fail() {
     child.free();
 }
 
+#[itest]
+fn node_find_children_of_type() {
+    let root = make_tree();
+
+    let names = root
+        .find_children_of_type::<Node3D>()
+        .iter()
+        .map(|node| node.get_name().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["Orc", "Hitbox", "Goblin", "Hitbox"]);
+
+    let hitbox = root.get_node_as::<Node>(NodePath::from("Enemies/Goblin/Hitbox"));
+    let enemy = hitbox.ancestor_of_type::<Node3D>().expect("ancestor found");
+    assert_eq!(enemy.get_name().to_string(), "Goblin");
+    assert!(root.ancestor_of_type::<Node>().is_none());
+
+    root.free();
+}
+
+#[itest]
+fn node_query() {
+    let root = make_tree();
+    let paths = |pattern: &str| {
+        root.query(pattern)
+            .iter()
+            .map(|node| root.get_path_to(node.clone()).to_string())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        paths("Enemies/*/Hitbox"),
+        ["Enemies/Orc/Hitbox", "Enemies/Goblin/Hitbox"]
+    );
+    assert_eq!(paths("Enemies/?rc"), ["Enemies/Orc"]);
+    assert_eq!(paths("**/Hitbox").len(), 2);
+    assert_eq!(paths("Enemies/*/Hitbox/..").len(), 2);
+    assert_eq!(paths("**/*Spawn"), ["Spawns/PlayerSpawn"]);
+    assert_eq!(paths("."), ["."]);
+    assert!(paths("Enemies/Troll").is_empty());
+
+    assert_eq!(root.query_as::<Node3D>("Enemies/*").len(), 2);
+
+    root.free();
+}
+
+/// Creates a tree:
+/// ```text
+/// Root
+/// ├─ Enemies
+/// │  ├─ Orc (Node3D)
+/// │  │  └─ Hitbox (Node3D)
+/// │  └─ Goblin (Node3D)
+/// │     └─ Hitbox (Node3D)
+/// └─ Spawns
+///    └─ PlayerSpawn
+/// ```
+fn make_tree() -> Gd<Node> {
+    let named = |name: &str, node: Gd<Node>| {
+        let mut node = node;
+        node.set_name(name.into());
+        node
+    };
+
+    let mut root = named("Root", Node::new_alloc());
+    let mut enemies = named("Enemies", Node::new_alloc());
+    let mut spawns = named("Spawns", Node::new_alloc());
+
+    for enemy_name in ["Orc", "Goblin"] {
+        let mut enemy = named(enemy_name, Node3D::new_alloc().upcast());
+        enemy.add_child(named("Hitbox", Node3D::new_alloc().upcast()));
+        enemies.add_child(enemy);
+    }
+
+    spawns.add_child(named("PlayerSpawn", Node::new_alloc()));
+    root.add_child(enemies);
+    root.add_child(spawns);
+    root
+}
+
 #[itest]
 fn node_path_from_str(ctx: &TestContext) {
     let child = ctx.scene_tree.clone();
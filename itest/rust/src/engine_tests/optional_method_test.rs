@@ -0,0 +1,85 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::Variant;
+use godot::classes::{Node, Node2D, Object};
+use godot::obj::NewAlloc;
+use godot::tools::{OptionalCallError, OptionalMethod};
+
+use crate::framework::itest;
+
+// Hash of `String Object::get_class() const`, stable across 4.x.
+static GET_CLASS: OptionalMethod = OptionalMethod::new("Object", "get_class", 201_670_096);
+
+static WRONG_HASH: OptionalMethod = OptionalMethod::new("Object", "get_class", 1);
+
+// Hash of `int Node::get_child_count(bool include_internal = false) const`.
+static GET_CHILD_COUNT: OptionalMethod =
+    OptionalMethod::new("Node", "get_child_count", 894_402_480);
+
+static MISSING: OptionalMethod =
+    OptionalMethod::new("Object", "method_that_does_not_exist", 201_670_096);
+
+#[itest]
+fn optional_method_available() {
+    assert!(GET_CLASS.is_available());
+
+    let node = Node::new_alloc();
+    let class = GET_CLASS.call(&node, &[]).expect("method is available");
+    assert_eq!(class, Variant::from("Node"));
+
+    node.free();
+}
+
+#[itest]
+fn optional_method_unavailable() {
+    assert!(!WRONG_HASH.is_available());
+    assert!(!MISSING.is_available());
+
+    let object = Object::new_alloc();
+    let err = MISSING.call(&object, &[]).unwrap_err();
+    assert!(matches!(
+        err,
+        OptionalCallError::Unavailable {
+            method_name: "method_that_does_not_exist",
+            ..
+        }
+    ));
+    assert!(err.to_string().contains("not available"));
+
+    object.free();
+}
+
+#[itest]
+fn optional_method_wrong_args() {
+    let object = Object::new_alloc();
+    let err = GET_CLASS.call(&object, &[Variant::from(1)]).unwrap_err();
+    assert!(matches!(err, OptionalCallError::Call(_)));
+
+    object.free();
+}
+
+#[itest]
+fn optional_method_wrong_class() {
+    let object = Object::new_alloc();
+    let err = GET_CHILD_COUNT.call(&object, &[]).unwrap_err();
+    assert!(matches!(
+        err,
+        OptionalCallError::WrongClass {
+            class_name: "Node",
+            ref actual_class,
+            ..
+        } if actual_class == "Object"
+    ));
+    object.free();
+
+    // Derived classes are accepted.
+    let node = Node2D::new_alloc();
+    let count = GET_CHILD_COUNT.call(&node, &[Variant::from(false)]);
+    assert_eq!(count.expect("Node instance"), Variant::from(0));
+    node.free();
+}
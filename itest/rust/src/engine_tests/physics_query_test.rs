@@ -0,0 +1,170 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Rid, Transform2D, Vector2};
+use godot::classes::physics_server_2d::BodyMode;
+use godot::classes::{
+    CircleShape2D, IPhysicsDirectBodyState2DExtension, Object, PhysicsDirectBodyState2D,
+    PhysicsDirectBodyState2DExtension, PhysicsDirectSpaceState2D, PhysicsServer2D, World2D,
+};
+use godot::obj::{Base, Gd, NewAlloc, NewGd};
+use godot::register::{godot_api, GodotClass};
+use godot::tools::{Contact2D, RayQuery2D, ShapeQuery2D};
+
+use crate::framework::itest;
+
+fn empty_space() -> (Gd<World2D>, Gd<PhysicsDirectSpaceState2D>) {
+    // Keep world alive, as the space is freed together with it.
+    let world = World2D::new_gd();
+    let space = world
+        .get_direct_space_state()
+        .expect("world has direct space state");
+
+    (world, space)
+}
+
+#[itest]
+fn physics_ray_query_no_hit() {
+    let (_world, mut space) = empty_space();
+
+    let hit = RayQuery2D::new(Vector2::ZERO, Vector2::new(100.0, 0.0))
+        .mask(0b101)
+        .collide_with_areas(true)
+        .cast(&mut space);
+
+    assert!(hit.is_none());
+}
+
+#[itest]
+fn physics_shape_query_no_hit() {
+    let (_world, mut space) = empty_space();
+
+    let mut circle = CircleShape2D::new_gd();
+    circle.set_radius(10.0);
+
+    let query = ShapeQuery2D::new(circle.upcast(), Transform2D::IDENTITY)
+        .motion(Vector2::new(50.0, 0.0))
+        .max_results(4);
+
+    let hits = query.intersect(&mut space);
+    assert!(hits.is_empty());
+
+    let motion = query.cast_motion(&mut space).expect("valid shape");
+    assert!(motion.is_free());
+    assert_eq!(motion.unsafe_fraction, 1.0);
+}
+
+#[itest]
+fn physics_body_state_bulk_helpers() {
+    let (world, _space) = empty_space();
+    let mut server = PhysicsServer2D::singleton();
+
+    let body = server.body_create();
+    server.body_set_mode(body, BodyMode::RIGID);
+    server.body_set_space(body, world.get_space());
+
+    let mut state = server
+        .body_get_direct_state(body)
+        .expect("body in space has direct state");
+
+    assert!(state.contacts().is_empty());
+    assert_eq!(state.total_contact_impulse(), Vector2::ZERO);
+
+    // Default mass is 1, so the linear velocity is the sum of the impulses.
+    state.apply_impulses(&[
+        (Vector2::new(10.0, 0.0), Vector2::ZERO),
+        (Vector2::new(0.0, 5.0), Vector2::ZERO),
+    ]);
+    assert_eq!(state.get_linear_velocity(), Vector2::new(10.0, 5.0));
+
+    server.free_rid(body);
+}
+
+/// Body state of a custom physics server, which serves its contacts from a `Vec`.
+#[derive(GodotClass)]
+#[class(init, base = PhysicsDirectBodyState2DExtension)]
+struct ContactBodyState {
+    contacts: Vec<Contact2D>,
+    base: Base<PhysicsDirectBodyState2DExtension>,
+}
+
+impl ContactBodyState {
+    fn contact(&self, index: i32) -> &Contact2D {
+        &self.contacts[index as usize]
+    }
+}
+
+#[godot_api]
+impl IPhysicsDirectBodyState2DExtension for ContactBodyState {
+    fn get_contact_count(&self) -> i32 {
+        self.contacts.len() as i32
+    }
+
+    fn get_contact_local_position(&self, contact_idx: i32) -> Vector2 {
+        self.contact(contact_idx).local_position
+    }
+
+    fn get_contact_local_normal(&self, contact_idx: i32) -> Vector2 {
+        self.contact(contact_idx).local_normal
+    }
+
+    fn get_contact_local_shape(&self, contact_idx: i32) -> i32 {
+        self.contact(contact_idx).local_shape
+    }
+
+    fn get_contact_collider_object(&self, contact_idx: i32) -> Option<Gd<Object>> {
+        self.contact(contact_idx).collider.clone()
+    }
+
+    fn get_contact_collider(&self, contact_idx: i32) -> Rid {
+        self.contact(contact_idx).collider_rid
+    }
+
+    fn get_contact_collider_position(&self, contact_idx: i32) -> Vector2 {
+        self.contact(contact_idx).collider_position
+    }
+
+    fn get_contact_collider_shape(&self, contact_idx: i32) -> i32 {
+        self.contact(contact_idx).collider_shape
+    }
+
+    fn get_contact_collider_velocity_at_position(&self, contact_idx: i32) -> Vector2 {
+        self.contact(contact_idx).collider_velocity_at_position
+    }
+
+    fn get_contact_impulse(&self, contact_idx: i32) -> Vector2 {
+        self.contact(contact_idx).impulse
+    }
+}
+
+#[itest]
+fn physics_body_state_contacts() {
+    let collider = Object::new_alloc();
+    let contact = |index: i32| Contact2D {
+        local_position: Vector2::new(index as f32, 0.0),
+        local_normal: Vector2::UP,
+        local_shape: index,
+        collider: Some(collider.clone()),
+        collider_rid: Rid::new(index as u64 + 1),
+        collider_position: Vector2::new(index as f32, 1.0),
+        collider_shape: 0,
+        collider_velocity_at_position: Vector2::ZERO,
+        impulse: Vector2::new(0.0, index as f32),
+    };
+    let expected = vec![contact(1), contact(2)];
+
+    let mut custom = ContactBodyState::new_alloc();
+    custom.bind_mut().contacts = expected.clone();
+
+    // Contacts are read through the engine API, which dispatches to the virtual functions above.
+    let state = custom.clone().upcast::<PhysicsDirectBodyState2D>();
+    assert_eq!(state.contacts(), expected);
+    assert_eq!(state.total_contact_impulse(), Vector2::new(0.0, 3.0));
+
+    custom.free();
+    collider.free();
+}
@@ -0,0 +1,122 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::{Node, Node3D, PackedScene, RefCounted};
+use godot::global::Error;
+use godot::obj::{Base, Gd, NewAlloc, NewGd};
+use godot::register::GodotClass;
+use godot::tools::pool::{IPoolable, Pool};
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct PooledCounter {
+    acquired: i32,
+    released: i32,
+    base: Base<RefCounted>,
+}
+
+impl IPoolable for PooledCounter {
+    fn on_acquire(&mut self) {
+        self.acquired += 1;
+    }
+
+    fn on_release(&mut self) {
+        self.released += 1;
+    }
+}
+
+#[itest]
+fn pool_refcounted_reuse() {
+    let mut pool = Pool::<PooledCounter>::new_default().with_poolable_hooks();
+    pool.prewarm(2);
+    assert_eq!(pool.available_count(), 2);
+    assert_eq!(pool.created_count(), 2);
+
+    let first = pool.acquire();
+    let first_id = first.instance_id();
+    assert_eq!(first.bind().acquired, 1);
+
+    pool.release(first);
+    assert_eq!(pool.available_count(), 2);
+
+    let again = pool.acquire();
+    assert_eq!(again.instance_id(), first_id, "released object reused");
+    assert_eq!(again.bind().acquired, 2);
+    assert_eq!(again.bind().released, 1);
+    assert_eq!(pool.created_count(), 2);
+}
+
+#[itest]
+fn pool_node_detach_and_free() {
+    let mut parent = Node::new_alloc();
+    let mut pool = Pool::<Node3D>::new_default().with_max_size(1);
+
+    let a = pool.acquire();
+    let b = pool.acquire();
+    parent.add_child(a.clone().upcast());
+    parent.add_child(b.clone().upcast());
+
+    pool.release(a.clone());
+    assert_eq!(a.get_parent(), None, "released node is detached");
+    assert_eq!(parent.get_child_count(), 1);
+
+    // Exceeds max size: destroyed.
+    pool.release(b.clone());
+    assert!(!b.is_instance_valid());
+
+    drop(pool);
+    assert!(!a.is_instance_valid(), "pooled nodes freed on drop");
+
+    parent.free();
+}
+
+#[itest]
+fn pool_skips_freed_nodes() {
+    let mut pool = Pool::<Node>::new_default();
+    pool.prewarm(1);
+
+    let node = pool.acquire();
+    let id = node.instance_id();
+    pool.release(node.clone());
+    node.free();
+
+    let fresh = pool.acquire();
+    assert_ne!(fresh.instance_id(), id);
+    fresh.free();
+}
+
+#[itest]
+fn pool_from_scene_with_hooks() {
+    let root = Node3D::new_alloc();
+    let mut scene = PackedScene::new_gd();
+    assert_eq!(scene.pack(root.clone().upcast()), Error::OK);
+    root.free();
+
+    let mut pool = Pool::<Node3D>::from_scene(scene)
+        .on_acquire(|node| node.set_visible(true))
+        .on_release(|node| node.set_visible(false));
+
+    let node = pool.acquire();
+    assert!(node.is_visible());
+
+    pool.release(node.clone());
+    assert!(!node.is_visible());
+}
+
+#[itest]
+fn pool_refcounted_dropped_without_release() {
+    let mut pool = Pool::<RefCounted>::new_default();
+
+    let obj = pool.acquire();
+    drop(obj);
+
+    // Nothing to reuse, a new object is created.
+    let _obj = pool.acquire();
+    assert_eq!(pool.created_count(), 2);
+}
@@ -0,0 +1,222 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Variant, VariantType};
+use godot::classes::{Button, Label, Node, Sprite2D, VBoxContainer};
+use godot::tools::scene::{
+    NodeSpec, SceneBuildErrorKind, SceneBuilder, SceneChange, SceneConnection, SceneDiff,
+};
+
+use crate::framework::itest;
+
+fn menu_spec(title: &str) -> NodeSpec {
+    NodeSpec::of::<VBoxContainer>("Menu")
+        .child(NodeSpec::of::<Label>("Title").property("text", title))
+        .child(
+            NodeSpec::of::<Button>("Start")
+                .property("text", "Start")
+                .group("buttons")
+                .connect("pressed", "..", "queue_free"),
+        )
+}
+
+#[itest]
+fn scene_builder_instantiate() {
+    let menu = SceneBuilder::new(menu_spec("Main menu"))
+        .instantiate()
+        .expect("valid scene");
+
+    assert_eq!(menu.get_class(), "VBoxContainer".into());
+    assert_eq!(menu.get_child_count(), 2);
+
+    let title = menu.get_node_as::<Label>("Title");
+    assert_eq!(title.get_text(), "Main menu".into());
+    assert_eq!(title.get_owner(), Some(menu.clone()));
+
+    let start = menu.get_node_as::<Node>("Start");
+    assert!(start.is_in_group("buttons".into()));
+    assert!(start.is_connected(
+        "pressed".into(),
+        godot::builtin::Callable::from_object_method(&menu, "queue_free")
+    ));
+
+    menu.free();
+}
+
+#[itest]
+fn scene_builder_pack() {
+    let scene = SceneBuilder::new(menu_spec("Main menu"))
+        .build()
+        .expect("valid scene");
+
+    let state = scene.get_state().unwrap();
+    assert_eq!(state.get_node_count(), 3);
+    assert_eq!(state.get_connection_count(), 1);
+
+    let menu = scene.instantiate().expect("instantiate");
+    assert_eq!(
+        menu.get_node_as::<Label>("Title").get_text(),
+        "Main menu".into()
+    );
+    menu.free();
+}
+
+#[itest]
+fn scene_builder_null_object_property() {
+    let spec = NodeSpec::of::<Node>("Root")
+        .child(NodeSpec::of::<Sprite2D>("Sprite").property("texture", Variant::nil()));
+
+    let root = SceneBuilder::new(spec)
+        .instantiate()
+        .expect("null is a valid object value");
+
+    assert!(root
+        .get_node_as::<Sprite2D>("Sprite")
+        .get_texture()
+        .is_none());
+    root.free();
+}
+
+#[itest]
+fn scene_builder_nested_connections() {
+    let spec = NodeSpec::of::<Node>("Root")
+        .child(NodeSpec::of::<Node>("Empty"))
+        .child(
+            NodeSpec::of::<VBoxContainer>("Box").child(NodeSpec::of::<Button>("Ok").connect(
+                "pressed",
+                "../..",
+                "queue_free",
+            )),
+        );
+
+    let root = SceneBuilder::new(spec).instantiate().expect("valid scene");
+
+    let ok = root.get_node_as::<Button>("Box/Ok");
+    assert!(ok.is_connected(
+        "pressed".into(),
+        godot::builtin::Callable::from_object_method(&root, "queue_free")
+    ));
+    root.free();
+}
+
+#[itest]
+fn scene_builder_errors() {
+    let err = SceneBuilder::new(NodeSpec::new("NoSuchClass", "Root"))
+        .build()
+        .unwrap_err();
+    assert_eq!(err.node_path(), "Root");
+    assert!(matches!(
+        err.kind(),
+        SceneBuildErrorKind::UnknownClass { .. }
+    ));
+
+    let err = SceneBuilder::new(NodeSpec::new("RefCounted", "Root"))
+        .build()
+        .unwrap_err();
+    assert!(matches!(err.kind(), SceneBuildErrorKind::NotANode { .. }));
+
+    let spec =
+        NodeSpec::of::<Node>("Root").child(NodeSpec::of::<Label>("Title").property("txt", "x"));
+    let err = SceneBuilder::new(spec).build().unwrap_err();
+    assert_eq!(err.node_path(), "Root/Title");
+    assert_eq!(
+        err.to_string(),
+        "node `Root/Title`: class Label has no property `txt`"
+    );
+
+    let spec = NodeSpec::of::<Label>("Root").property("text", 42);
+    let err = SceneBuilder::new(spec).build().unwrap_err();
+    assert_eq!(
+        err.kind(),
+        &SceneBuildErrorKind::PropertyTypeMismatch {
+            property: "text".to_string(),
+            expected: VariantType::STRING,
+            actual: VariantType::INT,
+        }
+    );
+
+    let spec = NodeSpec::of::<Node>("Root")
+        .child(NodeSpec::of::<Node>("A"))
+        .child(NodeSpec::of::<Node>("A"));
+    let err = SceneBuilder::new(spec).build().unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        SceneBuildErrorKind::DuplicateName { .. }
+    ));
+
+    let spec = NodeSpec::of::<Button>("Root").connect("pressed", "Missing", "queue_free");
+    let err = SceneBuilder::new(spec).build().unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        SceneBuildErrorKind::TargetNotFound { .. }
+    ));
+
+    let spec = NodeSpec::of::<Button>("Root").connect("pressed", ".", "no_such_method");
+    let err = SceneBuilder::new(spec).build().unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        SceneBuildErrorKind::UnknownMethod { .. }
+    ));
+}
+
+#[itest]
+fn scene_diff() {
+    let old = SceneBuilder::new(menu_spec("Main menu")).build().unwrap();
+    assert!(SceneDiff::between(&old, &old).is_empty());
+
+    let new = SceneBuilder::new(
+        NodeSpec::of::<VBoxContainer>("Menu")
+            .child(NodeSpec::of::<Label>("Title").property("text", "Pause"))
+            .child(NodeSpec::of::<Button>("Quit")),
+    )
+    .build()
+    .unwrap();
+
+    let diff = SceneDiff::between(&old, &new);
+    let changes = diff.as_slice();
+
+    assert_eq!(
+        changes[0],
+        SceneChange::NodeRemoved {
+            path: "Start".to_string(),
+            class_name: "Button".to_string()
+        }
+    );
+    assert_eq!(
+        changes[1],
+        SceneChange::PropertyChanged {
+            path: "Title".to_string(),
+            property: "text".to_string(),
+            old: Variant::from("Main menu"),
+            new: Variant::from("Pause"),
+        }
+    );
+    assert_eq!(
+        changes[2],
+        SceneChange::NodeAdded {
+            path: "Quit".to_string(),
+            class_name: "Button".to_string()
+        }
+    );
+    assert_eq!(
+        changes[3],
+        SceneChange::ConnectionRemoved(SceneConnection {
+            source: "Start".to_string(),
+            signal: "pressed".to_string(),
+            target: ".".to_string(),
+            method: "queue_free".to_string(),
+        })
+    );
+    assert_eq!(diff.len(), 4);
+
+    let text = diff.to_string();
+    assert!(text.contains("- Start (Button)"), "{text}");
+    assert!(
+        text.contains("~ Title: text = Main menu -> Pause"),
+        "{text}"
+    );
+}
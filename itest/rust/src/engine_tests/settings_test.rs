@@ -0,0 +1,85 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{GString, Variant};
+use godot::classes::ProjectSettings;
+use godot::meta::ToGodot;
+use godot::tools::Setting;
+
+use crate::framework::itest;
+
+fn clear(name: &str) {
+    // Assigning nil removes the setting.
+    ProjectSettings::singleton().set_setting(name.into(), Variant::nil());
+}
+
+#[itest]
+fn setting_register_and_access() {
+    let setting = Setting::project("gdext_itest/settings/volume", 0.5_f64);
+    assert!(!ProjectSettings::singleton().has_setting(setting.name().clone()));
+    assert_eq!(setting.get(), 0.5, "default before registration");
+
+    setting.register();
+    assert!(ProjectSettings::singleton().has_setting(setting.name().clone()));
+    assert!(!setting.is_changed());
+
+    setting.set(0.75);
+    assert_eq!(setting.get(), 0.75);
+    assert!(setting.is_changed());
+
+    // Registering again keeps the stored value.
+    setting.register();
+    assert_eq!(setting.get(), 0.75);
+
+    setting.reset();
+    assert_eq!(setting.get(), 0.5);
+
+    clear("gdext_itest/settings/volume");
+}
+
+#[itest]
+fn setting_type_mismatch() {
+    let name = "gdext_itest/settings/mismatch";
+    let setting = Setting::project(name, GString::from("default"));
+
+    ProjectSettings::singleton().set_setting(name.into(), 42.to_variant());
+    assert!(setting.try_get().is_err());
+    assert_eq!(setting.get(), GString::from("default"));
+
+    clear(name);
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn setting_on_changed() {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let name = "gdext_itest/settings/observed";
+    let setting = Setting::project(name, 1_i64);
+    setting.register();
+
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let seen_clone = seen.clone();
+    let subscription = setting.on_changed(move |value| seen_clone.borrow_mut().push(value));
+
+    // Godot emits "settings_changed" deferred; emit it manually to keep the test synchronous.
+    setting.set(2);
+    ProjectSettings::singleton().emit_signal("settings_changed".into(), &[]);
+    assert_eq!(*seen.borrow(), vec![2]);
+
+    // Unrelated notifications don't invoke the callback.
+    ProjectSettings::singleton().emit_signal("settings_changed".into(), &[]);
+    assert_eq!(*seen.borrow(), vec![2]);
+
+    assert!(subscription.unsubscribe());
+    setting.set(3);
+    ProjectSettings::singleton().emit_signal("settings_changed".into(), &[]);
+    assert_eq!(*seen.borrow(), vec![2]);
+
+    clear(name);
+}
@@ -0,0 +1,81 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Color, VariantType};
+use godot::classes::{Shader, ShaderMaterial};
+use godot::obj::{Gd, NewGd};
+use godot::tools::shader::{shader_params, ShaderParamError, ShaderParams};
+
+use crate::framework::itest;
+
+shader_params! {
+    struct WaterParams {
+        wave_speed: f32,
+        foam_color: Color,
+    }
+}
+
+shader_params! {
+    struct WrongParams {
+        wave_speed: bool,
+        depth: f32,
+    }
+}
+
+fn water_material() -> Gd<ShaderMaterial> {
+    let mut shader = Shader::new_gd();
+    shader.set_code(
+        r#"
+        shader_type spatial;
+        uniform float wave_speed = 1.5;
+        uniform vec4 foam_color : source_color = vec4(1.0);
+        "#
+        .into(),
+    );
+
+    let mut material = ShaderMaterial::new_gd();
+    material.set_shader(shader);
+    material
+}
+
+#[itest]
+fn shader_params_get_set() {
+    let mut water = WaterParams::from_material(water_material());
+    assert_eq!(water.wave_speed(), 1.5, "default from shader");
+
+    water.set_wave_speed(3.0);
+    water.set_foam_color(Color::RED);
+    assert_eq!(water.wave_speed(), 3.0);
+    assert_eq!(water.foam_color(), Color::RED);
+
+    assert_eq!(WaterParams::param_list().len(), 2);
+}
+
+#[itest]
+fn shader_params_validate() {
+    let water = WaterParams::from_material_unchecked(water_material());
+    assert_eq!(water.validate(), Ok(()));
+
+    let wrong = WrongParams::from_material_unchecked(water_material());
+    match wrong.validate() {
+        Err(ShaderParamError::TypeMismatch {
+            name,
+            expected,
+            actual,
+            ..
+        }) => {
+            assert_eq!(name, "wave_speed");
+            assert_eq!(expected, VariantType::BOOL);
+            assert_eq!(actual, VariantType::FLOAT);
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+
+    // Without shader, there's nothing to validate against.
+    let empty = WrongParams::from_material_unchecked(ShaderMaterial::new_gd());
+    assert_eq!(empty.validate(), Ok(()));
+}
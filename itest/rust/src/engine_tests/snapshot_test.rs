@@ -0,0 +1,87 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::NodePath;
+use godot::classes::{Label, Node, RemoteTransform3D, VBoxContainer};
+use godot::obj::{Gd, NewAlloc};
+use godot::tools::snapshot::{assert_scene_valid, validate_scene, SceneIssue, SceneSnapshot};
+
+use crate::framework::{expect_panic, itest};
+
+fn make_menu() -> Gd<Node> {
+    let mut menu = VBoxContainer::new_alloc();
+    menu.set_name("Menu".into());
+
+    for (name, text) in [("Title", "Main menu"), ("Start", "Start")] {
+        let mut label = Label::new_alloc();
+        label.set_name(name.into());
+        label.set_text(text.into());
+        menu.add_child(label.upcast());
+    }
+
+    menu.upcast()
+}
+
+#[itest]
+fn snapshot_capture_and_compare() {
+    let menu = make_menu();
+
+    let snapshot = SceneSnapshot::options().property("text").capture(&menu);
+    assert_eq!(
+        snapshot.as_text(),
+        "Menu (VBoxContainer)\n  Title (Label) text=\"Main menu\"\n  Start (Label) text=\"Start\"\n"
+    );
+
+    snapshot.assert_matches(
+        r#"
+        Menu (VBoxContainer)
+          Title (Label) text="Main menu"
+          Start (Label) text="Start"
+        "#,
+    );
+
+    let shallow = SceneSnapshot::options().max_depth(0).capture(&menu);
+    assert_eq!(shallow.as_text(), "Menu (VBoxContainer)\n");
+
+    let diff = snapshot
+        .diff(&SceneSnapshot::from_text("Menu (VBoxContainer)\n"))
+        .expect("snapshots differ");
+    assert!(diff.contains("+   Start (Label)"), "{diff}");
+
+    expect_panic("snapshot mismatch", || {
+        SceneSnapshot::capture(&menu).assert_matches("Menu (Control)");
+    });
+
+    menu.free();
+}
+
+#[itest]
+fn snapshot_validate_scene() {
+    let mut root = make_menu();
+    assert_scene_valid!(root);
+
+    let mut remote = RemoteTransform3D::new_alloc();
+    remote.set_name("Remote".into());
+    remote.set_remote_node(NodePath::from("../Missing"));
+    root.add_child(remote.upcast());
+
+    let issues = validate_scene(&root);
+    assert_eq!(
+        issues,
+        vec![SceneIssue::BrokenNodePath {
+            node: NodePath::from("Remote"),
+            property: "remote_path".to_string(),
+            path: NodePath::from("../Missing"),
+        }]
+    );
+
+    expect_panic("scene with broken path", || {
+        assert_scene_valid!(root);
+    });
+
+    root.free();
+}
@@ -0,0 +1,44 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::Os;
+use godot::tools::{CmdlineArgs, Features};
+
+use crate::framework::itest;
+
+#[itest]
+fn cmdline_args_match_os() {
+    let raw: Vec<String> = Os::singleton()
+        .get_cmdline_args()
+        .as_slice()
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect();
+
+    let args = CmdlineArgs::engine();
+    assert_eq!(args, CmdlineArgs::parse(&raw));
+
+    for flag in args.flags() {
+        assert!(raw.contains(&format!("--{flag}")) || raw.contains(&format!("-{flag}")));
+    }
+    for positional in args.positional() {
+        assert!(raw.contains(positional));
+    }
+}
+
+#[itest]
+fn features_match_os() {
+    let os = Os::singleton();
+
+    assert_eq!(Features::is_editor(), os.has_feature("editor".into()));
+    assert_eq!(Features::is_debug(), os.has_feature("debug".into()));
+    assert_eq!(Features::is_pc(), os.has_feature("pc".into()));
+    assert!(!Features::has("gdext_nonexistent_feature_tag"));
+
+    // Exactly one of the build types applies.
+    assert_ne!(Features::is_debug(), Features::is_release());
+}
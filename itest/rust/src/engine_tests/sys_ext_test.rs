@@ -0,0 +1,51 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+
+use godot::builtin::StringName;
+use godot::classes::{Node, RefCounted};
+use godot::obj::{NewAlloc, NewGd};
+use godot::sys_ext;
+
+#[itest]
+fn sys_ext_object_instance_id() {
+    let node = Node::new_alloc();
+    let id = sys_ext::object_instance_id(&node);
+    assert_eq!(id, Some(node.instance_id()));
+
+    let copy = node.clone();
+    node.free();
+    assert_eq!(sys_ext::object_instance_id(&copy), None);
+
+    let obj = RefCounted::new_gd();
+    assert_eq!(sys_ext::object_instance_id(&obj), Some(obj.instance_id()));
+}
+
+#[itest]
+fn sys_ext_class_exists() {
+    assert!(sys_ext::class_exists(&StringName::from("Node3D")));
+    assert!(sys_ext::class_exists(&StringName::from("ObjPayload")));
+    assert!(!sys_ext::class_exists(&StringName::from("DoesNotExist")));
+}
+
+#[itest]
+fn sys_ext_print_with_location() {
+    let location = sys_ext::SourceLocation {
+        function: "custom_function",
+        file: "res://custom/file.gd",
+        line: 42,
+    };
+
+    // Only verifies that printing doesn't crash, including with interior null bytes.
+    sys_ext::print_warning_at("sys_ext test warning; ignore", &location, false);
+    sys_ext::print_warning_at(
+        "with\0null byte; ignore",
+        &sys_ext::SourceLocation::caller(),
+        false,
+    );
+}
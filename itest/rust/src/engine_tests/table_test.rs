@@ -0,0 +1,87 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::io::Write;
+
+use godot::builtin::GString;
+use godot::classes::file_access::ModeFlags;
+use godot::register::TableRow;
+use godot::tools::table::{self, TableError, WatchedTable};
+use godot::tools::GFile;
+
+use crate::framework::itest;
+
+#[derive(TableRow, Debug, PartialEq)]
+struct EnemyRow {
+    name: GString,
+    #[table(column = "Max HP")]
+    max_hp: u32,
+    speed: f32,
+    #[table(default)]
+    boss: bool,
+    loot: Option<String>,
+}
+
+#[itest]
+fn table_load_typed_rows() {
+    const PATH: &str = "user://table_test.tsv";
+
+    let mut file = GFile::open(PATH, ModeFlags::WRITE).unwrap();
+    file.write_all(b"name\tspeed\tMax HP\tloot\norc\t1.5\t30\t\ndragon\t4\t900\tgold\n")
+        .unwrap();
+    drop(file);
+
+    let rows: Vec<EnemyRow> = table::load(PATH).expect("load table");
+    assert_eq!(
+        rows,
+        [
+            EnemyRow {
+                name: "orc".into(),
+                max_hp: 30,
+                speed: 1.5,
+                boss: false,
+                loot: None,
+            },
+            EnemyRow {
+                name: "dragon".into(),
+                max_hp: 900,
+                speed: 4.0,
+                boss: false,
+                loot: Some("gold".to_string()),
+            },
+        ]
+    );
+
+    let mut watched = WatchedTable::<EnemyRow>::load(PATH).expect("load watched table");
+    assert_eq!(watched.rows().len(), 2);
+    assert!(!watched.reload_if_changed().expect("unchanged file"));
+}
+
+#[itest]
+fn table_errors() {
+    assert_eq!(
+        EnemyRow::required_columns(),
+        ["name", "Max HP", "speed", "loot"]
+    );
+
+    let err = table::parse::<EnemyRow>(
+        "name,Max HP,speed,loot,boss\norc,30,fast,,\n",
+        ',',
+        "enemies.csv",
+    )
+    .unwrap_err();
+    assert!(matches!(
+        &err,
+        TableError::Cell { line: 2, column, value, .. } if column == "speed" && value == "fast"
+    ));
+
+    let err = table::parse::<EnemyRow>("name,speed,loot\n", ',', "enemies.csv").unwrap_err();
+    assert_eq!(err.to_string(), "enemies.csv: missing column `Max HP`");
+
+    let err = table::load::<EnemyRow>("user://does_not_exist.csv").unwrap_err();
+    assert!(matches!(err, TableError::Io { .. }));
+}
@@ -0,0 +1,145 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use godot::builtin::{Callable, Signal, Variant};
+use godot::classes::RefCounted;
+use godot::obj::NewGd;
+use godot::task::MainThreadQueue;
+use godot::tools::dispatch_process_callbacks;
+
+use crate::framework::itest;
+
+fn flush_frame() {
+    dispatch_process_callbacks(0.0);
+}
+
+#[itest]
+fn threadsafe_signal_emit_from_thread() {
+    let mut object = RefCounted::new_gd();
+    object.add_user_signal("progress".into());
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_in = received.clone();
+    object.connect(
+        "progress".into(),
+        Callable::from_fn("on_progress", move |args: &[&Variant]| {
+            received_in.lock().unwrap().push(args[0].to::<i64>());
+            Ok(Variant::nil())
+        }),
+    );
+
+    let signal = Signal::from_object_signal(&object, "progress").to_threadsafe();
+    assert_eq!(signal.object_id(), object.instance_id());
+    assert_eq!(signal.name(), "progress");
+
+    thread::spawn(move || {
+        for i in 0..3_i64 {
+            signal.emit_deferred((i,));
+        }
+    })
+    .join()
+    .unwrap();
+
+    assert!(
+        received.lock().unwrap().is_empty(),
+        "not emitted before flush"
+    );
+
+    flush_frame();
+    assert_eq!(*received.lock().unwrap(), [0, 1, 2]);
+}
+
+#[itest]
+fn threadsafe_queue_bounded() {
+    let queue = MainThreadQueue::bounded(2);
+    assert_eq!(queue.capacity(), Some(2));
+
+    let count = Arc::new(Mutex::new(0));
+    let post = |queue: &MainThreadQueue, count: &Arc<Mutex<i32>>| {
+        let count = count.clone();
+        queue.post(move || *count.lock().unwrap() += 1)
+    };
+
+    let worker_queue = queue.clone();
+    let worker_count = count.clone();
+    let results = thread::spawn(move || [(); 3].map(|_| post(&worker_queue, &worker_count)))
+        .join()
+        .unwrap();
+
+    assert!(results[0].is_ok());
+    assert!(results[1].is_ok());
+    assert_eq!(results[2].unwrap_err().capacity(), 2);
+    assert_eq!(queue.len(), 2);
+
+    flush_frame();
+    assert_eq!(*count.lock().unwrap(), 2);
+    assert!(queue.is_empty());
+
+    // Room again after flush.
+    assert!(post(&queue, &count).is_ok());
+    flush_frame();
+    assert_eq!(*count.lock().unwrap(), 3);
+}
+
+#[itest]
+fn threadsafe_queue_call_method() {
+    let object = RefCounted::new_gd();
+    let queue = MainThreadQueue::unbounded();
+    assert_eq!(queue.capacity(), None);
+
+    let worker_queue = queue.clone();
+    let id = object.instance_id();
+    thread::spawn(move || {
+        worker_queue
+            .call(id, "set_meta", ("answer".to_string(), 42_i64))
+            .unwrap();
+    })
+    .join()
+    .unwrap();
+
+    assert!(!object.has_meta("answer".into()));
+
+    flush_frame();
+    assert_eq!(object.get_meta("answer".into()), Variant::from(42_i64));
+}
+
+#[itest]
+fn threadsafe_queue_panic_keeps_remaining() {
+    let queue = MainThreadQueue::unbounded();
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    let log_before = log.clone();
+    let log_after = log.clone();
+    let worker_queue = queue.clone();
+    thread::spawn(move || {
+        worker_queue
+            .post(move || log_before.lock().unwrap().push("before"))
+            .unwrap();
+        worker_queue
+            .post(|| panic!("queued task panics on purpose"))
+            .unwrap();
+        worker_queue
+            .post(move || log_after.lock().unwrap().push("after"))
+            .unwrap();
+    })
+    .join()
+    .unwrap();
+
+    flush_frame();
+    assert_eq!(*log.lock().unwrap(), ["before", "after"]);
+    assert!(queue.is_empty());
+}
+
+#[cfg(feature = "experimental-threads")]
+#[itest]
+fn threadsafe_queue_requires_main_thread() {
+    let result = thread::spawn(MainThreadQueue::unbounded).join();
+    assert!(result.is_err(), "queue created off the main thread");
+}
@@ -0,0 +1,107 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::pin;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::time::Duration;
+
+use godot::classes::{SceneTree, Timer};
+use godot::obj::{Gd, NewAlloc};
+use godot::tools::{after, next_frame, next_frame_future};
+
+use crate::framework::{itest, TestContext};
+
+fn scene_tree(ctx: &TestContext) -> Gd<SceneTree> {
+    ctx.scene_tree.get_tree().unwrap()
+}
+
+fn emit_process_frame(ctx: &TestContext) {
+    scene_tree(ctx).emit_signal("process_frame".into(), &[]);
+}
+
+#[itest]
+fn timers_duration_accessors(ctx: &TestContext) {
+    let mut timer = Timer::new_alloc();
+    timer.set_wait_duration(Duration::from_millis(1500));
+    assert_eq!(timer.wait_duration(), Duration::from_millis(1500));
+    assert_eq!(timer.get_wait_time(), 1.5);
+
+    // Stopped timers report negative or zero time left.
+    assert_eq!(timer.time_left_duration(), Duration::ZERO);
+    timer.free();
+
+    let mut scene_timer = scene_tree(ctx).create_timer_duration(Duration::from_secs(2));
+    assert_eq!(scene_timer.get_time_left(), 2.0);
+
+    scene_timer.set_time_left_duration(Duration::from_millis(250));
+    assert_eq!(scene_timer.time_left_duration(), Duration::from_millis(250));
+}
+
+#[itest]
+fn timers_next_frame_runs_once(ctx: &TestContext) {
+    let calls = Rc::new(Cell::new(0));
+
+    let calls_in = calls.clone();
+    let handle = next_frame(move || calls_in.set(calls_in.get() + 1));
+    assert!(handle.is_pending());
+    assert_eq!(handle.time_left(), None);
+
+    emit_process_frame(ctx);
+    emit_process_frame(ctx);
+    assert_eq!(calls.get(), 1);
+    assert!(!handle.is_pending());
+    assert!(!handle.cancel());
+}
+
+#[itest]
+fn timers_cancel(ctx: &TestContext) {
+    let ran = Rc::new(Cell::new(false));
+
+    let ran_in = ran.clone();
+    let frame = next_frame(move || ran_in.set(true));
+    assert!(frame.cancel());
+
+    emit_process_frame(ctx);
+    assert!(!ran.get());
+
+    let delayed = after(Duration::from_secs(60), || {
+        panic!("cancelled callback must not run")
+    });
+    let time_left = delayed.time_left().unwrap();
+    assert!(time_left > Duration::from_secs(59), "{time_left:?}");
+    assert!(delayed.cancel());
+}
+
+#[itest]
+fn timers_next_frame_future(ctx: &TestContext) {
+    let woken = Arc::new(CountingWaker::default());
+    let waker = Waker::from(woken.clone());
+    let mut cx = Context::from_waker(&waker);
+
+    let mut delay = pin!(next_frame_future());
+    assert_eq!(delay.as_mut().poll(&mut cx), Poll::Pending);
+    assert!(!delay.is_elapsed());
+
+    emit_process_frame(ctx);
+    assert_eq!(woken.count.load(std::sync::atomic::Ordering::SeqCst), 1);
+    assert_eq!(delay.as_mut().poll(&mut cx), Poll::Ready(()));
+}
+
+#[derive(Default)]
+struct CountingWaker {
+    count: std::sync::atomic::AtomicU32,
+}
+
+impl Wake for CountingWaker {
+    fn wake(self: Arc<Self>) {
+        self.count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    }
+}
@@ -5,9 +5,12 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::framework::itest;
 use godot::builtin::Vector2;
-use godot::tools::{tr, tr_n};
+use godot::classes::{Translation, TranslationServer};
+use godot::obj::NewGd;
+use godot::tools::{check_translations, tr, tr_n, TranslationIssue};
+
+use crate::framework::itest;
 
 #[itest]
 fn tr_macro_format() {
@@ -46,3 +49,49 @@ fn tr_n_macro_format_plural() {
     let hello = tr_n!(n; "Hello singular {}!", "Hello plural {}s!", "world");
     assert_eq!(hello.to_string(), "Hello plural worlds!");
 }
+
+#[itest]
+fn tr_macro_substitutes_after_translation() {
+    let mut translation = Translation::new_gd();
+    translation.set_locale("x-test".into());
+    translation.add_message("TR_TEST_GREETING".into(), "{name}, welcome to {0}!".into());
+    translation.add_message("TR_TEST_UNKNOWN".into(), "Hello {player}".into());
+
+    let mut server = TranslationServer::singleton();
+    let previous_locale = server.get_locale();
+    server.add_translation(translation.clone());
+    server.set_locale("x-test".into());
+
+    let greeting = tr!("TR_TEST_GREETING", "Godot", name = "Ana");
+    assert_eq!(greeting.to_string(), "Ana, welcome to Godot!");
+
+    // Placeholders without argument stay visible.
+    let unknown = tr!("TR_TEST_UNKNOWN");
+    assert_eq!(unknown.to_string(), "Hello {player}");
+
+    // Uses of tr!/tr_n! are only recorded in Debug builds.
+    if cfg!(debug_assertions) {
+        let issues = check_translations(&[translation.clone()]);
+
+        assert!(
+            issues.iter().any(|issue| matches!(
+                issue,
+                TranslationIssue::UnknownPlaceholder { message, placeholder, .. }
+                    if message == "TR_TEST_UNKNOWN" && placeholder == "player"
+            )),
+            "{issues:?}"
+        );
+        assert!(issues.iter().any(|issue| matches!(
+            issue,
+            TranslationIssue::MissingMessage { locale, message, .. }
+                if locale == "x-test" && message == "Hello singular {}!"
+        )));
+        assert!(!issues.iter().any(|issue| matches!(
+            issue,
+            TranslationIssue::MissingMessage { message, .. } if message == "TR_TEST_GREETING"
+        )));
+    }
+
+    server.set_locale(previous_locale);
+    server.remove_translation(translation);
+}
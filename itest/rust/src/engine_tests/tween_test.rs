@@ -0,0 +1,147 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use godot::builtin::{Color, VariantType, Vector2};
+use godot::classes::{Node, Node2D};
+use godot::obj::{Gd, NewAlloc};
+use godot::tools::tween::{tween, Ease, TweenError};
+
+use crate::framework::{expect_panic, itest, TestContext};
+
+fn add_node2d(ctx: &TestContext) -> Gd<Node2D> {
+    let node = Node2D::new_alloc();
+    ctx.scene_tree
+        .clone()
+        .add_child(node.clone().upcast::<Node>());
+    node
+}
+
+#[itest]
+fn tween_prop_linear(ctx: &TestContext) {
+    let mut node = add_node2d(ctx);
+
+    let mut tween = tween(&node)
+        .prop("position", Vector2::new(100.0, 0.0))
+        .duration(1.0)
+        .start();
+
+    tween.custom_step(0.5);
+    assert_eq!(node.get_position(), Vector2::new(50.0, 0.0));
+
+    tween.custom_step(0.5);
+    assert_eq!(node.get_position(), Vector2::new(100.0, 0.0));
+    assert!(!tween.is_running());
+
+    node.free();
+}
+
+#[itest]
+fn tween_prop_component_and_settings(ctx: &TestContext) {
+    let mut node = add_node2d(ctx);
+    node.set_position(Vector2::new(10.0, 20.0));
+
+    let mut tween = tween(&node)
+        .prop("position:x", 10.0)
+        .relative()
+        .ease(Ease::OutCubic)
+        .prop("modulate", Color::from_rgba(1.0, 1.0, 1.0, 0.0))
+        .from(Color::WHITE)
+        .parallel()
+        .start();
+
+    tween.custom_step(1.0);
+    assert_eq!(node.get_position(), Vector2::new(20.0, 20.0));
+    assert_eq!(node.get_modulate(), Color::from_rgba(1.0, 1.0, 1.0, 0.0));
+
+    node.free();
+}
+
+#[itest]
+fn tween_prop_checked(ctx: &TestContext) {
+    let node = add_node2d(ctx);
+
+    let err = tween(&node)
+        .try_prop("positon", Vector2::ZERO)
+        .expect_err("misspelled property");
+    assert_eq!(
+        err,
+        TweenError::UnknownProperty {
+            class_name: "Node2D".to_string(),
+            property: "positon".to_string(),
+        }
+    );
+
+    let err = tween(&node)
+        .try_prop("position", 1.0)
+        .expect_err("wrong type");
+    assert_eq!(
+        err,
+        TweenError::TypeMismatch {
+            class_name: "Node2D".to_string(),
+            property: "position".to_string(),
+            expected: VariantType::VECTOR2,
+            actual: VariantType::FLOAT,
+        }
+    );
+
+    // Int and float values can be mixed.
+    tween(&node)
+        .try_prop("rotation", 1)
+        .expect("int for float property");
+
+    expect_panic("prop() with unknown property", || {
+        let _ = tween(&node).prop("does_not_exist", 0.0);
+    });
+
+    node.free();
+}
+
+#[itest]
+fn tween_method_and_callback(ctx: &TestContext) {
+    let node = add_node2d(ctx);
+
+    let values = Rc::new(RefCell::new(Vec::new()));
+    let done = Rc::new(Cell::new(false));
+
+    let values_in = values.clone();
+    let done_in = done.clone();
+    let mut tween = tween(&node)
+        .method(0.0, 10.0, move |value: f64| {
+            values_in.borrow_mut().push(value)
+        })
+        .duration(1.0)
+        .callback(move || done_in.set(true))
+        .start();
+
+    tween.custom_step(0.5);
+    assert_eq!(values.borrow().last(), Some(&5.0));
+    assert!(!done.get());
+
+    tween.custom_step(0.5);
+    assert_eq!(values.borrow().last(), Some(&10.0));
+    assert!(done.get());
+
+    node.free();
+}
+
+#[itest]
+fn tween_setting_without_step(ctx: &TestContext) {
+    let node = add_node2d(ctx);
+
+    expect_panic("duration() without step", || {
+        let _ = tween(&node).duration(1.0);
+    });
+
+    expect_panic("relative() on callback step", || {
+        let _ = tween(&node).callback(|| {}).relative();
+    });
+
+    node.free();
+}
@@ -0,0 +1,51 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::{Node, Node2D, Node3D, PackedScene};
+use godot::global::Error;
+use godot::obj::{Gd, NewAlloc, NewGd};
+use godot::tools::TypedScene;
+
+use crate::framework::itest;
+
+fn make_scene() -> Gd<PackedScene> {
+    let node = Node3D::new_alloc();
+    let mut scene = PackedScene::new_gd();
+
+    let err = scene.pack(node.clone().upcast());
+    assert_eq!(err, Error::OK);
+
+    node.free();
+    scene
+}
+
+#[itest]
+fn typed_scene_instantiate() {
+    let typed = TypedScene::<Node3D>::try_from_scene(make_scene()).expect("root is Node3D");
+
+    let instance: Gd<Node3D> = typed.instantiate();
+    assert_eq!(instance.get_class(), "Node3D".into());
+    instance.free();
+}
+
+#[itest]
+fn typed_scene_base_class() {
+    let typed = TypedScene::<Node>::try_from_scene(make_scene()).expect("Node3D derives Node");
+
+    let instance: Gd<Node> = typed.instantiate();
+    assert!(instance.clone().try_cast::<Node3D>().is_ok());
+    instance.free();
+}
+
+#[itest]
+fn typed_scene_wrong_root() {
+    let err = TypedScene::<Node2D>::try_from_scene(make_scene()).expect_err("root is not Node2D");
+
+    let message = err.to_string();
+    assert!(message.contains("Node3D"), "{message}");
+    assert!(message.contains("Node2D"), "{message}");
+}
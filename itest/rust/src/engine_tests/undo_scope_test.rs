@@ -0,0 +1,79 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::GString;
+use godot::classes::UndoRedo;
+use godot::meta::ToGodot;
+use godot::obj::NewAlloc;
+use godot::register::{godot_api, GodotClass};
+use godot::tools::editor::undo_scope_with;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct UndoTarget {
+    #[var]
+    value: i64,
+
+    calls: Vec<String>,
+}
+
+#[godot_api]
+impl UndoTarget {
+    #[func]
+    fn record(&mut self, label: GString) {
+        self.calls.push(label.to_string());
+    }
+}
+
+#[itest]
+fn undo_scope_commit_undo_redo() {
+    let mut history = UndoRedo::new_alloc();
+    let mut target = UndoTarget::new_alloc();
+    target.bind_mut().value = 1;
+
+    let returned = undo_scope_with(&history, "Change value", |s| {
+        s.set_property(&target, "value", 5);
+        s.method_with_args(
+            &target,
+            "record",
+            &["do".to_variant()],
+            &["undo".to_variant()],
+        );
+        42
+    });
+
+    // Committing executes the do operations.
+    assert_eq!(returned, 42);
+    assert_eq!(history.get_history_count(), 1);
+    assert_eq!(history.get_current_action_name(), "Change value".into());
+    assert_eq!(target.bind().value, 5);
+    assert_eq!(target.bind().calls, ["do"]);
+
+    assert!(history.undo());
+    assert_eq!(target.bind().value, 1);
+    assert_eq!(target.bind().calls, ["do", "undo"]);
+
+    assert!(history.redo());
+    assert_eq!(target.bind().value, 5);
+    assert_eq!(target.bind().calls, ["do", "undo", "do"]);
+
+    history.free();
+    target.free();
+}
+
+#[itest]
+fn undo_scope_empty_not_committed() {
+    let mut history = UndoRedo::new_alloc();
+
+    undo_scope_with(&history, "Nothing", |_| {});
+    assert_eq!(history.get_history_count(), 0);
+    assert!(!history.undo());
+
+    history.free();
+}
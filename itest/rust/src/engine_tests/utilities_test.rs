@@ -7,7 +7,7 @@
 
 use crate::framework::itest;
 
-use godot::builtin::{GString, Variant};
+use godot::builtin::{GString, Variant, Vector2};
 use godot::global::*;
 
 #[itest]
@@ -70,3 +70,21 @@ fn utilities_max() {
     );
     assert_eq!(output, Variant::from(-1.0));
 }
+
+#[itest]
+fn utilities_typed() {
+    assert_eq!(typed::abs(-7), 7);
+    assert_eq!(typed::sign(-2.5), -1.0);
+    assert_eq!(typed::clamp(15, 0, 10), 10);
+    assert_eq!(typed::snapped(7.3, 0.5), 7.5);
+
+    assert_eq!(typed::max(1, 3, &[7, 5]), 7);
+    assert_eq!(typed::min(1.0, -3.0, &[]), -3.0);
+
+    let clamped = typed::clamp(
+        Vector2::new(-1.0, 5.0),
+        Vector2::ZERO,
+        Vector2::new(2.0, 2.0),
+    );
+    assert_eq!(clamped, Vector2::new(0.0, 2.0));
+}
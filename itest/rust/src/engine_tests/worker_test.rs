@@ -0,0 +1,68 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use godot::task::{is_worker_task, parallel_for, spawn_blocking, spawn_parallel_for};
+
+use crate::framework::{expect_panic, itest, TestContext};
+
+#[itest]
+fn worker_spawn_blocking() {
+    let handle = spawn_blocking(|| {
+        assert!(is_worker_task());
+        (1..=10).product::<u64>()
+    });
+
+    assert_eq!(handle.join(), 3_628_800);
+    assert!(!is_worker_task());
+}
+
+#[itest]
+fn worker_spawn_blocking_panic() {
+    let handle = spawn_blocking(|| -> i32 { panic!("task failed") });
+
+    expect_panic("panic resumed on join", move || {
+        handle.join();
+    });
+}
+
+#[itest]
+fn worker_parallel_for() {
+    let visited = Arc::new((0..100).map(|_| AtomicUsize::new(0)).collect::<Vec<_>>());
+
+    let visited_ref = visited.clone();
+    parallel_for(20..120, move |i| {
+        visited_ref[i - 20].fetch_add(1, Ordering::Relaxed);
+    });
+
+    assert!(visited
+        .iter()
+        .all(|count| count.load(Ordering::Relaxed) == 1));
+
+    let handle = spawn_parallel_for(0..8, |_| {});
+    while !handle.is_finished() {
+        std::thread::yield_now();
+    }
+    assert_eq!(handle.finished_count(), 8);
+    handle.join();
+}
+
+#[cfg(debug_assertions)]
+#[itest]
+fn worker_scene_access_panics(ctx: &TestContext) {
+    let node = ctx.scene_tree.clone();
+
+    let handle = spawn_blocking(move || {
+        let _ = node.get_name();
+    });
+
+    expect_panic("scene tree access from worker", move || {
+        handle.join();
+    });
+}
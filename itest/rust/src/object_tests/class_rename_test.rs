@@ -24,6 +24,18 @@ pub mod rename {
     pub struct RepeatMe {}
 }
 
+pub mod namespaced {
+    use super::*;
+
+    #[derive(GodotClass)]
+    #[class(namespace = "Acme", no_init)]
+    pub struct RepeatMe {}
+
+    #[derive(GodotClass)]
+    #[class(namespace = Acme, rename = "Renamed", no_init)]
+    pub struct RepeatMeAgain {}
+}
+
 #[itest]
 fn renaming_changes_the_name() {
     assert_ne!(
@@ -33,3 +45,19 @@ fn renaming_changes_the_name() {
     assert_eq!(dont_rename::RepeatMe::class_name().as_str(), "RepeatMe");
     assert_eq!(rename::RepeatMe::class_name().as_str(), "NoRepeat");
 }
+
+#[itest]
+fn namespace_prefixes_the_name() {
+    assert_eq!(namespaced::RepeatMe::class_name().as_str(), "AcmeRepeatMe");
+    assert_eq!(
+        namespaced::RepeatMeAgain::class_name().as_str(),
+        "AcmeRenamed"
+    );
+
+    let registered = godot::classes::ClassDb::singleton()
+        .class_exists(namespaced::RepeatMe::class_name().to_string_name());
+    assert!(
+        registered,
+        "namespaced class is registered under its full name"
+    );
+}
@@ -0,0 +1,130 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::{RefCounted, Resource};
+use godot::global::PropertyHint;
+use godot::meta::{FromGodot, ToGodot};
+use godot::obj::{DynGd, Gd, NewGd};
+use godot::register::property::{Export, Var};
+use godot::register::{godot_dyn, GodotClass};
+
+use crate::framework::itest;
+
+trait AiBehavior {
+    fn next_action(&self) -> String;
+    fn tick(&mut self);
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Resource)]
+struct AggressiveAi {
+    ticks: i32,
+}
+
+#[godot_dyn]
+impl AiBehavior for AggressiveAi {
+    fn next_action(&self) -> String {
+        format!("attack {}", self.ticks)
+    }
+
+    fn tick(&mut self) {
+        self.ticks += 1;
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Resource)]
+struct CowardlyAi {}
+
+#[godot_dyn]
+impl AiBehavior for CowardlyAi {
+    fn next_action(&self) -> String {
+        "flee".to_string()
+    }
+
+    fn tick(&mut self) {}
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Resource)]
+struct UnrelatedResource {}
+
+#[itest]
+fn dyn_gd_bind_and_mutate() {
+    let mut brain = AggressiveAi::new_gd().into_dyn::<dyn AiBehavior>();
+    brain.dyn_bind_mut().tick();
+    assert_eq!(brain.dyn_bind().next_action(), "attack 1");
+
+    // Changes are visible through the concrete class.
+    let concrete: Gd<AggressiveAi> = brain.clone().into_gd();
+    assert_eq!(concrete.bind().ticks, 1);
+}
+
+#[itest]
+fn dyn_gd_from_base_class() {
+    let res: Gd<Resource> = CowardlyAi::new_gd().upcast();
+    let brain =
+        DynGd::<Resource, dyn AiBehavior>::try_from_gd(res.clone()).expect("implements trait");
+
+    assert_eq!(brain.dyn_bind().next_action(), "flee");
+    assert_eq!(*brain, res);
+
+    let via_variant = DynGd::<Resource, dyn AiBehavior>::from_variant(&res.to_variant());
+    assert_eq!(via_variant, brain);
+}
+
+#[itest]
+fn dyn_gd_rejects_non_implementor() {
+    let res: Gd<Resource> = UnrelatedResource::new_gd().upcast();
+    let err =
+        DynGd::<Resource, dyn AiBehavior>::try_from_gd(res).expect_err("trait not implemented");
+    assert!(err.to_string().contains("UnrelatedResource"), "{err}");
+
+    let engine_obj = RefCounted::new_gd();
+    assert!(DynGd::<RefCounted, dyn AiBehavior>::try_from_godot(engine_obj).is_err());
+}
+
+#[itest]
+fn dyn_gd_rejected_property_keeps_value() {
+    let mut field = AggressiveAi::new_gd()
+        .into_dyn::<dyn AiBehavior>()
+        .upcast::<Resource>();
+    let before = field.clone();
+
+    field.set_property(UnrelatedResource::new_gd().upcast());
+    assert_eq!(field, before);
+
+    field.set_property(CowardlyAi::new_gd().upcast());
+    assert_eq!(field.dyn_bind().next_action(), "flee");
+
+    // `Option` fields that are currently `None` go through the same check.
+    let mut optional: Option<DynGd<Resource, dyn AiBehavior>> = None;
+    optional.set_property(Some(UnrelatedResource::new_gd().upcast()));
+    assert!(optional.is_none());
+
+    optional.set_property(Some(CowardlyAi::new_gd().upcast()));
+    let brain = optional.clone().expect("implementor is accepted");
+    assert_eq!(brain.dyn_bind().next_action(), "flee");
+
+    optional.set_property(Some(UnrelatedResource::new_gd().upcast()));
+    assert_eq!(optional, Some(brain));
+}
+
+#[itest]
+fn dyn_gd_export_lists_implementors() {
+    let info = <DynGd<Resource, dyn AiBehavior> as Export>::default_export_info();
+    assert_eq!(info.hint, PropertyHint::RESOURCE_TYPE);
+
+    let mut classes: Vec<String> = info
+        .hint_string
+        .to_string()
+        .split(',')
+        .map(String::from)
+        .collect();
+    classes.sort();
+    assert_eq!(classes, ["AggressiveAi", "CowardlyAi"]);
+}
@@ -7,6 +7,7 @@
 
 use godot::builtin::{StringName, Variant, Vector3};
 use godot::classes::{Node, Node3D, Object};
+use godot::init::{panic_policy, set_panic_policy, PanicPolicy};
 use godot::meta::error::CallError;
 use godot::meta::{FromGodot, ToGodot};
 use godot::obj::{InstanceId, NewAlloc};
@@ -161,6 +162,35 @@ fn dynamic_call_with_panic() {
     obj.free();
 }
 
+#[itest]
+fn dynamic_call_with_panic_policy() {
+    let mut obj = ObjPayload::new_alloc();
+
+    // Per-method policy: panic is reported as script error, but the call still fails gracefully.
+    let call_error = obj
+        .try_call("do_panic_script_error".into(), &[])
+        .expect_err("panic should cause a call error");
+
+    assert_eq!(
+        call_error.to_string(),
+        "godot-rust function call failed: Object::call(&\"do_panic_script_error\")\
+        \n  Source: ObjPayload::do_panic_script_error()\
+        \n    Reason: [panic]  do_panic_script_error exploded"
+    );
+
+    // Global policy applies to methods without `on_panic`.
+    let prev_policy = set_panic_policy(PanicPolicy::ScriptError);
+    assert_eq!(panic_policy(), PanicPolicy::ScriptError);
+
+    let result = obj.try_call("do_panic".into(), &[]);
+    assert!(result.is_err(), "panic should cause a call error");
+
+    set_panic_policy(prev_policy);
+    assert_eq!(panic_policy(), PanicPolicy::LogAndDefault);
+
+    obj.free();
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Erroneous dynamic calls to engine APIs
 
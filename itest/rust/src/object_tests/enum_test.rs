@@ -9,7 +9,9 @@ use crate::framework::itest;
 use godot::builtin::varray;
 use godot::classes::input::CursorShape;
 use godot::classes::mesh::PrimitiveType;
-use godot::classes::{time, ArrayMesh};
+use godot::classes::node::DuplicateFlags;
+use godot::classes::object::ConnectFlags;
+use godot::classes::{time, ArrayMesh, Node};
 use std::collections::HashSet;
 
 #[itest]
@@ -63,6 +65,39 @@ fn enum_hash() {
     assert_eq!(months.len(), 12);
 }
 
+#[itest]
+fn bitfield_operators() {
+    use godot::obj::EngineBitfield;
+
+    let mut flags = ConnectFlags::DEFERRED | ConnectFlags::ONE_SHOT;
+    assert_eq!(flags & ConnectFlags::ONE_SHOT, ConnectFlags::ONE_SHOT);
+    assert_eq!(flags & ConnectFlags::PERSIST, ConnectFlags::from_ord(0));
+
+    flags &= ConnectFlags::DEFERRED;
+    assert_eq!(flags, ConnectFlags::DEFERRED);
+
+    flags |= ConnectFlags::PERSIST;
+    assert_eq!(ConnectFlags::from_ord(flags.ord()), flags);
+}
+
+// Parameter declared as int in Godot's API, but replaced with a bitfield.
+#[itest]
+fn bitfield_param_replaced() {
+    let mut node = Node::new_alloc();
+    node.add_to_group("persistent".into());
+
+    let mut copy = node
+        .duplicate_ex()
+        .flags(DuplicateFlags::GROUPS | DuplicateFlags::SCRIPTS)
+        .done()
+        .expect("duplicate succeeds");
+
+    assert!(copy.is_in_group("persistent".into()));
+
+    copy.free();
+    node.free();
+}
+
 // Testing https://github.com/godot-rust/gdext/issues/335
 // This fails upon calling the function, we don't actually need to make a good call.
 #[itest]
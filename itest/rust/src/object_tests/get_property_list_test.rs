@@ -78,3 +78,37 @@ fn get_property_list_returns() {
 
     obj.free();
 }
+
+#[derive(GodotClass)]
+#[class(base = Object, init)]
+pub struct EmptyPropertyListTest {
+    nested: Option<Gd<EmptyPropertyListTest>>,
+}
+
+#[godot_api]
+impl IObject for EmptyPropertyListTest {
+    fn get_property_list(&mut self) -> Vec<PropertyInfo> {
+        // Queries another empty list while this one is being built.
+        if let Some(nested) = &self.nested {
+            assert!(!nested.get_property_list().is_empty());
+        }
+
+        vec![]
+    }
+}
+
+#[itest]
+fn get_property_list_empty() {
+    let inner = EmptyPropertyListTest::new_alloc();
+    let mut outer = EmptyPropertyListTest::new_alloc();
+    outer.bind_mut().nested = Some(inner.clone());
+
+    // Godot still reports the built-in properties of Object, such as `script`.
+    for _ in 0..3 {
+        assert!(!outer.get_property_list().is_empty());
+        assert!(!inner.get_property_list().is_empty());
+    }
+
+    outer.free();
+    inner.free();
+}
@@ -0,0 +1,106 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Disabled in Release mode, since #[inject] methods are only generated in Debug.
+#![cfg(debug_assertions)]
+
+use godot::classes::{Node, RefCounted};
+use godot::obj::{Base, Gd, Injectable, NewAlloc, NewGd};
+use godot::register::GodotClass;
+
+use crate::framework::itest;
+
+trait Clock {
+    fn now(&self) -> u64;
+}
+
+struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> u64 {
+        u64::MAX
+    }
+}
+
+struct FakeClock(u64);
+
+impl Clock for FakeClock {
+    fn now(&self) -> u64 {
+        self.0
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct Scheduler {
+    #[inject]
+    clock: Injectable<Box<dyn Clock>>,
+
+    #[inject]
+    target: Injectable<Gd<Node>>,
+
+    base: Base<RefCounted>,
+}
+
+impl Scheduler {
+    fn is_expired(&mut self, deadline: u64) -> bool {
+        self.clock.init_with(|| Box::new(SystemClock)).now() >= deadline
+    }
+}
+
+#[itest]
+fn injectable_uses_injected_value() {
+    let mut scheduler = Scheduler::new_gd();
+    assert!(!scheduler.bind().clock.is_initialized());
+
+    let previous = scheduler.bind_mut().inject_clock(Box::new(FakeClock(10)));
+    assert!(previous.is_none());
+
+    // init_with() does not overwrite the injected value.
+    assert!(!scheduler.bind_mut().is_expired(20));
+    assert!(scheduler.bind_mut().is_expired(10));
+
+    // Re-injecting returns the previous value.
+    let previous = scheduler.bind_mut().inject_clock(Box::new(FakeClock(30)));
+    assert_eq!(previous.map(|clock| clock.now()), Some(10));
+    assert!(scheduler.bind_mut().is_expired(20));
+}
+
+#[itest]
+fn injectable_initializes_without_injection() {
+    let mut scheduler = Scheduler::new_gd();
+
+    assert!(scheduler.bind_mut().is_expired(1000));
+    assert_eq!(scheduler.bind().clock.now(), u64::MAX);
+}
+
+#[itest]
+fn injectable_engine_object() {
+    let mut scheduler = Scheduler::new_gd();
+    let node = Node::new_alloc();
+
+    scheduler.bind_mut().inject_target(node.clone());
+    assert_eq!(*scheduler.bind().target, node);
+
+    let mut taken = scheduler.bind_mut().target.take();
+    assert!(!scheduler.bind().target.is_initialized());
+    assert_eq!(taken.as_ref(), Some(&node));
+
+    taken.take().unwrap().free();
+}
+
+#[itest]
+fn injectable_new() {
+    let mut field = Injectable::new(5);
+    assert_eq!(*field, 5);
+    assert_eq!(*field.init_with(|| 7), 5);
+
+    *field += 1;
+    assert_eq!(field.get(), Some(&6));
+    assert_eq!(field.inject(8), Some(6));
+    assert_eq!(*field, 8);
+}
@@ -0,0 +1,84 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+use godot::prelude::*;
+
+#[derive(GodotClass)]
+#[class(init, base=Object, inline_storage)]
+struct InlineCounter {
+    count: i32,
+    reentered: bool,
+    base: Base<Object>,
+}
+
+#[godot_api]
+impl InlineCounter {
+    #[func]
+    fn increment_reentrant(&mut self) {
+        self.count += 1;
+        self.base_mut().call("mark_reentered".into(), &[]);
+        self.count += 1;
+    }
+
+    #[func]
+    fn mark_reentered(&mut self) {
+        self.reentered = true;
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, inline_storage)]
+struct InlineRefCounted {
+    #[var]
+    value: Vector2,
+}
+
+#[itest]
+fn inline_storage_bind() {
+    let mut obj = InlineCounter::new_alloc();
+    assert_eq!(obj.bind().count, 0);
+
+    obj.bind_mut().count = 5;
+    assert_eq!(obj.bind().count, 5);
+
+    let mut other = obj.clone();
+    let guard = obj.bind();
+    assert!(other.try_bind_mut().is_err());
+    drop(guard);
+    assert!(other.try_bind_mut().is_ok());
+
+    obj.free();
+}
+
+#[itest]
+fn inline_storage_base_reborrow() {
+    let mut obj = InlineCounter::new_alloc();
+
+    obj.call("increment_reentrant".into(), &[]);
+
+    let guard = obj.bind();
+    assert_eq!(guard.count, 2);
+    assert!(guard.reentered);
+    drop(guard);
+
+    obj.free();
+}
+
+#[itest]
+fn inline_storage_refcounted() {
+    let obj = Gd::from_object(InlineRefCounted {
+        value: Vector2::new(1.0, 2.0),
+    });
+    let mut copy = obj.clone();
+
+    copy.bind_mut().value.x = 3.0;
+    assert_eq!(obj.get("value".into()), Vector2::new(3.0, 2.0).to_variant());
+
+    drop(copy);
+    assert_eq!(obj.bind().value, Vector2::new(3.0, 2.0));
+}
@@ -7,19 +7,28 @@
 
 mod base_test;
 mod class_rename_test;
+mod dyn_gd_test;
 mod dynamic_call_test;
-// `get_property_list` is only supported in godot 4.3+
-#[cfg(since_api = "4.3")]
 mod get_property_list_test;
 mod init_level_test;
+mod injectable_test;
+// Inline storage has no effect with threads, and emits a warning.
+#[cfg(not(feature = "experimental-threads"))]
+mod inline_storage_test;
 mod object_swap_test;
 mod object_test;
 mod onready_test;
+mod persist_test;
+mod prop_test;
 mod property_template_test;
 mod property_test;
 mod reentrant_test;
+mod scoped_bind_test;
+mod script_handle_test;
 mod singleton_test;
+mod state_machine_test;
 mod virtual_methods_test;
+mod weak_gd_test;
 
 // Need to test this in the init level method.
 pub use init_level_test::initialize_init_level_test;
@@ -129,6 +129,51 @@ fn object_debug() {
     obj.free();
 }
 
+#[derive(GodotClass)]
+#[class(init, base=RefCounted, debug)]
+struct DebugPayload {
+    value: i32,
+    other: Option<Gd<DebugPayload>>,
+    base: Base<RefCounted>,
+}
+
+#[itest]
+fn object_user_debug() {
+    let mut obj = DebugPayload::new_gd();
+    obj.bind_mut().value = 7;
+    let id = obj.instance_id();
+
+    let actual = format!("{obj:?}");
+    let expected =
+        format!("Gd {{ id: {id}, class: DebugPayload, instance: DebugPayload {{ value: 7, other: None }} }}");
+    assert_eq!(actual, expected);
+
+    // Must not panic while bound.
+    let mut obj2 = obj.clone();
+    let guard = obj2.bind_mut();
+    let actual = format!("{obj:?}");
+    let expected = format!("Gd {{ id: {id}, class: DebugPayload, instance: <bound> }}");
+    assert_eq!(actual, expected);
+    drop(guard);
+}
+
+#[itest]
+fn object_user_debug_cycle() {
+    let mut obj = DebugPayload::new_gd();
+    let clone = obj.clone();
+    obj.bind_mut().other = Some(clone);
+    let id = obj.instance_id();
+
+    let actual = format!("{obj:?}");
+    let expected = format!(
+        "Gd {{ id: {id}, class: DebugPayload, instance: DebugPayload {{ value: 0, other: Some(Gd {{ id: {id}, class: DebugPayload }}) }} }}"
+    );
+    assert_eq!(actual, expected);
+
+    // Break cycle, to not leak.
+    obj.bind_mut().other = None;
+}
+
 #[itest]
 fn object_instance_id() {
     let value: i16 = 17943;
@@ -217,6 +262,77 @@ fn object_from_instance_id_unrelated_type() {
     node.free();
 }
 
+#[itest]
+fn object_from_instance_id_error_kinds() {
+    use godot::meta::error::InstanceIdError;
+
+    let node = Node3D::new_alloc();
+    let id = node.instance_id();
+
+    let err = Gd::<RefCounted>::try_from_instance_id(id).unwrap_err();
+    assert_eq!(
+        err,
+        InstanceIdError::WrongType {
+            id,
+            expected: "RefCounted".to_string(),
+            actual: "Node3D".to_string(),
+        }
+    );
+    assert!(!err.is_dead());
+
+    node.free();
+
+    let err = Gd::<Node3D>::try_from_instance_id(id).unwrap_err();
+    assert_eq!(err, InstanceIdError::Dead { id });
+    assert_eq!(err.instance_id(), id);
+}
+
+#[itest]
+fn object_from_instance_id_convert_error_compat() {
+    use godot::meta::error::{ConvertError, InstanceIdError};
+
+    // Code written against the previous `ConvertError` return type keeps compiling with `?`.
+    fn lookup(id: InstanceId) -> Result<Gd<Node3D>, ConvertError> {
+        let node = Gd::<Node3D>::try_from_instance_id(id)?;
+        Ok(node)
+    }
+
+    let node = Node3D::new_alloc();
+    let id = node.instance_id();
+    assert_eq!(lookup(id).expect("object is alive"), node);
+
+    node.free();
+    let err = lookup(id).unwrap_err();
+    let cause = err
+        .cause()
+        .and_then(|cause| cause.downcast_ref::<InstanceIdError>());
+    assert_eq!(cause, Some(&InstanceIdError::Dead { id }));
+}
+
+#[itest]
+fn object_instance_id_is_valid() {
+    let node = Node::new_alloc();
+    let id = node.instance_id();
+    assert!(id.is_valid());
+
+    node.free();
+    assert!(!id.is_valid());
+    assert!(!InstanceId::from_i64(0xDEADBEEF).is_valid());
+}
+
+#[itest]
+fn object_instance_id_var() {
+    use godot::register::property::Var;
+
+    let mut id = InstanceId::from_i64(5);
+    id.set_property(7);
+    assert_eq!(id.get_property(), 7);
+
+    // Zero is rejected, keeping the previous value.
+    id.set_property(0);
+    assert_eq!(id, InstanceId::from_i64(7));
+}
+
 #[itest]
 fn object_new_has_instance_id() {
     let obj = ObjPayload::new_alloc();
@@ -450,6 +566,36 @@ fn object_engine_convert_variant() {
     obj.free();
 }
 
+#[itest]
+fn object_option_gd_niche() {
+    use std::mem::{align_of, size_of};
+
+    assert_eq!(size_of::<Option<Gd<Node3D>>>(), size_of::<Gd<Node3D>>());
+    assert_eq!(align_of::<Option<Gd<Node3D>>>(), align_of::<Gd<Node3D>>());
+    assert_eq!(
+        size_of::<Option<Gd<RefcPayload>>>(),
+        size_of::<Gd<RefcPayload>>()
+    );
+    assert_eq!(
+        size_of::<[Option<Gd<Object>>; 16]>(),
+        16 * size_of::<Gd<Object>>()
+    );
+
+    // Null objects still travel through FFI as null pointers.
+    let node = Node3D::new_alloc();
+    assert_eq!(node.get_parent(), None);
+
+    let none = Option::<Gd<Node3D>>::None;
+    let variant = none.to_variant();
+    assert!(variant.is_nil());
+    assert_eq!(variant.to::<Option<Gd<Node3D>>>(), None);
+
+    let some = Some(node.clone()).to_variant();
+    assert_eq!(some.to::<Option<Gd<Node3D>>>(), Some(node.clone()));
+
+    node.free();
+}
+
 #[itest]
 fn object_user_convert_variant_refcount() {
     let obj: Gd<RefcPayload> = Gd::from_object(RefcPayload { value: -22222 });
@@ -873,6 +1019,11 @@ impl ObjPayload {
     fn do_panic(&self) {
         panic!("do_panic exploded");
     }
+
+    #[func(on_panic = ScriptError)]
+    fn do_panic_script_error(&self) {
+        panic!("do_panic_script_error exploded");
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
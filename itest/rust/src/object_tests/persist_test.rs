@@ -0,0 +1,160 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Dictionary, GString};
+use godot::classes::Node;
+use godot::meta::ToGodot;
+use godot::obj::{Base, Gd, NewAlloc};
+use godot::register::GodotClass;
+use godot::tools::persist::{Persist, PersistError, SaveGame};
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Node, persist, persist_version = 2, persist_migrate = migrate)]
+struct PersistPlayer {
+    #[persist]
+    health: i32,
+
+    #[persist(key = "name")]
+    display_name: GString,
+
+    transient: bool,
+
+    base: Base<Node>,
+}
+
+impl PersistPlayer {
+    fn migrate(version: u32, fields: &mut Dictionary) {
+        if version < 2 {
+            let percent = fields.get("health").map_or(100, |v| v.to::<i32>());
+            fields.set("health", percent * 5);
+        }
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node, persist)]
+struct PersistChest {
+    #[persist]
+    is_open: bool,
+
+    base: Base<Node>,
+}
+
+#[itest]
+fn persist_save_load_state() {
+    let mut player = PersistPlayer::new_alloc();
+    {
+        let mut p = player.bind_mut();
+        p.health = 250;
+        p.display_name = "Ferris".into();
+        p.transient = true;
+    }
+
+    let state = player.bind().save_state();
+    let fields: Dictionary = state.get("fields").unwrap().to();
+    assert_eq!(fields.get("health"), Some(250.to_variant()));
+    assert_eq!(fields.get("name"), Some("Ferris".to_variant()));
+    assert_eq!(fields.get("transient"), None);
+
+    let mut other = PersistPlayer::new_alloc();
+    other.bind_mut().load_state(&state).expect("load_state");
+    assert_eq!(other.bind().health, 250);
+    assert_eq!(other.bind().display_name, GString::from("Ferris"));
+    assert!(!other.bind().transient);
+
+    player.free();
+    other.free();
+}
+
+#[itest]
+fn persist_migrate_and_version_check() {
+    let mut player = PersistPlayer::new_alloc();
+
+    let mut old_fields = Dictionary::new();
+    old_fields.set("health", 40);
+
+    let mut old_state = player.bind().save_state();
+    old_state.set("version", 1);
+    old_state.set("fields", old_fields);
+
+    player
+        .bind_mut()
+        .load_state(&old_state)
+        .expect("migrated load");
+    assert_eq!(player.bind().health, 200);
+
+    let mut new_state = old_state.clone();
+    new_state.set("version", 3);
+    let err = player.bind_mut().load_state(&new_state).unwrap_err();
+    assert!(matches!(
+        err,
+        PersistError::VersionTooNew {
+            saved: 3,
+            current: 2,
+            ..
+        }
+    ));
+
+    let chest = PersistChest::new_alloc();
+    let err = player
+        .bind_mut()
+        .load_state(&chest.bind().save_state())
+        .unwrap_err();
+    assert!(matches!(err, PersistError::ClassMismatch { .. }));
+
+    player.free();
+    chest.free();
+}
+
+#[itest]
+fn persist_save_game_subtree() {
+    let mut root = Node::new_alloc();
+    let mut player = PersistPlayer::new_alloc();
+    player.set_name("Player".into());
+    let mut chest = PersistChest::new_alloc();
+    chest.set_name("Chest".into());
+
+    root.add_child(player.clone().upcast());
+    player.add_child(chest.clone().upcast());
+
+    player.bind_mut().health = 123;
+    chest.bind_mut().is_open = true;
+
+    let save = SaveGame::capture(&root);
+    assert_eq!(save.len(), 2);
+    assert!(save.node_state("Player/Chest").is_some());
+
+    // Roundtrip through binary format.
+    let save = SaveGame::from_bytes(&save.to_bytes()).expect("from_bytes");
+
+    player.bind_mut().health = 0;
+    chest.bind_mut().is_open = false;
+
+    let restored = save.restore(&root).expect("restore");
+    assert_eq!(restored, 2);
+    assert_eq!(player.bind().health, 123);
+    assert!(chest.bind().is_open);
+
+    root.free();
+}
+
+#[itest]
+fn persist_restore_skips_missing_nodes() {
+    let mut root = Node::new_alloc();
+    let mut chest = PersistChest::new_alloc();
+    chest.set_name("Chest".into());
+    root.add_child(chest.clone().upcast());
+
+    let save = SaveGame::capture(&root);
+    let empty: Gd<Node> = Node::new_alloc();
+    assert_eq!(save.restore(&empty).expect("restore"), 0);
+
+    empty.free();
+    root.free();
+}
@@ -0,0 +1,172 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use godot::obj::Prop;
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct PropHolder {
+    #[var]
+    #[init(default = Prop::new(100))]
+    health: Prop<i32>,
+
+    #[export]
+    label: Prop<GString>,
+
+    base: Base<RefCounted>,
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct PropSignalReceiver {
+    received: Vec<i32>,
+}
+
+#[godot_api]
+impl PropSignalReceiver {
+    #[func]
+    fn on_health_changed(&mut self, value: i32) {
+        self.received.push(value);
+    }
+}
+
+#[itest]
+fn prop_observer_notified_on_change() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let seen_in_observer = seen.clone();
+
+    let mut prop = Prop::new(1);
+    let link = prop.on_changed(move |value| seen_in_observer.borrow_mut().push(*value));
+
+    assert!(prop.set(2));
+    assert!(!prop.set(2), "equal value does not notify");
+    assert!(prop.modify(|value| *value += 3));
+    assert_eq!(prop.get(), 5);
+
+    link.disconnect();
+    prop.set(6);
+
+    assert_eq!(*seen.borrow(), vec![2, 5]);
+    assert_eq!(prop.observer_count(), 0);
+}
+
+#[itest]
+fn prop_bind_one_way() {
+    let mut source = Prop::new(1);
+    let mut target = Prop::new(0);
+    let mut text = Prop::new(GString::new());
+
+    source.bind_to(&target);
+    source.bind_to_with(&text, |value| GString::from(format!("HP: {value}")));
+    assert_eq!(target.get(), 1, "target synced on bind");
+
+    source.set(7);
+    assert_eq!(target.get(), 7);
+    assert_eq!(text.get(), GString::from("HP: 7"));
+
+    // Changes to the target are not propagated back.
+    target.set(9);
+    text.set(GString::from("unrelated"));
+    assert_eq!(source.get(), 7);
+}
+
+#[itest]
+fn prop_bind_two_way() {
+    let mut a = Prop::new(1);
+    let mut b = Prop::new(2);
+
+    let link = a.bind_two_way(&b);
+    assert_eq!(b.get(), 1);
+
+    a.set(3);
+    assert_eq!(b.get(), 3);
+
+    b.set(4);
+    assert_eq!(a.get(), 4);
+
+    link.disconnect();
+    a.set(5);
+    assert_eq!(b.get(), 4);
+}
+
+#[itest]
+fn prop_binding_outlives_target() {
+    let mut source = Prop::new(1);
+    let link = {
+        let target = Prop::new(0);
+        source.bind_to(&target)
+    };
+
+    // Dropped target is skipped, and its binding removed.
+    assert_eq!(source.observer_count(), 1);
+    assert!(source.set(2));
+    assert!(link.is_connected());
+    assert_eq!(source.observer_count(), 0);
+}
+
+#[itest]
+fn prop_class_field() {
+    let mut obj = PropHolder::new_gd();
+
+    assert!(obj.has_signal("health_changed".into()));
+    assert!(obj.has_signal("label_changed".into()));
+    assert_eq!(obj.get("health".into()), 100.to_variant());
+
+    let seen = Rc::new(RefCell::new(0));
+    let seen_in_observer = seen.clone();
+    obj.bind()
+        .health
+        .on_changed(move |value| *seen_in_observer.borrow_mut() = *value);
+
+    // Setting from Godot goes through Prop::set().
+    obj.set("health".into(), 42.to_variant());
+    assert_eq!(*seen.borrow(), 42);
+    assert_eq!(obj.bind().health.get(), 42);
+}
+
+#[itest]
+fn prop_changed_signal_emitted() {
+    let mut obj = PropHolder::new_gd();
+    let receiver = PropSignalReceiver::new_gd();
+
+    obj.connect(
+        "health_changed".into(),
+        Callable::from_object_method(&receiver, "on_health_changed"),
+    );
+
+    // Emitted immediately, once per actual change.
+    obj.set("health".into(), 50.to_variant());
+    obj.set("health".into(), 50.to_variant());
+    obj.bind_mut().health.set(60);
+    assert_eq!(receiver.bind().received, vec![50, 60]);
+
+    // Silent changes don't emit.
+    obj.bind_mut().health.set_silently(70);
+    assert_eq!(receiver.bind().received, vec![50, 60]);
+}
+
+#[itest]
+fn prop_attach_removed_after_owner_freed() {
+    let mut prop = {
+        let obj = PropHolder::new_gd();
+        let mut prop = Prop::new(1);
+        prop.attach(&obj.bind().base, "health_changed");
+
+        prop
+    };
+
+    // Owner is freed; the emission is removed on the next change.
+    assert_eq!(prop.observer_count(), 1);
+    prop.set(2);
+    assert_eq!(prop.observer_count(), 0);
+}
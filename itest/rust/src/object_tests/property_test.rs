@@ -5,11 +5,17 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use godot::builtin::{dict, Color, Dictionary, GString, Variant, VariantType};
-use godot::classes::{INode, IRefCounted, Node, Object, RefCounted, Resource, Texture};
+use godot::builtin::{
+    array, dict, Array, Color, Dictionary, GString, NodePath, Variant, VariantType,
+};
+use godot::classes::{
+    ClassDb, Control, INode, IRefCounted, Node, Node2D, Object, RefCounted, Resource, Texture,
+};
 use godot::global::{PropertyHint, PropertyUsageFlags};
+use godot::meta::error::NodeLookupError;
 use godot::meta::{GodotConvert, ToGodot};
 use godot::obj::{Base, EngineBitfield, EngineEnum, Gd, NewAlloc, NewGd};
+use godot::register::flags::FlagSet;
 use godot::register::property::{Export, PropertyHintInfo, Var};
 use godot::register::{godot_api, Export, GodotClass, GodotConvert, Var};
 use godot::test::itest;
@@ -287,6 +293,9 @@ struct CheckAllExports {
     #[export(global_file = "*.txt")]
     global_file_filter: GString,
 
+    #[export(file = ["*.json", "*.cfg"])]
+    file_filter_list: String,
+
     #[export(dir)]
     dir: GString,
 
@@ -363,6 +372,31 @@ fn derive_export() {
     check_property(&property, "usage", PropertyUsageFlags::DEFAULT.ord());
 }
 
+#[itest]
+fn export_file_filters() {
+    // Class has no constructor; query ClassDB directly.
+    let properties = ClassDb::singleton().class_get_property_list("CheckAllExports".into());
+
+    let find = |name: &str| {
+        properties
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == name.to_variant())
+            .unwrap()
+    };
+
+    let property = find("file_filter_list");
+    check_property(&property, "type", VariantType::STRING.ord());
+    check_property(&property, "hint", PropertyHint::FILE.ord());
+    check_property(&property, "hint_string", "*.json,*.cfg");
+
+    let property = find("global_file_filter");
+    check_property(&property, "hint", PropertyHint::GLOBAL_FILE.ord());
+    check_property(&property, "hint_string", "*.txt");
+
+    let property = find("dir");
+    check_property(&property, "hint", PropertyHint::DIR.ord());
+}
+
 #[derive(GodotClass)]
 #[class(init, base=Resource)]
 pub struct CustomResource {}
@@ -444,6 +478,402 @@ fn override_export() {
     check_property(&property, "usage", PropertyUsageFlags::GROUP.ord());
 }
 
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportCollections {
+    #[export]
+    resources: Array<Gd<CustomResource>>,
+
+    #[export]
+    textures: Array<Option<Gd<Texture>>>,
+
+    #[export(dictionary = (GString, Option<Gd<Texture>>))]
+    texture_dict: Dictionary,
+}
+
+#[itest]
+fn export_resource_collections() {
+    let class = ExportCollections::new_alloc();
+    let find_property = |name: &str| {
+        class
+            .get_property_list()
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == name.to_variant())
+            .unwrap()
+    };
+
+    let resource_hint = PropertyHint::RESOURCE_TYPE.ord();
+    let object_type = VariantType::OBJECT.ord();
+
+    let property = find_property("resources");
+    check_property(&property, "type", VariantType::ARRAY.ord());
+    check_property(&property, "hint", PropertyHint::TYPE_STRING.ord());
+    check_property(
+        &property,
+        "hint_string",
+        format!("{object_type}/{resource_hint}:CustomResource"),
+    );
+
+    let property = find_property("textures");
+    check_property(&property, "type", VariantType::ARRAY.ord());
+    check_property(&property, "hint", PropertyHint::TYPE_STRING.ord());
+    check_property(
+        &property,
+        "hint_string",
+        format!("{object_type}/{resource_hint}:Texture"),
+    );
+
+    let property = find_property("texture_dict");
+    check_property(&property, "type", VariantType::DICTIONARY.ord());
+    #[cfg(since_api = "4.4")]
+    {
+        let string_type = VariantType::STRING.ord();
+
+        check_property(&property, "hint", PropertyHint::DICTIONARY_TYPE.ord());
+        check_property(
+            &property,
+            "hint_string",
+            format!("{string_type}:;{object_type}/{resource_hint}:Texture"),
+        );
+    }
+    #[cfg(before_api = "4.4")]
+    check_property(&property, "hint", PropertyHint::NONE.ord());
+
+    class.free();
+}
+
 fn check_property(property: &Dictionary, key: &str, expected: impl ToGodot) {
     assert_eq!(property.get_or_nil(key), expected.to_variant());
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Flattened groups
+
+#[cfg(since_api = "4.2")]
+#[derive(godot::register::ExportGroup, Default)]
+struct MovementSettings {
+    #[export(range = (0.0, 100.0))]
+    speed: f32,
+    accel: i32,
+}
+
+#[cfg(since_api = "4.2")]
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportFlatten {
+    #[export(flatten)]
+    movement: MovementSettings,
+
+    #[export]
+    other: i32,
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn export_flatten() {
+    let mut class = ExportFlatten::new_alloc();
+
+    let property = class
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "movement/speed".to_variant())
+        .expect("flattened property registered");
+    check_property(&property, "type", VariantType::FLOAT.ord());
+    check_property(&property, "hint", PropertyHint::RANGE.ord());
+    check_property(&property, "usage", PropertyUsageFlags::DEFAULT.ord());
+
+    class.set("movement/speed".into(), 42.5.to_variant());
+    class.set("movement/accel".into(), 7.to_variant());
+    assert_eq!(class.bind().movement.speed, 42.5);
+    assert_eq!(class.bind().movement.accel, 7);
+
+    class.bind_mut().movement.accel = 11;
+    assert_eq!(class.get("movement/accel".into()), 11.to_variant());
+
+    class.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn export_flatten_invalid_value() {
+    let mut class = ExportFlatten::new_alloc();
+    class.bind_mut().movement.accel = 3;
+
+    // Not convertible to i32: reported as error, the field is unchanged.
+    class.set("movement/accel".into(), "fast".to_variant());
+    assert_eq!(class.bind().movement.accel, 3);
+
+    class.free();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Typed flags
+
+#[derive(godot::register::GodotFlags, Copy, Clone, Debug)]
+#[repr(u32)]
+enum Layer {
+    Terrain = 1 << 0,
+    Player = 1 << 1,
+    Enemies = 1 << 3,
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportFlags {
+    #[export]
+    detects: FlagSet<Layer>,
+
+    #[export(flags = Layer)]
+    raw_mask: u32,
+}
+
+#[itest]
+fn flag_set_operations() {
+    let mut set = Layer::Terrain | Layer::Enemies;
+    assert_eq!(set.bits(), 0b1001);
+    assert!(set.contains(Layer::Enemies));
+    assert!(!set.contains(Layer::Player));
+
+    set.insert(Layer::Player);
+    set.remove(Layer::Terrain);
+    assert_eq!(set.iter().collect::<Vec<_>>().len(), 2);
+    assert_eq!(format!("{set:?}"), "FlagSet(Player | Enemies)");
+
+    // Unknown bits survive.
+    let set = FlagSet::<Layer>::from_bits(0b10_0010);
+    assert_eq!(set.bits(), 0b10_0010);
+    assert_eq!(format!("{set:?}"), "FlagSet(Player | 0x20)");
+
+    assert_eq!(FlagSet::<Layer>::all().bits(), 0b1011);
+    assert!(FlagSet::<Layer>::default().is_empty());
+    assert_eq!(Layer::Enemies.name(), Some("Enemies"));
+}
+
+#[itest]
+fn export_flags_typed() {
+    let mut class = ExportFlags::new_alloc();
+
+    for name in ["detects", "raw_mask"] {
+        let property = class
+            .get_property_list()
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == name.to_variant())
+            .unwrap();
+
+        check_property(&property, "type", VariantType::INT.ord());
+        check_property(&property, "hint", PropertyHint::FLAGS.ord());
+        check_property(&property, "hint_string", "Terrain:1,Player:2,Enemies:8");
+    }
+
+    class.set("detects".into(), 0b1010.to_variant());
+    assert!(class.bind().detects.contains(Layer::Player));
+    assert!(class.bind().detects.contains(Layer::Enemies));
+
+    class.bind_mut().detects.remove(Layer::Player);
+    assert_eq!(class.get("detects".into()), 0b1000.to_variant());
+
+    class.free();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Node paths
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportNodePaths {
+    #[export(node_path = (Node2D, Control))]
+    target: NodePath,
+
+    #[export]
+    any_paths: Vec<NodePath>,
+
+    #[export(node_path = (Node2D))]
+    array_paths: Array<NodePath>,
+
+    #[export(node_path = (Node2D))]
+    vec_paths: Vec<NodePath>,
+}
+
+#[itest]
+fn export_node_paths() {
+    let mut class = ExportNodePaths::new_alloc();
+    let find_property = |name: &str| {
+        class
+            .get_property_list()
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == name.to_variant())
+            .unwrap()
+    };
+
+    let property = find_property("target");
+    check_property(&property, "type", VariantType::NODE_PATH.ord());
+    check_property(&property, "hint", PropertyHint::NODE_PATH_VALID_TYPES.ord());
+    check_property(&property, "hint_string", "Node2D,Control");
+
+    let node_path_type = VariantType::NODE_PATH.ord();
+    let valid_types_hint = PropertyHint::NODE_PATH_VALID_TYPES.ord();
+
+    for name in ["array_paths", "vec_paths"] {
+        let property = find_property(name);
+        check_property(&property, "type", VariantType::ARRAY.ord());
+        check_property(&property, "hint", PropertyHint::TYPE_STRING.ord());
+        check_property(
+            &property,
+            "hint_string",
+            format!("{node_path_type}/{valid_types_hint}:Node2D"),
+        );
+    }
+
+    // Without filter, `Vec<NodePath>` is exported like `Array<NodePath>`.
+    let property = find_property("any_paths");
+    check_property(&property, "type", VariantType::ARRAY.ord());
+    check_property(
+        &property,
+        "hint_string",
+        <Array<NodePath> as Export>::default_export_info().hint_string,
+    );
+
+    class.set(
+        "vec_paths".into(),
+        array![NodePath::from("A"), NodePath::from("B")].to_variant(),
+    );
+    assert_eq!(
+        class.bind().vec_paths,
+        vec![NodePath::from("A"), NodePath::from("B")]
+    );
+
+    class.free();
+}
+
+#[itest]
+fn resolve_nodes_aggregates_errors() {
+    let mut root = Node::new_alloc();
+
+    let mut sprite = Node2D::new_alloc();
+    sprite.set_name("Sprite".into());
+    root.add_child(sprite.clone().upcast());
+
+    let mut plain = Node::new_alloc();
+    plain.set_name("Plain".into());
+    root.add_child(plain.clone());
+
+    let nodes = root
+        .resolve_nodes_as::<Node2D>(&[NodePath::from("Sprite")])
+        .expect("all paths valid");
+    assert_eq!(nodes, vec![sprite.clone()]);
+
+    let paths = vec![
+        NodePath::from("Sprite"),
+        NodePath::from("Missing"),
+        NodePath::from("Plain"),
+        NodePath::default(),
+    ];
+    let err = root
+        .resolve_nodes_as::<Node2D>(&paths)
+        .expect_err("invalid paths");
+
+    assert_eq!(err.total(), 4);
+    assert_eq!(
+        err.failures(),
+        &[
+            (
+                1,
+                NodeLookupError::NotFound {
+                    path: "Missing".into()
+                }
+            ),
+            (
+                2,
+                NodeLookupError::WrongType {
+                    path: "Plain".into(),
+                    expected: "Node2D".to_string(),
+                    actual: "Node".to_string(),
+                }
+            ),
+            (3, NodeLookupError::Empty),
+        ]
+    );
+    assert!(
+        err.to_string()
+            .starts_with("3 of 4 node paths could not be resolved:"),
+        "{err}"
+    );
+
+    root.free();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct ValidatedProperty {
+    #[var(validate = clamp_health)]
+    #[init(default = 50)]
+    health: i32,
+
+    #[export(validate = check_name)]
+    #[init(default = GString::from("initial"))]
+    name: GString,
+
+    // Implements only `Var`, not `FromGodot`.
+    #[var(validate = reject_c)]
+    mode: SomeCStyleEnum,
+}
+
+impl ValidatedProperty {
+    fn clamp_health(&self, new_value: i32) -> Result<i32, String> {
+        Ok(new_value.clamp(0, 100))
+    }
+
+    fn check_name(&self, new_value: GString) -> Result<GString, String> {
+        if new_value.is_empty() {
+            Err("name must not be empty".to_string())
+        } else {
+            Ok(new_value)
+        }
+    }
+
+    fn reject_c(&self, new_value: SomeCStyleEnum) -> Result<SomeCStyleEnum, String> {
+        match new_value {
+            SomeCStyleEnum::C => Err("mode C is not allowed".to_string()),
+            other => Ok(other),
+        }
+    }
+}
+
+#[itest]
+fn var_validate_replaces_value() {
+    let mut obj = ValidatedProperty::new_gd();
+
+    obj.set("health".into(), 150.to_variant());
+    assert_eq!(obj.bind().health, 100);
+
+    obj.bind_mut().set_health(-5);
+    assert_eq!(obj.bind().health, 0);
+
+    obj.set("health".into(), 42.to_variant());
+    assert_eq!(obj.get("health".into()), 42.to_variant());
+}
+
+#[itest]
+fn export_validate_keeps_old_value_on_error() {
+    let mut obj = ValidatedProperty::new_gd();
+
+    obj.set("name".into(), "renamed".to_variant());
+    assert_eq!(obj.bind().name, GString::from("renamed"));
+
+    // Rejected: logs an error and keeps the previous value.
+    obj.set("name".into(), "".to_variant());
+    assert_eq!(obj.bind().name, GString::from("renamed"));
+}
+
+#[itest]
+fn var_validate_var_only_type() {
+    let mut obj = ValidatedProperty::new_gd();
+
+    obj.set("mode".into(), 1.to_variant());
+    assert_eq!(obj.get("mode".into()), 1.to_variant());
+
+    obj.set("mode".into(), 2.to_variant());
+    assert_eq!(obj.get("mode".into()), 1.to_variant());
+}
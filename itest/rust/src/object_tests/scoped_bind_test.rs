@@ -0,0 +1,125 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::{Node, RefCounted};
+use godot::obj::{bind_scope, Gd, NewAlloc, NewGd};
+use godot::register::GodotClass;
+
+use crate::framework::{expect_panic, itest};
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct Fighter {
+    health: i32,
+    damage: i32,
+}
+
+fn fighter(health: i32, damage: i32) -> Gd<Fighter> {
+    let mut gd = Fighter::new_gd();
+    gd.with_mut(|f| {
+        f.health = health;
+        f.damage = damage;
+    });
+    gd
+}
+
+#[itest]
+fn gd_with_and_with_mut() {
+    let mut gd = fighter(10, 3);
+
+    assert_eq!(gd.with(|f| f.health), 10);
+    gd.with_mut(|f| f.health -= 4);
+    assert_eq!(gd.bind().health, 6);
+}
+
+#[itest]
+fn gd_try_with_reports_conflicts() {
+    let mut gd = fighter(10, 3);
+    let mut other = gd.clone();
+
+    {
+        let _guard = gd.bind_mut();
+
+        let err = other.try_with(|f| f.health).expect_err("bound mutably");
+        assert!(!err.is_exclusive());
+        assert_eq!(err.instance_id(), Some(other.instance_id()));
+
+        let err = other.try_with_mut(|f| f.health).expect_err("bound mutably");
+        assert!(err.is_exclusive());
+    }
+
+    {
+        let _guard = gd.bind();
+        assert_eq!(other.try_with(|f| f.health), Ok(10), "shared binds coexist");
+        assert!(other.try_bind_mut().is_err());
+    }
+
+    assert_eq!(other.try_with_mut(|f| f.health), Ok(10));
+}
+
+#[itest]
+fn bind_scope_multiple() {
+    let attacker = fighter(10, 3);
+    let mut defender = fighter(5, 1);
+
+    let defeated = bind_scope!(ref attacker = attacker, mut defender = defender => {
+        defender.health -= 2 * attacker.damage;
+        defender.health <= 0
+    });
+
+    assert!(defeated);
+    assert_eq!(defender.bind().health, -1);
+    assert!(defender.try_bind_mut().is_ok(), "guards released");
+}
+
+#[itest]
+fn bind_scope_references() {
+    fn attack(attacker: &Gd<Fighter>, defender: &mut Gd<Fighter>) -> bool {
+        bind_scope!(ref attacker = attacker, mut defender = defender => {
+            defender.health -= attacker.damage;
+            defender.health <= 0
+        })
+    }
+
+    let attacker = fighter(10, 3);
+    let mut defender = fighter(5, 1);
+
+    assert!(!attack(&attacker, &mut defender));
+    assert!(attack(&attacker, &mut defender));
+    assert_eq!(defender.bind().health, -1);
+}
+
+#[itest]
+fn bind_scope_same_object_panics() {
+    let a = fighter(10, 3);
+    let mut b = a.clone();
+
+    expect_panic("same object bound shared and mutably", || {
+        bind_scope!(ref a = a, mut b = b => {
+            b.health += a.health;
+        });
+    });
+
+    // Shared access to the same object is fine.
+    let c = a.clone();
+    let sum = bind_scope!(ref a = a, ref c = c => { a.health + c.health });
+    assert_eq!(sum, 20);
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct NodeFighter {}
+
+#[itest]
+fn gd_try_with_dead_object() {
+    let obj = NodeFighter::new_alloc();
+    let dead = obj.clone();
+    obj.free();
+
+    let err = dead.try_with(|_| ()).expect_err("object is dead");
+    assert_eq!(err.instance_id(), None);
+}
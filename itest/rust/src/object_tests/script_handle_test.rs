@@ -0,0 +1,102 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{GString, StringName, Variant};
+use godot::classes::{GDScript, RefCounted, Script};
+use godot::meta::error::ScriptError;
+use godot::meta::ToGodot;
+use godot::obj::{Gd, NewGd};
+
+use crate::framework::itest;
+
+fn make_script() -> Gd<GDScript> {
+    let code = r#"
+extends RefCounted
+
+@export_group("Stats")
+var health: int = 10
+
+func heal(amount: int) -> int:
+    health += amount
+    return health
+
+func greet(name: String) -> String:
+    return str("Hello ", name)
+"#;
+
+    let mut script = GDScript::new_gd();
+    script.set_source_code(code.into());
+    script.reload();
+    script
+}
+
+#[itest]
+fn script_handle_attach_and_query() {
+    let mut obj = RefCounted::new_gd();
+    assert!(obj.script_handle::<Script>().is_none());
+
+    let handle = obj.attach_script(make_script());
+
+    assert!(handle.has_method("heal"));
+    assert!(
+        !handle.has_method("get_reference_count"),
+        "engine methods excluded"
+    );
+    assert!(handle.has_property("health"));
+    assert!(!handle.has_property("Stats"), "groups excluded");
+    assert_eq!(handle.property_names(), vec![StringName::from("health")]);
+
+    let typed = obj.script_handle::<GDScript>().expect("GDScript attached");
+    assert_eq!(typed.script(), handle.script());
+    assert_eq!(typed.object().instance_id(), obj.instance_id());
+
+    let detached = obj.detach_script().expect("script was attached");
+    assert_eq!(detached.instance_id(), handle.script().instance_id());
+    assert!(obj.script_handle::<Script>().is_none());
+    assert!(obj.detach_script().is_none());
+}
+
+#[itest]
+fn script_handle_call_and_properties() {
+    let mut obj = RefCounted::new_gd();
+    let mut handle = obj.attach_script(make_script());
+
+    let greeting: GString = handle.call("greet", &["Rust".to_variant()]).unwrap();
+    assert_eq!(greeting, GString::from("Hello Rust"));
+
+    let health: i64 = handle.call("heal", &[5.to_variant()]).unwrap();
+    assert_eq!(health, 15);
+
+    handle.set("health", 3).unwrap();
+    assert_eq!(handle.get::<i64>("health").unwrap(), 3);
+
+    let raw: Variant = handle.call("heal", &[1.to_variant()]).unwrap();
+    assert_eq!(raw, 4.to_variant());
+}
+
+#[itest]
+fn script_handle_errors() {
+    let mut obj = RefCounted::new_gd();
+    let mut handle = obj.attach_script(make_script());
+
+    let err = handle.call::<Variant>("missing", &[]).unwrap_err();
+    assert!(
+        matches!(&err, ScriptError::MethodNotFound { method, .. } if method == "missing"),
+        "{err}"
+    );
+
+    let err = handle.get::<i64>("mana").unwrap_err();
+    assert!(matches!(err, ScriptError::PropertyNotFound { .. }), "{err}");
+
+    let err = handle.call::<Variant>("heal", &[]).unwrap_err();
+    assert!(matches!(err, ScriptError::Call(_)), "{err}");
+
+    let err = handle
+        .call::<i64>("greet", &["x".to_variant()])
+        .unwrap_err();
+    assert!(matches!(err, ScriptError::Convert(_)), "{err}");
+}
@@ -0,0 +1,202 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[derive(StateEnum, Debug, PartialEq)]
+enum Movement {
+    Idle,
+    Walking { speed: f32 },
+    Jumping(i32),
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct StateHolder {
+    #[init(default = StateMachine::new(Movement::Idle))]
+    state: StateMachine<Movement>,
+
+    base: Base<RefCounted>,
+}
+
+#[derive(GodotClass, Debug, PartialEq)]
+#[class(init, base=Node)]
+enum Door {
+    Closed,
+    Opening { progress: f64 },
+    Open,
+}
+
+#[godot_api]
+impl INode for Door {
+    #[func(gd_self)]
+    fn process(mut this: Gd<Self>, delta: f64) {
+        Self::step(&mut this, |state| match state {
+            Door::Opening { progress } if *progress >= 1.0 => Some(Door::Open),
+            Door::Opening { progress } => {
+                *progress += delta;
+                None
+            }
+            Door::Closed | Door::Open => None,
+        });
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct TransitionRecorder {
+    transitions: Vec<(StringName, StringName)>,
+}
+
+#[godot_api]
+impl TransitionRecorder {
+    #[func]
+    fn on_state_changed(&mut self, from: StringName, to: StringName) {
+        self.transitions.push((from, to));
+    }
+}
+
+fn record_transitions(door: &mut Gd<Door>) -> Gd<TransitionRecorder> {
+    let recorder = TransitionRecorder::new_gd();
+    door.connect(
+        "state_changed".into(),
+        Callable::from_object_method(&recorder, "on_state_changed"),
+    );
+
+    recorder
+}
+
+fn transition_names(recorder: &Gd<TransitionRecorder>) -> Vec<(String, String)> {
+    recorder
+        .bind()
+        .transitions
+        .iter()
+        .map(|(from, to)| (from.to_string(), to.to_string()))
+        .collect()
+}
+
+#[itest]
+fn state_enum_derive() {
+    assert_eq!(Movement::VARIANT_NAMES, ["Idle", "Walking", "Jumping"]);
+    assert_eq!(Movement::Jumping(3).variant_index(), 2);
+    assert_eq!(Movement::Walking { speed: 1.0 }.variant_name(), "Walking");
+
+    assert_eq!(Movement::from_variant_name("Idle"), Some(Movement::Idle));
+    assert_eq!(Movement::from_variant_name("Walking"), None, "data variant");
+    assert_eq!(Movement::from_variant_name("Flying"), None);
+}
+
+#[itest]
+fn state_machine_step() {
+    let mut machine = StateMachine::new(Movement::Idle);
+
+    let changed = machine.step(|state| match state {
+        Movement::Idle => Some(Movement::Walking { speed: 1.0 }),
+        _ => None,
+    });
+    assert!(changed);
+    assert_eq!(machine.variant_name(), "Walking");
+
+    // Mutating data in place is not a transition.
+    let changed = machine.step(|state| {
+        if let Movement::Walking { speed } = state {
+            *speed *= 2.0;
+        }
+        None
+    });
+    assert!(!changed);
+    assert_eq!(*machine.get(), Movement::Walking { speed: 2.0 });
+
+    let previous = machine.transition(Movement::Jumping(5));
+    assert_eq!(previous, Movement::Walking { speed: 2.0 });
+    assert_eq!(*machine.get(), Movement::Jumping(5));
+}
+
+#[itest]
+fn state_machine_class_field() {
+    let mut obj = StateHolder::new_gd();
+
+    assert!(obj.has_signal("state_changed".into()));
+    assert_eq!(
+        obj.get("state".into()),
+        StringName::from("Idle").to_variant()
+    );
+
+    obj.bind_mut()
+        .state
+        .transition(Movement::Walking { speed: 3.0 });
+    assert_eq!(
+        obj.get("state".into()),
+        StringName::from("Walking").to_variant()
+    );
+
+    // Unit variants can be entered by name.
+    obj.set("state".into(), StringName::from("Idle").to_variant());
+    assert_eq!(*obj.bind().state.get(), Movement::Idle);
+
+    // Data variants cannot; the state is kept.
+    obj.set("state".into(), StringName::from("Jumping").to_variant());
+    assert_eq!(*obj.bind().state.get(), Movement::Idle);
+}
+
+#[itest]
+fn state_class_property_and_signal() {
+    let mut door = Door::new_alloc();
+    let recorder = record_transitions(&mut door);
+
+    assert!(door.has_signal("state_changed".into()));
+    assert_eq!(
+        door.get("state".into()),
+        StringName::from("Closed").to_variant()
+    );
+
+    // Unit variants can be entered by name; data variants are rejected.
+    door.set("state".into(), StringName::from("Open").to_variant());
+    assert_eq!(*door.bind(), Door::Open);
+    door.set("state".into(), StringName::from("Opening").to_variant());
+    assert_eq!(*door.bind(), Door::Open);
+
+    let previous = Door::transition(&mut door, Door::Opening { progress: 0.5 });
+    assert_eq!(previous, Door::Open);
+
+    assert_eq!(
+        transition_names(&recorder),
+        [
+            ("Closed".to_string(), "Open".to_string()),
+            ("Open".to_string(), "Opening".to_string()),
+        ]
+    );
+
+    door.free();
+}
+
+#[itest]
+fn state_class_step_dispatch() {
+    let mut door = Door::new_alloc();
+    let recorder = record_transitions(&mut door);
+
+    Door::transition(&mut door, Door::Opening { progress: 0.0 });
+
+    // Updating data in place is not a transition.
+    Door::process(door.clone(), 0.6);
+    assert_eq!(*door.bind(), Door::Opening { progress: 0.6 });
+    Door::process(door.clone(), 0.6);
+    Door::process(door.clone(), 0.6);
+    assert_eq!(*door.bind(), Door::Open);
+
+    assert_eq!(
+        transition_names(&recorder),
+        [
+            ("Closed".to_string(), "Opening".to_string()),
+            ("Opening".to_string(), "Open".to_string()),
+        ]
+    );
+
+    door.free();
+}
@@ -7,6 +7,8 @@
 
 #![allow(dead_code)]
 
+use std::cell::Cell;
+
 use crate::framework::{itest, TestContext};
 
 use godot::builtin::{
@@ -22,7 +24,7 @@ use godot::classes::{
     ResourceLoader, Viewport, Window,
 };
 use godot::meta::ToGodot;
-use godot::obj::{Base, Gd, NewAlloc, NewGd};
+use godot::obj::{Base, Gd, NewAlloc, NewGd, OnReady};
 use godot::private::class_macros::assert_eq_approx;
 use godot::register::{godot_api, GodotClass};
 
@@ -109,6 +111,40 @@ impl INode2D for VirtualTreeTest {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+#[derive(GodotClass, Debug)]
+#[class(init, base=Node2D)]
+struct VirtualGdSelfTest {
+    base: Base<Node2D>,
+    tree_enters: i32,
+    ready_calls: i32,
+    input_calls: Cell<i32>,
+    #[init(default = OnReady::new(|| 42))]
+    ready_value: OnReady<i32>,
+}
+
+#[godot_api]
+impl INode2D for VirtualGdSelfTest {
+    #[func(gd_self)]
+    fn enter_tree(mut this: Gd<Self>) {
+        this.bind_mut().tree_enters += 1;
+    }
+
+    #[func(gd_self)]
+    fn ready(mut this: Gd<Self>) {
+        let mut obj = this.bind_mut();
+        assert_eq!(*obj.ready_value, 42, "OnReady initialized before ready()");
+        obj.ready_calls += 1;
+    }
+
+    #[func(gd_self)]
+    fn input(this: Gd<Self>, _event: Gd<InputEvent>) {
+        let obj = this.bind();
+        obj.input_calls.set(obj.input_calls.get() + 1);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 #[derive(GodotClass, Debug)]
 #[class(init, base=PrimitiveMesh)]
 struct VirtualReturnTest {
@@ -403,6 +439,33 @@ fn test_tree_enters_exits(test_context: &TestContext) {
     assert_eq!(obj.bind().tree_exits, 1);
 }
 
+#[itest]
+fn test_virtual_gd_self(test_context: &TestContext) {
+    let obj = VirtualGdSelfTest::new_alloc();
+    let mut test_viewport = Window::new_alloc();
+
+    test_context
+        .scene_tree
+        .clone()
+        .add_child(test_viewport.clone().upcast());
+
+    test_viewport.clone().add_child(obj.clone().upcast());
+    assert_eq!(obj.bind().tree_enters, 1);
+    assert_eq!(obj.bind().ready_calls, 1);
+
+    let mut event = InputEventAction::new_gd();
+    event.set_action("debug".into());
+    event.set_pressed(true);
+
+    // Shared bind held during the virtual call; a `&mut self` receiver would panic.
+    let guard = obj.bind();
+    test_viewport.clone().push_input(event.upcast());
+    assert_eq!(guard.input_calls.get(), 1);
+    drop(guard);
+
+    test_viewport.queue_free();
+}
+
 #[itest]
 fn test_virtual_method_with_return() {
     let obj = VirtualReturnTest::new_gd();
@@ -0,0 +1,96 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::obj::WeakGd;
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[itest]
+fn weak_gd_manual_upgrade() {
+    let node = Node::new_alloc();
+    let weak = node.downgrade();
+
+    assert!(weak.is_valid());
+    assert_eq!(weak.instance_id(), Some(node.instance_id()));
+    assert_eq!(weak.upgrade(), Some(node.clone()));
+
+    node.free();
+    assert!(!weak.is_valid());
+    assert_eq!(weak.upgrade(), None);
+}
+
+#[itest]
+fn weak_gd_refcounted_does_not_keep_alive() {
+    let object = RefCounted::new_gd();
+    let weak = WeakGd::from_gd(&object);
+
+    {
+        let strong = weak.upgrade().expect("object alive");
+        assert_eq!(strong.get_reference_count(), 2);
+    }
+    assert_eq!(object.get_reference_count(), 1);
+
+    drop(object);
+    assert!(!weak.is_valid());
+    assert_eq!(weak.upgrade(), None);
+}
+
+#[itest]
+fn weak_gd_empty() {
+    let weak = WeakGd::<Node>::default();
+
+    assert!(!weak.is_valid());
+    assert_eq!(weak.instance_id(), None);
+    assert_eq!(weak.upgrade(), None);
+    assert_eq!(weak, WeakGd::new());
+}
+
+#[itest]
+fn weak_gd_wrong_class() {
+    let node = Node::new_alloc();
+    let id = node.instance_id();
+
+    // Same instance ID, but interpreted as a derived class that the object does not have.
+    let weak_node3d = WeakGd::<Node3D>::from_instance_id(id);
+    assert_eq!(weak_node3d.instance_id(), Some(id));
+    assert_eq!(weak_node3d.upgrade(), None);
+    assert!(!weak_node3d.is_valid());
+
+    // Base classes upgrade fine.
+    let weak_object = WeakGd::<Object>::from_instance_id(id);
+    assert_eq!(weak_object.upgrade(), Some(node.clone().upcast::<Object>()));
+
+    node.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct WeakGdHolder {
+    #[var]
+    parent: WeakGd<Node>,
+
+    #[export]
+    target: WeakGd<Node3D>,
+}
+
+#[itest]
+fn weak_gd_property() {
+    let mut holder = WeakGdHolder::new_alloc();
+    let parent = Node::new_alloc();
+
+    assert_eq!(holder.get("parent".into()), Variant::nil());
+
+    holder.set("parent".into(), parent.to_variant());
+    assert_eq!(holder.bind().parent.upgrade(), Some(parent.clone()));
+    assert_eq!(holder.get("parent".into()), parent.to_variant());
+
+    parent.free();
+    assert_eq!(holder.get("parent".into()), Variant::nil());
+
+    holder.free();
+}
@@ -0,0 +1,70 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::{ClassDb, GDScript};
+use godot::obj::{Gd, NewGd};
+use godot::register::{godot_api, GodotClass};
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, abstract)]
+struct AbstractShape {
+    #[var]
+    #[init(default = 3)]
+    sides: i32,
+}
+
+#[godot_api]
+impl AbstractShape {
+    #[func]
+    fn sides_doubled(&self) -> i32 {
+        self.sides * 2
+    }
+}
+
+fn make_square_script() -> Gd<GDScript> {
+    let code = r#"
+extends AbstractShape
+
+func square_sides() -> int:
+    sides = 4
+    return sides_doubled()
+"#;
+
+    let mut script = GDScript::new_gd();
+    script.set_source_code(code.into());
+    script.reload();
+    script
+}
+
+#[derive(GodotClass)]
+#[class(init)]
+struct ConcreteShape {}
+
+#[itest]
+fn abstract_class_registered() {
+    assert!(ClassDb::singleton().class_exists("AbstractShape".into()));
+
+    // Godot only consults the abstract ("virtual") flag in the editor, e.g. in the "Create New Node" dialog.
+    assert!(godot::private::is_class_virtual("AbstractShape"));
+    assert!(!godot::private::is_class_virtual("ConcreteShape"));
+
+    // Rust code can still instantiate the class.
+    let shape = AbstractShape::new_gd();
+    assert_eq!(shape.bind().sides, 3);
+}
+
+#[itest]
+fn abstract_class_inherited_by_script() {
+    let mut shape = AbstractShape::new_gd();
+    let mut handle = shape.attach_script(make_square_script());
+
+    let doubled: i64 = handle.call("square_sides", &[]).unwrap();
+    assert_eq!(doubled, 8);
+    assert_eq!(shape.bind().sides, 4);
+}
@@ -0,0 +1,50 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::{ClassDb, Node};
+use godot::init::InitLevel;
+use godot::obj::{Base, Gd, NewAlloc};
+use godot::register::GodotClass;
+
+use crate::framework::itest;
+
+// Would be deferred on its own, but is pulled in by RequiringWorkshop.
+#[derive(GodotClass)]
+#[class(init, base=Object, lazy)]
+struct RequiredLazyTool {}
+
+// Engine classes like Node are valid requirements, without effect.
+#[derive(GodotClass)]
+#[class(init, base=Node, requires = [RequiredLazyTool, Node])]
+struct RequiringWorkshop {
+    tool: Option<Gd<RequiredLazyTool>>,
+    base: Base<Node>,
+}
+
+#[itest]
+fn class_requires_registers_lazy_dependency() {
+    let class_db = ClassDb::singleton();
+    assert!(class_db.class_exists("RequiringWorkshop".into()));
+
+    // The lazy class must be registered right before the requiring class -- not later, e.g. by register_lazy_classes() in another test.
+    let order = godot::private::registration_order(InitLevel::Scene);
+    let position = |class: &str| {
+        order
+            .iter()
+            .position(|name| name == class)
+            .unwrap_or_else(|| panic!("{class} registered at Scene level"))
+    };
+    assert_eq!(
+        position("RequiredLazyTool") + 1,
+        position("RequiringWorkshop"),
+        "lazy class registered because another class requires it"
+    );
+
+    let workshop = RequiringWorkshop::new_alloc();
+    assert!(workshop.bind().tool.is_none());
+    workshop.free();
+}
@@ -0,0 +1,110 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Godot only asks for configuration warnings in the editor, so the tests call `_get_configuration_warnings` through the same virtual
+// callback that the engine uses.
+
+use godot::classes::{INode, Node, PackedScene};
+use godot::obj::cap::ImplementsGodotVirtual;
+use godot::obj::UserClass;
+use godot::prelude::*;
+use godot::private::call_virtual;
+
+use crate::framework::itest;
+
+const WARNINGS_VIRTUAL: &str = "_get_configuration_warnings";
+
+#[derive(GodotClass)]
+#[class(tool, init, base=Node)]
+struct RequiredWarnings {
+    #[export(required)]
+    scene: Option<Gd<PackedScene>>,
+
+    #[export(required)]
+    target: NodePath,
+
+    #[export]
+    optional: NodePath,
+}
+
+#[derive(GodotClass)]
+#[class(tool, init, base=Node, warnings = check_level)]
+struct CustomWarnings {
+    #[export]
+    level: i32,
+}
+
+impl CustomWarnings {
+    fn check_level(&self) -> Vec<String> {
+        if self.level <= 0 {
+            vec!["Level must be positive.".to_string()]
+        } else {
+            vec![]
+        }
+    }
+}
+
+#[godot_api]
+impl INode for CustomWarnings {
+    fn get_configuration_warnings(&self) -> PackedStringArray {
+        PackedStringArray::from(&["From override.".into()])
+    }
+}
+
+fn warnings_of(warnings: PackedStringArray) -> Vec<String> {
+    warnings.as_slice().iter().map(GString::to_string).collect()
+}
+
+#[itest]
+fn config_warnings_required_unset() {
+    let node = RequiredWarnings::new_alloc();
+
+    let callback = RequiredWarnings::__default_virtual_call(WARNINGS_VIRTUAL);
+    let warnings = warnings_of(call_virtual(&node, callback));
+
+    assert_eq!(
+        warnings,
+        [
+            "Property \"scene\" is required, but not assigned.",
+            "Property \"target\" is required, but not assigned.",
+        ]
+    );
+
+    node.free();
+}
+
+#[itest]
+fn config_warnings_required_set() {
+    let mut node = RequiredWarnings::new_alloc();
+    {
+        let mut guard = node.bind_mut();
+        guard.scene = Some(PackedScene::new_gd());
+        guard.target = NodePath::from("../Target");
+    }
+
+    let callback = RequiredWarnings::__default_virtual_call(WARNINGS_VIRTUAL);
+    let warnings = warnings_of(call_virtual(&node, callback));
+    assert!(warnings.is_empty(), "unexpected warnings: {warnings:?}");
+
+    node.free();
+}
+
+#[itest]
+fn config_warnings_class_fn() {
+    let mut node = CustomWarnings::new_alloc();
+
+    // Generated warnings are appended to those of the overridden get_configuration_warnings().
+    let callback = CustomWarnings::__virtual_call(WARNINGS_VIRTUAL);
+    let warnings = warnings_of(call_virtual(&node, callback));
+    assert_eq!(warnings, ["From override.", "Level must be positive."]);
+
+    node.bind_mut().level = 3;
+    let warnings = warnings_of(call_virtual(&node, callback));
+    assert_eq!(warnings, ["From override."]);
+
+    node.free();
+}
@@ -114,6 +114,41 @@ fn cfg_removes_or_keeps_constants() {
     ));
 }
 
+#[derive(GodotClass)]
+#[class(init)]
+struct HasNonIntegerConstants {
+    base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl HasNonIntegerConstants {
+    #[constant]
+    const GRAVITY: f64 = 9.5;
+
+    #[constant]
+    const TAG: &'static str = "enemy";
+}
+
+#[itest]
+fn constants_non_integer_as_static_functions() {
+    // Not registered as integer constants, but as static functions of the same name.
+    assert!(!class_has_integer_constant::<HasNonIntegerConstants>(
+        "GRAVITY"
+    ));
+    assert!(!class_has_integer_constant::<HasNonIntegerConstants>("TAG"));
+
+    let mut obj = HasNonIntegerConstants::new_gd();
+    assert_eq!(
+        obj.call("GRAVITY", &[]),
+        HasNonIntegerConstants::GRAVITY.to_variant()
+    );
+    assert_eq!(obj.call("TAG", &[]), "enemy".to_variant());
+
+    // Rust-side constants are unchanged.
+    assert_eq!(HasNonIntegerConstants::GRAVITY, 9.5);
+    assert_eq!(HasNonIntegerConstants::TAG, "enemy");
+}
+
 #[derive(GodotClass)]
 #[class(no_init)]
 struct HasOtherConstants {}
@@ -239,3 +274,84 @@ test_enum_export!(
     #[itest]
     fn bitfield_export_correct_values() { .. }
 );
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// #[godot_enum]
+
+#[godot_enum]
+#[repr(i64)]
+enum Direction {
+    Up,
+    Down = 5,
+    Left,
+    Right = -1,
+}
+
+#[godot_enum(rename = Permissions, bitfield)]
+#[repr(i64)]
+enum PermissionFlags {
+    Read = 1,
+    Write = 2,
+    Execute = 4,
+}
+
+#[derive(GodotClass)]
+#[class(no_init)]
+struct HasEnums {}
+
+#[godot_api(enums = [Direction, PermissionFlags])]
+impl HasEnums {
+    #[constant]
+    const PLAIN: i64 = 7;
+}
+
+fn enum_constants<T: GodotClass>(enum_name: &str) -> Vec<(String, i64)> {
+    let class_name = T::class_name().to_string_name();
+
+    ClassDb::singleton()
+        .class_get_enum_constants_ex(class_name.clone(), enum_name.into())
+        .no_inheritance(true)
+        .done()
+        .as_slice()
+        .iter()
+        .map(|name| {
+            let value = ClassDb::singleton()
+                .class_get_integer_constant(class_name.clone(), StringName::from(name));
+
+            (name.to_string(), value)
+        })
+        .collect()
+}
+
+#[itest]
+fn godot_enum_export_correct_values() {
+    let class_name = HasEnums::class_name().to_string_name();
+
+    assert!(ClassDb::singleton()
+        .class_has_enum_ex(class_name.clone(), "Direction".into())
+        .no_inheritance(true)
+        .done());
+    assert!(!ClassDb::singleton().is_class_enum_bitfield(class_name.clone(), "Direction".into()));
+
+    let expected = [("Up", 0), ("Down", 5), ("Left", 6), ("Right", -1)];
+    let expected = expected.map(|(name, value)| (name.to_string(), value));
+    assert_eq!(enum_constants::<HasEnums>("Direction"), expected);
+
+    // Plain constants are still registered alongside enums.
+    assert!(class_has_integer_constant::<HasEnums>("PLAIN"));
+}
+
+#[itest]
+fn godot_enum_export_bitfield_renamed() {
+    let class_name = HasEnums::class_name().to_string_name();
+
+    assert!(!ClassDb::singleton()
+        .class_has_enum_ex(class_name.clone(), "PermissionFlags".into())
+        .no_inheritance(true)
+        .done());
+    assert!(ClassDb::singleton().is_class_enum_bitfield(class_name, "Permissions".into()));
+
+    let expected = [("Read", 1), ("Write", 2), ("Execute", 4)];
+    let expected = expected.map(|(name, value)| (name.to_string(), value));
+    assert_eq!(enum_constants::<HasEnums>("Permissions"), expected);
+}
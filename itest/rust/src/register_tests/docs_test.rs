@@ -0,0 +1,103 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Only compiled with the `register-docs` feature of itest.
+
+use godot::docs::{self, MemberDocs};
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+/// A documented class.
+///
+/// With a longer description,
+/// spanning multiple lines.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct DocumentedClass {
+    /// Health of the entity.
+    #[var]
+    health: i32,
+
+    #[var]
+    undocumented: i32,
+}
+
+#[godot_api]
+impl DocumentedClass {
+    /// Emitted when the entity dies.
+    #[signal]
+    fn died();
+
+    /// Maximum health.
+    #[constant]
+    const MAX_HEALTH: i32 = 100;
+
+    /// Heals the entity.
+    ///
+    /// Never exceeds the maximum health.
+    #[func]
+    fn heal(&mut self, amount: i32) {
+        self.health = (self.health + amount).min(Self::MAX_HEALTH);
+    }
+}
+
+fn member<'a>(members: &'a [MemberDocs], name: &str) -> &'a MemberDocs {
+    members
+        .iter()
+        .find(|member| member.name == name)
+        .unwrap_or_else(|| panic!("member `{name}` has docs"))
+}
+
+#[itest]
+fn docs_class_description() {
+    let class = docs::class_docs::<DocumentedClass>().expect("class docs are registered");
+
+    assert_eq!(class.name, "DocumentedClass");
+    assert_eq!(class.base, "RefCounted");
+    assert_eq!(class.brief, "A documented class.");
+    assert_eq!(
+        class.description,
+        "With a longer description,\nspanning multiple lines."
+    );
+
+    let by_name = docs::class_docs_by_name("DocumentedClass");
+    assert_eq!(by_name.as_ref(), Some(&class));
+    assert!(docs::registered_classes().contains(&class));
+}
+
+#[itest]
+fn docs_class_members() {
+    let class = docs::class_docs::<DocumentedClass>().expect("class docs are registered");
+
+    assert_eq!(
+        member(&class.properties, "health").description,
+        "Health of the entity."
+    );
+    assert_eq!(member(&class.properties, "undocumented").description, "");
+
+    let heal = member(&class.methods, "heal");
+    assert_eq!(heal.brief(), "Heals the entity.");
+    assert_eq!(
+        heal.description,
+        "Heals the entity.\n\nNever exceeds the maximum health."
+    );
+
+    assert_eq!(
+        member(&class.signals, "died").description,
+        "Emitted when the entity dies."
+    );
+    assert_eq!(
+        member(&class.constants, "MAX_HEALTH").description,
+        "Maximum health."
+    );
+}
+
+#[itest]
+fn docs_unregistered_class() {
+    assert_eq!(docs::class_docs_by_name("NoSuchDocumentedClass"), None);
+}
@@ -0,0 +1,80 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::ClassDb;
+use godot::init::{library_metadata, LibraryMetadata};
+use godot::obj::{Gd, NewGd};
+use godot::register::{extensions, GodotClass};
+
+use crate::framework::itest;
+
+#[itest]
+fn extensions_own_classes_recorded() {
+    let class = extensions::find_rust_class("HasConstants").expect("class recorded");
+
+    assert_eq!(class.class_name, "HasConstants");
+    assert_eq!(class.library_path, extensions::library_path());
+    assert!(class.is_own());
+
+    let classes = extensions::rust_classes();
+    assert!(classes.contains(&class));
+    assert!(classes
+        .windows(2)
+        .all(|pair| pair[0].class_name < pair[1].class_name));
+}
+
+#[itest]
+fn extensions_engine_classes_not_recorded() {
+    assert_eq!(extensions::find_rust_class("Node"), None);
+    assert_eq!(extensions::find_rust_class("DoesNotExist"), None);
+}
+
+#[itest]
+fn extensions_library_metadata() {
+    // Defaults to the package of the crate with #[gdextension].
+    assert_eq!(library_metadata(), LibraryMetadata::new("itest", "0.0.0"));
+
+    let library = extensions::find_rust_library("itest").expect("library recorded");
+    assert_eq!(library.version, "0.0.0");
+    assert_eq!(library.library_path, extensions::library_path());
+    assert!(!library.godot_rust_version.is_empty());
+    assert!(library.is_own());
+
+    assert!(extensions::rust_libraries().contains(&library));
+    assert_eq!(extensions::find_rust_library("does_not_exist"), None);
+}
+
+#[cfg(since_api = "4.1")]
+#[itest]
+fn extensions_library_path() {
+    let path = extensions::library_path();
+    assert!(path.contains("itest"), "unexpected library path: {path}");
+}
+
+#[derive(GodotClass)]
+#[class(init, namespace = "", shared)]
+struct SharedCounter {
+    count: i32,
+}
+
+#[itest]
+fn extensions_shared_class_registered_by_first_library() {
+    // As the only library declaring it, itest registers the shared class itself.
+    let class = extensions::find_rust_class("SharedCounter").expect("class recorded");
+    assert!(class.is_own());
+    assert!(!extensions::is_imported("SharedCounter"));
+
+    // Instances created through Godot and through Rust are bound the same way.
+    let mut from_godot = ClassDb::singleton()
+        .instantiate("SharedCounter".into())
+        .to::<Gd<SharedCounter>>();
+    from_godot.bind_mut().count += 1;
+    assert_eq!(from_godot.bind().count, 1);
+
+    let from_rust = SharedCounter::new_gd();
+    assert_eq!(from_rust.bind().count, 0);
+}
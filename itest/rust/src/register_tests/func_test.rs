@@ -11,6 +11,8 @@
 use crate::framework::itest;
 use godot::classes::ClassDb;
 use godot::prelude::*;
+use godot::register::call_stats;
+use std::error::Error;
 
 #[derive(GodotClass)]
 #[class(init, base=RefCounted)]
@@ -251,6 +253,29 @@ impl IRefCounted for GdSelfObj {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct OptParamObj;
+
+#[godot_api]
+impl OptParamObj {
+    #[func]
+    fn shoot(&self, dir: Vector2, #[opt(default = 1.0)] speed: f32) -> Vector2 {
+        dir * speed
+    }
+
+    #[func]
+    fn greet(
+        #[opt(default = GString::from("world"))] name: GString,
+        #[opt(default = 1)] times: i64,
+    ) -> GString {
+        let greetings = vec![format!("Hello {name}!"); times as usize];
+        greetings.join(" ").into()
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Tests
 
@@ -296,6 +321,191 @@ fn cfg_removes_or_keeps_signals() {
     assert!(!class_has_signal::<GdSelfObj>("cfg_removes_signal"));
 }
 
+#[itest]
+fn func_opt_params_omitted() {
+    let mut obj = OptParamObj::new_gd();
+
+    let full = obj.call(
+        "shoot".into(),
+        &[Vector2::UP.to_variant(), 2.5.to_variant()],
+    );
+    assert_eq!(full.to::<Vector2>(), Vector2::new(0.0, -2.5));
+
+    let omitted = obj.call("shoot".into(), &[Vector2::UP.to_variant()]);
+    assert_eq!(omitted.to::<Vector2>(), Vector2::UP);
+
+    // Direct Rust calls are unaffected.
+    assert_eq!(obj.bind().shoot(Vector2::UP, 3.0), Vector2::new(0.0, -3.0));
+}
+
+#[itest]
+fn func_opt_params_static() {
+    // Static methods can also be called through an instance.
+    let mut obj = OptParamObj::new_gd();
+
+    assert_eq!(obj.call("greet".into(), &[]), "Hello world!".to_variant());
+    assert_eq!(
+        obj.call("greet".into(), &["Rust".to_variant()]),
+        "Hello Rust!".to_variant()
+    );
+    assert_eq!(
+        obj.call("greet".into(), &["Rust".to_variant(), 2.to_variant()]),
+        "Hello Rust! Hello Rust!".to_variant()
+    );
+}
+
+#[itest]
+fn func_opt_params_too_few_args() {
+    let mut obj = OptParamObj::new_gd();
+
+    let call_error = obj
+        .try_call("shoot".into(), &[])
+        .expect_err("expected failed call");
+
+    let source = call_error.source().expect("must have source CallError");
+    assert_eq!(
+        source.to_string(),
+        "godot-rust function call failed: OptParamObj::shoot()\
+        \n    Reason: function has 2 parameters (1 required), but received 0 arguments"
+    );
+}
+
+#[itest]
+fn func_opt_params_registered() {
+    let class = godot::register::reflection::find_class("OptParamObj").expect("class registered");
+
+    let shoot = class.method("shoot").expect("shoot() registered");
+    assert_eq!(shoot.default_args, vec![1.0.to_variant()]);
+    assert!(shoot.accepts_arg_count(1));
+    assert!(!shoot.accepts_arg_count(0));
+
+    let greet = class.method("greet").expect("greet() registered");
+    assert_eq!(
+        greet.default_args,
+        vec!["world".to_variant(), 1.to_variant()]
+    );
+    assert!(greet.accepts_arg_count(0));
+}
+
+#[itest]
+fn func_call_stats_count_varcalls() {
+    let stats = || call_stats::method_call_stats("OptParamObj", "shoot");
+    let before = stats();
+
+    let mut obj = OptParamObj::new_gd();
+    obj.call("shoot".into(), &[Vector2::UP.to_variant()]);
+
+    // Direct Rust calls bypass Godot.
+    obj.bind().shoot(Vector2::UP, 1.0);
+
+    let after = stats();
+    if cfg!(debug_assertions) {
+        let before = before.expect("shoot() registered");
+        let after = after.expect("shoot() registered");
+        assert_eq!(after.ptrcalls, before.ptrcalls);
+        assert_eq!(after.varcalls, before.varcalls + 1);
+    } else {
+        // Counters are not registered in release builds.
+        assert_eq!(before, None);
+        assert_eq!(after, None);
+    }
+
+    assert!(call_stats::method_call_stats("OptParamObj", "does_not_exist").is_none());
+}
+
+/// Used by `ManualFfiTests.gd`, which calls `ping()` from typed GDScript (ptrcall) and untyped GDScript (varcall).
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct CallStatsObj;
+
+#[godot_api]
+impl CallStatsObj {
+    #[func]
+    fn ping(&self, value: i64) -> i64 {
+        value
+    }
+
+    /// Returns `[ptrcalls, varcalls]` of `ping()`, or an empty array in release builds.
+    #[func]
+    fn ping_call_counts() -> PackedInt64Array {
+        match call_stats::method_call_stats("CallStatsObj", "ping") {
+            Some(stats) => PackedInt64Array::from(&[stats.ptrcalls as i64, stats.varcalls as i64]),
+            None => PackedInt64Array::new(),
+        }
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct SplitApiObj {
+    count: i64,
+}
+
+#[godot_api]
+impl SplitApiObj {
+    #[constant]
+    const START: i64 = 10;
+
+    #[signal]
+    fn counted(total: i64);
+
+    #[func]
+    fn count(&self) -> i64 {
+        self.count
+    }
+}
+
+mod split_api {
+    use super::SplitApiObj;
+    use godot::prelude::*;
+
+    #[godot_api(secondary)]
+    impl SplitApiObj {
+        #[constant]
+        const STEP: i64 = 5;
+
+        #[signal]
+        fn reset();
+
+        #[func(rename = increment)]
+        fn increment_inner(&mut self) -> i64 {
+            self.count += Self::STEP;
+            self.count
+        }
+    }
+
+    #[godot_api(secondary)]
+    impl SplitApiObj {
+        #[func]
+        fn restart(&mut self) {
+            self.count = Self::START;
+        }
+    }
+}
+
+#[itest]
+fn func_secondary_impl_blocks_merge() {
+    assert!(class_has_method::<SplitApiObj>("count"));
+    assert!(class_has_method::<SplitApiObj>("increment"));
+    assert!(class_has_method::<SplitApiObj>("restart"));
+    assert!(class_has_signal::<SplitApiObj>("counted"));
+    assert!(class_has_signal::<SplitApiObj>("reset"));
+
+    let class_name = SplitApiObj::class_name().to_string_name();
+    let db = ClassDb::singleton();
+    assert_eq!(
+        db.class_get_integer_constant(class_name.clone(), "START".into()),
+        10
+    );
+    assert_eq!(db.class_get_integer_constant(class_name, "STEP".into()), 5);
+
+    let mut obj = SplitApiObj::new_gd();
+    obj.call("restart".into(), &[]);
+    let total = obj.call("increment".into(), &[]);
+    assert_eq!(total, 15.to_variant());
+    assert_eq!(obj.call("count".into(), &[]), 15.to_variant());
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helpers
 
@@ -118,6 +118,42 @@ fn func_virtual_stateful() {
     assert_eq!(retrieved, variant);
 }
 
+#[itest]
+fn func_virtual_has_script_method() {
+    let mut object = VirtualScriptCalls::new_gd();
+    assert!(!object.has_script_method("_greet_lang"));
+
+    object.set_script(make_script().to_variant());
+    assert!(object.has_script_method("_greet_lang"));
+    assert!(object.has_script_method("_double"));
+    assert!(!object.has_script_method("_missing"));
+
+    // Rust-registered methods are not script methods.
+    assert!(!object.has_script_method("get_thing"));
+}
+
+#[itest]
+fn func_virtual_call_script_virtual() {
+    let mut object = VirtualScriptCalls::new_gd();
+
+    // Without script: fallback.
+    let result: i64 = object.call_script_virtual("_double", &[21.to_variant()], || -1);
+    assert_eq!(result, -1);
+
+    // With script: script implementation.
+    object.set_script(make_script().to_variant());
+    let result: i64 = object.call_script_virtual("_double", &[21.to_variant()], || -1);
+    assert_eq!(result, 42);
+
+    let result: GString =
+        object.call_script_virtual("_greet_lang", &[5.to_variant()], || "fallback".into());
+    assert_eq!(result, GString::from("GDScript#5"));
+
+    // Script does not define method: fallback.
+    let result: i64 = object.call_script_virtual("_missing", &[], || 7);
+    assert_eq!(result, 7);
+}
+
 fn make_script() -> Gd<GDScript> {
     let code = r#"
 extends VirtualScriptCalls
@@ -138,6 +174,9 @@ func _set_thing(anything):
 
 func _get_thing():
     return thing
+
+func _double(x: int) -> int:
+    return x * 2
 "#;
 
     let mut script = GDScript::new_gd();
@@ -159,7 +198,8 @@ func _get_thing():
             "greet_lang2",
             "_greet_lang3",
             "_set_thing",
-            "_get_thing"
+            "_get_thing",
+            "_double"
         ]
     );
 
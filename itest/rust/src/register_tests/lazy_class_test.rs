@@ -0,0 +1,59 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::{ClassDb, Object};
+use godot::init::register_lazy_classes;
+use godot::obj::{Gd, NewAlloc};
+use godot::register::GodotClass;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Object, lazy)]
+struct LazyOnFirstUse {}
+
+#[derive(GodotClass)]
+#[class(init, base=Object, lazy)]
+struct LazyOnDemand {}
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct NotLazy {}
+
+// Tests don't assume that the classes are unregistered on entry, as another test may have called register_lazy_classes() before.
+
+#[itest]
+fn lazy_class_registered_on_first_use() {
+    assert!(godot::private::was_class_deferred("LazyOnFirstUse"));
+
+    // Instantiation from Rust registers the class.
+    let obj = LazyOnFirstUse::new_alloc();
+    assert!(ClassDb::singleton().class_exists("LazyOnFirstUse".into()));
+    assert_eq!(obj.get_class(), "LazyOnFirstUse".into());
+    obj.free();
+}
+
+#[itest]
+fn lazy_class_registered_on_demand() {
+    assert!(godot::private::was_class_deferred("LazyOnDemand"));
+
+    // Afterwards, Godot can instantiate the class by name.
+    register_lazy_classes();
+    let class_db = ClassDb::singleton();
+    assert!(class_db.class_exists("LazyOnDemand".into()));
+
+    let obj = class_db
+        .instantiate("LazyOnDemand".into())
+        .to::<Gd<Object>>();
+    assert_eq!(obj.get_class(), "LazyOnDemand".into());
+    obj.free();
+}
+
+#[itest]
+fn lazy_class_not_deferred_when_eager() {
+    assert!(!godot::private::was_class_deferred("NotLazy"));
+}
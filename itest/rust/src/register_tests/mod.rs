@@ -5,14 +5,22 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+mod abstract_class_test;
+mod class_requires_test;
+mod config_warnings_test;
 mod constant_test;
 mod conversion_test;
 mod derive_variant_test;
+mod extensions_test;
 mod func_test;
 mod gdscript_ffi_test;
+mod lazy_class_test;
 mod option_ffi_test;
+mod reflection_test;
 mod var_test;
 
+#[cfg(feature = "register-docs")]
+mod docs_test;
 #[cfg(since_api = "4.3")]
 mod func_virtual_test;
 
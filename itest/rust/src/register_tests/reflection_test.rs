@@ -0,0 +1,151 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{GString, VariantType};
+use godot::classes::Node;
+use godot::obj::{Base, Gd};
+use godot::register::{godot_api, reflection, GodotClass};
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ReflectedConsole {
+    #[var]
+    history_size: i32,
+
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl ReflectedConsole {
+    #[constant]
+    const MAX_HISTORY: i32 = 64;
+
+    #[signal]
+    fn command_run(command: GString, target: Gd<Node>);
+
+    #[func]
+    fn spawn(&mut self, enemy: GString, count: i32) -> Vec<Gd<Node>> {
+        let _ = (enemy, count);
+        vec![]
+    }
+
+    #[func]
+    fn version() -> GString {
+        GString::from("1.0")
+    }
+}
+
+#[itest]
+fn reflection_find_class() {
+    let class = reflection::find_class("ReflectedConsole").expect("class registered");
+    assert_eq!(class.name, "ReflectedConsole");
+    assert_eq!(class.base_class, "Node");
+
+    // Methods.
+    let spawn = class.method("spawn").expect("method registered");
+    let param_names = spawn
+        .params
+        .iter()
+        .map(|p| p.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(param_names, ["enemy", "count"]);
+    assert_eq!(spawn.params[0].variant_type, VariantType::STRING);
+    assert_eq!(spawn.params[1].variant_type, VariantType::INT);
+    assert!(!spawn.is_static);
+    assert!(spawn.accepts_arg_count(2));
+    assert!(!spawn.accepts_arg_count(1));
+
+    let ret = spawn.return_type.as_ref().expect("returns value");
+    assert_eq!(ret.variant_type, VariantType::ARRAY);
+    #[cfg(since_api = "4.2")]
+    {
+        assert_eq!(ret.hint, godot::global::PropertyHint::ARRAY_TYPE);
+        assert_eq!(ret.hint_string, "Node");
+    }
+
+    let version = class.method("version").expect("static method registered");
+    assert!(version.is_static);
+    assert!(version.params.is_empty());
+
+    // Accessors generated for #[var].
+    assert!(class.method("get_history_size").is_some());
+    assert!(class.properties.iter().any(|p| p.name == "history_size"));
+
+    // Signals and constants.
+    let signal = class.signal("command_run").expect("signal registered");
+    assert_eq!(signal.params.len(), 2);
+    assert_eq!(signal.params[1].class_name, "Node");
+
+    let constant = class.constant("MAX_HISTORY").expect("constant registered");
+    assert_eq!(constant.value, 64);
+    assert_eq!(constant.enum_name, None);
+}
+
+#[itest]
+fn reflection_own_classes() {
+    let classes = reflection::own_classes();
+
+    assert!(classes.iter().any(|class| class.name == "ReflectedConsole"));
+    assert!(classes.windows(2).all(|pair| pair[0].name < pair[1].name));
+
+    assert!(reflection::find_class("Node").is_none(), "engine class");
+    assert!(reflection::find_class("DoesNotExist").is_none());
+}
+
+#[itest]
+fn class_db_hierarchy_queries() {
+    use godot::classes::{Camera3D, Node3D, RefCounted};
+    use godot::meta::class_db;
+    use godot::obj::GodotClass;
+
+    assert!(class_db::is_subclass_of::<Camera3D, Node>());
+    assert!(class_db::is_subclass_of::<Node, Node>());
+    assert!(!class_db::is_subclass_of::<RefCounted, Node>());
+    assert!(class_db::is_subclass_of::<ReflectedConsole, Node>());
+
+    assert!(class_db::class_exists("Camera3D"));
+    assert!(!class_db::class_exists("NoSuchClass"));
+    assert!(!class_db::class_inherits("NoSuchClass", "Object"));
+
+    assert_eq!(class_db::parent_class("Node3D").as_deref(), Some("Node"));
+    assert_eq!(class_db::parent_class("Object"), None);
+    assert!(class_db::subclasses_of("Node3D").contains(&"Camera3D".to_string()));
+    assert!(class_db::can_instantiate("Node3D"));
+    assert!(!class_db::can_instantiate("NoSuchClass"));
+
+    let own = class_db::methods("Node3D", false);
+    assert!(own.iter().any(|method| method.name == "set_position"));
+    assert!(!own.iter().any(|method| method.name == "add_child"));
+    assert!(class_db::methods("Node3D", true)
+        .iter()
+        .any(|method| method.name == "add_child"));
+
+    assert!(class_db::properties("Node3D", false)
+        .iter()
+        .any(
+            |property| property.name == "position" && property.variant_type == VariantType::VECTOR3
+        ));
+    assert!(class_db::signals("Node", false)
+        .iter()
+        .any(|signal| signal.name == "ready"));
+    assert!(class_db::constants("Node", false)
+        .iter()
+        .any(|constant| constant.name == "NOTIFICATION_READY"));
+    assert!(class_db::methods("NoSuchClass", true).is_empty());
+
+    assert_eq!(
+        class_db::rust_class_name("Node3D"),
+        Some(Node3D::class_name())
+    );
+    assert_eq!(
+        class_db::rust_class_name(ReflectedConsole::class_name().as_str()),
+        Some(ReflectedConsole::class_name())
+    );
+    assert_eq!(class_db::rust_class_name("NoSuchClass"), None);
+}
@@ -5,8 +5,12 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::sync::atomic::{AtomicU32, Ordering};
+
 use godot::prelude::*;
 
+use crate::framework::itest;
+
 #[derive(GodotClass)]
 #[class(init)]
 struct WithInitDefaults {
@@ -21,3 +25,177 @@ struct WithInitDefaults {
     #[init(default = -42)]
     expr_int: i64,
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Replication
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ReplicatedNode {
+    #[var(replicate = always)]
+    position: Vector2,
+
+    #[var(replicate = on_change)]
+    health: i32,
+
+    #[var(get, replicate = spawn)]
+    player_id: i64,
+
+    #[var]
+    not_replicated: bool,
+
+    base: Base<Node>,
+}
+
+#[itest]
+fn var_replicated_properties() {
+    use godot::register::replication::{replicated_properties, ReplicatedProperty, Replication};
+
+    let expected = vec![
+        ReplicatedProperty {
+            name: "position",
+            mode: Replication::Always,
+        },
+        ReplicatedProperty {
+            name: "health",
+            mode: Replication::OnChange,
+        },
+        ReplicatedProperty {
+            name: "player_id",
+            mode: Replication::SpawnOnly,
+        },
+    ];
+
+    assert_eq!(replicated_properties::<ReplicatedNode>(), expected);
+}
+
+#[itest]
+fn var_replication_config() {
+    use godot::register::replication::{add_replicated_properties, replication_config};
+
+    let mut config = replication_config::<ReplicatedNode>();
+
+    let paths: Vec<String> = config
+        .get_properties()
+        .iter_shared()
+        .map(|path| path.to_string())
+        .collect();
+    assert_eq!(paths, [".:position", ".:health", ".:player_id"]);
+
+    for path in ["position", "health", "player_id"] {
+        assert!(config.property_get_spawn(format!(".:{path}").into()));
+    }
+
+    #[cfg(since_api = "4.2")]
+    {
+        use godot::classes::scene_replication_config::ReplicationMode;
+
+        let mode = |path: &str| config.property_get_replication_mode(path.into());
+        assert_eq!(mode(".:position"), ReplicationMode::ALWAYS);
+        assert_eq!(mode(".:health"), ReplicationMode::ON_CHANGE);
+        assert_eq!(mode(".:player_id"), ReplicationMode::NEVER);
+    }
+
+    // Adding again for a child node extends the config.
+    add_replicated_properties::<ReplicatedNode>(&mut config, "Child");
+    assert_eq!(config.get_properties().len(), 6);
+    assert!(config.has_property("Child:health".into()));
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Revert to defaults
+
+#[derive(GodotClass)]
+#[class(init)]
+struct RevertToInit {
+    #[export]
+    #[init(default = 42)]
+    level: i64,
+
+    #[export]
+    title: GString,
+
+    #[var]
+    not_exported: i32,
+}
+
+#[itest]
+fn var_revert_to_init_defaults() {
+    let mut obj = RevertToInit::new_gd();
+    obj.bind_mut().level = 7;
+    obj.bind_mut().title = "changed".into();
+
+    assert!(obj.property_can_revert("level".into()));
+    assert_eq!(obj.property_get_revert("level".into()), 42.to_variant());
+    assert_eq!(
+        obj.property_get_revert("title".into()),
+        GString::new().to_variant()
+    );
+
+    // Only exported properties, declared by the class itself.
+    assert!(!obj.property_can_revert("not_exported".into()));
+    assert!(!obj.property_can_revert("script".into()));
+    assert_eq!(obj.property_get_revert("unknown".into()), Variant::nil());
+}
+
+static REVERT_INITIALIZER_CALLS: AtomicU32 = AtomicU32::new(0);
+
+fn counted_initializer() -> i64 {
+    REVERT_INITIALIZER_CALLS.fetch_add(1, Ordering::Relaxed);
+    5
+}
+
+#[derive(GodotClass)]
+#[class(init)]
+struct RevertCounted {
+    #[export]
+    #[init(default = counted_initializer())]
+    counted: i64,
+
+    #[export]
+    other: i32,
+}
+
+#[itest]
+fn var_revert_evaluates_initializers_once() {
+    let obj = RevertCounted::new_gd();
+    let after_init = REVERT_INITIALIZER_CALLS.load(Ordering::Relaxed);
+
+    for _ in 0..3 {
+        assert!(obj.property_can_revert("counted".into()));
+        assert_eq!(obj.property_get_revert("counted".into()), 5.to_variant());
+        assert_eq!(obj.property_get_revert("other".into()), 0.to_variant());
+    }
+
+    // All queries share one evaluation, cached for the class.
+    let revert_calls = REVERT_INITIALIZER_CALLS.load(Ordering::Relaxed) - after_init;
+    assert_eq!(revert_calls, 1);
+}
+
+static REVERT_USER_INIT_CALLS: AtomicU32 = AtomicU32::new(0);
+
+#[derive(GodotClass)]
+#[class(base=RefCounted)]
+struct RevertToUserInit {
+    #[export]
+    level: i64,
+}
+
+#[godot_api]
+impl IRefCounted for RevertToUserInit {
+    fn init(_base: Base<RefCounted>) -> Self {
+        REVERT_USER_INIT_CALLS.fetch_add(1, Ordering::Relaxed);
+        Self { level: 42 }
+    }
+}
+
+#[itest]
+fn var_revert_without_generated_init() {
+    let obj = RevertToUserInit::new_gd();
+    let calls = REVERT_USER_INIT_CALLS.load(Ordering::Relaxed);
+
+    // A user-defined init() is never run to compute reverts.
+    assert!(!obj.property_can_revert("level".into()));
+    assert_eq!(obj.property_get_revert("level".into()), Variant::nil());
+    assert_eq!(REVERT_USER_INIT_CALLS.load(Ordering::Relaxed), calls);
+}